@@ -39,6 +39,20 @@ fn bench_refresh_processes(b: &mut test::Bencher) {
     });
 }
 
+// To be compared with `bench_refresh_processes`, which refreshes the processes in parallel
+// when the `multithread` feature is enabled.
+#[cfg(feature = "system")]
+#[bench]
+fn bench_refresh_processes_sequential(b: &mut test::Bencher) {
+    let mut s = sysinfo::System::new();
+
+    s.set_refresh_parallelism(1);
+    s.refresh_processes(sysinfo::ProcessesToUpdate::All, true); // to load the whole processes list a first time.
+    b.iter(move || {
+        s.refresh_processes(sysinfo::ProcessesToUpdate::All, true);
+    });
+}
+
 #[cfg(feature = "system")]
 #[bench]
 fn bench_first_refresh_processes(b: &mut test::Bencher) {
@@ -91,6 +105,17 @@ fn bench_refresh_networks(b: &mut test::Bencher) {
     });
 }
 
+#[cfg(feature = "network")]
+#[bench]
+fn bench_refresh_network_interface(b: &mut test::Bencher) {
+    let mut n = sysinfo::Networks::new_with_refreshed_list();
+    let name = n.keys().next().cloned().unwrap_or_default();
+
+    b.iter(move || {
+        n.refresh_interface(&name);
+    });
+}
+
 #[cfg(feature = "system")]
 #[bench]
 fn bench_refresh_memory(b: &mut test::Bencher) {
@@ -122,6 +147,20 @@ fn bench_refresh_components(b: &mut test::Bencher) {
     });
 }
 
+#[cfg(feature = "component")]
+#[bench]
+fn bench_refresh_component(b: &mut test::Bencher) {
+    let mut c = sysinfo::Components::new_with_refreshed_list();
+
+    // Nothing to benchmark on machines without sensors.
+    let Some(component) = c.list_mut().first_mut() else {
+        return;
+    };
+    b.iter(move || {
+        component.refresh();
+    });
+}
+
 #[bench]
 fn bench_refresh_users_list(b: &mut test::Bencher) {
     let mut users = sysinfo::Users::new_with_refreshed_list();
@@ -87,7 +87,7 @@ int main() {
         printf("CPU #%d usage: %f%%\n", i, procs[i]);
         i += 1;
     }
-    free(procs);
+    sysinfo_cpus_usage_free(procs);
 
     // processes part
     i = 0;
@@ -0,0 +1,83 @@
+#!/usr/bin/env python3
+# Take a look at the license at the top of the repository in the LICENSE file.
+
+# Generates `src/common/mac_vendors/list.rs` from the IEEE MA-L registry.
+#
+# Usage: `python3 scripts/generate_mac_vendors.py [oui.csv]`
+#
+# The registry is downloaded from the IEEE website if no file is given.
+
+import csv
+import io
+import os
+import re
+import sys
+import urllib.request
+
+REGISTRY_URL = "https://standards-oui.ieee.org/oui/oui.csv"
+OUTPUT = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)), "..", "src", "common", "mac_vendors", "list.rs"
+)
+
+# The MA-M and MA-S blocks are divided between several organizations.
+SKIPPED_ORGANIZATIONS = {"IEEE Registration Authority", "Private"}
+
+# The legal forms which are removed from the end of the organization names, like in
+# "Dell Inc." or "Realtek Semiconductor Corp.".
+LEGAL_FORMS = re.compile(
+    r"[\s,.]*\b(?:Inc|Incorporated|Corp|Corporation|Co|Company|Ltd|Limited|LLC|L\.L\.C|GmbH"
+    r"|AG|S\.?A|S\.?A\.?S|B\.?V|N\.?V|Oy|AB|A/S|K\.?K|Pte|Pty|PLC|S\.?p\.?A|S\.?r\.?l)\.?$",
+    re.IGNORECASE,
+)
+
+
+def shorten(name):
+    name = " ".join(name.split())
+    while True:
+        shortened = LEGAL_FORMS.sub("", name).rstrip(" ,.")
+        if shortened == name or not shortened:
+            return name
+        name = shortened
+
+
+def read_registry(path):
+    if path is None:
+        request = urllib.request.Request(REGISTRY_URL, headers={"User-Agent": "sysinfo"})
+        with urllib.request.urlopen(request) as response:
+            return response.read().decode("utf-8")
+    with open(path, encoding="utf-8") as f:
+        return f.read()
+
+
+def main():
+    content = read_registry(sys.argv[1] if len(sys.argv) > 1 else None)
+    vendors = {}
+    for row in csv.DictReader(io.StringIO(content)):
+        if row["Registry"] != "MA-L":
+            continue
+        name = shorten(row["Organization Name"])
+        if name in SKIPPED_ORGANIZATIONS:
+            continue
+        vendors[bytes.fromhex(row["Assignment"])] = name
+
+    with open(OUTPUT, "w", encoding="utf-8", newline="\n") as f:
+        f.write(
+            "// Take a look at the license at the top of the repository in the LICENSE file.\n"
+            "\n"
+            "// Generated by `scripts/generate_mac_vendors.py` from the IEEE MA-L registry, don't\n"
+            "// edit it manually.\n"
+            "\n"
+            "/// Organizationally unique identifiers (the first three bytes of the MAC addresses\n"
+            "/// assigned by the IEEE) and the names of their organizations, sorted by prefix.\n"
+            "#[rustfmt::skip]\n"
+            "pub(super) static VENDORS: &[([u8; 3], &str)] = &[\n"
+        )
+        for oui, name in sorted(vendors.items()):
+            prefix = ", ".join(f"0x{byte:02x}" for byte in oui)
+            name = name.replace("\\", "\\\\").replace('"', '\\"')
+            f.write(f'    ([{prefix}], "{name}"),\n')
+        f.write("];\n")
+
+
+if __name__ == "__main__":
+    main()
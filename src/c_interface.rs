@@ -1,9 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{Disks, Networks, Pid, Process, ProcessesToUpdate, System};
-use libc::{self, c_char, c_float, c_uint, c_void, size_t};
-use std::borrow::BorrowMut;
-use std::ffi::CString;
+use crate::{
+    Disk, DiskKind, Disks, Networks, Pid, Process, ProcessStatus, ProcessesToUpdate, System,
+};
+use libc::{self, c_char, c_float, c_int, c_uint, c_void, size_t};
+use std::borrow::{BorrowMut, Cow};
+use std::ffi::{CString, OsStr};
+#[cfg(unix)]
+use std::os::unix::ffi::OsStrExt;
 
 /// on windows, libc has not include pid_t.
 #[cfg(target_os = "windows")]
@@ -27,6 +31,42 @@ pub type ProcessPidLoop = extern "C" fn(pid: PID, data: *mut c_void) -> bool;
 pub type CNetworks = *mut c_void;
 /// Equivalent of [`Disks`][crate::Disks] struct.
 pub type CDisks = *mut c_void;
+/// Equivalent of [`Disk`][crate::Disk] struct.
+pub type CDisk = *const c_void;
+/// Callback used by [`list`][crate::Disks#method.list].
+pub type DiskLoop = extern "C" fn(disk: CDisk, data: *mut c_void) -> bool;
+
+/// Returns the bytes of `s` as is on Unix, where they can be used directly with the system APIs,
+/// and converted to UTF-8 (the invalid sequences being replaced with `U+FFFD`) otherwise.
+fn os_str_to_bytes(s: &OsStr) -> Cow<'_, [u8]> {
+    #[cfg(unix)]
+    {
+        Cow::Borrowed(s.as_bytes())
+    }
+    #[cfg(not(unix))]
+    {
+        match s.to_string_lossy() {
+            Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
+            Cow::Owned(s) => Cow::Owned(s.into_bytes()),
+        }
+    }
+}
+
+/// Copies `s` into `buffer` and returns its length in bytes.
+///
+/// At most `buffer_len` bytes are written and no nul byte is added, so if the returned length is
+/// bigger than `buffer_len`, the caller needs to retry with a bigger buffer. `buffer` can be null
+/// when `buffer_len` is `0` to only get the length.
+fn copy_to_buffer(s: &[u8], buffer: *mut c_char, buffer_len: size_t) -> size_t {
+    let len = s.len().min(buffer_len);
+    if len != 0 {
+        assert!(!buffer.is_null());
+        unsafe {
+            std::ptr::copy_nonoverlapping(s.as_ptr(), buffer as *mut u8, len);
+        }
+    }
+    s.len() as size_t
+}
 
 /// Equivalent of [`System::new()`][crate::System#method.new].
 #[no_mangle]
@@ -148,6 +188,112 @@ pub extern "C" fn sysinfo_disks_refresh(disks: CDisks) {
     }
 }
 
+/// Equivalent of iterating over [`Disks::list()`][crate::Disks#method.list].
+///
+/// # ⚠️ WARNING ⚠️
+///
+/// While having this method returned disks, you should *never* call
+/// [`sysinfo_disks_refresh`]!
+#[no_mangle]
+pub extern "C" fn sysinfo_disks(
+    disks: CDisks,
+    fn_pointer: Option<DiskLoop>,
+    data: *mut c_void,
+) -> size_t {
+    assert!(!disks.is_null());
+    if let Some(fn_pointer) = fn_pointer {
+        let disks = disks as *const Disks;
+        unsafe {
+            for disk in (*disks).list() {
+                if !fn_pointer(disk as *const Disk as CDisk, data) {
+                    break;
+                }
+            }
+            (*disks).list().len() as size_t
+        }
+    } else {
+        0
+    }
+}
+
+/// Equivalent of [`Disk::name()`][crate::Disk#method.name].
+///
+/// The name is written into `buffer`, see [`sysinfo_process_name`] for the details.
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_name(
+    disk: CDisk,
+    buffer: *mut c_char,
+    buffer_len: size_t,
+) -> size_t {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { copy_to_buffer(&os_str_to_bytes((*disk).name()), buffer, buffer_len) }
+}
+
+/// Equivalent of [`Disk::mount_point()`][crate::Disk#method.mount_point].
+///
+/// The mount point is written into `buffer`, see [`sysinfo_process_name`] for the details.
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_mount_point(
+    disk: CDisk,
+    buffer: *mut c_char,
+    buffer_len: size_t,
+) -> size_t {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe {
+        copy_to_buffer(
+            &os_str_to_bytes((*disk).mount_point().as_os_str()),
+            buffer,
+            buffer_len,
+        )
+    }
+}
+
+/// Equivalent of [`Disk::file_system()`][crate::Disk#method.file_system].
+///
+/// The file system is written into `buffer`, see [`sysinfo_process_name`] for the details.
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_file_system(
+    disk: CDisk,
+    buffer: *mut c_char,
+    buffer_len: size_t,
+) -> size_t {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { copy_to_buffer(&os_str_to_bytes((*disk).file_system()), buffer, buffer_len) }
+}
+
+/// Equivalent of [`Disk::total_space()`][crate::Disk#method.total_space].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_total_space(disk: CDisk) -> u64 {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { (*disk).total_space() }
+}
+
+/// Equivalent of [`Disk::available_space()`][crate::Disk#method.available_space].
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_available_space(disk: CDisk) -> u64 {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    unsafe { (*disk).available_space() }
+}
+
+/// Equivalent of [`Disk::kind()`][crate::Disk#method.kind].
+///
+/// Returns one of the `SYSINFO_DISK_KIND_*` values of `sysinfo.h`.
+#[no_mangle]
+pub extern "C" fn sysinfo_disk_kind(disk: CDisk) -> c_int {
+    assert!(!disk.is_null());
+    let disk = disk as *const Disk;
+    match unsafe { (*disk).kind() } {
+        DiskKind::HDD => 0,
+        DiskKind::SSD => 1,
+        DiskKind::Unknown(_) => 2,
+    }
+}
+
 /// Equivalent of [`System::total_memory()`][crate::System#method.total_memory].
 #[no_mangle]
 pub extern "C" fn sysinfo_total_memory(system: CSystem) -> size_t {
@@ -282,6 +428,7 @@ pub extern "C" fn sysinfo_networks_transmitted(networks: CNetworks) -> size_t {
 ///
 /// * `length` will contain the number of CPU usage added into `procs`.
 /// * `procs` will be allocated if it's null and will contain of CPU usage.
+///   If allocated, it must be freed with [`sysinfo_cpus_usage_free`].
 #[no_mangle]
 pub extern "C" fn sysinfo_cpus_usage(
     system: CSystem,
@@ -309,6 +456,16 @@ pub extern "C" fn sysinfo_cpus_usage(
     }
 }
 
+/// Frees the array allocated by [`sysinfo_cpus_usage`].
+#[no_mangle]
+pub extern "C" fn sysinfo_cpus_usage_free(procs: *mut c_float) {
+    if !procs.is_null() {
+        unsafe {
+            libc::free(procs as *mut c_void);
+        }
+    }
+}
+
 /// Equivalent of [`System::processes()`][crate::System#method.processes]. Returns an
 /// array ended by a null pointer. Must be freed.
 ///
@@ -412,6 +569,91 @@ pub extern "C" fn sysinfo_process_parent_pid(process: CProcess) -> PID {
     unsafe { (*process).parent().unwrap_or(Pid(0)).0 as _ }
 }
 
+/// Equivalent of [`Process::name()`][crate::Process#method.name].
+///
+/// The name is written into `buffer` as is on Unix, and as UTF-8 (the invalid sequences being
+/// replaced with `U+FFFD`) on the other platforms. At most `buffer_len` bytes are written and no nul byte is added. The returned value
+/// is the length of the whole name: if it's bigger than `buffer_len`, the name was truncated and
+/// you need to call this function again with a bigger buffer. `buffer` can be null if
+/// `buffer_len` is `0`.
+#[no_mangle]
+pub extern "C" fn sysinfo_process_name(
+    process: CProcess,
+    buffer: *mut c_char,
+    buffer_len: size_t,
+) -> size_t {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    unsafe { copy_to_buffer(&os_str_to_bytes((*process).name()), buffer, buffer_len) }
+}
+
+/// Equivalent of [`Process::exe()`][crate::Process#method.exe].
+///
+/// The path is written into `buffer`, see [`sysinfo_process_name`] for the details. If the path
+/// is unknown, nothing is written and `0` is returned.
+#[no_mangle]
+pub extern "C" fn sysinfo_process_exe(
+    process: CProcess,
+    buffer: *mut c_char,
+    buffer_len: size_t,
+) -> size_t {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    unsafe {
+        match (*process).exe() {
+            Some(exe) => copy_to_buffer(&os_str_to_bytes(exe.as_os_str()), buffer, buffer_len),
+            None => 0,
+        }
+    }
+}
+
+/// Equivalent of [`Process::cmd()`][crate::Process#method.cmd].
+///
+/// The arguments are written into `buffer`, each of them being followed by a nul byte (like in
+/// `/proc/[pid]/cmdline`). See [`sysinfo_process_name`] for the details about the conversion,
+/// the truncation and the returned length.
+#[no_mangle]
+pub extern "C" fn sysinfo_process_cmd(
+    process: CProcess,
+    buffer: *mut c_char,
+    buffer_len: size_t,
+) -> size_t {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    let mut cmd = Vec::new();
+    unsafe {
+        for arg in (*process).cmd() {
+            cmd.extend_from_slice(&os_str_to_bytes(arg));
+            cmd.push(0);
+        }
+        copy_to_buffer(&cmd, buffer, buffer_len)
+    }
+}
+
+/// Equivalent of [`Process::status()`][crate::Process#method.status].
+///
+/// Returns one of the `SYSINFO_PROCESS_STATUS_*` values of `sysinfo.h`.
+#[no_mangle]
+pub extern "C" fn sysinfo_process_status(process: CProcess) -> c_int {
+    assert!(!process.is_null());
+    let process = process as *const Process;
+    match unsafe { (*process).status() } {
+        ProcessStatus::Idle => 0,
+        ProcessStatus::Run => 1,
+        ProcessStatus::Sleep => 2,
+        ProcessStatus::Stop => 3,
+        ProcessStatus::Zombie => 4,
+        ProcessStatus::Tracing => 5,
+        ProcessStatus::Dead => 6,
+        ProcessStatus::Wakekill => 7,
+        ProcessStatus::Waking => 8,
+        ProcessStatus::Parked => 9,
+        ProcessStatus::LockBlocked => 10,
+        ProcessStatus::UninterruptibleDiskSleep => 11,
+        ProcessStatus::Unknown(_) => 12,
+    }
+}
+
 /// Equivalent of [`Process::cpu_usage()`][crate::Process#method.cpu_usage].
 #[no_mangle]
 pub extern "C" fn sysinfo_process_cpu_usage(process: CProcess) -> c_float {
@@ -0,0 +1,401 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+//! Refreshes which don't block the caller, enabled with the `async` feature.
+//!
+//! The collection is shared with the refresh through an [`Arc`]`<`[`Mutex`]`<_>>`. Each refresh
+//! runs on its own thread, so the returned futures can be awaited with any async runtime and a
+//! refresh which never returns (like a disk refresh stuck on a network file system) doesn't
+//! prevent the other refreshes from running. The collection is locked for the whole refresh, so
+//! other threads never see it half-updated, and no `&mut` borrow is held across the `.await`.
+//!
+//! If the future is dropped before it resolves, the refresh still runs until the end: the
+//! collection keeps all its information and gets the refreshed values.
+
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+struct State<T> {
+    result: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+/// Future resolving once the refresh running on its own thread is done.
+///
+/// It is returned by the `refresh_*async` methods, like [`Disks::refresh_async`].
+///
+/// [`Disks::refresh_async`]: crate::Disks::refresh_async
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct RefreshFuture<T = ()> {
+    state: Arc<Mutex<State<T>>>,
+}
+
+impl<T> std::fmt::Debug for RefreshFuture<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RefreshFuture").finish_non_exhaustive()
+    }
+}
+
+impl<T: Send + 'static> RefreshFuture<T> {
+    fn spawn<F: FnOnce() -> T + Send + 'static>(refresh: F) -> Self {
+        let state = Arc::new(Mutex::new(State {
+            result: None,
+            waker: None,
+        }));
+        let thread_state = Arc::clone(&state);
+        thread::spawn(move || {
+            // The panic is forwarded to the task polling the future.
+            let result = panic::catch_unwind(AssertUnwindSafe(refresh));
+            let mut state = thread_state.lock().unwrap_or_else(|e| e.into_inner());
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        });
+        Self { state }
+    }
+}
+
+impl RefreshFuture {
+    /// Runs `refresh` on the collection behind `collection` once it could be locked.
+    fn refresh<C, F>(collection: &Arc<Mutex<C>>, refresh: F) -> Self
+    where
+        C: Send + 'static,
+        F: FnOnce(&mut C) + Send + 'static,
+    {
+        let collection = Arc::clone(collection);
+        Self::spawn(move || {
+            // If a previous refresh panicked, the collection is still consistent enough to be
+            // refreshed again.
+            let mut collection = collection.lock().unwrap_or_else(|e| e.into_inner());
+            refresh(&mut collection);
+        })
+    }
+}
+
+impl<T> Future for RefreshFuture<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        match state.result.take() {
+            Some(Ok(output)) => Poll::Ready(output),
+            Some(Err(payload)) => {
+                drop(state);
+                panic::resume_unwind(payload)
+            }
+            None => {
+                // The task might have been moved to another executor thread since the last poll.
+                if !state
+                    .waker
+                    .as_ref()
+                    .is_some_and(|waker| waker.will_wake(cx.waker()))
+                {
+                    state.waker = Some(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+impl crate::System {
+    /// Refreshes all the system information of `system` on another thread.
+    ///
+    /// It is the equivalent of [`System::refresh_all`][crate::System::refresh_all]. `system`
+    /// is locked until the refresh is done.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use sysinfo::System;
+    ///
+    /// async fn refresh(s: &Arc<Mutex<System>>) {
+    ///     System::refresh_all_async(s).await;
+    ///     println!("{} processes", s.lock().unwrap().processes().len());
+    /// }
+    /// ```
+    pub fn refresh_all_async(system: &Arc<Mutex<Self>>) -> RefreshFuture {
+        Self::refresh_specifics_async(system, crate::RefreshKind::everything())
+    }
+
+    /// Refreshes the given information of `system` on another thread.
+    ///
+    /// It is the equivalent of [`System::refresh_specifics`][crate::System::refresh_specifics].
+    /// `system` is locked until the refresh is done.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use sysinfo::{MemoryRefreshKind, RefreshKind, System};
+    ///
+    /// async fn refresh(s: &Arc<Mutex<System>>) {
+    ///     System::refresh_specifics_async(
+    ///         s,
+    ///         RefreshKind::nothing().with_memory(MemoryRefreshKind::everything()),
+    ///     )
+    ///     .await;
+    ///     println!("{} bytes used", s.lock().unwrap().used_memory());
+    /// }
+    /// ```
+    pub fn refresh_specifics_async(
+        system: &Arc<Mutex<Self>>,
+        refreshes: crate::RefreshKind,
+    ) -> RefreshFuture {
+        RefreshFuture::refresh(system, move |s| s.refresh_specifics(refreshes))
+    }
+}
+
+#[cfg(feature = "disk")]
+impl crate::Disks {
+    /// Refreshes the disks information of `disks` on another thread.
+    ///
+    /// It is the equivalent of [`Disks::refresh`][crate::Disks::refresh], which can block for a
+    /// long time if a network file system doesn't answer. `disks` is
+    /// locked until the refresh is done.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use sysinfo::Disks;
+    ///
+    /// async fn refresh(disks: &Arc<Mutex<Disks>>) {
+    ///     Disks::refresh_async(disks, true).await;
+    ///     println!("{} disks", disks.lock().unwrap().list().len());
+    /// }
+    /// ```
+    pub fn refresh_async(disks: &Arc<Mutex<Self>>, remove_not_listed_disks: bool) -> RefreshFuture {
+        RefreshFuture::refresh(disks, move |disks| disks.refresh(remove_not_listed_disks))
+    }
+}
+
+#[cfg(feature = "network")]
+impl crate::Networks {
+    /// Refreshes the network interfaces information of `networks` on another thread.
+    ///
+    /// It is the equivalent of [`Networks::refresh`][crate::Networks::refresh]. `networks` is
+    /// locked until the refresh is done.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use sysinfo::Networks;
+    ///
+    /// async fn refresh(networks: &Arc<Mutex<Networks>>) {
+    ///     Networks::refresh_async(networks, true).await;
+    ///     for (name, network) in networks.lock().unwrap().iter() {
+    ///         println!("{name}: {} B received", network.received());
+    ///     }
+    /// }
+    /// ```
+    pub fn refresh_async(
+        networks: &Arc<Mutex<Self>>,
+        remove_not_listed_interfaces: bool,
+    ) -> RefreshFuture {
+        RefreshFuture::refresh(networks, move |networks| {
+            networks.refresh(remove_not_listed_interfaces)
+        })
+    }
+}
+
+#[cfg(feature = "component")]
+impl crate::Components {
+    /// Refreshes the components information of `components` on another thread.
+    ///
+    /// It is the equivalent of [`Components::refresh`][crate::Components::refresh]. `components` is
+    /// locked until the refresh is done.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use sysinfo::Components;
+    ///
+    /// async fn refresh(components: &Arc<Mutex<Components>>) {
+    ///     Components::refresh_async(components, true).await;
+    ///     for component in components.lock().unwrap().list() {
+    ///         println!("{}: {:?}°C", component.label(), component.temperature());
+    ///     }
+    /// }
+    /// ```
+    pub fn refresh_async(
+        components: &Arc<Mutex<Self>>,
+        remove_not_listed_components: bool,
+    ) -> RefreshFuture {
+        RefreshFuture::refresh(components, move |components| {
+            components.refresh(remove_not_listed_components)
+        })
+    }
+}
+
+#[cfg(feature = "user")]
+impl crate::Users {
+    /// Refreshes the list of users of `users` on another thread.
+    ///
+    /// It is the equivalent of [`Users::refresh`][crate::Users::refresh]. `users` is
+    /// locked until the refresh is done.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use sysinfo::Users;
+    ///
+    /// async fn refresh(users: &Arc<Mutex<Users>>) {
+    ///     Users::refresh_async(users).await;
+    ///     println!("{} users", users.lock().unwrap().list().len());
+    /// }
+    /// ```
+    pub fn refresh_async(users: &Arc<Mutex<Self>>) -> RefreshFuture {
+        RefreshFuture::refresh(users, |users| users.refresh())
+    }
+}
+
+#[cfg(feature = "user")]
+impl crate::Groups {
+    /// Refreshes the list of groups of `groups` on another thread.
+    ///
+    /// It is the equivalent of [`Groups::refresh`][crate::Groups::refresh]. `groups` is
+    /// locked until the refresh is done.
+    ///
+    /// ```no_run
+    /// use std::sync::{Arc, Mutex};
+    /// use sysinfo::Groups;
+    ///
+    /// async fn refresh(groups: &Arc<Mutex<Groups>>) {
+    ///     Groups::refresh_async(groups).await;
+    ///     println!("{} groups", groups.lock().unwrap().list().len());
+    /// }
+    /// ```
+    pub fn refresh_async(groups: &Arc<Mutex<Self>>) -> RefreshFuture {
+        RefreshFuture::refresh(groups, |groups| groups.refresh())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RefreshFuture;
+
+    use std::future::Future;
+    use std::pin::pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    // Minimal executor, so the tests don't depend on an async runtime.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn check_refresh_future() {
+        let value = block_on(RefreshFuture::spawn(|| {
+            thread::sleep(std::time::Duration::from_millis(10));
+            42
+        }));
+        assert_eq!(value, 42);
+    }
+
+    #[test]
+    #[should_panic(expected = "refresh failed")]
+    fn check_refresh_future_panic() {
+        block_on(RefreshFuture::spawn(|| -> u32 { panic!("refresh failed") }));
+    }
+
+    #[cfg(feature = "disk")]
+    #[test]
+    fn check_cancelled_refresh() {
+        let disks = Arc::new(Mutex::new(crate::Disks::new_with_refreshed_list()));
+        let expected = disks.lock().unwrap().list().len();
+        if crate::IS_SUPPORTED_SYSTEM {
+            assert!(expected > 0);
+        }
+
+        // Dropping the future doesn't stop the refresh, and the collection keeps its disks.
+        drop(crate::Disks::refresh_async(&disks, true));
+        assert_eq!(disks.lock().unwrap().list().len(), expected);
+
+        block_on(crate::Disks::refresh_async(&disks, true));
+        assert_eq!(disks.lock().unwrap().list().len(), expected);
+    }
+
+    #[cfg(feature = "network")]
+    #[test]
+    fn check_networks_refresh_async_deltas() {
+        let networks = Arc::new(Mutex::new(crate::Networks::new_with_refreshed_list()));
+        let before: std::collections::HashMap<_, _> = networks
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(name, network)| (name.clone(), network.total_received()))
+            .collect();
+
+        // Generates some traffic on the loopback interface.
+        let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+        socket
+            .send_to(&[0; 1024], socket.local_addr().unwrap())
+            .unwrap();
+
+        block_on(crate::Networks::refresh_async(&networks, true));
+        let networks = networks.lock().unwrap();
+        if crate::IS_SUPPORTED_SYSTEM {
+            assert!(!networks.list().is_empty());
+        }
+        for (name, network) in networks.iter() {
+            if let Some(total_received) = before.get(name) {
+                // The deltas are computed from the previous refresh of the collection.
+                assert_eq!(
+                    network.received(),
+                    network.total_received() - total_received
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "system")]
+    #[test]
+    fn check_system_refresh_async() {
+        let s = Arc::new(Mutex::new(crate::System::new()));
+        block_on(crate::System::refresh_specifics_async(
+            &s,
+            crate::RefreshKind::nothing().with_memory(crate::MemoryRefreshKind::everything()),
+        ));
+        let s = s.lock().unwrap();
+        if crate::IS_SUPPORTED_SYSTEM {
+            assert!(s.total_memory() > 0);
+        }
+        assert!(s.processes().is_empty());
+    }
+
+    #[cfg(feature = "system")]
+    #[test]
+    fn check_system_refresh_async_cpu_usage() {
+        let refreshes = crate::RefreshKind::nothing()
+            .with_cpu(crate::CpuRefreshKind::nothing().with_cpu_usage());
+        let s = Arc::new(Mutex::new(crate::System::new_with_specifics(refreshes)));
+
+        // Keeps the CPU busy so its usage is not `0`.
+        let start = std::time::Instant::now();
+        while start.elapsed() < crate::MINIMUM_CPU_UPDATE_INTERVAL {
+            std::hint::black_box(start.elapsed());
+        }
+
+        block_on(crate::System::refresh_specifics_async(&s, refreshes));
+        if crate::IS_SUPPORTED_SYSTEM {
+            // The usage is computed from the previous refresh of the collection.
+            assert!(s.lock().unwrap().global_cpu_usage() > 0.);
+        }
+    }
+}
@@ -0,0 +1,345 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{BatteriesInner, BatteryInner};
+
+/// Interacting with batteries.
+///
+/// Machines without batteries (like most desktops) simply have an empty list.
+///
+/// ⚠️ On FreeBSD, the batteries are retrieved from the ACPI `hw.acpi.battery` sysctls, so only
+/// one battery combining all of them is listed.
+///
+/// ```no_run
+/// use sysinfo::Batteries;
+///
+/// let batteries = Batteries::new_with_refreshed_list();
+/// for battery in &batteries {
+///     println!("{battery:?}");
+/// }
+/// ```
+pub struct Batteries {
+    pub(crate) inner: BatteriesInner,
+}
+
+impl Default for Batteries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Batteries> for Vec<Battery> {
+    fn from(batteries: Batteries) -> Self {
+        batteries.inner.into_vec()
+    }
+}
+
+impl From<Vec<Battery>> for Batteries {
+    fn from(batteries: Vec<Battery>) -> Self {
+        Self {
+            inner: BatteriesInner::from_vec(batteries),
+        }
+    }
+}
+
+impl std::ops::Deref for Batteries {
+    type Target = [Battery];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Batteries {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Batteries {
+    type Item = &'a Battery;
+    type IntoIter = std::slice::Iter<'a, Battery>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Batteries {
+    type Item = &'a mut Battery;
+    type IntoIter = std::slice::IterMut<'a, Battery>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Batteries {
+    /// Creates a new empty [`Batteries`][crate::Batteries] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Batteries::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let mut batteries = Batteries::new();
+    /// batteries.refresh(false);
+    /// for battery in &batteries {
+    ///     println!("{battery:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: BatteriesInner::new(),
+        }
+    }
+
+    /// Creates a new [`Batteries`][crate::Batteries] type with the batteries list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in batteries.list() {
+    ///     println!("{battery:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut batteries = Self::new();
+        batteries.refresh(true);
+        batteries
+    }
+
+    /// Returns the batteries list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in batteries.list() {
+    ///     println!("{battery:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Battery] {
+        self.inner.list()
+    }
+
+    /// Returns the batteries list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let mut batteries = Batteries::new_with_refreshed_list();
+    /// for battery in batteries.list_mut() {
+    ///     battery.refresh();
+    ///     println!("{battery:?}");
+    /// }
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Battery] {
+        self.inner.list_mut()
+    }
+
+    /// Refreshes the batteries list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let mut batteries = Batteries::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// batteries.refresh(false);
+    /// ```
+    pub fn refresh(&mut self, remove_not_listed_batteries: bool) {
+        self.inner.refresh();
+        if remove_not_listed_batteries {
+            // Remove batteries which are gone.
+            self.inner.batteries.retain_mut(|b| {
+                if !b.inner.updated {
+                    return false;
+                }
+                b.inner.updated = false;
+                true
+            });
+        }
+    }
+}
+
+/// Getting a battery information.
+///
+/// ```no_run
+/// use sysinfo::Batteries;
+///
+/// let batteries = Batteries::new_with_refreshed_list();
+/// for battery in &batteries {
+///     println!("{}: {:?}% ({:?})", battery.name(), battery.charge_percent(), battery.state());
+/// }
+/// ```
+pub struct Battery {
+    pub(crate) inner: BatteryInner,
+}
+
+impl Battery {
+    /// Returns the name of the battery.
+    ///
+    /// ## Linux
+    ///
+    /// It is the name of its `/sys/class/power_supply` folder, like `BAT0`.
+    ///
+    /// ## macOS
+    ///
+    /// It is the name of its power source, like `InternalBattery-0` for the internal battery, or
+    /// the product name of a UPS.
+    ///
+    /// ## Windows
+    ///
+    /// It is its device name if it is available, `Battery {index}` otherwise.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in &batteries {
+    ///     println!("{}", battery.name());
+    /// }
+    /// ```
+    pub fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    /// Returns the charge of the battery, in percent of its
+    /// [full charge capacity](Battery::full_charge_capacity).
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in &batteries {
+    ///     if let Some(charge) = battery.charge_percent() {
+    ///         println!("{charge}%");
+    ///     }
+    /// }
+    /// ```
+    pub fn charge_percent(&self) -> Option<f32> {
+        self.inner.charge_percent()
+    }
+
+    /// Returns whether the battery is charging, discharging or full.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in &batteries {
+    ///     println!("{:?}", battery.state());
+    /// }
+    /// ```
+    pub fn state(&self) -> BatteryState {
+        self.inner.state()
+    }
+
+    /// Returns the number of charge cycles of the battery.
+    ///
+    /// It returns `None` if the battery doesn't provide it. On macOS, it is only available for
+    /// the internal battery.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in &batteries {
+    ///     if let Some(cycle_count) = battery.cycle_count() {
+    ///         println!("{cycle_count} cycles");
+    ///     }
+    /// }
+    /// ```
+    pub fn cycle_count(&self) -> Option<u32> {
+        self.inner.cycle_count()
+    }
+
+    /// Returns the capacity of the battery when it was new, in watt-hours.
+    ///
+    /// When the capacity is reported in ampere-hours, it is converted using the voltage of the
+    /// battery.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in &batteries {
+    ///     if let Some(capacity) = battery.design_capacity() {
+    ///         println!("{capacity} Wh");
+    ///     }
+    /// }
+    /// ```
+    pub fn design_capacity(&self) -> Option<f32> {
+        self.inner.design_capacity()
+    }
+
+    /// Returns the capacity of the battery when it is fully charged, in watt-hours.
+    ///
+    /// It decreases as the battery wears out, so comparing it with
+    /// [`Battery::design_capacity`] gives the health of the battery.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in &batteries {
+    ///     if let (Some(full), Some(design)) =
+    ///         (battery.full_charge_capacity(), battery.design_capacity())
+    ///     {
+    ///         println!("health: {}%", full * 100. / design);
+    ///     }
+    /// }
+    /// ```
+    pub fn full_charge_capacity(&self) -> Option<f32> {
+        self.inner.full_charge_capacity()
+    }
+
+    /// Returns the power going into the battery (when it is charging) or out of it (when it is
+    /// discharging), in watts.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let batteries = Batteries::new_with_refreshed_list();
+    /// for battery in &batteries {
+    ///     if let Some(power) = battery.power_watts() {
+    ///         println!("{power} W ({:?})", battery.state());
+    ///     }
+    /// }
+    /// ```
+    pub fn power_watts(&self) -> Option<f32> {
+        self.inner.power_watts()
+    }
+
+    /// Refreshes battery.
+    ///
+    /// ```no_run
+    /// use sysinfo::Batteries;
+    ///
+    /// let mut batteries = Batteries::new_with_refreshed_list();
+    /// for battery in batteries.iter_mut() {
+    ///     battery.refresh();
+    /// }
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh()
+    }
+}
+
+/// State of a [`Battery`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum BatteryState {
+    /// The battery is charging.
+    Charging,
+    /// The battery is discharging.
+    Discharging,
+    /// The battery is fully charged.
+    Full,
+    /// The machine is plugged in but the battery is neither charging nor full, for example
+    /// because a charge limit was set.
+    NotCharging,
+    /// The state of the battery is unknown.
+    Unknown,
+}
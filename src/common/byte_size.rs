@@ -0,0 +1,245 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::fmt;
+
+/// Prefixes used when displaying a [`ByteSize`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BytePrefix {
+    /// Powers of 1024: `KiB`, `MiB`, `GiB`...
+    #[default]
+    Binary,
+    /// Powers of 1000: `kB`, `MB`, `GB`...
+    Decimal,
+}
+
+impl BytePrefix {
+    fn base(self) -> f64 {
+        match self {
+            Self::Binary => 1024.,
+            Self::Decimal => 1000.,
+        }
+    }
+
+    fn units(self) -> &'static [&'static str] {
+        match self {
+            Self::Binary => &["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+            Self::Decimal => &["B", "kB", "MB", "GB", "TB", "PB", "EB"],
+        }
+    }
+}
+
+/// An amount of bytes, like the memory used by a [`Process`][crate::Process].
+///
+/// It is displayed with the most fitting unit, with one decimal by default. The binary prefixes
+/// are used unless the decimal ones are selected with [`ByteSize::with_prefix`].
+///
+/// ```
+/// use sysinfo::{BytePrefix, ByteSize};
+///
+/// let size = ByteSize::new(1_572_864);
+/// assert_eq!(size.to_string(), "1.5 MiB");
+/// assert_eq!(format!("{size:.2}"), "1.50 MiB");
+/// assert_eq!(size.with_prefix(BytePrefix::Decimal).to_string(), "1.6 MB");
+/// assert_eq!(size.as_mib(), 1.5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ByteSize {
+    bytes: u64,
+    prefix: BytePrefix,
+}
+
+impl ByteSize {
+    /// Creates a new [`ByteSize`] displayed with the binary prefixes.
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(512).to_string(), "512 B");
+    /// ```
+    pub fn new(bytes: u64) -> Self {
+        Self {
+            bytes,
+            prefix: BytePrefix::Binary,
+        }
+    }
+
+    /// Returns a copy of this [`ByteSize`] which is displayed with the given prefixes.
+    ///
+    /// ```
+    /// use sysinfo::{BytePrefix, ByteSize};
+    ///
+    /// let size = ByteSize::new(2_000_000_000).with_prefix(BytePrefix::Decimal);
+    /// assert_eq!(size.to_string(), "2.0 GB");
+    /// ```
+    pub fn with_prefix(self, prefix: BytePrefix) -> Self {
+        Self { prefix, ..self }
+    }
+
+    /// Returns the prefixes used to display this [`ByteSize`].
+    ///
+    /// ```
+    /// use sysinfo::{BytePrefix, ByteSize};
+    ///
+    /// assert_eq!(ByteSize::new(0).prefix(), BytePrefix::Binary);
+    /// ```
+    pub fn prefix(&self) -> BytePrefix {
+        self.prefix
+    }
+
+    /// Returns the number of bytes.
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(2048).as_u64(), 2048);
+    /// ```
+    pub fn as_u64(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns the size in kibibytes (1024 bytes).
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(2048).as_kib(), 2.);
+    /// ```
+    pub fn as_kib(&self) -> f64 {
+        self.bytes as f64 / 1024.
+    }
+
+    /// Returns the size in mebibytes (1024 kibibytes).
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(3 * 1024 * 1024).as_mib(), 3.);
+    /// ```
+    pub fn as_mib(&self) -> f64 {
+        self.as_kib() / 1024.
+    }
+
+    /// Returns the size in gibibytes (1024 mebibytes).
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(512 * 1024 * 1024).as_gib(), 0.5);
+    /// ```
+    pub fn as_gib(&self) -> f64 {
+        self.as_mib() / 1024.
+    }
+
+    /// Returns the size in kilobytes (1000 bytes).
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(1500).as_kb(), 1.5);
+    /// ```
+    pub fn as_kb(&self) -> f64 {
+        self.bytes as f64 / 1000.
+    }
+
+    /// Returns the size in megabytes (1000 kilobytes).
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(2_000_000).as_mb(), 2.);
+    /// ```
+    pub fn as_mb(&self) -> f64 {
+        self.as_kb() / 1000.
+    }
+
+    /// Returns the size in gigabytes (1000 megabytes).
+    ///
+    /// ```
+    /// use sysinfo::ByteSize;
+    ///
+    /// assert_eq!(ByteSize::new(250_000_000).as_gb(), 0.25);
+    /// ```
+    pub fn as_gb(&self) -> f64 {
+        self.as_mb() / 1000.
+    }
+}
+
+impl From<u64> for ByteSize {
+    fn from(bytes: u64) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<ByteSize> for u64 {
+    fn from(size: ByteSize) -> Self {
+        size.bytes
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let base = self.prefix.base();
+        let units = self.prefix.units();
+        if (self.bytes as f64) < base {
+            return write!(f, "{} {}", self.bytes, units[0]);
+        }
+        let precision = f.precision().unwrap_or(1);
+        let mut value = self.bytes as f64;
+        let mut unit = 0;
+        while value >= base && unit + 1 < units.len() {
+            value /= base;
+            unit += 1;
+        }
+        // Otherwise `1023.99 KiB` would be displayed as `1024.0 KiB`.
+        let rounding = 10_f64.powi(precision as i32);
+        if (value * rounding).round() / rounding >= base && unit + 1 < units.len() {
+            value /= base;
+            unit += 1;
+        }
+        write!(f, "{value:.precision$} {}", units[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BytePrefix, ByteSize};
+
+    #[test]
+    fn check_byte_size_display() {
+        let binary = |bytes: u64| ByteSize::new(bytes).to_string();
+        let decimal = |bytes: u64| {
+            ByteSize::new(bytes)
+                .with_prefix(BytePrefix::Decimal)
+                .to_string()
+        };
+
+        assert_eq!(binary(0), "0 B");
+        assert_eq!(binary(1023), "1023 B");
+        assert_eq!(binary(1024), "1.0 KiB");
+        assert_eq!(binary(1536), "1.5 KiB");
+        assert_eq!(binary(1024 * 1024 - 1), "1.0 MiB");
+        assert_eq!(binary(5 * 1024 * 1024 * 1024), "5.0 GiB");
+        assert_eq!(binary(u64::MAX), "16.0 EiB");
+
+        assert_eq!(decimal(999), "999 B");
+        assert_eq!(decimal(1000), "1.0 kB");
+        assert_eq!(decimal(1_572_864), "1.6 MB");
+        assert_eq!(decimal(999_999), "1.0 MB");
+        assert_eq!(decimal(3_000_000_000_000), "3.0 TB");
+
+        assert_eq!(format!("{:.0}", ByteSize::new(1536)), "2 KiB");
+        assert_eq!(format!("{:.3}", ByteSize::new(1536)), "1.500 KiB");
+        assert_eq!(format!("{:.3}", ByteSize::new(12)), "12 B");
+    }
+
+    #[test]
+    fn check_byte_size_conversions() {
+        let size = ByteSize::new(3 * 1024 * 1024 * 1024);
+        assert_eq!(size.as_u64(), 3 * 1024 * 1024 * 1024);
+        assert_eq!(size.as_kib(), 3. * 1024. * 1024.);
+        assert_eq!(size.as_mib(), 3. * 1024.);
+        assert_eq!(size.as_gib(), 3.);
+        assert_eq!(ByteSize::new(4_000_000_000).as_gb(), 4.);
+        assert_eq!(u64::from(ByteSize::from(42)), 42);
+    }
+}
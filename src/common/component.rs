@@ -1,9 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{ComponentInner, ComponentsInner};
+use crate::{
+    ComponentInner, ComponentsInner, FanInner, FansInner, PowerSensorInner, PowerSensorsInner,
+};
 
 /// Interacting with components.
 ///
+/// ⚠️ On Windows, the temperatures are retrieved from the ACPI thermal zones, or from the
+/// thermal zones performance counters if they are not available, as well as from the storage
+/// drives and the GPUs which expose them.
+///
+/// With the `nvml` feature, the NVIDIA GPUs temperature is also retrieved from the NVML
+/// library on Linux and Windows, if it is installed.
+///
 /// ```no_run
 /// use sysinfo::Components;
 ///
@@ -137,6 +146,9 @@ impl Components {
 
     /// Refreshes the components list.
     ///
+    /// The [observed maximum][Component::observed_max] temperature of the components which were
+    /// already listed is kept.
+    ///
     /// ```no_run
     /// use sysinfo::Components;
     ///
@@ -147,16 +159,57 @@ impl Components {
     pub fn refresh(&mut self, remove_not_listed_components: bool) {
         self.inner.refresh();
         if remove_not_listed_components {
-            // Remove interfaces which are gone.
-            self.inner.components.retain_mut(|c| {
-                if !c.inner.updated {
-                    return false;
-                }
-                c.inner.updated = false;
-                true
-            });
+            self.remove_not_updated();
+        }
+        for component in self.inner.list_mut() {
+            component.update_observed_max();
+        }
+    }
+
+    /// Refreshes the components for which `filter` returns `true`.
+    ///
+    /// `filter` receives the [device name][Component::device_name] (or an empty string if it
+    /// is unknown) and the [label][Component::label] of each component. The components for
+    /// which it returns `false` are not refreshed and are removed from the list (or never added
+    /// to it), just like the components which don't exist anymore.
+    ///
+    /// The [observed maximum][Component::observed_max] temperature of the components is reset.
+    ///
+    /// ## Linux
+    ///
+    /// `filter` is called before the `hwmon` sensor files are opened, so the skipped sensors
+    /// don't cost anything.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new();
+    /// components.refresh_list_filtered(|device_name, _label| {
+    ///     matches!(device_name, "coretemp" | "k10temp")
+    /// });
+    /// for component in &components {
+    ///     println!("{component:?}");
+    /// }
+    /// ```
+    pub fn refresh_list_filtered<F: FnMut(&str, &str) -> bool>(&mut self, mut filter: F) {
+        self.inner.refresh_filtered(&mut filter);
+        self.remove_not_updated();
+        for component in self.inner.list_mut() {
+            component.observed_max = None;
+            component.update_observed_max();
         }
     }
+
+    fn remove_not_updated(&mut self) {
+        // Remove components which are gone.
+        self.inner.components.retain_mut(|c| {
+            if !c.inner.updated {
+                return false;
+            }
+            c.inner.updated = false;
+            true
+        });
+    }
 }
 
 /// Getting a component temperature information.
@@ -175,6 +228,7 @@ impl Components {
 /// ```
 pub struct Component {
     pub(crate) inner: ComponentInner,
+    pub(crate) observed_max: Option<f32>,
 }
 
 impl Component {
@@ -203,9 +257,14 @@ impl Component {
     /// Note: if `temperature` is higher than the current `max`,
     /// `max` value will be updated on refresh.
     ///
+    /// If you're looking for the highest temperature the component reached, use
+    /// [`Component::observed_max`] instead.
+    ///
     /// ## Linux
     ///
-    /// May be computed by `sysinfo` from kernel.
+    /// It is the highest temperature recorded by the chip if it is available (`tempN_highest`),
+    /// which may have been reached long before the first refresh. Otherwise, it is computed by
+    /// `sysinfo` from the temperature.
     /// Returns `f32::NAN` if it failed to retrieve it.
     ///
     /// ```no_run
@@ -222,6 +281,50 @@ impl Component {
         self.inner.max()
     }
 
+    /// Returns the highest temperature of the component (in celsius degree) seen by the
+    /// refreshes of its [`Components`].
+    ///
+    /// Unlike [`Component::max`], which may come from the hardware, it is only computed from the
+    /// values returned by [`Component::temperature`]. It is reset by
+    /// [`Components::refresh_list_filtered`], and it is `None` if no temperature was retrieved
+    /// yet.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let mut components = Components::new_with_refreshed_list();
+    /// // We wait some time...
+    /// components.refresh(false);
+    /// for component in &components {
+    ///     if let Some(observed_max) = component.observed_max() {
+    ///         println!("{} reached {observed_max}°C", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn observed_max(&self) -> Option<f32> {
+        self.observed_max
+    }
+
+    /// Returns the lowest temperature the component is expected to run at (in celsius degree).
+    ///
+    /// ## Linux
+    ///
+    /// Minimum threshold defined by chip or kernel.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if let Some(min) = component.min() {
+    ///         println!("{min}°C");
+    ///     }
+    /// }
+    /// ```
+    pub fn min(&self) -> Option<f32> {
+        self.inner.min()
+    }
+
     /// Returns the highest temperature before the component halts (in celsius degree).
     ///
     /// ## Linux
@@ -242,6 +345,103 @@ impl Component {
         self.inner.critical()
     }
 
+    /// Returns the temperature the component needs to go back under for the critical alarm to be
+    /// cleared (in celsius degree).
+    ///
+    /// ## Linux
+    ///
+    /// Critical threshold hysteresis defined by chip or kernel.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if let Some(hysteresis) = component.critical_hysteresis() {
+    ///         println!("{hysteresis}°C");
+    ///     }
+    /// }
+    /// ```
+    pub fn critical_hysteresis(&self) -> Option<f32> {
+        self.inner.critical_hysteresis()
+    }
+
+    /// Returns `true` if the alarm of the component was triggered.
+    ///
+    /// It is updated on every refresh.
+    ///
+    /// ## Linux
+    ///
+    /// Alarm or critical alarm raised by chip or kernel.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if component.alarm() == Some(true) {
+    ///         println!("{} is too hot!", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn alarm(&self) -> Option<bool> {
+        self.inner.alarm()
+    }
+
+    /// Returns the name of the device (chip or driver) providing the component.
+    ///
+    /// It can be used to filter components with [`Components::refresh_list_filtered`].
+    ///
+    /// ## Linux
+    ///
+    /// It is the `hwmon` chip name (like `"coretemp"`, `"k10temp"` or `"nvme"`), or the
+    /// thermal zone type if the component comes from `/sys/class/thermal`.
+    ///
+    /// ## Other platforms
+    ///
+    /// It always returns `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     println!("{}: {:?}", component.label(), component.device_name());
+    /// }
+    /// ```
+    pub fn device_name(&self) -> Option<&str> {
+        self.inner.device_name()
+    }
+
+    /// Returns the raw identifier of the component, as given by the system.
+    ///
+    /// It is mostly useful when [`Component::label`] was made more human readable than this
+    /// identifier.
+    ///
+    /// ## macOS
+    ///
+    /// On Apple Silicon, it is the name of the HID temperature sensor (like
+    /// `"pACC MTR Temp Sensor2"`, labelled `"CPU P-core 2"`). On Intel, it is the SMC key (like
+    /// `"TC0P"`).
+    ///
+    /// ## Other platforms
+    ///
+    /// It always returns `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Components;
+    ///
+    /// let components = Components::new_with_refreshed_list();
+    /// for component in &components {
+    ///     if let Some(id) = component.id() {
+    ///         println!("{}: {id}", component.label());
+    ///     }
+    /// }
+    /// ```
+    pub fn id(&self) -> Option<&str> {
+        self.inner.id()
+    }
+
     /// Returns the label of the component.
     ///
     /// ## Linux
@@ -257,6 +457,23 @@ impl Component {
     /// | ✓    | ✗    | ✓  | ✓ | `"{name} {device_model}"` |
     /// | ✓    | ✗    | ✗  | ✓ | `"{name} temp{id}"` |
     ///
+    /// The sensors of the `amdgpu`, `nouveau` and `radeon` GPU drivers are labelled
+    /// `"GPU {name} {card} {label}"` instead (like `"GPU amdgpu card0 edge"`), or
+    /// `"GPU {name} {card} temp{id}"` if they don't have a label.
+    ///
+    /// With the `nvml` feature, the NVIDIA GPUs are labelled `"GPU nvidia{index} {GPU name}"`.
+    ///
+    /// ## macOS
+    ///
+    /// On Apple Silicon, the known temperature sensors are given a human readable label (like
+    /// `"CPU P-core 2"` or `"Battery"`). The other ones keep the name returned by
+    /// [`Component::id`].
+    ///
+    /// ## Windows
+    ///
+    /// With the `nvml` feature, the NVIDIA GPUs which don't already expose their temperature to
+    /// Windows are labelled `"GPU nvidia{index} {GPU name}"`, like on Linux.
+    ///
     /// ```no_run
     /// use sysinfo::Components;
     ///
@@ -271,6 +488,9 @@ impl Component {
 
     /// Refreshes component.
     ///
+    /// Only this component's sensor is read again, which is much cheaper than refreshing all
+    /// [`Components`] when only a few of them are needed.
+    ///
     /// ```no_run
     /// use sysinfo::Components;
     ///
@@ -279,11 +499,587 @@ impl Component {
     ///     component.refresh();
     /// }
     /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh();
+        self.update_observed_max();
+    }
+
+    pub(crate) fn update_observed_max(&mut self) {
+        let Some(temperature) = self.temperature().filter(|t| !t.is_nan()) else {
+            return;
+        };
+        self.observed_max = Some(match self.observed_max {
+            Some(observed_max) => observed_max.max(temperature),
+            None => temperature,
+        });
+    }
+}
+
+/// Interacting with fans.
+///
+/// Fans are listed separately from the [`Components`] since they don't report a temperature.
+///
+/// ```no_run
+/// use sysinfo::Fans;
+///
+/// let fans = Fans::new_with_refreshed_list();
+/// for fan in &fans {
+///     println!("{fan:?}");
+/// }
+/// ```
+pub struct Fans {
+    pub(crate) inner: FansInner,
+}
+
+impl Default for Fans {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Fans> for Vec<Fan> {
+    fn from(fans: Fans) -> Self {
+        fans.inner.into_vec()
+    }
+}
+
+impl From<Vec<Fan>> for Fans {
+    fn from(fans: Vec<Fan>) -> Self {
+        Self {
+            inner: FansInner::from_vec(fans),
+        }
+    }
+}
+
+impl std::ops::Deref for Fans {
+    type Target = [Fan];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for Fans {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a Fans {
+    type Item = &'a Fan;
+    type IntoIter = std::slice::Iter<'a, Fan>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Fans {
+    type Item = &'a mut Fan;
+    type IntoIter = std::slice::IterMut<'a, Fan>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl Fans {
+    /// Creates a new empty [`Fans`][crate::Fans] type.
+    ///
+    /// If you want it to be filled directly, take a look at [`Fans::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let mut fans = Fans::new();
+    /// fans.refresh(false);
+    /// for fan in &fans {
+    ///     println!("{fan:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: FansInner::new(),
+        }
+    }
+
+    /// Creates a new [`Fans`][crate::Fans] type with the fans list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let fans = Fans::new_with_refreshed_list();
+    /// for fan in fans.list() {
+    ///     println!("{fan:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut fans = Self::new();
+        fans.refresh(true);
+        fans
+    }
+
+    /// Returns the fans list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let fans = Fans::new_with_refreshed_list();
+    /// for fan in fans.list() {
+    ///     println!("{fan:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Fan] {
+        self.inner.list()
+    }
+
+    /// Returns the fans list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let mut fans = Fans::new_with_refreshed_list();
+    /// for fan in fans.list_mut() {
+    ///     fan.refresh();
+    ///     println!("{fan:?}");
+    /// }
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [Fan] {
+        self.inner.list_mut()
+    }
+
+    /// Refreshes the fans list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let mut fans = Fans::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// fans.refresh(false);
+    /// ```
+    pub fn refresh(&mut self, remove_not_listed_fans: bool) {
+        self.inner.refresh();
+        if remove_not_listed_fans {
+            // Remove fans which are gone.
+            self.inner.fans.retain_mut(|f| {
+                if !f.inner.updated {
+                    return false;
+                }
+                f.inner.updated = false;
+                true
+            });
+        }
+    }
+}
+
+/// Getting a fan speed information.
+///
+/// ```no_run
+/// use sysinfo::Fans;
+///
+/// let fans = Fans::new_with_refreshed_list();
+/// for fan in &fans {
+///     if let Some(rpm) = fan.rpm() {
+///         println!("{} {rpm} RPM", fan.label());
+///     } else {
+///         println!("{} (unknown speed)", fan.label());
+///     }
+/// }
+/// ```
+pub struct Fan {
+    pub(crate) inner: FanInner,
+}
+
+impl Fan {
+    /// Returns the current speed of the fan (in revolutions per minute).
+    ///
+    /// ## Windows
+    ///
+    /// It is the `DesiredSpeed` of the `Win32_Fan` WMI class, which most hardware vendors don't
+    /// provide, so it is often unavailable.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let fans = Fans::new_with_refreshed_list();
+    /// for fan in &fans {
+    ///     if let Some(rpm) = fan.rpm() {
+    ///         println!("{rpm} RPM");
+    ///     }
+    /// }
+    /// ```
+    pub fn rpm(&self) -> Option<u32> {
+        self.inner.rpm()
+    }
+
+    /// Returns the minimum speed of the fan (in revolutions per minute), if provided by the
+    /// hardware.
+    ///
+    /// ## Linux
+    ///
+    /// Read in `fanN_min`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let fans = Fans::new_with_refreshed_list();
+    /// for fan in &fans {
+    ///     if let Some(min_rpm) = fan.min_rpm() {
+    ///         println!("{min_rpm} RPM");
+    ///     }
+    /// }
+    /// ```
+    pub fn min_rpm(&self) -> Option<u32> {
+        self.inner.min_rpm()
+    }
+
+    /// Returns the maximum speed of the fan (in revolutions per minute), if provided by the
+    /// hardware.
+    ///
+    /// ## Linux
+    ///
+    /// Read in `fanN_max`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let fans = Fans::new_with_refreshed_list();
+    /// for fan in &fans {
+    ///     if let Some(max_rpm) = fan.max_rpm() {
+    ///         println!("{max_rpm} RPM");
+    ///     }
+    /// }
+    /// ```
+    pub fn max_rpm(&self) -> Option<u32> {
+        self.inner.max_rpm()
+    }
+
+    /// Returns the label of the fan.
+    ///
+    /// ## Linux
+    ///
+    /// The labels are generated the same way as for [`Component::label`], with `fan{id}`
+    /// instead of `temp{id}`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let fans = Fans::new_with_refreshed_list();
+    /// for fan in &fans {
+    ///     println!("{}", fan.label());
+    /// }
+    /// ```
+    pub fn label(&self) -> &str {
+        self.inner.label()
+    }
+
+    /// Refreshes fan.
+    ///
+    /// ```no_run
+    /// use sysinfo::Fans;
+    ///
+    /// let mut fans = Fans::new_with_refreshed_list();
+    /// for fan in fans.iter_mut() {
+    ///     fan.refresh();
+    /// }
+    /// ```
+    pub fn refresh(&mut self) {
+        self.inner.refresh()
+    }
+}
+
+/// Interacting with voltage, current and power sensors.
+///
+/// ```no_run
+/// use sysinfo::PowerSensors;
+///
+/// let sensors = PowerSensors::new_with_refreshed_list();
+/// for sensor in &sensors {
+///     println!("{sensor:?}");
+/// }
+/// ```
+pub struct PowerSensors {
+    pub(crate) inner: PowerSensorsInner,
+}
+
+impl Default for PowerSensors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<PowerSensors> for Vec<PowerSensor> {
+    fn from(sensors: PowerSensors) -> Self {
+        sensors.inner.into_vec()
+    }
+}
+
+impl From<Vec<PowerSensor>> for PowerSensors {
+    fn from(sensors: Vec<PowerSensor>) -> Self {
+        Self {
+            inner: PowerSensorsInner::from_vec(sensors),
+        }
+    }
+}
+
+impl std::ops::Deref for PowerSensors {
+    type Target = [PowerSensor];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl std::ops::DerefMut for PowerSensors {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.list_mut()
+    }
+}
+
+impl<'a> IntoIterator for &'a PowerSensors {
+    type Item = &'a PowerSensor;
+    type IntoIter = std::slice::Iter<'a, PowerSensor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut PowerSensors {
+    type Item = &'a mut PowerSensor;
+    type IntoIter = std::slice::IterMut<'a, PowerSensor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list_mut().iter_mut()
+    }
+}
+
+impl PowerSensors {
+    /// Creates a new empty [`PowerSensors`][crate::PowerSensors] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`PowerSensors::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let mut sensors = PowerSensors::new();
+    /// sensors.refresh(false);
+    /// for sensor in &sensors {
+    ///     println!("{sensor:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            inner: PowerSensorsInner::new(),
+        }
+    }
+
+    /// Creates a new [`PowerSensors`][crate::PowerSensors] type with the sensors list loaded.
+    ///
+    /// Please note that the power computed from energy counters (like Intel RAPL on Linux)
+    /// needs two refreshes to be available.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in sensors.list() {
+    ///     println!("{sensor:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut sensors = Self::new();
+        sensors.refresh(true);
+        sensors
+    }
+
+    /// Returns the sensors list.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in sensors.list() {
+    ///     println!("{sensor:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[PowerSensor] {
+        self.inner.list()
+    }
+
+    /// Returns the sensors list.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let mut sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in sensors.list_mut() {
+    ///     sensor.refresh();
+    ///     println!("{sensor:?}");
+    /// }
+    /// ```
+    pub fn list_mut(&mut self) -> &mut [PowerSensor] {
+        self.inner.list_mut()
+    }
+
+    /// Refreshes the sensors list.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let mut sensors = PowerSensors::new_with_refreshed_list();
+    /// // We wait some time...?
+    /// sensors.refresh(false);
+    /// ```
+    pub fn refresh(&mut self, remove_not_listed_sensors: bool) {
+        self.inner.refresh();
+        if remove_not_listed_sensors {
+            // Remove sensors which are gone.
+            self.inner.sensors.retain_mut(|s| {
+                if !s.inner.updated {
+                    return false;
+                }
+                s.inner.updated = false;
+                true
+            });
+        }
+    }
+}
+
+/// Getting a voltage, current or power sensor information.
+///
+/// ```no_run
+/// use sysinfo::PowerSensors;
+///
+/// let sensors = PowerSensors::new_with_refreshed_list();
+/// for sensor in &sensors {
+///     if let Some(value) = sensor.value() {
+///         println!("{} {value}{}", sensor.label(), sensor.unit());
+///     } else {
+///         println!("{} (unknown {:?})", sensor.label(), sensor.kind());
+///     }
+/// }
+/// ```
+pub struct PowerSensor {
+    pub(crate) inner: PowerSensorInner,
+}
+
+impl PowerSensor {
+    /// Returns what the sensor measures.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in &sensors {
+    ///     println!("{:?}", sensor.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> PowerSensorKind {
+        self.inner.kind()
+    }
+
+    /// Returns the value of the sensor, in the unit returned by [`PowerSensor::unit`].
+    ///
+    /// ## Linux
+    ///
+    /// For Intel RAPL (`/sys/class/powercap/intel-rapl:*`) sensors, the power is computed from
+    /// the energy consumed since the previous refresh, so it is `None` after the first one.
+    /// Please note that their energy counters are only readable with root privileges on most
+    /// systems, so these sensors aren't listed otherwise.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in &sensors {
+    ///     if let Some(value) = sensor.value() {
+    ///         println!("{value}{}", sensor.unit());
+    ///     }
+    /// }
+    /// ```
+    pub fn value(&self) -> Option<f32> {
+        self.inner.value()
+    }
+
+    /// Returns the unit of [`PowerSensor::value`]. See [`PowerSensorKind::unit`].
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in &sensors {
+    ///     println!("{}", sensor.unit());
+    /// }
+    /// ```
+    pub fn unit(&self) -> &'static str {
+        self.kind().unit()
+    }
+
+    /// Returns the label of the sensor.
+    ///
+    /// ## Linux
+    ///
+    /// For `hwmon` sensors, the labels are generated the same way as for
+    /// [`Component::label`], with `in{id}`, `curr{id}` or `power{id}` instead of `temp{id}`.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in &sensors {
+    ///     println!("{}", sensor.label());
+    /// }
+    /// ```
+    pub fn label(&self) -> &str {
+        self.inner.label()
+    }
+
+    /// Refreshes sensor.
+    ///
+    /// ```no_run
+    /// use sysinfo::PowerSensors;
+    ///
+    /// let mut sensors = PowerSensors::new_with_refreshed_list();
+    /// for sensor in sensors.iter_mut() {
+    ///     sensor.refresh();
+    /// }
+    /// ```
     pub fn refresh(&mut self) {
         self.inner.refresh()
     }
 }
 
+/// What a [`PowerSensor`] measures.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum PowerSensorKind {
+    /// Voltage, in volts.
+    Voltage,
+    /// Current, in amperes.
+    Current,
+    /// Power, in watts.
+    Power,
+}
+
+impl PowerSensorKind {
+    /// Returns the unit of the values of this kind of sensor.
+    ///
+    /// ```
+    /// use sysinfo::PowerSensorKind;
+    ///
+    /// assert_eq!(PowerSensorKind::Power.unit(), "W");
+    /// ```
+    pub fn unit(self) -> &'static str {
+        match self {
+            Self::Voltage => "V",
+            Self::Current => "A",
+            Self::Power => "W",
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
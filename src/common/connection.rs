@@ -0,0 +1,522 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::fmt;
+use std::net::SocketAddr;
+
+use crate::common::impl_get_set::impl_get_set;
+use crate::Pid;
+
+/// Interacting with the sockets of the system, like `netstat` or `ss` would.
+///
+/// ```no_run
+/// use sysinfo::Connections;
+///
+/// let connections = Connections::new_with_refreshed_list();
+/// for connection in connections.list() {
+///     println!(
+///         "{} {} -> {:?} ({:?})",
+///         connection.protocol(),
+///         connection.local_address(),
+///         connection.remote_address(),
+///         connection.state(),
+///     );
+/// }
+/// ```
+pub struct Connections {
+    connections: Vec<Connection>,
+}
+
+impl Default for Connections {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Connections> for Vec<Connection> {
+    fn from(connections: Connections) -> Self {
+        connections.connections
+    }
+}
+
+impl std::ops::Deref for Connections {
+    type Target = [Connection];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl<'a> IntoIterator for &'a Connections {
+    type Item = &'a Connection;
+    type IntoIter = std::slice::Iter<'a, Connection>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl Connections {
+    /// Creates a new empty [`Connections`][crate::Connections] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Connections::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let mut connections = Connections::new();
+    /// connections.refresh();
+    /// for connection in connections.list() {
+    ///     println!("{connection:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            connections: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Connections`][crate::Connections] type with the connections list loaded.
+    ///
+    /// Equivalent to <code>[Connections::new_with_refreshed_list_specifics]\([ConnectionsFilter::All], [ConnectionsRefreshKind::nothing]\())</code>.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     println!("{connection:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        Self::new_with_refreshed_list_specifics(
+            ConnectionsFilter::All,
+            ConnectionsRefreshKind::nothing(),
+        )
+    }
+
+    /// Creates a new [`Connections`][crate::Connections] type with the connections matching
+    /// `filter` loaded and refreshed according to the given [`ConnectionsRefreshKind`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Connections, ConnectionsFilter, ConnectionsRefreshKind};
+    ///
+    /// let connections = Connections::new_with_refreshed_list_specifics(
+    ///     ConnectionsFilter::Listening,
+    ///     ConnectionsRefreshKind::everything(),
+    /// );
+    /// for connection in connections.list() {
+    ///     println!("{} is listened by {:?}", connection.local_address(), connection.pid());
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list_specifics(
+        filter: ConnectionsFilter<'_>,
+        refresh_kind: ConnectionsRefreshKind,
+    ) -> Self {
+        let mut connections = Self::new();
+        connections.refresh_specifics(filter, refresh_kind);
+        connections
+    }
+
+    /// Returns the connections list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     println!("{connection:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Connection] {
+        &self.connections
+    }
+
+    /// The connections list will be emptied then completely recomputed.
+    ///
+    /// Equivalent to <code>[Connections::refresh_specifics]\([ConnectionsFilter::All], [ConnectionsRefreshKind::nothing]\())</code>.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let mut connections = Connections::new();
+    /// connections.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        self.refresh_specifics(ConnectionsFilter::All, ConnectionsRefreshKind::nothing());
+    }
+
+    /// The connections list will be emptied then recomputed with only the connections matching
+    /// `filter`, according to the given [`ConnectionsRefreshKind`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Connections, ConnectionsFilter, ConnectionsRefreshKind, Pid};
+    ///
+    /// let mut connections = Connections::new();
+    /// connections.refresh_specifics(
+    ///     ConnectionsFilter::Processes(&[Pid::from_u32(1337)]),
+    ///     ConnectionsRefreshKind::nothing(),
+    /// );
+    /// ```
+    pub fn refresh_specifics(
+        &mut self,
+        filter: ConnectionsFilter<'_>,
+        refresh_kind: ConnectionsRefreshKind,
+    ) {
+        let with_pid = refresh_kind.pid() || matches!(filter, ConnectionsFilter::Processes(_));
+        // The backends might use `filter` to avoid retrieving some of the connections, but they
+        // don't have to, so we filter out the remaining ones here.
+        crate::sys::get_connections(&mut self.connections, filter, with_pid);
+        match filter {
+            ConnectionsFilter::All => {}
+            ConnectionsFilter::Listening => self.connections.retain(Connection::is_listening),
+            ConnectionsFilter::Processes(pids) => self
+                .connections
+                .retain(|c| c.pid.is_some_and(|pid| pids.contains(&pid))),
+        }
+    }
+}
+
+/// This enum allows you to specify which connections you want to retrieve.
+///
+/// ```no_run
+/// use sysinfo::{Connections, ConnectionsFilter, ConnectionsRefreshKind, Pid};
+///
+/// // To only retrieve the listening sockets:
+/// let connections = Connections::new_with_refreshed_list_specifics(
+///     ConnectionsFilter::Listening,
+///     ConnectionsRefreshKind::nothing(),
+/// );
+///
+/// // To only retrieve the sockets of a given process:
+/// let connections = Connections::new_with_refreshed_list_specifics(
+///     ConnectionsFilter::Processes(&[Pid::from_u32(1337)]),
+///     ConnectionsRefreshKind::nothing(),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionsFilter<'a> {
+    /// To retrieve all connections.
+    All,
+    /// To only retrieve the TCP sockets in the [`TcpState::Listen`] state and the UDP sockets
+    /// which are not connected to a remote address.
+    Listening,
+    /// To only retrieve the connections owned by the listed processes.
+    ///
+    /// The owning process of the connections is retrieved even if
+    /// [`ConnectionsRefreshKind::pid`] is disabled.
+    Processes(&'a [Pid]),
+}
+
+/// Used to determine what you want to retrieve when refreshing the [`Connections`] list.
+///
+/// * `pid` is about retrieving the [`Connection::pid`] information. On Linux, it requires going
+///   through the file descriptors of all processes, which is expensive.
+///
+/// ```no_run
+/// use sysinfo::{Connections, ConnectionsFilter, ConnectionsRefreshKind};
+///
+/// let connections = Connections::new_with_refreshed_list_specifics(
+///     ConnectionsFilter::All,
+///     ConnectionsRefreshKind::nothing().with_pid(),
+/// );
+///
+/// for connection in connections.list() {
+///     println!("{} is owned by {:?}", connection.local_address(), connection.pid());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ConnectionsRefreshKind {
+    pid: bool,
+}
+
+impl ConnectionsRefreshKind {
+    /// Creates a new `ConnectionsRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::ConnectionsRefreshKind;
+    ///
+    /// let r = ConnectionsRefreshKind::nothing();
+    ///
+    /// assert_eq!(r.pid(), false);
+    /// ```
+    pub fn nothing() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `ConnectionsRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::ConnectionsRefreshKind;
+    ///
+    /// let r = ConnectionsRefreshKind::everything();
+    ///
+    /// assert_eq!(r.pid(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self { pid: true }
+    }
+
+    impl_get_set!(ConnectionsRefreshKind, pid, with_pid, without_pid);
+}
+
+/// Type containing a socket information.
+///
+/// It is returned by [`Connections`][crate::Connections].
+///
+/// ```no_run
+/// use sysinfo::Connections;
+///
+/// let connections = Connections::new_with_refreshed_list();
+/// for connection in connections.list() {
+///     println!("{connection:?}");
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Connection {
+    pub(crate) protocol: ConnectionProtocol,
+    pub(crate) local_address: SocketAddr,
+    pub(crate) remote_address: Option<SocketAddr>,
+    pub(crate) state: Option<TcpState>,
+    pub(crate) pid: Option<Pid>,
+    pub(crate) inode: Option<u64>,
+}
+
+impl Connection {
+    /// Returns the transport protocol of the socket.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     println!("{}", connection.protocol());
+    /// }
+    /// ```
+    pub fn protocol(&self) -> ConnectionProtocol {
+        self.protocol
+    }
+
+    /// Returns the local address of the socket.
+    ///
+    /// Whether it is an IPv4 or an IPv6 socket can be known with [`SocketAddr::is_ipv6`]. The
+    /// IPv4-mapped IPv6 addresses (like `::ffff:127.0.0.1`) used by the dual-stack sockets are
+    /// returned as IPv4 addresses.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     println!("{}", connection.local_address());
+    /// }
+    /// ```
+    pub fn local_address(&self) -> SocketAddr {
+        self.local_address
+    }
+
+    /// Returns the remote address of the socket, or `None` if it is not connected.
+    ///
+    /// Like for [`Connection::local_address`], the IPv4-mapped IPv6 addresses are returned as
+    /// IPv4 addresses.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     if let Some(remote_address) = connection.remote_address() {
+    ///         println!("{} -> {remote_address}", connection.local_address());
+    ///     }
+    /// }
+    /// ```
+    pub fn remote_address(&self) -> Option<SocketAddr> {
+        self.remote_address
+    }
+
+    /// Returns the state of the socket. It is always `None` for UDP sockets.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Connections, TcpState};
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// let established = connections
+    ///     .list()
+    ///     .iter()
+    ///     .filter(|connection| connection.state() == Some(TcpState::Established))
+    ///     .count();
+    /// println!("{established} established TCP connections");
+    /// ```
+    pub fn state(&self) -> Option<TcpState> {
+        self.state
+    }
+
+    /// Returns the ID of the process owning the socket.
+    ///
+    /// It is only retrieved if [`ConnectionsRefreshKind::pid`] was enabled or if the connections
+    /// were filtered with [`ConnectionsFilter::Processes`]. It is also `None` if the socket isn't
+    /// owned by any process anymore (like the ones in the [`TcpState::TimeWait`] state) or if it
+    /// is owned by a process the current user isn't allowed to inspect.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Connections, ConnectionsFilter, ConnectionsRefreshKind};
+    ///
+    /// let connections = Connections::new_with_refreshed_list_specifics(
+    ///     ConnectionsFilter::All,
+    ///     ConnectionsRefreshKind::nothing().with_pid(),
+    /// );
+    /// for connection in connections.list() {
+    ///     println!("{} => {:?}", connection.local_address(), connection.pid());
+    /// }
+    /// ```
+    pub fn pid(&self) -> Option<Pid> {
+        self.pid
+    }
+
+    /// Returns the inode of the socket.
+    ///
+    /// It is only available on Linux, where it is the number shown in the `socket:[inode]` links
+    /// of the `/proc/[pid]/fd` folders. It is `None` if the socket isn't owned by any process
+    /// anymore.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     println!("{} => {:?}", connection.local_address(), connection.inode());
+    /// }
+    /// ```
+    pub fn inode(&self) -> Option<u64> {
+        self.inode
+    }
+
+    /// Returns `true` if this is a listening TCP socket or an unconnected UDP socket.
+    ///
+    /// ```no_run
+    /// use sysinfo::Connections;
+    ///
+    /// let connections = Connections::new_with_refreshed_list();
+    /// for connection in connections.list() {
+    ///     if connection.is_listening() {
+    ///         println!("listening on {}", connection.local_address());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_listening(&self) -> bool {
+        match self.protocol {
+            ConnectionProtocol::Tcp => self.state == Some(TcpState::Listen),
+            ConnectionProtocol::Udp => self.remote_address.is_none(),
+        }
+    }
+}
+
+/// Transport protocol of a [`Connection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ConnectionProtocol {
+    /// Transmission Control Protocol.
+    Tcp,
+    /// User Datagram Protocol.
+    Udp,
+}
+
+impl fmt::Display for ConnectionProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            Self::Tcp => "TCP",
+            Self::Udp => "UDP",
+        })
+    }
+}
+
+/// State of a TCP [`Connection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum TcpState {
+    /// The connection is established and data can be exchanged.
+    Established,
+    /// A connection request was sent, waiting for the remote answer.
+    SynSent,
+    /// A connection request was received and answered, waiting for its acknowledgement.
+    SynReceived,
+    /// The socket was closed, waiting for the remote to acknowledge it.
+    FinWait1,
+    /// The socket was closed and it was acknowledged, waiting for the remote to close too.
+    FinWait2,
+    /// Both sides closed the connection, waiting for the remaining packets to expire.
+    TimeWait,
+    /// The socket is not used.
+    Closed,
+    /// The remote closed the connection, waiting for the socket to be closed.
+    CloseWait,
+    /// The remote closed the connection then the socket was closed, waiting for the last
+    /// acknowledgement.
+    LastAck,
+    /// Waiting for incoming connections.
+    Listen,
+    /// Both sides closed the connection at the same time.
+    Closing,
+    /// Unknown state, with the value used by the operating system.
+    Unknown(u32),
+}
+
+impl fmt::Display for TcpState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Established => f.write_str("ESTABLISHED"),
+            Self::SynSent => f.write_str("SYN_SENT"),
+            Self::SynReceived => f.write_str("SYN_RECEIVED"),
+            Self::FinWait1 => f.write_str("FIN_WAIT_1"),
+            Self::FinWait2 => f.write_str("FIN_WAIT_2"),
+            Self::TimeWait => f.write_str("TIME_WAIT"),
+            Self::Closed => f.write_str("CLOSED"),
+            Self::CloseWait => f.write_str("CLOSE_WAIT"),
+            Self::LastAck => f.write_str("LAST_ACK"),
+            Self::Listen => f.write_str("LISTEN"),
+            Self::Closing => f.write_str("CLOSING"),
+            Self::Unknown(state) => write!(f, "UNKNOWN({state})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn check_listening_socket() {
+        if !IS_SUPPORTED_SYSTEM || cfg!(any(target_os = "freebsd", target_os = "ios")) {
+            return;
+        }
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let local_address = listener.local_addr().unwrap();
+        let pid = Pid::from_u32(std::process::id());
+
+        let connections = Connections::new_with_refreshed_list_specifics(
+            ConnectionsFilter::Processes(&[pid]),
+            ConnectionsRefreshKind::nothing(),
+        );
+        let connection = connections
+            .list()
+            .iter()
+            .find(|connection| connection.local_address() == local_address)
+            .expect("listening socket not found");
+        assert_eq!(connection.protocol(), ConnectionProtocol::Tcp);
+        assert_eq!(connection.state(), Some(TcpState::Listen));
+        assert_eq!(connection.remote_address(), None);
+        assert_eq!(connection.pid(), Some(pid));
+        assert_eq!(connection.inode().is_some(), cfg!(target_os = "linux"));
+        assert!(connections.list().iter().all(|c| c.pid() == Some(pid)));
+
+        let connections = Connections::new_with_refreshed_list_specifics(
+            ConnectionsFilter::Listening,
+            ConnectionsRefreshKind::nothing(),
+        );
+        assert!(connections.list().iter().all(|c| c.is_listening()));
+        assert!(connections
+            .list()
+            .iter()
+            .any(|c| c.local_address() == local_address && c.pid().is_none()));
+    }
+}
@@ -0,0 +1,265 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Architecture of the CPU, along with its word size and byte order.
+///
+/// It is returned by [`System::cpu_arch_detail`][crate::System::cpu_arch_detail].
+///
+/// ```no_run
+/// use sysinfo::{Arch, System, X86Level};
+///
+/// let detail = System::cpu_arch_detail();
+/// match detail.arch {
+///     Arch::X86(Some(level)) if level >= X86Level::V3 => println!("x86-64-v3 is supported"),
+///     arch => println!("{arch:?} ({} bits, {:?})", detail.bits, detail.endianness),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CpuArchDetail {
+    /// Architecture family of the CPU.
+    pub arch: Arch,
+    /// Word size of the architecture, `32` or `64`.
+    pub bits: u32,
+    /// Byte order of the architecture.
+    pub endianness: Endianness,
+}
+
+/// Architecture family of a CPU.
+///
+/// It is used by [`CpuArchDetail`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Arch {
+    /// x86 or x86-64, with the x86-64 microarchitecture level of the CPU.
+    ///
+    /// The level is only known on x86-64 when the current process runs natively, since it is
+    /// computed from the CPUID flags.
+    X86(Option<X86Level>),
+    /// ARM or AArch64, with the version of the architecture if it is known.
+    Arm(Option<ArmVersion>),
+    /// RISC-V.
+    RiscV,
+    /// PowerPC.
+    PowerPc,
+    /// MIPS.
+    Mips,
+    /// LoongArch.
+    LoongArch,
+    /// IBM Z.
+    S390x,
+    /// Another architecture, with the name returned by
+    /// [`System::cpu_arch`][crate::System::cpu_arch].
+    Other(String),
+}
+
+/// x86-64 microarchitecture level, as defined by the x86-64 psABI.
+///
+/// Each level includes the instructions of the previous ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum X86Level {
+    /// Baseline x86-64 (`x86-64`).
+    V1,
+    /// Adds `CMPXCHG16B`, `POPCNT` and SSE up to SSE4.2 (`x86-64-v2`).
+    V2,
+    /// Adds AVX, AVX2, BMI1, BMI2, F16C, FMA, `LZCNT`, `MOVBE` and `XSAVE` (`x86-64-v3`).
+    V3,
+    /// Adds AVX-512F, AVX-512BW, AVX-512CD, AVX-512DQ and AVX-512VL (`x86-64-v4`).
+    V4,
+}
+
+/// Version of the ARM architecture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ArmVersion {
+    /// ARMv5.
+    V5,
+    /// ARMv6.
+    V6,
+    /// ARMv7.
+    V7,
+    /// ARMv8.
+    V8,
+    /// ARMv9. It is detected from the support of SVE2, which is mandatory since ARMv9.
+    V9,
+}
+
+/// Byte order of an architecture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// The least significant byte is stored first.
+    Little,
+    /// The most significant byte is stored first.
+    Big,
+}
+
+impl CpuArchDetail {
+    pub(crate) fn new(name: &str) -> Self {
+        let (arch, bits, endianness) = parse_arch_name(name);
+        let arch = match arch {
+            Arch::X86(_) if bits == Some(64) => Arch::X86(x86_level()),
+            Arch::Arm(Some(ArmVersion::V8)) if bits == Some(64) && has_sve2() => {
+                Arch::Arm(Some(ArmVersion::V9))
+            }
+            arch => arch,
+        };
+        Self {
+            arch,
+            bits: bits.unwrap_or(usize::BITS),
+            endianness: endianness.unwrap_or(if cfg!(target_endian = "big") {
+                Endianness::Big
+            } else {
+                Endianness::Little
+            }),
+        }
+    }
+}
+
+/// Parses the architecture names returned by `uname` and the ones of the Rust targets. The word
+/// size and the byte order are `None` when the name doesn't tell.
+fn parse_arch_name(name: &str) -> (Arch, Option<u32>, Option<Endianness>) {
+    use Endianness::{Big, Little};
+
+    match name {
+        "x86_64" | "amd64" | "x64" => (Arch::X86(None), Some(64), Some(Little)),
+        "x86" | "i386" | "i486" | "i586" | "i686" => (Arch::X86(None), Some(32), Some(Little)),
+        "aarch64" | "arm64" => (Arch::Arm(Some(ArmVersion::V8)), Some(64), Some(Little)),
+        "aarch64_be" => (Arch::Arm(Some(ArmVersion::V8)), Some(64), Some(Big)),
+        "riscv64" => (Arch::RiscV, Some(64), Some(Little)),
+        "riscv32" => (Arch::RiscV, Some(32), Some(Little)),
+        "ppc64le" | "powerpc64le" => (Arch::PowerPc, Some(64), Some(Little)),
+        "ppc64" | "powerpc64" => (Arch::PowerPc, Some(64), Some(Big)),
+        "ppc" | "powerpc" => (Arch::PowerPc, Some(32), Some(Big)),
+        "mips64el" => (Arch::Mips, Some(64), Some(Little)),
+        "mips64" => (Arch::Mips, Some(64), Some(Big)),
+        "mipsel" => (Arch::Mips, Some(32), Some(Little)),
+        "mips" => (Arch::Mips, Some(32), Some(Big)),
+        "loongarch64" => (Arch::LoongArch, Some(64), Some(Little)),
+        "s390x" => (Arch::S390x, Some(64), Some(Big)),
+        _ => match name.strip_prefix("arm") {
+            // Like `armv7l`, `armv6l` or `armv7b`. `armv8l` is a 32-bit process on a 64-bit CPU.
+            Some(rest) => {
+                let version = match rest.strip_prefix('v').and_then(|rest| rest.get(..1)) {
+                    Some("5") => Some(ArmVersion::V5),
+                    Some("6") => Some(ArmVersion::V6),
+                    Some("7") => Some(ArmVersion::V7),
+                    Some("8") => Some(ArmVersion::V8),
+                    _ => None,
+                };
+                let endianness = if rest.ends_with('b') { Big } else { Little };
+                (Arch::Arm(version), Some(32), Some(endianness))
+            }
+            None => (Arch::Other(name.to_owned()), None, None),
+        },
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn x86_level() -> Option<X86Level> {
+    use std::arch::is_x86_feature_detected as has;
+
+    let level = if !(has!("cmpxchg16b")
+        && has!("popcnt")
+        && has!("sse3")
+        && has!("ssse3")
+        && has!("sse4.1")
+        && has!("sse4.2"))
+    {
+        X86Level::V1
+    } else if !(has!("avx")
+        && has!("avx2")
+        && has!("bmi1")
+        && has!("bmi2")
+        && has!("f16c")
+        && has!("fma")
+        && has!("lzcnt")
+        && has!("movbe")
+        && has!("xsave"))
+    {
+        X86Level::V2
+    } else if !(has!("avx512f")
+        && has!("avx512bw")
+        && has!("avx512cd")
+        && has!("avx512dq")
+        && has!("avx512vl"))
+    {
+        X86Level::V3
+    } else {
+        X86Level::V4
+    };
+    Some(level)
+}
+
+// The CPUID flags can't be trusted when running in a 32-bit process or emulated on another
+// architecture.
+#[cfg(not(target_arch = "x86_64"))]
+fn x86_level() -> Option<X86Level> {
+    None
+}
+
+#[cfg(target_arch = "aarch64")]
+fn has_sve2() -> bool {
+    std::arch::is_aarch64_feature_detected!("sve2")
+}
+
+#[cfg(not(target_arch = "aarch64"))]
+fn has_sve2() -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_parse_arch_name() {
+        use Endianness::{Big, Little};
+
+        assert_eq!(
+            parse_arch_name("x86_64"),
+            (Arch::X86(None), Some(64), Some(Little))
+        );
+        assert_eq!(
+            parse_arch_name("i686"),
+            (Arch::X86(None), Some(32), Some(Little))
+        );
+        assert_eq!(
+            parse_arch_name("arm64"),
+            (Arch::Arm(Some(ArmVersion::V8)), Some(64), Some(Little))
+        );
+        assert_eq!(
+            parse_arch_name("armv7l"),
+            (Arch::Arm(Some(ArmVersion::V7)), Some(32), Some(Little))
+        );
+        assert_eq!(
+            parse_arch_name("armv6b"),
+            (Arch::Arm(Some(ArmVersion::V6)), Some(32), Some(Big))
+        );
+        assert_eq!(
+            parse_arch_name("arm"),
+            (Arch::Arm(None), Some(32), Some(Little))
+        );
+        assert_eq!(
+            parse_arch_name("ppc64le"),
+            (Arch::PowerPc, Some(64), Some(Little))
+        );
+        assert_eq!(parse_arch_name("s390x"), (Arch::S390x, Some(64), Some(Big)));
+        assert_eq!(
+            parse_arch_name("sparc64"),
+            (Arch::Other("sparc64".to_owned()), None, None)
+        );
+    }
+
+    #[test]
+    fn check_cpu_arch_detail() {
+        let detail = CpuArchDetail::new(std::env::consts::ARCH);
+        assert_eq!(detail.bits, usize::BITS);
+        if cfg!(target_arch = "x86_64") {
+            assert!(matches!(detail.arch, Arch::X86(Some(_))));
+        }
+        assert_eq!(
+            CpuArchDetail::new("i686"),
+            CpuArchDetail {
+                arch: Arch::X86(None),
+                bits: 32,
+                endianness: Endianness::Little,
+            }
+        );
+    }
+}
@@ -3,6 +3,7 @@
 use std::ffi::OsStr;
 use std::fmt;
 use std::path::Path;
+use std::time::Instant;
 
 use crate::common::impl_get_set::impl_get_set;
 use crate::DiskUsage;
@@ -106,6 +107,50 @@ impl Disk {
         self.inner.available_space()
     }
 
+    /// Returns the percentage of the disk space which is used, computed from
+    /// [`Disk::total_space`] and [`Disk::available_space`].
+    ///
+    /// Returns `0.0` if the total size is `0`, which is the case for most pseudo file systems.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_with_refreshed_list();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {:.1}% used", disk.name(), disk.usage_percent());
+    /// }
+    /// ```
+    pub fn usage_percent(&self) -> f32 {
+        usage_percent(self.total_space(), self.available_space())
+    }
+
+    /// Returns how fast the disk is filling up, in bytes per second, computed from the
+    /// variation of [`Disk::available_space`] between the last two refreshes of the storage
+    /// information.
+    ///
+    /// It is negative when space is being freed. Returns `None` until the storage information
+    /// was refreshed twice.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// std::thread::sleep(std::time::Duration::from_secs(10));
+    /// disks.refresh(false);
+    /// for disk in disks.list() {
+    ///     if let Some(rate) = disk.fill_rate_bytes_per_sec().filter(|rate| *rate > 0.) {
+    ///         println!(
+    ///             "[{:?}] full in {:.0}s",
+    ///             disk.name(),
+    ///             disk.available_space() as f64 / rate,
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn fill_rate_bytes_per_sec(&self) -> Option<f64> {
+        self.inner.fill_rate_bytes_per_sec()
+    }
+
     /// Returns `true` if the disk is removable.
     ///
     /// ```no_run
@@ -276,6 +321,30 @@ impl Disks {
         Self::new_with_refreshed_list_specifics(DiskRefreshKind::everything())
     }
 
+    /// Creates a new [`Disks`][crate::Disks] type with the disk list loaded and the bytes read
+    /// and written during one second.
+    ///
+    /// Unlike [`Disks::new_with_refreshed_list`], in which the `read_bytes` and `written_bytes`
+    /// fields of [`Disk::usage`] are the same as the total ones since there was no previous
+    /// refresh, it refreshes the disks a second time after one second.
+    ///
+    /// ⚠️ This method blocks the current thread for one second.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let disks = Disks::new_all_refreshed();
+    /// for disk in disks.list() {
+    ///     println!("[{:?}] {} B/s read", disk.name(), disk.usage().read_bytes);
+    /// }
+    /// ```
+    pub fn new_all_refreshed() -> Self {
+        let mut disks = Self::new_with_refreshed_list();
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        disks.refresh(true);
+        disks
+    }
+
     /// Creates a new [`Disks`][crate::Disks] type with the disk list loaded
     /// and refreshed according to the given [`DiskRefreshKind`].
     ///
@@ -343,6 +412,37 @@ impl Disks {
         self.inner
             .refresh_specifics(remove_not_listed_disks, refreshes);
     }
+
+    /// Refreshes the listed disks' information like [`Disks::refresh`], but returns the errors
+    /// which happened instead of ignoring them.
+    ///
+    /// A failure only affects the information it was about: if the storage information of one
+    /// disk couldn't be retrieved, the other disks are still refreshed and the errors are returned
+    /// once everything that could be refreshed was.
+    ///
+    /// The failures are reported on Linux and Android (when `/proc/mounts` or `/proc/diskstats`
+    /// can't be read, or when `statvfs` fails on a mount point), on Windows (when
+    /// `FindFirstVolumeW` or `GetDiskFreeSpaceExW` fails), on macOS and iOS (when `getfsstat`
+    /// fails) and on FreeBSD (when `getmntinfo` or `statvfs` fails). On the other platforms, `Ok`
+    /// is always returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::Disks;
+    ///
+    /// let mut disks = Disks::new_with_refreshed_list();
+    /// if let Err(errors) = disks.try_refresh(true) {
+    ///     for error in errors {
+    ///         eprintln!("{error}");
+    ///     }
+    /// }
+    /// ```
+    pub fn try_refresh(
+        &mut self,
+        remove_not_listed_disks: bool,
+    ) -> Result<(), Vec<crate::RefreshError>> {
+        self.inner
+            .try_refresh_specifics(remove_not_listed_disks, DiskRefreshKind::everything())
+    }
 }
 
 impl std::ops::Deref for Disks {
@@ -454,6 +554,43 @@ impl DiskRefreshKind {
     impl_get_set!(DiskRefreshKind, io_usage, with_io_usage, without_io_usage);
 }
 
+fn usage_percent(total: u64, available: u64) -> f32 {
+    if total == 0 {
+        return 0.;
+    }
+    // `f64` to not lose too much precision on large disks.
+    (total.saturating_sub(available) as f64 * 100. / total as f64) as f32
+}
+
+/// Keeps the previous available space of a disk to compute how fast it is filling up.
+#[allow(dead_code)] // Needed for unsupported targets.
+#[derive(Debug, Default)]
+pub(crate) struct FillRate {
+    last: Option<(u64, Instant)>,
+    rate: Option<f64>,
+}
+
+#[allow(dead_code)]
+impl FillRate {
+    pub(crate) fn update(&mut self, available_space: u64) {
+        self.update_at(available_space, Instant::now());
+    }
+
+    fn update_at(&mut self, available_space: u64, now: Instant) {
+        if let Some((last_available_space, last_update)) = self.last {
+            let elapsed = now.duration_since(last_update).as_secs_f64();
+            if elapsed > 0. {
+                self.rate = Some((last_available_space as f64 - available_space as f64) / elapsed);
+            }
+        }
+        self.last = Some((available_space, now));
+    }
+
+    pub(crate) fn get(&self) -> Option<f64> {
+        self.rate
+    }
+}
+
 #[cfg(test)]
 mod tests {
     /// This first doctest ensure that we can create a new `Disks`.
@@ -475,4 +612,32 @@ mod tests {
         let disks = crate::Disks::new();
         is_send(&disks);
     }
+
+    #[test]
+    fn check_usage_percent() {
+        assert_eq!(super::usage_percent(0, 0), 0.);
+        assert_eq!(super::usage_percent(200, 200), 0.);
+        assert_eq!(super::usage_percent(200, 50), 75.);
+        assert_eq!(super::usage_percent(200, 0), 100.);
+        // Just in case the available space is reported as bigger than the total size.
+        assert_eq!(super::usage_percent(200, 300), 0.);
+    }
+
+    #[test]
+    fn check_fill_rate() {
+        use std::time::{Duration, Instant};
+
+        let mut fill_rate = super::FillRate::default();
+        let now = Instant::now();
+        fill_rate.update_at(1_000, now);
+        assert_eq!(fill_rate.get(), None);
+        fill_rate.update_at(600, now + Duration::from_secs(2));
+        assert_eq!(fill_rate.get(), Some(200.));
+        // Space being freed.
+        fill_rate.update_at(1_600, now + Duration::from_secs(4));
+        assert_eq!(fill_rate.get(), Some(-500.));
+        // No time elapsed, the previous rate is kept.
+        fill_rate.update_at(0, now + Duration::from_secs(4));
+        assert_eq!(fill_rate.get(), Some(-500.));
+    }
 }
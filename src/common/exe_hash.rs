@@ -0,0 +1,148 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::SystemTime;
+
+use sha2::{Digest, Sha256};
+
+/// Digest algorithm used by [`Process::exe_hash`][crate::Process::exe_hash].
+///
+/// ```no_run
+/// use sysinfo::{HashAlgorithm, Pid, System};
+///
+/// let s = System::new_all();
+/// if let Some(process) = s.process(Pid::from(1337)) {
+///     println!("{:?}", process.exe_hash(HashAlgorithm::Sha256));
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    /// SHA-256, as defined in FIPS 180-4. The digest is 64 hexadecimal characters long.
+    Sha256,
+}
+
+struct CachedDigest {
+    modified: SystemTime,
+    len: u64,
+    digest: String,
+}
+
+/// The digests of the executables, by path. Only the last version of each file is kept, so its
+/// size is bounded by the number of different executables.
+fn cache() -> &'static Mutex<HashMap<(PathBuf, HashAlgorithm), CachedDigest>> {
+    static CACHE: OnceLock<Mutex<HashMap<(PathBuf, HashAlgorithm), CachedDigest>>> =
+        OnceLock::new();
+    CACHE.get_or_init(Default::default)
+}
+
+/// On Linux, `/proc/[pid]/exe` links to `<path> (deleted)` once the executable was removed (or
+/// replaced by a new file, like during an upgrade).
+fn is_deleted(path: &Path) -> bool {
+    cfg!(any(target_os = "linux", target_os = "android"))
+        && path.as_os_str().as_encoded_bytes().ends_with(b" (deleted)")
+}
+
+pub(crate) fn exe_hash(path: &Path, algorithm: HashAlgorithm) -> Option<String> {
+    if path.as_os_str().is_empty() || is_deleted(path) {
+        return None;
+    }
+    let metadata = std::fs::metadata(path).ok()?;
+    if !metadata.is_file() {
+        return None;
+    }
+    let modified = metadata.modified().ok()?;
+    let key = (path.to_owned(), algorithm);
+    if let Some(cached) = cache().lock().ok()?.get(&key) {
+        if cached.modified == modified && cached.len == metadata.len() {
+            return Some(cached.digest.clone());
+        }
+    }
+    // The lock isn't held while hashing so other executables can be hashed in the meantime.
+    let digest = match hash_file(path, algorithm) {
+        Ok(digest) => digest,
+        Err(_error) => {
+            sysinfo_debug!("failed to hash {path:?}: {_error:?}");
+            return None;
+        }
+    };
+    cache().lock().ok()?.insert(
+        key,
+        CachedDigest {
+            modified,
+            len: metadata.len(),
+            digest: digest.clone(),
+        },
+    );
+    Some(digest)
+}
+
+fn hash_file(path: &Path, algorithm: HashAlgorithm) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut buffer = vec![0; 64 * 1024];
+    match algorithm {
+        HashAlgorithm::Sha256 => {
+            let mut hasher = Sha256::new();
+            loop {
+                match file.read(&mut buffer) {
+                    Ok(0) => break,
+                    Ok(read) => hasher.update(&buffer[..read]),
+                    Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                    Err(error) => return Err(error),
+                }
+            }
+            Ok(to_hex(&hasher.finalize()))
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut s, byte| {
+            let _ = write!(s, "{byte:02x}");
+            s
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_exe_hash() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = dir.path().join("exe");
+        std::fs::write(&path, "abc").expect("failed to write file");
+
+        let expected = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        assert_eq!(
+            exe_hash(&path, HashAlgorithm::Sha256).as_deref(),
+            Some(expected)
+        );
+        let key = (path.clone(), HashAlgorithm::Sha256);
+        assert_eq!(cache().lock().unwrap()[&key].digest, expected);
+
+        // The size changed so the cached digest isn't used.
+        std::fs::write(&path, "abcd").expect("failed to write file");
+        assert_eq!(
+            exe_hash(&path, HashAlgorithm::Sha256).as_deref(),
+            Some("88d4266fd4e6338d13b845fcf289579d209c897823b9217da3e161936f031589")
+        );
+
+        assert_eq!(exe_hash(dir.path(), HashAlgorithm::Sha256), None);
+        assert_eq!(
+            exe_hash(&dir.path().join("missing"), HashAlgorithm::Sha256),
+            None
+        );
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            let deleted = dir.path().join("exe (deleted)");
+            std::fs::write(&deleted, "abc").expect("failed to write file");
+            assert_eq!(exe_hash(&deleted, HashAlgorithm::Sha256), None);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::io;
+
+/// Information of a [`Process`][crate::Process] whose retrieval commonly fails, used with
+/// [`Process::field_status`][crate::Process::field_status].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessField {
+    /// [`Process::exe`][crate::Process::exe].
+    Exe,
+    /// [`Process::cwd`][crate::Process::cwd].
+    Cwd,
+    /// [`Process::environ`][crate::Process::environ].
+    Environ,
+    /// [`Process::disk_usage`][crate::Process::disk_usage].
+    DiskUsage,
+    /// [`Process::cmd`][crate::Process::cmd].
+    Cmd,
+}
+
+impl ProcessField {
+    const COUNT: usize = 5;
+}
+
+/// Outcome of the last retrieval of a [`ProcessField`].
+///
+/// It is returned by [`Process::field_status`][crate::Process::field_status]. The accessors of
+/// the fields keep returning `None` (or an empty value) on failure, this type allows to know why.
+///
+/// ```no_run
+/// use sysinfo::{FieldStatus, ProcessField, System};
+///
+/// let s = System::new_all();
+/// let denied = s
+///     .processes()
+///     .values()
+///     .filter(|p| p.field_status(ProcessField::Exe) == FieldStatus::PermissionDenied)
+///     .count();
+/// if denied != 0 {
+///     println!("{denied} executables can only be retrieved with more privileges");
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FieldStatus {
+    /// The information was retrieved successfully.
+    Ok,
+    /// The information wasn't retrieved yet because it wasn't asked for with the
+    /// [`ProcessRefreshKind`][crate::ProcessRefreshKind] of the refreshes.
+    #[default]
+    NotRetrieved,
+    /// The information is not available on this platform, or the reason why it couldn't be
+    /// retrieved is not reported on this platform.
+    Unsupported,
+    /// The current process isn't allowed to read the information, like the executable of the
+    /// processes of the other users when not running as root.
+    PermissionDenied,
+    /// The information doesn't exist, like the executable of the kernel threads, or the process
+    /// ended while it was being refreshed.
+    NotFound,
+    /// Another error happened.
+    Other,
+}
+
+impl FieldStatus {
+    #[allow(dead_code)]
+    pub(crate) fn from_result<T>(result: &io::Result<T>) -> Self {
+        match result {
+            Ok(_) => Self::Ok,
+            Err(error) => Self::from_error(error),
+        }
+    }
+
+    #[allow(dead_code)]
+    pub(crate) fn from_error(error: &io::Error) -> Self {
+        match error.kind() {
+            io::ErrorKind::PermissionDenied => Self::PermissionDenied,
+            io::ErrorKind::NotFound => Self::NotFound,
+            // It's what the system calls taking a PID return when the process doesn't exist.
+            #[cfg(unix)]
+            _ if error.raw_os_error() == Some(libc::ESRCH) => Self::NotFound,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// The [`FieldStatus`] of each [`ProcessField`].
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct FieldStatuses([FieldStatus; ProcessField::COUNT]);
+
+#[allow(dead_code)]
+impl FieldStatuses {
+    pub(crate) fn get(&self, field: ProcessField) -> FieldStatus {
+        self.0[field as usize]
+    }
+
+    pub(crate) fn set(&mut self, field: ProcessField, status: FieldStatus) {
+        self.0[field as usize] = status;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_field_status_from_result() {
+        assert_eq!(FieldStatus::from_result(&Ok(())), FieldStatus::Ok);
+        assert_eq!(
+            FieldStatus::from_result::<()>(&Err(io::ErrorKind::PermissionDenied.into())),
+            FieldStatus::PermissionDenied
+        );
+        assert_eq!(
+            FieldStatus::from_result::<()>(&Err(io::ErrorKind::NotFound.into())),
+            FieldStatus::NotFound
+        );
+        assert_eq!(
+            FieldStatus::from_result::<()>(&Err(io::ErrorKind::InvalidData.into())),
+            FieldStatus::Other
+        );
+        #[cfg(unix)]
+        assert_eq!(
+            FieldStatus::from_error(&io::Error::from_raw_os_error(libc::ESRCH)),
+            FieldStatus::NotFound
+        );
+
+        let mut statuses = FieldStatuses::default();
+        assert_eq!(statuses.get(ProcessField::Cmd), FieldStatus::NotRetrieved);
+        statuses.set(ProcessField::Cmd, FieldStatus::Ok);
+        assert_eq!(statuses.get(ProcessField::Cmd), FieldStatus::Ok);
+        assert_eq!(statuses.get(ProcessField::Cwd), FieldStatus::NotRetrieved);
+    }
+}
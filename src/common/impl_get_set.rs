@@ -170,4 +170,33 @@ assert_eq!(r.", stringify!($name), "().is_some(), false);
     };
 }
 
+// Generates the `without_*` methods which disable a refresh on an inner `*RefreshKind`, like
+// `RefreshKind::without_cpu_frequency`.
+macro_rules! impl_without_nested {
+    ($ty_name:ident, $name:ident, $typ:ident, $($without:ident => $nested_without:ident),+ $(,)?) => {
+        $(
+            #[doc = concat!("Calls [`", stringify!($typ), "::", stringify!($nested_without), "`] on the \"", stringify!($name), "\" refresh kind if it is set.
+
+The other information of the \"", stringify!($name), "\" refresh kind is still refreshed, and nothing changes if it
+is `None`.
+
+```
+use sysinfo::{", stringify!($ty_name), ", ", stringify!($typ), "};
+
+let r = ", stringify!($ty_name), "::everything().", stringify!($without), "();
+assert_eq!(r.", stringify!($name), "(), Some(", stringify!($typ), "::everything().", stringify!($nested_without), "()));
+
+let r = ", stringify!($ty_name), "::nothing().", stringify!($without), "();
+assert_eq!(r.", stringify!($name), "(), None);
+```")]
+            #[must_use]
+            pub fn $without(mut self) -> Self {
+                self.$name = self.$name.map($typ::$nested_without);
+                self
+            }
+        )+
+    };
+}
+
 pub(crate) use impl_get_set;
+pub(crate) use impl_without_nested;
@@ -0,0 +1,90 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Generated by `scripts/generate_mac_vendors.py` from the IEEE MA-L registry, don't
+// edit it manually.
+
+/// Organizationally unique identifiers (the first three bytes of the MAC addresses
+/// assigned by the IEEE) and the names of their organizations, sorted by prefix.
+#[rustfmt::skip]
+pub(super) static VENDORS: &[([u8; 3], &str)] = &[
+    ([0x00, 0x00, 0x0c], "Cisco"),
+    ([0x00, 0x02, 0xb3], "Intel"),
+    ([0x00, 0x02, 0xc9], "Mellanox"),
+    ([0x00, 0x03, 0x93], "Apple"),
+    ([0x00, 0x03, 0xba], "Sun Microsystems"),
+    ([0x00, 0x03, 0xff], "Microsoft"),
+    ([0x00, 0x04, 0x23], "Intel"),
+    ([0x00, 0x05, 0x02], "Apple"),
+    ([0x00, 0x05, 0x69], "VMware"),
+    ([0x00, 0x05, 0x85], "Juniper Networks"),
+    ([0x00, 0x07, 0xe9], "Intel"),
+    ([0x00, 0x09, 0x0f], "Fortinet"),
+    ([0x00, 0x0a, 0x95], "Apple"),
+    ([0x00, 0x0a, 0xf7], "Broadcom"),
+    ([0x00, 0x0c, 0x29], "VMware"),
+    ([0x00, 0x0c, 0xf1], "Intel"),
+    ([0x00, 0x0d, 0x3a], "Microsoft"),
+    ([0x00, 0x0d, 0x93], "Apple"),
+    ([0x00, 0x0e, 0x0c], "Intel"),
+    ([0x00, 0x0e, 0xc6], "ASIX Electronics"),
+    ([0x00, 0x10, 0x18], "Broadcom"),
+    ([0x00, 0x11, 0x11], "Intel"),
+    ([0x00, 0x12, 0x5a], "Microsoft"),
+    ([0x00, 0x13, 0x20], "Intel"),
+    ([0x00, 0x14, 0x22], "Dell"),
+    ([0x00, 0x14, 0x4f], "Oracle"),
+    ([0x00, 0x15, 0x17], "Intel"),
+    ([0x00, 0x15, 0x5d], "Microsoft"),
+    ([0x00, 0x15, 0xc5], "Dell"),
+    ([0x00, 0x16, 0x3e], "Xensource"),
+    ([0x00, 0x17, 0xf2], "Apple"),
+    ([0x00, 0x18, 0x51], "SWsoft"),
+    ([0x00, 0x1a, 0x11], "Google"),
+    ([0x00, 0x1a, 0x4a], "Qumranet"),
+    ([0x00, 0x1a, 0xa0], "Dell"),
+    ([0x00, 0x1b, 0x17], "Palo Alto Networks"),
+    ([0x00, 0x1b, 0x21], "Intel"),
+    ([0x00, 0x1b, 0x63], "Apple"),
+    ([0x00, 0x1c, 0x14], "VMware"),
+    ([0x00, 0x1c, 0x42], "Parallels"),
+    ([0x00, 0x1c, 0x73], "Arista Networks"),
+    ([0x00, 0x1c, 0xb3], "Apple"),
+    ([0x00, 0x1c, 0xc0], "Intel"),
+    ([0x00, 0x1d, 0x09], "Dell"),
+    ([0x00, 0x1e, 0x67], "Intel"),
+    ([0x00, 0x1e, 0xc2], "Apple"),
+    ([0x00, 0x1f, 0xf3], "Apple"),
+    ([0x00, 0x21, 0x9b], "Dell"),
+    ([0x00, 0x23, 0xdf], "Apple"),
+    ([0x00, 0x25, 0x00], "Apple"),
+    ([0x00, 0x25, 0x90], "Super Micro Computer"),
+    ([0x00, 0x25, 0xb5], "Cisco"),
+    ([0x00, 0x26, 0xb9], "Dell"),
+    ([0x00, 0x26, 0xbb], "Apple"),
+    ([0x00, 0x30, 0x48], "Super Micro Computer"),
+    ([0x00, 0x50, 0x43], "Marvell"),
+    ([0x00, 0x50, 0x56], "VMware"),
+    ([0x00, 0x50, 0xf2], "Microsoft"),
+    ([0x00, 0x90, 0x27], "Intel"),
+    ([0x00, 0xa0, 0xc9], "Intel"),
+    ([0x00, 0xaa, 0x00], "Intel"),
+    ([0x00, 0xe0, 0x4c], "Realtek"),
+    ([0x08, 0x00, 0x20], "Sun Microsystems"),
+    ([0x08, 0x00, 0x27], "PCS Systemtechnik"),
+    ([0x24, 0x8a, 0x07], "Mellanox"),
+    ([0x28, 0xcd, 0xc1], "Raspberry Pi"),
+    ([0x3c, 0x07, 0x54], "Apple"),
+    ([0x3c, 0x5a, 0xb4], "Google"),
+    ([0x3c, 0xd9, 0x2b], "Hewlett Packard"),
+    ([0x3c, 0xfd, 0xfe], "Intel"),
+    ([0xa0, 0x36, 0x9f], "Intel"),
+    ([0xa4, 0x5e, 0x60], "Apple"),
+    ([0xac, 0x1f, 0x6b], "Super Micro Computer"),
+    ([0xb8, 0x27, 0xeb], "Raspberry Pi"),
+    ([0xb8, 0xac, 0x6f], "Dell"),
+    ([0xdc, 0xa6, 0x32], "Raspberry Pi"),
+    ([0xe4, 0x5f, 0x01], "Raspberry Pi"),
+    ([0xf0, 0x18, 0x98], "Apple"),
+    ([0xf4, 0xf5, 0xd8], "Google"),
+    ([0xf8, 0xbc, 0x12], "Dell"),
+];
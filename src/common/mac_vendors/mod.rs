@@ -0,0 +1,31 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+mod list;
+
+use self::list::VENDORS;
+
+pub(crate) fn vendor_from_oui(oui: [u8; 3]) -> Option<&'static str> {
+    VENDORS
+        .binary_search_by_key(&oui, |(prefix, _)| *prefix)
+        .ok()
+        .map(|index| VENDORS[index].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{vendor_from_oui, VENDORS};
+
+    #[test]
+    fn check_vendors_are_sorted() {
+        assert!(VENDORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn check_vendor_from_oui() {
+        assert_eq!(vendor_from_oui([0x00, 0x50, 0x56]), Some("VMware"));
+        assert_eq!(vendor_from_oui([0xf8, 0xbc, 0x12]), Some("Dell"));
+        // The multicast prefixes are never assigned.
+        assert_eq!(vendor_from_oui([0x01, 0x00, 0x5e]), None);
+        assert_eq!(vendor_from_oui([0xff, 0xff, 0xff]), None);
+    }
+}
@@ -1,17 +1,62 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#[cfg(all(
+    feature = "async",
+    any(
+        feature = "system",
+        feature = "disk",
+        feature = "network",
+        feature = "component",
+        feature = "user"
+    )
+))]
+pub(crate) mod async_refresh;
+#[cfg(feature = "battery")]
+pub(crate) mod battery;
+pub(crate) mod byte_size;
 #[cfg(feature = "component")]
 pub(crate) mod component;
+#[cfg(feature = "network")]
+pub(crate) mod connection;
+#[cfg(feature = "system")]
+pub(crate) mod cpu_arch;
 #[cfg(feature = "disk")]
 pub(crate) mod disk;
-#[cfg(any(feature = "system", feature = "disk"))]
+#[cfg(feature = "exe-hash")]
+pub(crate) mod exe_hash;
+#[cfg(feature = "system")]
+pub(crate) mod field_status;
+#[cfg(any(
+    feature = "system",
+    feature = "disk",
+    feature = "network",
+    feature = "user"
+))]
 pub(crate) mod impl_get_set;
+#[cfg(feature = "mac-vendors")]
+pub(crate) mod mac_vendors;
 #[cfg(feature = "network")]
 pub(crate) mod network;
 #[cfg(feature = "system")]
+pub(crate) mod process_tree;
+#[cfg(feature = "system")]
+pub(crate) mod process_watcher;
+#[cfg(feature = "system")]
+pub(crate) mod rapl;
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+pub(crate) mod refresh_error;
+#[cfg(feature = "user")]
+pub(crate) mod session;
+#[cfg(feature = "system")]
+pub(crate) mod shared_system;
+#[cfg(feature = "system")]
 pub(crate) mod system;
+#[cfg(feature = "system")]
+pub(crate) mod system_diff;
 #[cfg(feature = "user")]
 pub(crate) mod user;
+#[cfg(feature = "system")]
+pub(crate) mod virtualization;
 
 /// Type containing read and written bytes.
 ///
@@ -81,6 +126,14 @@ macro_rules! xid {
             }
         }
 
+        // The numeric ids are `u32` on all the platforms, which also provides `TryFrom<u32>`.
+        #[cfg(any(feature = "system", feature = "user"))]
+        impl From<u32> for $name {
+            fn from(t: u32) -> Self {
+                Self(t)
+            }
+        }
+
         #[cfg(any(feature = "system", feature = "user"))]
         impl $trait for $name {
             type Err = <$type as $trait>::Err;
@@ -146,3 +199,120 @@ cfg_if! {
         gid!(u32);
     }
 }
+
+#[cfg(any(feature = "system", feature = "user"))]
+impl Uid {
+    /// Returns the numeric value of the user id.
+    ///
+    /// On Windows, it is the relative identifier (RID) of the SID, which is its last
+    /// sub-authority. ⚠️ Unlike the unix user ids, it is **not** unique: the accounts of different
+    /// domains can have the same RID (like the local and the domain `Administrator` accounts,
+    /// which are both `500`), and well-known SIDs like `S-1-5-18` (`SYSTEM`) only have one
+    /// sub-authority. It is `None` if the SID has no sub-authority.
+    ///
+    /// ```
+    /// use sysinfo::Uid;
+    ///
+    /// # #[cfg(not(windows))]
+    /// assert_eq!("1000".parse::<Uid>().ok().and_then(|uid| uid.as_u32()), Some(1000));
+    /// ```
+    pub fn as_u32(&self) -> Option<u32> {
+        cfg_if! {
+            if #[cfg(windows)] {
+                self.0.rid()
+            } else {
+                Some(self.0)
+            }
+        }
+    }
+}
+
+/// Returns the energy consumed (in microjoules) between two readings of a RAPL energy counter.
+///
+/// If the counter decreased, it means it went past `max_energy_range` and started again from `0`.
+#[cfg(any(feature = "system", feature = "component"))]
+#[cfg_attr(not(feature = "system"), allow(dead_code))]
+pub(crate) fn rapl_energy_consumed(last_energy: u64, energy: u64, max_energy_range: u64) -> u64 {
+    if energy >= last_energy {
+        energy - last_energy
+    } else {
+        max_energy_range.saturating_sub(last_energy) + energy
+    }
+}
+
+macro_rules! pid_decl {
+    ($typ:ty) => {
+        #[cfg(any(feature = "system", feature = "network"))]
+        #[doc = include_str!("../../md_doc/pid.md")]
+        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+        #[repr(transparent)]
+        pub struct Pid(pub(crate) $typ);
+
+        #[cfg(any(feature = "system", feature = "network"))]
+        impl From<usize> for Pid {
+            fn from(v: usize) -> Self {
+                Self(v as _)
+            }
+        }
+        #[cfg(any(feature = "system", feature = "network"))]
+        impl From<Pid> for usize {
+            fn from(v: Pid) -> Self {
+                v.0 as _
+            }
+        }
+        #[cfg(any(feature = "system", feature = "network"))]
+        impl std::str::FromStr for Pid {
+            type Err = <$typ as std::str::FromStr>::Err;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Self(<$typ as std::str::FromStr>::from_str(s)?))
+            }
+        }
+        #[cfg(any(feature = "system", feature = "network"))]
+        impl std::fmt::Display for Pid {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+        #[cfg(any(feature = "system", feature = "network"))]
+        impl Pid {
+            /// Allows to convert [`Pid`][crate::Pid] into [`u32`].
+            ///
+            /// ```
+            /// use sysinfo::Pid;
+            ///
+            /// let pid = Pid::from_u32(0);
+            /// let value: u32 = pid.as_u32();
+            /// ```
+            pub fn as_u32(self) -> u32 {
+                self.0 as _
+            }
+            /// Allows to convert a [`u32`] into [`Pid`][crate::Pid].
+            ///
+            /// ```
+            /// use sysinfo::Pid;
+            ///
+            /// let pid = Pid::from_u32(0);
+            /// ```
+            pub fn from_u32(v: u32) -> Self {
+                Self(v as _)
+            }
+        }
+    };
+}
+
+cfg_if! {
+    if #[cfg(all(
+        not(feature = "unknown-ci"),
+        any(
+            target_os = "freebsd",
+            target_os = "linux",
+            target_os = "android",
+            target_os = "macos",
+            target_os = "ios",
+        )
+    ))] {
+        pid_decl!(libc::pid_t);
+    } else {
+        pid_decl!(usize);
+    }
+}
@@ -5,7 +5,9 @@ use std::fmt;
 use std::net::{AddrParseError, IpAddr};
 use std::num::ParseIntError;
 use std::str::FromStr;
+use std::time::Duration;
 
+use crate::common::impl_get_set::impl_get_set;
 use crate::{NetworkDataInner, NetworksInner};
 
 /// Interacting with network interfaces.
@@ -22,6 +24,44 @@ pub struct Networks {
     pub(crate) inner: NetworksInner,
 }
 
+// Generates the `Networks` methods summing a `NetworkData` counter over the interfaces, both
+// its delta since the last refresh and its cumulative value.
+macro_rules! network_totals {
+    ($delta:ident, $total:ident, $what:literal) => {
+        #[doc = concat!("Returns the number of ", $what, " since the last refresh, summed over the
+interfaces selected by `filter`.
+
+It is the sum of the [`NetworkData::", stringify!($delta), "`] values.
+
+```no_run
+use sysinfo::{NetworkTotalsFilter, Networks};
+
+let mut networks = Networks::new_with_refreshed_list();
+std::thread::sleep(std::time::Duration::from_millis(10));
+networks.refresh(true);
+println!(\"{}\", networks.", stringify!($delta), "(NetworkTotalsFilter::new()));
+```")]
+        pub fn $delta(&self, filter: NetworkTotalsFilter) -> u64 {
+            self.sum_over(filter, NetworkData::$delta)
+        }
+
+        #[doc = concat!("Returns the total number of ", $what, ", summed over the interfaces
+selected by `filter`.
+
+It is the sum of the cumulative [`NetworkData::", stringify!($total), "`] counters.
+
+```no_run
+use sysinfo::{NetworkTotalsFilter, Networks};
+
+let networks = Networks::new_with_refreshed_list();
+println!(\"{}\", networks.", stringify!($total), "(NetworkTotalsFilter::new()));
+```")]
+        pub fn $total(&self, filter: NetworkTotalsFilter) -> u64 {
+            self.sum_over(filter, NetworkData::$total)
+        }
+    };
+}
+
 impl<'a> IntoIterator for &'a Networks {
     type Item = (&'a String, &'a NetworkData);
     type IntoIter = std::collections::hash_map::Iter<'a, String, NetworkData>;
@@ -69,8 +109,47 @@ impl Networks {
     /// }
     /// ```
     pub fn new_with_refreshed_list() -> Self {
+        Self::new_with_refreshed_list_specifics(NetworksRefreshKind::everything())
+    }
+
+    /// Creates a new [`Networks`][crate::Networks] type with the network interfaces list loaded
+    /// and the data exchanged during one second.
+    ///
+    /// Unlike [`Networks::new_with_refreshed_list`], in which the values returned by
+    /// [`NetworkData::received`] and [`NetworkData::transmitted`] are always `0` since there was
+    /// no previous refresh, it refreshes the interfaces a second time after one second.
+    ///
+    /// ⚠️ This method blocks the current thread for one second.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_all_refreshed();
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {} B/s received", network.received());
+    /// }
+    /// ```
+    pub fn new_all_refreshed() -> Self {
+        let mut networks = Self::new_with_refreshed_list();
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        networks.refresh(true);
+        networks
+    }
+
+    /// Creates a new [`Networks`][crate::Networks] type with the network interfaces
+    /// list loaded and refreshed according to the given [`NetworksRefreshKind`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Networks, NetworksRefreshKind};
+    ///
+    /// let networks = Networks::new_with_refreshed_list_specifics(NetworksRefreshKind::nothing());
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {} B", network.total_received());
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list_specifics(refresh_kind: NetworksRefreshKind) -> Self {
         let mut networks = Self::new();
-        networks.refresh(false);
+        networks.refresh_specifics(false, refresh_kind);
         networks
     }
 
@@ -98,8 +177,424 @@ impl Networks {
     /// networks.refresh(true);
     /// ```
     pub fn refresh(&mut self, remove_not_listed_interfaces: bool) {
-        self.inner.refresh(remove_not_listed_interfaces)
+        self.refresh_specifics(
+            remove_not_listed_interfaces,
+            NetworksRefreshKind::everything(),
+        )
+    }
+
+    /// Refreshes the network interfaces according to the given [`NetworksRefreshKind`].
+    ///
+    /// The counters are always refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Networks, NetworksRefreshKind};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Wait some time...? Then refresh the counters of each network.
+    /// networks.refresh_specifics(true, NetworksRefreshKind::nothing());
+    /// ```
+    pub fn refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) {
+        self.inner
+            .refresh_specifics(remove_not_listed_interfaces, refresh_kind)
+    }
+
+    /// Refreshes the network interfaces like [`Networks::refresh`], but returns the errors which
+    /// happened instead of ignoring them.
+    ///
+    /// A failure only affects the interface it was about: the other interfaces are still
+    /// refreshed and the errors are returned once everything that could be refreshed was.
+    ///
+    /// The failures are reported on Linux and Android (when `/sys/class/net/` or the `statistics`
+    /// of an interface can't be read), on Windows (when `GetIfTable2` fails), on macOS and iOS
+    /// (when the `sysctl` listing the interfaces or returning their data fails) and on FreeBSD
+    /// (when the `sysctl` counting the interfaces fails). On the other platforms, `Ok` is always
+    /// returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// if let Err(errors) = networks.try_refresh(true) {
+    ///     for error in errors {
+    ///         eprintln!("{error}");
+    ///     }
+    /// }
+    /// ```
+    pub fn try_refresh(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+    ) -> Result<(), Vec<crate::RefreshError>> {
+        self.inner.try_refresh_specifics(
+            remove_not_listed_interfaces,
+            NetworksRefreshKind::everything(),
+        )
+    }
+
+    /// Refreshes the network interfaces for which `filter` returns `true`.
+    ///
+    /// The interfaces for which `filter` returns `false` are not refreshed and are removed from
+    /// the list (or never added to it), just like the interfaces which don't exist anymore.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// networks.refresh_list_filtered(|name| !name.starts_with("veth"));
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {} B", network.total_received());
+    /// }
+    /// ```
+    pub fn refresh_list_filtered<F: FnMut(&str) -> bool>(&mut self, mut filter: F) {
+        self.inner.refresh_list_filtered(&mut filter)
+    }
+
+    /// Refreshes the counters of the `name` network interface only. It is added to the list if it
+    /// wasn't in it yet.
+    ///
+    /// Returns `false` if this interface doesn't exist. In this case, it is not removed from the
+    /// list, use [`Networks::refresh`] for that.
+    ///
+    /// ⚠️ The IP networks, MAC address and Wi-Fi information of the interface are not refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// if networks.refresh_interface("eth0") {
+    ///     // Wait some time...? Then refresh the data of this interface.
+    ///     networks.refresh_interface("eth0");
+    ///     println!("received: {} B", networks.list()["eth0"].received());
+    /// }
+    /// ```
+    pub fn refresh_interface(&mut self, name: &str) -> bool {
+        self.inner.refresh_interface(name)
+    }
+
+    /// Refreshes the counters of the `names` network interfaces only, the other interfaces are
+    /// left untouched. The ones which weren't in the list yet are added to it.
+    ///
+    /// Returns the number of these interfaces which exist. The ones which don't are not removed
+    /// from the list, use [`Networks::refresh`] for that.
+    ///
+    /// ⚠️ Just like with [`Networks::refresh_interface`], the IP networks, MAC address and Wi-Fi
+    /// information of these interfaces are not refreshed.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new();
+    /// networks.refresh_interfaces(&["eth0", "wlan0"]);
+    /// loop {
+    ///     std::thread::sleep(std::time::Duration::from_secs(1));
+    ///     networks.refresh_interfaces(&["eth0", "wlan0"]);
+    ///     for (interface_name, network) in &networks {
+    ///         println!("{interface_name}: {} B/s", network.received());
+    ///     }
+    /// }
+    /// ```
+    pub fn refresh_interfaces(&mut self, names: &[&str]) -> usize {
+        self.inner.refresh_interfaces(names)
+    }
+
+    /// Returns an iterator over the network interfaces which are neither loopback nor
+    /// point-to-point (which is what tunnels and VPNs usually are), and whose
+    /// [`kind`][NetworkData::kind] isn't [`Loopback`][InterfaceKind::Loopback],
+    /// [`Bridge`][InterfaceKind::Bridge], [`Tunnel`][InterfaceKind::Tunnel] or
+    /// [`Virtual`][InterfaceKind::Virtual].
+    ///
+    /// It is useful if you want to sum the "real" traffic of the machine.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// let total: u64 = networks
+    ///     .physical_interfaces()
+    ///     .map(|(_, network)| network.total_received())
+    ///     .sum();
+    /// println!("total received: {total} B");
+    /// ```
+    pub fn physical_interfaces(&self) -> impl Iterator<Item = (&String, &NetworkData)> {
+        self.list().iter().filter(|(_, network)| {
+            !network.is_loopback()
+                && !network.is_point_to_point()
+                && !matches!(
+                    network.kind(),
+                    InterfaceKind::Loopback
+                        | InterfaceKind::Bridge
+                        | InterfaceKind::Tunnel
+                        | InterfaceKind::Virtual
+                )
+        })
+    }
+
+    network_totals!(received, total_received, "received bytes");
+    network_totals!(transmitted, total_transmitted, "transmitted bytes");
+    network_totals!(packets_received, total_packets_received, "incoming packets");
+    network_totals!(
+        packets_transmitted,
+        total_packets_transmitted,
+        "outgoing packets"
+    );
+    network_totals!(
+        errors_on_received,
+        total_errors_on_received,
+        "receive errors"
+    );
+    network_totals!(
+        errors_on_transmitted,
+        total_errors_on_transmitted,
+        "transmission errors"
+    );
+
+    fn sum_over<F: Fn(&NetworkData) -> u64>(&self, filter: NetworkTotalsFilter, value: F) -> u64 {
+        self.list()
+            .values()
+            .filter(|network| filter.matches(network))
+            .map(value)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// Returns the network interface with the given OS interface index, along with its name.
+    ///
+    /// Unlike the interface name, the index doesn't change when an interface is renamed. Take
+    /// a look at [`NetworkData::index`] for more information.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// if let Some((interface_name, network)) = networks.get_by_index(1) {
+    ///     println!("[{interface_name}] {:?}", network.friendly_name());
+    /// }
+    /// ```
+    pub fn get_by_index(&self, index: u32) -> Option<(&str, &NetworkData)> {
+        if index == 0 {
+            return None;
+        }
+        self.list()
+            .iter()
+            .find(|(_, network)| network.index() == index)
+            .map(|(name, network)| (name.as_str(), network))
+    }
+
+    /// Returns the default routes of the system.
+    ///
+    /// There can be more than one default route, for example one for IPv4 and one for IPv6,
+    /// or one per interface on multi-homed machines.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ⚠️ This information is not available on FreeBSD and iOS, so it always returns an empty
+    /// list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new();
+    /// for route in networks.default_routes() {
+    ///     println!("default route via {} on {}", route.gateway, route.interface);
+    /// }
+    /// ```
+    pub fn default_routes(&self) -> Vec<RouteInfo> {
+        self.inner.default_routes()
+    }
+
+    /// Returns the IP addresses of the configured DNS servers.
+    ///
+    /// On Unix systems, they are read from `/etc/resolv.conf`. On Linux, if it only lists the
+    /// local stub resolver of `systemd-resolved` (`127.0.0.53`), the upstream servers listed in
+    /// `/run/systemd/resolve/resolv.conf` are returned instead.
+    ///
+    /// ⚠️ This information is computed every time this method is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new();
+    /// println!("DNS servers: {:?}", networks.dns_servers());
+    /// ```
+    pub fn dns_servers(&self) -> Vec<IpAddr> {
+        self.inner.dns_servers()
+    }
+}
+
+/// Used to select the network interfaces summed by the totals methods of [`Networks`], like
+/// [`Networks::total_received`].
+///
+/// By default, loopback interfaces are excluded since their traffic never leaves the machine,
+/// while the interfaces which are down are included since their counters still hold what went
+/// through them before.
+///
+/// ```no_run
+/// use sysinfo::{NetworkTotalsFilter, Networks};
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// let filter = NetworkTotalsFilter::new().with_down_interfaces(false);
+/// println!("received: {} B", networks.total_received(filter));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NetworkTotalsFilter {
+    loopback: bool,
+    down_interfaces: bool,
+}
+
+impl Default for NetworkTotalsFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NetworkTotalsFilter {
+    /// Creates a new `NetworkTotalsFilter` which excludes the loopback interfaces and includes
+    /// the interfaces which are down.
+    ///
+    /// ```
+    /// use sysinfo::NetworkTotalsFilter;
+    ///
+    /// let filter = NetworkTotalsFilter::new();
+    ///
+    /// assert_eq!(filter.loopback(), false);
+    /// assert_eq!(filter.down_interfaces(), true);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            loopback: false,
+            down_interfaces: true,
+        }
+    }
+
+    /// Returns `true` if the loopback interfaces are included.
+    ///
+    /// ```
+    /// use sysinfo::NetworkTotalsFilter;
+    ///
+    /// let filter = NetworkTotalsFilter::new().with_loopback(true);
+    /// assert_eq!(filter.loopback(), true);
+    /// ```
+    pub fn loopback(&self) -> bool {
+        self.loopback
+    }
+
+    /// Sets whether the loopback interfaces (see [`NetworkData::is_loopback`]) are included.
+    ///
+    /// ```
+    /// use sysinfo::NetworkTotalsFilter;
+    ///
+    /// let filter = NetworkTotalsFilter::new().with_loopback(true);
+    /// assert_eq!(filter.loopback(), true);
+    /// ```
+    #[must_use]
+    pub fn with_loopback(mut self, include: bool) -> Self {
+        self.loopback = include;
+        self
+    }
+
+    /// Returns `true` if the interfaces which are down are included.
+    ///
+    /// ```
+    /// use sysinfo::NetworkTotalsFilter;
+    ///
+    /// let filter = NetworkTotalsFilter::new().with_down_interfaces(false);
+    /// assert_eq!(filter.down_interfaces(), false);
+    /// ```
+    pub fn down_interfaces(&self) -> bool {
+        self.down_interfaces
+    }
+
+    /// Sets whether the interfaces which are down (see [`NetworkData::is_up`]) are included.
+    ///
+    /// ```
+    /// use sysinfo::NetworkTotalsFilter;
+    ///
+    /// let filter = NetworkTotalsFilter::new().with_down_interfaces(false);
+    /// assert_eq!(filter.down_interfaces(), false);
+    /// ```
+    #[must_use]
+    pub fn with_down_interfaces(mut self, include: bool) -> Self {
+        self.down_interfaces = include;
+        self
+    }
+
+    fn matches(&self, network: &NetworkData) -> bool {
+        (self.loopback || !network.is_loopback()) && (self.down_interfaces || network.is_up())
+    }
+}
+
+/// Used to determine what you want to refresh specifically on the [`Networks`] type, on top of
+/// the counters which are always refreshed.
+///
+/// * `addresses` is about refreshing the [`NetworkData::ip_networks`] and
+///   [`NetworkData::mac_address`] information. It requires going through the addresses of all
+///   the interfaces of the system.
+/// * `wifi_info` is about refreshing the [`NetworkData::wifi_info`] information. It has no effect
+///   on the systems where it is retrieved when requesting it.
+///
+/// To only go through some of the interfaces, take a look at [`Networks::refresh_list_filtered`]
+/// and [`Networks::refresh_interfaces`].
+///
+/// ```no_run
+/// use sysinfo::{Networks, NetworksRefreshKind};
+///
+/// let mut networks = Networks::new_with_refreshed_list();
+/// // The addresses rarely change, no need to refresh them every time.
+/// networks.refresh_specifics(true, NetworksRefreshKind::nothing());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NetworksRefreshKind {
+    addresses: bool,
+    wifi_info: bool,
+}
+
+impl NetworksRefreshKind {
+    /// Creates a new `NetworksRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::NetworksRefreshKind;
+    ///
+    /// let r = NetworksRefreshKind::nothing();
+    ///
+    /// assert_eq!(r.addresses(), false);
+    /// assert_eq!(r.wifi_info(), false);
+    /// ```
+    pub fn nothing() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `NetworksRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::NetworksRefreshKind;
+    ///
+    /// let r = NetworksRefreshKind::everything();
+    ///
+    /// assert_eq!(r.addresses(), true);
+    /// assert_eq!(r.wifi_info(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            addresses: true,
+            wifi_info: true,
+        }
     }
+
+    impl_get_set!(
+        NetworksRefreshKind,
+        addresses,
+        with_addresses,
+        without_addresses
+    );
+    impl_get_set!(
+        NetworksRefreshKind,
+        wifi_info,
+        with_wifi_info,
+        without_wifi_info
+    );
 }
 
 impl std::ops::Deref for Networks {
@@ -365,64 +860,737 @@ impl NetworkData {
         self.inner.total_errors_on_transmitted()
     }
 
-    /// Returns the MAC address associated to current interface.
+    /// Returns the number of incoming packets dropped since the last refresh.
+    ///
+    /// If you want the total number of incoming packets dropped, take a look at the
+    /// [`total_dropped_packets_received`](NetworkData::total_dropped_packets_received) method.
     ///
     /// ```no_run
     /// use sysinfo::Networks;
+    /// use std::{thread, time};
     ///
     /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
     /// for (interface_name, network) in &networks {
-    ///     println!("MAC address: {}", network.mac_address());
+    ///     println!("dropped in: {}", network.dropped_packets_received());
     /// }
     /// ```
-    pub fn mac_address(&self) -> MacAddr {
-        self.inner.mac_address()
+    pub fn dropped_packets_received(&self) -> u64 {
+        self.inner.dropped_packets_received()
     }
 
-    /// Returns the Ip Networks associated to current interface.
+    /// Returns the total number of incoming packets dropped.
+    ///
+    /// If you want the number of incoming packets dropped since the last refresh, take a look at
+    /// the [`dropped_packets_received`](NetworkData::dropped_packets_received) method.
     ///
     /// ```no_run
     /// use sysinfo::Networks;
     ///
-    /// let mut networks = Networks::new_with_refreshed_list();
+    /// let networks = Networks::new_with_refreshed_list();
     /// for (interface_name, network) in &networks {
-    ///     println!("Ip Networks: {:?}", network.ip_networks());
+    ///     println!("dropped in: {}", network.total_dropped_packets_received());
     /// }
     /// ```
-    pub fn ip_networks(&self) -> &[IpNetwork] {
-        self.inner.ip_networks()
+    pub fn total_dropped_packets_received(&self) -> u64 {
+        self.inner.total_dropped_packets_received()
     }
 
-    /// Returns the Maximum Transfer Unit (MTU) of the interface.
+    /// Returns the number of outgoing packets dropped since the last refresh.
+    ///
+    /// If you want the total number of outgoing packets dropped, take a look at the
+    /// [`total_dropped_packets_transmitted`](NetworkData::total_dropped_packets_transmitted) method.
     ///
     /// ```no_run
     /// use sysinfo::Networks;
+    /// use std::{thread, time};
     ///
     /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
     /// for (interface_name, network) in &networks {
-    ///     println!("mtu: {}", network.mtu());
+    ///     println!("dropped out: {}", network.dropped_packets_transmitted());
     /// }
     /// ```
-    pub fn mtu(&self) -> u64 {
-        self.inner.mtu()
+    pub fn dropped_packets_transmitted(&self) -> u64 {
+        self.inner.dropped_packets_transmitted()
     }
-}
-
-/// MAC address for network interface.
-///
-/// It is returned by [`NetworkData::mac_address`][crate::NetworkData::mac_address].
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
-#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
-pub struct MacAddr(pub [u8; 6]);
 
-impl MacAddr {
-    /// A `MacAddr` with all bytes set to `0`.
-    pub const UNSPECIFIED: Self = MacAddr([0; 6]);
+    /// Returns the total number of outgoing packets dropped.
+    ///
+    /// If you want the number of outgoing packets dropped since the last refresh, take a look at
+    /// the [`dropped_packets_transmitted`](NetworkData::dropped_packets_transmitted) method.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("dropped out: {}", network.total_dropped_packets_transmitted());
+    /// }
+    /// ```
+    pub fn total_dropped_packets_transmitted(&self) -> u64 {
+        self.inner.total_dropped_packets_transmitted()
+    }
+
+    /// Returns the number of collisions since the last refresh.
+    ///
+    /// If you want the total number of collisions, take a look at the
+    /// [`total_collisions`](NetworkData::total_collisions) method.
+    ///
+    /// ⚠️ This information is not available on Windows, so it always returns `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("collisions: {}", network.collisions());
+    /// }
+    /// ```
+    pub fn collisions(&self) -> u64 {
+        self.inner.collisions()
+    }
+
+    /// Returns the total number of collisions.
+    ///
+    /// If you want the number of collisions since the last refresh, take a look at
+    /// the [`collisions`](NetworkData::collisions) method.
+    ///
+    /// ⚠️ This information is not available on Windows, so it always returns `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("collisions: {}", network.total_collisions());
+    /// }
+    /// ```
+    pub fn total_collisions(&self) -> u64 {
+        self.inner.total_collisions()
+    }
+
+    /// Returns the number of multicast packets received since the last refresh.
+    ///
+    /// If you want the total number of multicast packets received, take a look at the
+    /// [`total_multicast_received`](NetworkData::total_multicast_received) method.
+    ///
+    /// ⚠️ This information is not available on Windows, so it always returns `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(10));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("multicast in: {}", network.multicast_received());
+    /// }
+    /// ```
+    pub fn multicast_received(&self) -> u64 {
+        self.inner.multicast_received()
+    }
+
+    /// Returns the total number of multicast packets received.
+    ///
+    /// If you want the number of multicast packets received since the last refresh, take a look at
+    /// the [`multicast_received`](NetworkData::multicast_received) method.
+    ///
+    /// ⚠️ This information is not available on Windows, so it always returns `0`.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("multicast in: {}", network.total_multicast_received());
+    /// }
+    /// ```
+    pub fn total_multicast_received(&self) -> u64 {
+        self.inner.total_multicast_received()
+    }
+
+    /// Returns the number of received bytes per second, computed over the time elapsed between the
+    /// last two refreshes.
+    ///
+    /// Returns `None` if the interface has only been refreshed once.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?} received bytes/s", network.received_bytes_per_sec());
+    /// }
+    /// ```
+    pub fn received_bytes_per_sec(&self) -> Option<f64> {
+        per_sec(self.received(), self.inner.refresh_interval()?)
+    }
+
+    /// Returns the number of transmitted bytes per second, computed over the time elapsed between the
+    /// last two refreshes.
+    ///
+    /// Returns `None` if the interface has only been refreshed once.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?} transmitted bytes/s", network.transmitted_bytes_per_sec());
+    /// }
+    /// ```
+    pub fn transmitted_bytes_per_sec(&self) -> Option<f64> {
+        per_sec(self.transmitted(), self.inner.refresh_interval()?)
+    }
+
+    /// Returns the number of received packets per second, computed over the time elapsed between the
+    /// last two refreshes.
+    ///
+    /// Returns `None` if the interface has only been refreshed once.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?} received packets/s", network.packets_received_per_sec());
+    /// }
+    /// ```
+    pub fn packets_received_per_sec(&self) -> Option<f64> {
+        per_sec(self.packets_received(), self.inner.refresh_interval()?)
+    }
+
+    /// Returns the number of transmitted packets per second, computed over the time elapsed between the
+    /// last two refreshes.
+    ///
+    /// Returns `None` if the interface has only been refreshed once.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    /// use std::{thread, time};
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// // Waiting a bit to get data from network...
+    /// thread::sleep(time::Duration::from_millis(500));
+    /// // Refreshing again to generate diff.
+    /// networks.refresh(true);
+    ///
+    /// for (interface_name, network) in &networks {
+    ///     println!("{interface_name}: {:?} transmitted packets/s", network.packets_transmitted_per_sec());
+    /// }
+    /// ```
+    pub fn packets_transmitted_per_sec(&self) -> Option<f64> {
+        per_sec(self.packets_transmitted(), self.inner.refresh_interval()?)
+    }
+
+    /// Returns the MAC address associated to current interface.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("MAC address: {}", network.mac_address());
+    /// }
+    /// ```
+    pub fn mac_address(&self) -> MacAddr {
+        self.inner.mac_address()
+    }
+
+    /// Returns the Ip Networks associated to current interface.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("Ip Networks: {:?}", network.ip_networks());
+    /// }
+    /// ```
+    pub fn ip_networks(&self) -> &[IpNetwork] {
+        self.inner.ip_networks()
+    }
+
+    /// Returns the globally routable IP addresses of this interface, in other words the
+    /// [`ip_networks`](NetworkData::ip_networks) with an [`IpScope::Global`] scope.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     for ip in network.global_addresses() {
+    ///         println!("[{interface_name}] {ip}");
+    ///     }
+    /// }
+    /// ```
+    pub fn global_addresses(&self) -> impl Iterator<Item = &IpNetwork> + '_ {
+        self.ip_networks()
+            .iter()
+            .filter(|ip| ip.scope() == IpScope::Global)
+    }
+
+    /// Returns the Maximum Transfer Unit (MTU) of the interface.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let mut networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("mtu: {}", network.mtu());
+    /// }
+    /// ```
+    pub fn mtu(&self) -> u64 {
+        self.inner.mtu()
+    }
+
+    /// Returns `true` if this is a loopback interface.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] is loopback: {}", network.is_loopback());
+    /// }
+    /// ```
+    pub fn is_loopback(&self) -> bool {
+        self.inner.is_loopback()
+    }
+
+    /// Returns `true` if this interface is up.
+    ///
+    /// On Unix systems, it means that the interface was administratively enabled (`IFF_UP`). On
+    /// Windows, it means that its operational status is "up".
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] is up: {}", network.is_up());
+    /// }
+    /// ```
+    pub fn is_up(&self) -> bool {
+        self.inner.is_up()
+    }
+
+    /// Returns `true` if this interface is a point-to-point link (like most tunnels and VPNs).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] is point-to-point: {}", network.is_point_to_point());
+    /// }
+    /// ```
+    pub fn is_point_to_point(&self) -> bool {
+        self.inner.is_point_to_point()
+    }
+
+    /// Returns `true` if this interface supports multicast.
+    ///
+    /// ⚠️ On Windows, this information is deduced from the interface access type, so it is
+    /// `true` for broadcast and point-to-multipoint interfaces.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] supports multicast: {}", network.supports_multicast());
+    /// }
+    /// ```
+    pub fn supports_multicast(&self) -> bool {
+        self.inner.supports_multicast()
+    }
+
+    /// Returns `true` if this interface has a valid broadcast address.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] supports broadcast: {}", network.supports_broadcast());
+    /// }
+    /// ```
+    pub fn supports_broadcast(&self) -> bool {
+        self.inner.supports_broadcast()
+    }
+
+    /// Returns the kind of the interface.
+    ///
+    /// This information is retrieved when the interface is discovered and is not updated
+    /// afterwards.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] kind: {}", network.kind());
+    /// }
+    /// ```
+    pub fn kind(&self) -> InterfaceKind {
+        self.inner.kind()
+    }
+
+    /// Returns information about the Wi-Fi connection of this interface.
+    ///
+    /// It returns `None` if this interface isn't a Wi-Fi interface, or if no information could
+    /// be retrieved. If only part of the information could be retrieved (because of missing
+    /// permissions for example), the missing fields are set to `None`.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux, macOS and Windows. On
+    /// macOS 14 and later, the `ssid` and `bssid` fields are only set if the application is
+    /// allowed to use the location services.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(wifi) = network.wifi_info() {
+    ///         println!("[{interface_name}] SSID: {:?}, signal: {:?} dBm", wifi.ssid, wifi.signal);
+    ///     }
+    /// }
+    /// ```
+    pub fn wifi_info(&self) -> Option<WifiInfo> {
+        self.inner.wifi_info()
+    }
+
+    /// Returns the OS index of this interface, or `0` if it couldn't be retrieved.
+    ///
+    /// It is the value returned by `if_nametoindex` on unix systems and the `InterfaceIndex`
+    /// on Windows. It can be used with [`Networks::get_by_index`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     println!("[{interface_name}] index: {}", network.index());
+    /// }
+    /// ```
+    pub fn index(&self) -> u32 {
+        self.inner.index()
+    }
+
+    /// Returns a human-friendly name for this interface.
+    ///
+    /// It is the interface alias (`/sys/class/net/<if>/ifalias`) on Linux, the adapter
+    /// description on Windows and the localized display name (like "Wi-Fi" for `en0`) on macOS.
+    ///
+    /// ⚠️ This method always returns `None` on FreeBSD and iOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(friendly_name) = network.friendly_name() {
+    ///         println!("[{interface_name}] {friendly_name}");
+    ///     }
+    /// }
+    /// ```
+    pub fn friendly_name(&self) -> Option<&str> {
+        self.inner.friendly_name()
+    }
+
+    /// Returns the name of the bond or bridge this interface is enslaved to.
+    ///
+    /// The information is updated on every refresh.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux, macOS and Windows.
+    /// On macOS, only bonds are handled. On Windows, it's the interface aggregating this one
+    /// (like a NIC team).
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(master) = network.master() {
+    ///         println!("[{interface_name}] enslaved to {master}");
+    ///     }
+    /// }
+    /// ```
+    pub fn master(&self) -> Option<&str> {
+        self.inner.master()
+    }
+
+    /// Returns the name of the interface this interface is stacked on (the parent of a VLAN
+    /// interface for example).
+    ///
+    /// It returns `None` if this interface isn't stacked on exactly one other interface.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux, macOS and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(lower_device) = network.lower_device() {
+    ///         println!("[{interface_name}] stacked on {lower_device}");
+    ///     }
+    /// }
+    /// ```
+    pub fn lower_device(&self) -> Option<&str> {
+        self.inner.lower_device()
+    }
+
+    /// Returns the VLAN ID of this interface if it is a VLAN interface.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux and macOS. On Linux,
+    /// the information comes from `/proc/net/vlan/config`, which is only readable by root.
+    /// On Windows, the VLAN ID is a setting of the network adapter driver so it isn't available.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     if let Some(vlan_id) = network.vlan_id() {
+    ///         println!("[{interface_name}] VLAN {vlan_id}");
+    ///     }
+    /// }
+    /// ```
+    pub fn vlan_id(&self) -> Option<u16> {
+        self.inner.vlan_id()
+    }
+}
+
+fn per_sec(delta: u64, interval: Duration) -> Option<f64> {
+    if interval.is_zero() {
+        return None;
+    }
+    Some(delta as f64 / interval.as_secs_f64())
+}
+
+/// Default route of the system.
+///
+/// It is returned by [`Networks::default_routes`][crate::Networks::default_routes].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct RouteInfo {
+    /// IP address of the gateway.
+    pub gateway: IpAddr,
+    /// Name of the network interface used to reach the gateway.
+    pub interface: String,
+    /// Metric of the route: the lower, the more preferred the route is.
+    ///
+    /// ⚠️ This information is not available on macOS, so it is always `0`.
+    pub metric: u32,
+}
+
+/// Information about a Wi-Fi connection.
+///
+/// It is returned by [`NetworkData::wifi_info`][crate::NetworkData::wifi_info].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WifiInfo {
+    /// Name of the Wi-Fi network the interface is connected to.
+    pub ssid: Option<String>,
+    /// MAC address of the access point the interface is connected to.
+    pub bssid: Option<MacAddr>,
+    /// Signal strength (in dBm).
+    ///
+    /// On Windows, it is computed from the signal quality percentage.
+    pub signal: Option<i32>,
+    /// Frequency of the channel (in MHz).
+    pub frequency: Option<u32>,
+    /// Channel number.
+    pub channel: Option<u32>,
+}
+
+/// Enum containing the different supported network interfaces kinds.
+///
+/// It is returned by [`NetworkData::kind`][crate::NetworkData::kind].
+///
+/// ```no_run
+/// use sysinfo::{InterfaceKind, Networks};
+///
+/// let networks = Networks::new_with_refreshed_list();
+/// for (interface_name, network) in &networks {
+///     if network.kind() == InterfaceKind::Cellular {
+///         println!("[{interface_name}] is metered");
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum InterfaceKind {
+    /// Wired ethernet interface.
+    Ethernet,
+    /// Wireless (802.11) interface.
+    Wifi,
+    /// Mobile broadband interface.
+    Cellular,
+    /// Loopback interface.
+    Loopback,
+    /// Bridge interface.
+    Bridge,
+    /// Tunnel interface (VPN, point-to-point protocol, IP-in-IP...).
+    Tunnel,
+    /// Virtual interface not backed by a hardware device (`veth`, VLAN, bond...).
+    Virtual,
+    /// Other kind of interface, with its raw type value:
+    ///
+    /// | Platform | Value |
+    /// |---|---|
+    /// | Linux | `/sys/class/net/<interface>/type` (`ARPHRD_*`) |
+    /// | Windows | `MIB_IF_ROW2.Type` (`IF_TYPE_*`) |
+    /// | macOS / FreeBSD | `if_data.ifi_type` (`IFT_*`) |
+    Other(u32),
+}
+
+impl fmt::Display for InterfaceKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            InterfaceKind::Ethernet => "Ethernet",
+            InterfaceKind::Wifi => "Wifi",
+            InterfaceKind::Cellular => "Cellular",
+            InterfaceKind::Loopback => "Loopback",
+            InterfaceKind::Bridge => "Bridge",
+            InterfaceKind::Tunnel => "Tunnel",
+            InterfaceKind::Virtual => "Virtual",
+            InterfaceKind::Other(_) => "Other",
+        })
+    }
+}
+
+/// MAC address for network interface.
+///
+/// It is returned by [`NetworkData::mac_address`][crate::NetworkData::mac_address]. It is
+/// displayed as lowercase colon-separated bytes, like `00:1b:21:3a:4f:5e`, and can be parsed
+/// with either colons or dashes as separators.
+///
+/// ```
+/// use sysinfo::MacAddr;
+///
+/// let mac: MacAddr = "00-1B-21-3A-4F-5E".parse().unwrap();
+/// assert_eq!(mac.to_string(), "00:1b:21:3a:4f:5e");
+/// assert!(!mac.is_multicast());
+/// assert!(!mac.is_locally_administered());
+/// ```
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct MacAddr(pub [u8; 6]);
+
+impl MacAddr {
+    /// A `MacAddr` with all bytes set to `0`.
+    pub const UNSPECIFIED: Self = MacAddr([0; 6]);
 
     /// Checks if this `MacAddr` has all bytes equal to `0`.
     pub fn is_unspecified(&self) -> bool {
         self == &MacAddr::UNSPECIFIED
     }
+
+    /// Checks if this `MacAddr` is a multicast (or broadcast) address, meaning that the least
+    /// significant bit of its first byte is set.
+    ///
+    /// ```
+    /// use sysinfo::MacAddr;
+    ///
+    /// assert!(MacAddr([0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb]).is_multicast());
+    /// assert!(!MacAddr([0x00, 0x1b, 0x21, 0x3a, 0x4f, 0x5e]).is_multicast());
+    /// ```
+    pub fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Checks if this `MacAddr` is locally administered, meaning that the second least
+    /// significant bit of its first byte is set. These addresses are not assigned by the
+    /// manufacturer, like the ones of virtual interfaces or randomized Wi-Fi addresses.
+    ///
+    /// ```
+    /// use sysinfo::MacAddr;
+    ///
+    /// assert!(MacAddr([0x02, 0x42, 0xac, 0x11, 0x00, 0x02]).is_locally_administered());
+    /// assert!(!MacAddr([0x00, 0x1b, 0x21, 0x3a, 0x4f, 0x5e]).is_locally_administered());
+    /// ```
+    pub fn is_locally_administered(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+
+    /// Returns the organizationally unique identifier of this `MacAddr`, which is its first
+    /// three bytes.
+    ///
+    /// ```
+    /// use sysinfo::MacAddr;
+    ///
+    /// assert_eq!(
+    ///     MacAddr([0x00, 0x1b, 0x21, 0x3a, 0x4f, 0x5e]).oui(),
+    ///     [0x00, 0x1b, 0x21],
+    /// );
+    /// ```
+    pub fn oui(&self) -> [u8; 3] {
+        [self.0[0], self.0[1], self.0[2]]
+    }
+
+    /// Returns the name of the vendor of the network interface from the
+    /// [organizationally unique identifier][MacAddr::oui] of this `MacAddr`.
+    ///
+    /// It is looked up in a copy of the IEEE MA-L registry bundled in `sysinfo`, with the legal
+    /// forms (like `Inc.` or `Corporation`) removed from the organization names. It returns
+    /// `None` for the prefixes which are not in it (like the ones divided into MA-M and MA-S
+    /// blocks), as well as for the [multicast][MacAddr::is_multicast] and the
+    /// [locally administered][MacAddr::is_locally_administered] addresses.
+    ///
+    /// This method is only available with the `mac-vendors` feature.
+    ///
+    /// ```
+    /// use sysinfo::MacAddr;
+    ///
+    /// assert_eq!(MacAddr([0x00, 0x50, 0x56, 0x01, 0x02, 0x03]).vendor(), Some("VMware"));
+    /// assert_eq!(MacAddr([0x02, 0x50, 0x56, 0x01, 0x02, 0x03]).vendor(), None);
+    /// ```
+    #[cfg(feature = "mac-vendors")]
+    pub fn vendor(&self) -> Option<&'static str> {
+        if self.is_multicast() || self.is_locally_administered() {
+            return None;
+        }
+        crate::common::mac_vendors::vendor_from_oui(self.oui())
+    }
 }
 
 impl fmt::Display for MacAddr {
@@ -441,7 +1609,8 @@ impl fmt::Display for MacAddr {
 pub enum MacAddrFromStrError {
     /// A number is not in hexadecimal format.
     IntError(ParseIntError),
-    /// Input is not of format `{02X}:{02X}:{02X}:{02X}:{02X}:{02X}`.
+    /// Input is not of format `{02X}:{02X}:{02X}:{02X}:{02X}:{02X}` (or with dashes instead of
+    /// colons).
     InvalidAddrFormat,
 }
 
@@ -449,8 +1618,10 @@ impl FromStr for MacAddr {
     type Err = MacAddrFromStrError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Windows displays the MAC addresses with dashes.
+        let separator = if s.contains('-') { '-' } else { ':' };
         let mut parts = s
-            .split(':')
+            .split(separator)
             .map(|s| u8::from_str_radix(s, 16).map_err(MacAddrFromStrError::IntError));
 
         let Some(data0) = parts.next() else {
@@ -483,13 +1654,157 @@ impl FromStr for MacAddr {
 /// IP networks address for network interface.
 ///
 /// It is returned by [`NetworkData::ip_networks`][crate::NetworkData::ip_networks].
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize))]
 pub struct IpNetwork {
     /// The IP of the network interface.
     pub addr: IpAddr,
     /// The netmask, prefix of the IP address.
     pub prefix: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) is_temporary: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) is_deprecated: bool,
+}
+
+// The address flags are not compared, so an `IpNetwork` is the same network whether its address
+// is temporary or deprecated or not.
+impl PartialEq for IpNetwork {
+    fn eq(&self, other: &Self) -> bool {
+        (self.addr, self.prefix) == (other.addr, other.prefix)
+    }
+}
+
+impl Eq for IpNetwork {}
+
+impl std::hash::Hash for IpNetwork {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.addr, self.prefix).hash(state);
+    }
+}
+
+impl PartialOrd for IpNetwork {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IpNetwork {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.addr, self.prefix).cmp(&(other.addr, other.prefix))
+    }
+}
+
+impl IpNetwork {
+    /// Returns whether this is a temporary (privacy extensions) IPv6 address.
+    ///
+    /// ⚠️ This information is only available on Linux, macOS and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     for ip in network.ip_networks().iter().filter(|ip| ip.is_temporary()) {
+    ///         println!("[{interface_name}] temporary address: {ip}");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_temporary(&self) -> bool {
+        self.is_temporary
+    }
+
+    /// Returns whether this address is deprecated, meaning it should not be used to open new
+    /// connections.
+    ///
+    /// ⚠️ This information is only available on Linux, macOS and Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Networks;
+    ///
+    /// let networks = Networks::new_with_refreshed_list();
+    /// for (interface_name, network) in &networks {
+    ///     for ip in network.ip_networks().iter().filter(|ip| ip.is_deprecated()) {
+    ///         println!("[{interface_name}] deprecated address: {ip}");
+    ///     }
+    /// }
+    /// ```
+    pub fn is_deprecated(&self) -> bool {
+        self.is_deprecated
+    }
+
+    /// Returns the scope of the address.
+    ///
+    /// It is computed from the address itself:
+    ///
+    /// * `127.0.0.0/8` and `::1` are [`IpScope::Loopback`].
+    /// * `169.254.0.0/16` and `fe80::/10` are [`IpScope::LinkLocal`].
+    /// * `10.0.0.0/8`, `172.16.0.0/12`, `192.168.0.0/16`, `fc00::/7` (unique local addresses)
+    ///   and `fec0::/10` are [`IpScope::SiteLocal`].
+    /// * Everything else is [`IpScope::Global`].
+    ///
+    /// ```
+    /// use sysinfo::{IpNetwork, IpScope};
+    /// use std::str::FromStr;
+    ///
+    /// let ip = IpNetwork::from_str("fe80::1/64").unwrap();
+    /// assert_eq!(ip.scope(), IpScope::LinkLocal);
+    /// ```
+    pub fn scope(&self) -> IpScope {
+        match self.addr {
+            IpAddr::V4(addr) => {
+                if addr.is_loopback() {
+                    IpScope::Loopback
+                } else if addr.is_link_local() {
+                    IpScope::LinkLocal
+                } else if addr.is_private() {
+                    IpScope::SiteLocal
+                } else {
+                    IpScope::Global
+                }
+            }
+            IpAddr::V6(addr) => {
+                let first = addr.segments()[0];
+                if addr.is_loopback() {
+                    IpScope::Loopback
+                } else if first & 0xffc0 == 0xfe80 {
+                    IpScope::LinkLocal
+                } else if first & 0xfe00 == 0xfc00 || first & 0xffc0 == 0xfec0 {
+                    IpScope::SiteLocal
+                } else {
+                    IpScope::Global
+                }
+            }
+        }
+    }
+}
+
+/// Scope of an [`IpNetwork`] address.
+///
+/// It is returned by [`IpNetwork::scope`].
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum IpScope {
+    /// Globally routable address.
+    Global,
+    /// Address only valid inside a site, like private IPv4 addresses or IPv6 unique local
+    /// addresses.
+    SiteLocal,
+    /// Address only valid on the link it is assigned to.
+    LinkLocal,
+    /// Loopback address.
+    Loopback,
+}
+
+impl fmt::Display for IpScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Self::Global => "Global",
+            Self::SiteLocal => "SiteLocal",
+            Self::LinkLocal => "LinkLocal",
+            Self::Loopback => "Loopback",
+        })
+    }
 }
 
 impl fmt::Display for IpNetwork {
@@ -529,6 +1844,8 @@ impl FromStr for IpNetwork {
         Ok(IpNetwork {
             addr: IpAddr::from_str(addr).map_err(IpNetworkFromStrError::AddrParseError)?,
             prefix: u8::from_str_radix(prefix, 10).map_err(IpNetworkFromStrError::PrefixError)?,
+            is_temporary: false,
+            is_deprecated: false,
         })
     }
 }
@@ -576,6 +1893,34 @@ mod tests {
             MacAddr::from_str("0a:0b:0c:0d:0e"),
             Err(MacAddrFromStrError::InvalidAddrFormat)
         );
+        assert_eq!(Ok(mac), MacAddr::from_str("0A-0B-0C-0D-0E-0F"));
+        assert_eq!(
+            MacAddr::from_str("0a-0b:0c:0d:0e:0f"),
+            Err(MacAddrFromStrError::InvalidAddrFormat)
+        );
+    }
+
+    #[test]
+    fn check_mac_address_bits() {
+        let broadcast = MacAddr([0xff; 6]);
+        assert!(broadcast.is_multicast());
+        assert!(broadcast.is_locally_administered());
+
+        let docker = MacAddr([0x02, 0x42, 0xac, 0x11, 0x00, 0x02]);
+        assert!(!docker.is_multicast());
+        assert!(docker.is_locally_administered());
+        assert_eq!(docker.oui(), [0x02, 0x42, 0xac]);
+    }
+
+    #[cfg(feature = "mac-vendors")]
+    #[test]
+    fn check_mac_address_vendor() {
+        assert_eq!(
+            MacAddr([0x00, 0x15, 0x5d, 0x01, 0x02, 0x03]).vendor(),
+            Some("Microsoft")
+        );
+        assert_eq!(MacAddr([0x01, 0x00, 0x5e, 0x00, 0x00, 0xfb]).vendor(), None);
+        assert_eq!(MacAddr([0x02, 0x15, 0x5d, 0x01, 0x02, 0x03]).vendor(), None);
     }
 
     // Ensure that the `Display` and `Debug` traits are implemented on the `IpNetwork` struct
@@ -585,11 +1930,15 @@ mod tests {
             "{} {:?}",
             IpNetwork {
                 addr: IpAddr::from(Ipv4Addr::new(1, 2, 3, 4)),
-                prefix: 3
+                prefix: 3,
+                is_temporary: false,
+                is_deprecated: false
             },
             IpNetwork {
                 addr: IpAddr::from(Ipv4Addr::new(255, 255, 255, 0)),
-                prefix: 21
+                prefix: 21,
+                is_temporary: false,
+                is_deprecated: false
             }
         );
     }
@@ -600,15 +1949,33 @@ mod tests {
             "{} {:?}",
             IpNetwork {
                 addr: IpAddr::from(Ipv6Addr::new(0xffff, 0xaabb, 00, 0, 0, 0x000c, 11, 21)),
-                prefix: 127
+                prefix: 127,
+                is_temporary: false,
+                is_deprecated: false
             },
             IpNetwork {
                 addr: IpAddr::from(Ipv6Addr::new(0xffcc, 0, 0, 0xffcc, 0, 0xffff, 0, 0xccaa)),
-                prefix: 120
+                prefix: 120,
+                is_temporary: false,
+                is_deprecated: false
             }
         )
     }
 
+    #[test]
+    fn check_ip_network_flags_not_compared() {
+        let ip = IpNetwork::from_str("fe80::1/64").unwrap();
+        let temporary = IpNetwork {
+            is_temporary: true,
+            is_deprecated: true,
+            ..ip
+        };
+        assert!(!ip.is_temporary() && !ip.is_deprecated());
+        assert!(temporary.is_temporary() && temporary.is_deprecated());
+        assert_eq!(ip, temporary);
+        assert_eq!(ip.cmp(&temporary), std::cmp::Ordering::Equal);
+    }
+
     #[test]
     fn check_ip_networks() {
         if !IS_SUPPORTED_SYSTEM {
@@ -621,11 +1988,32 @@ mod tests {
         panic!("Networks should have at least one IP network ");
     }
 
+    #[test]
+    fn check_ip_network_scope() {
+        for (addr, scope) in [
+            ("127.0.0.1/8", IpScope::Loopback),
+            ("169.254.12.1/16", IpScope::LinkLocal),
+            ("10.1.2.3/8", IpScope::SiteLocal),
+            ("172.20.0.1/12", IpScope::SiteLocal),
+            ("192.168.1.10/24", IpScope::SiteLocal),
+            ("8.8.8.8/32", IpScope::Global),
+            ("::1/128", IpScope::Loopback),
+            ("fe80::1/64", IpScope::LinkLocal),
+            ("fd12:3456::1/64", IpScope::SiteLocal),
+            ("fec0::1/10", IpScope::SiteLocal),
+            ("2001:db8::1/64", IpScope::Global),
+        ] {
+            assert_eq!(IpNetwork::from_str(addr).unwrap().scope(), scope, "{addr}");
+        }
+    }
+
     #[test]
     fn check_ip_network_conversions() {
         let addr = IpNetwork {
             addr: IpAddr::from(Ipv6Addr::new(0xff, 0xa, 0x8, 0x12, 0x7, 0xc, 0xa, 0xb)),
             prefix: 12,
+            is_temporary: false,
+            is_deprecated: false,
         };
 
         let addr_s = addr.to_string();
@@ -635,6 +2023,8 @@ mod tests {
         let addr = IpNetwork {
             addr: IpAddr::from(Ipv4Addr::new(255, 255, 255, 0)),
             prefix: 21,
+            is_temporary: false,
+            is_deprecated: false,
         };
 
         let addr_s = addr.to_string();
@@ -0,0 +1,239 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::{Pid, Process, ThreadKind};
+
+/// Processes arranged by their parent, like `pstree` displays them.
+///
+/// It is created with [`System::process_tree`][crate::System::process_tree] and borrows the
+/// processes of the [`System`][crate::System], so it needs to be created again after the
+/// processes are refreshed.
+///
+/// It is displayed as one process per line, with the children indented below their parent:
+///
+/// ```text
+/// 1 systemd
+/// ├─ 412 sshd
+/// │  └─ 1337 bash
+/// └─ 596 cron
+/// ```
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// println!("{}", s.process_tree(None));
+/// ```
+pub struct ProcessTree<'a> {
+    roots: Vec<ProcessTreeNode<'a>>,
+}
+
+/// A process of a [`ProcessTree`] along with its children.
+pub struct ProcessTreeNode<'a> {
+    process: &'a Process,
+    children: Vec<ProcessTreeNode<'a>>,
+}
+
+impl<'a> ProcessTree<'a> {
+    pub(crate) fn new(processes: &'a HashMap<Pid, Process>, root: Option<Pid>) -> Self {
+        // The threads are not displayed as children of their process.
+        let is_process = |process: &Process| process.thread_kind() != Some(ThreadKind::Userland);
+
+        let mut children: HashMap<Pid, Vec<Pid>> = HashMap::new();
+        let mut roots = Vec::new();
+        for (pid, process) in processes {
+            if !is_process(process) {
+                continue;
+            }
+            match process.parent() {
+                // Some processes, like the `System Idle Process` on Windows, are their own parent.
+                Some(parent)
+                    if parent != *pid && processes.get(&parent).is_some_and(is_process) =>
+                {
+                    children.entry(parent).or_default().push(*pid);
+                }
+                _ => roots.push(*pid),
+            }
+        }
+        for pids in children.values_mut() {
+            pids.sort_unstable();
+        }
+
+        let mut builder = TreeBuilder {
+            processes,
+            children,
+            visited: HashSet::new(),
+        };
+        let roots = match root {
+            Some(root) => builder.build(root).into_iter().collect(),
+            None => {
+                roots.sort_unstable();
+                let mut nodes = roots
+                    .into_iter()
+                    .filter_map(|pid| builder.build(pid))
+                    .collect::<Vec<_>>();
+                // Since the PIDs are reused, the parents can form a cycle. These processes are not
+                // reachable from the roots, so they are added at the top level.
+                let mut remaining = builder
+                    .children
+                    .values()
+                    .flatten()
+                    .copied()
+                    .filter(|pid| !builder.visited.contains(pid))
+                    .collect::<Vec<_>>();
+                remaining.sort_unstable();
+                for pid in remaining {
+                    nodes.extend(builder.build(pid));
+                }
+                nodes
+            }
+        };
+        Self { roots }
+    }
+
+    /// Returns the processes at the top of the tree.
+    ///
+    /// If the tree was created with a root process, it only contains this one (or nothing if
+    /// it doesn't exist). Otherwise, it contains the processes without parent as well as the
+    /// ones whose parent isn't in the processes list anymore.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for node in s.process_tree(None).roots() {
+    ///     println!("{:?} has {} children", node.process().name(), node.children().len());
+    /// }
+    /// ```
+    pub fn roots(&self) -> &[ProcessTreeNode<'a>] {
+        &self.roots
+    }
+
+    /// Returns the node of the process with the given `pid` if it is in the tree.
+    ///
+    /// ```no_run
+    /// use sysinfo::{get_current_pid, System};
+    ///
+    /// let s = System::new_all();
+    /// let tree = s.process_tree(None);
+    /// if let Some(node) = tree.find(get_current_pid().unwrap()) {
+    ///     println!("{} children", node.children().len());
+    /// }
+    /// ```
+    pub fn find(&self, pid: Pid) -> Option<&ProcessTreeNode<'a>> {
+        self.roots.iter().find_map(|node| node.find(pid))
+    }
+}
+
+impl<'a> ProcessTreeNode<'a> {
+    /// Returns the process of this node.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for node in s.process_tree(None).roots() {
+    ///     println!("{:?}", node.process().name());
+    /// }
+    /// ```
+    pub fn process(&self) -> &'a Process {
+        self.process
+    }
+
+    /// Returns the children of the process, sorted by PID.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for node in s.process_tree(None).roots() {
+    ///     for child in node.children() {
+    ///         println!("{:?}", child.process().name());
+    ///     }
+    /// }
+    /// ```
+    pub fn children(&self) -> &[ProcessTreeNode<'a>] {
+        &self.children
+    }
+
+    /// Returns the node of the process with the given `pid` if it is this process or one of its
+    /// descendants.
+    ///
+    /// ```no_run
+    /// use sysinfo::{get_current_pid, Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// let tree = s.process_tree(Some(Pid::from(1)));
+    /// let is_descendant = tree
+    ///     .roots()
+    ///     .iter()
+    ///     .any(|node| node.find(get_current_pid().unwrap()).is_some());
+    /// ```
+    pub fn find(&self, pid: Pid) -> Option<&ProcessTreeNode<'a>> {
+        if self.process.pid() == pid {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(pid))
+    }
+
+    fn fmt_with_prefix(&self, f: &mut fmt::Formatter<'_>, prefix: &mut String) -> fmt::Result {
+        writeln!(
+            f,
+            "{} {}",
+            self.process.pid(),
+            self.process.name().to_string_lossy()
+        )?;
+        for (index, child) in self.children.iter().enumerate() {
+            let is_last = index + 1 == self.children.len();
+            f.write_str(prefix)?;
+            f.write_str(if is_last { "└─ " } else { "├─ " })?;
+            let len = prefix.len();
+            prefix.push_str(if is_last { "   " } else { "│  " });
+            child.fmt_with_prefix(f, prefix)?;
+            prefix.truncate(len);
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ProcessTree<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut prefix = String::new();
+        for root in &self.roots {
+            root.fmt_with_prefix(f, &mut prefix)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ProcessTreeNode<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with_prefix(f, &mut String::new())
+    }
+}
+
+struct TreeBuilder<'a> {
+    processes: &'a HashMap<Pid, Process>,
+    children: HashMap<Pid, Vec<Pid>>,
+    visited: HashSet<Pid>,
+}
+
+impl<'a> TreeBuilder<'a> {
+    fn build(&mut self, pid: Pid) -> Option<ProcessTreeNode<'a>> {
+        let process = self.processes.get(&pid)?;
+        if !self.visited.insert(pid) {
+            return None;
+        }
+        let children = self
+            .children
+            .get(&pid)
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|child| self.build(child))
+            .collect();
+        Some(ProcessTreeNode { process, children })
+    }
+}
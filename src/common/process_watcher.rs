@@ -0,0 +1,176 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::ffi::{OsStr, OsString};
+use std::sync::mpsc::{self, Receiver};
+use std::time::Duration;
+
+use crate::{Pid, ProcessWatcherInner};
+
+/// Receives the processes' start and stop events as they happen, without polling.
+///
+/// It is created with [`System::watch_processes`][crate::System::watch_processes]. The events
+/// are received in the background and buffered until they are read. The watcher stops when it
+/// is dropped.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// match System::watch_processes() {
+///     Ok(watcher) => {
+///         for event in watcher {
+///             println!("{:?} {} ({:?})", event.kind(), event.pid(), event.name());
+///         }
+///     }
+///     Err(error) => eprintln!("cannot watch processes: {error:?}"),
+/// }
+/// ```
+pub struct ProcessWatcher {
+    receiver: Receiver<ProcessEvent>,
+    // Stops receiving the events when dropped.
+    _inner: ProcessWatcherInner,
+}
+
+impl ProcessWatcher {
+    pub(crate) fn new() -> Result<Self, WatchProcessesError> {
+        let (sender, receiver) = mpsc::channel();
+        Ok(Self {
+            receiver,
+            _inner: ProcessWatcherInner::new(sender)?,
+        })
+    }
+
+    /// Waits for the next event.
+    ///
+    /// It returns `None` if the watcher stopped because of an error.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let watcher = System::watch_processes().expect("cannot watch processes");
+    /// if let Some(event) = watcher.recv() {
+    ///     println!("{:?}", event);
+    /// }
+    /// ```
+    pub fn recv(&self) -> Option<ProcessEvent> {
+        self.receiver.recv().ok()
+    }
+
+    /// Returns the next event if there is one already available, without waiting.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let watcher = System::watch_processes().expect("cannot watch processes");
+    /// while let Some(event) = watcher.try_recv() {
+    ///     println!("{:?}", event);
+    /// }
+    /// ```
+    pub fn try_recv(&self) -> Option<ProcessEvent> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Waits for the next event for at most `timeout`.
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use sysinfo::System;
+    ///
+    /// let watcher = System::watch_processes().expect("cannot watch processes");
+    /// if let Some(event) = watcher.recv_timeout(Duration::from_secs(1)) {
+    ///     println!("{:?}", event);
+    /// }
+    /// ```
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<ProcessEvent> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+}
+
+impl Iterator for ProcessWatcher {
+    type Item = ProcessEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
+
+/// Event received by a [`ProcessWatcher`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessEvent {
+    pub(crate) kind: ProcessEventKind,
+    pub(crate) pid: Pid,
+    pub(crate) parent_pid: Option<Pid>,
+    pub(crate) name: OsString,
+}
+
+impl ProcessEvent {
+    /// Returns whether the process started or stopped.
+    pub fn kind(&self) -> ProcessEventKind {
+        self.kind
+    }
+
+    /// Returns the PID of the process.
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the PID of the parent of the process.
+    ///
+    /// For [`ProcessEventKind::Stopped`] events, it is only known if the process was started
+    /// after the watcher was created.
+    pub fn parent_pid(&self) -> Option<Pid> {
+        self.parent_pid
+    }
+
+    /// Returns the name of the executable of the process.
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+}
+
+/// Kind of a [`ProcessEvent`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum ProcessEventKind {
+    /// The process started.
+    Started,
+    /// The process stopped.
+    Stopped,
+}
+
+/// Enum describing possible [`System::watch_processes`][crate::System::watch_processes] errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum WatchProcessesError {
+    /// Watching processes isn't supported on this platform.
+    Unsupported,
+    /// The current user isn't allowed to watch processes.
+    PermissionDenied,
+    /// The watcher failed to start.
+    FailedToStart,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{System, WatchProcessesError};
+
+    #[test]
+    fn check_watch_processes() {
+        match System::watch_processes() {
+            Ok(watcher) => {
+                assert!(cfg!(any(
+                    windows,
+                    target_os = "linux",
+                    target_os = "android"
+                )));
+                // Nothing guarantees that a process will start or stop in the meantime.
+                let _ = watcher.try_recv();
+            }
+            // It requires administrator privileges on Windows.
+            Err(error) if cfg!(windows) => assert_ne!(error, WatchProcessesError::Unsupported),
+            // It requires the `CAP_NET_ADMIN` capability on Linux, and the kernel might have been
+            // built without the proc connector.
+            Err(_) if cfg!(any(target_os = "linux", target_os = "android")) => {}
+            Err(error) => assert_eq!(error, WatchProcessesError::Unsupported),
+        }
+    }
+}
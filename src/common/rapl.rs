@@ -0,0 +1,181 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::SystemInner;
+
+/// Raw value of a RAPL energy counter, as read from the system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct RaplCounter {
+    /// Current value of the counter, in microjoules.
+    pub(crate) energy: u64,
+    /// Value after which the counter goes back to `0`, in microjoules.
+    pub(crate) max_energy_range: u64,
+}
+
+/// Raw values of the RAPL energy counters of a CPU package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct RaplPackageCounters {
+    pub(crate) package_id: u32,
+    pub(crate) package: RaplCounter,
+    pub(crate) core: Option<RaplCounter>,
+    pub(crate) dram: Option<RaplCounter>,
+}
+
+/// Cumulative energy consumed by a CPU package, as reported by Intel RAPL (Running Average
+/// Power Limit).
+///
+/// It is returned by [`System::rapl_energy`][crate::System::rapl_energy]. The values are in
+/// microjoules, start at `0` at the first refresh and only ever increase: the wraparounds of the
+/// underlying RAPL counters are taken into account. To get the power (in watts), divide the energy consumed between two
+/// refreshes by the time elapsed between them.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let mut s = System::new();
+/// s.refresh_rapl_energy();
+/// for package in s.rapl_energy().unwrap_or_default() {
+///     println!("package {}: {} µJ", package.package_id(), package.package());
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RaplEnergy {
+    package_id: u32,
+    package: u64,
+    core: Option<u64>,
+    dram: Option<u64>,
+}
+
+impl RaplEnergy {
+    /// Returns the ID of the CPU package.
+    pub fn package_id(&self) -> u32 {
+        self.package_id
+    }
+
+    /// Returns the energy consumed by the whole package, in microjoules.
+    pub fn package(&self) -> u64 {
+        self.package
+    }
+
+    /// Returns the energy consumed by the cores of the package, in microjoules.
+    ///
+    /// Returns `None` if the package doesn't report it.
+    pub fn core(&self) -> Option<u64> {
+        self.core
+    }
+
+    /// Returns the energy consumed by the memory attached to the package, in microjoules.
+    ///
+    /// Returns `None` if the package doesn't report it.
+    pub fn dram(&self) -> Option<u64> {
+        self.dram
+    }
+}
+
+/// Keeps the last raw RAPL counters to accumulate the consumed energy across refreshes.
+#[derive(Default)]
+pub(crate) struct RaplEnergyTracker {
+    counters: Vec<RaplPackageCounters>,
+    energy: Option<Vec<RaplEnergy>>,
+}
+
+impl RaplEnergyTracker {
+    pub(crate) fn refresh(&mut self) {
+        match SystemInner::rapl_counters() {
+            Some(counters) => self.update(counters),
+            None => *self = Self::default(),
+        }
+    }
+
+    fn update(&mut self, counters: Vec<RaplPackageCounters>) {
+        let old_energy = self.energy.take().unwrap_or_default();
+        let energy = counters
+            .iter()
+            .map(|new| {
+                let old = self
+                    .counters
+                    .iter()
+                    .zip(&old_energy)
+                    .find(|(old, _)| old.package_id == new.package_id);
+                RaplEnergy {
+                    package_id: new.package_id,
+                    package: accumulate_energy(
+                        new.package,
+                        old.map(|(old, energy)| (old.package, energy.package)),
+                    ),
+                    core: new.core.map(|core| {
+                        accumulate_energy(core, old.and_then(|(old, e)| Some((old.core?, e.core?))))
+                    }),
+                    dram: new.dram.map(|dram| {
+                        accumulate_energy(dram, old.and_then(|(old, e)| Some((old.dram?, e.dram?))))
+                    }),
+                }
+            })
+            .collect();
+        self.counters = counters;
+        self.energy = Some(energy);
+    }
+
+    pub(crate) fn energy(&self) -> Option<&[RaplEnergy]> {
+        self.energy.as_deref()
+    }
+}
+
+/// Returns the total energy consumed with the `new` counter value, where `old` is the previous
+/// counter value with the total energy consumed at that time.
+///
+/// The total starts at `0` the first time a counter is read.
+fn accumulate_energy(new: RaplCounter, old: Option<(RaplCounter, u64)>) -> u64 {
+    let Some((old, total)) = old else {
+        return 0;
+    };
+    total.saturating_add(crate::common::rapl_energy_consumed(
+        old.energy,
+        new.energy,
+        new.max_energy_range,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counters(package_id: u32, package: u64, dram: Option<u64>) -> RaplPackageCounters {
+        let counter = |energy| RaplCounter {
+            energy,
+            max_energy_range: 1_000,
+        };
+        RaplPackageCounters {
+            package_id,
+            package: counter(package),
+            core: None,
+            dram: dram.map(counter),
+        }
+    }
+
+    #[test]
+    fn test_rapl_energy_tracker() {
+        let mut tracker = RaplEnergyTracker::default();
+        assert_eq!(tracker.energy(), None);
+
+        // The energy is counted from the first refresh.
+        tracker.update(vec![counters(0, 100, Some(10))]);
+        let energy = &tracker.energy().unwrap()[0];
+        assert_eq!(energy.package(), 0);
+        assert_eq!(energy.core(), None);
+        assert_eq!(energy.dram(), Some(0));
+
+        tracker.update(vec![counters(0, 300, Some(20))]);
+        let energy = &tracker.energy().unwrap()[0];
+        assert_eq!(energy.package(), 200);
+        assert_eq!(energy.dram(), Some(10));
+
+        // The package counter wrapped around.
+        tracker.update(vec![counters(0, 50, Some(30)), counters(1, 5, None)]);
+        let energy = tracker.energy().unwrap();
+        assert_eq!(energy[0].package(), 200 + 700 + 50);
+        assert_eq!(energy[0].dram(), Some(20));
+        // A new package starts from `0` too.
+        assert_eq!(energy[1].package_id(), 1);
+        assert_eq!(energy[1].package(), 0);
+    }
+}
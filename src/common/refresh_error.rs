@@ -0,0 +1,125 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::{error, fmt, io};
+
+/// Information which was being refreshed when a [`RefreshError`] happened.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RefreshSubsystem {
+    /// The memory and swap information, refreshed with
+    /// [`System::try_refresh_memory`][crate::System::try_refresh_memory].
+    Memory,
+    /// The disks, refreshed with [`Disks::try_refresh`][crate::Disks::try_refresh].
+    Disks,
+    /// The network interfaces, refreshed with
+    /// [`Networks::try_refresh`][crate::Networks::try_refresh].
+    Networks,
+}
+
+impl fmt::Display for RefreshSubsystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match *self {
+            Self::Memory => "memory",
+            Self::Disks => "disks",
+            Self::Networks => "networks",
+        })
+    }
+}
+
+/// Error which happened while refreshing some information.
+///
+/// It is returned by the `try_refresh*` methods, like
+/// [`System::try_refresh_memory`][crate::System::try_refresh_memory]. The equivalent methods
+/// without the `try_` prefix ignore these errors and keep the previous values.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let mut s = System::new();
+/// if let Err(error) = s.try_refresh_memory() {
+///     eprintln!("{error}");
+///     if error.io_error().kind() == std::io::ErrorKind::PermissionDenied {
+///         eprintln!("cannot read `{}`", error.origin());
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct RefreshError {
+    subsystem: RefreshSubsystem,
+    origin: String,
+    error: io::Error,
+}
+
+impl RefreshError {
+    #[allow(dead_code)]
+    pub(crate) fn new(
+        subsystem: RefreshSubsystem,
+        origin: impl Into<String>,
+        error: io::Error,
+    ) -> Self {
+        Self {
+            subsystem,
+            origin: origin.into(),
+            error,
+        }
+    }
+
+    /// Returns which information was being refreshed.
+    pub fn subsystem(&self) -> RefreshSubsystem {
+        self.subsystem
+    }
+
+    /// Returns the path which couldn't be read or the name of the system API which failed (like
+    /// `"/proc/meminfo"` or `"statvfs(/mnt/nfs)"`).
+    pub fn origin(&self) -> &str {
+        &self.origin
+    }
+
+    /// Returns the underlying error.
+    pub fn io_error(&self) -> &io::Error {
+        &self.error
+    }
+
+    /// Returns the OS error code of the underlying error, if there is one.
+    pub fn raw_os_error(&self) -> Option<i32> {
+        self.error.raw_os_error()
+    }
+}
+
+impl fmt::Display for RefreshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to refresh the {}: `{}` failed: {}",
+            self.subsystem, self.origin, self.error
+        )
+    }
+}
+
+impl error::Error for RefreshError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RefreshError, RefreshSubsystem};
+    use std::error::Error;
+    use std::io;
+
+    #[test]
+    fn check_refresh_error() {
+        let error = RefreshError::new(
+            RefreshSubsystem::Disks,
+            "statvfs(/mnt/nfs)",
+            io::Error::from_raw_os_error(13),
+        );
+        assert_eq!(error.subsystem(), RefreshSubsystem::Disks);
+        assert_eq!(error.origin(), "statvfs(/mnt/nfs)");
+        assert_eq!(error.raw_os_error(), Some(13));
+        assert!(error.source().is_some());
+        assert!(error
+            .to_string()
+            .starts_with("failed to refresh the disks: `statvfs(/mnt/nfs)` failed: "));
+    }
+}
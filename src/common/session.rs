@@ -0,0 +1,307 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Uid;
+
+/// Interacting with the login sessions of the system, like `who` or `query user` would.
+///
+/// ```no_run
+/// use sysinfo::Sessions;
+///
+/// let sessions = Sessions::new_with_refreshed_list();
+/// for session in sessions.list() {
+///     println!(
+///         "{} logged in at {} on {:?} from {:?}",
+///         session.user_name(),
+///         session.login_time(),
+///         session.terminal(),
+///         session.remote_host(),
+///     );
+/// }
+/// ```
+pub struct Sessions {
+    sessions: Vec<Session>,
+}
+
+impl Default for Sessions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Sessions> for Vec<Session> {
+    fn from(sessions: Sessions) -> Self {
+        sessions.sessions
+    }
+}
+
+impl From<Vec<Session>> for Sessions {
+    fn from(sessions: Vec<Session>) -> Self {
+        Self { sessions }
+    }
+}
+
+impl std::ops::Deref for Sessions {
+    type Target = [Session];
+
+    fn deref(&self) -> &Self::Target {
+        self.list()
+    }
+}
+
+impl<'a> IntoIterator for &'a Sessions {
+    type Item = &'a Session;
+    type IntoIter = std::slice::Iter<'a, Session>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.list().iter()
+    }
+}
+
+impl Sessions {
+    /// Creates a new empty [`Sessions`][crate::Sessions] type.
+    ///
+    /// If you want it to be filled directly, take a look at
+    /// [`Sessions::new_with_refreshed_list`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let mut sessions = Sessions::new();
+    /// sessions.refresh();
+    /// for session in sessions.list() {
+    ///     println!("{session:?}");
+    /// }
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            sessions: Vec::new(),
+        }
+    }
+
+    /// Creates a new [`Sessions`][crate::Sessions] type with the sessions list loaded.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     println!("{session:?}");
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list() -> Self {
+        let mut sessions = Self::new();
+        sessions.refresh();
+        sessions
+    }
+
+    /// Returns the sessions list.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     println!("{session:?}");
+    /// }
+    /// ```
+    pub fn list(&self) -> &[Session] {
+        &self.sessions
+    }
+
+    /// The sessions list will be emptied then completely recomputed.
+    ///
+    /// On unix, the sessions are read from the `utmpx` database. The entries of the sessions
+    /// which were not properly closed are skipped by checking that their process is still alive.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let mut sessions = Sessions::new();
+    /// sessions.refresh();
+    /// ```
+    pub fn refresh(&mut self) {
+        crate::sys::get_sessions(&mut self.sessions);
+    }
+}
+
+/// Information about a login session.
+///
+/// It is returned by [`Sessions`].
+///
+/// ```no_run
+/// use sysinfo::Sessions;
+///
+/// let sessions = Sessions::new_with_refreshed_list();
+/// for session in sessions.list() {
+///     println!("{session:?}");
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Session {
+    pub(crate) user_name: String,
+    pub(crate) user_id: Option<Uid>,
+    pub(crate) session_id: Option<u32>,
+    pub(crate) login_time: u64,
+    pub(crate) kind: SessionKind,
+    pub(crate) terminal: Option<String>,
+    pub(crate) remote_host: Option<String>,
+}
+
+impl Session {
+    /// Returns the name of the user owning the session.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     println!("{}", session.user_name());
+    /// }
+    /// ```
+    pub fn user_name(&self) -> &str {
+        &self.user_name
+    }
+
+    /// Returns the ID of the user owning the session, or `None` if it couldn't be retrieved.
+    ///
+    /// It can be used to find the matching [`User`][crate::User] with
+    /// [`Users::get_user_by_id`][crate::Users::get_user_by_id].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Sessions, Users};
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// let users = Users::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     if let Some(user) = session.user_id().and_then(|id| users.get_user_by_id(id)) {
+    ///         println!("{:?}", user);
+    ///     }
+    /// }
+    /// ```
+    pub fn user_id(&self) -> Option<&Uid> {
+        self.user_id.as_ref()
+    }
+
+    /// Returns the ID of the session.
+    ///
+    /// On unix, it is the session ID of the login process. On Windows, it is the ID of the
+    /// Remote Desktop Services session.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     println!("{:?}", session.session_id());
+    /// }
+    /// ```
+    pub fn session_id(&self) -> Option<u32> {
+        self.session_id
+    }
+
+    /// Returns the time (in seconds) when the user logged in since UNIX epoch.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     println!("{}", session.login_time());
+    /// }
+    /// ```
+    pub fn login_time(&self) -> u64 {
+        self.login_time
+    }
+
+    /// Returns the kind of the session, like whether it's local or remote.
+    ///
+    /// Take a look at [`SessionKind`] for how it's retrieved on each platform.
+    ///
+    /// ```no_run
+    /// use sysinfo::{SessionKind, Sessions};
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// let remote = sessions
+    ///     .list()
+    ///     .iter()
+    ///     .filter(|session| session.kind() == SessionKind::Remote)
+    ///     .count();
+    /// println!("{remote} remote sessions");
+    /// ```
+    pub fn kind(&self) -> SessionKind {
+        self.kind
+    }
+
+    /// Returns the terminal of the session, like `tty1` or `pts/0` on unix, and the name of the
+    /// window station, like `Console` or `RDP-Tcp#0`, on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     println!("{:?}", session.terminal());
+    /// }
+    /// ```
+    pub fn terminal(&self) -> Option<&str> {
+        self.terminal.as_deref()
+    }
+
+    /// Returns the host name or the IP address the user connected from, if it is a remote
+    /// session.
+    ///
+    /// ```no_run
+    /// use sysinfo::Sessions;
+    ///
+    /// let sessions = Sessions::new_with_refreshed_list();
+    /// for session in sessions.list() {
+    ///     if let Some(host) = session.remote_host() {
+    ///         println!("{} connected from {host}", session.user_name());
+    ///     }
+    /// }
+    /// ```
+    pub fn remote_host(&self) -> Option<&str> {
+        self.remote_host.as_deref()
+    }
+}
+
+/// Kind of a [`Session`].
+///
+/// More kinds might be added in the future, so matching on it should have a wildcard arm.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[non_exhaustive]
+pub enum SessionKind {
+    /// The user is logged in on the machine itself, in a graphical session or in a terminal
+    /// opened from it.
+    ///
+    /// On Unix systems, it's a session without a remote host or with an X display as host
+    /// (like `:0`). On macOS, the graphical login (on the `console` terminal) is local too.
+    ///
+    /// On Windows, it's a session using the console protocol.
+    Local,
+    /// The user is logged in from another machine, like with SSH or Remote Desktop.
+    ///
+    /// On Unix systems, it's a session with a remote host. On Windows, it's a session using
+    /// another protocol than the console one.
+    Remote,
+    /// The user is logged in on a text console of the machine.
+    ///
+    /// On Unix systems, it's a session on a virtual console or a serial line (like `tty1`,
+    /// `ttyS0` or `ttyv0` on FreeBSD).
+    ///
+    /// ⚠️ Not used on Windows.
+    Console,
+    /// The session used by the system services.
+    ///
+    /// On Windows, it's the session 0.
+    ///
+    /// ⚠️ Not used on Unix systems.
+    Service,
+    /// The kind of the session couldn't be retrieved.
+    ///
+    /// On Windows, it's when the protocol of the session cannot be queried.
+    ///
+    /// ⚠️ Not used on Unix systems.
+    Unknown,
+}
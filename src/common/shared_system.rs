@@ -0,0 +1,391 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::sync::{Arc, Mutex, RwLock};
+
+#[cfg(feature = "disk")]
+use crate::common::system_diff::DiskSnapshot;
+#[cfg(feature = "network")]
+use crate::common::system_diff::NetworkSnapshot;
+use crate::{DiffThresholds, ProcessSnapshot, RefreshKind, System, SystemDiff};
+
+/// Wrapper around [`System`] which can be shared between threads and refreshed while it is
+/// being read.
+///
+/// Readers get an immutable [`SystemSnapshot`] with [`SharedSystem::load`]. When
+/// [`SharedSystem::refresh`] is called, a new snapshot is built off to the side and then swapped
+/// in, so readers always see a consistent version. Readers only wait for the swap itself (the
+/// time to replace an [`Arc`]), never for the refresh. Snapshots previously returned by
+/// [`SharedSystem::load`] stay valid and unchanged.
+///
+/// Only the information covered by the [`RefreshKind`] given to [`SharedSystem::new`] is
+/// refreshed and part of the snapshots.
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, SharedSystem};
+///
+/// let shared = Arc::new(SharedSystem::new(
+///     RefreshKind::nothing()
+///         .with_memory(MemoryRefreshKind::everything())
+///         .with_cpu(CpuRefreshKind::everything()),
+/// ));
+///
+/// let reader = {
+///     let shared = Arc::clone(&shared);
+///     std::thread::spawn(move || {
+///         let snapshot = shared.load();
+///         println!("used memory: {} / {}", snapshot.used_memory(), snapshot.total_memory());
+///     })
+/// };
+/// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+/// shared.refresh();
+/// println!("global CPU usage: {}%", shared.load().global_cpu_usage());
+/// reader.join().unwrap();
+/// ```
+pub struct SharedSystem {
+    // Only used by `refresh`, so readers never wait for it.
+    system: Mutex<System>,
+    refreshes: RefreshKind,
+    // Only locked to clone or replace the `Arc`.
+    snapshot: RwLock<Arc<SystemSnapshot>>,
+}
+
+impl SharedSystem {
+    /// Creates a new [`SharedSystem`] with the information covered by `refreshes` already
+    /// loaded.
+    ///
+    /// Please note that the CPU usage needs two refreshes to be computed, so you need to call
+    /// [`SharedSystem::refresh`] (after at least [`MINIMUM_CPU_UPDATE_INTERVAL`]) to get it.
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, RefreshKind, SharedSystem};
+    ///
+    /// let shared = SharedSystem::new(
+    ///     RefreshKind::nothing().with_processes(ProcessRefreshKind::everything()),
+    /// );
+    /// ```
+    pub fn new(refreshes: RefreshKind) -> Self {
+        let system = System::new_with_specifics(refreshes);
+        let snapshot = SystemSnapshot::new(&system);
+        Self {
+            system: Mutex::new(system),
+            refreshes,
+            snapshot: RwLock::new(Arc::new(snapshot)),
+        }
+    }
+
+    /// Returns the latest [`SystemSnapshot`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{RefreshKind, SharedSystem};
+    ///
+    /// let shared = SharedSystem::new(RefreshKind::everything());
+    /// println!("{} CPUs", shared.load().cpus().len());
+    /// ```
+    pub fn load(&self) -> Arc<SystemSnapshot> {
+        Arc::clone(&self.snapshot.read().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Refreshes the information covered by the [`RefreshKind`] given to [`SharedSystem::new`]
+    /// and makes it available to the next calls to [`SharedSystem::load`].
+    ///
+    /// If another thread is already refreshing, it waits for it to be done first.
+    ///
+    /// ```no_run
+    /// use sysinfo::{RefreshKind, SharedSystem};
+    ///
+    /// let shared = SharedSystem::new(RefreshKind::everything());
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// shared.refresh();
+    /// ```
+    pub fn refresh(&self) {
+        let mut system = self.system.lock().unwrap_or_else(|e| e.into_inner());
+        system.refresh_specifics(self.refreshes);
+        let snapshot = Arc::new(SystemSnapshot::new(&system));
+        *self.snapshot.write().unwrap_or_else(|e| e.into_inner()) = snapshot;
+    }
+}
+
+/// Immutable copy of the information of a [`System`].
+///
+/// It is returned by [`System::snapshot`] and by [`SharedSystem::load`] (which only contains
+/// the information covered by its [`RefreshKind`]). Two snapshots can be compared with
+/// [`SystemSnapshot::diff`].
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let s = System::new_all();
+/// let snapshot = s.snapshot();
+/// println!("{} processes", snapshot.processes().len());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SystemSnapshot {
+    total_memory: u64,
+    free_memory: u64,
+    available_memory: u64,
+    used_memory: u64,
+    total_swap: u64,
+    free_swap: u64,
+    used_swap: u64,
+    global_cpu_usage: f32,
+    cpus: Vec<CpuSnapshot>,
+    processes: Vec<ProcessSnapshot>,
+    #[cfg(feature = "disk")]
+    disks: Vec<DiskSnapshot>,
+    #[cfg(feature = "network")]
+    networks: Vec<NetworkSnapshot>,
+}
+
+impl SystemSnapshot {
+    pub(crate) fn new(system: &System) -> Self {
+        Self {
+            total_memory: system.total_memory(),
+            free_memory: system.free_memory(),
+            available_memory: system.available_memory(),
+            used_memory: system.used_memory(),
+            total_swap: system.total_swap(),
+            free_swap: system.free_swap(),
+            used_swap: system.used_swap(),
+            global_cpu_usage: system.global_cpu_usage(),
+            cpus: system
+                .cpus()
+                .iter()
+                .map(|cpu| CpuSnapshot {
+                    name: cpu.name().to_owned(),
+                    cpu_usage: cpu.cpu_usage(),
+                    frequency: cpu.frequency(),
+                })
+                .collect(),
+            processes: system
+                .processes()
+                .values()
+                .map(ProcessSnapshot::new)
+                .collect(),
+            #[cfg(feature = "disk")]
+            disks: Vec::new(),
+            #[cfg(feature = "network")]
+            networks: Vec::new(),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn with_processes(mut self, processes: Vec<ProcessSnapshot>) -> Self {
+        self.processes = processes;
+        self
+    }
+
+    /// Adds a copy of the space information of `disks` to the snapshot, so it is part of
+    /// [`SystemSnapshot::diff`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Disks, System};
+    ///
+    /// let s = System::new();
+    /// let disks = Disks::new_with_refreshed_list();
+    /// let snapshot = s.snapshot().with_disks(&disks);
+    /// ```
+    #[cfg(feature = "disk")]
+    pub fn with_disks(mut self, disks: &crate::Disks) -> Self {
+        self.disks = disks
+            .list()
+            .iter()
+            .map(|disk| DiskSnapshot {
+                mount_point: disk.mount_point().to_owned(),
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+            })
+            .collect();
+        self
+    }
+
+    /// Adds a copy of the traffic counters of `networks` to the snapshot, so it is part of
+    /// [`SystemSnapshot::diff`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Networks, System};
+    ///
+    /// let s = System::new();
+    /// let networks = Networks::new_with_refreshed_list();
+    /// let snapshot = s.snapshot().with_networks(&networks);
+    /// ```
+    #[cfg(feature = "network")]
+    pub fn with_networks(mut self, networks: &crate::Networks) -> Self {
+        self.networks = networks
+            .list()
+            .iter()
+            .map(|(interface_name, network)| NetworkSnapshot {
+                interface_name: interface_name.clone(),
+                total_received: network.total_received(),
+                total_transmitted: network.total_transmitted(),
+            })
+            .collect();
+        self
+    }
+
+    /// Returns the changes from this snapshot to the `newer` one, reporting any change of memory
+    /// or CPU usage of the processes.
+    ///
+    /// The processes are identified by their PID and their start time, so a process whose PID
+    /// was reused by a new process is reported as removed and the new one as added.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// let before = s.snapshot();
+    /// std::thread::sleep(std::time::Duration::from_secs(5));
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// let diff = before.diff(&s.snapshot());
+    /// println!("{} processes exited", diff.removed_processes().len());
+    /// ```
+    pub fn diff(&self, newer: &SystemSnapshot) -> SystemDiff {
+        self.diff_with_thresholds(newer, DiffThresholds::default())
+    }
+
+    /// Same as [`SystemSnapshot::diff`], but only the processes whose memory or CPU usage
+    /// changed by more than `thresholds` are part of [`SystemDiff::changed_processes`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{DiffThresholds, ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// let before = s.snapshot();
+    /// std::thread::sleep(std::time::Duration::from_secs(5));
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// let thresholds = DiffThresholds::default()
+    ///     .with_memory(100 * 1024 * 1024)
+    ///     .with_cpu_usage(f32::INFINITY);
+    /// for process in before.diff_with_thresholds(&s.snapshot(), thresholds).changed_processes() {
+    ///     println!("{:?} grew by {} bytes", process.name(), process.memory());
+    /// }
+    /// ```
+    pub fn diff_with_thresholds(
+        &self,
+        newer: &SystemSnapshot,
+        thresholds: DiffThresholds,
+    ) -> SystemDiff {
+        SystemDiff::new(self, newer, thresholds)
+    }
+
+    /// Returns the RAM size in bytes. See [`System::total_memory`].
+    pub fn total_memory(&self) -> u64 {
+        self.total_memory
+    }
+
+    /// Returns the amount of free RAM in bytes. See [`System::free_memory`].
+    pub fn free_memory(&self) -> u64 {
+        self.free_memory
+    }
+
+    /// Returns the amount of available RAM in bytes. See [`System::available_memory`].
+    pub fn available_memory(&self) -> u64 {
+        self.available_memory
+    }
+
+    /// Returns the amount of used RAM in bytes. See [`System::used_memory`].
+    pub fn used_memory(&self) -> u64 {
+        self.used_memory
+    }
+
+    /// Returns the SWAP size in bytes. See [`System::total_swap`].
+    pub fn total_swap(&self) -> u64 {
+        self.total_swap
+    }
+
+    /// Returns the amount of free SWAP in bytes. See [`System::free_swap`].
+    pub fn free_swap(&self) -> u64 {
+        self.free_swap
+    }
+
+    /// Returns the amount of used SWAP in bytes. See [`System::used_swap`].
+    pub fn used_swap(&self) -> u64 {
+        self.used_swap
+    }
+
+    /// Returns the "global" CPU usage (percentage). See [`System::global_cpu_usage`].
+    pub fn global_cpu_usage(&self) -> f32 {
+        self.global_cpu_usage
+    }
+
+    /// Returns the list of the CPUs. See [`System::cpus`].
+    pub fn cpus(&self) -> &[CpuSnapshot] {
+        &self.cpus
+    }
+
+    /// Returns the list of the processes. See [`System::processes`].
+    pub fn processes(&self) -> &[ProcessSnapshot] {
+        &self.processes
+    }
+
+    #[cfg(feature = "disk")]
+    pub(crate) fn disks(&self) -> &[DiskSnapshot] {
+        &self.disks
+    }
+
+    #[cfg(feature = "network")]
+    pub(crate) fn networks(&self) -> &[NetworkSnapshot] {
+        &self.networks
+    }
+}
+
+/// Immutable copy of the information of a [`Cpu`][crate::Cpu].
+///
+/// It is returned by [`SystemSnapshot::cpus`].
+#[derive(Clone, Debug)]
+pub struct CpuSnapshot {
+    name: String,
+    cpu_usage: f32,
+    frequency: u64,
+}
+
+impl CpuSnapshot {
+    /// Returns this CPU's name. See [`Cpu::name`][crate::Cpu::name].
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns this CPU's usage (percentage). See [`Cpu::cpu_usage`][crate::Cpu::cpu_usage].
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    /// Returns this CPU's frequency (in MHz). See [`Cpu::frequency`][crate::Cpu::frequency].
+    pub fn frequency(&self) -> u64 {
+        self.frequency
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SharedSystem;
+    use crate::{CpuRefreshKind, MemoryRefreshKind, ProcessRefreshKind, RefreshKind};
+    use std::sync::Arc;
+
+    #[test]
+    fn check_shared_system_snapshots() {
+        let shared = SharedSystem::new(
+            RefreshKind::nothing()
+                .with_memory(MemoryRefreshKind::everything())
+                .with_cpu(CpuRefreshKind::everything())
+                .with_processes(ProcessRefreshKind::nothing()),
+        );
+        let first = shared.load();
+
+        assert!(Arc::ptr_eq(&first, &shared.load()));
+        if crate::IS_SUPPORTED_SYSTEM {
+            assert!(first.total_memory() > 0);
+            assert!(!first.cpus().is_empty());
+            assert!(!first.processes().is_empty());
+        }
+
+        shared.refresh();
+        let second = shared.load();
+        assert!(!Arc::ptr_eq(&first, &second));
+        // These values are not expected to change between two refreshes.
+        assert_eq!(first.cpus().len(), second.cpus().len());
+        assert_eq!(first.total_memory(), second.total_memory());
+    }
+}
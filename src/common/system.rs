@@ -2,14 +2,19 @@
 
 use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
-use std::fmt;
 use std::path::Path;
 use std::process::ExitStatus;
-use std::str::FromStr;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime};
 
-use crate::common::impl_get_set::impl_get_set;
+use crate::common::impl_get_set::{impl_get_set, impl_without_nested};
+use crate::common::rapl::RaplEnergyTracker;
 use crate::common::DiskUsage;
-use crate::{CpuInner, Gid, ProcessInner, SystemInner, Uid};
+use crate::{
+    ByteSize, CpuArchDetail, CpuInner, FieldStatus, Gid, Pid, ProcessField, ProcessInner,
+    ProcessTree, ProcessWatcher, RaplEnergy, SystemInner, SystemSnapshot, Uid, Virtualization,
+    WatchProcessesError,
+};
 
 /// Structs containing system's information such as processes, memory and CPU.
 ///
@@ -27,6 +32,9 @@ use crate::{CpuInner, Gid, ProcessInner, SystemInner, Uid};
 /// ```
 pub struct System {
     pub(crate) inner: SystemInner,
+    cpu_usage_history_len: usize,
+    name_index: Option<ProcessNameIndex>,
+    rapl: RaplEnergyTracker,
 }
 
 impl Default for System {
@@ -67,6 +75,41 @@ impl System {
         Self::new_with_specifics(RefreshKind::everything())
     }
 
+    /// Creates a new [`System`] instance with everything loaded, including accurate CPU usage.
+    ///
+    /// Unlike [`System::new_all`], in which the CPU usage of the CPUs and of the processes is
+    /// always `0`, it refreshes the CPU usage a second time after
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`], so it can be used right away in scripts.
+    ///
+    /// ⚠️ This method blocks the current thread for [`MINIMUM_CPU_UPDATE_INTERVAL`].
+    ///
+    /// The components, disks and networks aren't part of [`System`]. `Disks::new_all_refreshed`
+    /// and `Networks::new_all_refreshed` similarly wait to retrieve their usage, whereas
+    /// `Components::new_with_refreshed_list` already returns the current temperatures.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all_refreshed();
+    /// println!("CPU usage: {}%", s.global_cpu_usage());
+    /// for (pid, process) in s.processes() {
+    ///     println!("[{pid}] {:?} {}%", process.name(), process.cpu_usage());
+    /// }
+    /// ```
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    pub fn new_all_refreshed() -> Self {
+        let mut s = Self::new_all();
+        std::thread::sleep(crate::MINIMUM_CPU_UPDATE_INTERVAL);
+        s.refresh_cpu_usage();
+        s.refresh_processes_specifics(
+            ProcessesToUpdate::All,
+            true,
+            ProcessRefreshKind::nothing().with_cpu(),
+        );
+        s
+    }
+
     /// Creates a new [`System`] instance and refresh the data corresponding to the
     /// given [`RefreshKind`].
     ///
@@ -87,11 +130,62 @@ impl System {
     pub fn new_with_specifics(refreshes: RefreshKind) -> Self {
         let mut s = Self {
             inner: SystemInner::new(),
+            cpu_usage_history_len: 0,
+            name_index: None,
+            rapl: RaplEnergyTracker::default(),
         };
         s.refresh_specifics(refreshes);
         s
     }
 
+    /// Makes each [`Cpu`] keep its last `len` usage values, returned by
+    /// [`Cpu::usage_history`]. A value is added every time the CPUs usage is refreshed.
+    ///
+    /// By default (or if `len` is `0`), no history is kept.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new().with_cpu_usage_history(60);
+    /// for _ in 0..10 {
+    ///     std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    ///     s.refresh_cpu_usage();
+    /// }
+    /// for cpu in s.cpus() {
+    ///     println!("{}: {:?}", cpu.name(), cpu.usage_history());
+    /// }
+    /// ```
+    pub fn with_cpu_usage_history(mut self, len: usize) -> Self {
+        self.cpu_usage_history_len = len;
+        for cpu in self.inner.cpus_mut() {
+            truncate_usage_history(&mut cpu.usage_history, len);
+        }
+        self
+    }
+
+    /// Makes [`System`] keep an index of the processes by name, used by
+    /// [`System::processes_by_exact_name`] to not go through all processes.
+    ///
+    /// The index is updated when processes are refreshed, including for the processes which
+    /// changed their name since the previous refresh. By default, no index is kept.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all().with_process_name_index(true);
+    /// for process in s.processes_by_exact_name("htop".as_ref()) {
+    ///     println!("{} {:?}", process.pid(), process.name());
+    /// }
+    /// ```
+    pub fn with_process_name_index(mut self, enabled: bool) -> Self {
+        self.name_index = match self.name_index.take() {
+            Some(index) if enabled => Some(index),
+            None if enabled => Some(ProcessNameIndex::new(self.inner.processes())),
+            _ => None,
+        };
+        self
+    }
+
     /// Refreshes according to the given [`RefreshKind`]. It calls the corresponding
     /// "refresh_" methods.
     ///
@@ -159,16 +253,48 @@ impl System {
 
     /// Refreshes system memory specific information.
     ///
+    /// On Linux and Android, the RAM and the swap information come from the same read of
+    /// `/proc/meminfo`, so both are always updated. On the other platforms, the swap is retrieved
+    /// with its own system calls (`GetPerformanceInfo` on Windows, the `vm.swapusage` sysctl on
+    /// macOS and `kvm_getswapinfo` on FreeBSD) which are skipped if
+    /// [`MemoryRefreshKind::swap`] is `false`.
+    ///
     /// ```no_run
     /// use sysinfo::{MemoryRefreshKind, System};
     ///
     /// let mut s = System::new();
     /// s.refresh_memory_specifics(MemoryRefreshKind::nothing().with_ram());
+    /// // Or, to refresh everything but the swap:
+    /// s.refresh_memory_specifics(MemoryRefreshKind::everything().without_swap());
     /// ```
     pub fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
         self.inner.refresh_memory_specifics(refresh_kind)
     }
 
+    /// Refreshes RAM and SWAP usage like [`System::refresh_memory`], but returns the error which
+    /// prevented it instead of ignoring it.
+    ///
+    /// The values which couldn't be retrieved keep their previous value (or `0` if they were never
+    /// retrieved).
+    ///
+    /// The failures are reported on Linux and Android (when `/proc/meminfo` can't be read), on
+    /// Windows (when `GlobalMemoryStatusEx` fails), on macOS and iOS (when `sysctl` or
+    /// `host_statistics64` fails) and on FreeBSD (when `sysctl` or `kvm_getswapinfo` fails). On
+    /// the other platforms, `Ok` is always returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// if let Err(error) = s.try_refresh_memory() {
+    ///     eprintln!("cannot get the memory information: {error}");
+    /// }
+    /// ```
+    pub fn try_refresh_memory(&mut self) -> Result<(), crate::RefreshError> {
+        self.inner
+            .try_refresh_memory_specifics(MemoryRefreshKind::everything())
+    }
+
     /// Refreshes CPUs usage.
     ///
     /// ⚠️ Please note that the result will very likely be inaccurate at the first call.
@@ -194,6 +320,30 @@ impl System {
         self.refresh_cpu_specifics(CpuRefreshKind::nothing().with_cpu_usage())
     }
 
+    /// Refreshes CPUs usage, computed over `interval`.
+    ///
+    /// It refreshes CPUs usage once, sleeps for `interval` and then refreshes CPUs usage
+    /// again, so you get an accurate value in one call, even if CPUs usage was never
+    /// refreshed before.
+    ///
+    /// ⚠️ This method blocks the current thread for `interval`. If `interval` is shorter than
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`], the computed value might be inaccurate.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_cpu_over(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// println!("{}%", s.global_cpu_usage());
+    /// ```
+    ///
+    /// [`MINIMUM_CPU_UPDATE_INTERVAL`]: crate::MINIMUM_CPU_UPDATE_INTERVAL
+    pub fn refresh_cpu_over(&mut self, interval: Duration) {
+        self.refresh_cpu_usage();
+        std::thread::sleep(interval);
+        self.refresh_cpu_usage();
+    }
+
     /// Refreshes CPUs frequency information.
     ///
     /// Calling this method is the same as calling
@@ -263,7 +413,14 @@ impl System {
     /// s.refresh_cpu_specifics(CpuRefreshKind::everything());
     /// ```
     pub fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
-        self.inner.refresh_cpu_specifics(refresh_kind)
+        self.inner.refresh_cpu_specifics(refresh_kind);
+        let len = self.cpu_usage_history_len;
+        if len != 0 && refresh_kind.cpu_usage() {
+            for cpu in self.inner.cpus_mut() {
+                let usage = cpu.cpu_usage();
+                push_usage_history(&mut cpu.usage_history, len, usage);
+            }
+        }
     }
 
     /// Gets all processes and updates their information, along with all the tasks each process has.
@@ -391,9 +548,112 @@ impl System {
                 }
             }
         }
+        if let Some(index) = self.name_index.as_mut() {
+            let processes = self.inner.processes();
+            match processes_to_update {
+                ProcessesToUpdate::All => index.update_all(processes),
+                ProcessesToUpdate::Some(pids) => {
+                    for pid in pids {
+                        index.update(*pid, processes);
+                    }
+                }
+            }
+        }
         nb_updated
     }
 
+    /// Sets the number of threads used to refresh the processes.
+    ///
+    /// By default, or if `threads` is `0`, the processes are refreshed on the global `rayon`
+    /// thread pool, which has one thread per CPU. Passing `1` refreshes them sequentially. The
+    /// refreshed information doesn't depend on the number of threads.
+    ///
+    /// ⚠️ It is only used on Linux and macOS when the `multithread` feature is enabled. Otherwise,
+    /// it does nothing.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// s.set_refresh_parallelism(4);
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// ```
+    pub fn set_refresh_parallelism(&mut self, threads: usize) {
+        self.inner.set_refresh_parallelism(threads);
+    }
+
+    /// Refreshes only the processes in `pids` and updates the specified information.
+    ///
+    /// The processes of the list which appeared since the last refresh are added, and the ones
+    /// which disappeared are kept but marked as not existing anymore (take a look at
+    /// [`Process::exists`]). The CPU usage of the already known processes is computed since their
+    /// previous refresh, like with [`System::refresh_processes_specifics`].
+    ///
+    /// It is cheaper than refreshing all processes when tracking a few known processes: on
+    /// Linux, only the `/proc/[PID]` folders of the given processes are read.
+    ///
+    /// Returns the number of updated processes.
+    ///
+    /// ⚠️ The processes which are not in `pids` are left untouched, so their information can be
+    /// outdated and they are kept even if they don't exist anymore.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, ProcessRefreshKind, System};
+    ///
+    /// let pids = [Pid::from(1), Pid::from(1337)];
+    /// let mut s = System::new();
+    /// s.refresh_pids(&pids, ProcessRefreshKind::nothing().with_cpu().with_memory());
+    /// for pid in pids {
+    ///     if let Some(process) = s.process(pid).filter(|process| process.exists()) {
+    ///         println!("{pid}: {}% CPU", process.cpu_usage());
+    ///     }
+    /// }
+    /// ```
+    pub fn refresh_pids(&mut self, pids: &[Pid], refresh_kind: ProcessRefreshKind) -> usize {
+        self.refresh_processes_specifics(ProcessesToUpdate::Some(pids), false, refresh_kind)
+    }
+
+    /// Does the same as [`System::refresh_processes`] but also returns which processes were
+    /// added, removed or changed, so only those need to be handled.
+    ///
+    /// A process is considered as changed if its name or its status changed, if its CPU usage
+    /// changed by more than 10 percentage points, or if its memory usage changed by more than 10%.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// let changes = s.refresh_processes_with_changes(ProcessesToUpdate::All, true);
+    /// for pid in changes.added() {
+    ///     println!("new process: {pid}");
+    /// }
+    /// for pid in changes.removed() {
+    ///     println!("process is gone: {pid}");
+    /// }
+    /// ```
+    pub fn refresh_processes_with_changes(
+        &mut self,
+        processes_to_update: ProcessesToUpdate<'_>,
+        remove_dead_processes: bool,
+    ) -> ProcessChanges {
+        let track = |processes: &HashMap<Pid, Process>| -> HashMap<Pid, TrackedProcess> {
+            match processes_to_update {
+                ProcessesToUpdate::All => processes
+                    .iter()
+                    .map(|(pid, process)| (*pid, TrackedProcess::new(process)))
+                    .collect(),
+                ProcessesToUpdate::Some(pids) => pids
+                    .iter()
+                    .filter_map(|pid| Some((*pid, TrackedProcess::new(processes.get(pid)?))))
+                    .collect(),
+            }
+        };
+
+        let before = track(self.processes());
+        self.refresh_processes(processes_to_update, remove_dead_processes);
+        ProcessChanges::new(&before, &track(self.processes()))
+    }
+
     /// Returns the process list.
     ///
     /// ```no_run
@@ -408,6 +668,40 @@ impl System {
         self.inner.processes()
     }
 
+    /// Returns the processes sorted by `key`, in ascending order or in descending order if
+    /// `descending` is `true`.
+    ///
+    /// The processes with the same `key` are sorted by PID, so the order doesn't change between
+    /// two calls if nothing changed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessSortKey, System};
+    ///
+    /// let s = System::new_all();
+    /// for process in s.processes_sorted_by(ProcessSortKey::Memory, true).iter().take(10) {
+    ///     println!("{} {:?}: {} bytes", process.pid(), process.name(), process.memory());
+    /// }
+    /// ```
+    pub fn processes_sorted_by(&self, key: ProcessSortKey, descending: bool) -> Vec<&Process> {
+        let mut processes: Vec<&Process> = self.processes().values().collect();
+        processes.sort_unstable_by(|a, b| {
+            let ordering = match key {
+                ProcessSortKey::CpuUsage => a.cpu_usage().total_cmp(&b.cpu_usage()),
+                ProcessSortKey::Memory => a.memory().cmp(&b.memory()),
+                ProcessSortKey::Pid => std::cmp::Ordering::Equal,
+                ProcessSortKey::Name => a.name().cmp(b.name()),
+                ProcessSortKey::StartTime => a.start_time().cmp(&b.start_time()),
+            }
+            .then_with(|| a.pid().cmp(&b.pid()));
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        processes
+    }
+
     /// Returns the process corresponding to the given `pid` or `None` if no such process exists.
     ///
     /// ```no_run
@@ -422,6 +716,68 @@ impl System {
         self.inner.process(pid)
     }
 
+    /// Returns the processes arranged by their parent, starting from the process with the given
+    /// `root` PID or, if `None`, from all the processes without parent.
+    ///
+    /// The processes whose parent isn't in the processes list (because it exited or because
+    /// only some processes were refreshed) are at the top of the tree. On Linux, the threads
+    /// are not part of the tree, use [`Process::tasks`] to get them.
+    ///
+    /// It is more efficient than looking for the children of each process in
+    /// [`System::processes`], since the list is only traversed once.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// print!("{}", s.process_tree(None));
+    /// ```
+    pub fn process_tree(&self, root: Option<Pid>) -> ProcessTree<'_> {
+        ProcessTree::new(self.processes(), root)
+    }
+
+    /// Returns the number of processes whose parent is `pid`.
+    ///
+    /// Only the direct children are counted, not their own children. It is cheaper than building
+    /// the whole tree with [`System::process_tree`]. On Linux, the threads are not counted.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// for process in s.processes_by_exact_name("nginx".as_ref()) {
+    ///     println!("{}: {} workers", process.pid(), s.child_count(process.pid()));
+    /// }
+    /// ```
+    pub fn child_count(&self, pid: Pid) -> usize {
+        self.processes()
+            .values()
+            .filter(|process| {
+                process.parent() == Some(pid) && process.thread_kind() != Some(ThreadKind::Userland)
+            })
+            .count()
+    }
+
+    /// Returns an owned copy of the memory, CPU and processes information, which can be compared
+    /// with another one later on with [`SystemSnapshot::diff`].
+    ///
+    /// The disks and networks information can be added with [`SystemSnapshot::with_disks`] and
+    /// [`SystemSnapshot::with_networks`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// let before = s.snapshot();
+    /// std::thread::sleep(std::time::Duration::from_secs(5));
+    /// s.refresh_processes(ProcessesToUpdate::All, true);
+    /// let diff = before.diff(&s.snapshot());
+    /// println!("{} new processes", diff.added_processes().len());
+    /// ```
+    pub fn snapshot(&self) -> SystemSnapshot {
+        SystemSnapshot::new(self)
+    }
+
     /// Returns an iterator of process containing the given `name`.
     ///
     /// If you want only the processes with exactly the given `name`, take a look at
@@ -474,13 +830,123 @@ impl System {
         &'a self,
         name: &'b OsStr,
     ) -> impl Iterator<Item = &'a Process> + 'b {
+        let processes = self.processes();
+        let (indexed, all) = match self.name_index.as_ref() {
+            Some(index) => (Some(index.pids(name)), None),
+            None => (None, Some(processes.values())),
+        };
+        indexed
+            .into_iter()
+            .flatten()
+            .filter_map(move |pid| processes.get(pid))
+            .chain(all.into_iter().flatten())
+            .filter(move |val: &&Process| val.name() == name)
+    }
+
+    /// Returns an iterator of processes whose executable path is `path`.
+    ///
+    /// Processes for which the executable path couldn't be retrieved (see [`Process::exe`])
+    /// are skipped.
+    ///
+    /// If `canonicalize` is `true`, both `path` and the processes' executable paths are
+    /// canonicalized (symlinks resolved) before being compared. If a path cannot be
+    /// canonicalized, it is compared as is. Please note that it means a filesystem access
+    /// for each process.
+    ///
+    /// ⚠️ The executable path is only retrieved if [`ProcessRefreshKind::exe`] was enabled
+    /// when refreshing processes.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    /// use std::path::Path;
+    ///
+    /// let s = System::new_all();
+    /// for process in s.processes_by_exe(Path::new("/usr/bin/htop"), false) {
+    ///     println!("{} {:?}", process.pid(), process.name());
+    /// }
+    /// ```
+    pub fn processes_by_exe<'a: 'b, 'b>(
+        &'a self,
+        path: &'b Path,
+        canonicalize: bool,
+    ) -> impl Iterator<Item = &'a Process> + 'b {
+        let canonical_path = if canonicalize {
+            path.canonicalize().ok()
+        } else {
+            None
+        };
+        self.processes().values().filter(move |val: &&Process| {
+            let Some(exe) = val.exe() else {
+                return false;
+            };
+            if !canonicalize {
+                return exe == path;
+            }
+            let path = canonical_path.as_deref().unwrap_or(path);
+            match exe.canonicalize() {
+                Ok(exe) => exe == path,
+                Err(_) => exe == path,
+            }
+        })
+    }
+
+    /// Returns an iterator of processes owned by the user `uid`.
+    ///
+    /// The owner is the real user ID of the process (see [`Process::user_id`]), not its
+    /// effective one. Processes for which it couldn't be retrieved are skipped.
+    ///
+    /// ⚠️ The user ID is only retrieved if [`ProcessRefreshKind::user`] was enabled when
+    /// refreshing processes.
+    ///
+    /// ```no_run
+    /// use sysinfo::{get_current_pid, System};
+    ///
+    /// let s = System::new_all();
+    /// let current = s.process(get_current_pid().unwrap()).unwrap();
+    /// if let Some(uid) = current.user_id() {
+    ///     for process in s.processes_by_user(uid.clone()) {
+    ///         println!("{} {:?}", process.pid(), process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn processes_by_user(&self, uid: Uid) -> impl Iterator<Item = &Process> + '_ {
         self.processes()
             .values()
-            .filter(move |val: &&Process| val.name() == name)
+            .filter(move |val: &&Process| val.user_id() == Some(&uid))
+    }
+
+    /// Returns an iterator of processes whose group is `gid`.
+    ///
+    /// The group is the real group ID of the process (see [`Process::group_id`]), not its
+    /// effective one. Processes for which it couldn't be retrieved are skipped.
+    ///
+    /// ⚠️ The group ID is only retrieved if [`ProcessRefreshKind::user`] was enabled when
+    /// refreshing processes. On Windows, it is never retrieved so this iterator is always
+    /// empty.
+    ///
+    /// ```no_run
+    /// use sysinfo::{get_current_pid, System};
+    ///
+    /// let s = System::new_all();
+    /// let current = s.process(get_current_pid().unwrap()).unwrap();
+    /// if let Some(gid) = current.group_id() {
+    ///     for process in s.processes_by_group(gid) {
+    ///         println!("{} {:?}", process.pid(), process.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn processes_by_group(&self, gid: Gid) -> impl Iterator<Item = &Process> + '_ {
+        self.processes()
+            .values()
+            .filter(move |val: &&Process| val.group_id() == Some(gid))
     }
 
     /// Returns "global" CPUs usage (aka the addition of all the CPUs).
     ///
+    /// This value is computed from the aggregated CPU times provided by the system (like the
+    /// `cpu` line of `/proc/stat` on Linux) and not from the average of each CPU usage, so it
+    /// might slightly differ from it.
+    ///
     /// To have up-to-date information, you need to call [`System::refresh_cpu_specifics`] or
     /// [`System::refresh_specifics`] with `cpu` enabled.
     ///
@@ -576,6 +1042,12 @@ impl System {
 
     /// Returns the amount of used RAM in bytes.
     ///
+    /// How it is computed depends on the platform: on macOS and FreeBSD, it is the sum of the
+    /// pages in use reported by the kernel, so it doesn't always match the difference between
+    /// [`total_memory`](System::total_memory) and [`available_memory`](System::available_memory).
+    /// If you want the same value as `free` or `top`, use
+    /// [`used_memory_like_free`](System::used_memory_like_free).
+    ///
     /// ```no_run
     /// use sysinfo::System;
     ///
@@ -586,6 +1058,27 @@ impl System {
         self.inner.used_memory()
     }
 
+    /// Returns the amount of used RAM in bytes, computed like `free` does: total memory minus
+    /// available memory.
+    ///
+    /// On Linux, the available memory is the `MemAvailable` value of `/proc/meminfo`, which was
+    /// added so tools don't have to guess how much of the buffers and cached memory can be
+    /// reclaimed (the older "total - free - buffers - cached" estimation). It is the same value
+    /// as [`used_memory`](System::used_memory) on Linux and Windows.
+    ///
+    /// The returned value and [`available_memory`](System::available_memory) always add up to
+    /// [`total_memory`](System::total_memory).
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    /// println!("{} bytes", s.used_memory_like_free());
+    /// ```
+    pub fn used_memory_like_free(&self) -> u64 {
+        self.total_memory().saturating_sub(self.available_memory())
+    }
+
     /// Returns the SWAP size in bytes.
     ///
     /// ```no_run
@@ -642,61 +1135,298 @@ impl System {
         self.inner.cgroup_limits()
     }
 
-    /// Returns system uptime (in seconds).
+    /// Refreshes the energy consumed by the CPU packages, returned by [`System::rapl_energy`].
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// ⚠️ It is only implemented for Intel CPUs on Linux, where the energy counters are read from
+    /// `/sys/class/powercap/intel-rapl:*`. Reading them usually requires root privileges. It
+    /// does nothing on the other systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// println!("System running since {} seconds", System::uptime());
+    /// let mut s = System::new();
+    /// s.refresh_rapl_energy();
     /// ```
-    pub fn uptime() -> u64 {
-        SystemInner::uptime()
+    pub fn refresh_rapl_energy(&mut self) {
+        self.rapl.refresh();
     }
 
-    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    /// Returns the cumulative energy consumed by each CPU package since the first call to
+    /// [`System::refresh_rapl_energy`], so all the values are `0` after that first call.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// The wraparounds of the RAPL counters between two refreshes are taken into account, so the
+    /// values only ever increase as long as the refreshes are frequent enough for a counter not
+    /// to wrap around more than once (it takes minutes at the very least). Dividing the energy
+    /// consumed between two refreshes by the time elapsed between them gives the power in watts.
+    ///
+    /// Returns `None` if the energy counters couldn't be read during the last refresh (or if
+    /// [`System::refresh_rapl_energy`] was never called). Only Intel CPUs on Linux are
+    /// supported, so it always returns `None` on AMD and non-x86 CPUs and on the other systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
+    /// use std::time::{Duration, Instant};
     ///
-    /// println!("System booted at {} seconds", System::boot_time());
+    /// let mut s = System::new();
+    /// s.refresh_rapl_energy();
+    /// let start = Instant::now();
+    /// let before: Vec<u64> = s
+    ///     .rapl_energy()
+    ///     .unwrap_or_default()
+    ///     .iter()
+    ///     .map(|package| package.package())
+    ///     .collect();
+    /// std::thread::sleep(Duration::from_secs(1));
+    /// s.refresh_rapl_energy();
+    /// let elapsed = start.elapsed().as_secs_f64();
+    /// for (package, before) in s.rapl_energy().unwrap_or_default().iter().zip(before) {
+    ///     let watts = (package.package() - before) as f64 / 1_000_000. / elapsed;
+    ///     println!("package {}: {watts:.1} W", package.package_id());
+    /// }
     /// ```
-    pub fn boot_time() -> u64 {
-        SystemInner::boot_time()
+    pub fn rapl_energy(&self) -> Option<&[RaplEnergy]> {
+        self.rapl.energy()
     }
 
-    /// Returns the system load average value.
+    /// Returns the number of CPUs the processes of the current cgroup can use.
     ///
-    /// **Important**: this information is computed every time this function is called.
+    /// When a CPU quota is set for the cgroup (like with `docker run --cpus=2.5`), it returns
+    /// the quota divided by the period, so the result can be fractional: `2.5` means that the
+    /// processes can use up to two and a half CPUs worth of time. It is useful to compute a CPU
+    /// usage relative to what is actually available inside of a container.
     ///
-    /// ⚠️ This is currently not working on **Windows**.
+    /// Otherwise, or if the quota is higher than the number of CPUs, it returns the number of
+    /// logical CPUs, like [`System::cpus`] does. If the CPUs list wasn't refreshed yet, the
+    /// number returned by [`std::thread::available_parallelism`] is used instead.
+    ///
+    /// This information is computed every time the method is called.
+    ///
+    /// ⚠️ The CPU quota is only retrieved on Linux, from the `cpu.max` file with cgroups v2 and
+    /// from the `cpu.cfs_quota_us` and `cpu.cfs_period_us` files with cgroups v1. The number of
+    /// logical CPUs is always returned on the other systems.
     ///
     /// ```no_run
     /// use sysinfo::System;
     ///
-    /// let load_avg = System::load_average();
-    /// println!(
-    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
-    ///     load_avg.one,
-    ///     load_avg.five,
-    ///     load_avg.fifteen,
-    /// );
+    /// let s = System::new_all();
+    /// println!("{} CPUs available", s.effective_cpu_count());
     /// ```
-    pub fn load_average() -> LoadAvg {
-        SystemInner::load_average()
+    pub fn effective_cpu_count(&self) -> f64 {
+        let logical_count = match self.cpus().len() {
+            0 => std::thread::available_parallelism().map_or(1, |count| count.get()),
+            count => count,
+        } as f64;
+        self.inner
+            .cgroup_cpu_quota()
+            .map_or(logical_count, |quota| quota.min(logical_count))
     }
 
-    /// Returns the system name.
+    /// Returns system uptime (in seconds).
     ///
-    /// | example platform | value of `System::name()` |
-    /// |---|---|
-    /// | linux laptop | "Ubuntu" |
-    /// | android phone | "Pixel 9 Pro" |
-    /// | apple laptop | "Darwin" |
-    /// | windows server | "Windows" |
+    /// It is the wall-clock time elapsed since the boot, including the time spent suspended, on
+    /// all platforms. Use [`System::active_time`] to exclude the time spent suspended.
+    ///
+    /// | Platform | Source |
+    /// |----------|--------|
+    /// | Linux    | `/proc/uptime` (same as `CLOCK_BOOTTIME`) |
+    /// | Windows  | `GetTickCount64` |
+    /// | macOS    | current time minus `kern.boottime` |
+    /// | FreeBSD  | current time minus `kern.boottime` |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("System running since {} seconds", System::uptime());
+    /// ```
+    pub fn uptime() -> u64 {
+        SystemInner::uptime()
+    }
+
+    /// Returns the time (in seconds) the system was running since the boot, excluding the time
+    /// spent suspended.
+    ///
+    /// It is never greater than [`System::uptime`], and is equal to it on a system which was never
+    /// suspended.
+    ///
+    /// | Platform | Source |
+    /// |----------|--------|
+    /// | Linux    | `CLOCK_MONOTONIC` |
+    /// | Windows  | `QueryUnbiasedInterruptTime` |
+    /// | macOS    | `mach_absolute_time` |
+    /// | FreeBSD  | `CLOCK_UPTIME` |
+    ///
+    /// Returns `0` if the current system is not supported.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let suspended = System::uptime() - System::active_time();
+    /// println!("System suspended for {suspended} seconds since the boot");
+    /// ```
+    pub fn active_time() -> u64 {
+        SystemInner::active_time()
+    }
+
+    /// Returns the number of ticks per second of the clock used to count the CPU time of the
+    /// processes, which is the granularity of [`Process::user_cpu_time`] and
+    /// [`Process::system_cpu_time`].
+    ///
+    /// | Platform | Source |
+    /// |----------|--------|
+    /// | Linux    | `sysconf(_SC_CLK_TCK)` |
+    /// | Windows  | the clock interrupt period from `GetSystemTimeAdjustment` |
+    /// | macOS    | the `mach_timebase_info` ratio, in ticks of `mach_absolute_time` |
+    /// | FreeBSD  | `sysconf(_SC_CLK_TCK)` |
+    ///
+    /// If it cannot be retrieved, `100` is returned on Linux, FreeBSD and on unsupported
+    /// systems, `64` on Windows and `1_000_000_000` on macOS.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let resolution = std::time::Duration::from_secs(1) / System::clock_ticks_per_second() as u32;
+    /// println!("CPU times are counted every {resolution:?}");
+    /// ```
+    pub fn clock_ticks_per_second() -> u64 {
+        SystemInner::clock_ticks_per_second()
+    }
+
+    /// Returns the time (in seconds) when the system booted since UNIX epoch.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("System booted at {} seconds", System::boot_time());
+    /// ```
+    pub fn boot_time() -> u64 {
+        SystemInner::boot_time()
+    }
+
+    /// Returns `true` if the system needs to be rebooted, usually to finish installing updates.
+    ///
+    /// On Windows, it checks the registry entries which are set while a reboot is pending: the
+    /// `RebootPending` key of the servicing stack, the `RebootRequired` key of Windows Update and
+    /// the pending file rename operations.
+    ///
+    /// On Linux, it checks the `/var/run/reboot-required` file created by Debian and Ubuntu.
+    /// Otherwise, it compares the running kernel to the newest kernel installed in `/boot`,
+    /// which is only a heuristic: it returns `Some(true)` when a newer kernel was installed but
+    /// can't detect the other updates needing a reboot. It returns `None` if the running kernel
+    /// isn't installed in `/boot`, like in a container.
+    ///
+    /// It always returns `None` on the other platforms.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::reboot_required() == Some(true) {
+    ///     println!("The system needs to be rebooted");
+    /// }
+    /// ```
+    pub fn reboot_required() -> Option<bool> {
+        SystemInner::reboot_required()
+    }
+
+    /// Returns the system load average value.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This is currently not working on **Windows**.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let load_avg = System::load_average();
+    /// println!(
+    ///     "one minute: {}%, five minutes: {}%, fifteen minutes: {}%",
+    ///     load_avg.one,
+    ///     load_avg.five,
+    ///     load_avg.fifteen,
+    /// );
+    /// ```
+    pub fn load_average() -> LoadAvg {
+        SystemInner::load_average()
+    }
+
+    /// Returns the memory pressure reported by the kernel.
+    ///
+    /// On Linux, it is read from the [PSI] (Pressure Stall Information) `/proc/pressure/memory`
+    /// file, which contains the share of time during which tasks were stalled waiting for
+    /// memory. On macOS, it is the memory pressure level used by the system to decide when to
+    /// reclaim memory.
+    ///
+    /// It returns `None` if it isn't available (on older Linux kernels or kernels built without
+    /// PSI for example) or on other platforms.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// [PSI]: https://docs.kernel.org/accounting/psi.html
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(pressure) = System::memory_pressure() {
+    ///     println!("{pressure:?}");
+    /// }
+    /// ```
+    pub fn memory_pressure() -> Option<Pressure> {
+        SystemInner::memory_pressure()
+    }
+
+    /// Returns the CPU pressure reported by the kernel.
+    ///
+    /// It is only available on Linux, read from the PSI `/proc/pressure/cpu` file. See
+    /// [`System::memory_pressure`] for more information.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(stall) = System::cpu_pressure().and_then(|pressure| pressure.some) {
+    ///     println!("tasks waited for a CPU {}% of the last 10 seconds", stall.avg10);
+    /// }
+    /// ```
+    pub fn cpu_pressure() -> Option<Pressure> {
+        SystemInner::cpu_pressure()
+    }
+
+    /// Returns the I/O pressure reported by the kernel.
+    ///
+    /// It is only available on Linux, read from the PSI `/proc/pressure/io` file. See
+    /// [`System::memory_pressure`] for more information.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(stall) = System::io_pressure().and_then(|pressure| pressure.full) {
+    ///     println!("all tasks waited for I/O {}% of the last minute", stall.avg60);
+    /// }
+    /// ```
+    pub fn io_pressure() -> Option<Pressure> {
+        SystemInner::io_pressure()
+    }
+
+    /// Returns the system name.
+    ///
+    /// | example platform | value of `System::name()` |
+    /// |---|---|
+    /// | linux laptop | "Ubuntu" |
+    /// | android phone | "Pixel 9 Pro" |
+    /// | apple laptop | "Darwin" |
+    /// | windows server | "Windows" |
     ///
     /// **Important**: this information is computed every time this function is called.
     ///
@@ -770,6 +1500,54 @@ impl System {
         SystemInner::long_os_version()
     }
 
+    /// Returns the build of the system, which tells apart the releases sharing the same
+    /// [`os_version`][System::os_version].
+    ///
+    /// It is built from the `CurrentBuildNumber` and `UBR` (the update build revision) registry
+    /// values on Windows, it is `kern.osversion` on macOS (like `sw_vers -buildVersion`) and the
+    /// `BUILD_ID` field of `/etc/os-release` on Linux, which most of the distributions don't set.
+    ///
+    /// | example platform | value of `System::os_build()` |
+    /// |---|---|
+    /// | arch linux laptop | "rolling" |
+    /// | android phone | "AP4A.250105.002" |
+    /// | apple laptop | "24B91" |
+    /// | windows laptop | "22631.4123" |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS build: {:?}", System::os_build());
+    /// ```
+    pub fn os_build() -> Option<String> {
+        SystemInner::os_build()
+    }
+
+    /// Returns the name under which the system release is marketed.
+    ///
+    /// It is the `DisplayVersion` registry value on Windows (or `ReleaseId` before Windows 10
+    /// 20H2), the name of the release on macOS and the `VERSION_CODENAME` field of
+    /// `/etc/os-release` (or `DISTRIB_CODENAME` of `/etc/lsb-release`) on Linux.
+    ///
+    /// | example platform | value of `System::os_display_version()` |
+    /// |---|---|
+    /// | linux laptop | "noble" |
+    /// | apple laptop | "Sequoia" |
+    /// | windows laptop | "23H2" |
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("OS display version: {:?}", System::os_display_version());
+    /// ```
+    pub fn os_display_version() -> Option<String> {
+        SystemInner::os_display_version()
+    }
+
     /// Returns the distribution id as defined by os-release,
     /// or [`std::env::consts::OS`].
     ///
@@ -870,8 +1648,79 @@ impl System {
         SystemInner::host_name()
     }
 
+    /// Returns the name of the domain the machine is a member of.
+    ///
+    /// On Windows, it is the name of the domain or of the workgroup returned by
+    /// `NetGetJoinInformation`, like `CORP` or `WORKGROUP`: take a look at
+    /// [`System::domain_kind`] to know which one it is. On other systems, it is the DNS domain
+    /// of the [`System::fqdn`], or the NIS domain returned by `getdomainname` if the resolver
+    /// doesn't know it.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Domain: {:?}", System::domain_name());
+    /// ```
+    pub fn domain_name() -> Option<String> {
+        SystemInner::domain_name()
+    }
+
+    /// Returns the kind of [`System::domain_name`].
+    ///
+    /// Only Windows has workgroups, so it is always [`DomainKind::Domain`] on other systems
+    /// when the domain is known.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{DomainKind, System};
+    ///
+    /// if System::domain_kind() == Some(DomainKind::Workgroup) {
+    ///     println!("workgroup: {:?}", System::domain_name());
+    /// }
+    /// ```
+    pub fn domain_kind() -> Option<DomainKind> {
+        SystemInner::domain_kind()
+    }
+
+    /// Returns the fully qualified domain name of the machine, like `host.example.com`.
+    ///
+    /// On Windows, it is the DNS host name followed by the primary DNS suffix. On other
+    /// systems, it is the canonical name of the [`System::host_name`] as resolved by
+    /// `getaddrinfo`. It returns `None` if the host name has no DNS domain.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("FQDN: {:?}", System::fqdn());
+    /// ```
+    pub fn fqdn() -> Option<String> {
+        SystemInner::fqdn()
+    }
+
     /// Returns the CPU architecture (eg. x86, amd64, aarch64, ...).
     ///
+    /// It is the native architecture of the machine, even if the current process is emulated
+    /// (with Rosetta 2 on macOS or the x64 emulation of Windows on ARM) or runs with WOW64 on
+    /// Windows. If it cannot be retrieved, [`System::process_arch`] is returned.
+    ///
+    /// The three architectures differ in these cases:
+    ///
+    /// | Current process | `cpu_arch` | [`kernel_arch`] | [`process_arch`] | [`is_translated`] |
+    /// |-|-|-|-|-|
+    /// | x86 on x64 Windows (WOW64) | `x86_64` | `x86_64` | `x86` | `Some(false)` |
+    /// | x64 on ARM64 Windows | `arm64` | `x86_64` | `x86_64` | `Some(true)` |
+    /// | x86_64 on Apple silicon (Rosetta 2) | `arm64` | `x86_64` | `x86_64` | `Some(true)` |
+    /// | 32-bit ARM on 64-bit Linux | `aarch64` | `aarch64` | `arm` | `None` |
+    ///
+    /// [`kernel_arch`]: System::kernel_arch
+    /// [`process_arch`]: System::process_arch
+    /// [`is_translated`]: System::is_translated
+    ///
     /// **Important**: this information is computed every time this function is called.
     ///
     /// ```no_run
@@ -880,7 +1729,85 @@ impl System {
     /// println!("CPU Architecture: {:?}", System::cpu_arch());
     /// ```
     pub fn cpu_arch() -> String {
-        SystemInner::cpu_arch().unwrap_or_else(|| std::env::consts::ARCH.to_owned())
+        SystemInner::cpu_arch().unwrap_or_else(|| Self::process_arch().to_owned())
+    }
+
+    /// Returns the architecture the kernel reports to the current process.
+    ///
+    /// On unix, it is the `machine` field of `uname`. On Windows, it is the architecture
+    /// returned by `GetNativeSystemInfo`. Both report the emulated architecture to the
+    /// processes running with Rosetta 2 or the x64 emulation of Windows on ARM: take a look at
+    /// [`System::cpu_arch`] for the differences between the architectures.
+    ///
+    /// Returns `None` if the current system is not supported.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Kernel architecture: {:?}", System::kernel_arch());
+    /// ```
+    pub fn kernel_arch() -> Option<String> {
+        SystemInner::kernel_arch()
+    }
+
+    /// Returns the architecture the current process was compiled for, like `x86_64` or
+    /// `aarch64`.
+    ///
+    /// It is [`std::env::consts::ARCH`], so it uses the Rust names of the architectures, which
+    /// can differ from the names returned by [`System::cpu_arch`] (like `aarch64` instead of
+    /// `arm64` on macOS and Windows).
+    ///
+    /// ```
+    /// use sysinfo::System;
+    ///
+    /// assert_eq!(System::process_arch(), std::env::consts::ARCH);
+    /// ```
+    pub fn process_arch() -> &'static str {
+        std::env::consts::ARCH
+    }
+
+    /// Returns the architecture of the CPU returned by [`System::cpu_arch`] as a
+    /// [`CpuArchDetail`], with its word size and byte order.
+    ///
+    /// On x86-64, the microarchitecture level (like `x86-64-v3`) is computed from the CPUID flags
+    /// when the current process is an x86-64 process. On AArch64, ARMv9 is detected from the
+    /// support of SVE2 when the current process is an AArch64 process.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let detail = System::cpu_arch_detail();
+    /// println!("{:?}, {} bits, {:?}", detail.arch, detail.bits, detail.endianness);
+    /// ```
+    pub fn cpu_arch_detail() -> CpuArchDetail {
+        CpuArchDetail::new(&Self::cpu_arch())
+    }
+
+    /// Returns `true` if the current process runs with a binary translator: Rosetta 2 on macOS,
+    /// or the x86 and x64 emulation of Windows on ARM.
+    ///
+    /// The x86 processes running with WOW64 on x64 Windows are not translated since the CPU
+    /// runs them natively. Take a look at [`System::cpu_arch`] for the differences between the
+    /// architectures.
+    ///
+    /// Returns `None` if it couldn't be retrieved or if the current system is not supported
+    /// (it is always the case on Linux and FreeBSD).
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if System::is_translated() == Some(true) {
+    ///     println!("running on {} with emulation", System::cpu_arch());
+    /// }
+    /// ```
+    pub fn is_translated() -> Option<bool> {
+        SystemInner::is_translated()
     }
 
     /// Returns the number of physical cores on the CPU or `None` if it couldn't get it.
@@ -914,6 +1841,363 @@ impl System {
     pub fn open_files_limit() -> Option<usize> {
         SystemInner::open_files_limit()
     }
+
+    /// Returns the number of files opened on the whole system and the maximum number of files
+    /// which can be opened, as `(allocated, max)`.
+    ///
+    /// On Windows, it is the number of handles of all the processes. Since Windows doesn't limit
+    /// the number of handles on the whole system, the maximum is `u64::MAX`. It can also be very
+    /// high on Linux as the kernel doesn't have a fixed limit by default.
+    ///
+    /// Returns `None` if it failed retrieving the information or if the current system is not
+    /// supported.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some((allocated, max)) = System::open_files() {
+    ///     println!("{allocated} files opened out of {max}");
+    /// }
+    /// ```
+    pub fn open_files() -> Option<(u64, u64)> {
+        SystemInner::open_files()
+    }
+
+    /// Returns the highest PID which can be assigned to a process, after which the PIDs wrap
+    /// around.
+    ///
+    /// Returns `None` if it failed retrieving the information or if the current system is not
+    /// supported.
+    ///
+    /// ⚠️ This method always returns `None` on Windows and macOS, which don't have such a
+    /// setting.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Max PID: {:?}", System::max_pid());
+    /// ```
+    pub fn max_pid() -> Option<u64> {
+        SystemInner::max_pid()
+    }
+
+    /// Returns the number of threads of all the processes of the system.
+    ///
+    /// Unlike summing the [`Process::thread_count`] of all the processes, it doesn't require to
+    /// refresh the processes.
+    ///
+    /// Returns `None` if it failed retrieving the information or if the current system is not
+    /// supported.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux and Windows.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("{:?} threads running", System::threads_total());
+    /// ```
+    pub fn threads_total() -> Option<u64> {
+        SystemInner::threads_total()
+    }
+
+    /// Returns information about the hardware of the machine, like its vendor, its product name
+    /// and the version of its BIOS.
+    ///
+    /// This information doesn't change while the machine is running, so it is only retrieved the
+    /// first time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let hardware = System::hardware();
+    /// println!("{:?} {:?}", hardware.vendor, hardware.product_name);
+    /// ```
+    pub fn hardware() -> &'static HardwareInfo {
+        static HARDWARE: OnceLock<HardwareInfo> = OnceLock::new();
+        HARDWARE.get_or_init(SystemInner::hardware)
+    }
+
+    /// Returns `true` if a process with this `pid` is currently running.
+    ///
+    /// Unlike [`System::process`], it doesn't need the processes list to be refreshed: it only
+    /// asks the system, with `kill(pid, 0)` on unix and `OpenProcess` on Windows. It is useful to
+    /// cheaply poll whether a process is still alive between two refreshes.
+    ///
+    /// Processes which exited but weren't reaped by their parent yet (the "zombie" processes)
+    /// are not considered as running on Linux, Android, FreeBSD and macOS.
+    ///
+    /// It is also available as the [`process_exists`][crate::process_exists] function.
+    ///
+    /// ```no_run
+    /// use sysinfo::{get_current_pid, System};
+    ///
+    /// let pid = get_current_pid().expect("failed to get current pid");
+    /// assert!(System::process_exists(pid));
+    /// ```
+    pub fn process_exists(pid: Pid) -> bool {
+        SystemInner::process_exists(pid)
+    }
+
+    /// Returns the virtualization environment the system is running in, or `None` if it couldn't
+    /// be detected on this platform.
+    ///
+    /// Like `systemd-detect-virt`, it combines the SMBIOS (DMI) information, the hypervisor
+    /// signature of the CPU and, on Linux, the files created by the container managers (like
+    /// `/.dockerenv`) and the cgroups of the init process. If the system runs in a container
+    /// inside of a virtual machine, the hypervisor is available in
+    /// [`Virtualization::Container::host`].
+    ///
+    /// On Windows, macOS and iOS, only the virtual machines are detected. On FreeBSD, the jails
+    /// are reported as [`ContainerKind::Other`][crate::ContainerKind::Other].
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Virtualization: {:?}", System::virtualization());
+    /// ```
+    pub fn virtualization() -> Option<Virtualization> {
+        SystemInner::virtualization()
+    }
+
+    /// Returns the identifier of the machine, which stays the same across reboots.
+    ///
+    /// It is read from `/etc/machine-id` (or `/var/lib/dbus/machine-id`) on Linux, the
+    /// `MachineGuid` value of the `HKLM\SOFTWARE\Microsoft\Cryptography` registry key on
+    /// Windows, the `IOPlatformUUID` property on macOS and the `kern.hostuuid` sysctl on FreeBSD.
+    ///
+    /// ⚠️ This identifier uniquely identifies the machine and should be considered confidential:
+    /// it shouldn't be exposed to untrusted parties. If an identifier is needed for a single
+    /// application, derive it from this one with a keyed hash instead of using it directly.
+    ///
+    /// This information doesn't change while the machine is running, so it is only retrieved the
+    /// first time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Machine ID: {:?}", System::machine_id());
+    /// ```
+    pub fn machine_id() -> Option<String> {
+        static MACHINE_ID: OnceLock<Option<String>> = OnceLock::new();
+        MACHINE_ID.get_or_init(SystemInner::machine_id).clone()
+    }
+
+    /// Returns an identifier of the current boot of the machine, which changes every time it
+    /// is rebooted.
+    ///
+    /// It is read from `/proc/sys/kernel/random/boot_id` on Linux. The other platforms don't
+    /// provide one, so it is derived from [`System::machine_id`] and the boot time: it has the
+    /// same format as the Linux one but it isn't random. Since the kernel updates the boot time
+    /// when the system clock is set, processes started before and after such a change can get
+    /// different values. Returns `None` if the machine identifier isn't available.
+    ///
+    /// This information doesn't change while the machine is running, so it is only retrieved the
+    /// first time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Boot ID: {:?}", System::boot_id());
+    /// ```
+    pub fn boot_id() -> Option<String> {
+        static BOOT_ID: OnceLock<Option<String>> = OnceLock::new();
+        BOOT_ID.get_or_init(SystemInner::boot_id).clone()
+    }
+
+    /// Returns the command line the kernel was booted with, like
+    /// `BOOT_IMAGE=/vmlinuz root=/dev/sda1 ro mitigations=off`.
+    ///
+    /// It is read from `/proc/cmdline` on Linux and from the `boot-args` NVRAM variable (through
+    /// the `kern.bootargs` sysctl) on macOS. Returns `None` on the other platforms.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(cmdline) = System::kernel_cmdline() {
+    ///     let mitigations_off = cmdline.split_whitespace().any(|arg| arg == "mitigations=off");
+    ///     println!("CPU mitigations disabled: {mitigations_off}");
+    /// }
+    /// ```
+    pub fn kernel_cmdline() -> Option<String> {
+        SystemInner::kernel_cmdline()
+    }
+
+    /// Returns the value of a kernel parameter (a "sysctl"), like `vm.swappiness` or
+    /// `kernel.pid_max`.
+    ///
+    /// ⚠️ This is only supported on Linux, where it is read from `/proc/sys`: `vm.swappiness` is
+    /// read from `/proc/sys/vm/swappiness`. Like with the `sysctl` command, the components of
+    /// the name can also be separated with slashes, which is needed when one of them contains a
+    /// dot (like in `net/ipv4/conf/eth0.100/forwarding`).
+    ///
+    /// Returns `None` if the name is invalid (for example if it contains `..`), if the parameter
+    /// doesn't exist or if it cannot be read, which is the case for some of them if the current
+    /// user isn't root. The trailing newline is removed but the values made of several numbers,
+    /// like `kernel.printk`, are returned as is.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("swappiness: {:?}", System::kernel_parameter("vm.swappiness"));
+    /// println!("pid_max: {:?}", System::kernel_parameter("kernel.pid_max"));
+    /// ```
+    pub fn kernel_parameter(name: &str) -> Option<String> {
+        SystemInner::kernel_parameter(name)
+    }
+
+    /// Returns `Some(true)` if the system was booted with UEFI Secure Boot enabled and
+    /// `Some(false)` if it is disabled.
+    ///
+    /// It is read from the `SecureBoot` EFI variable in `/sys/firmware/efi/efivars` on Linux and
+    /// from the `UEFISecureBootEnabled` value of the
+    /// `HKLM\SYSTEM\CurrentControlSet\Control\SecureBoot\State` registry key on Windows (only
+    /// if the firmware type returned by `GetFirmwareType` is UEFI).
+    ///
+    /// Returns `None` if it is unknown, which is the case when the system wasn't booted with
+    /// UEFI (like with a legacy BIOS) or when the information cannot be read. It always returns
+    /// `None` on the other platforms.
+    ///
+    /// This information doesn't change while the machine is running, so it is only retrieved the
+    /// first time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// match System::secure_boot() {
+    ///     Some(true) => println!("Secure Boot is enabled"),
+    ///     Some(false) => println!("Secure Boot is disabled"),
+    ///     None => println!("Secure Boot state is unknown"),
+    /// }
+    /// ```
+    pub fn secure_boot() -> Option<bool> {
+        static SECURE_BOOT: OnceLock<Option<bool>> = OnceLock::new();
+        *SECURE_BOOT.get_or_init(SystemInner::secure_boot)
+    }
+
+    /// Returns the version of the TPM (Trusted Platform Module) of the machine, or `None` if it
+    /// has no TPM or if its version cannot be retrieved.
+    ///
+    /// It is read from `/sys/class/tpm/tpm0/tpm_version_major` on Linux (available since
+    /// Linux 5.6) and retrieved with `Tbsi_GetDeviceInfo` on Windows. It always returns `None` on
+    /// the other platforms.
+    ///
+    /// This information doesn't change while the machine is running, so it is only retrieved the
+    /// first time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::{System, TpmVersion};
+    ///
+    /// if System::tpm_version() != Some(TpmVersion::V2) {
+    ///     println!("No TPM 2.0 found");
+    /// }
+    /// ```
+    pub fn tpm_version() -> Option<TpmVersion> {
+        static TPM_VERSION: OnceLock<Option<TpmVersion>> = OnceLock::new();
+        *TPM_VERSION.get_or_init(SystemInner::tpm_version)
+    }
+
+    /// Returns the IANA name of the time zone of the system, like `Europe/Paris`.
+    ///
+    /// On Linux, FreeBSD and macOS, it is the target of the `/etc/localtime` symbolic link or,
+    /// if it isn't one, the content of `/etc/timezone` (or `/var/db/zoneinfo` on FreeBSD). On
+    /// Windows, the name of the time zone is converted with the mapping table of the Unicode
+    /// CLDR, so `None` is returned for the time zones it doesn't contain.
+    ///
+    /// The `TZ` environment variable is not taken into account.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Time zone: {:?}", System::timezone());
+    /// ```
+    pub fn timezone() -> Option<String> {
+        SystemInner::timezone()
+    }
+
+    /// Returns the locale of the current user.
+    ///
+    /// On unix, it is the value of the `LC_ALL` environment variable, or of `LANG` if it isn't
+    /// set, like `en_US.UTF-8`. On macOS, if neither is set, it is the identifier of the locale
+    /// selected in the system settings, like `en_US`. On Windows, it is the name returned by
+    /// `GetUserDefaultLocaleName`, like `en-US`.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// println!("Locale: {:?}", System::locale());
+    /// ```
+    pub fn locale() -> Option<String> {
+        SystemInner::locale()
+    }
+
+    /// Returns the time elapsed since the system booted, measured with a monotonic clock which
+    /// keeps counting while the system is suspended.
+    ///
+    /// Unlike [`System::boot_time`], it isn't affected by the changes of the system clock, so
+    /// comparing both can detect that the system clock was adjusted since the boot: the boot
+    /// time plus this duration should be close to the current time.
+    ///
+    /// Returns `None` if the current system is not supported.
+    ///
+    /// ```no_run
+    /// use std::time::{Duration, SystemTime, UNIX_EPOCH};
+    /// use sysinfo::System;
+    ///
+    /// if let Some(since_boot) = System::time_since_boot_monotonic() {
+    ///     let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    ///     let boot_time = Duration::from_secs(System::boot_time());
+    ///     let skew = now.as_secs_f64() - (boot_time + since_boot).as_secs_f64();
+    ///     println!("clock skew: {skew:.1} seconds");
+    /// }
+    /// ```
+    pub fn time_since_boot_monotonic() -> Option<Duration> {
+        SystemInner::time_since_boot_monotonic()
+    }
+
+    /// Starts watching the processes' start and stop events.
+    ///
+    /// Unlike [`System::refresh_processes`], it doesn't poll, so even short-lived processes are
+    /// reported. Take a look at [`ProcessWatcher`] for more information.
+    ///
+    /// ⚠️ This is only supported on:
+    ///
+    /// - Windows, where it uses an ETW real-time session. Creating it requires administrator
+    ///   privileges (or being a member of the "Performance Log Users" group).
+    /// - Linux, where it uses the netlink proc connector. Subscribing to it requires the
+    ///   `CAP_NET_ADMIN` capability, and it only reports the processes when running in the
+    ///   initial network namespace. Since the start events are sent when the process forks, their
+    ///   [`name`][crate::ProcessEvent::name] is usually the one of the parent process.
+    ///
+    /// Without the required privileges, [`WatchProcessesError::PermissionDenied`] is returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Ok(watcher) = System::watch_processes() {
+    ///     for event in watcher {
+    ///         println!("{:?}", event);
+    ///     }
+    /// }
+    /// ```
+    pub fn watch_processes() -> Result<ProcessWatcher, WatchProcessesError> {
+        ProcessWatcher::new()
+    }
 }
 
 /// A struct representing system load average value.
@@ -942,6 +2226,123 @@ pub struct LoadAvg {
     pub fifteen: f64,
 }
 
+/// A resource pressure reported by the kernel.
+///
+/// It is returned by [`System::memory_pressure`][crate::System::memory_pressure],
+/// [`System::cpu_pressure`][crate::System::cpu_pressure] and
+/// [`System::io_pressure`][crate::System::io_pressure]. Which fields are filled depends on
+/// the platform.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// if let Some(pressure) = System::memory_pressure() {
+///     if let Some(level) = pressure.level {
+///         println!("memory pressure level: {level:?}");
+///     }
+///     if let Some(some) = pressure.some {
+///         println!("some tasks were stalled {}% of the last 10 seconds", some.avg10);
+///     }
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Pressure {
+    /// Pressure level, only available on macOS (and only for the memory).
+    pub level: Option<PressureLevel>,
+    /// Time during which at least some tasks were stalled on the resource, only available on
+    /// Linux.
+    pub some: Option<PressureStall>,
+    /// Time during which all non-idle tasks were stalled on the resource at the same time,
+    /// only available on Linux.
+    ///
+    /// For the CPU, it is only provided since Linux 5.13 and is always `0` at the system
+    /// level.
+    pub full: Option<PressureStall>,
+}
+
+/// Share of time during which tasks were stalled on a resource. See [`Pressure`].
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PressureStall {
+    /// Percentage of the last 10 seconds.
+    pub avg10: f64,
+    /// Percentage of the last 60 seconds.
+    pub avg60: f64,
+    /// Percentage of the last 300 seconds.
+    pub avg300: f64,
+    /// Total stall time, in microseconds.
+    pub total: u64,
+}
+
+/// Pressure level of a resource. See [`Pressure::level`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum PressureLevel {
+    /// The resource isn't under pressure.
+    Normal,
+    /// The resource is getting scarce.
+    Warning,
+    /// The resource is critically scarce.
+    Critical,
+}
+
+/// Information about the hardware of the machine.
+///
+/// It is returned by [`System::hardware`][crate::System::hardware]. The fields which are not
+/// available on the current platform are `None`:
+///
+/// * On Linux, it is read from `/sys/class/dmi/id`, whose fields are readable by all users.
+/// * On Windows, it is read from the `HARDWARE\DESCRIPTION\System\BIOS` registry key and from
+///   the SMBIOS table for the chassis type.
+/// * On macOS, only the vendor, the product name (like `MacBookPro18,3`) and the board name on
+///   Intel machines are available, from the `IOPlatformExpertDevice` IOKit service.
+/// * On FreeBSD, it is read from the `smbios.*` kernel environment variables.
+///
+/// ```no_run
+/// use sysinfo::System;
+///
+/// let hardware = System::hardware();
+/// if let Some(bios_version) = &hardware.bios_version {
+///     println!("BIOS version: {bios_version}");
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct HardwareInfo {
+    /// Manufacturer of the machine, like `Dell Inc.` or `Apple Inc.`.
+    pub vendor: Option<String>,
+    /// Product name of the machine.
+    pub product_name: Option<String>,
+    /// Product name of the motherboard.
+    pub board_name: Option<String>,
+    /// Version of the BIOS (or of the UEFI firmware).
+    pub bios_version: Option<String>,
+    /// Release date of the BIOS, as provided by the firmware (usually `MM/DD/YYYY`).
+    pub bios_date: Option<String>,
+    /// Chassis type as defined by the SMBIOS specification, like `3` for a desktop, `10` for a
+    /// notebook or `23` for a rack mount chassis.
+    pub chassis_type: Option<u8>,
+}
+
+/// Version of a TPM (Trusted Platform Module). See [`System::tpm_version`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum TpmVersion {
+    /// TPM 1.2.
+    V1,
+    /// TPM 2.0.
+    V2,
+}
+
+/// Kind of network the machine is a member of. See [`System::domain_kind`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum DomainKind {
+    /// The machine is a member of a domain: a Windows (Active Directory) domain, or a DNS
+    /// domain on the other systems.
+    Domain,
+    /// The machine is a member of a Windows workgroup.
+    Workgroup,
+}
+
 /// An enum representing signals on UNIX-like systems.
 ///
 /// On non-unix systems, this enum is mostly useless and is only there to keep coherency between
@@ -1211,6 +2612,47 @@ pub enum KillError {
     FailedToSendSignal,
 }
 
+/// I/O scheduling class of a process. It is part of [`IoPriority`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum IoPriorityClass {
+    /// No I/O priority was set: it is computed from the CPU nice value of the process.
+    None,
+    /// The process is always given the first access to the disk.
+    Realtime,
+    /// Default scheduling class.
+    BestEffort,
+    /// The process only gets disk time when no other process needs it.
+    Idle,
+}
+
+/// I/O priority of a process, as returned by [`Process::io_priority`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct IoPriority {
+    /// Scheduling class.
+    pub class: IoPriorityClass,
+    /// Priority level inside the scheduling class, from `0` (highest) to `7` (lowest). It is
+    /// only meaningful for the [`IoPriorityClass::Realtime`] and
+    /// [`IoPriorityClass::BestEffort`] classes.
+    pub level: u8,
+}
+
+/// Enum describing possible [`Process::set_io_priority`] errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub enum IoPriorityError {
+    /// Setting the I/O priority isn't supported on this platform.
+    Unsupported,
+    /// The current user isn't allowed to set this I/O priority on this process.
+    PermissionDenied,
+    /// The I/O priority is invalid (its level is too big for example).
+    InvalidPriority,
+    /// The I/O priority failed to be set (because the process doesn't exist anymore for
+    /// example).
+    FailedToSetPriority,
+}
+
 /// Struct containing information of a process.
 ///
 /// ## iOS
@@ -1234,7 +2676,33 @@ pub struct Process {
     pub(crate) inner: ProcessInner,
 }
 
-impl Process {
+impl Process {
+    /// Reads the information of a process from a `/proc/[pid]` folder, which can be a copy of it
+    /// taken on another system (for example, for forensic analysis). The live system is not
+    /// involved, except for its boot time, uptime, page size and clock ticks which are used to
+    /// compute the times and the memory usage. The PID is read from the `stat` file, which is
+    /// required. Returns `None` if it cannot be read.
+    ///
+    /// ⚠️ The methods which act on a process, like [`Process::kill`] or [`Process::wait`], still
+    /// target the process of the live system with the same PID.
+    ///
+    /// ⚠️ This function is only available on Linux and Android.
+    ///
+    /// ```no_run
+    /// use sysinfo::Process;
+    ///
+    /// if let Some(process) = Process::from_proc_dir("captures/1337") {
+    ///     println!("{:?} {:?}", process.name(), process.cmd());
+    /// }
+    /// ```
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        not(feature = "unknown-ci")
+    ))]
+    pub fn from_proc_dir(path: impl AsRef<Path>) -> Option<Self> {
+        crate::sys::process::get_process_from_dir(path.as_ref())
+    }
+
     /// Sends [`Signal::Kill`] to the process (which is the only signal supported on all supported
     /// platforms by this crate).
     ///
@@ -1360,6 +2828,9 @@ impl Process {
     /// On **Linux**/**Android**, we check that the start time of the PID we're waiting is the same
     /// as the current process'. If not it means the process died and a new one got its PID.
     ///
+    /// On **Linux**, **macOS** and **FreeBSD**, if the process was already reaped by
+    /// [`Process::exit_code`], its kept exit status is returned.
+    ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
@@ -1375,12 +2846,42 @@ impl Process {
         self.inner.wait()
     }
 
+    /// Returns the exit code of the process if it exited, without waiting for it.
+    ///
+    /// It returns `None` if the process is still running or if it was terminated by a signal.
+    ///
+    /// On **Linux**, **macOS** and **FreeBSD**, the exit code can only be retrieved if the
+    /// process is a child of the current process which wasn't reaped somewhere else (for example
+    /// with [`std::process::Child::wait`]). Once retrieved, including by [`Process::wait`], it
+    /// is kept so it is returned by the next calls.
+    ///
+    /// On **Windows**, as long as we have a (internal) handle, we can always retrieve it.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     match process.exit_code() {
+    ///         Some(exit_code) => println!("Pid 1337 exited with: {exit_code}"),
+    ///         None => println!("Pid 1337 exit code isn't available"),
+    ///     }
+    /// }
+    /// ```
+    pub fn exit_code(&self) -> Option<i32> {
+        self.inner.exit_code()
+    }
+
     /// Returns the name of the process.
     ///
     /// **⚠️ Important ⚠️**
     ///
     /// On **Linux**, there are two things to know about processes' name:
-    ///  1. It is limited to 15 characters.
+    ///  1. It is the kernel `comm` value (see `Process::comm`), which is limited to 15
+    ///     characters. When it is truncated, the full name is retrieved from the command line or
+    ///     the executable if they were loaded (with [`ProcessRefreshKind::cmd`] or
+    ///     [`ProcessRefreshKind::exe`]) and their file name starts with it.
     ///  2. It is not always the exe name.
     ///
     /// If you are looking for a specific process, unless you know what you are
@@ -1399,8 +2900,34 @@ impl Process {
         self.inner.name()
     }
 
+    /// Returns the kernel `comm` value of the process, exactly as it is in `/proc/[pid]/stat`.
+    ///
+    /// Contrary to [`Process::name`], it is truncated to 15 bytes and it is updated when the
+    /// process changes it (with `prctl(PR_SET_NAME)` for example).
+    ///
+    /// ⚠️ This method is only available on Linux and Android.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.comm());
+    /// }
+    /// ```
+    #[cfg(all(
+        any(target_os = "linux", target_os = "android"),
+        not(feature = "unknown-ci")
+    ))]
+    pub fn comm(&self) -> &str {
+        self.inner.comm()
+    }
+
     /// Returns the command line.
     ///
+    /// The arguments are not converted, so they are kept as is even if they aren't valid UTF-8,
+    /// empty or only made of whitespace.
+    ///
     ///  **⚠️ Important ⚠️**
     ///
     /// On **Windows**, you might need to use `administrator` privileges when running your program
@@ -1444,6 +2971,57 @@ impl Process {
         self.inner.exe()
     }
 
+    /// Returns the digest of the executable of the process (the file at [`Process::exe`]) as a
+    /// lowercase hexadecimal string.
+    ///
+    /// The file is read from the disk when this method is called: if it was replaced since the
+    /// process was started, the digest of the new file is returned, so comparing it with an
+    /// expected value detects the binaries which were modified. The digests are cached by path
+    /// and modification time, so an executable is only hashed again if it changed, even when it
+    /// is shared by several processes.
+    ///
+    /// It returns `None` if [`Process::exe`] is not known, if the file cannot be read or if it
+    /// was deleted (on Linux, [`Process::exe`] ends with ` (deleted)` in this case).
+    ///
+    /// This method is only available with the `exe-hash` feature.
+    ///
+    /// ```no_run
+    /// use sysinfo::{HashAlgorithm, Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.exe_hash(HashAlgorithm::Sha256));
+    /// }
+    /// ```
+    #[cfg(feature = "exe-hash")]
+    pub fn exe_hash(&self, algorithm: crate::HashAlgorithm) -> Option<String> {
+        crate::common::exe_hash::exe_hash(self.exe()?, algorithm)
+    }
+
+    /// Returns the path of the executable image of the process, as known by the system.
+    ///
+    /// Unlike [`Process::exe`] and the first element of [`Process::cmd`], it doesn't depend on
+    /// how the process was launched, so it is always an absolute path, even for the processes
+    /// started with a relative path or with a spoofed `argv[0]`.
+    ///
+    /// It is `None` if the process cannot be queried, like for the protected and the system
+    /// processes. It is retrieved with the [`ProcessRefreshKind::exe`] information.
+    ///
+    /// ⚠️ This method is only available on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.image_path());
+    /// }
+    /// ```
+    #[cfg(all(windows, not(feature = "unknown-ci")))]
+    pub fn image_path(&self) -> Option<&Path> {
+        self.inner.image_path()
+    }
+
     /// Returns the PID of the process.
     ///
     /// ```no_run
@@ -1460,6 +3038,13 @@ impl Process {
 
     /// Returns the environment variables of the process.
     ///
+    /// Like [`Process::cmd`], the variables are kept as is even if they aren't valid UTF-8.
+    ///
+    /// The environment of a process rarely changes, so it is a snapshot taken when the process
+    /// was first observed: with [`UpdateKind::OnlyIfNotSet`], it is not read again in the
+    /// following refreshes. Use [`UpdateKind::Always`] with [`ProcessRefreshKind::with_environ`]
+    /// to force it to be read again.
+    ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
@@ -1525,6 +3110,21 @@ impl Process {
         self.inner.memory()
     }
 
+    /// Returns the same value as [`Process::memory`], as a [`ByteSize`] which
+    /// can be displayed with the most fitting unit.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{}", process.memory_size());
+    /// }
+    /// ```
+    pub fn memory_size(&self) -> ByteSize {
+        ByteSize::new(self.inner.memory())
+    }
+
     /// Returns the virtual memory usage (in bytes).
     ///
     /// This method returns the [size of virtual memory], that is, the amount of memory that the
@@ -1583,6 +3183,10 @@ impl Process {
 
     /// Returns the time where the process was started (in seconds) from epoch.
     ///
+    /// The start time is what identifies a process across refreshes: if a PID is reused by
+    /// another process, the start time changes and the previous [`Process`] is replaced by a new
+    /// one instead of being updated.
+    ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
@@ -1595,8 +3199,30 @@ impl Process {
         self.inner.start_time()
     }
 
+    /// Returns the time where the process was started, with a sub-second precision.
+    ///
+    /// On Linux, it is computed from the boot time and the number of clock ticks since boot
+    /// when the process started, so its precision is the clock tick (usually 10 milliseconds).
+    /// Because [`Process::start_time`] relies on the boot time rounded to the second, their
+    /// seconds can differ by one.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("Started at {:?}", process.start_time_precise());
+    /// }
+    /// ```
+    pub fn start_time_precise(&self) -> SystemTime {
+        self.inner.start_time_precise()
+    }
+
     /// Returns for how much time the process has been running (in seconds).
     ///
+    /// It is computed from [`Process::start_time`], so it starts again from the new process
+    /// start time if the PID was reused. The same goes for the CPU and disk usage.
+    ///
     /// ```no_run
     /// use sysinfo::{Pid, System};
     ///
@@ -1661,6 +3287,71 @@ impl Process {
         self.inner.accumulated_cpu_time()
     }
 
+    /// Returns the CPU time spent by this process, both in user and kernel mode.
+    ///
+    /// It is only updated when [`ProcessRefreshKind::cpu`] is set. On Linux, the clock ticks
+    /// reported by the kernel are converted using `sysconf(_SC_CLK_TCK)`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.cpu_time());
+    /// }
+    /// ```
+    pub fn cpu_time(&self) -> Duration {
+        self.user_cpu_time().saturating_add(self.system_cpu_time())
+    }
+
+    /// Returns the CPU time spent by this process in user mode.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.user_cpu_time());
+    /// }
+    /// ```
+    pub fn user_cpu_time(&self) -> Duration {
+        self.inner.user_cpu_time()
+    }
+
+    /// Returns the CPU time spent by this process in kernel mode.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.system_cpu_time());
+    /// }
+    /// ```
+    pub fn system_cpu_time(&self) -> Duration {
+        self.inner.system_cpu_time()
+    }
+
+    /// Returns the number of threads of the process, or `None` if it couldn't be retrieved.
+    ///
+    /// Contrary to [`Process::tasks`], the threads are not listed so it is updated with every
+    /// refresh.
+    ///
+    /// ⚠️ On macOS, it is only updated if [`ProcessRefreshKind::cpu`] or
+    /// [`ProcessRefreshKind::memory`] is set.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     println!("{:?}", process.thread_count());
+    /// }
+    /// ```
+    pub fn thread_count(&self) -> Option<usize> {
+        self.inner.thread_count()
+    }
+
     /// Returns number of bytes read and written to disk.
     ///
     /// ⚠️ On Windows, this method actually returns **ALL** I/O read and
@@ -1690,6 +3381,35 @@ impl Process {
         self.inner.disk_usage()
     }
 
+    /// Returns whether the last retrieval of `field` succeeded, and why it failed otherwise.
+    ///
+    /// The accessors of these fields (like [`Process::exe`]) return `None` or an empty value when
+    /// they cannot be retrieved, so this method allows to tell apart the information which
+    /// requires more privileges ([`FieldStatus::PermissionDenied`]) from the information which
+    /// doesn't exist ([`FieldStatus::NotFound`]), to only ask for the elevation of the current
+    /// process when it would help. The status is updated every time the field is retrieved.
+    ///
+    /// On Windows, the executable, the command line, the environment and the current working
+    /// directory are read from the memory of the process, so they are reported as
+    /// [`FieldStatus::PermissionDenied`] when the process can only be opened with limited rights.
+    ///
+    /// ⚠️ The failures are only reported on Linux, Android, macOS and Windows for now. On the
+    /// other platforms, [`FieldStatus::Unsupported`] is always returned.
+    ///
+    /// ```no_run
+    /// use sysinfo::{FieldStatus, Pid, ProcessField, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if process.field_status(ProcessField::Environ) == FieldStatus::PermissionDenied {
+    ///         println!("the environment of the process can only be read by its owner");
+    ///     }
+    /// }
+    /// ```
+    pub fn field_status(&self, field: ProcessField) -> FieldStatus {
+        self.inner.field_status(field)
+    }
+
     /// Returns the ID of the owner user of this process or `None` if this
     /// information couldn't be retrieved. If you want to get the [`User`] from
     /// it, take a look at [`Users::get_user_by_id`].
@@ -1774,6 +3494,122 @@ impl Process {
         self.inner.effective_group_id()
     }
 
+    /// Returns the file mode creation mask (umask) of the process or `None` if it couldn't be
+    /// retrieved.
+    ///
+    /// It is refreshed along with the user information (see [`ProcessRefreshKind::user`]).
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux and on Linux kernels
+    /// older than 4.7 (which don't provide it in `/proc/[pid]/status`).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let mut s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(umask) = process.umask() {
+    ///         println!("Umask for process 1337: {umask:03o}");
+    ///     }
+    /// }
+    /// ```
+    pub fn umask(&self) -> Option<u32> {
+        self.inner.umask()
+    }
+
+    /// Returns the PID of the process in its own PID namespace, or `None` if it couldn't be
+    /// retrieved.
+    ///
+    /// It is the same as [`Process::pid`] unless the process runs in a container (or in another
+    /// PID namespace nested in the one of `sysinfo`). It is refreshed along with the user
+    /// information (see [`ProcessRefreshKind::user`]).
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux and on Linux kernels
+    /// older than 4.1 (which don't provide it in `/proc/[pid]/status`).
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(ns_pid) = process.ns_pid() {
+    ///         println!("PID in its namespace: {ns_pid}");
+    ///     }
+    /// }
+    /// ```
+    pub fn ns_pid(&self) -> Option<Pid> {
+        self.inner.ns_pid()
+    }
+
+    /// Returns `true` if the process is the init process of a container, meaning that its
+    /// [PID in its namespace][Process::ns_pid] is `1` while its PID isn't.
+    ///
+    /// The init process of the PID namespace `sysinfo` runs in isn't a container init, so it
+    /// always returns `false` for the host init. It always returns `false` when
+    /// [`Process::ns_pid`] returns `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_user(UpdateKind::OnlyIfNotSet),
+    /// );
+    /// for process in s.processes().values().filter(|p| p.is_container_init()) {
+    ///     println!("container init: {} {:?}", process.pid(), process.name());
+    /// }
+    /// ```
+    pub fn is_container_init(&self) -> bool {
+        self.ns_pid() == Some(Pid::from(1)) && self.pid() != Pid::from(1)
+    }
+
+    /// Returns the I/O priority of the process or `None` if it couldn't be retrieved.
+    ///
+    /// **Important**: this information is computed every time this function is called.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(priority) = process.io_priority() {
+    ///         println!("{:?} (level {})", priority.class, priority.level);
+    ///     }
+    /// }
+    /// ```
+    pub fn io_priority(&self) -> Option<IoPriority> {
+        self.inner.io_priority()
+    }
+
+    /// Sets the I/O priority of the process.
+    ///
+    /// Setting the [`IoPriorityClass::Realtime`] class, or setting the priority of a process
+    /// owned by another user, usually requires elevated privileges. In this case,
+    /// [`IoPriorityError::PermissionDenied`] is returned.
+    ///
+    /// ⚠️ This method always returns [`IoPriorityError::Unsupported`] on other platforms than
+    /// Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{IoPriority, IoPriorityClass, Pid, System};
+    ///
+    /// let s = System::new_all();
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     let priority = IoPriority { class: IoPriorityClass::Idle, level: 0 };
+    ///     if let Err(error) = process.set_io_priority(priority) {
+    ///         println!("failed to set I/O priority: {error:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn set_io_priority(&self, priority: IoPriority) -> Result<(), IoPriorityError> {
+        self.inner.set_io_priority(priority)
+    }
+
     /// Returns the session ID for the current process or `None` if it couldn't
     /// be retrieved.
     ///
@@ -1854,6 +3690,26 @@ impl Process {
         }
     }
 
+    /// Returns `true` if the process is a kernel thread.
+    ///
+    /// On Linux, it is detected with the `PF_KTHREAD` flag of the process, so you don't need to
+    /// check if its name is surrounded by brackets or if its `exe` is empty.
+    ///
+    /// ⚠️ This method always returns `false` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let s = System::new_all();
+    ///
+    /// for process in s.processes().values().filter(|p| !p.is_kernel_thread()) {
+    ///     println!("{:?} {:?}", process.pid(), process.name());
+    /// }
+    /// ```
+    pub fn is_kernel_thread(&self) -> bool {
+        self.thread_kind() == Some(ThreadKind::Kernel)
+    }
+
     /// Returns `true` if the process doesn't exist anymore but was not yet removed from
     /// the processes list because the `remove_dead_processes` argument was set to `false`
     /// in methods like [`System::refresh_processes`].
@@ -1921,84 +3777,132 @@ impl Process {
     ///     );
     /// }
     /// ```
-    pub fn open_files_limit(&self) -> Option<usize> {
-        self.inner.open_files_limit()
+    pub fn open_files_limit(&self) -> Option<usize> {
+        self.inner.open_files_limit()
+    }
+
+    /// Returns the number of open files of the process compared to its limits, along with the
+    /// highest number of open files seen so far.
+    ///
+    /// Unlike [`Process::open_files`], it is only updated when the processes are refreshed with
+    /// [`ProcessRefreshKind::open_files`] enabled, which also updates the peak. Returns `None` if
+    /// it was never refreshed or if the number of open files couldn't be retrieved.
+    ///
+    /// On Linux, the limits come from `/proc/[pid]/limits`. On the other platforms, only the
+    /// soft limit is available and it is the same value as [`Process::open_files_limit`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_open_files(),
+    /// );
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(usage) = process.fd_usage() {
+    ///         if usage.soft_limit.is_some_and(|limit| usage.open * 10 >= limit * 9) {
+    ///             println!("{pid} is about to run out of file descriptors: {usage:?}");
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn fd_usage(&self) -> Option<FdUsage> {
+        self.inner.fd_usage()
+    }
+
+    /// Returns the namespaces the process belongs to.
+    ///
+    /// Two processes with the same namespace inode are in the same namespace, which allows to
+    /// group the processes of a container without knowing which container runtime created it.
+    ///
+    /// It is only updated when the processes are refreshed with
+    /// [`ProcessRefreshKind::namespaces`] enabled. Returns `None` if it was never refreshed or if
+    /// none of the namespaces could be retrieved.
+    ///
+    /// ⚠️ This method always returns `None` on other platforms than Linux.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// s.refresh_processes_specifics(
+    ///     ProcessesToUpdate::All,
+    ///     true,
+    ///     ProcessRefreshKind::nothing().with_namespaces(),
+    /// );
+    ///
+    /// for (pid, process) in s.processes() {
+    ///     if let Some(net) = process.namespaces().and_then(|ns| ns.net) {
+    ///         println!("{pid} is in the network namespace {net}");
+    ///     }
+    /// }
+    /// ```
+    pub fn namespaces(&self) -> Option<Namespaces> {
+        self.inner.namespaces()
     }
 }
 
-macro_rules! pid_decl {
-    ($typ:ty) => {
-        #[doc = include_str!("../../md_doc/pid.md")]
-        #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-        #[repr(transparent)]
-        pub struct Pid(pub(crate) $typ);
-
-        impl From<usize> for Pid {
-            fn from(v: usize) -> Self {
-                Self(v as _)
-            }
-        }
-        impl From<Pid> for usize {
-            fn from(v: Pid) -> Self {
-                v.0 as _
-            }
-        }
-        impl FromStr for Pid {
-            type Err = <$typ as FromStr>::Err;
-            fn from_str(s: &str) -> Result<Self, Self::Err> {
-                Ok(Self(<$typ>::from_str(s)?))
-            }
-        }
-        impl fmt::Display for Pid {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                write!(f, "{}", self.0)
-            }
-        }
-        impl Pid {
-            /// Allows to convert [`Pid`][crate::Pid] into [`u32`].
-            ///
-            /// ```
-            /// use sysinfo::Pid;
-            ///
-            /// let pid = Pid::from_u32(0);
-            /// let value: u32 = pid.as_u32();
-            /// ```
-            pub fn as_u32(self) -> u32 {
-                self.0 as _
-            }
-            /// Allows to convert a [`u32`] into [`Pid`][crate::Pid].
-            ///
-            /// ```
-            /// use sysinfo::Pid;
-            ///
-            /// let pid = Pid::from_u32(0);
-            /// ```
-            pub fn from_u32(v: u32) -> Self {
-                Self(v as _)
-            }
-        }
-    };
+/// Number of open files of a process and their limits.
+///
+/// It is returned by [`Process::fd_usage`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FdUsage {
+    /// Number of open files.
+    pub open: usize,
+    /// Limit of open files that the process can raise up to the hard limit. `None` if unlimited
+    /// or unknown.
+    pub soft_limit: Option<usize>,
+    /// Maximum value of the soft limit. `None` if unlimited or unknown.
+    pub hard_limit: Option<usize>,
+    /// Highest number of open files seen across the refreshes.
+    pub peak: usize,
 }
 
-cfg_if! {
-    if #[cfg(all(
-        not(feature = "unknown-ci"),
-        any(
-            target_os = "freebsd",
-            target_os = "linux",
-            target_os = "android",
-            target_os = "macos",
-            target_os = "ios",
-        )
-    ))] {
-        use libc::pid_t;
-
-        pid_decl!(pid_t);
-    } else {
-        pid_decl!(usize);
+impl FdUsage {
+    // Not used on the platforms which don't support processes.
+    #[allow(dead_code)]
+    pub(crate) fn update(
+        previous: Option<Self>,
+        open: Option<usize>,
+        soft_limit: Option<usize>,
+        hard_limit: Option<usize>,
+    ) -> Option<Self> {
+        let open = open?;
+        Some(Self {
+            open,
+            soft_limit,
+            hard_limit,
+            peak: previous.map_or(open, |previous| previous.peak.max(open)),
+        })
     }
 }
 
+/// Inode numbers of the namespaces of a process.
+///
+/// It is returned by [`Process::namespaces`]. Each value is `None` if the namespace couldn't be
+/// retrieved, usually because of missing permissions or because the kernel doesn't support this
+/// namespace type.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Namespaces {
+    /// PID namespace.
+    pub pid: Option<u64>,
+    /// Network namespace.
+    pub net: Option<u64>,
+    /// Mount namespace.
+    pub mnt: Option<u64>,
+    /// UTS (host name and domain name) namespace.
+    pub uts: Option<u64>,
+    /// IPC namespace.
+    pub ipc: Option<u64>,
+    /// User namespace.
+    pub user: Option<u64>,
+    /// Cgroup namespace.
+    pub cgroup: Option<u64>,
+}
+
 /// This enum allows you to specify when you want the related information to be updated.
 ///
 /// For example if you only want the [`Process::exe()`] information to be refreshed only if it's not
@@ -2065,6 +3969,232 @@ pub enum ProcessesToUpdate<'a> {
     Some(&'a [Pid]),
 }
 
+/// Used to sort the processes with [`System::processes_sorted_by`].
+///
+/// ```no_run
+/// use sysinfo::{ProcessSortKey, System};
+///
+/// let s = System::new_all();
+/// let by_cpu = s.processes_sorted_by(ProcessSortKey::CpuUsage, true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProcessSortKey {
+    /// Sorts by [`Process::cpu_usage`].
+    CpuUsage,
+    /// Sorts by [`Process::memory`].
+    Memory,
+    /// Sorts by [`Process::pid`].
+    Pid,
+    /// Sorts by [`Process::name`].
+    Name,
+    /// Sorts by [`Process::start_time`].
+    StartTime,
+}
+
+/// Index of the processes by name, enabled with [`System::with_process_name_index`].
+#[derive(Default)]
+struct ProcessNameIndex {
+    pids_by_name: HashMap<OsString, HashSet<Pid>>,
+    names: HashMap<Pid, OsString>,
+}
+
+impl ProcessNameIndex {
+    fn new(processes: &HashMap<Pid, Process>) -> Self {
+        let mut index = Self::default();
+        index.update_all(processes);
+        index
+    }
+
+    fn pids(&self, name: &OsStr) -> impl Iterator<Item = &Pid> {
+        self.pids_by_name.get(name).into_iter().flatten()
+    }
+
+    /// Updates the entry of `pid`, which is removed if `pid` isn't in `processes` anymore.
+    fn update(&mut self, pid: Pid, processes: &HashMap<Pid, Process>) {
+        let Some(process) = processes.get(&pid) else {
+            self.remove(pid);
+            return;
+        };
+        let name = process.name();
+        if self.names.get(&pid).is_some_and(|old| old == name) {
+            return;
+        }
+        // The process is new or it changed its name.
+        self.remove(pid);
+        self.pids_by_name
+            .entry(name.to_owned())
+            .or_default()
+            .insert(pid);
+        self.names.insert(pid, name.to_owned());
+    }
+
+    fn update_all(&mut self, processes: &HashMap<Pid, Process>) {
+        let removed: Vec<Pid> = self
+            .names
+            .keys()
+            .filter(|pid| !processes.contains_key(pid))
+            .copied()
+            .collect();
+        for pid in removed {
+            self.remove(pid);
+        }
+        for pid in processes.keys() {
+            self.update(*pid, processes);
+        }
+    }
+
+    fn remove(&mut self, pid: Pid) {
+        let Some(name) = self.names.remove(&pid) else {
+            return;
+        };
+        if let Some(pids) = self.pids_by_name.get_mut(&name) {
+            pids.remove(&pid);
+            if pids.is_empty() {
+                self.pids_by_name.remove(&name);
+            }
+        }
+    }
+}
+
+/// Processes which were added, removed or changed during a
+/// [`System::refresh_processes_with_changes`] call.
+///
+/// The PIDs are sorted.
+///
+/// ```no_run
+/// use sysinfo::{ProcessesToUpdate, System};
+///
+/// let mut s = System::new();
+/// let changes = s.refresh_processes_with_changes(ProcessesToUpdate::All, true);
+/// println!("{} new processes", changes.added().len());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProcessChanges {
+    added: Vec<Pid>,
+    removed: Vec<Pid>,
+    changed: Vec<Pid>,
+}
+
+impl ProcessChanges {
+    fn new(before: &HashMap<Pid, TrackedProcess>, after: &HashMap<Pid, TrackedProcess>) -> Self {
+        let mut changes = Self::default();
+        for (pid, process) in after {
+            match before.get(pid) {
+                None if process.exists => changes.added.push(*pid),
+                None => {}
+                // Dead processes are kept if they are not removed.
+                Some(old) if old.exists && !process.exists => changes.removed.push(*pid),
+                Some(old) if process.has_changed_from(old) => changes.changed.push(*pid),
+                Some(_) => {}
+            }
+        }
+        changes.removed.extend(
+            before
+                .iter()
+                .filter(|(pid, process)| process.exists && !after.contains_key(pid))
+                .map(|(pid, _)| *pid),
+        );
+        changes.added.sort_unstable();
+        changes.removed.sort_unstable();
+        changes.changed.sort_unstable();
+        changes
+    }
+
+    /// Returns the PIDs of the processes which appeared.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new();
+    /// let changes = s.refresh_processes_with_changes(ProcessesToUpdate::All, true);
+    /// for pid in changes.added() {
+    ///     println!("new process: {pid}");
+    /// }
+    /// ```
+    pub fn added(&self) -> &[Pid] {
+        &self.added
+    }
+
+    /// Returns the PIDs of the processes which disappeared or are not running anymore.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// let changes = s.refresh_processes_with_changes(ProcessesToUpdate::All, true);
+    /// for pid in changes.removed() {
+    ///     println!("process is gone: {pid}");
+    /// }
+    /// ```
+    pub fn removed(&self) -> &[Pid] {
+        &self.removed
+    }
+
+    /// Returns the PIDs of the processes whose name, status, CPU usage or memory usage changed
+    /// noticeably.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// let changes = s.refresh_processes_with_changes(ProcessesToUpdate::All, true);
+    /// for pid in changes.changed() {
+    ///     println!("process changed: {pid}");
+    /// }
+    /// ```
+    pub fn changed(&self) -> &[Pid] {
+        &self.changed
+    }
+
+    /// Returns `true` if no process was added, removed or changed.
+    ///
+    /// ```no_run
+    /// use sysinfo::{ProcessesToUpdate, System};
+    ///
+    /// let mut s = System::new_all();
+    /// if s.refresh_processes_with_changes(ProcessesToUpdate::All, true).is_empty() {
+    ///     println!("nothing to redraw");
+    /// }
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Minimum CPU usage change (in percentage points) for a process to be considered as changed.
+const CPU_USAGE_CHANGE_THRESHOLD: f32 = 10.;
+/// Minimum memory usage change (in percents) for a process to be considered as changed.
+const MEMORY_CHANGE_THRESHOLD: u64 = 10;
+
+/// Subset of the process information compared by [`System::refresh_processes_with_changes`].
+struct TrackedProcess {
+    name: OsString,
+    status: ProcessStatus,
+    cpu_usage: f32,
+    memory: u64,
+    exists: bool,
+}
+
+impl TrackedProcess {
+    fn new(process: &Process) -> Self {
+        Self {
+            name: process.name().to_owned(),
+            status: process.status(),
+            cpu_usage: process.cpu_usage(),
+            memory: process.memory(),
+            exists: process.exists(),
+        }
+    }
+
+    fn has_changed_from(&self, old: &Self) -> bool {
+        self.name != old.name
+            || self.status != old.status
+            || (self.cpu_usage - old.cpu_usage).abs() > CPU_USAGE_CHANGE_THRESHOLD
+            || self.memory.abs_diff(old.memory) * 100
+                > self.memory.max(old.memory) * MEMORY_CHANGE_THRESHOLD
+    }
+}
+
 /// Used to determine what you want to refresh specifically on the [`Process`] type.
 ///
 /// When all refresh are ruled out, a [`Process`] will still retrieve the following information:
@@ -2104,6 +4234,21 @@ pub enum ProcessesToUpdate<'a> {
 /// }
 /// ```
 ///
+/// Starting from [`ProcessRefreshKind::everything`] and removing what you don't need is usually
+/// shorter than listing everything you need:
+///
+/// ```
+/// use sysinfo::{ProcessRefreshKind, UpdateKind};
+///
+/// // Everything except the environment variables and the command lines.
+/// let r = ProcessRefreshKind::everything().without_environ().without_cmd();
+///
+/// assert_eq!(r.environ(), UpdateKind::Never);
+/// assert_eq!(r.cmd(), UpdateKind::Never);
+/// assert_eq!(r.exe(), UpdateKind::OnlyIfNotSet);
+/// assert!(r.cpu() && r.tasks());
+/// ```
+///
 /// [`Process`]: crate::Process
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct ProcessRefreshKind {
@@ -2117,6 +4262,8 @@ pub struct ProcessRefreshKind {
     cmd: UpdateKind,
     exe: UpdateKind,
     tasks: bool,
+    open_files: bool,
+    namespaces: bool,
 }
 
 /// Creates a new `ProcessRefreshKind` with every refresh set to `false`, except for `tasks`.
@@ -2136,6 +4283,8 @@ impl Default for ProcessRefreshKind {
             cmd: UpdateKind::default(),
             exe: UpdateKind::default(),
             tasks: true, // Process by default includes all tasks.
+            open_files: false,
+            namespaces: false,
         }
     }
 }
@@ -2180,6 +4329,8 @@ impl ProcessRefreshKind {
             cmd: UpdateKind::OnlyIfNotSet,
             exe: UpdateKind::OnlyIfNotSet,
             tasks: true,
+            open_files: true,
+            namespaces: true,
         }
     }
 
@@ -2225,11 +4376,33 @@ It will retrieve the following information:
         environ,
         with_environ,
         without_environ,
-        UpdateKind
+        UpdateKind,
+        "\
+With [`UpdateKind::OnlyIfNotSet`], the environment variables are only read the first time the
+process is seen (on Linux) or as long as they are empty (on other platforms). Use
+[`UpdateKind::Always`] to read them again on every refresh."
     );
     impl_get_set!(ProcessRefreshKind, cmd, with_cmd, without_cmd, UpdateKind);
     impl_get_set!(ProcessRefreshKind, exe, with_exe, without_exe, UpdateKind);
     impl_get_set!(ProcessRefreshKind, tasks, with_tasks, without_tasks);
+    impl_get_set!(
+        ProcessRefreshKind,
+        open_files,
+        with_open_files,
+        without_open_files,
+        "\
+It will retrieve the number of open files and their limits, returned by
+[`Process::fd_usage`][crate::Process::fd_usage]."
+    );
+    impl_get_set!(
+        ProcessRefreshKind,
+        namespaces,
+        with_namespaces,
+        without_namespaces,
+        "\
+It will retrieve the namespaces of the process, returned by
+[`Process::namespaces`][crate::Process::namespaces]. It is only supported on Linux."
+    );
 }
 
 /// Used to determine what you want to refresh specifically on the [`Cpu`] type.
@@ -2370,6 +4543,23 @@ impl MemoryRefreshKind {
 /// assert!(system.processes().len() > 0);
 /// # }
 /// ```
+///
+/// The information of the inner refresh kinds can also be removed directly:
+///
+/// ```
+/// use sysinfo::{CpuRefreshKind, ProcessRefreshKind, RefreshKind};
+///
+/// // Everything except the CPU frequency and the environment variables of the processes.
+/// let r = RefreshKind::everything()
+///     .without_cpu_frequency()
+///     .without_process_environ();
+///
+/// assert_eq!(r.cpu(), Some(CpuRefreshKind::nothing().with_cpu_usage()));
+/// assert_eq!(
+///     r.processes(),
+///     Some(ProcessRefreshKind::everything().without_environ()),
+/// );
+/// ```
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct RefreshKind {
     processes: Option<ProcessRefreshKind>,
@@ -2427,6 +4617,37 @@ impl RefreshKind {
         MemoryRefreshKind
     );
     impl_get_set!(RefreshKind, cpu, with_cpu, without_cpu, CpuRefreshKind);
+    impl_without_nested!(
+        RefreshKind,
+        processes,
+        ProcessRefreshKind,
+        without_process_cpu => without_cpu,
+        without_process_disk_usage => without_disk_usage,
+        without_process_memory => without_memory,
+        without_process_user => without_user,
+        without_process_cwd => without_cwd,
+        without_process_root => without_root,
+        without_process_environ => without_environ,
+        without_process_cmd => without_cmd,
+        without_process_exe => without_exe,
+        without_process_tasks => without_tasks,
+        without_process_open_files => without_open_files,
+        without_process_namespaces => without_namespaces,
+    );
+    impl_without_nested!(
+        RefreshKind,
+        memory,
+        MemoryRefreshKind,
+        without_ram => without_ram,
+        without_swap => without_swap,
+    );
+    impl_without_nested!(
+        RefreshKind,
+        cpu,
+        CpuRefreshKind,
+        without_cpu_usage => without_cpu_usage,
+        without_cpu_frequency => without_frequency,
+    );
 }
 
 /// Returns the pid for the current process.
@@ -2477,6 +4698,46 @@ pub fn get_current_pid() -> Result<Pid, &'static str> {
     inner()
 }
 
+/// Returns `true` if a process with this `pid` is currently running.
+///
+/// Take a look at [`System::process_exists`] for more information.
+///
+/// ```no_run
+/// use sysinfo::{get_current_pid, process_exists};
+///
+/// let pid = get_current_pid().expect("failed to get current pid");
+/// assert!(process_exists(pid));
+/// ```
+pub fn process_exists(pid: Pid) -> bool {
+    System::process_exists(pid)
+}
+
+/// Hashes the machine identifier and the boot time with the 128-bit FNV-1a algorithm and formats
+/// the result like a UUID.
+// Not used on the platforms which provide a boot identifier.
+#[allow(dead_code)]
+pub(crate) fn derive_boot_id(machine_id: &str, boot_time: u64) -> String {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    let hash = machine_id
+        .as_bytes()
+        .iter()
+        .chain(&boot_time.to_le_bytes())
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ *byte as u128).wrapping_mul(PRIME)
+        });
+    let hex = format!("{hash:032x}");
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
 /// Contains all the methods of the [`Cpu`][crate::Cpu] struct.
 ///
 /// ```no_run
@@ -2497,6 +4758,21 @@ pub fn get_current_pid() -> Result<Pid, &'static str> {
 /// ```
 pub struct Cpu {
     pub(crate) inner: CpuInner,
+    pub(crate) usage_history: Vec<f32>,
+}
+
+/// Only keeps the `len` most recent values of `history`.
+fn truncate_usage_history(history: &mut Vec<f32>, len: usize) {
+    if history.len() > len {
+        history.drain(..history.len() - len);
+    }
+}
+
+/// Adds `usage` at the end of `history`, removing the oldest value if it already contains
+/// `len` values.
+fn push_usage_history(history: &mut Vec<f32>, len: usize, usage: f32) {
+    truncate_usage_history(history, len.saturating_sub(1));
+    history.push(usage);
 }
 
 impl Cpu {
@@ -2588,6 +4864,24 @@ impl Cpu {
     pub fn frequency(&self) -> u64 {
         self.inner.frequency()
     }
+
+    /// Returns the last usage values of this CPU, from the oldest to the most recent one.
+    ///
+    /// It is always empty unless [`System::with_cpu_usage_history`] was used.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// let mut s = System::new().with_cpu_usage_history(60);
+    /// std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    /// s.refresh_cpu_usage();
+    /// for cpu in s.cpus() {
+    ///     println!("{:?}", cpu.usage_history());
+    /// }
+    /// ```
+    pub fn usage_history(&self) -> &[f32] {
+        &self.usage_history
+    }
 }
 
 #[cfg(test)]
@@ -2614,6 +4908,293 @@ mod test {
         );
     }
 
+    // Each `without_*` method must only disable its own refresh.
+    #[test]
+    fn check_process_refresh_kind_without() {
+        let all = ProcessRefreshKind::everything();
+        let never = UpdateKind::Never;
+        for (kind, expected) in [
+            (all.without_cpu(), ProcessRefreshKind { cpu: false, ..all }),
+            (
+                all.without_disk_usage(),
+                ProcessRefreshKind {
+                    disk_usage: false,
+                    ..all
+                },
+            ),
+            (
+                all.without_memory(),
+                ProcessRefreshKind {
+                    memory: false,
+                    ..all
+                },
+            ),
+            (
+                all.without_user(),
+                ProcessRefreshKind { user: never, ..all },
+            ),
+            (all.without_cwd(), ProcessRefreshKind { cwd: never, ..all }),
+            (
+                all.without_root(),
+                ProcessRefreshKind { root: never, ..all },
+            ),
+            (
+                all.without_environ(),
+                ProcessRefreshKind {
+                    environ: never,
+                    ..all
+                },
+            ),
+            (all.without_cmd(), ProcessRefreshKind { cmd: never, ..all }),
+            (all.without_exe(), ProcessRefreshKind { exe: never, ..all }),
+            (
+                all.without_tasks(),
+                ProcessRefreshKind {
+                    tasks: false,
+                    ..all
+                },
+            ),
+            (
+                all.without_open_files(),
+                ProcessRefreshKind {
+                    open_files: false,
+                    ..all
+                },
+            ),
+            (
+                all.without_namespaces(),
+                ProcessRefreshKind {
+                    namespaces: false,
+                    ..all
+                },
+            ),
+        ] {
+            assert_eq!(kind, expected);
+        }
+    }
+
+    #[test]
+    fn check_cpu_and_memory_refresh_kind_without() {
+        let all = CpuRefreshKind::everything();
+        assert_eq!(
+            all.without_cpu_usage(),
+            CpuRefreshKind {
+                cpu_usage: false,
+                ..all
+            }
+        );
+        assert_eq!(
+            all.without_frequency(),
+            CpuRefreshKind {
+                frequency: false,
+                ..all
+            }
+        );
+
+        let all = MemoryRefreshKind::everything();
+        assert_eq!(all.without_ram(), MemoryRefreshKind { ram: false, ..all });
+        assert_eq!(all.without_swap(), MemoryRefreshKind { swap: false, ..all });
+    }
+
+    #[test]
+    fn check_refresh_kind_without() {
+        let all = RefreshKind::everything();
+        assert_eq!(
+            all.without_processes(),
+            RefreshKind {
+                processes: None,
+                ..all
+            }
+        );
+        assert_eq!(
+            all.without_memory(),
+            RefreshKind {
+                memory: None,
+                ..all
+            }
+        );
+        assert_eq!(all.without_cpu(), RefreshKind { cpu: None, ..all });
+
+        let processes = ProcessRefreshKind::everything();
+        for (kind, expected) in [
+            (all.without_process_cpu(), processes.without_cpu()),
+            (
+                all.without_process_disk_usage(),
+                processes.without_disk_usage(),
+            ),
+            (all.without_process_memory(), processes.without_memory()),
+            (all.without_process_user(), processes.without_user()),
+            (all.without_process_cwd(), processes.without_cwd()),
+            (all.without_process_root(), processes.without_root()),
+            (all.without_process_environ(), processes.without_environ()),
+            (all.without_process_cmd(), processes.without_cmd()),
+            (all.without_process_exe(), processes.without_exe()),
+            (all.without_process_tasks(), processes.without_tasks()),
+            (
+                all.without_process_open_files(),
+                processes.without_open_files(),
+            ),
+            (
+                all.without_process_namespaces(),
+                processes.without_namespaces(),
+            ),
+        ] {
+            assert_eq!(
+                kind,
+                RefreshKind {
+                    processes: Some(expected),
+                    ..all
+                }
+            );
+        }
+
+        let memory = MemoryRefreshKind::everything();
+        for (kind, expected) in [
+            (all.without_ram(), memory.without_ram()),
+            (all.without_swap(), memory.without_swap()),
+        ] {
+            assert_eq!(
+                kind,
+                RefreshKind {
+                    memory: Some(expected),
+                    ..all
+                }
+            );
+        }
+
+        let cpu = CpuRefreshKind::everything();
+        for (kind, expected) in [
+            (all.without_cpu_usage(), cpu.without_cpu_usage()),
+            (all.without_cpu_frequency(), cpu.without_frequency()),
+        ] {
+            assert_eq!(
+                kind,
+                RefreshKind {
+                    cpu: Some(expected),
+                    ..all
+                }
+            );
+        }
+
+        // The inner refresh kinds which aren't set stay unset.
+        let none = RefreshKind::nothing();
+        assert_eq!(none.without_process_cmd(), none);
+        assert_eq!(none.without_swap(), none);
+        assert_eq!(none.without_cpu_frequency(), none);
+    }
+
+    #[test]
+    fn check_cpu_usage_history() {
+        let mut history = Vec::new();
+        for usage in [10., 20., 30.] {
+            super::push_usage_history(&mut history, 3, usage);
+        }
+        assert_eq!(history, [10., 20., 30.]);
+        for usage in [40., 50.] {
+            super::push_usage_history(&mut history, 3, usage);
+        }
+        assert_eq!(history, [30., 40., 50.]);
+        super::truncate_usage_history(&mut history, 1);
+        assert_eq!(history, [50.]);
+
+        let mut s = System::new();
+        s.refresh_cpu_usage();
+        assert!(s.cpus().iter().all(|cpu| cpu.usage_history().is_empty()));
+
+        let mut s = s.with_cpu_usage_history(2);
+        for _ in 0..3 {
+            s.refresh_cpu_usage();
+        }
+        s.refresh_cpu_frequency();
+        for cpu in s.cpus() {
+            assert_eq!(cpu.usage_history().len(), 2);
+            assert_eq!(cpu.usage_history().last(), Some(&cpu.cpu_usage()));
+        }
+    }
+
+    #[test]
+    fn check_derive_boot_id() {
+        let boot_id = super::derive_boot_id("4c4c4544-0042-3510-8052-b4c04f4e3732", 1_700_000_000);
+        assert_eq!(boot_id.len(), 36);
+        assert_eq!(
+            boot_id.split('-').map(str::len).collect::<Vec<_>>(),
+            [8, 4, 4, 4, 12]
+        );
+        assert_eq!(
+            boot_id,
+            super::derive_boot_id("4c4c4544-0042-3510-8052-b4c04f4e3732", 1_700_000_000)
+        );
+        assert_ne!(
+            boot_id,
+            super::derive_boot_id("4c4c4544-0042-3510-8052-b4c04f4e3732", 1_700_000_001)
+        );
+        assert_ne!(boot_id, super::derive_boot_id("other", 1_700_000_000));
+    }
+
+    #[test]
+    fn check_refresh_processes_with_changes() {
+        let mut s = System::new();
+        let changes = s.refresh_processes_with_changes(ProcessesToUpdate::All, false);
+        assert!(changes.removed().is_empty());
+        assert!(changes.changed().is_empty());
+        if IS_SUPPORTED_SYSTEM {
+            let pid = get_current_pid().expect("failed to get current pid");
+            assert!(changes.added().contains(&pid));
+
+            let changes = s.refresh_processes_with_changes(ProcessesToUpdate::Some(&[pid]), false);
+            assert!(changes.added().is_empty());
+            assert!(changes.removed().is_empty());
+        } else {
+            assert!(changes.is_empty());
+        }
+    }
+
+    #[test]
+    fn check_process_changes() {
+        use super::TrackedProcess;
+        use std::collections::HashMap;
+
+        let process = |name: &str, memory: u64, exists: bool| TrackedProcess {
+            name: name.into(),
+            status: ProcessStatus::Run,
+            cpu_usage: 0.,
+            memory,
+            exists,
+        };
+        let before = HashMap::from([
+            (Pid::from(1), process("init", 1000, true)),
+            (Pid::from(2), process("removed", 1000, true)),
+            (Pid::from(3), process("dead", 1000, true)),
+            (Pid::from(4), process("old name", 1000, true)),
+            (Pid::from(5), process("memory", 1000, true)),
+            (Pid::from(6), process("small memory change", 1000, true)),
+            (Pid::from(7), process("already dead", 1000, false)),
+        ]);
+        let mut after = HashMap::from([
+            (Pid::from(1), process("init", 1000, true)),
+            (Pid::from(3), process("dead", 1000, false)),
+            (Pid::from(4), process("new name", 1000, true)),
+            (Pid::from(5), process("memory", 2000, true)),
+            (Pid::from(6), process("small memory change", 1050, true)),
+            (Pid::from(7), process("already dead", 1000, false)),
+            (Pid::from(9), process("added", 1000, true)),
+            (Pid::from(8), process("added", 1000, true)),
+        ]);
+        let mut cpu_usage = process("cpu", 1000, true);
+        cpu_usage.cpu_usage = 50.;
+        after.insert(Pid::from(10), cpu_usage);
+
+        let changes = super::ProcessChanges::new(&before, &after);
+        assert_eq!(
+            changes.added(),
+            &[Pid::from(8), Pid::from(9), Pid::from(10)]
+        );
+        assert_eq!(changes.removed(), &[Pid::from(2), Pid::from(3)]);
+        assert_eq!(changes.changed(), &[Pid::from(4), Pid::from(5)]);
+        assert!(!changes.is_empty());
+        assert!(super::ProcessChanges::new(&before, &before).is_empty());
+    }
+
     #[test]
     fn check_cpu_arch() {
         assert!(!System::cpu_arch().is_empty());
@@ -2643,6 +5224,39 @@ mod test {
         }
     }
 
+    #[test]
+    fn check_refresh_cpu_over() {
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let mut s = System::new();
+        let start = std::time::Instant::now();
+        s.refresh_cpu_over(MINIMUM_CPU_UPDATE_INTERVAL);
+        assert!(start.elapsed() >= MINIMUM_CPU_UPDATE_INTERVAL);
+        assert!(!s.cpus().is_empty());
+        let usage = s.global_cpu_usage();
+        assert!((0. ..=100.).contains(&usage), "{usage}");
+    }
+
+    // The global CPU usage is computed from the aggregated CPU times, so it is not exactly the
+    // mean of each CPU usage, but it shouldn't be far from it.
+    #[test]
+    fn check_global_cpu_usage_aggregate() {
+        if !IS_SUPPORTED_SYSTEM {
+            return;
+        }
+        let mut s = System::new();
+        s.refresh_cpu_over(MINIMUM_CPU_UPDATE_INTERVAL);
+        let cpus = s.cpus();
+        assert!(!cpus.is_empty());
+        let mean = cpus.iter().map(|cpu| cpu.cpu_usage()).sum::<f32>() / cpus.len() as f32;
+        let global = s.global_cpu_usage();
+        assert!(
+            (global - mean).abs() < 10.,
+            "global: {global}, mean: {mean}"
+        );
+    }
+
     #[test]
     fn check_process_memory_usage() {
         let mut s = System::new();
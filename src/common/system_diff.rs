@@ -0,0 +1,419 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
+#[cfg(feature = "disk")]
+use std::path::{Path, PathBuf};
+
+use crate::{Pid, Process, SystemSnapshot};
+
+/// Immutable copy of the information of a [`Process`].
+///
+/// It is returned by [`SystemSnapshot::processes`].
+#[derive(Clone, Debug)]
+pub struct ProcessSnapshot {
+    pid: Pid,
+    parent: Option<Pid>,
+    name: OsString,
+    start_time: u64,
+    memory: u64,
+    cpu_usage: f32,
+}
+
+impl ProcessSnapshot {
+    pub(crate) fn new(process: &Process) -> Self {
+        Self {
+            pid: process.pid(),
+            parent: process.parent(),
+            name: process.name().to_owned(),
+            start_time: process.start_time(),
+            memory: process.memory(),
+            cpu_usage: process.cpu_usage(),
+        }
+    }
+
+    /// Returns the PID of the process. See [`Process::pid`].
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the parent PID of the process. See [`Process::parent`].
+    pub fn parent(&self) -> Option<Pid> {
+        self.parent
+    }
+
+    /// Returns the name of the process. See [`Process::name`].
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    /// Returns the start time of the process (in seconds). See [`Process::start_time`].
+    pub fn start_time(&self) -> u64 {
+        self.start_time
+    }
+
+    /// Returns the memory usage of the process (in bytes). See [`Process::memory`].
+    pub fn memory(&self) -> u64 {
+        self.memory
+    }
+
+    /// Returns the CPU usage of the process (percentage). See [`Process::cpu_usage`].
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+
+    /// A PID can be reused once its process is gone, so the start time is needed to know if two
+    /// snapshots are of the same process.
+    fn key(&self) -> (Pid, u64) {
+        (self.pid, self.start_time)
+    }
+}
+
+#[cfg(feature = "disk")]
+#[derive(Clone, Debug)]
+pub(crate) struct DiskSnapshot {
+    pub(crate) mount_point: PathBuf,
+    pub(crate) total_space: u64,
+    pub(crate) available_space: u64,
+}
+
+#[cfg(feature = "network")]
+#[derive(Clone, Debug)]
+pub(crate) struct NetworkSnapshot {
+    pub(crate) interface_name: String,
+    pub(crate) total_received: u64,
+    pub(crate) total_transmitted: u64,
+}
+
+/// Minimum changes for a process to be part of [`SystemDiff::changed_processes`].
+///
+/// By default, any change of memory or CPU usage is reported.
+///
+/// ```no_run
+/// use sysinfo::{DiffThresholds, System};
+///
+/// let s = System::new_all();
+/// let before = s.snapshot();
+/// let after = s.snapshot();
+/// // Only the processes whose memory usage changed by more than 100 MB.
+/// let thresholds = DiffThresholds::default()
+///     .with_memory(100 * 1024 * 1024)
+///     .with_cpu_usage(f32::INFINITY);
+/// let diff = before.diff_with_thresholds(&after, thresholds);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiffThresholds {
+    memory: u64,
+    cpu_usage: f32,
+}
+
+impl DiffThresholds {
+    /// Reports the processes whose memory usage changed by more than `bytes`.
+    pub fn with_memory(mut self, bytes: u64) -> Self {
+        self.memory = bytes;
+        self
+    }
+
+    /// Reports the processes whose CPU usage changed by more than `percentage_points`.
+    pub fn with_cpu_usage(mut self, percentage_points: f32) -> Self {
+        self.cpu_usage = percentage_points;
+        self
+    }
+
+    /// Returns the memory threshold (in bytes).
+    pub fn memory(&self) -> u64 {
+        self.memory
+    }
+
+    /// Returns the CPU usage threshold (in percentage points).
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+}
+
+/// Change of a process between two [`SystemSnapshot`]s.
+///
+/// It is returned by [`SystemDiff::changed_processes`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProcessDelta {
+    pid: Pid,
+    name: OsString,
+    memory: i64,
+    cpu_usage: f32,
+}
+
+impl ProcessDelta {
+    /// Returns the PID of the process.
+    pub fn pid(&self) -> Pid {
+        self.pid
+    }
+
+    /// Returns the name of the process in the newer snapshot.
+    pub fn name(&self) -> &OsStr {
+        &self.name
+    }
+
+    /// Returns how much the memory usage grew (in bytes). It is negative if it shrank.
+    pub fn memory(&self) -> i64 {
+        self.memory
+    }
+
+    /// Returns how much the CPU usage grew (in percentage points). It is negative if it shrank.
+    pub fn cpu_usage(&self) -> f32 {
+        self.cpu_usage
+    }
+}
+
+/// Change of the space of a disk between two [`SystemSnapshot`]s.
+///
+/// It is returned by [`SystemDiff::disks`].
+#[cfg(feature = "disk")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskDelta {
+    mount_point: PathBuf,
+    total_space: u64,
+    available_space: i64,
+}
+
+#[cfg(feature = "disk")]
+impl DiskDelta {
+    /// Returns the mount point of the disk.
+    pub fn mount_point(&self) -> &Path {
+        &self.mount_point
+    }
+
+    /// Returns the total space of the disk (in bytes) in the newer snapshot.
+    pub fn total_space(&self) -> u64 {
+        self.total_space
+    }
+
+    /// Returns how much the available space grew (in bytes). It is negative if the disk is
+    /// filling up.
+    pub fn available_space(&self) -> i64 {
+        self.available_space
+    }
+}
+
+/// Traffic of a network interface between two [`SystemSnapshot`]s.
+///
+/// It is returned by [`SystemDiff::networks`].
+#[cfg(feature = "network")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NetworkDelta {
+    interface_name: String,
+    received: u64,
+    transmitted: u64,
+}
+
+#[cfg(feature = "network")]
+impl NetworkDelta {
+    /// Returns the name of the network interface.
+    pub fn interface_name(&self) -> &str {
+        &self.interface_name
+    }
+
+    /// Returns the number of bytes received between the two snapshots.
+    pub fn received(&self) -> u64 {
+        self.received
+    }
+
+    /// Returns the number of bytes transmitted between the two snapshots.
+    pub fn transmitted(&self) -> u64 {
+        self.transmitted
+    }
+}
+
+/// Changes between two [`SystemSnapshot`]s.
+///
+/// It is returned by [`SystemSnapshot::diff`]. All lists are sorted by PID, mount point or
+/// interface name.
+///
+/// ```no_run
+/// use sysinfo::{ProcessesToUpdate, System};
+///
+/// let mut s = System::new_all();
+/// let before = s.snapshot();
+/// std::thread::sleep(std::time::Duration::from_secs(5));
+/// s.refresh_processes(ProcessesToUpdate::All, true);
+/// let diff = before.diff(&s.snapshot());
+/// for process in diff.added_processes() {
+///     println!("new process: {} {:?}", process.pid(), process.name());
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SystemDiff {
+    added_processes: Vec<ProcessSnapshot>,
+    removed_processes: Vec<ProcessSnapshot>,
+    changed_processes: Vec<ProcessDelta>,
+    #[cfg(feature = "disk")]
+    disks: Vec<DiskDelta>,
+    #[cfg(feature = "network")]
+    networks: Vec<NetworkDelta>,
+}
+
+impl SystemDiff {
+    pub(crate) fn new(
+        older: &SystemSnapshot,
+        newer: &SystemSnapshot,
+        thresholds: DiffThresholds,
+    ) -> Self {
+        let mut diff = Self::default();
+        let old_processes: HashMap<_, _> = older
+            .processes()
+            .iter()
+            .map(|process| (process.key(), process))
+            .collect();
+        let new_processes: HashMap<_, _> = newer
+            .processes()
+            .iter()
+            .map(|process| (process.key(), process))
+            .collect();
+
+        for process in newer.processes() {
+            let Some(old) = old_processes.get(&process.key()) else {
+                diff.added_processes.push(process.clone());
+                continue;
+            };
+            let memory = process.memory as i64 - old.memory as i64;
+            let cpu_usage = process.cpu_usage - old.cpu_usage;
+            if memory.unsigned_abs() > thresholds.memory || cpu_usage.abs() > thresholds.cpu_usage {
+                diff.changed_processes.push(ProcessDelta {
+                    pid: process.pid,
+                    name: process.name.clone(),
+                    memory,
+                    cpu_usage,
+                });
+            }
+        }
+        diff.removed_processes.extend(
+            older
+                .processes()
+                .iter()
+                .filter(|process| !new_processes.contains_key(&process.key()))
+                .cloned(),
+        );
+        diff.added_processes
+            .sort_unstable_by_key(ProcessSnapshot::key);
+        diff.removed_processes
+            .sort_unstable_by_key(ProcessSnapshot::key);
+        diff.changed_processes
+            .sort_unstable_by_key(|process| process.pid);
+
+        #[cfg(feature = "disk")]
+        {
+            for disk in newer.disks() {
+                let Some(old) = older
+                    .disks()
+                    .iter()
+                    .find(|old| old.mount_point == disk.mount_point)
+                else {
+                    continue;
+                };
+                let available_space = disk.available_space as i64 - old.available_space as i64;
+                if available_space != 0 || disk.total_space != old.total_space {
+                    diff.disks.push(DiskDelta {
+                        mount_point: disk.mount_point.clone(),
+                        total_space: disk.total_space,
+                        available_space,
+                    });
+                }
+            }
+            diff.disks
+                .sort_unstable_by(|a, b| a.mount_point.cmp(&b.mount_point));
+        }
+        #[cfg(feature = "network")]
+        {
+            for network in newer.networks() {
+                let Some(old) = older
+                    .networks()
+                    .iter()
+                    .find(|old| old.interface_name == network.interface_name)
+                else {
+                    continue;
+                };
+                // The counters start again from `0` if the interface is reset.
+                diff.networks.push(NetworkDelta {
+                    interface_name: network.interface_name.clone(),
+                    received: network.total_received.saturating_sub(old.total_received),
+                    transmitted: network
+                        .total_transmitted
+                        .saturating_sub(old.total_transmitted),
+                });
+            }
+            diff.networks
+                .sort_unstable_by(|a, b| a.interface_name.cmp(&b.interface_name));
+        }
+        diff
+    }
+
+    /// Returns the processes which are only in the newer snapshot.
+    ///
+    /// A process whose PID was reused is both in the removed and in the added processes.
+    pub fn added_processes(&self) -> &[ProcessSnapshot] {
+        &self.added_processes
+    }
+
+    /// Returns the processes which are only in the older snapshot.
+    pub fn removed_processes(&self) -> &[ProcessSnapshot] {
+        &self.removed_processes
+    }
+
+    /// Returns the processes whose memory or CPU usage changed by more than the
+    /// [`DiffThresholds`].
+    pub fn changed_processes(&self) -> &[ProcessDelta] {
+        &self.changed_processes
+    }
+
+    /// Returns the disks whose space changed. Only the disks added to both snapshots with
+    /// [`SystemSnapshot::with_disks`] are compared.
+    #[cfg(feature = "disk")]
+    pub fn disks(&self) -> &[DiskDelta] {
+        &self.disks
+    }
+
+    /// Returns the traffic of the network interfaces. Only the interfaces added to both
+    /// snapshots with [`SystemSnapshot::with_networks`] are compared.
+    #[cfg(feature = "network")]
+    pub fn networks(&self) -> &[NetworkDelta] {
+        &self.networks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DiffThresholds, ProcessSnapshot, SystemDiff};
+    use crate::{Pid, System};
+
+    fn process(start_time: u64, memory: u64) -> ProcessSnapshot {
+        ProcessSnapshot {
+            pid: Pid::from_u32(42),
+            parent: None,
+            name: "foo".into(),
+            start_time,
+            memory,
+            cpu_usage: 0.,
+        }
+    }
+
+    #[test]
+    fn check_diff_processes() {
+        let s = System::new();
+        let older = s.snapshot().with_processes(vec![process(1, 10)]);
+
+        let newer = s.snapshot().with_processes(vec![process(1, 20)]);
+        let diff = SystemDiff::new(&older, &newer, DiffThresholds::default());
+        assert!(diff.added_processes().is_empty());
+        assert!(diff.removed_processes().is_empty());
+        assert_eq!(diff.changed_processes().len(), 1);
+        assert_eq!(diff.changed_processes()[0].memory(), 10);
+
+        // The PID was reused by another process.
+        let newer = s.snapshot().with_processes(vec![process(2, 20)]);
+        let diff = SystemDiff::new(&older, &newer, DiffThresholds::default());
+        assert!(diff.changed_processes().is_empty());
+        assert_eq!(diff.removed_processes().len(), 1);
+        assert_eq!(diff.removed_processes()[0].start_time(), 1);
+        assert_eq!(diff.added_processes().len(), 1);
+        assert_eq!(diff.added_processes()[0].start_time(), 2);
+    }
+}
@@ -1,7 +1,10 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
+use crate::common::impl_get_set::impl_get_set;
 use crate::{Gid, Uid, UserInner};
 
 /// Type containing user information.
@@ -18,6 +21,10 @@ use crate::{Gid, Uid, UserInner};
 /// ```
 pub struct User {
     pub(crate) inner: UserInner,
+    /// Groups resolved when refreshing the users list with [`UsersRefreshKind::groups`].
+    pub(crate) groups: Option<Vec<Group>>,
+    pub(crate) home_directory: Option<PathBuf>,
+    pub(crate) shell: Option<PathBuf>,
 }
 
 impl PartialEq for User {
@@ -80,6 +87,8 @@ impl User {
 
     /// Returns the name of the user.
     ///
+    /// On Windows, it doesn't include the domain, take a look at `User::domain` to get it.
+    ///
     /// ```no_run
     /// use sysinfo::Users;
     ///
@@ -92,10 +101,111 @@ impl User {
         self.inner.name()
     }
 
-    /// Returns the groups of the user.
+    /// Returns the full name of the user, or `None` if it isn't set.
+    ///
+    /// On unix, it is the first comma-separated component of the GECOS field. On Windows, it is
+    /// the full name of the account, or its comment if there is none.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     println!("{}", user.full_name().unwrap_or(user.name()));
+    /// }
+    /// ```
+    pub fn full_name(&self) -> Option<&str> {
+        self.inner.full_name()
+    }
+
+    /// Returns whether the account is disabled, or `None` if it is unknown.
+    ///
+    /// On Windows, it is only known for local accounts. On unix, the accounts with a locked
+    /// password are considered disabled, but it can only be known if the shadow file is readable
+    /// (usually only by root). It is always `None` on macOS.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     if user.is_disabled() == Some(true) {
+    ///         println!("{} is disabled", user.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_disabled(&self) -> Option<bool> {
+        self.inner.is_disabled()
+    }
+
+    /// Returns whether the account is locked out (because of too many failed login attempts), or
+    /// `None` if it is unknown.
+    ///
+    /// It is only known for local accounts on Windows.
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     if user.is_locked_out() == Some(true) {
+    ///         println!("{} is locked out", user.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn is_locked_out(&self) -> Option<bool> {
+        self.inner.is_locked_out()
+    }
+
+    /// Returns whether the password of the account expired, or `None` if it is unknown.
+    ///
+    /// It is only known for local accounts on Windows, and only if the process can access their
+    /// detailed information (usually only administrators).
+    ///
+    /// ```no_run
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// for user in users.list() {
+    ///     if user.password_expired() == Some(true) {
+    ///         println!("The password of {} expired", user.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn password_expired(&self) -> Option<bool> {
+        self.inner.password_expired()
+    }
+
+    /// Returns when the user last logged in, or `None` if they never did or if it is unknown.
     ///
     /// ⚠️ This is computed every time this method is called.
     ///
+    /// On Windows, it is only known for local accounts and it is the last logon on this machine,
+    /// not on the whole domain. On Linux, it is read from `/var/log/lastlog`, or from
+    /// `/var/log/wtmp` if there is no `lastlog` file. On macOS and FreeBSD, it is read from the
+    /// `utmpx` last login database. It is always `None` on Android and iOS.
+    ///
+    /// ```no_run
+    /// use std::time::{Duration, SystemTime};
+    /// use sysinfo::Users;
+    ///
+    /// let users = Users::new_with_refreshed_list();
+    /// let limit = SystemTime::now() - Duration::from_secs(90 * 24 * 60 * 60);
+    /// for user in users.list() {
+    ///     if user.last_login().is_some_and(|last_login| last_login < limit) {
+    ///         println!("{} didn't log in for 90 days", user.name());
+    ///     }
+    /// }
+    /// ```
+    pub fn last_login(&self) -> Option<SystemTime> {
+        self.inner.last_login()
+    }
+
+    /// Returns the groups of the user.
+    ///
+    /// ⚠️ Unless the users list was refreshed with [`UsersRefreshKind::groups`] enabled, this is
+    /// computed every time this method is called.
+    ///
     /// ```no_run
     /// use sysinfo::Users;
     ///
@@ -105,13 +215,61 @@ impl User {
     /// }
     /// ```
     pub fn groups(&self) -> Vec<Group> {
-        self.inner.groups()
+        match self.groups {
+            Some(ref groups) => groups.clone(),
+            None => self.inner.groups(),
+        }
+    }
+
+    /// Returns the home directory of the user.
+    ///
+    /// It is only retrieved if the users list was refreshed with
+    /// [`UsersRefreshKind::home_directory`] enabled, otherwise `None` is returned. It is also
+    /// `None` if the user has no home directory.
+    ///
+    /// On Windows, this is the home folder set on the account if there is one, and the profile
+    /// directory of the user otherwise.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Users, UsersRefreshKind};
+    ///
+    /// let users = Users::new_with_refreshed_list_specifics(
+    ///     UsersRefreshKind::nothing().with_home_directory(),
+    /// );
+    /// for user in users.list() {
+    ///     println!("{} lives in {:?}", user.name(), user.home_directory());
+    /// }
+    /// ```
+    pub fn home_directory(&self) -> Option<&Path> {
+        self.home_directory.as_deref()
+    }
+
+    /// Returns the login shell of the user.
+    ///
+    /// It is only retrieved if the users list was refreshed with [`UsersRefreshKind::shell`]
+    /// enabled, otherwise `None` is returned. Accounts which are not allowed to log in usually
+    /// have a shell like `/usr/sbin/nologin`, which is returned as is.
+    ///
+    /// ⚠️ This information is not available on Windows, so it always returns `None`.
+    ///
+    /// ```no_run
+    /// use sysinfo::{Users, UsersRefreshKind};
+    ///
+    /// let users = Users::new_with_refreshed_list_specifics(UsersRefreshKind::nothing().with_shell());
+    /// for user in users.list() {
+    ///     println!("{} uses {:?}", user.name(), user.shell());
+    /// }
+    /// ```
+    pub fn shell(&self) -> Option<&Path> {
+        self.shell.as_deref()
     }
 
     /// Returns the domain of the user.
     ///
+    /// For local accounts, it is the name of the machine. For well-known accounts, it is their
+    /// standard domain, like `NT AUTHORITY` for `SYSTEM`.
+    ///
     /// ⚠️ This information is only available on Windows.
-    /// On other platforms, this method will return `None`.
     ///
     /// ```no_run
     /// use sysinfo::Users;
@@ -131,10 +289,13 @@ impl User {
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub(crate) struct GroupInner {
     pub(crate) id: Gid,
     pub(crate) name: String,
+    pub(crate) members: Vec<String>,
+    #[cfg(windows)]
+    pub(crate) member_ids: Vec<Uid>,
 }
 
 /// Type containing group information.
@@ -158,7 +319,7 @@ pub(crate) struct GroupInner {
 ///     }
 /// }
 /// ```
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub struct Group {
     pub(crate) inner: GroupInner,
 }
@@ -199,6 +360,47 @@ impl Group {
     pub fn name(&self) -> &str {
         self.inner.name()
     }
+
+    /// Returns the names of the users which are members of the group.
+    ///
+    /// Users having this group as primary group are only listed if they were explicitly added
+    /// to it.
+    ///
+    /// ⚠️ This information is only retrieved by [`Groups`], it is always empty for the groups
+    /// returned by [`User::groups`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Groups;
+    ///
+    /// let groups = Groups::new_with_refreshed_list();
+    ///
+    /// if let Some(group) = groups.list().iter().find(|group| group.name() == "docker") {
+    ///     println!("docker members: {:?}", group.members());
+    /// }
+    /// ```
+    pub fn members(&self) -> &[String] {
+        self.inner.members()
+    }
+
+    /// Returns the SIDs of the members of the group. The members whose SID couldn't be
+    /// retrieved are not listed, so it isn't always matching [`Group::members`].
+    ///
+    /// ⚠️ This information is only available on Windows and, like [`Group::members`], only
+    /// retrieved by [`Groups`].
+    ///
+    /// ```no_run
+    /// use sysinfo::Groups;
+    ///
+    /// let groups = Groups::new_with_refreshed_list();
+    ///
+    /// for group in groups.list() {
+    ///     println!("{}: {:?}", group.name(), group.member_ids());
+    /// }
+    /// ```
+    #[cfg(windows)]
+    pub fn member_ids(&self) -> &[Uid] {
+        &self.inner.member_ids
+    }
 }
 
 /// Interacting with users.
@@ -285,6 +487,8 @@ impl Users {
 
     /// Creates a new [`Users`][crate::Users] type with the user list loaded.
     ///
+    /// Equivalent to <code>[Users::new_with_refreshed_list_specifics]\([UsersRefreshKind::nothing]\())</code>.
+    ///
     /// ```no_run
     /// use sysinfo::Users;
     ///
@@ -294,8 +498,23 @@ impl Users {
     /// }
     /// ```
     pub fn new_with_refreshed_list() -> Self {
+        Self::new_with_refreshed_list_specifics(UsersRefreshKind::nothing())
+    }
+
+    /// Creates a new [`Users`][crate::Users] type with the user list loaded
+    /// and refreshed according to the given [`UsersRefreshKind`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Users, UsersRefreshKind};
+    ///
+    /// let mut users = Users::new_with_refreshed_list_specifics(UsersRefreshKind::everything());
+    /// for user in users.list() {
+    ///     println!("{} is in {:?}", user.name(), user.groups());
+    /// }
+    /// ```
+    pub fn new_with_refreshed_list_specifics(refreshes: UsersRefreshKind) -> Self {
         let mut users = Self::new();
-        users.refresh();
+        users.refresh_specifics(refreshes);
         users
     }
 
@@ -329,6 +548,8 @@ impl Users {
 
     /// The user list will be emptied then completely recomputed.
     ///
+    /// Equivalent to <code>[Users::refresh_specifics]\([UsersRefreshKind::nothing]\())</code>.
+    ///
     /// ```no_run
     /// use sysinfo::Users;
     ///
@@ -336,7 +557,37 @@ impl Users {
     /// users.refresh();
     /// ```
     pub fn refresh(&mut self) {
+        self.refresh_specifics(UsersRefreshKind::nothing());
+    }
+
+    /// The user list will be emptied then completely recomputed according to the given
+    /// [`UsersRefreshKind`].
+    ///
+    /// ```no_run
+    /// use sysinfo::{Users, UsersRefreshKind};
+    ///
+    /// let mut users = Users::new();
+    /// users.refresh_specifics(UsersRefreshKind::nothing().with_groups());
+    /// ```
+    pub fn refresh_specifics(&mut self, refreshes: UsersRefreshKind) {
         crate::sys::get_users(&mut self.users);
+        if !refreshes.groups() && !refreshes.home_directory() && !refreshes.shell() {
+            return;
+        }
+        for user in self.users.iter_mut() {
+            if refreshes.groups() {
+                user.groups = Some(user.inner.groups());
+            }
+            if refreshes.home_directory() || refreshes.shell() {
+                let (home_directory, shell) = user.inner.home_directory_and_shell();
+                if refreshes.home_directory() {
+                    user.home_directory = home_directory;
+                }
+                if refreshes.shell() {
+                    user.shell = shell;
+                }
+            }
+        }
     }
 
     /// Returns the [`User`] matching the given `user_id`.
@@ -370,6 +621,113 @@ impl Users {
     pub fn get_user_by_id(&self, user_id: &Uid) -> Option<&User> {
         self.users.iter().find(|user| user.id() == user_id)
     }
+
+    /// Returns the [`User`] matching the given `user_id`. If it isn't in the list, only this
+    /// user is retrieved and added to the list, which is much faster than refreshing the whole
+    /// list on systems with a lot of users.
+    ///
+    /// The retrieved user has the same information as the ones added by [`Users::refresh`],
+    /// except on Windows where [`User::full_name`] is only available for local accounts.
+    ///
+    /// Contrary to [`Users::refresh`], it can return the users which are usually filtered out,
+    /// like system accounts, since it doesn't enumerate them.
+    ///
+    #[cfg_attr(feature = "system", doc = "```no_run")]
+    #[cfg_attr(not(feature = "system"), doc = "```ignore")]
+    /// use sysinfo::{Pid, System, Users};
+    ///
+    /// let mut s = System::new_all();
+    /// let mut users = Users::new();
+    ///
+    /// if let Some(process) = s.process(Pid::from(1337)) {
+    ///     if let Some(user_id) = process.user_id() {
+    ///         println!(
+    ///             "User for process 1337: {:?}",
+    ///             users.get_user_by_id_or_fetch(user_id),
+    ///         );
+    ///     }
+    /// }
+    /// ```
+    pub fn get_user_by_id_or_fetch(&mut self, user_id: &Uid) -> Option<&User> {
+        if let Some(pos) = self.users.iter().position(|user| user.id() == user_id) {
+            return self.users.get(pos);
+        }
+        let user = crate::sys::get_user_by_id(user_id)?;
+        self.users.push(user);
+        self.users.last()
+    }
+}
+
+/// Used to determine what you want to retrieve when refreshing the [`Users`] list.
+///
+/// By default, only the user IDs and names are retrieved, which is what you need if you only
+/// want to map a [`Uid`] to a user name. Resolving the other information can be slow on systems
+/// with a lot of users (like the ones backed by LDAP or SSSD).
+///
+/// * `groups` is about resolving the [`User::groups`] information once when refreshing instead
+///   of every time [`User::groups`] is called.
+/// * `home_directory` is about retrieving the [`User::home_directory`] information.
+/// * `shell` is about retrieving the [`User::shell`] information.
+///
+/// ```no_run
+/// use sysinfo::{Users, UsersRefreshKind};
+///
+/// let users = Users::new_with_refreshed_list_specifics(UsersRefreshKind::nothing().with_groups());
+///
+/// for user in users.list() {
+///     println!("{} is in {} groups", user.name(), user.groups().len());
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UsersRefreshKind {
+    groups: bool,
+    home_directory: bool,
+    shell: bool,
+}
+
+impl UsersRefreshKind {
+    /// Creates a new `UsersRefreshKind` with every refresh set to `false`.
+    ///
+    /// ```
+    /// use sysinfo::UsersRefreshKind;
+    ///
+    /// let r = UsersRefreshKind::nothing();
+    ///
+    /// assert_eq!(r.groups(), false);
+    /// assert_eq!(r.home_directory(), false);
+    /// assert_eq!(r.shell(), false);
+    /// ```
+    pub fn nothing() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new `UsersRefreshKind` with every refresh set to `true`.
+    ///
+    /// ```
+    /// use sysinfo::UsersRefreshKind;
+    ///
+    /// let r = UsersRefreshKind::everything();
+    ///
+    /// assert_eq!(r.groups(), true);
+    /// assert_eq!(r.home_directory(), true);
+    /// assert_eq!(r.shell(), true);
+    /// ```
+    pub fn everything() -> Self {
+        Self {
+            groups: true,
+            home_directory: true,
+            shell: true,
+        }
+    }
+
+    impl_get_set!(UsersRefreshKind, groups, with_groups, without_groups);
+    impl_get_set!(
+        UsersRefreshKind,
+        home_directory,
+        with_home_directory,
+        without_home_directory
+    );
+    impl_get_set!(UsersRefreshKind, shell, with_shell, without_shell);
 }
 
 /// Interacting with groups.
@@ -523,6 +881,52 @@ mod tests {
         assert!(users.list().len() >= MIN_USERS);
     }
 
+    #[test]
+    fn check_list_with_groups() {
+        let users = Users::new_with_refreshed_list_specifics(UsersRefreshKind::nothing());
+        assert!(users.list().iter().all(|user| user.groups.is_none()));
+
+        let users = Users::new_with_refreshed_list_specifics(UsersRefreshKind::everything());
+        if IS_SUPPORTED_SYSTEM {
+            assert!(!users.list().is_empty());
+        }
+        for user in users.list() {
+            assert_eq!(user.groups.as_deref(), Some(user.inner.groups().as_slice()));
+        }
+    }
+
+    #[cfg(all(target_os = "linux", not(feature = "unknown-ci")))]
+    #[test]
+    fn check_list_with_home_directory_and_shell() {
+        use std::path::Path;
+
+        let users = Users::new_with_refreshed_list_specifics(UsersRefreshKind::nothing());
+        assert!(users
+            .list()
+            .iter()
+            .all(|user| user.home_directory().is_none() && user.shell().is_none()));
+
+        let users = Users::new_with_refreshed_list_specifics(
+            UsersRefreshKind::nothing()
+                .with_home_directory()
+                .with_shell(),
+        );
+        assert!(users.list().len() >= MIN_USERS);
+        let passwd = std::fs::read_to_string("/etc/passwd").unwrap();
+        let to_path = |field: &str| (!field.is_empty()).then(|| Path::new(field).to_path_buf());
+        for user in users.list() {
+            let uid = user.id().to_string();
+            // Accounts sharing a UID all get the information of the first entry.
+            let fields = passwd
+                .lines()
+                .map(|line| line.split(':').collect::<Vec<_>>())
+                .find(|fields| fields.get(2) == Some(&uid.as_str()))
+                .unwrap();
+            assert_eq!(user.home_directory(), to_path(fields[5]).as_deref());
+            assert_eq!(user.shell(), to_path(fields[6]).as_deref());
+        }
+    }
+
     // This test exists to ensure that the `TryFrom<usize>` and `FromStr` traits are implemented
     // on `Uid`, `Gid` and `Pid`.
     #[allow(clippy::unnecessary_fallible_conversions)]
@@ -553,4 +957,24 @@ mod tests {
         }
         assert!(!Groups::new_with_refreshed_list().is_empty());
     }
+
+    #[cfg(all(target_os = "linux", not(feature = "unknown-ci")))]
+    #[test]
+    fn check_groups_members() {
+        let content = std::fs::read_to_string("/etc/group").unwrap();
+        let groups = Groups::new_with_refreshed_list();
+        for line in content.lines() {
+            let fields = line.split(':').collect::<Vec<_>>();
+            let Some(group) = groups.list().iter().find(|group| group.name() == fields[0]) else {
+                continue;
+            };
+            let members = fields
+                .get(3)
+                .into_iter()
+                .flat_map(|members| members.split(','))
+                .filter(|member| !member.is_empty())
+                .collect::<Vec<_>>();
+            assert_eq!(group.members(), members);
+        }
+    }
 }
@@ -0,0 +1,228 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+/// Virtualization environment the system is running in.
+///
+/// It is returned by [`System::virtualization`][crate::System::virtualization].
+///
+/// ```no_run
+/// use sysinfo::{System, Virtualization};
+///
+/// match System::virtualization() {
+///     Some(Virtualization::BareMetal) => println!("not virtualized"),
+///     Some(Virtualization::VirtualMachine(hypervisor)) => println!("VM: {hypervisor:?}"),
+///     Some(Virtualization::Container { kind, host }) => {
+///         println!("container: {kind:?}, running in {host:?}")
+///     }
+///     None => println!("unknown"),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Virtualization {
+    /// The system runs directly on the hardware.
+    BareMetal,
+    /// The system runs in a virtual machine.
+    VirtualMachine(Hypervisor),
+    /// The system runs in a container.
+    Container {
+        /// Kind of the container.
+        kind: ContainerKind,
+        /// Hypervisor of the virtual machine the container runs in, if any.
+        host: Option<Hypervisor>,
+    },
+}
+
+impl Virtualization {
+    /// Returns the hypervisor of the virtual machine the system runs in, including when it
+    /// runs in a container inside a virtual machine.
+    ///
+    /// ```no_run
+    /// use sysinfo::System;
+    ///
+    /// if let Some(hypervisor) = System::virtualization().and_then(|v| v.hypervisor().cloned()) {
+    ///     println!("running in a {hypervisor:?} virtual machine");
+    /// }
+    /// ```
+    pub fn hypervisor(&self) -> Option<&Hypervisor> {
+        match self {
+            Self::BareMetal => None,
+            Self::VirtualMachine(hypervisor) => Some(hypervisor),
+            Self::Container { host, .. } => host.as_ref(),
+        }
+    }
+}
+
+/// Hypervisor of a virtual machine.
+///
+/// It is used by [`Virtualization`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Hypervisor {
+    /// KVM or QEMU.
+    KvmQemu,
+    /// VMware.
+    VMware,
+    /// Microsoft Hyper-V.
+    HyperV,
+    /// Oracle VirtualBox.
+    VirtualBox,
+    /// Xen.
+    Xen,
+    /// Parallels.
+    Parallels,
+    /// Windows Subsystem for Linux.
+    Wsl,
+    /// Another hypervisor. Its name is empty if it is only known that the system runs in a
+    /// virtual machine.
+    Other(String),
+}
+
+/// Kind of a container.
+///
+/// It is used by [`Virtualization`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerKind {
+    /// Docker.
+    Docker,
+    /// Podman.
+    Podman,
+    /// LXC.
+    Lxc,
+    /// Another kind of container, like `systemd-nspawn` or a FreeBSD jail.
+    Other(String),
+}
+
+/// Matches the vendor or the product name of the SMBIOS (DMI) information, which are the most
+/// reliable source since the hypervisors can expose the CPUID signature of another one.
+#[allow(dead_code)]
+pub(crate) fn hypervisor_from_dmi<'a>(
+    values: impl IntoIterator<Item = Option<&'a str>>,
+) -> Option<Hypervisor> {
+    const HYPERVISORS: &[(&str, Hypervisor)] = &[
+        ("KVM", Hypervisor::KvmQemu),
+        ("QEMU", Hypervisor::KvmQemu),
+        ("VMware", Hypervisor::VMware),
+        ("VMW", Hypervisor::VMware),
+        ("innotek GmbH", Hypervisor::VirtualBox),
+        ("VirtualBox", Hypervisor::VirtualBox),
+        ("Xen", Hypervisor::Xen),
+        ("Parallels", Hypervisor::Parallels),
+        // The vendor is `Microsoft Corporation` on both the Surface devices and Hyper-V.
+        ("Virtual Machine", Hypervisor::HyperV),
+    ];
+
+    values.into_iter().flatten().find_map(|value| {
+        HYPERVISORS
+            .iter()
+            .find(|(prefix, _)| value.starts_with(prefix))
+            .map(|(_, hypervisor)| hypervisor.clone())
+    })
+}
+
+#[cfg(any(test, target_arch = "x86", target_arch = "x86_64"))]
+fn hypervisor_from_cpuid_vendor(vendor: &[u8; 12]) -> Hypervisor {
+    match vendor {
+        b"KVMKVMKVM\0\0\0" | b"TCGTCGTCGTCG" => Hypervisor::KvmQemu,
+        b"VMwareVMware" => Hypervisor::VMware,
+        b"Microsoft Hv" => Hypervisor::HyperV,
+        b"XenVMMXenVMM" => Hypervisor::Xen,
+        b"VBoxVBoxVBox" => Hypervisor::VirtualBox,
+        b" lrpepyh  vr" | b"prl hyperv  " => Hypervisor::Parallels,
+        _ => Hypervisor::Other(
+            String::from_utf8_lossy(vendor)
+                .trim_matches(|c: char| c == '\0' || c.is_whitespace())
+                .to_owned(),
+        ),
+    }
+}
+
+/// Returns the hypervisor advertised by the CPU, if the "hypervisor present" bit is set.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[allow(dead_code)]
+pub(crate) fn cpuid_hypervisor() -> Option<Hypervisor> {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::__cpuid;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::__cpuid;
+
+    // `__cpuid` is only safe since Rust 1.89.
+    #[allow(unused_unsafe)]
+    unsafe {
+        if __cpuid(1).ecx & (1 << 31) == 0 {
+            return None;
+        }
+        let leaf = __cpuid(0x4000_0000);
+        let mut vendor = [0; 12];
+        vendor[..4].copy_from_slice(&leaf.ebx.to_le_bytes());
+        vendor[4..8].copy_from_slice(&leaf.ecx.to_le_bytes());
+        vendor[8..].copy_from_slice(&leaf.edx.to_le_bytes());
+        let hypervisor = hypervisor_from_cpuid_vendor(&vendor);
+        // Windows itself runs on top of Hyper-V when virtualization-based security is enabled,
+        // in which case it is the root partition and can create partitions.
+        if hypervisor == Hypervisor::HyperV
+            && leaf.eax >= 0x4000_0003
+            && __cpuid(0x4000_0003).ebx & 1 != 0
+        {
+            return None;
+        }
+        Some(hypervisor)
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[allow(dead_code)]
+pub(crate) fn cpuid_hypervisor() -> Option<Hypervisor> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_hypervisor_from_dmi() {
+        assert_eq!(
+            hypervisor_from_dmi([Some("QEMU"), Some("Standard PC (Q35 + ICH9, 2009)")]),
+            Some(Hypervisor::KvmQemu)
+        );
+        assert_eq!(
+            hypervisor_from_dmi([Some("VMware, Inc."), Some("VMware7,1")]),
+            Some(Hypervisor::VMware)
+        );
+        assert_eq!(
+            hypervisor_from_dmi([Some("innotek GmbH"), Some("VirtualBox")]),
+            Some(Hypervisor::VirtualBox)
+        );
+        assert_eq!(
+            hypervisor_from_dmi([Some("Microsoft Corporation"), Some("Virtual Machine")]),
+            Some(Hypervisor::HyperV)
+        );
+        assert_eq!(
+            hypervisor_from_dmi([None, Some("Parallels-ARM")]),
+            Some(Hypervisor::Parallels)
+        );
+        assert_eq!(
+            hypervisor_from_dmi([Some("Microsoft Corporation"), Some("Surface Pro 7")]),
+            None
+        );
+        assert_eq!(hypervisor_from_dmi([Some("Dell Inc."), None]), None);
+    }
+
+    #[test]
+    fn check_hypervisor_from_cpuid_vendor() {
+        assert_eq!(
+            hypervisor_from_cpuid_vendor(b"KVMKVMKVM\0\0\0"),
+            Hypervisor::KvmQemu
+        );
+        assert_eq!(
+            hypervisor_from_cpuid_vendor(b"Microsoft Hv"),
+            Hypervisor::HyperV
+        );
+        assert_eq!(
+            hypervisor_from_cpuid_vendor(b" lrpepyh  vr"),
+            Hypervisor::Parallels
+        );
+        assert_eq!(
+            hypervisor_from_cpuid_vendor(b"bhyve bhyve "),
+            Hypervisor::Other("bhyve bhyve".to_owned())
+        );
+    }
+}
@@ -29,6 +29,22 @@ impl std::fmt::Debug for crate::System {
     }
 }
 
+#[cfg(feature = "system")]
+impl std::fmt::Debug for crate::SharedSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedSystem")
+            .field("snapshot", &self.load())
+            .finish()
+    }
+}
+
+#[cfg(feature = "system")]
+impl std::fmt::Debug for crate::ProcessWatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProcessWatcher").finish_non_exhaustive()
+    }
+}
+
 #[cfg(feature = "system")]
 impl std::fmt::Debug for crate::Process {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -44,6 +60,8 @@ impl std::fmt::Debug for crate::Process {
             .field("virtual memory usage", &self.virtual_memory())
             .field("CPU usage", &self.cpu_usage())
             .field("accumulated CPU time", &self.accumulated_cpu_time())
+            .field("CPU time", &self.cpu_time())
+            .field("thread count", &self.thread_count())
             .field("status", &self.status())
             .field("root", &self.root())
             .field("disk_usage", &self.disk_usage())
@@ -107,6 +125,76 @@ impl std::fmt::Debug for crate::Component {
     }
 }
 
+#[cfg(feature = "component")]
+impl std::fmt::Debug for crate::Fans {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "component")]
+impl std::fmt::Debug for crate::Fan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ", self.label())?;
+        if let Some(rpm) = self.rpm() {
+            write!(f, "speed: {rpm} RPM (")?;
+        } else {
+            f.write_str("speed: unknown (")?;
+        }
+        if let Some(min_rpm) = self.min_rpm() {
+            write!(f, "min: {min_rpm} RPM / ")?;
+        } else {
+            f.write_str("min: unknown / ")?;
+        }
+        if let Some(max_rpm) = self.max_rpm() {
+            write!(f, "max: {max_rpm} RPM)")
+        } else {
+            f.write_str("max: unknown)")
+        }
+    }
+}
+
+#[cfg(feature = "component")]
+impl std::fmt::Debug for crate::PowerSensors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "component")]
+impl std::fmt::Debug for crate::PowerSensor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:?}: ", self.label(), self.kind())?;
+        if let Some(value) = self.value() {
+            write!(f, "{value}{}", self.unit())
+        } else {
+            f.write_str("unknown")
+        }
+    }
+}
+
+#[cfg(feature = "battery")]
+impl std::fmt::Debug for crate::Batteries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(feature = "battery")]
+impl std::fmt::Debug for crate::Battery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Battery")
+            .field("name", &self.name())
+            .field("charge", &self.charge_percent())
+            .field("state", &self.state())
+            .field("cycle count", &self.cycle_count())
+            .field("design capacity", &self.design_capacity())
+            .field("full charge capacity", &self.full_charge_capacity())
+            .field("power", &self.power_watts())
+            .finish()
+    }
+}
+
 #[cfg(feature = "network")]
 impl std::fmt::Debug for crate::Networks {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -114,6 +202,13 @@ impl std::fmt::Debug for crate::Networks {
     }
 }
 
+#[cfg(feature = "network")]
+impl std::fmt::Debug for crate::Connections {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 #[cfg(feature = "network")]
 impl std::fmt::Debug for crate::NetworkData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -131,6 +226,17 @@ impl std::fmt::Debug for crate::NetworkData {
             .field("errors outcome", &self.errors_on_transmitted())
             .field("total errors outcome", &self.total_errors_on_transmitted())
             .field("maximum transfer unit", &self.mtu())
+            .field("is loopback", &self.is_loopback())
+            .field("is up", &self.is_up())
+            .field("is point-to-point", &self.is_point_to_point())
+            .field("supports multicast", &self.supports_multicast())
+            .field("supports broadcast", &self.supports_broadcast())
+            .field("kind", &self.kind())
+            .field("index", &self.index())
+            .field("friendly name", &self.friendly_name())
+            .field("master", &self.master())
+            .field("lower device", &self.lower_device())
+            .field("VLAN ID", &self.vlan_id())
             .finish()
     }
 }
@@ -142,6 +248,13 @@ impl std::fmt::Debug for crate::Users {
     }
 }
 
+#[cfg(feature = "user")]
+impl std::fmt::Debug for crate::Sessions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
 #[cfg(feature = "user")]
 impl std::fmt::Debug for crate::User {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -149,6 +262,12 @@ impl std::fmt::Debug for crate::User {
             .field("uid", &self.id())
             .field("gid", &self.group_id())
             .field("name", &self.name())
+            .field("full name", &self.full_name())
+            .field("disabled", &self.is_disabled())
+            .field("locked out", &self.is_locked_out())
+            .field("password expired", &self.password_expired())
+            .field("home directory", &self.home_directory())
+            .field("shell", &self.shell())
             .finish()
     }
 }
@@ -68,22 +68,83 @@ cfg_if! {
     }
 }
 
+#[cfg(all(
+    feature = "nvml",
+    any(target_os = "linux", windows),
+    not(feature = "unknown-ci")
+))]
+mod nvml;
+
+#[cfg(all(
+    feature = "async",
+    any(
+        feature = "system",
+        feature = "disk",
+        feature = "network",
+        feature = "component",
+        feature = "user"
+    )
+))]
+pub use crate::common::async_refresh::RefreshFuture;
+#[cfg(feature = "battery")]
+pub use crate::common::battery::{Batteries, Battery, BatteryState};
+pub use crate::common::byte_size::{BytePrefix, ByteSize};
 #[cfg(feature = "component")]
-pub use crate::common::component::{Component, Components};
+pub use crate::common::component::{
+    Component, Components, Fan, Fans, PowerSensor, PowerSensorKind, PowerSensors,
+};
+#[cfg(feature = "network")]
+pub use crate::common::connection::{
+    Connection, ConnectionProtocol, Connections, ConnectionsFilter, ConnectionsRefreshKind,
+    TcpState,
+};
+#[cfg(feature = "system")]
+pub use crate::common::cpu_arch::{Arch, ArmVersion, CpuArchDetail, Endianness, X86Level};
 #[cfg(feature = "disk")]
 pub use crate::common::disk::{Disk, DiskKind, DiskRefreshKind, Disks};
+#[cfg(feature = "exe-hash")]
+pub use crate::common::exe_hash::HashAlgorithm;
+#[cfg(feature = "system")]
+pub use crate::common::field_status::{FieldStatus, ProcessField};
 #[cfg(feature = "network")]
 pub use crate::common::network::{
-    IpNetwork, IpNetworkFromStrError, MacAddr, MacAddrFromStrError, NetworkData, Networks,
+    InterfaceKind, IpNetwork, IpNetworkFromStrError, IpScope, MacAddr, MacAddrFromStrError,
+    NetworkData, NetworkTotalsFilter, Networks, NetworksRefreshKind, RouteInfo, WifiInfo,
+};
+#[cfg(feature = "system")]
+pub use crate::common::process_tree::{ProcessTree, ProcessTreeNode};
+#[cfg(feature = "system")]
+pub use crate::common::process_watcher::{
+    ProcessEvent, ProcessEventKind, ProcessWatcher, WatchProcessesError,
 };
 #[cfg(feature = "system")]
+pub use crate::common::rapl::RaplEnergy;
+#[cfg(any(feature = "system", feature = "disk", feature = "network"))]
+pub use crate::common::refresh_error::{RefreshError, RefreshSubsystem};
+#[cfg(feature = "user")]
+pub use crate::common::session::{Session, SessionKind, Sessions};
+#[cfg(feature = "system")]
+pub use crate::common::shared_system::{CpuSnapshot, SharedSystem, SystemSnapshot};
+#[cfg(feature = "system")]
 pub use crate::common::system::{
-    get_current_pid, CGroupLimits, Cpu, CpuRefreshKind, KillError, LoadAvg, MemoryRefreshKind, Pid,
-    Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, RefreshKind, Signal, System,
-    ThreadKind, UpdateKind,
+    get_current_pid, process_exists, CGroupLimits, Cpu, CpuRefreshKind, DomainKind, FdUsage,
+    HardwareInfo, IoPriority, IoPriorityClass, IoPriorityError, KillError, LoadAvg,
+    MemoryRefreshKind, Namespaces, Pressure, PressureLevel, PressureStall, Process, ProcessChanges,
+    ProcessRefreshKind, ProcessSortKey, ProcessStatus, ProcessesToUpdate, RefreshKind, Signal,
+    System, ThreadKind, TpmVersion, UpdateKind,
 };
+#[cfg(all(feature = "system", feature = "disk"))]
+pub use crate::common::system_diff::DiskDelta;
+#[cfg(all(feature = "system", feature = "network"))]
+pub use crate::common::system_diff::NetworkDelta;
+#[cfg(feature = "system")]
+pub use crate::common::system_diff::{DiffThresholds, ProcessDelta, ProcessSnapshot, SystemDiff};
 #[cfg(feature = "user")]
-pub use crate::common::user::{Group, Groups, User, Users};
+pub use crate::common::user::{Group, Groups, User, Users, UsersRefreshKind};
+#[cfg(feature = "system")]
+pub use crate::common::virtualization::{ContainerKind, Hypervisor, Virtualization};
+#[cfg(any(feature = "system", feature = "network"))]
+pub use crate::common::Pid;
 #[cfg(any(feature = "user", feature = "system"))]
 pub use crate::common::{Gid, Uid};
 #[cfg(feature = "system")]
@@ -96,10 +157,14 @@ pub use crate::common::DiskUsage;
 pub(crate) use crate::common::user::GroupInner;
 #[cfg(feature = "user")]
 pub(crate) use crate::sys::UserInner;
+#[cfg(feature = "battery")]
+pub(crate) use crate::sys::{BatteriesInner, BatteryInner};
 #[cfg(feature = "component")]
-pub(crate) use crate::sys::{ComponentInner, ComponentsInner};
+pub(crate) use crate::sys::{
+    ComponentInner, ComponentsInner, FanInner, FansInner, PowerSensorInner, PowerSensorsInner,
+};
 #[cfg(feature = "system")]
-pub(crate) use crate::sys::{CpuInner, ProcessInner, SystemInner};
+pub(crate) use crate::sys::{CpuInner, ProcessInner, ProcessWatcherInner, SystemInner};
 #[cfg(feature = "disk")]
 pub(crate) use crate::sys::{DiskInner, DisksInner};
 #[cfg(feature = "network")]
@@ -128,11 +193,12 @@ mod unknown;
 #[cfg(any())]
 mod windows;
 
-/// This function is only used on Linux targets, when the `system` feature is enabled. In other
-/// cases, it does nothing and returns `false`.
+/// This function is only used on Linux targets, when the `system` or `component` feature is
+/// enabled. In other cases, it does nothing and returns `false`.
 ///
-/// On Linux, to improve performance, we keep a `/proc` file open for each process we index with
-/// a maximum number of files open equivalent to half of the system limit.
+/// On Linux, to improve performance, we keep a `/proc` file open for each process we index and
+/// the `hwmon` input file of each component with a maximum number of files open equivalent to
+/// half of the system limit.
 ///
 /// The problem is that some users might need all the available file descriptors so we need to
 /// allow them to change this limit.
@@ -154,12 +220,12 @@ mod windows;
 /// ```
 pub fn set_open_files_limit(mut _new_limit: usize) -> bool {
     cfg_if! {
-        if #[cfg(all(feature = "system", not(feature = "unknown-ci"), any(target_os = "linux", target_os = "android")))]
+        if #[cfg(all(any(feature = "system", feature = "component"), not(feature = "unknown-ci"), any(target_os = "linux", target_os = "android")))]
         {
-            use crate::sys::system::remaining_files;
+            use crate::sys::utils::{get_max_nb_fds, remaining_files};
             use std::sync::atomic::Ordering;
 
-            let max = sys::system::get_max_nb_fds();
+            let max = get_max_nb_fds();
             if _new_limit > max {
                 _new_limit = max;
             }
@@ -196,23 +262,59 @@ use sysinfo::", stringify!($imports), r";
     compile_fail_import!(
         no_system_feature =>
         get_current_pid,
+        process_exists,
+        Arch,
+        ArmVersion,
         CGroupLimits,
+        ContainerKind,
         Cpu,
+        CpuArchDetail,
         CpuRefreshKind,
+        CpuSnapshot,
+        DiffThresholds,
+        DiskDelta,
         DiskUsage,
+        DomainKind,
+        Endianness,
+        FdUsage,
+        HardwareInfo,
+        Hypervisor,
+        IoPriority,
+        IoPriorityClass,
+        IoPriorityError,
         KillError,
         LoadAvg,
         MemoryRefreshKind,
-        Pid,
+        Namespaces,
+        Pressure,
+        PressureLevel,
+        PressureStall,
         Process,
+        ProcessChanges,
+        ProcessDelta,
+        ProcessEvent,
+        ProcessEventKind,
         ProcessesToUpdate,
         ProcessRefreshKind,
+        ProcessSnapshot,
+        ProcessSortKey,
         ProcessStatus,
+        ProcessTree,
+        ProcessTreeNode,
+        ProcessWatcher,
+        RaplEnergy,
         RefreshKind,
+        SharedSystem,
         Signal,
         System,
+        SystemDiff,
+        SystemSnapshot,
         ThreadKind,
+        TpmVersion,
         UpdateKind,
+        Virtualization,
+        WatchProcessesError,
+        X86Level,
     );
 
     #[cfg(not(feature = "disk"))]
@@ -223,20 +325,52 @@ use sysinfo::", stringify!($imports), r";
         DiskKind,
     );
 
+    #[cfg(not(feature = "battery"))]
+    compile_fail_import!(
+        no_battery_feature =>
+        Batteries,
+        Battery,
+        BatteryState,
+    );
+
     #[cfg(not(feature = "component"))]
     compile_fail_import!(
         no_component_feature =>
         Component,
         Components,
+        Fan,
+        Fans,
+        PowerSensor,
+        PowerSensorKind,
+        PowerSensors,
     );
 
     #[cfg(not(feature = "network"))]
     compile_fail_import!(
         no_network_feature =>
+        Connection,
+        ConnectionProtocol,
+        Connections,
+        ConnectionsFilter,
+        ConnectionsRefreshKind,
+        InterfaceKind,
         IpNetwork,
+        IpScope,
         MacAddr,
         NetworkData,
+        NetworkDelta,
+        NetworkTotalsFilter,
         Networks,
+        NetworksRefreshKind,
+        RouteInfo,
+        TcpState,
+        WifiInfo,
+    );
+
+    #[cfg(not(any(feature = "system", feature = "network")))]
+    compile_fail_import!(
+        no_system_and_network_features =>
+        Pid,
     );
 
     #[cfg(not(feature = "user"))]
@@ -244,8 +378,12 @@ use sysinfo::", stringify!($imports), r";
         no_user_feature =>
         Group,
         Groups,
+        Session,
+        SessionKind,
+        Sessions,
         User,
         Users,
+        UsersRefreshKind,
     );
 }
 
@@ -312,8 +450,8 @@ mod test {
                     ));
                 assert!(s
                     .processes()
-                    .iter()
-                    .filter_map(|(_, p)| p.user_id())
+                    .values()
+                    .filter_map(|p| p.user_id())
                     .any(|uid| users.get_user_by_id(uid).is_some()));
             }
         }
@@ -26,3 +26,60 @@ pub(crate) fn refresh_networks_addresses(interfaces: &mut HashMap<String, Networ
         }
     }
 }
+
+/// Extends a 32-bit cumulative counter into a monotonically increasing 64-bit one.
+///
+/// `previous` is the last value returned by this function for this counter (or the first value
+/// read) and `raw` is the new value read from the system. If `raw` is lower than the lower 32 bits
+/// of `previous`, the counter wrapped around.
+#[cfg(any(target_vendor = "apple", test))]
+pub(crate) fn extend_32_bit_counter(previous: u64, raw: u32) -> u64 {
+    let mut high = previous & !(u32::MAX as u64);
+    if raw < previous as u32 {
+        high = high.wrapping_add(1 << 32);
+    }
+    high | raw as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::extend_32_bit_counter;
+
+    #[test]
+    fn check_extend_32_bit_counter() {
+        let raw_values = [
+            0,
+            1_000,
+            u32::MAX - 10,
+            u32::MAX,
+            5,
+            2_000_000_000,
+            4_000_000_000,
+            3,
+            3,
+        ];
+        let mut total = 0;
+        let mut totals = Vec::new();
+        for raw in raw_values {
+            let new_total = extend_32_bit_counter(total, raw);
+            assert!(new_total >= total);
+            total = new_total;
+            totals.push(total);
+        }
+        let wrap = 1 << 32;
+        assert_eq!(
+            totals,
+            [
+                0,
+                1_000,
+                u32::MAX as u64 - 10,
+                u32::MAX as u64,
+                wrap + 5,
+                wrap + 2_000_000_000,
+                wrap + 4_000_000_000,
+                2 * wrap + 3,
+                2 * wrap + 3,
+            ]
+        );
+    }
+}
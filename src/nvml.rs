@@ -0,0 +1,220 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// NVIDIA GPUs temperature, retrieved from the NVML library when it is installed. It is loaded
+// at runtime so it isn't a requirement to use `sysinfo`.
+
+use std::ffi::{c_char, c_int, c_uint, c_void, CStr};
+use std::sync::OnceLock;
+
+type NvmlDevice = *mut c_void;
+type NvmlReturn = c_int;
+
+const NVML_SUCCESS: NvmlReturn = 0;
+const NVML_TEMPERATURE_GPU: c_int = 0;
+const NVML_TEMPERATURE_THRESHOLD_SHUTDOWN: c_int = 0;
+const NVML_DEVICE_NAME_BUFFER_SIZE: usize = 96;
+
+/// `nvmlPciInfo_t`.
+#[cfg(windows)]
+#[repr(C)]
+struct NvmlPciInfo {
+    _bus_id_legacy: [c_char; 16],
+    _domain: c_uint,
+    bus: c_uint,
+    device: c_uint,
+    _pci_device_id: c_uint,
+    _pci_sub_system_id: c_uint,
+    _bus_id: [c_char; 32],
+}
+
+struct Nvml {
+    device_get_count: unsafe extern "C" fn(*mut c_uint) -> NvmlReturn,
+    device_get_handle_by_index: unsafe extern "C" fn(c_uint, *mut NvmlDevice) -> NvmlReturn,
+    device_get_name: unsafe extern "C" fn(NvmlDevice, *mut c_char, c_uint) -> NvmlReturn,
+    device_get_temperature: unsafe extern "C" fn(NvmlDevice, c_int, *mut c_uint) -> NvmlReturn,
+    device_get_temperature_threshold:
+        unsafe extern "C" fn(NvmlDevice, c_int, *mut c_uint) -> NvmlReturn,
+    /// Not required since it's only used to match the GPUs already known to Windows.
+    #[cfg(windows)]
+    device_get_pci_info: Option<unsafe extern "C" fn(NvmlDevice, *mut NvmlPciInfo) -> NvmlReturn>,
+}
+
+impl Nvml {
+    /// Returns the NVML functions, or `None` if the library isn't available. It is only loaded
+    /// and initialized once, and never unloaded since the devices handles are kept.
+    fn get() -> Option<&'static Self> {
+        static NVML: OnceLock<Option<Nvml>> = OnceLock::new();
+
+        NVML.get_or_init(|| unsafe {
+            let library = open_library()?;
+            let init: unsafe extern "C" fn() -> NvmlReturn = get_symbol(library, b"nvmlInit_v2\0")?;
+            let nvml = Self {
+                device_get_count: get_symbol(library, b"nvmlDeviceGetCount_v2\0")?,
+                device_get_handle_by_index: get_symbol(
+                    library,
+                    b"nvmlDeviceGetHandleByIndex_v2\0",
+                )?,
+                device_get_name: get_symbol(library, b"nvmlDeviceGetName\0")?,
+                device_get_temperature: get_symbol(library, b"nvmlDeviceGetTemperature\0")?,
+                device_get_temperature_threshold: get_symbol(
+                    library,
+                    b"nvmlDeviceGetTemperatureThreshold\0",
+                )?,
+                #[cfg(windows)]
+                device_get_pci_info: get_symbol(library, b"nvmlDeviceGetPciInfo_v3\0"),
+            };
+            let _ret = init();
+            if _ret != NVML_SUCCESS {
+                sysinfo_debug!("nvmlInit_v2 failed: {_ret}");
+                return None;
+            }
+            Some(nvml)
+        })
+        .as_ref()
+    }
+}
+
+/// NVIDIA GPU found through NVML.
+pub(crate) struct Gpu {
+    device: NvmlDevice,
+    index: u32,
+    name: String,
+}
+
+// The NVML functions are thread-safe and the handles stay valid as long as the library is
+// initialized, which is never undone.
+unsafe impl Send for Gpu {}
+unsafe impl Sync for Gpu {}
+
+impl Gpu {
+    /// Index of the GPU, as used by `nvidia-smi`.
+    pub(crate) fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Current temperature of the GPU die, in celsius.
+    pub(crate) fn temperature(&self) -> Option<f32> {
+        let nvml = Nvml::get()?;
+        let mut temperature = 0;
+        unsafe {
+            ((nvml.device_get_temperature)(self.device, NVML_TEMPERATURE_GPU, &mut temperature)
+                == NVML_SUCCESS)
+                .then_some(temperature as f32)
+        }
+    }
+
+    /// PCI bus and device numbers of the GPU.
+    #[cfg(windows)]
+    pub(crate) fn pci_location(&self) -> Option<(u32, u32)> {
+        let device_get_pci_info = Nvml::get()?.device_get_pci_info?;
+        unsafe {
+            let mut info: NvmlPciInfo = std::mem::zeroed();
+            (device_get_pci_info(self.device, &mut info) == NVML_SUCCESS)
+                .then_some((info.bus, info.device))
+        }
+    }
+
+    /// Temperature at which the GPU shuts down, in celsius.
+    pub(crate) fn critical(&self) -> Option<f32> {
+        let nvml = Nvml::get()?;
+        let mut temperature = 0;
+        unsafe {
+            ((nvml.device_get_temperature_threshold)(
+                self.device,
+                NVML_TEMPERATURE_THRESHOLD_SHUTDOWN,
+                &mut temperature,
+            ) == NVML_SUCCESS)
+                .then_some(temperature as f32)
+        }
+    }
+}
+
+/// Returns the NVIDIA GPUs, or nothing if NVML isn't installed.
+pub(crate) fn get_gpus() -> Vec<Gpu> {
+    let Some(nvml) = Nvml::get() else {
+        return Vec::new();
+    };
+    unsafe {
+        let mut count = 0;
+        if (nvml.device_get_count)(&mut count) != NVML_SUCCESS {
+            return Vec::new();
+        }
+        (0..count)
+            .filter_map(|index| {
+                let mut device = std::ptr::null_mut();
+                if (nvml.device_get_handle_by_index)(index, &mut device) != NVML_SUCCESS {
+                    return None;
+                }
+                let mut name = [0 as c_char; NVML_DEVICE_NAME_BUFFER_SIZE];
+                let name = if (nvml.device_get_name)(device, name.as_mut_ptr(), name.len() as _)
+                    == NVML_SUCCESS
+                {
+                    CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned()
+                } else {
+                    String::new()
+                };
+                Some(Gpu {
+                    device,
+                    index,
+                    name,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(unix)]
+type Library = *mut c_void;
+#[cfg(windows)]
+type Library = windows::Win32::Foundation::HMODULE;
+
+#[cfg(unix)]
+unsafe fn open_library() -> Option<Library> {
+    [&b"libnvidia-ml.so.1\0"[..], b"libnvidia-ml.so\0"]
+        .into_iter()
+        .map(|name| libc::dlopen(name.as_ptr() as _, libc::RTLD_NOW | libc::RTLD_LOCAL))
+        .find(|library| !library.is_null())
+}
+
+#[cfg(windows)]
+unsafe fn open_library() -> Option<Library> {
+    use windows::core::w;
+    use windows::Win32::System::LibraryLoader::{
+        LoadLibraryExW, LOAD_LIBRARY_FLAGS, LOAD_LIBRARY_SEARCH_SYSTEM32,
+    };
+
+    // Recent drivers install it in `System32`, older ones in the `NVSMI` folder.
+    LoadLibraryExW(w!("nvml.dll"), None, LOAD_LIBRARY_SEARCH_SYSTEM32)
+        .or_else(|_| {
+            LoadLibraryExW(
+                w!("C:\\Program Files\\NVIDIA Corporation\\NVSMI\\nvml.dll"),
+                None,
+                LOAD_LIBRARY_FLAGS(0),
+            )
+        })
+        .ok()
+}
+
+/// `name` must be NUL-terminated and `F` must be the function pointer type of the symbol.
+unsafe fn get_symbol<F: Copy>(library: Library, name: &[u8]) -> Option<F> {
+    #[cfg(unix)]
+    let symbol = libc::dlsym(library, name.as_ptr() as _);
+    #[cfg(windows)]
+    let symbol = windows::Win32::System::LibraryLoader::GetProcAddress(
+        library,
+        windows::core::PCSTR(name.as_ptr()),
+    )
+    .map_or(std::ptr::null_mut(), |f| f as *mut c_void);
+    if symbol.is_null() {
+        sysinfo_debug!(
+            "NVML symbol not found: {:?}",
+            CStr::from_bytes_with_nul(name)
+        );
+        return None;
+    }
+    Some(std::mem::transmute_copy::<*mut c_void, F>(&symbol))
+}
@@ -1,13 +1,17 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 #[cfg(any(
+    feature = "battery",
     feature = "component",
     feature = "disk",
     feature = "network",
     feature = "system",
     feature = "user"
 ))]
-use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde::ser::SerializeStruct;
+#[cfg(feature = "system")]
+use serde::ser::SerializeStructVariant;
+use serde::{Serialize, Serializer};
 
 #[cfg(feature = "disk")]
 impl Serialize for crate::Disk {
@@ -15,18 +19,19 @@ impl Serialize for crate::Disk {
     where
         S: Serializer,
     {
-        // `7` corresponds to the (maximum) number of fields.
-        let mut state = serializer.serialize_struct("Disk", 7)?;
+        // `9` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Disk", 9)?;
 
         state.serialize_field("DiskKind", &self.kind())?;
-        if let Some(s) = self.name().to_str() {
-            state.serialize_field("name", s)?;
-        }
+        // The name is always serialized so the fields are the same on all the disks.
+        state.serialize_field("name", &self.name().to_string_lossy())?;
         state.serialize_field("file_system", &self.file_system())?;
         state.serialize_field("mount_point", &self.mount_point())?;
         state.serialize_field("total_space", &self.total_space())?;
         state.serialize_field("available_space", &self.available_space())?;
         state.serialize_field("is_removable", &self.is_removable())?;
+        state.serialize_field("is_read_only", &self.is_read_only())?;
+        state.serialize_field("usage", &self.usage())?;
 
         state.end()
     }
@@ -62,7 +67,16 @@ impl Serialize for crate::DiskKind {
     }
 }
 
-#[cfg(feature = "system")]
+impl Serialize for crate::ByteSize {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct("ByteSize", &self.as_u64())
+    }
+}
+
+#[cfg(any(feature = "system", feature = "network"))]
 impl Serialize for crate::Pid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -78,13 +92,14 @@ impl Serialize for crate::Process {
     where
         S: Serializer,
     {
-        // `19` corresponds to the (maximum) number of fields.
-        let mut state = serializer.serialize_struct("Process", 19)?;
+        // `28` corresponds to the (maximum) number of fields.
+        let mut state = serializer.serialize_struct("Process", 28)?;
 
         state.serialize_field("name", &self.name())?;
         state.serialize_field("cmd", &self.cmd())?;
         state.serialize_field("exe", &self.exe())?;
         state.serialize_field("pid", &self.pid().as_u32())?;
+        state.serialize_field("ns_pid", &self.ns_pid())?;
         state.serialize_field("environ", &self.environ())?;
         state.serialize_field("cwd", &self.cwd())?;
         state.serialize_field("root", &self.root())?;
@@ -96,10 +111,18 @@ impl Serialize for crate::Process {
         state.serialize_field("run_time", &self.run_time())?;
         state.serialize_field("cpu_usage", &self.cpu_usage())?;
         state.serialize_field("accumulated_cpu_time", &self.accumulated_cpu_time())?;
+        state.serialize_field("user_cpu_time", &self.user_cpu_time())?;
+        state.serialize_field("system_cpu_time", &self.system_cpu_time())?;
+        state.serialize_field("thread_count", &self.thread_count())?;
         state.serialize_field("disk_usage", &self.disk_usage())?;
+        state.serialize_field("fd_usage", &self.fd_usage())?;
+        state.serialize_field("namespaces", &self.namespaces())?;
         state.serialize_field("user_id", &self.user_id())?;
+        state.serialize_field("effective_user_id", &self.effective_user_id())?;
         state.serialize_field("group_id", &self.group_id())?;
+        state.serialize_field("effective_group_id", &self.effective_group_id())?;
         state.serialize_field("session_id", &self.session_id())?;
+        state.serialize_field("thread_kind", &self.thread_kind())?;
 
         state.end()
     }
@@ -236,6 +259,349 @@ impl Serialize for crate::Signal {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessChanges {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessChanges", 3)?;
+
+        state.serialize_field("added", &self.added())?;
+        state.serialize_field("removed", &self.removed())?;
+        state.serialize_field("changed", &self.changed())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::HardwareInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("HardwareInfo", 6)?;
+
+        state.serialize_field("vendor", &self.vendor)?;
+        state.serialize_field("product_name", &self.product_name)?;
+        state.serialize_field("board_name", &self.board_name)?;
+        state.serialize_field("bios_version", &self.bios_version)?;
+        state.serialize_field("bios_date", &self.bios_date)?;
+        state.serialize_field("chassis_type", &self.chassis_type)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Virtualization {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::BareMetal => serializer.serialize_unit_variant("Virtualization", 0, "BareMetal"),
+            Self::VirtualMachine(ref hypervisor) => serializer.serialize_newtype_variant(
+                "Virtualization",
+                1,
+                "VirtualMachine",
+                hypervisor,
+            ),
+            Self::Container { ref kind, ref host } => {
+                // `2` corresponds to the number of fields.
+                let mut state =
+                    serializer.serialize_struct_variant("Virtualization", 2, "Container", 2)?;
+
+                state.serialize_field("kind", kind)?;
+                state.serialize_field("host", host)?;
+
+                state.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Hypervisor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::KvmQemu => (0, "KvmQemu", None),
+            Self::VMware => (1, "VMware", None),
+            Self::HyperV => (2, "HyperV", None),
+            Self::VirtualBox => (3, "VirtualBox", None),
+            Self::Xen => (4, "Xen", None),
+            Self::Parallels => (5, "Parallels", None),
+            Self::Wsl => (6, "Wsl", None),
+            Self::Other(ref s) => (7, "Other", Some(s)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("Hypervisor", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("Hypervisor", index, variant)
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ContainerKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::Docker => (0, "Docker", None),
+            Self::Podman => (1, "Podman", None),
+            Self::Lxc => (2, "Lxc", None),
+            Self::Other(ref s) => (3, "Other", Some(s)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("ContainerKind", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("ContainerKind", index, variant)
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::CpuArchDetail {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("CpuArchDetail", 3)?;
+
+        state.serialize_field("arch", &self.arch)?;
+        state.serialize_field("bits", &self.bits)?;
+        state.serialize_field("endianness", &self.endianness)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Arch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Self::X86(ref level) => serializer.serialize_newtype_variant("Arch", 0, "X86", level),
+            Self::Arm(ref version) => {
+                serializer.serialize_newtype_variant("Arch", 1, "Arm", version)
+            }
+            Self::RiscV => serializer.serialize_unit_variant("Arch", 2, "RiscV"),
+            Self::PowerPc => serializer.serialize_unit_variant("Arch", 3, "PowerPc"),
+            Self::Mips => serializer.serialize_unit_variant("Arch", 4, "Mips"),
+            Self::LoongArch => serializer.serialize_unit_variant("Arch", 5, "LoongArch"),
+            Self::S390x => serializer.serialize_unit_variant("Arch", 6, "S390x"),
+            Self::Other(ref name) => serializer.serialize_newtype_variant("Arch", 7, "Other", name),
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::X86Level {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::V1 => (0, "V1"),
+            Self::V2 => (1, "V2"),
+            Self::V3 => (2, "V3"),
+            Self::V4 => (3, "V4"),
+        };
+        serializer.serialize_unit_variant("X86Level", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ArmVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::V5 => (0, "V5"),
+            Self::V6 => (1, "V6"),
+            Self::V7 => (2, "V7"),
+            Self::V8 => (3, "V8"),
+            Self::V9 => (4, "V9"),
+        };
+        serializer.serialize_unit_variant("ArmVersion", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Endianness {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Little => (0, "Little"),
+            Self::Big => (1, "Big"),
+        };
+        serializer.serialize_unit_variant("Endianness", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Pressure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Pressure", 3)?;
+
+        state.serialize_field("level", &self.level)?;
+        state.serialize_field("some", &self.some)?;
+        state.serialize_field("full", &self.full)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::PressureStall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PressureStall", 4)?;
+
+        state.serialize_field("avg10", &self.avg10)?;
+        state.serialize_field("avg60", &self.avg60)?;
+        state.serialize_field("avg300", &self.avg300)?;
+        state.serialize_field("total", &self.total)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::PressureLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Normal => (0, "Normal"),
+            Self::Warning => (1, "Warning"),
+            Self::Critical => (2, "Critical"),
+        };
+
+        serializer.serialize_unit_variant("PressureLevel", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::DomainKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Domain => (0, "Domain"),
+            Self::Workgroup => (1, "Workgroup"),
+        };
+
+        serializer.serialize_unit_variant("DomainKind", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::TpmVersion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::V1 => (0, "V1"),
+            Self::V2 => (1, "V2"),
+        };
+
+        serializer.serialize_unit_variant("TpmVersion", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::IoPriority {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `2` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("IoPriority", 2)?;
+
+        state.serialize_field("class", &self.class)?;
+        state.serialize_field("level", &self.level)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::IoPriorityClass {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::None => (0, "None"),
+            Self::Realtime => (1, "Realtime"),
+            Self::BestEffort => (2, "BestEffort"),
+            Self::Idle => (3, "Idle"),
+        };
+
+        serializer.serialize_unit_variant("IoPriorityClass", index, variant)
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("ProcessEvent", 4)?;
+
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("pid", &self.pid())?;
+        state.serialize_field("parent_pid", &self.parent_pid())?;
+        state.serialize_field("name", &self.name())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::ProcessEventKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Started => (0, "Started"),
+            Self::Stopped => (1, "Stopped"),
+        };
+
+        serializer.serialize_unit_variant("ProcessEventKind", index, variant)
+    }
+}
+
 #[cfg(feature = "system")]
 impl Serialize for crate::LoadAvg {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -282,7 +648,7 @@ impl Serialize for crate::ProcessStatus {
     }
 }
 
-#[cfg(feature = "system")]
+#[cfg(any(feature = "disk", feature = "system"))]
 impl Serialize for crate::DiskUsage {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -300,6 +666,45 @@ impl Serialize for crate::DiskUsage {
     }
 }
 
+#[cfg(feature = "system")]
+impl Serialize for crate::FdUsage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("FdUsage", 4)?;
+
+        state.serialize_field("open", &self.open)?;
+        state.serialize_field("soft_limit", &self.soft_limit)?;
+        state.serialize_field("hard_limit", &self.hard_limit)?;
+        state.serialize_field("peak", &self.peak)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "system")]
+impl Serialize for crate::Namespaces {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Namespaces", 7)?;
+
+        state.serialize_field("pid", &self.pid)?;
+        state.serialize_field("net", &self.net)?;
+        state.serialize_field("mnt", &self.mnt)?;
+        state.serialize_field("uts", &self.uts)?;
+        state.serialize_field("ipc", &self.ipc)?;
+        state.serialize_field("user", &self.user)?;
+        state.serialize_field("cgroup", &self.cgroup)?;
+
+        state.end()
+    }
+}
+
 #[cfg(feature = "component")]
 impl Serialize for crate::Components {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -316,18 +721,145 @@ impl Serialize for crate::Component {
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Component", 4)?;
+        // `10` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Component", 10)?;
 
         state.serialize_field("temperature", &self.temperature())?;
         state.serialize_field("max", &self.max())?;
+        state.serialize_field("observed_max", &self.observed_max())?;
+        state.serialize_field("min", &self.min())?;
         state.serialize_field("critical", &self.critical())?;
+        state.serialize_field("critical_hysteresis", &self.critical_hysteresis())?;
+        state.serialize_field("alarm", &self.alarm())?;
+        state.serialize_field("label", &self.label())?;
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("device_name", &self.device_name())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::Fans {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::Fan {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Fan", 4)?;
+
+        state.serialize_field("rpm", &self.rpm())?;
+        state.serialize_field("min_rpm", &self.min_rpm())?;
+        state.serialize_field("max_rpm", &self.max_rpm())?;
         state.serialize_field("label", &self.label())?;
 
         state.end()
     }
 }
 
+#[cfg(feature = "component")]
+impl Serialize for crate::PowerSensors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::PowerSensor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `4` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("PowerSensor", 4)?;
+
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("value", &self.value())?;
+        state.serialize_field("unit", &self.unit())?;
+        state.serialize_field("label", &self.label())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "component")]
+impl Serialize for crate::PowerSensorKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Voltage => (0, "Voltage"),
+            Self::Current => (1, "Current"),
+            Self::Power => (2, "Power"),
+        };
+
+        serializer.serialize_unit_variant("PowerSensorKind", index, variant)
+    }
+}
+
+#[cfg(feature = "battery")]
+impl Serialize for crate::Batteries {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "battery")]
+impl Serialize for crate::Battery {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Battery", 7)?;
+
+        state.serialize_field("name", &self.name())?;
+        state.serialize_field("charge_percent", &self.charge_percent())?;
+        state.serialize_field("state", &self.state())?;
+        state.serialize_field("cycle_count", &self.cycle_count())?;
+        state.serialize_field("design_capacity", &self.design_capacity())?;
+        state.serialize_field("full_charge_capacity", &self.full_charge_capacity())?;
+        state.serialize_field("power_watts", &self.power_watts())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "battery")]
+impl Serialize for crate::BatteryState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Charging => (0, "Charging"),
+            Self::Discharging => (1, "Discharging"),
+            Self::Full => (2, "Full"),
+            Self::NotCharging => (3, "NotCharging"),
+            Self::Unknown => (4, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("BatteryState", index, variant)
+    }
+}
+
 #[cfg(feature = "network")]
 impl Serialize for crate::Networks {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -344,8 +876,8 @@ impl Serialize for crate::NetworkData {
     where
         S: Serializer,
     {
-        // `14` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("NetworkData", 14)?;
+        // `39` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("NetworkData", 39)?;
 
         state.serialize_field("received", &self.received())?;
         state.serialize_field("total_received", &self.total_received())?;
@@ -365,14 +897,205 @@ impl Serialize for crate::NetworkData {
             "total_errors_on_transmitted",
             &self.total_errors_on_transmitted(),
         )?;
+        state.serialize_field("dropped_packets_received", &self.dropped_packets_received())?;
+        state.serialize_field(
+            "total_dropped_packets_received",
+            &self.total_dropped_packets_received(),
+        )?;
+        state.serialize_field(
+            "dropped_packets_transmitted",
+            &self.dropped_packets_transmitted(),
+        )?;
+        state.serialize_field(
+            "total_dropped_packets_transmitted",
+            &self.total_dropped_packets_transmitted(),
+        )?;
+        state.serialize_field("collisions", &self.collisions())?;
+        state.serialize_field("total_collisions", &self.total_collisions())?;
+        state.serialize_field("multicast_received", &self.multicast_received())?;
+        state.serialize_field("total_multicast_received", &self.total_multicast_received())?;
+        state.serialize_field("received_bytes_per_sec", &self.received_bytes_per_sec())?;
+        state.serialize_field(
+            "transmitted_bytes_per_sec",
+            &self.transmitted_bytes_per_sec(),
+        )?;
+        state.serialize_field("packets_received_per_sec", &self.packets_received_per_sec())?;
+        state.serialize_field(
+            "packets_transmitted_per_sec",
+            &self.packets_transmitted_per_sec(),
+        )?;
         state.serialize_field("mac_address", &self.mac_address())?;
         state.serialize_field("ip_networks", &self.ip_networks())?;
         state.serialize_field("mtu", &self.mtu())?;
+        state.serialize_field("is_loopback", &self.is_loopback())?;
+        state.serialize_field("is_up", &self.is_up())?;
+        state.serialize_field("is_point_to_point", &self.is_point_to_point())?;
+        state.serialize_field("supports_multicast", &self.supports_multicast())?;
+        state.serialize_field("supports_broadcast", &self.supports_broadcast())?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("wifi_info", &self.wifi_info())?;
+        state.serialize_field("index", &self.index())?;
+        state.serialize_field("friendly_name", &self.friendly_name())?;
+        state.serialize_field("master", &self.master())?;
+        state.serialize_field("lower_device", &self.lower_device())?;
+        state.serialize_field("vlan_id", &self.vlan_id())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::RouteInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("RouteInfo", 3)?;
+
+        state.serialize_field("gateway", &self.gateway)?;
+        state.serialize_field("interface", &self.interface)?;
+        state.serialize_field("metric", &self.metric)?;
 
         state.end()
     }
 }
 
+#[cfg(feature = "network")]
+impl Serialize for crate::Connections {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::Connection {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `6` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Connection", 6)?;
+
+        state.serialize_field("protocol", &self.protocol())?;
+        state.serialize_field("local_address", &self.local_address())?;
+        state.serialize_field("remote_address", &self.remote_address())?;
+        state.serialize_field("state", &self.state())?;
+        state.serialize_field("pid", &self.pid())?;
+        state.serialize_field("inode", &self.inode())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::ConnectionProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Tcp => (0, "Tcp"),
+            Self::Udp => (1, "Udp"),
+        };
+
+        serializer.serialize_unit_variant("ConnectionProtocol", index, variant)
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::TcpState {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::Established => (0, "Established", None),
+            Self::SynSent => (1, "SynSent", None),
+            Self::SynReceived => (2, "SynReceived", None),
+            Self::FinWait1 => (3, "FinWait1", None),
+            Self::FinWait2 => (4, "FinWait2", None),
+            Self::TimeWait => (5, "TimeWait", None),
+            Self::Closed => (6, "Closed", None),
+            Self::CloseWait => (7, "CloseWait", None),
+            Self::LastAck => (8, "LastAck", None),
+            Self::Listen => (9, "Listen", None),
+            Self::Closing => (10, "Closing", None),
+            Self::Unknown(n) => (11, "Unknown", Some(n)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("TcpState", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("TcpState", index, variant)
+        }
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::WifiInfo {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("WifiInfo", 5)?;
+
+        state.serialize_field("ssid", &self.ssid)?;
+        state.serialize_field("bssid", &self.bssid)?;
+        state.serialize_field("signal", &self.signal)?;
+        state.serialize_field("frequency", &self.frequency)?;
+        state.serialize_field("channel", &self.channel)?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::InterfaceKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant, maybe_value) = match *self {
+            Self::Ethernet => (0, "Ethernet", None),
+            Self::Wifi => (1, "Wifi", None),
+            Self::Cellular => (2, "Cellular", None),
+            Self::Loopback => (3, "Loopback", None),
+            Self::Bridge => (4, "Bridge", None),
+            Self::Tunnel => (5, "Tunnel", None),
+            Self::Virtual => (6, "Virtual", None),
+            Self::Other(ref s) => (7, "Other", Some(s)),
+        };
+
+        if let Some(ref value) = maybe_value {
+            serializer.serialize_newtype_variant("InterfaceKind", index, variant, value)
+        } else {
+            serializer.serialize_unit_variant("InterfaceKind", index, variant)
+        }
+    }
+}
+
+#[cfg(feature = "network")]
+impl Serialize for crate::IpScope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Global => (0, "Global"),
+            Self::SiteLocal => (1, "SiteLocal"),
+            Self::LinkLocal => (2, "LinkLocal"),
+            Self::Loopback => (3, "Loopback"),
+        };
+
+        serializer.serialize_unit_variant("IpScope", index, variant)
+    }
+}
+
 #[cfg(feature = "network")]
 impl Serialize for crate::MacAddr {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -389,10 +1112,14 @@ impl Serialize for crate::IpNetwork {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("IpNetwork", 2)?;
+        // `5` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("IpNetwork", 5)?;
 
         state.serialize_field("addr", &self.addr)?;
         state.serialize_field("prefix", &self.prefix)?;
+        state.serialize_field("is_temporary", &self.is_temporary)?;
+        state.serialize_field("is_deprecated", &self.is_deprecated)?;
+        state.serialize_field("scope", &self.scope())?;
 
         state.end()
     }
@@ -414,34 +1141,107 @@ impl Serialize for crate::User {
     where
         S: Serializer,
     {
-        // `4` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("User", 4)?;
+        // `12` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("User", 12)?;
 
         state.serialize_field("id", &self.id())?;
         state.serialize_field("group_id", &self.group_id())?;
         state.serialize_field("name", &self.name())?;
+        state.serialize_field("full_name", &self.full_name())?;
+        state.serialize_field("is_disabled", &self.is_disabled())?;
+        state.serialize_field("is_locked_out", &self.is_locked_out())?;
+        state.serialize_field("password_expired", &self.password_expired())?;
         state.serialize_field("groups", &self.groups())?;
+        state.serialize_field("home_directory", &self.home_directory())?;
+        state.serialize_field("shell", &self.shell())?;
+        // Only Windows has domains, but the field is always there to keep the same format on
+        // all platforms.
+        #[cfg(windows)]
+        state.serialize_field("domain", &self.domain())?;
+        #[cfg(not(windows))]
+        state.serialize_field("domain", &None::<&str>)?;
+        state.serialize_field("last_login", &self.last_login())?;
 
         state.end()
     }
 }
 
+#[cfg(feature = "user")]
+impl Serialize for crate::Groups {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
 #[cfg(feature = "user")]
 impl Serialize for crate::Group {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        // `2` corresponds to the number of fields.
-        let mut state = serializer.serialize_struct("Group", 2)?;
+        // `3` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Group", 3)?;
 
         state.serialize_field("id", &self.id())?;
         state.serialize_field("name", &self.name())?;
+        state.serialize_field("members", &self.members())?;
+
+        state.end()
+    }
+}
+
+#[cfg(feature = "user")]
+impl Serialize for crate::Sessions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "user")]
+impl Serialize for crate::Session {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // `7` corresponds to the number of fields.
+        let mut state = serializer.serialize_struct("Session", 7)?;
+
+        state.serialize_field("user_name", &self.user_name())?;
+        state.serialize_field("user_id", &self.user_id())?;
+        state.serialize_field("session_id", &self.session_id())?;
+        state.serialize_field("login_time", &self.login_time())?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("terminal", &self.terminal())?;
+        state.serialize_field("remote_host", &self.remote_host())?;
 
         state.end()
     }
 }
 
+#[cfg(feature = "user")]
+impl Serialize for crate::SessionKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (index, variant) = match *self {
+            Self::Local => (0, "Local"),
+            Self::Remote => (1, "Remote"),
+            Self::Console => (2, "Console"),
+            Self::Service => (3, "Service"),
+            Self::Unknown => (4, "Unknown"),
+        };
+
+        serializer.serialize_unit_variant("SessionKind", index, variant)
+    }
+}
+
 #[cfg(any(feature = "user", feature = "system"))]
 impl Serialize for crate::Gid {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
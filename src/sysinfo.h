@@ -11,6 +11,7 @@ typedef const void* CProcess;
 typedef const char* RString;
 typedef void* CNetworks;
 typedef void* CDisks;
+typedef const void* CDisk;
 
 #ifdef WIN32
 typedef size_t PID;
@@ -18,6 +19,29 @@ typedef size_t PID;
 typedef pid_t PID;
 #endif
 
+// Values returned by `sysinfo_process_status`.
+enum {
+    SYSINFO_PROCESS_STATUS_IDLE = 0,
+    SYSINFO_PROCESS_STATUS_RUN = 1,
+    SYSINFO_PROCESS_STATUS_SLEEP = 2,
+    SYSINFO_PROCESS_STATUS_STOP = 3,
+    SYSINFO_PROCESS_STATUS_ZOMBIE = 4,
+    SYSINFO_PROCESS_STATUS_TRACING = 5,
+    SYSINFO_PROCESS_STATUS_DEAD = 6,
+    SYSINFO_PROCESS_STATUS_WAKEKILL = 7,
+    SYSINFO_PROCESS_STATUS_WAKING = 8,
+    SYSINFO_PROCESS_STATUS_PARKED = 9,
+    SYSINFO_PROCESS_STATUS_LOCK_BLOCKED = 10,
+    SYSINFO_PROCESS_STATUS_UNINTERRUPTIBLE_DISK_SLEEP = 11,
+    SYSINFO_PROCESS_STATUS_UNKNOWN = 12,
+};
+
+// Values returned by `sysinfo_disk_kind`.
+enum {
+    SYSINFO_DISK_KIND_HDD = 0,
+    SYSINFO_DISK_KIND_SSD = 1,
+    SYSINFO_DISK_KIND_UNKNOWN = 2,
+};
 
 CSystem     sysinfo_init(void);
 void        sysinfo_destroy(CSystem system);
@@ -33,6 +57,18 @@ void        sysinfo_refresh_process(CSystem system, PID pid);
 CDisks      sysinfo_disks_init(void);
 void        sysinfo_disks_destroy(CDisks disks);
 void        sysinfo_disks_refresh(CDisks disks);
+size_t      sysinfo_disks(CDisks disks, bool (*fn_pointer)(CDisk, void*), void *data);
+
+// The `size_t (*)(..., char *buffer, size_t buffer_len)` functions write the string into `buffer`
+// without adding a nul byte: as is on Unix, and as UTF-8 (the invalid sequences being replaced
+// with U+FFFD) on the other platforms. They return the length of the whole string, which is
+// bigger than `buffer_len` if it was truncated.
+size_t      sysinfo_disk_name(CDisk disk, char *buffer, size_t buffer_len);
+size_t      sysinfo_disk_mount_point(CDisk disk, char *buffer, size_t buffer_len);
+size_t      sysinfo_disk_file_system(CDisk disk, char *buffer, size_t buffer_len);
+uint64_t    sysinfo_disk_total_space(CDisk disk);
+uint64_t    sysinfo_disk_available_space(CDisk disk);
+int         sysinfo_disk_kind(CDisk disk);
 
 size_t      sysinfo_total_memory(CSystem system);
 size_t      sysinfo_free_memory(CSystem system);
@@ -42,6 +78,7 @@ size_t      sysinfo_free_swap(CSystem system);
 size_t      sysinfo_used_swap(CSystem system);
 
 void        sysinfo_cpus_usage(CSystem system, unsigned int *length, float **cpus);
+void        sysinfo_cpus_usage_free(float *cpus);
 
 size_t      sysinfo_processes(CSystem system, bool (*fn_pointer)(PID, CProcess, void*),
                               void *data);
@@ -50,6 +87,11 @@ size_t      sysinfo_process_tasks(CProcess process, bool (*fn_pointer)(PID, void
 CProcess    sysinfo_process_by_pid(CSystem system, PID pid);
 PID         sysinfo_process_pid(CProcess process);
 PID         sysinfo_process_parent_pid(CProcess process);
+size_t      sysinfo_process_name(CProcess process, char *buffer, size_t buffer_len);
+// Each argument is followed by a nul byte.
+size_t      sysinfo_process_cmd(CProcess process, char *buffer, size_t buffer_len);
+size_t      sysinfo_process_exe(CProcess process, char *buffer, size_t buffer_len);
+int         sysinfo_process_status(CProcess process);
 float       sysinfo_process_cpu_usage(CProcess process);
 size_t      sysinfo_process_memory(CProcess process);
 size_t      sysinfo_process_virtual_memory(CProcess process);
@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Component;
+use crate::{Component, Fan, PowerSensor, PowerSensorKind};
 
 pub(crate) struct ComponentInner {
     pub(crate) updated: bool,
@@ -15,10 +15,30 @@ impl ComponentInner {
         None
     }
 
+    pub(crate) fn min(&self) -> Option<f32> {
+        None
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         None
     }
 
+    pub(crate) fn critical_hysteresis(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn alarm(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn device_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn id(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         ""
     }
@@ -56,4 +76,114 @@ impl ComponentsInner {
     pub(crate) fn refresh(&mut self) {
         // Doesn't do anything.
     }
+
+    pub(crate) fn refresh_filtered(&mut self, _filter: &mut dyn FnMut(&str, &str) -> bool) {
+        // Doesn't do anything.
+    }
+}
+
+pub(crate) struct FanInner {
+    pub(crate) updated: bool,
+}
+
+impl FanInner {
+    pub(crate) fn rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn min_rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn max_rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct FansInner {
+    pub(crate) fans: Vec<Fan>,
+}
+
+impl FansInner {
+    pub(crate) fn new() -> Self {
+        Self { fans: Vec::new() }
+    }
+
+    pub(crate) fn from_vec(fans: Vec<Fan>) -> Self {
+        Self { fans }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Fan> {
+        self.fans
+    }
+
+    pub(crate) fn list(&self) -> &[Fan] {
+        &self.fans
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Fan] {
+        &mut self.fans
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // Doesn't do anything.
+    }
+}
+
+pub(crate) struct PowerSensorInner {
+    pub(crate) updated: bool,
+}
+
+impl PowerSensorInner {
+    pub(crate) fn kind(&self) -> PowerSensorKind {
+        PowerSensorKind::Power
+    }
+
+    pub(crate) fn value(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct PowerSensorsInner {
+    pub(crate) sensors: Vec<PowerSensor>,
+}
+
+impl PowerSensorsInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            sensors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(sensors: Vec<PowerSensor>) -> Self {
+        Self { sensors }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<PowerSensor> {
+        self.sensors
+    }
+
+    pub(crate) fn list(&self) -> &[PowerSensor] {
+        &self.sensors
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [PowerSensor] {
+        &mut self.sensors
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // Doesn't do anything.
+    }
 }
@@ -1,5 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+#[cfg(feature = "battery")]
+pub mod battery;
 #[cfg(feature = "component")]
 pub mod component;
 #[cfg(feature = "system")]
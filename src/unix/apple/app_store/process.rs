@@ -3,8 +3,12 @@
 use std::ffi::{OsStr, OsString};
 use std::path::Path;
 use std::process::ExitStatus;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, FdUsage, Gid, IoPriority, IoPriorityError, Namespaces, Pid, ProcessStatus, Signal,
+    Uid,
+};
 
 pub(crate) struct ProcessInner;
 
@@ -61,6 +65,10 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn start_time_precise(&self) -> SystemTime {
+        UNIX_EPOCH
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         0
     }
@@ -73,10 +81,26 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn user_cpu_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    pub(crate) fn system_cpu_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        None
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
 
+    pub(crate) fn field_status(&self, _field: crate::ProcessField) -> crate::FieldStatus {
+        crate::FieldStatus::Unsupported
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         None
     }
@@ -93,10 +117,30 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn umask(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn ns_pid(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn io_priority(&self) -> Option<IoPriority> {
+        None
+    }
+
+    pub(crate) fn set_io_priority(&self, _priority: IoPriority) -> Result<(), IoPriorityError> {
+        Err(IoPriorityError::Unsupported)
+    }
+
     pub(crate) fn wait(&self) -> Option<ExitStatus> {
         None
     }
 
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        None
+    }
+
     pub(crate) fn session_id(&self) -> Option<Pid> {
         None
     }
@@ -110,4 +154,12 @@ impl ProcessInner {
     pub(crate) fn exists(&self) -> bool {
         false
     }
+
+    pub(crate) fn fd_usage(&self) -> Option<FdUsage> {
+        None
+    }
+
+    pub(crate) fn namespaces(&self) -> Option<Namespaces> {
+        None
+    }
 }
@@ -0,0 +1,292 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Connection, ConnectionsFilter};
+
+#[cfg(target_os = "macos")]
+pub(crate) fn get_connections(
+    connections: &mut Vec<Connection>,
+    filter: ConnectionsFilter<'_>,
+    with_pid: bool,
+) {
+    connections.clear();
+
+    let pids = match filter {
+        ConnectionsFilter::Processes(pids) => pids.to_vec(),
+        _ => macos::get_all_pids(),
+    };
+    for pid in pids {
+        unsafe {
+            macos::get_process_connections(connections, pid, with_pid);
+        }
+    }
+    // Sockets can be shared between processes (after a `fork` for example), so we only keep the
+    // first occurrence.
+    let mut seen = std::collections::HashSet::new();
+    connections.retain(|connection| {
+        seen.insert((
+            connection.protocol,
+            connection.local_address,
+            connection.remote_address,
+        ))
+    });
+}
+
+// FIXME: iOS doesn't allow to retrieve information about other processes.
+#[cfg(not(target_os = "macos"))]
+pub(crate) fn get_connections(
+    connections: &mut Vec<Connection>,
+    _filter: ConnectionsFilter<'_>,
+    _with_pid: bool,
+) {
+    connections.clear();
+}
+
+#[cfg(target_os = "macos")]
+mod macos {
+    use crate::{Connection, ConnectionProtocol, Pid, TcpState};
+
+    use libc::{c_int, c_void};
+    use std::mem;
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+    // The following types and constants come from `sys/proc_info.h` and aren't provided by `libc`.
+    const PROC_PIDFDSOCKETINFO: c_int = 3;
+    const SOCKINFO_IN: c_int = 1;
+    const SOCKINFO_TCP: c_int = 2;
+    const INI_IPV4: u8 = 0x1;
+    const INI_IPV6: u8 = 0x2;
+
+    #[repr(C)]
+    struct proc_fileinfo {
+        fi_openflags: u32,
+        fi_status: u32,
+        fi_offset: libc::off_t,
+        fi_type: i32,
+        fi_guardflags: u32,
+    }
+
+    #[repr(C)]
+    struct vinfo_stat {
+        vst_dev: u32,
+        vst_mode: u16,
+        vst_nlink: u16,
+        vst_ino: u64,
+        vst_uid: libc::uid_t,
+        vst_gid: libc::gid_t,
+        vst_times: [i64; 8],
+        vst_size: libc::off_t,
+        vst_blocks: i64,
+        vst_blksize: i32,
+        vst_flags: u32,
+        vst_gen: u32,
+        vst_rdev: u32,
+        vst_qspare: [i64; 2],
+    }
+
+    #[repr(C)]
+    struct sockbuf_info {
+        sbi_cc: u32,
+        sbi_hiwat: u32,
+        sbi_mbcnt: u32,
+        sbi_mbmax: u32,
+        sbi_lowat: u32,
+        sbi_flags: i16,
+        sbi_timeo: i16,
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct in_sockinfo {
+        insi_fport: c_int,
+        insi_lport: c_int,
+        insi_gencnt: u64,
+        insi_flags: u32,
+        insi_flow: u32,
+        insi_vflag: u8,
+        insi_ip_ttl: u8,
+        rfu_1: u32,
+        // For IPv4 addresses, only the last 4 bytes are used.
+        insi_faddr: [u8; 16],
+        insi_laddr: [u8; 16],
+        insi_v4: u8,
+        insi_v6: [u32; 3],
+    }
+
+    #[repr(C)]
+    #[derive(Clone, Copy)]
+    struct tcp_sockinfo {
+        tcpsi_ini: in_sockinfo,
+        tcpsi_state: c_int,
+        tcpsi_timer: [c_int; 4],
+        tcpsi_mss: c_int,
+        tcpsi_flags: u32,
+        rfu_1: u32,
+        tcpsi_tp: u64,
+    }
+
+    #[repr(C)]
+    union soi_proto {
+        pri_in: in_sockinfo,
+        pri_tcp: tcp_sockinfo,
+        // The biggest variant is `un_sockinfo`.
+        _pad: [u64; 66],
+    }
+
+    #[repr(C)]
+    struct socket_info {
+        soi_stat: vinfo_stat,
+        soi_so: u64,
+        soi_pcb: u64,
+        soi_type: c_int,
+        soi_protocol: c_int,
+        soi_family: c_int,
+        soi_options: i16,
+        soi_linger: i16,
+        soi_state: i16,
+        soi_qlen: i16,
+        soi_incqlen: i16,
+        soi_qlimit: i16,
+        soi_timeo: i16,
+        soi_error: u16,
+        soi_oobmark: u32,
+        soi_rcv: sockbuf_info,
+        soi_snd: sockbuf_info,
+        soi_kind: c_int,
+        rfu_1: u32,
+        soi_proto: soi_proto,
+    }
+
+    #[repr(C)]
+    struct socket_fdinfo {
+        pfi: proc_fileinfo,
+        psi: socket_info,
+    }
+
+    fn tcp_state(state: c_int) -> TcpState {
+        // Values from `TSI_S_*` in `sys/proc_info.h`.
+        match state {
+            0 => TcpState::Closed,
+            1 => TcpState::Listen,
+            2 => TcpState::SynSent,
+            3 => TcpState::SynReceived,
+            4 => TcpState::Established,
+            5 => TcpState::CloseWait,
+            6 => TcpState::FinWait1,
+            7 => TcpState::Closing,
+            8 => TcpState::LastAck,
+            9 => TcpState::FinWait2,
+            10 => TcpState::TimeWait,
+            state => TcpState::Unknown(state as _),
+        }
+    }
+
+    fn ip_address(info: &in_sockinfo, addr: &[u8; 16]) -> Option<IpAddr> {
+        if info.insi_vflag & INI_IPV4 != 0 {
+            Some(IpAddr::V4(Ipv4Addr::new(
+                addr[12], addr[13], addr[14], addr[15],
+            )))
+        } else if info.insi_vflag & INI_IPV6 != 0 {
+            let mut addr = *addr;
+            // The kernel embeds the scope ID into link-local addresses.
+            if addr[0] == 0xfe && addr[1] & 0xc0 == 0x80 {
+                addr[2] = 0;
+                addr[3] = 0;
+            }
+            // Dual-stack sockets use IPv4-mapped addresses for their IPv4 peers.
+            Some(IpAddr::V6(Ipv6Addr::from(addr)).to_canonical())
+        } else {
+            None
+        }
+    }
+
+    pub(super) fn get_all_pids() -> Vec<Pid> {
+        unsafe {
+            let count = libc::proc_listallpids(std::ptr::null_mut(), 0);
+            if count < 1 {
+                return Vec::new();
+            }
+            // Some room in case new processes were created in the meantime.
+            let mut pids: Vec<libc::pid_t> = vec![0; count as usize + 16];
+            let count = libc::proc_listallpids(
+                pids.as_mut_ptr() as *mut c_void,
+                (pids.len() * mem::size_of::<libc::pid_t>()) as _,
+            );
+            if count < 1 {
+                return Vec::new();
+            }
+            pids.truncate(count as usize);
+            pids.into_iter().map(Pid).collect()
+        }
+    }
+
+    pub(super) unsafe fn get_process_connections(
+        connections: &mut Vec<Connection>,
+        pid: Pid,
+        with_pid: bool,
+    ) {
+        let size = libc::proc_pidinfo(pid.0, libc::PROC_PIDLISTFDS, 0, std::ptr::null_mut(), 0);
+        if size <= 0 {
+            return;
+        }
+        let mut fds: Vec<libc::proc_fdinfo> =
+            Vec::with_capacity(size as usize / mem::size_of::<libc::proc_fdinfo>());
+        let size = libc::proc_pidinfo(
+            pid.0,
+            libc::PROC_PIDLISTFDS,
+            0,
+            fds.as_mut_ptr() as *mut c_void,
+            (fds.capacity() * mem::size_of::<libc::proc_fdinfo>()) as _,
+        );
+        if size <= 0 {
+            return;
+        }
+        fds.set_len(size as usize / mem::size_of::<libc::proc_fdinfo>());
+
+        for fd in fds {
+            if fd.proc_fdtype as c_int != libc::PROX_FDTYPE_SOCKET {
+                continue;
+            }
+            let mut info: socket_fdinfo = mem::zeroed();
+            let ret = libc::proc_pidfdinfo(
+                pid.0,
+                fd.proc_fd,
+                PROC_PIDFDSOCKETINFO,
+                &mut info as *mut socket_fdinfo as *mut c_void,
+                mem::size_of::<socket_fdinfo>() as _,
+            );
+            if ret <= 0 {
+                continue;
+            }
+            let (protocol, in_info, state) = match info.psi.soi_kind {
+                SOCKINFO_TCP => {
+                    let tcp_info = info.psi.soi_proto.pri_tcp;
+                    (
+                        ConnectionProtocol::Tcp,
+                        tcp_info.tcpsi_ini,
+                        Some(tcp_state(tcp_info.tcpsi_state)),
+                    )
+                }
+                SOCKINFO_IN if info.psi.soi_protocol == libc::IPPROTO_UDP => {
+                    (ConnectionProtocol::Udp, info.psi.soi_proto.pri_in, None)
+                }
+                _ => continue,
+            };
+            let Some(local_ip) = ip_address(&in_info, &in_info.insi_laddr) else {
+                continue;
+            };
+            // Ports are stored in network byte order.
+            let local_address = SocketAddr::new(local_ip, u16::from_be(in_info.insi_lport as u16));
+            let remote_address = ip_address(&in_info, &in_info.insi_faddr)
+                .map(|ip| SocketAddr::new(ip, u16::from_be(in_info.insi_fport as u16)))
+                .filter(|addr| !addr.ip().is_unspecified() || addr.port() != 0);
+            connections.push(Connection {
+                protocol,
+                local_address,
+                remote_address,
+                state,
+                pid: with_pid.then_some(pid),
+                inode: None,
+            });
+        }
+    }
+}
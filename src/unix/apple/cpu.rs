@@ -51,16 +51,20 @@ impl CpusWrapper {
         if refresh_kind.cpu_usage() && need_cpu_usage_update {
             self.last_update = Some(Instant::now());
             update_cpu_usage(port, &mut self.global_cpu, |proc_data, cpu_info| {
-                let mut percentage = 0f32;
+                let mut total_in_use = 0i64;
+                let mut total_ticks = 0i64;
                 let mut offset = 0;
                 for proc_ in cpus.iter_mut() {
-                    let cpu_usage = compute_usage_of_cpu(proc_, cpu_info, offset);
-                    proc_.inner.update(cpu_usage, Arc::clone(&proc_data));
-                    percentage += proc_.inner.cpu_usage();
+                    let (in_use, total) = compute_ticks_of_cpu(proc_, cpu_info, offset);
+                    proc_
+                        .inner
+                        .update(ticks_to_usage(in_use, total), Arc::clone(&proc_data));
+                    total_in_use = total_in_use.saturating_add(in_use);
+                    total_ticks = total_ticks.saturating_add(total);
 
                     offset += libc::CPU_STATE_MAX as isize;
                 }
-                (percentage, cpus.len())
+                (total_in_use, total_ticks)
             });
         }
     }
@@ -256,7 +260,8 @@ fn get_idle(cpu_info: *mut i32, offset: isize) -> i32 {
     unsafe { *cpu_info.offset(offset + libc::CPU_STATE_IDLE as isize) }
 }
 
-pub(crate) fn compute_usage_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isize) -> f32 {
+/// Returns the "in use" and total ticks of the CPU since the last update.
+fn compute_ticks_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isize) -> (i64, i64) {
     let old_cpu_info = proc_.inner.data().cpu_info.0;
     let in_use;
     let idle;
@@ -275,7 +280,10 @@ pub(crate) fn compute_usage_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isiz
         in_use = new_in_use.saturating_sub(old_in_use);
         idle = new_idle.saturating_sub(old_idle) as _;
     }
-    let total = in_use.saturating_add(idle as _);
+    (in_use, in_use.saturating_add(idle as _))
+}
+
+fn ticks_to_usage(in_use: i64, total: i64) -> f32 {
     let usage = (in_use as f32 / total as f32) * 100.;
     if usage.is_nan() {
         // If divided by zero, avoid returning a NaN
@@ -285,7 +293,9 @@ pub(crate) fn compute_usage_of_cpu(proc_: &Cpu, cpu_info: *mut i32, offset: isiz
     }
 }
 
-pub(crate) fn update_cpu_usage<F: FnOnce(Arc<CpuData>, *mut i32) -> (f32, usize)>(
+/// `f` returns the sum of "in use" and total ticks of all CPUs, which are used to compute the
+/// global CPU usage (rather than doing the average of each CPU's usage).
+pub(crate) fn update_cpu_usage<F: FnOnce(Arc<CpuData>, *mut i32) -> (i64, i64)>(
     port: libc::mach_port_t,
     global_cpu: &mut CpuUsage,
     f: F,
@@ -305,9 +315,8 @@ pub(crate) fn update_cpu_usage<F: FnOnce(Arc<CpuData>, *mut i32) -> (f32, usize)
             &mut num_cpu_info as *mut u32,
         ) == libc::KERN_SUCCESS
         {
-            let (total_percentage, len) =
-                f(Arc::new(CpuData::new(cpu_info, num_cpu_info)), cpu_info);
-            total_cpu_usage = total_percentage / len as f32;
+            let (in_use, total) = f(Arc::new(CpuData::new(cpu_info, num_cpu_info)), cpu_info);
+            total_cpu_usage = ticks_to_usage(in_use, total);
         }
         global_cpu.set_cpu_usage(total_cpu_usage);
     }
@@ -339,7 +348,8 @@ pub(crate) fn init_cpus(
         }
     }
     update_cpu_usage(port, global_cpu, |proc_data, cpu_info| {
-        let mut percentage = 0f32;
+        let mut total_in_use = 0i64;
+        let mut total_ticks = 0i64;
         let mut offset = 0;
         for i in 0..num_cpu {
             let mut cpu = Cpu {
@@ -350,17 +360,19 @@ pub(crate) fn init_cpus(
                     vendor_id.clone(),
                     brand.clone(),
                 ),
+                usage_history: Vec::new(),
             };
             if refresh_kind.cpu_usage() {
-                let cpu_usage = compute_usage_of_cpu(&cpu, cpu_info, offset);
-                cpu.inner.set_cpu_usage(cpu_usage);
-                percentage += cpu.cpu_usage();
+                let (in_use, total) = compute_ticks_of_cpu(&cpu, cpu_info, offset);
+                cpu.inner.set_cpu_usage(ticks_to_usage(in_use, total));
+                total_in_use = total_in_use.saturating_add(in_use);
+                total_ticks = total_ticks.saturating_add(total);
             }
             cpus.push(cpu);
 
             offset += libc::CPU_STATE_MAX as isize;
         }
-        (percentage, cpus.len())
+        (total_in_use, total_ticks)
     });
 }
 
@@ -1,7 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::disk::FillRate;
 use crate::{sys::ffi, DiskUsage};
-use crate::{Disk, DiskKind, DiskRefreshKind};
+use crate::{Disk, DiskKind, DiskRefreshKind, RefreshError, RefreshSubsystem};
 
 use objc2_core_foundation::{
     kCFAllocatorDefault, kCFTypeArrayCallBacks, kCFURLVolumeAvailableCapacityForImportantUsageKey,
@@ -14,6 +15,7 @@ use objc2_core_foundation::{
 use libc::c_void;
 
 use std::ffi::{CStr, OsStr, OsString};
+use std::io;
 use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
 use std::ptr;
@@ -28,6 +30,7 @@ pub(crate) struct DiskInner {
     volume_url: CFRetained<CFURL>,
     pub(crate) total_space: u64,
     pub(crate) available_space: u64,
+    fill_rate: FillRate,
     pub(crate) is_removable: bool,
     pub(crate) is_read_only: bool,
     pub(crate) old_written_bytes: u64,
@@ -68,6 +71,10 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn fill_rate_bytes_per_sec(&self) -> Option<f64> {
+        self.fill_rate.get()
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -96,7 +103,10 @@ impl DiskInner {
                                 }
                             }
                             match get_available_volume_space(&disk_props) {
-                                Some(available_space) => self.available_space = available_space,
+                                Some(available_space) => {
+                                    self.available_space = available_space;
+                                    self.fill_rate.update(available_space);
+                                }
                                 None => {
                                     sysinfo_debug!("Failed to get disk available space");
                                 }
@@ -183,13 +193,19 @@ impl crate::DisksInner {
         remove_not_listed_disks: bool,
         refresh_kind: DiskRefreshKind,
     ) {
-        unsafe {
+        let _ = self.try_refresh_specifics(remove_not_listed_disks, refresh_kind);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        let result = unsafe {
             // SAFETY: We don't keep any Objective-C objects around because we
             // don't make any direct Objective-C calls in this code.
-            with_autorelease(|| {
-                get_list(&mut self.disks, refresh_kind);
-            })
-        }
+            with_autorelease(|| get_list(&mut self.disks, refresh_kind))
+        };
 
         if remove_not_listed_disks {
             self.disks.retain_mut(|disk| {
@@ -204,6 +220,7 @@ impl crate::DisksInner {
                 c.inner.updated = false;
             }
         }
+        result.map_err(|error| vec![error])
     }
 
     pub(crate) fn list(&self) -> &[Disk] {
@@ -215,18 +232,32 @@ impl crate::DisksInner {
     }
 }
 
-unsafe fn get_list(container: &mut Vec<Disk>, refresh_kind: DiskRefreshKind) {
+unsafe fn get_list(
+    container: &mut Vec<Disk>,
+    refresh_kind: DiskRefreshKind,
+) -> Result<(), RefreshError> {
+    let getfsstat_error = || {
+        RefreshError::new(
+            RefreshSubsystem::Disks,
+            "getfsstat",
+            io::Error::last_os_error(),
+        )
+    };
     let raw_disks = {
         let count = libc::getfsstat(ptr::null_mut(), 0, libc::MNT_NOWAIT);
-        if count < 1 {
-            return;
+        if count < 0 {
+            return Err(getfsstat_error());
+        } else if count == 0 {
+            return Ok(());
         }
         let bufsize = count * std::mem::size_of::<libc::statfs>() as libc::c_int;
         let mut disks = Vec::with_capacity(count as _);
         let count = libc::getfsstat(disks.as_mut_ptr(), bufsize, libc::MNT_NOWAIT);
 
-        if count < 1 {
-            return;
+        if count < 0 {
+            return Err(getfsstat_error());
+        } else if count == 0 {
+            return Ok(());
         }
 
         disks.set_len(count as usize);
@@ -258,7 +289,7 @@ unsafe fn get_list(container: &mut Vec<Disk>, refresh_kind: DiskRefreshKind) {
         Some(properties) => properties,
         None => {
             sysinfo_debug!("failed to create volume key list");
-            return;
+            return Ok(());
         }
     };
 
@@ -326,6 +357,7 @@ unsafe fn get_list(container: &mut Vec<Disk>, refresh_kind: DiskRefreshKind) {
             container.push(disk);
         }
     }
+    Ok(())
 }
 
 unsafe fn build_requested_properties(
@@ -422,6 +454,7 @@ unsafe fn new_disk(
         }
         if let Some(available_space) = available_space {
             disk.available_space = available_space;
+            disk.fill_rate.update(available_space);
         }
         disk.refresh_io(refresh_kind);
         disk.refresh_kind(refresh_kind);
@@ -482,6 +515,7 @@ unsafe fn new_disk(
         volume_url,
         total_space: total_space.unwrap_or(0),
         available_space: available_space.unwrap_or(0),
+        fill_rate: FillRate::default(),
         is_removable,
         is_read_only,
         read_bytes: 0,
@@ -492,6 +526,9 @@ unsafe fn new_disk(
         uuid,
     };
 
+    if let Some(available_space) = available_space {
+        disk.fill_rate.update(available_space);
+    }
     disk.refresh_kind(refresh_kind);
     disk.refresh_io(refresh_kind);
 
@@ -6,3 +6,16 @@ extern "C" {
     pub fn objc_autoreleasePoolPop(pool: *mut libc::c_void);
     pub fn objc_autoreleasePoolPush() -> *mut libc::c_void;
 }
+
+#[cfg(all(feature = "user", target_os = "macos"))]
+#[repr(C)]
+pub struct lastlogx {
+    pub ll_tv: libc::timeval,
+    pub ll_line: [libc::c_char; 32],
+    pub ll_host: [libc::c_char; 256],
+}
+
+#[cfg(all(feature = "user", target_os = "macos"))]
+extern "C" {
+    pub fn getlastlogxbyname(user: *const libc::c_char, lastlogx: *mut lastlogx) -> *mut lastlogx;
+}
@@ -27,15 +27,22 @@ pub(crate) fn get_groups(groups: &mut Vec<Group>) {
                     continue;
                 }
 
-                let gid = (*gr).gr_gid;
-                groups_map.insert(name, Gid(gid));
+                let mut members = Vec::new();
+                let mut member = (*gr).gr_mem;
+                while !member.is_null() && !(*member).is_null() {
+                    if let Some(name) = crate::unix::utils::cstr_to_rust(*member) {
+                        members.push(name);
+                    }
+                    member = member.add(1);
+                }
+                groups_map.insert(name, (Gid((*gr).gr_gid), members));
             }
         }
         endgrent();
     }
-    for (name, gid) in groups_map {
+    for (name, (gid, members)) in groups_map {
         groups.push(Group {
-            inner: GroupInner::new(gid, name),
+            inner: GroupInner::new(gid, name, members),
         });
     }
 }
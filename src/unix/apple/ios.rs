@@ -1,6 +1,8 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 pub mod ffi {}
+#[cfg(feature = "battery")]
+pub use crate::sys::app_store::battery;
 #[cfg(feature = "component")]
 pub use crate::sys::app_store::component;
 #[cfg(feature = "system")]
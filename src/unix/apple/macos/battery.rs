@@ -0,0 +1,254 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Information about the batteries (the internal one and the UPSs), read from the IOKit power
+// sources. The internal battery details which aren't available there are read from the
+// `AppleSmartBattery` IOKit service, in which the capacities are in mAh, the voltage in mV and
+// the current in mA.
+use crate::sys::macos::utils::IOReleaser;
+use crate::{Battery, BatteryState};
+
+use objc2_core_foundation::{
+    kCFAllocatorDefault, CFArray, CFBoolean, CFDictionary, CFNumber, CFRetained, CFString, CFType,
+};
+use objc2_io_kit::{
+    IOPSCopyPowerSourcesInfo, IOPSCopyPowerSourcesList, IOPSGetPowerSourceDescription,
+    IORegistryEntryCreateCFProperty, IOServiceGetMatchingService, IOServiceMatching,
+};
+
+type PowerSource = CFDictionary<CFString, CFType>;
+
+/// Returns the descriptions of the power sources. The power adapter isn't part of them.
+fn get_power_sources() -> Vec<CFRetained<PowerSource>> {
+    let Some(info) = IOPSCopyPowerSourcesInfo() else {
+        sysinfo_debug!("IOPSCopyPowerSourcesInfo call failed");
+        return Vec::new();
+    };
+    unsafe {
+        let Some(list) = IOPSCopyPowerSourcesList(Some(&info)) else {
+            sysinfo_debug!("IOPSCopyPowerSourcesList call failed");
+            return Vec::new();
+        };
+        // SAFETY: Documented to be a CFArray of CFTypeRef.
+        let list = CFRetained::cast_unchecked::<CFArray<CFType>>(list);
+        list.iter()
+            .filter_map(|source| IOPSGetPowerSourceDescription(Some(&info), Some(&source)))
+            // SAFETY: The keys of the description are the `kIOPS*Key` strings.
+            .map(|description| CFRetained::cast_unchecked::<PowerSource>(description))
+            .collect()
+    }
+}
+
+fn get_source_value(source: &PowerSource, key: &'static str) -> Option<CFRetained<CFType>> {
+    source.get(&CFString::from_static_str(key))
+}
+
+fn get_source_int(source: &PowerSource, key: &'static str) -> Option<i64> {
+    get_source_value(source, key)?
+        .downcast::<CFNumber>()
+        .ok()?
+        .as_i64()
+}
+
+fn get_source_bool(source: &PowerSource, key: &'static str) -> bool {
+    get_source_value(source, key)
+        .and_then(|value| value.downcast::<CFBoolean>().ok())
+        .is_some_and(|value| value.as_bool())
+}
+
+fn get_source_string(source: &PowerSource, key: &'static str) -> Option<String> {
+    get_source_value(source, key)
+        .and_then(|value| value.downcast::<CFString>().ok())
+        .map(|value| value.to_string())
+}
+
+fn get_battery_service() -> Option<IOReleaser> {
+    unsafe {
+        let Some(matching) = IOServiceMatching(b"AppleSmartBattery\0".as_ptr().cast()) else {
+            sysinfo_debug!("IOServiceMatching call failed, `AppleSmartBattery` not found");
+            return None;
+        };
+        let matching = CFRetained::<CFDictionary>::from(&matching);
+        // Machines without battery don't have this service. `0` (`MACH_PORT_NULL`) means the
+        // default main port: `kIOMasterPortDefault` is deprecated and `kIOMainPortDefault` is only
+        // available since macOS 12.
+        IOReleaser::new(IOServiceGetMatchingService(0, Some(matching)))
+    }
+}
+
+unsafe fn get_property(service: &IOReleaser, key: &'static str) -> Option<CFRetained<CFType>> {
+    IORegistryEntryCreateCFProperty(
+        service.inner(),
+        Some(&CFString::from_static_str(key)),
+        kCFAllocatorDefault,
+        0,
+    )
+}
+
+unsafe fn get_int(service: &IOReleaser, key: &'static str) -> Option<i64> {
+    get_property(service, key)?
+        .downcast::<CFNumber>()
+        .ok()?
+        .as_i64()
+}
+
+pub(crate) struct BatteryInner {
+    name: String,
+    charge_percent: Option<f32>,
+    state: BatteryState,
+    cycle_count: Option<u32>,
+    design_capacity: Option<f32>,
+    full_charge_capacity: Option<f32>,
+    power_watts: Option<f32>,
+    pub(crate) updated: bool,
+}
+
+impl BatteryInner {
+    fn new(name: String, source: &PowerSource) -> Self {
+        let mut battery = Self {
+            name,
+            charge_percent: None,
+            state: BatteryState::Unknown,
+            cycle_count: None,
+            design_capacity: None,
+            full_charge_capacity: None,
+            power_watts: None,
+            updated: true,
+        };
+        battery.update(source);
+        battery
+    }
+
+    fn update(&mut self, source: &PowerSource) {
+        self.state = if get_source_bool(source, "Is Charged") {
+            BatteryState::Full
+        } else if get_source_bool(source, "Is Charging") {
+            BatteryState::Charging
+        } else {
+            match get_source_string(source, "Power Source State").as_deref() {
+                Some("AC Power") => BatteryState::NotCharging,
+                Some("Battery Power") => BatteryState::Discharging,
+                _ => BatteryState::Unknown,
+            }
+        };
+        self.charge_percent = match (
+            get_source_int(source, "Current Capacity"),
+            get_source_int(source, "Max Capacity"),
+        ) {
+            (Some(current), Some(max)) if max > 0 => {
+                Some((current as f32 * 100. / max as f32).min(100.))
+            }
+            _ => None,
+        };
+        if get_source_string(source, "Type").as_deref() == Some("InternalBattery") {
+            if let Some(service) = get_battery_service() {
+                unsafe { self.update_from_service(&service) };
+            }
+        } else {
+            // mA * mV = µW.
+            self.power_watts = get_source_int(source, "Current")
+                .zip(get_source_int(source, "Voltage"))
+                .map(|(current, voltage)| {
+                    (current.unsigned_abs() * voltage.unsigned_abs()) as f32 / 1_000_000.
+                });
+        }
+    }
+
+    unsafe fn update_from_service(&mut self, service: &IOReleaser) {
+        self.cycle_count =
+            get_int(service, "CycleCount").and_then(|count| u32::try_from(count).ok());
+        let voltage = get_int(service, "Voltage").filter(|&voltage| voltage > 0);
+        // mAh * mV = µWh.
+        let energy = |key| Some((get_int(service, key)? * voltage?) as f32 / 1_000_000.);
+        // On Apple Silicon, `MaxCapacity` is a percentage, so the actual capacity is only
+        // available in the `AppleRawMaxCapacity` property.
+        self.design_capacity = energy("DesignCapacity");
+        self.full_charge_capacity = energy("AppleRawMaxCapacity");
+        self.power_watts = get_int(service, "InstantAmperage")
+            .or_else(|| get_int(service, "Amperage"))
+            .zip(voltage)
+            .map(|(current, voltage)| {
+                (current.unsigned_abs() * voltage as u64) as f32 / 1_000_000.
+            });
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn charge_percent(&self) -> Option<f32> {
+        self.charge_percent
+    }
+
+    pub(crate) fn state(&self) -> BatteryState {
+        self.state
+    }
+
+    pub(crate) fn cycle_count(&self) -> Option<u32> {
+        self.cycle_count
+    }
+
+    pub(crate) fn design_capacity(&self) -> Option<f32> {
+        self.design_capacity
+    }
+
+    pub(crate) fn full_charge_capacity(&self) -> Option<f32> {
+        self.full_charge_capacity
+    }
+
+    pub(crate) fn power_watts(&self) -> Option<f32> {
+        self.power_watts
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        if let Some(source) = get_power_sources()
+            .into_iter()
+            .find(|source| get_source_string(source, "Name").as_deref() == Some(&self.name))
+        {
+            self.update(&source);
+        }
+    }
+}
+
+pub(crate) struct BatteriesInner {
+    pub(crate) batteries: Vec<Battery>,
+}
+
+impl BatteriesInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            batteries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(batteries: Vec<Battery>) -> Self {
+        Self { batteries }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Battery> {
+        self.batteries
+    }
+
+    pub(crate) fn list(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Battery] {
+        &mut self.batteries
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        for source in get_power_sources() {
+            let Some(name) = get_source_string(&source, "Name") else {
+                continue;
+            };
+            if let Some(battery) = self.batteries.iter_mut().find(|b| b.inner.name == name) {
+                battery.inner.update(&source);
+                battery.inner.updated = true;
+            } else {
+                self.batteries.push(Battery {
+                    inner: BatteryInner::new(name, &source),
+                });
+            }
+        }
+    }
+}
@@ -12,13 +12,60 @@ use crate::sys::inner::ffi::{
     HID_DEVICE_PROPERTY_PRIMARY_USAGE, HID_DEVICE_PROPERTY_PRIMARY_USAGE_PAGE,
     HID_DEVICE_PROPERTY_PRODUCT,
 };
-use crate::Component;
+use crate::{Component, PowerSensor, PowerSensorKind};
+
+// Human readable names of the known temperature sensors, matched against their HID product
+// name. In both, `{}` stands for the sensor number. Sensors which aren't listed keep their HID
+// product name as label.
+const SENSOR_NAMES: &[(&str, &str)] = &[
+    ("pACC MTR Temp Sensor{}", "CPU P-core {}"),
+    ("eACC MTR Temp Sensor{}", "CPU E-core {}"),
+    ("GPU MTR Temp Sensor{}", "GPU {}"),
+    ("ANE MTR Temp Sensor{}", "Neural Engine {}"),
+    ("ISP MTR Temp Sensor{}", "Image Signal Processor {}"),
+    ("SOC MTR Temp Sensor{}", "SoC {}"),
+    ("PMGR SOC Die Temp Sensor{}", "SoC Die {}"),
+    ("PMU tdie{}", "PMU Die {}"),
+    ("PMU tdev{}", "PMU Device {}"),
+    ("PMU2 tdie{}", "PMU 2 Die {}"),
+    ("PMU2 tdev{}", "PMU 2 Device {}"),
+    ("PMU tcal", "PMU Calibration"),
+    ("NAND CH{} temp", "NAND"),
+    ("gas gauge battery", "Battery"),
+];
+
+/// Returns the sensor number if `id` matches `pattern`, or an empty string if `pattern` doesn't
+/// contain one.
+fn match_sensor_pattern<'a>(pattern: &str, id: &'a str) -> Option<&'a str> {
+    match pattern.split_once("{}") {
+        Some((prefix, suffix)) => {
+            let nb = id.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            (!nb.is_empty() && nb.bytes().all(|c| c.is_ascii_digit())).then_some(nb)
+        }
+        None => (pattern == id).then_some(""),
+    }
+}
+
+fn sensor_label(id: &str) -> String {
+    SENSOR_NAMES
+        .iter()
+        .find_map(|(pattern, name)| {
+            let nb = match_sensor_pattern(pattern, id)?;
+            Some(name.replace("{}", nb))
+        })
+        .unwrap_or_else(|| id.to_owned())
+}
 
 pub(crate) struct ComponentsInner {
     pub(crate) components: Vec<Component>,
     client: Option<CFRetained<IOHIDEventSystemClient>>,
 }
 
+// Needed because `client` type (`IOHIDEventSystemClient` contains an `*const UnsafeCell`).
+// `UnsafeCell` is `!Sync` and `*const` is both `!Sync` and `!Send`. However, the client is only
+// used through `&mut self`, so it's "safe" to make it `Send`.
+unsafe impl Send for ComponentsInner {}
+
 impl ComponentsInner {
     pub(crate) fn new() -> Self {
         Self {
@@ -46,8 +93,12 @@ impl ComponentsInner {
         &mut self.components
     }
 
-    #[allow(unreachable_code)]
     pub(crate) fn refresh(&mut self) {
+        self.refresh_filtered(&mut |_, _| true);
+    }
+
+    #[allow(unreachable_code)]
+    pub(crate) fn refresh_filtered(&mut self, filter: &mut dyn FnMut(&str, &str) -> bool) {
         let keys = [
             &*CFString::from_static_str(HID_DEVICE_PROPERTY_PRIMARY_USAGE_PAGE),
             &*CFString::from_static_str(HID_DEVICE_PROPERTY_PRIMARY_USAGE),
@@ -92,21 +143,24 @@ impl ComponentsInner {
                 };
                 let name = name.downcast::<CFString>().unwrap();
                 let name_str = name.to_string();
+                let label = sensor_label(&name_str);
+                if !filter("", &label) {
+                    continue;
+                }
 
-                if let Some(c) = self
-                    .components
-                    .iter_mut()
-                    .find(|c| c.inner.label == name_str)
-                {
+                if let Some(c) = self.components.iter_mut().find(|c| c.inner.id == name_str) {
                     c.refresh();
                     c.inner.updated = true;
                     continue;
                 }
 
-                let mut component = ComponentInner::new(name_str, None, None, service);
+                let mut component = ComponentInner::new(label, name_str, None, None, service);
                 component.refresh();
 
-                self.components.push(Component { inner: component });
+                self.components.push(Component {
+                    inner: component,
+                    observed_max: None,
+                });
             }
         }
     }
@@ -116,6 +170,8 @@ pub(crate) struct ComponentInner {
     service: CFRetained<IOHIDServiceClient>,
     temperature: Option<f32>,
     label: String,
+    // The HID product name.
+    id: String,
     max: f32,
     critical: Option<f32>,
     pub(crate) updated: bool,
@@ -127,6 +183,7 @@ unsafe impl Sync for ComponentInner {}
 impl ComponentInner {
     pub(crate) fn new(
         label: String,
+        id: String,
         max: Option<f32>,
         critical: Option<f32>,
         service: CFRetained<IOHIDServiceClient>,
@@ -134,6 +191,7 @@ impl ComponentInner {
         Self {
             service,
             label,
+            id,
             max: max.unwrap_or(0.),
             critical,
             temperature: None,
@@ -149,10 +207,30 @@ impl ComponentInner {
         Some(self.max)
     }
 
+    pub(crate) fn min(&self) -> Option<f32> {
+        None
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         self.critical
     }
 
+    pub(crate) fn critical_hysteresis(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn alarm(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn device_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn id(&self) -> Option<&str> {
+        Some(&self.id)
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
@@ -179,3 +257,79 @@ impl ComponentInner {
         }
     }
 }
+
+pub(crate) struct PowerSensorInner {
+    pub(crate) updated: bool,
+}
+
+impl PowerSensorInner {
+    pub(crate) fn kind(&self) -> PowerSensorKind {
+        PowerSensorKind::Power
+    }
+
+    pub(crate) fn value(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct PowerSensorsInner {
+    pub(crate) sensors: Vec<PowerSensor>,
+}
+
+impl PowerSensorsInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            sensors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(sensors: Vec<PowerSensor>) -> Self {
+        Self { sensors }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<PowerSensor> {
+        self.sensors
+    }
+
+    pub(crate) fn list(&self) -> &[PowerSensor] {
+        &self.sensors
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [PowerSensor] {
+        &mut self.sensors
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // FIXME: could be retrieved from the SMC power keys like on x86.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{sensor_label, SENSOR_NAMES};
+
+    #[test]
+    fn check_sensor_names() {
+        for (pattern, name) in SENSOR_NAMES {
+            let label = sensor_label(&pattern.replace("{}", "1"));
+            assert!(!label.is_empty(), "empty label for {pattern:?}");
+            assert!(!label.contains("{}"), "{label:?}");
+            if name.contains("{}") {
+                assert!(label.ends_with(" 1"), "{label:?}");
+            }
+        }
+        assert_eq!(sensor_label("pACC MTR Temp Sensor12"), "CPU P-core 12");
+        assert_eq!(sensor_label("NAND CH0 temp"), "NAND");
+        assert_eq!(sensor_label("gas gauge battery"), "Battery");
+        // Unknown sensors keep their raw name.
+        assert_eq!(sensor_label("PMU tdevX"), "PMU tdevX");
+        assert_eq!(sensor_label("pACC MTR Temp Sensor"), "pACC MTR Temp Sensor");
+        assert_eq!(sensor_label("unknown sensor"), "unknown sensor");
+    }
+}
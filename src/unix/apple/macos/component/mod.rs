@@ -1,5 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+pub(crate) mod smc;
+
+pub(crate) use self::smc::{FanInner, FansInner};
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub(crate) mod x86;
 
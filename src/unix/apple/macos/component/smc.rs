@@ -0,0 +1,377 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Access to the System Management Controller (SMC), available on both Intel and Apple Silicon
+// Macs.
+
+use crate::sys::macos::{ffi, utils::IOReleaser};
+use crate::Fan;
+
+use libc::{c_char, c_int, c_void};
+use objc2_core_foundation::{CFDictionary, CFRetained};
+use objc2_io_kit::{
+    io_connect_t, io_iterator_t, kIOMasterPortDefault, kIOReturnSuccess, IOConnectCallStructMethod,
+    IOIteratorNext, IOServiceClose, IOServiceGetMatchingServices, IOServiceMatching, IOServiceOpen,
+};
+
+use std::mem;
+
+pub(crate) struct ComponentFFI {
+    pub(super) input_structure: ffi::KeyData_t,
+    pub(super) val: ffi::Val_t,
+    /// It is the `System::connection`. We need it to not require an extra argument
+    /// in `ComponentInner::refresh`.
+    pub(super) connection: io_connect_t,
+}
+
+impl ComponentFFI {
+    pub(super) fn new(key: &[i8], connection: io_connect_t) -> Option<ComponentFFI> {
+        unsafe {
+            get_key_size(connection, key)
+                .ok()
+                .map(|(input_structure, val)| ComponentFFI {
+                    input_structure,
+                    val,
+                    connection,
+                })
+        }
+    }
+
+    pub(super) fn value(&self) -> Option<f32> {
+        get_value_inner(self.connection, &self.input_structure, &self.val)
+    }
+
+    fn rpm(&self) -> Option<u32> {
+        self.value().map(|rpm| rpm.max(0.) as u32)
+    }
+}
+
+/// Returns the `F{id}{suffix}` SMC key, like `F0Ac` for the current speed of the first fan.
+fn fan_key(id: u8, suffix: &[u8; 2]) -> [i8; 4] {
+    [
+        b'F' as i8,
+        (b'0' + id) as i8,
+        suffix[0] as i8,
+        suffix[1] as i8,
+    ]
+}
+
+pub(crate) struct FansInner {
+    pub(crate) fans: Vec<Fan>,
+    connection: Option<IoService>,
+}
+
+impl FansInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            fans: Vec::with_capacity(2),
+            connection: IoService::new_connection(),
+        }
+    }
+
+    pub(crate) fn from_vec(fans: Vec<Fan>) -> Self {
+        Self {
+            fans,
+            connection: IoService::new_connection(),
+        }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Fan> {
+        self.fans
+    }
+
+    pub(crate) fn list(&self) -> &[Fan] {
+        &self.fans
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Fan] {
+        &mut self.fans
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        let Some(ref connection) = self.connection else {
+            sysinfo_debug!("No connection to IoService, skipping fans refresh");
+            return;
+        };
+        let connection = connection.inner();
+        // The fans keys only have one digit for the fan ID.
+        let nb_fans = get_nb_fans(connection).unwrap_or(0).min(10);
+
+        for id in 0..nb_fans {
+            let label = format!("Fan {}", id + 1);
+            if let Some(f) = self.fans.iter_mut().find(|f| f.inner.label == label) {
+                f.refresh();
+                f.inner.updated = true;
+            } else if let Some(f) = FanInner::new(label, id, connection) {
+                self.fans.push(Fan { inner: f });
+            }
+        }
+    }
+}
+
+pub(crate) struct FanInner {
+    rpm: Option<u32>,
+    min_rpm: Option<u32>,
+    max_rpm: Option<u32>,
+    label: String,
+    ffi_part: ComponentFFI,
+    pub(crate) updated: bool,
+}
+
+impl FanInner {
+    fn new(label: String, id: u8, connection: io_connect_t) -> Option<Self> {
+        let ffi_part = ComponentFFI::new(&fan_key(id, b"Ac"), connection)?;
+        ffi_part.rpm().map(|rpm| Self {
+            rpm: Some(rpm),
+            min_rpm: get_rpm(connection, &fan_key(id, b"Mn")),
+            max_rpm: get_rpm(connection, &fan_key(id, b"Mx")),
+            label,
+            ffi_part,
+            updated: true,
+        })
+    }
+
+    pub(crate) fn rpm(&self) -> Option<u32> {
+        self.rpm
+    }
+
+    pub(crate) fn min_rpm(&self) -> Option<u32> {
+        self.min_rpm
+    }
+
+    pub(crate) fn max_rpm(&self) -> Option<u32> {
+        self.max_rpm
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.rpm = self.ffi_part.rpm();
+    }
+}
+
+unsafe fn perform_call(
+    conn: io_connect_t,
+    index: c_int,
+    input_structure: *const ffi::KeyData_t,
+    output_structure: *mut ffi::KeyData_t,
+) -> i32 {
+    let mut structure_output_size = mem::size_of::<ffi::KeyData_t>();
+
+    IOConnectCallStructMethod(
+        conn,
+        index as u32,
+        input_structure.cast(),
+        mem::size_of::<ffi::KeyData_t>(),
+        output_structure.cast(),
+        &mut structure_output_size,
+    )
+}
+
+// Adapted from https://github.com/lavoiesl/osx-cpu-temp/blob/master/smc.c#L28
+#[inline]
+fn strtoul(s: &[i8]) -> u32 {
+    unsafe {
+        ((*s.get_unchecked(0) as u32) << (3u32 << 3))
+            + ((*s.get_unchecked(1) as u32) << (2u32 << 3))
+            + ((*s.get_unchecked(2) as u32) << (1u32 << 3))
+            + (*s.get_unchecked(3) as u32)
+    }
+}
+
+#[inline]
+unsafe fn ultostr(s: *mut c_char, val: u32) {
+    *s.offset(0) = ((val >> 24) % 128) as i8;
+    *s.offset(1) = ((val >> 16) % 128) as i8;
+    *s.offset(2) = ((val >> 8) % 128) as i8;
+    *s.offset(3) = (val % 128) as i8;
+    *s.offset(4) = 0;
+}
+
+pub(super) unsafe fn get_key_size(
+    con: io_connect_t,
+    key: &[i8],
+) -> Result<(ffi::KeyData_t, ffi::Val_t), i32> {
+    let mut input_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
+    let mut output_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
+    let mut val: ffi::Val_t = mem::zeroed::<ffi::Val_t>();
+
+    input_structure.key = strtoul(key);
+    input_structure.data8 = ffi::SMC_CMD_READ_KEYINFO;
+
+    let result = perform_call(
+        con,
+        ffi::KERNEL_INDEX_SMC,
+        &input_structure,
+        &mut output_structure,
+    );
+    if result != kIOReturnSuccess {
+        return Err(result);
+    }
+
+    val.data_size = output_structure.key_info.data_size;
+    ultostr(
+        val.data_type.as_mut_ptr(),
+        output_structure.key_info.data_type,
+    );
+    input_structure.key_info.data_size = val.data_size;
+    input_structure.data8 = ffi::SMC_CMD_READ_BYTES;
+    Ok((input_structure, val))
+}
+
+pub(super) unsafe fn read_key(
+    con: io_connect_t,
+    input_structure: &ffi::KeyData_t,
+    mut val: ffi::Val_t,
+) -> Result<ffi::Val_t, i32> {
+    let mut output_structure: ffi::KeyData_t = mem::zeroed::<ffi::KeyData_t>();
+
+    #[allow(non_upper_case_globals)]
+    match perform_call(
+        con,
+        ffi::KERNEL_INDEX_SMC,
+        input_structure,
+        &mut output_structure,
+    ) {
+        kIOReturnSuccess => {
+            libc::memcpy(
+                val.bytes.as_mut_ptr() as *mut c_void,
+                output_structure.bytes.as_mut_ptr() as *mut c_void,
+                mem::size_of::<[u8; 32]>(),
+            );
+            Ok(val)
+        }
+        result => Err(result),
+    }
+}
+
+/// Converts the `flt `, `fpXY` (unsigned fixed point) and `spXY` (signed fixed point) values,
+/// `Y` being the number of fraction bits in hexadecimal.
+fn get_value_inner(
+    con: io_connect_t,
+    input_structure: &ffi::KeyData_t,
+    original_val: &ffi::Val_t,
+) -> Option<f32> {
+    unsafe {
+        let val = read_key(con, input_structure, (*original_val).clone()).ok()?;
+        if val.data_size == 0 {
+            return None;
+        }
+        let bytes = val.bytes.map(|b| b as u8);
+        match val.data_type.map(|c| c as u8) {
+            [b'f', b'l', b't', b' ', _] => {
+                Some(f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+            }
+            [sign @ (b'f' | b's'), b'p', _, fraction, _] => {
+                let fraction = char::from(fraction).to_digit(16)?;
+                let raw = u16::from_be_bytes([bytes[0], bytes[1]]);
+                let raw = if sign == b's' {
+                    f32::from(raw as i16)
+                } else {
+                    f32::from(raw)
+                };
+                Some(raw / (1u32 << fraction) as f32)
+            }
+            _ => None,
+        }
+    }
+}
+
+fn get_value(con: io_connect_t, key: &[i8]) -> Option<f32> {
+    unsafe {
+        let (input_structure, val) = get_key_size(con, key).ok()?;
+        get_value_inner(con, &input_structure, &val)
+    }
+}
+
+fn get_rpm(con: io_connect_t, key: &[i8]) -> Option<u32> {
+    get_value(con, key).map(|rpm| rpm.max(0.) as u32)
+}
+
+fn get_nb_fans(con: io_connect_t) -> Option<u8> {
+    unsafe {
+        let (input_structure, val) =
+            get_key_size(con, &['F' as i8, 'N' as i8, 'u' as i8, 'm' as i8]).ok()?;
+        let val = read_key(con, &input_structure, val).ok()?;
+        (val.data_size > 0).then_some(val.bytes[0] as u8)
+    }
+}
+
+pub(crate) struct IoService(io_connect_t);
+
+impl IoService {
+    fn new(obj: io_connect_t) -> Option<Self> {
+        if obj == 0 {
+            None
+        } else {
+            Some(Self(obj))
+        }
+    }
+
+    pub(crate) fn inner(&self) -> io_connect_t {
+        self.0
+    }
+
+    // code from https://github.com/Chris911/iStats
+    // Not supported on iOS, or in the default macOS
+    pub(crate) fn new_connection() -> Option<Self> {
+        let mut iterator: io_iterator_t = 0;
+
+        unsafe {
+            let Some(matching) = IOServiceMatching(b"AppleSMC\0".as_ptr() as *const i8) else {
+                sysinfo_debug!("IOServiceMatching call failed, `AppleSMC` not found");
+                return None;
+            };
+            let matching = CFRetained::<CFDictionary>::from(&matching);
+
+            let result =
+                IOServiceGetMatchingServices(kIOMasterPortDefault, Some(matching), &mut iterator);
+            if result != kIOReturnSuccess {
+                sysinfo_debug!("Error: IOServiceGetMatchingServices() = {}", result);
+                return None;
+            }
+            let iterator = match IOReleaser::new(iterator) {
+                Some(i) => i,
+                None => {
+                    sysinfo_debug!("Error: IOServiceGetMatchingServices() succeeded but returned invalid descriptor");
+                    return None;
+                }
+            };
+
+            let device = match IOReleaser::new(IOIteratorNext(iterator.inner())) {
+                Some(d) => d,
+                None => {
+                    sysinfo_debug!("Error: no SMC found");
+                    return None;
+                }
+            };
+
+            let mut conn = 0;
+            let result = IOServiceOpen(
+                device.inner(),
+                #[allow(deprecated)]
+                libc::mach_task_self(),
+                0,
+                &mut conn,
+            );
+            if result != kIOReturnSuccess {
+                sysinfo_debug!("Error: IOServiceOpen() = {}", result);
+                return None;
+            }
+            let conn = IoService::new(conn);
+            if conn.is_none() {
+                sysinfo_debug!(
+                    "Error: IOServiceOpen() succeeded but returned invalid descriptor..."
+                );
+            }
+            conn
+        }
+    }
+}
+
+impl Drop for IoService {
+    fn drop(&mut self) {
+        unsafe { IOServiceClose(self.0) };
+    }
+}
@@ -22,13 +22,7 @@ cfg_if! {
     }
 }
 
-#[cfg(all(
-    not(feature = "apple-sandbox"),
-    all(
-        feature = "component",
-        any(target_arch = "x86", target_arch = "x86_64")
-    ),
-))]
+#[cfg(all(not(feature = "apple-sandbox"), feature = "component"))]
 mod keydata {
     #[cfg_attr(feature = "debug", derive(Eq, Hash, PartialEq))]
     #[derive(Clone)]
@@ -92,13 +86,7 @@ mod keydata {
     pub const SMC_CMD_READ_BYTES: u8 = 5;
 }
 
-#[cfg(all(
-    not(feature = "apple-sandbox"),
-    all(
-        feature = "component",
-        any(target_arch = "x86", target_arch = "x86_64")
-    ),
-))]
+#[cfg(all(not(feature = "apple-sandbox"), feature = "component"))]
 pub use keydata::*;
 
 /// Private Apple APIs.
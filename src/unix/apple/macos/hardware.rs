@@ -0,0 +1,74 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// The information of the machine is stored in the properties of the root of the IOKit registry.
+use crate::sys::macos::utils::IOReleaser;
+use crate::HardwareInfo;
+
+use objc2_core_foundation::{kCFAllocatorDefault, CFData, CFDictionary, CFRetained, CFString};
+#[allow(deprecated)] // Because of kIOMasterPortDefault.
+use objc2_io_kit::{
+    kIOMasterPortDefault, IORegistryEntryCreateCFProperty, IOServiceGetMatchingService,
+    IOServiceMatching,
+};
+
+/// The string properties are stored as NUL-terminated data.
+unsafe fn get_string(service: &IOReleaser, key: &'static str) -> Option<String> {
+    let data = IORegistryEntryCreateCFProperty(
+        service.inner(),
+        Some(&CFString::from_static_str(key)),
+        kCFAllocatorDefault,
+        0,
+    )?
+    .downcast::<CFData>()
+    .ok()?
+    .to_vec();
+    let value = std::str::from_utf8(&data)
+        .ok()?
+        .trim_end_matches('\0')
+        .trim();
+    (!value.is_empty()).then(|| value.to_owned())
+}
+
+#[allow(deprecated)] // Because of kIOMasterPortDefault.
+unsafe fn get_platform_expert() -> Option<IOReleaser> {
+    let Some(matching) = IOServiceMatching(b"IOPlatformExpertDevice\0".as_ptr().cast()) else {
+        sysinfo_debug!("IOServiceMatching call failed, `IOPlatformExpertDevice` not found");
+        return None;
+    };
+    let matching = CFRetained::<CFDictionary>::from(&matching);
+    IOReleaser::new(IOServiceGetMatchingService(
+        kIOMasterPortDefault,
+        Some(matching),
+    ))
+}
+
+pub(crate) fn get_hardware_info() -> HardwareInfo {
+    unsafe {
+        let Some(service) = get_platform_expert() else {
+            return HardwareInfo::default();
+        };
+        HardwareInfo {
+            vendor: get_string(&service, "manufacturer"),
+            product_name: get_string(&service, "model"),
+            // It is only a string on Intel machines.
+            board_name: get_string(&service, "board-id"),
+            ..Default::default()
+        }
+    }
+}
+
+pub(crate) fn get_platform_uuid() -> Option<String> {
+    unsafe {
+        let service = get_platform_expert()?;
+        let uuid = IORegistryEntryCreateCFProperty(
+            service.inner(),
+            Some(&CFString::from_static_str("IOPlatformUUID")),
+            kCFAllocatorDefault,
+            0,
+        )?
+        .downcast::<CFString>()
+        .ok()?
+        .to_string();
+        (!uuid.is_empty()).then_some(uuid)
+    }
+}
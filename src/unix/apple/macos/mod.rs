@@ -5,6 +5,7 @@ pub mod ffi;
 cfg_if! {
     if #[cfg(all(feature = "system", not(feature = "apple-sandbox")))] {
         pub(crate) mod cpu;
+        pub(crate) mod hardware;
         pub mod system;
         pub mod process;
     }
@@ -17,13 +18,7 @@ cfg_if! {
             feature = "disk",
             all(
                 not(feature = "apple-sandbox"),
-                any(
-                    feature = "system",
-                    all(
-                        feature = "component",
-                        any(target_arch = "x86", target_arch = "x86_64")
-                    )
-                )
+                any(feature = "system", feature = "component", feature = "battery")
             ),
         ))]
     {
@@ -40,16 +35,27 @@ cfg_if! {
     } else if #[cfg(feature = "component")] {
         pub mod component;
     }
+
+    if #[cfg(feature = "apple-sandbox")] {
+        #[cfg(feature = "battery")]
+        pub use crate::sys::app_store::battery;
+    } else if #[cfg(feature = "battery")] {
+        pub mod battery;
+    }
 }
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod battery;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
 #[cfg(any())]
+mod hardware;
+#[cfg(any())]
 mod process;
 #[cfg(any())]
 mod system;
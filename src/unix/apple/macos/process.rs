@@ -1,14 +1,21 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::ffi::{OsStr, OsString};
+use std::io;
 use std::mem::{self, MaybeUninit};
 use std::os::unix::ffi::{OsStrExt, OsStringExt};
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use libc::{c_int, c_void, kill};
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::common::field_status::FieldStatuses;
+use crate::{
+    DiskUsage, FdUsage, FieldStatus, Gid, IoPriority, IoPriorityError, Namespaces, Pid, Process,
+    ProcessField, ProcessRefreshKind, ProcessStatus, Signal, Uid,
+};
 
 use crate::sys::process::ThreadStatus;
 use crate::sys::system::Wrap;
@@ -28,6 +35,7 @@ pub(crate) struct ProcessInner {
     old_utime: u64,
     old_stime: u64,
     start_time: u64,
+    start_time_precise: Duration,
     run_time: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
@@ -46,7 +54,14 @@ pub(crate) struct ProcessInner {
     pub(crate) read_bytes: u64,
     pub(crate) written_bytes: u64,
     accumulated_cpu_time: u64,
+    user_cpu_time: Duration,
+    system_cpu_time: Duration,
+    thread_count: Option<usize>,
+    fd_usage: Option<FdUsage>,
+    field_statuses: FieldStatuses,
     exists: bool,
+    // Once the process was reaped, its exit status cannot be retrieved anymore.
+    exit_status: OnceLock<ExitStatus>,
 }
 
 impl ProcessInner {
@@ -67,6 +82,7 @@ impl ProcessInner {
             old_stime: 0,
             updated: true,
             start_time: 0,
+            start_time_precise: Duration::ZERO,
             run_time: 0,
             user_id: None,
             effective_user_id: None,
@@ -79,7 +95,13 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            user_cpu_time: Duration::ZERO,
+            system_cpu_time: Duration::ZERO,
+            thread_count: None,
+            fd_usage: None,
+            field_statuses: FieldStatuses::default(),
             exists: true,
+            exit_status: OnceLock::new(),
         }
     }
 
@@ -100,6 +122,7 @@ impl ProcessInner {
             old_stime: 0,
             updated: true,
             start_time,
+            start_time_precise: Duration::from_secs(start_time),
             run_time,
             user_id: None,
             effective_user_id: None,
@@ -112,7 +135,13 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            user_cpu_time: Duration::ZERO,
+            system_cpu_time: Duration::ZERO,
+            thread_count: None,
+            fd_usage: None,
+            field_statuses: FieldStatuses::default(),
             exists: true,
+            exit_status: OnceLock::new(),
         }
     }
 
@@ -176,6 +205,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_precise(&self) -> SystemTime {
+        UNIX_EPOCH + self.start_time_precise
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -188,6 +221,18 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn user_cpu_time(&self) -> Duration {
+        self.user_cpu_time
+    }
+
+    pub(crate) fn system_cpu_time(&self) -> Duration {
+        self.system_cpu_time
+    }
+
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             read_bytes: self.read_bytes.saturating_sub(self.old_read_bytes),
@@ -197,6 +242,10 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn field_status(&self, field: ProcessField) -> FieldStatus {
+        self.field_statuses.get(field)
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         self.user_id.as_ref()
     }
@@ -213,8 +262,28 @@ impl ProcessInner {
         self.effective_group_id
     }
 
+    pub(crate) fn umask(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn ns_pid(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn io_priority(&self) -> Option<IoPriority> {
+        None
+    }
+
+    pub(crate) fn set_io_priority(&self, _priority: IoPriority) -> Result<(), IoPriorityError> {
+        Err(IoPriorityError::Unsupported)
+    }
+
     pub(crate) fn wait(&self) -> Option<ExitStatus> {
-        crate::unix::utils::wait_process(self.pid)
+        crate::unix::utils::wait_process(self.pid, &self.exit_status)
+    }
+
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        crate::unix::utils::process_exit_code(self.pid, &self.exit_status)
     }
 
     pub(crate) fn session_id(&self) -> Option<Pid> {
@@ -239,6 +308,14 @@ impl ProcessInner {
     pub(crate) fn exists(&self) -> bool {
         self.exists
     }
+
+    pub(crate) fn fd_usage(&self) -> Option<FdUsage> {
+        self.fd_usage
+    }
+
+    pub(crate) fn namespaces(&self) -> Option<Namespaces> {
+        None
+    }
 }
 
 #[allow(deprecated)] // Because of libc::mach_absolute_time.
@@ -312,6 +389,18 @@ unsafe fn get_task_info(pid: Pid) -> libc::proc_taskinfo {
     task_info
 }
 
+/// The task info CPU times are in mach absolute time units, which are not nanoseconds on Apple
+/// Silicon.
+fn set_cpu_time(p: &mut ProcessInner, task_info: &libc::proc_taskinfo, timebase_to_ms: f64) {
+    let to_duration = |time: u64| Duration::from_nanos((time as f64 * timebase_to_ms * 1e6) as u64);
+    p.accumulated_cpu_time = (task_info
+        .pti_total_user
+        .saturating_add(task_info.pti_total_system) as f64
+        * timebase_to_ms) as u64;
+    p.user_cpu_time = to_duration(task_info.pti_total_user);
+    p.system_cpu_time = to_duration(task_info.pti_total_system);
+}
+
 #[inline]
 fn check_if_pid_is_alive(pid: Pid, check_if_alive: bool) -> bool {
     // In case we are iterating all pids we got from `proc_listallpids`, then
@@ -331,6 +420,10 @@ fn check_if_pid_is_alive(pid: Pid, check_if_alive: bool) -> bool {
     }
 }
 
+pub(crate) unsafe fn is_zombie(pid: Pid) -> bool {
+    get_bsd_info(pid).is_some_and(|info| info.pbi_status == libc::SZOMB)
+}
+
 unsafe fn get_bsd_info(pid: Pid) -> Option<libc::proc_bsdinfo> {
     let mut info = mem::zeroed::<libc::proc_bsdinfo>();
 
@@ -348,6 +441,13 @@ unsafe fn get_bsd_info(pid: Pid) -> Option<libc::proc_bsdinfo> {
     }
 }
 
+fn get_start_time_precise(info: &libc::proc_bsdinfo) -> Duration {
+    Duration::new(
+        info.pbi_start_tvsec,
+        (info.pbi_start_tvusec as u32).saturating_mul(1_000),
+    )
+}
+
 fn get_parent(info: &libc::proc_bsdinfo) -> Option<Pid> {
     match info.pbi_ppid as i32 {
         0 => None,
@@ -366,6 +466,7 @@ unsafe fn create_new_process(
         Some(info) => info,
         None => {
             let mut p = ProcessInner::new_empty(pid);
+            set_proc_args_status(&mut p, refresh_kind, FieldStatus::Other);
             if get_exe_and_name_backup(&mut p, refresh_kind, false) {
                 get_cwd_root(&mut p, refresh_kind);
                 return Ok(Some(Process { inner: p }));
@@ -381,6 +482,10 @@ unsafe fn create_new_process(
     let run_time = now.saturating_sub(start_time);
 
     let mut p = ProcessInner::new(pid, parent, start_time, run_time);
+    p.start_time_precise = get_start_time_precise(&info);
+    // Used to know why the arguments cannot be retrieved.
+    p.user_id = Some(Uid(info.pbi_ruid));
+    p.effective_user_id = Some(Uid(info.pbi_uid));
     if !get_process_infos(&mut p, refresh_kind)
         && !get_exe_and_name_backup(&mut p, refresh_kind, false)
     {
@@ -392,12 +497,9 @@ unsafe fn create_new_process(
     if refresh_kind.cpu() || refresh_kind.memory() {
         let task_info = get_task_info(pid);
 
+        p.thread_count = Some(task_info.pti_threadnum as _);
         if refresh_kind.cpu() {
-            p.accumulated_cpu_time = (task_info
-                .pti_total_user
-                .saturating_add(task_info.pti_total_system)
-                as f64
-                * timebase_to_ms) as u64;
+            set_cpu_time(&mut p, &task_info, timebase_to_ms);
         }
         if refresh_kind.memory() {
             p.memory = task_info.pti_resident_size;
@@ -405,14 +507,15 @@ unsafe fn create_new_process(
         }
     }
 
-    p.user_id = Some(Uid(info.pbi_ruid));
-    p.effective_user_id = Some(Uid(info.pbi_uid));
     p.group_id = Some(Gid(info.pbi_rgid));
     p.effective_group_id = Some(Gid(info.pbi_gid));
     p.process_status = ProcessStatus::from(info.pbi_status);
     if refresh_kind.disk_usage() {
         update_proc_disk_activity(&mut p);
     }
+    if refresh_kind.open_files() {
+        update_fd_usage(&mut p);
+    }
     Ok(Some(Process { inner: p }))
 }
 
@@ -443,10 +546,19 @@ unsafe fn get_exe_and_name_backup(
             }
             if exe_needs_update {
                 process.exe = Some(exe);
+                process
+                    .field_statuses
+                    .set(ProcessField::Exe, FieldStatus::Ok);
             }
             true
         }
-        _ => false,
+        _ => {
+            if exe_needs_update {
+                let status = FieldStatus::from_error(&io::Error::last_os_error());
+                process.field_statuses.set(ProcessField::Exe, status);
+            }
+            false
+        }
     }
 }
 
@@ -477,10 +589,19 @@ unsafe fn get_cwd_root(process: &mut ProcessInner, refresh_kind: ProcessRefreshK
     );
     if result < 1 {
         sysinfo_debug!("Failed to retrieve cwd and root for {}", process.pid.0);
+        if cwd_needs_update {
+            let status = FieldStatus::from_error(&io::Error::last_os_error());
+            process.field_statuses.set(ProcessField::Cwd, status);
+        }
         return;
     }
     if cwd_needs_update {
         process.cwd = convert_node_path_info(&vnodepathinfo.pvi_cdir);
+        let status = match process.cwd {
+            Some(_) => FieldStatus::Ok,
+            None => FieldStatus::NotFound,
+        };
+        process.field_statuses.set(ProcessField::Cwd, status);
     }
     if root_needs_update {
         process.root = convert_node_path_info(&vnodepathinfo.pvi_rdir);
@@ -534,6 +655,7 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
             "couldn't get arguments and environment size for PID {}",
             process.pid.0
         );
+        set_proc_args_status(process, refresh_kind, get_proc_args_error_status(process));
         return false; // not enough rights I assume?
     }
 
@@ -551,14 +673,17 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
             "couldn't get arguments and environment for PID {}",
             process.pid.0
         );
+        set_proc_args_status(process, refresh_kind, get_proc_args_error_status(process));
         return false; // What changed since the previous call? Dark magic!
     }
 
     proc_args.set_len(arg_max);
 
     if proc_args.is_empty() {
+        set_proc_args_status(process, refresh_kind, FieldStatus::NotFound);
         return false;
     }
+    set_proc_args_status(process, refresh_kind, FieldStatus::Ok);
     // We copy the number of arguments (`argc`) to `n_args`.
     let mut n_args: c_int = 0;
     libc::memcpy(
@@ -596,6 +721,46 @@ unsafe fn get_process_infos(process: &mut ProcessInner, refresh_kind: ProcessRef
     true
 }
 
+/// Sets the status of the information retrieved from `KERN_PROCARGS2`, before they're updated.
+fn set_proc_args_status(
+    process: &mut ProcessInner,
+    refresh_kind: ProcessRefreshKind,
+    status: FieldStatus,
+) {
+    if refresh_kind.exe().needs_update(|| process.exe.is_none()) {
+        process.field_statuses.set(ProcessField::Exe, status);
+    }
+    if refresh_kind.cmd().needs_update(|| process.cmd.is_empty()) {
+        process.field_statuses.set(ProcessField::Cmd, status);
+    }
+    if refresh_kind
+        .environ()
+        .needs_update(|| process.environ.is_empty())
+    {
+        process.field_statuses.set(ProcessField::Environ, status);
+    }
+}
+
+/// `KERN_PROCARGS2` fails with `EINVAL` both for the processes of the other users (unless
+/// running as root) and for the zombie processes.
+unsafe fn get_proc_args_error_status(process: &ProcessInner) -> FieldStatus {
+    let error = io::Error::last_os_error();
+    if error.raw_os_error() != Some(libc::EINVAL) {
+        return FieldStatus::from_error(&error);
+    }
+    let euid = libc::geteuid();
+    if euid != 0
+        && process
+            .effective_user_id
+            .as_ref()
+            .map_or(true, |uid| uid.0 != euid)
+    {
+        FieldStatus::PermissionDenied
+    } else {
+        FieldStatus::NotFound
+    }
+}
+
 fn get_exe(data: &[u8]) -> (&Path, &[u8]) {
     let pos = data.iter().position(|c| *c == 0).unwrap_or(data.len());
     let (exe, proc_args) = data.split_at(pos);
@@ -671,7 +836,8 @@ pub(crate) fn update_process(
             let mut extra_checked = false;
 
             if let Some(info) = get_bsd_info(pid) {
-                if info.pbi_start_tvsec != p.start_time {
+                // Comparing with the microseconds to detect a PID reused in the same second.
+                if get_start_time_precise(&info) != p.start_time_precise {
                     // We don't want it to be removed, just replaced.
                     p.updated = true;
                     // To ensure the name and exe path will be updated.
@@ -704,6 +870,9 @@ pub(crate) fn update_process(
             if refresh_kind.disk_usage() {
                 update_proc_disk_activity(p);
             }
+            if refresh_kind.open_files() {
+                update_fd_usage(p);
+            }
 
             let mut thread_info = mem::zeroed::<libc::proc_threadinfo>();
             let (user_time, system_time, thread_status) = if libc::proc_pidinfo(
@@ -733,13 +902,10 @@ pub(crate) fn update_process(
             if refresh_kind.cpu() || refresh_kind.memory() {
                 let task_info = get_task_info(pid);
 
+                p.thread_count = Some(task_info.pti_threadnum as _);
                 if refresh_kind.cpu() {
                     compute_cpu_usage(p, task_info, system_time, user_time, time_interval);
-                    p.accumulated_cpu_time = (task_info
-                        .pti_total_user
-                        .saturating_add(task_info.pti_total_system)
-                        as f64
-                        * timebase_to_ms) as u64;
+                    set_cpu_time(p, &task_info, timebase_to_ms);
                 }
                 if refresh_kind.memory() {
                     p.memory = task_info.pti_resident_size;
@@ -769,14 +935,22 @@ fn update_proc_disk_activity(p: &mut ProcessInner) {
 
         if retval < 0 {
             sysinfo_debug!("proc_pid_rusage failed: {:?}", retval);
+            let status = FieldStatus::from_error(&io::Error::last_os_error());
+            p.field_statuses.set(ProcessField::DiskUsage, status);
         } else {
             let pidrusage = pidrusage.assume_init();
             p.read_bytes = pidrusage.ri_diskio_bytesread;
             p.written_bytes = pidrusage.ri_diskio_byteswritten;
+            p.field_statuses
+                .set(ProcessField::DiskUsage, FieldStatus::Ok);
         }
     }
 }
 
+fn update_fd_usage(p: &mut ProcessInner) {
+    p.fd_usage = FdUsage::update(p.fd_usage, p.open_files(), p.open_files_limit(), None);
+}
+
 #[allow(clippy::uninit_vec)]
 pub(crate) fn get_proc_list() -> Option<Vec<Pid>> {
     unsafe {
@@ -5,7 +5,7 @@ use libc::{mach_timebase_info, mach_timebase_info_data_t};
 
 use libc::{
     host_processor_info, mach_port_t, munmap, natural_t, processor_cpu_load_info,
-    processor_cpu_load_info_t, sysconf, vm_page_size, PROCESSOR_CPU_LOAD_INFO, _SC_CLK_TCK,
+    processor_cpu_load_info_t, vm_page_size, PROCESSOR_CPU_LOAD_INFO,
 };
 use std::ptr::null_mut;
 use std::time::Instant;
@@ -68,17 +68,8 @@ impl SystemTimeInfo {
     #[allow(deprecated)] // Everything related to mach_timebase_info_data_t
     pub fn new(port: mach_port_t) -> Option<Self> {
         unsafe {
-            let clock_ticks_per_sec = sysconf(_SC_CLK_TCK);
-
-            // FIXME: Maybe check errno here? Problem is that if errno is not 0 before this call,
-            //        we will get an error which isn't related...
-            // if let Some(er) = std::io::Error::last_os_error().raw_os_error() {
-            //     if err != 0 {
-            //         println!("==> {:?}", er);
-            //         sysinfo_debug!("Failed to get _SC_CLK_TCK value, using old CPU tick measure system");
-            //         return None;
-            //     }
-            // }
+            // The CPU ticks are counted in units of `_SC_CLK_TCK`.
+            let clock_ticks_per_sec = crate::unix::utils::clock_ticks_per_second();
 
             let mut info = mach_timebase_info_data_t { numer: 0, denom: 0 };
             if mach_timebase_info(&mut info) != libc::KERN_SUCCESS {
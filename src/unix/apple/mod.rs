@@ -7,10 +7,10 @@ mod ffi;
 mod utils;
 
 cfg_if! {
-    if #[cfg(all(target_os = "macos", any(feature = "disk", feature = "system", feature = "component")))] {
+    if #[cfg(all(target_os = "macos", any(feature = "disk", feature = "system", feature = "component", feature = "battery")))] {
         pub(crate) mod macos;
         pub(crate) use self::macos as inner;
-    } else if #[cfg(all(target_os = "ios", any(feature = "system", feature = "component")))] {
+    } else if #[cfg(all(target_os = "ios", any(feature = "system", feature = "component", feature = "battery")))] {
         pub(crate) mod ios;
         pub(crate) use self::ios as inner;
     }
@@ -20,10 +20,12 @@ cfg_if! {
     if #[cfg(feature = "system")] {
         pub mod cpu;
         pub mod process;
+        pub mod process_watcher;
         pub mod system;
 
         pub(crate) use self::cpu::CpuInner;
         pub(crate) use self::process::ProcessInner;
+        pub(crate) use self::process_watcher::ProcessWatcherInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
@@ -37,12 +39,23 @@ cfg_if! {
     if #[cfg(feature = "component")] {
         pub mod component;
 
-        pub(crate) use self::component::{ComponentInner, ComponentsInner};
+        pub(crate) use self::component::{
+            ComponentInner, ComponentsInner, FanInner, FansInner, PowerSensorInner,
+            PowerSensorsInner,
+        };
+    }
+
+    if #[cfg(feature = "battery")] {
+        pub mod battery;
+
+        pub(crate) use self::battery::{BatteriesInner, BatteryInner};
     }
 
     if #[cfg(feature = "network")] {
+        pub mod connection;
         pub mod network;
 
+        pub(crate) use self::connection::get_connections;
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
     }
 
@@ -51,7 +64,8 @@ cfg_if! {
         pub mod users;
 
         pub(crate) use crate::unix::groups::get_groups;
-        pub(crate) use crate::unix::users::{get_users, UserInner};
+        pub(crate) use crate::unix::sessions::get_sessions;
+        pub(crate) use crate::unix::users::{get_user_by_id, get_users, UserInner};
     }
 }
 
@@ -60,8 +74,12 @@ pub const IS_SUPPORTED_SYSTEM: bool = true;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod battery;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
+mod connection;
+#[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
@@ -76,6 +94,8 @@ mod network;
 #[cfg(any())]
 mod process;
 #[cfg(any())]
+mod process_watcher;
+#[cfg(any())]
 mod system;
 #[cfg(any())]
 mod users;
@@ -6,11 +6,19 @@ use libc::{
 };
 
 use std::collections::{hash_map, HashMap};
+use std::io;
 use std::mem::{size_of, MaybeUninit};
+use std::net::IpAddr;
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
 use std::ptr::null_mut;
+use std::time::{Duration, Instant};
 
-use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::network::{extend_32_bit_counter, refresh_networks_addresses};
+use crate::network_helper::{get_dns_servers, interface_kind_from_type};
+use crate::{
+    InterfaceKind, IpNetwork, MacAddr, NetworkData, NetworksRefreshKind, RefreshError,
+    RefreshSubsystem, RouteInfo, WifiInfo,
+};
 
 // FIXME: To be removed once https://github.com/rust-lang/libc/pull/4022 is merged and released.
 #[repr(C)]
@@ -31,13 +39,297 @@ pub const IFMIB_IFDATA: c_int = 2;
 // FIXME: To be removed once https://github.com/rust-lang/libc/pull/4022 is merged and released.
 pub const NETLINK_GENERIC: c_int = 0;
 
+// Not provided by libc: `struct ifreq` using its `ifr_functional_type` field.
+#[repr(C)]
+struct ifreq_functional_type {
+    ifr_name: [c_char; IFNAMSIZ],
+    ifr_functional_type: u32,
+    _padding: [u8; 12],
+}
+// `_IOWR('i', 173, struct ifreq)` from `<sys/sockio.h>`.
+const SIOCGIFFUNCTIONALTYPE: libc::c_ulong = 0xc02069ad;
+const IFRTYPE_FUNCTIONAL_WIFI_INFRA: u32 = 3;
+const IFRTYPE_FUNCTIONAL_WIFI_AWDL: u32 = 4;
+const IFRTYPE_FUNCTIONAL_CELLULAR: u32 = 5;
+
+// Not provided by libc: `struct ifreq` using its `ifr_data` field.
+#[repr(C)]
+struct ifreq_data {
+    ifr_name: [c_char; IFNAMSIZ],
+    ifr_data: *mut libc::c_void,
+    _padding: [u8; 16 - size_of::<usize>()],
+}
+// Not provided by libc: `struct vlanreq` from `<net/if_vlan_var.h>`.
+#[repr(C)]
+struct vlanreq {
+    vlr_parent: [c_char; IFNAMSIZ],
+    vlr_tag: u16,
+}
+// `_IOWR('i', 127, struct ifreq)` from `<sys/sockio.h>`.
+const SIOCGIFVLAN: libc::c_ulong = 0xc020697f;
+// Not provided by libc: `struct if_bond_status` from `<net/if_bond_var.h>`.
+#[repr(C)]
+struct if_bond_status {
+    ibs_if_name: [c_char; IFNAMSIZ],
+    _ibs_port_priority: u16,
+    _ibs_state: u8,
+    _ibs_selected_state: u8,
+    _ibs_partner_state: [u8; 16],
+    _ibs_reserved: [u32; 8],
+}
+// Not provided by libc: `struct if_bond_status_req` from `<net/if_bond_var.h>`.
+#[repr(C)]
+struct if_bond_status_req {
+    ibsr_version: c_int,
+    ibsr_total: c_int,
+    ibsr_count: c_int,
+    ibsr_buffer: u64,
+    _ibsr_key: u16,
+    _ibsr_mode: u8,
+    _ibsr_reserved0: u8,
+    _ibsr_reserved: [u32; 3],
+}
+// Not provided by libc: `struct if_bond_req` from `<net/if_bond_var.h>` using its
+// `ibr_ibru.ibru_status` field.
+#[repr(C, packed)]
+struct if_bond_req {
+    ibr_op: u32,
+    ibr_status: if_bond_status_req,
+}
+const IF_BOND_OP_GET_STATUS: u32 = 3;
+const IF_BOND_STATUS_REQ_VERSION: c_int = 1;
+// `_IOWR('i', 71, struct ifreq)` from `<sys/sockio.h>`.
+const SIOCGIFBOND: libc::c_ulong = 0xc0206947;
+// From `<net/if_types.h>`.
+const IFT_L2VLAN: u8 = 0x87;
+const IFT_IEEE8023ADLAG: u8 = 0x88;
+
+/// Returns the socket used for the `ioctl` calls, creating it if needed.
+unsafe fn get_socket(socket: &mut Option<OwnedFd>) -> Option<c_int> {
+    if socket.is_none() {
+        let fd = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if fd < 0 {
+            sysinfo_debug!("Failed to create socket to get interface information");
+            return None;
+        }
+        *socket = Some(OwnedFd::from_raw_fd(fd));
+    }
+    socket.as_ref().map(|socket| socket.as_raw_fd())
+}
+
+fn set_ifr_name(ifr_name: &mut [c_char; IFNAMSIZ], name: &str) {
+    for (dest, src) in ifr_name.iter_mut().zip(name.as_bytes()) {
+        *dest = *src as _;
+    }
+}
+
+/// Wi-Fi and cellular interfaces are reported as ethernet by `ifi_type`, so we need to
+/// check their "functional type" to be able to distinguish them.
+unsafe fn get_interface_kind(
+    socket: &mut Option<OwnedFd>,
+    name: &str,
+    ifi_type: u8,
+    flags: u32,
+) -> InterfaceKind {
+    let kind = interface_kind_from_type(ifi_type, flags);
+    if kind != InterfaceKind::Ethernet || name.len() >= IFNAMSIZ {
+        return kind;
+    }
+    let Some(socket) = get_socket(socket) else {
+        return kind;
+    };
+    let mut req: ifreq_functional_type = std::mem::zeroed();
+    set_ifr_name(&mut req.ifr_name, name);
+    if libc::ioctl(socket, SIOCGIFFUNCTIONALTYPE, &mut req) < 0 {
+        return kind;
+    }
+    match req.ifr_functional_type {
+        IFRTYPE_FUNCTIONAL_WIFI_INFRA | IFRTYPE_FUNCTIONAL_WIFI_AWDL => InterfaceKind::Wifi,
+        IFRTYPE_FUNCTIONAL_CELLULAR => InterfaceKind::Cellular,
+        _ => kind,
+    }
+}
+
+/// Returns the parent interface and the tag of a VLAN interface.
+unsafe fn get_vlan_info(
+    socket: &mut Option<OwnedFd>,
+    name: &str,
+    ifi_type: u8,
+) -> (Option<String>, Option<u16>) {
+    if ifi_type != IFT_L2VLAN || name.len() >= IFNAMSIZ {
+        return (None, None);
+    }
+    let Some(socket) = get_socket(socket) else {
+        return (None, None);
+    };
+    let mut vlan: vlanreq = std::mem::zeroed();
+    let mut req: ifreq_data = std::mem::zeroed();
+    set_ifr_name(&mut req.ifr_name, name);
+    req.ifr_data = &mut vlan as *mut vlanreq as *mut _;
+    if libc::ioctl(socket, SIOCGIFVLAN, &mut req) < 0 {
+        return (None, None);
+    }
+    let parent = std::ffi::CStr::from_ptr(vlan.vlr_parent.as_ptr())
+        .to_str()
+        .ok()
+        .filter(|parent| !parent.is_empty())
+        .map(|parent| parent.to_owned());
+    // A VLAN interface which isn't configured yet has no parent.
+    let tag = parent.is_some().then_some(vlan.vlr_tag);
+    (parent, tag)
+}
+
+/// Returns the names of the interfaces aggregated by a bond interface.
+unsafe fn get_bond_members(socket: &mut Option<OwnedFd>, name: &str) -> Vec<String> {
+    if name.len() >= IFNAMSIZ {
+        return Vec::new();
+    }
+    let Some(socket) = get_socket(socket) else {
+        return Vec::new();
+    };
+    // The first call, with an empty buffer, only returns the number of members. Since they can
+    // change between the calls, we retry until the buffer is large enough.
+    let mut members: Vec<if_bond_status> = Vec::new();
+    loop {
+        let mut bond: if_bond_req = std::mem::zeroed();
+        bond.ibr_op = IF_BOND_OP_GET_STATUS;
+        bond.ibr_status.ibsr_version = IF_BOND_STATUS_REQ_VERSION;
+        bond.ibr_status.ibsr_count = members.capacity() as _;
+        bond.ibr_status.ibsr_buffer = members.as_mut_ptr() as u64;
+        let mut req: ifreq_data = std::mem::zeroed();
+        set_ifr_name(&mut req.ifr_name, name);
+        req.ifr_data = &mut bond as *mut if_bond_req as *mut _;
+        if libc::ioctl(socket, SIOCGIFBOND, &mut req) < 0 {
+            return Vec::new();
+        }
+        let total = bond.ibr_status.ibsr_total.max(0) as usize;
+        if total <= members.capacity() {
+            members.set_len(total);
+            break;
+        }
+        members.reserve_exact(total);
+    }
+    members
+        .iter()
+        .filter_map(|member| {
+            std::ffi::CStr::from_ptr(member.ibs_if_name.as_ptr())
+                .to_str()
+                .ok()
+                .map(|member| member.to_owned())
+        })
+        .collect()
+}
+
+#[cfg(target_os = "macos")]
+fn channel_to_frequency(channel: u32, band: objc2_core_wlan::CWChannelBand) -> Option<u32> {
+    use objc2_core_wlan::CWChannelBand;
+
+    match (band, channel) {
+        (CWChannelBand::Band2GHz, 14) => Some(2484),
+        (CWChannelBand::Band2GHz, 1..=13) => Some(2407 + channel * 5),
+        (CWChannelBand::Band5GHz, _) => Some(5000 + channel * 5),
+        (CWChannelBand::Band6GHz, _) => Some(5950 + channel * 5),
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn get_wifi_info(interface: &objc2_core_wlan::CWInterface) -> Option<WifiInfo> {
+    let signal = interface.rssiValue();
+    // `0` is returned if the interface isn't connected to a Wi-Fi network.
+    if signal == 0 {
+        return None;
+    }
+    let channel = interface.wlanChannel();
+    let number = channel
+        .as_ref()
+        .and_then(|channel| u32::try_from(channel.channelNumber()).ok());
+    Some(WifiInfo {
+        // Since macOS 14, the SSID and the BSSID are only returned if the application is allowed
+        // to use the location services.
+        ssid: interface.ssid().map(|ssid| ssid.to_string()),
+        bssid: interface
+            .bssid()
+            .and_then(|bssid| bssid.to_string().parse().ok()),
+        signal: Some(signal as _),
+        frequency: channel
+            .zip(number)
+            .and_then(|(channel, number)| channel_to_frequency(number, channel.channelBand())),
+        channel: number,
+    })
+}
+
+#[cfg(target_os = "macos")]
+fn refresh_wifi_info(interfaces: &mut HashMap<String, NetworkData>) {
+    use objc2_core_wlan::CWWiFiClient;
+    use objc2_foundation::NSString;
+
+    if interfaces
+        .values()
+        .all(|interface| interface.inner.kind != InterfaceKind::Wifi)
+    {
+        return;
+    }
+    objc2::rc::autoreleasepool(|_| unsafe {
+        let client = CWWiFiClient::sharedWiFiClient();
+        for (name, interface) in interfaces.iter_mut() {
+            let interface = &mut interface.inner;
+            if interface.kind != InterfaceKind::Wifi {
+                continue;
+            }
+            interface.wifi_info = client
+                .interfaceWithName(Some(&NSString::from_str(name)))
+                .and_then(|wifi| get_wifi_info(&wifi));
+        }
+    });
+}
+
+/// Returns the localized display names (like "Wi-Fi") of the interfaces, indexed by their
+/// BSD name (like `en0`).
+#[cfg(target_os = "macos")]
+fn get_display_names() -> HashMap<String, String> {
+    use objc2_core_foundation::{CFArray, CFRetained, CFString};
+    use std::ffi::c_void;
+    use std::ptr::NonNull;
+
+    #[link(name = "SystemConfiguration", kind = "framework")]
+    extern "C" {
+        fn SCNetworkInterfaceCopyAll() -> Option<NonNull<CFArray>>;
+        fn SCNetworkInterfaceGetBSDName(interface: *const c_void) -> *const CFString;
+        fn SCNetworkInterfaceGetLocalizedDisplayName(interface: *const c_void) -> *const CFString;
+    }
+
+    let mut display_names = HashMap::new();
+    unsafe {
+        // SAFETY: `SCNetworkInterfaceCopyAll` is a "copy" function, so the array has +1 retain
+        // count.
+        let Some(interfaces) = SCNetworkInterfaceCopyAll().map(|array| CFRetained::from_raw(array))
+        else {
+            return display_names;
+        };
+        for i in 0..interfaces.count() {
+            let interface = interfaces.value_at_index(i);
+            // The returned strings are owned by the interface.
+            let (Some(bsd_name), Some(display_name)) = (
+                SCNetworkInterfaceGetBSDName(interface).as_ref(),
+                SCNetworkInterfaceGetLocalizedDisplayName(interface).as_ref(),
+            ) else {
+                continue;
+            };
+            display_names.insert(bsd_name.to_string(), display_name.to_string());
+        }
+    }
+    display_names
+}
+
 #[inline]
 fn update_field(old_field: &mut u64, new_field: &mut u64, value: u64) {
     *old_field = *new_field;
     *new_field = value;
 }
 
-fn update_network_data(inner: &mut NetworkDataInner, data: &if_data64) {
+fn update_network_data(inner: &mut NetworkDataInner, mib_data: &ifmibdata) {
+    let data = &mib_data.ifmd_data;
     update_field(&mut inner.old_out, &mut inner.current_out, data.ifi_obytes);
     update_field(&mut inner.old_in, &mut inner.current_in, data.ifi_ibytes);
 
@@ -62,6 +354,141 @@ fn update_network_data(inner: &mut NetworkDataInner, data: &if_data64) {
         &mut inner.errors_out,
         data.ifi_oerrors,
     );
+
+    update_field(
+        &mut inner.old_drops_in,
+        &mut inner.drops_in,
+        data.ifi_iqdrops,
+    );
+    // Contrary to the other counters, this one is only 32-bit wide.
+    let drops_out = extend_32_bit_counter(inner.drops_out, mib_data.ifmd_snd_drops);
+    update_field(&mut inner.old_drops_out, &mut inner.drops_out, drops_out);
+    update_field(
+        &mut inner.old_collisions,
+        &mut inner.collisions,
+        data.ifi_collisions,
+    );
+    update_field(
+        &mut inner.old_multicast_in,
+        &mut inner.multicast_in,
+        data.ifi_imcasts,
+    );
+}
+
+/// Returns the IP address stored in a `sockaddr_in` or a `sockaddr_in6`.
+#[cfg(target_os = "macos")]
+fn sockaddr_ip(sa: &[u8]) -> Option<IpAddr> {
+    match *sa.get(1)? as c_int {
+        libc::AF_INET => {
+            let addr: [u8; 4] = sa.get(4..8)?.try_into().ok()?;
+            Some(IpAddr::from(addr))
+        }
+        libc::AF_INET6 => {
+            let mut addr: [u8; 16] = sa.get(8..24)?.try_into().ok()?;
+            // The kernel embeds the scope ID of link-local addresses in their second 16-bit word.
+            if addr[0] == 0xfe && addr[1] & 0xc0 == 0x80 {
+                addr[2] = 0;
+                addr[3] = 0;
+            }
+            Some(IpAddr::from(addr))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "macos")]
+unsafe fn get_default_routes() -> Vec<RouteInfo> {
+    const RTAX_MAX: usize = 8;
+    let mib = &mut [
+        CTL_NET,
+        PF_ROUTE,
+        0,
+        0,
+        libc::NET_RT_FLAGS,
+        libc::RTF_GATEWAY,
+    ];
+
+    let mut len = 0;
+    if sysctl(
+        mib.as_mut_ptr(),
+        mib.len() as _,
+        null_mut(),
+        &mut len,
+        null_mut(),
+        0,
+    ) < 0
+    {
+        sysinfo_debug!("Cannot get routing table size: sysctl failed");
+        return Vec::new();
+    }
+    let mut buf = vec![0u8; len];
+    if sysctl(
+        mib.as_mut_ptr(),
+        mib.len() as _,
+        buf.as_mut_ptr() as _,
+        &mut len,
+        null_mut(),
+        0,
+    ) < 0
+    {
+        sysinfo_debug!("Cannot get routing table: sysctl failed");
+        return Vec::new();
+    }
+    buf.truncate(len);
+
+    let mut routes = Vec::new();
+    let mut offset = 0;
+    while offset + size_of::<libc::rt_msghdr>() <= buf.len() {
+        let rtm = std::ptr::read_unaligned(buf.as_ptr().add(offset) as *const libc::rt_msghdr);
+        let msg_len = rtm.rtm_msglen as usize;
+        if msg_len == 0 || offset + msg_len > buf.len() {
+            break;
+        }
+        let msg = &buf[offset..offset + msg_len];
+        offset += msg_len;
+
+        // The header is followed by one `sockaddr` for each bit set in `rtm_addrs`, each of them
+        // padded to a multiple of 4 bytes.
+        let mut addrs: [Option<&[u8]>; RTAX_MAX] = [None; RTAX_MAX];
+        let mut pos = size_of::<libc::rt_msghdr>();
+        for (i, addr) in addrs.iter_mut().enumerate() {
+            if rtm.rtm_addrs & (1 << i) == 0 {
+                continue;
+            }
+            let Some(&sa_len) = msg.get(pos) else {
+                break;
+            };
+            *addr = msg.get(pos..pos + sa_len as usize);
+            pos += if sa_len == 0 {
+                4
+            } else {
+                1 + ((sa_len as usize - 1) | 3)
+            };
+        }
+
+        let is_default = addrs[libc::RTAX_DST as usize]
+            .and_then(sockaddr_ip)
+            .is_some_and(|dst| dst.is_unspecified())
+            && addrs[libc::RTAX_NETMASK as usize]
+                .map_or(true, |mask| mask.iter().skip(2).all(|b| *b == 0));
+        if !is_default {
+            continue;
+        }
+        let Some(gateway) = addrs[libc::RTAX_GATEWAY as usize].and_then(sockaddr_ip) else {
+            continue;
+        };
+        let mut name = [0u8; IFNAMSIZ];
+        if libc::if_indextoname(rtm.rtm_index as _, name.as_mut_ptr() as _).is_null() {
+            continue;
+        }
+        let len = name.iter().position(|c| *c == 0).unwrap_or(name.len());
+        routes.push(RouteInfo {
+            gateway,
+            interface: String::from_utf8_lossy(&name[..len]).into_owned(),
+            metric: 0,
+        });
+    }
+    routes
 }
 
 pub(crate) struct NetworksInner {
@@ -79,8 +506,69 @@ impl NetworksInner {
         &self.interfaces
     }
 
-    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
-        self.update_networks();
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) {
+        self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        let errors =
+            self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub(crate) fn refresh_list_filtered(&mut self, filter: &mut dyn FnMut(&str) -> bool) {
+        self.refresh_filtered(true, NetworksRefreshKind::everything(), filter);
+    }
+
+    pub(crate) fn refresh_interface(&mut self, name: &str) -> bool {
+        if let Some(interface) = self.interfaces.get_mut(name) {
+            interface.inner.updated = false;
+        }
+        self.update_networks(&mut |interface_name| interface_name == name);
+        self.interfaces
+            .get(name)
+            .is_some_and(|interface| interface.inner.updated)
+    }
+
+    pub(crate) fn refresh_interfaces(&mut self, names: &[&str]) -> usize {
+        for name in names {
+            if let Some(interface) = self.interfaces.get_mut(*name) {
+                interface.inner.updated = false;
+            }
+        }
+        self.update_networks(&mut |interface_name| names.contains(&interface_name));
+        names
+            .iter()
+            .filter(|name| {
+                self.interfaces
+                    .get(**name)
+                    .is_some_and(|interface| interface.inner.updated)
+            })
+            .count()
+    }
+
+    fn refresh_filtered(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+        filter: &mut dyn FnMut(&str) -> bool,
+    ) -> Vec<RefreshError> {
+        for interface in self.interfaces.values_mut() {
+            interface.inner.updated = false;
+        }
+        let errors = self.update_networks(filter);
         if remove_not_listed_interfaces {
             self.interfaces.retain(|_, i| {
                 if !i.inner.updated {
@@ -90,12 +578,41 @@ impl NetworksInner {
                 true
             });
         }
-        refresh_networks_addresses(&mut self.interfaces);
+        if refresh_kind.addresses() {
+            refresh_networks_addresses(&mut self.interfaces);
+        }
+        #[cfg(target_os = "macos")]
+        if refresh_kind.wifi_info() {
+            refresh_wifi_info(&mut self.interfaces);
+        }
+        errors
+    }
+
+    pub(crate) fn default_routes(&self) -> Vec<RouteInfo> {
+        #[cfg(target_os = "macos")]
+        unsafe {
+            get_default_routes()
+        }
+        #[cfg(not(target_os = "macos"))]
+        Vec::new()
+    }
+
+    pub(crate) fn dns_servers(&self) -> Vec<IpAddr> {
+        get_dns_servers()
     }
 
     #[allow(clippy::cast_ptr_alignment)]
     #[allow(clippy::uninit_vec)]
-    fn update_networks(&mut self) {
+    fn update_networks(&mut self, filter: &mut dyn FnMut(&str) -> bool) -> Vec<RefreshError> {
+        let mut errors = Vec::new();
+        let now = Instant::now();
+        let list_error = || {
+            RefreshError::new(
+                RefreshSubsystem::Networks,
+                "sysctl(NET_RT_IFLIST2)",
+                io::Error::last_os_error(),
+            )
+        };
         let mib = &mut [CTL_NET, PF_ROUTE, 0, 0, NET_RT_IFLIST2, 0];
         let mib2 = &mut [
             CTL_NET,
@@ -117,8 +634,8 @@ impl NetworksInner {
                 0,
             ) < 0
             {
-                // TODO: might be nice to put an error in here...
-                return;
+                errors.push(list_error());
+                return errors;
             }
             let mut buf = Vec::with_capacity(len);
             buf.set_len(len);
@@ -131,12 +648,19 @@ impl NetworksInner {
                 0,
             ) < 0
             {
-                // TODO: might be nice to put an error in here...
-                return;
+                errors.push(list_error());
+                return errors;
             }
             let buf = buf.as_ptr() as *const c_char;
             let lim = buf.add(len);
             let mut next = buf;
+            // Only created if we need to retrieve the kind of a new interface.
+            let mut socket = None;
+            // Only retrieved if there is a new interface.
+            #[cfg(target_os = "macos")]
+            let mut display_names = None;
+            // The bond interfaces of each of their members.
+            let mut masters = HashMap::new();
 
             while next < lim {
                 let ifm = next as *const libc::if_msghdr;
@@ -156,7 +680,18 @@ impl NetworksInner {
                     }
                     name.set_len(libc::strlen(pname));
                     let name = String::from_utf8_unchecked(name);
+                    let ifi_type = (*if2m).ifm_data.ifi_type;
+                    // Even if the bond interface is filtered out, its members can be refreshed.
+                    if ifi_type == IFT_IEEE8023ADLAG {
+                        for member in get_bond_members(&mut socket, &name) {
+                            masters.insert(member, name.clone());
+                        }
+                    }
+                    if !filter(&name) {
+                        continue;
+                    }
                     let mtu = (*if2m).ifm_data.ifi_mtu as u64;
+                    let flags = (*if2m).ifm_flags as u32;
 
                     // Because data size is capped at 32 bits with the previous sysctl call for some
                     // reasons, we need to make another sysctl call to get the actual values
@@ -174,6 +709,13 @@ impl NetworksInner {
                         null_mut(),
                         0,
                     );
+                    if ret < 0 {
+                        errors.push(RefreshError::new(
+                            RefreshSubsystem::Networks,
+                            format!("sysctl(IFDATA_GENERAL, {name})"),
+                            io::Error::last_os_error(),
+                        ));
+                    }
 
                     match self.interfaces.entry(name) {
                         hash_map::Entry::Occupied(mut e) => {
@@ -186,11 +728,16 @@ impl NetworksInner {
                                 );
                             } else {
                                 let data = mib_data.assume_init();
-                                update_network_data(interface, &data.ifmd_data);
+                                update_network_data(interface, &data);
                             }
                             if interface.mtu != mtu {
                                 interface.mtu = mtu
                             }
+                            interface.flags = flags;
+                            interface.index = (*if2m).ifm_index as _;
+                            interface.refresh_interval =
+                                Some(now.saturating_duration_since(interface.last_refresh));
+                            interface.last_refresh = now;
                             interface.updated = true;
                         }
                         hash_map::Entry::Vacant(e) => {
@@ -200,6 +747,10 @@ impl NetworksInner {
                             let packets_out;
                             let errors_in;
                             let errors_out;
+                            let drops_in;
+                            let drops_out;
+                            let collisions;
+                            let multicast_in;
 
                             if ret < 0 {
                                 sysinfo_debug!(
@@ -212,8 +763,13 @@ impl NetworksInner {
                                 packets_out = 0;
                                 errors_in = 0;
                                 errors_out = 0;
+                                drops_in = 0;
+                                drops_out = 0;
+                                collisions = 0;
+                                multicast_in = 0;
                             } else {
                                 let data = mib_data.assume_init();
+                                drops_out = data.ifmd_snd_drops as _;
                                 let data = data.ifmd_data;
 
                                 current_in = data.ifi_ibytes;
@@ -222,8 +778,21 @@ impl NetworksInner {
                                 packets_out = data.ifi_opackets;
                                 errors_in = data.ifi_ierrors;
                                 errors_out = data.ifi_oerrors;
+                                drops_in = data.ifi_iqdrops;
+                                collisions = data.ifi_collisions;
+                                multicast_in = data.ifi_imcasts;
                             }
 
+                            let kind = get_interface_kind(&mut socket, e.key(), ifi_type, flags);
+                            let (lower_device, vlan_id) =
+                                get_vlan_info(&mut socket, e.key(), ifi_type);
+                            #[cfg(target_os = "macos")]
+                            let friendly_name = display_names
+                                .get_or_insert_with(get_display_names)
+                                .get(e.key())
+                                .cloned();
+                            #[cfg(not(target_os = "macos"))]
+                            let friendly_name = None;
                             e.insert(NetworkData {
                                 inner: NetworkDataInner {
                                     current_in,
@@ -238,17 +807,42 @@ impl NetworksInner {
                                     old_errors_in: errors_in,
                                     errors_out,
                                     old_errors_out: errors_out,
+                                    drops_in,
+                                    old_drops_in: drops_in,
+                                    drops_out,
+                                    old_drops_out: drops_out,
+                                    collisions,
+                                    old_collisions: collisions,
+                                    multicast_in,
+                                    old_multicast_in: multicast_in,
+                                    last_refresh: now,
+                                    refresh_interval: None,
                                     updated: true,
                                     mac_addr: MacAddr::UNSPECIFIED,
                                     ip_networks: vec![],
                                     mtu,
+                                    flags,
+                                    kind,
+                                    master: None,
+                                    lower_device,
+                                    vlan_id,
+                                    index: (*if2m).ifm_index as _,
+                                    friendly_name,
+                                    wifi_info: None,
                                 },
                             });
                         }
                     }
                 }
             }
+            // An interface can be added to or removed from a bond at any time.
+            for (name, interface) in self.interfaces.iter_mut() {
+                if interface.inner.updated {
+                    interface.inner.master = masters.get(name).cloned();
+                }
+            }
         }
+        errors
     }
 }
 
@@ -266,6 +860,18 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    drops_in: u64,
+    old_drops_in: u64,
+    drops_out: u64,
+    old_drops_out: u64,
+    collisions: u64,
+    old_collisions: u64,
+    multicast_in: u64,
+    old_multicast_in: u64,
+    /// When the above data was last refreshed.
+    last_refresh: Instant,
+    /// Time elapsed between the last two refreshes.
+    refresh_interval: Option<Duration>,
     updated: bool,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
@@ -273,6 +879,15 @@ pub(crate) struct NetworkDataInner {
     pub(crate) ip_networks: Vec<IpNetwork>,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Interface flags (`IFF_*`).
+    flags: u32,
+    kind: InterfaceKind,
+    master: Option<String>,
+    lower_device: Option<String>,
+    vlan_id: Option<u16>,
+    index: u32,
+    friendly_name: Option<String>,
+    wifi_info: Option<WifiInfo>,
 }
 
 impl NetworkDataInner {
@@ -324,6 +939,38 @@ impl NetworkDataInner {
         self.errors_out
     }
 
+    pub(crate) fn dropped_packets_received(&self) -> u64 {
+        self.drops_in.saturating_sub(self.old_drops_in)
+    }
+
+    pub(crate) fn total_dropped_packets_received(&self) -> u64 {
+        self.drops_in
+    }
+
+    pub(crate) fn dropped_packets_transmitted(&self) -> u64 {
+        self.drops_out.saturating_sub(self.old_drops_out)
+    }
+
+    pub(crate) fn total_dropped_packets_transmitted(&self) -> u64 {
+        self.drops_out
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        self.collisions.saturating_sub(self.old_collisions)
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    pub(crate) fn multicast_received(&self) -> u64 {
+        self.multicast_in.saturating_sub(self.old_multicast_in)
+    }
+
+    pub(crate) fn total_multicast_received(&self) -> u64 {
+        self.multicast_in
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -335,4 +982,56 @@ impl NetworkDataInner {
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_interval
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.flags & libc::IFF_LOOPBACK as u32 != 0
+    }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.flags & libc::IFF_UP as u32 != 0
+    }
+
+    pub(crate) fn is_point_to_point(&self) -> bool {
+        self.flags & libc::IFF_POINTOPOINT as u32 != 0
+    }
+
+    pub(crate) fn supports_multicast(&self) -> bool {
+        self.flags & libc::IFF_MULTICAST as u32 != 0
+    }
+
+    pub(crate) fn supports_broadcast(&self) -> bool {
+        self.flags & libc::IFF_BROADCAST as u32 != 0
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn wifi_info(&self) -> Option<WifiInfo> {
+        self.wifi_info.clone()
+    }
+
+    pub(crate) fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub(crate) fn friendly_name(&self) -> Option<&str> {
+        self.friendly_name.as_deref()
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn lower_device(&self) -> Option<&str> {
+        self.lower_device.as_deref()
+    }
+
+    pub(crate) fn vlan_id(&self) -> Option<u16> {
+        self.vlan_id
+    }
 }
@@ -5,15 +5,20 @@ use crate::sys::cpu::*;
 use crate::sys::process::*;
 use crate::sys::utils::{get_sys_value, get_sys_value_by_name};
 
+use crate::common::rapl::RaplPackageCounters;
+use crate::common::system::derive_boot_id;
+use crate::common::virtualization::{cpuid_hypervisor, hypervisor_from_dmi};
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    Cpu, CpuRefreshKind, DomainKind, HardwareInfo, Hypervisor, LoadAvg, MemoryRefreshKind, Pid,
+    Pressure, PressureLevel, Process, ProcessRefreshKind, ProcessesToUpdate, RefreshError,
+    RefreshSubsystem, TpmVersion, Virtualization,
 };
 
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::ffi::CStr;
+use std::io;
 use std::mem;
 use std::time::Duration;
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
@@ -23,6 +28,7 @@ use libc::{
     c_int, c_void, host_statistics64, mach_port_t, sysconf, sysctl, timeval, vm_statistics64,
     _SC_PAGESIZE,
 };
+use objc2_core_foundation::CFLocale;
 
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
 declare_signals! {
@@ -86,6 +92,8 @@ pub(crate) struct SystemInner {
     #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
     clock_info: Option<crate::sys::macos::system::SystemTimeInfo>,
     cpus: CpusWrapper,
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    refresh_pool: crate::utils::RefreshPool,
 }
 
 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
@@ -148,12 +156,22 @@ impl SystemInner {
                 #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
                 clock_info: crate::sys::macos::system::SystemTimeInfo::new(port),
                 cpus: CpusWrapper::new(),
+                #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+                refresh_pool: crate::utils::RefreshPool::new(),
             }
         }
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
+        let _ = self.try_refresh_memory_specifics(refresh_kind);
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
         let mut mib = [libc::CTL_VM as _, libc::VM_SWAPUSAGE as _];
+        let mut result = Ok(());
 
         unsafe {
             if refresh_kind.swap() {
@@ -167,28 +185,47 @@ impl SystemInner {
                 ) {
                     self.swap_total = xs.xsu_total;
                     self.swap_free = xs.xsu_avail;
+                } else {
+                    result = Err(RefreshError::new(
+                        RefreshSubsystem::Memory,
+                        "sysctl(vm.swapusage)",
+                        io::Error::last_os_error(),
+                    ));
                 }
             }
             if refresh_kind.ram() {
                 mib[0] = libc::CTL_HW as _;
                 mib[1] = libc::HW_MEMSIZE as _;
                 // get ram info
-                if self.mem_total < 1 {
-                    get_sys_value(
+                if self.mem_total < 1
+                    && !get_sys_value(
                         mem::size_of::<u64>(),
                         &mut self.mem_total as *mut u64 as *mut c_void,
                         &mut mib,
-                    );
+                    )
+                {
+                    result = Err(RefreshError::new(
+                        RefreshSubsystem::Memory,
+                        "sysctl(hw.memsize)",
+                        io::Error::last_os_error(),
+                    ));
                 }
                 let mut count: u32 = libc::HOST_VM_INFO64_COUNT as _;
                 let mut stat = mem::zeroed::<vm_statistics64>();
-                if host_statistics64(
+                let ret = host_statistics64(
                     self.port,
                     libc::HOST_VM_INFO64,
                     &mut stat as *mut vm_statistics64 as *mut _,
                     &mut count,
-                ) == libc::KERN_SUCCESS
-                {
+                );
+                if ret != libc::KERN_SUCCESS {
+                    // It returns a `kern_return_t`, not an `errno` value.
+                    result = Err(RefreshError::new(
+                        RefreshSubsystem::Memory,
+                        "host_statistics64",
+                        io::Error::other(format!("kern_return_t {ret}")),
+                    ));
+                } else {
                     // From the apple documentation:
                     //
                     // /*
@@ -213,12 +250,17 @@ impl SystemInner {
                 }
             }
         }
+        result
     }
 
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
 
+    pub(crate) fn cgroup_cpu_quota(&self) -> Option<f64> {
+        None
+    }
+
     pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus.refresh(refresh_kind, self.port);
     }
@@ -228,6 +270,14 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind, self.port);
     }
 
+    #[cfg(any(target_os = "ios", feature = "apple-sandbox"))]
+    pub(crate) fn set_refresh_parallelism(&mut self, _threads: usize) {}
+
+    #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+    pub(crate) fn set_refresh_parallelism(&mut self, threads: usize) {
+        self.refresh_pool.set_threads(threads);
+    }
+
     #[cfg(any(target_os = "ios", feature = "apple-sandbox"))]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
@@ -286,8 +336,9 @@ impl SystemInner {
                 .as_ref()
                 .map(|c| c.timebase_to_ms)
                 .unwrap_or_default();
-            let entries: Vec<Process> = {
-                let wrap = &Wrap(UnsafeCell::new(&mut self.process_list));
+            let process_list = &mut self.process_list;
+            let entries: Vec<Process> = self.refresh_pool.install(|| {
+                let wrap = &Wrap(UnsafeCell::new(process_list));
 
                 #[cfg(feature = "multithread")]
                 use rayon::iter::ParallelIterator;
@@ -310,7 +361,7 @@ impl SystemInner {
                         .unwrap_or_default()
                     })
                     .collect()
-            };
+            });
             entries.into_iter().for_each(|entry| {
                 self.process_list.insert(entry.pid(), entry);
             });
@@ -344,6 +395,10 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -381,6 +436,33 @@ impl SystemInner {
         }
     }
 
+    #[allow(deprecated)] // Because of libc::mach_absolute_time and libc::mach_timebase_info.
+    pub(crate) fn active_time() -> u64 {
+        // Unlike `mach_continuous_time`, it doesn't count while the system is asleep.
+        let (time, info) = unsafe {
+            let mut info = libc::mach_timebase_info_data_t { numer: 0, denom: 0 };
+            if libc::mach_timebase_info(&mut info) != libc::KERN_SUCCESS || info.denom == 0 {
+                sysinfo_debug!("mach_timebase_info failed");
+                return 0;
+            }
+            (libc::mach_absolute_time(), info)
+        };
+        let nanos = time as u128 * info.numer as u128 / info.denom as u128;
+        // The uptime is computed from the system clock, which can be adjusted.
+        ((nanos / 1_000_000_000) as u64).min(Self::uptime())
+    }
+
+    #[allow(deprecated)] // Because of libc::mach_timebase_info.
+    pub(crate) fn clock_ticks_per_second() -> u64 {
+        let mut info = libc::mach_timebase_info_data_t { numer: 0, denom: 0 };
+        if unsafe { libc::mach_timebase_info(&mut info) } != libc::KERN_SUCCESS || info.numer == 0 {
+            sysinfo_debug!("mach_timebase_info failed");
+            return 1_000_000_000;
+        }
+        // A tick lasts `numer / denom` nanoseconds.
+        1_000_000_000 * info.denom as u64 / info.numer as u64
+    }
+
     pub(crate) fn load_average() -> LoadAvg {
         let mut loads = vec![0f64; 3];
 
@@ -394,10 +476,51 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn memory_pressure() -> Option<Pressure> {
+        let mut level: c_int = 0;
+        let mut len = mem::size_of::<c_int>();
+        unsafe {
+            if !get_sys_value_by_name(
+                b"kern.memorystatus_vm_pressure_level\0",
+                &mut len,
+                &mut level as *mut c_int as *mut c_void,
+            ) {
+                return None;
+            }
+        }
+        // The values are the `NOTE_MEMORYSTATUS_PRESSURE_*` flags.
+        let level = match level {
+            1 => PressureLevel::Normal,
+            2 => PressureLevel::Warning,
+            4 => PressureLevel::Critical,
+            _ => return None,
+        };
+        Some(Pressure {
+            level: Some(level),
+            ..Default::default()
+        })
+    }
+
+    pub(crate) fn cpu_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn io_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn rapl_counters() -> Option<Vec<RaplPackageCounters>> {
+        None
+    }
+
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
 
+    pub(crate) fn reboot_required() -> Option<bool> {
+        None
+    }
+
     pub(crate) fn name() -> Option<String> {
         get_system_info(libc::KERN_OSTYPE, Some("Darwin"))
     }
@@ -408,38 +531,12 @@ impl SystemInner {
             let Some(os_version) = Self::os_version() else {
                 return Some("macOS".to_owned());
             };
-            // https://en.wikipedia.org/wiki/MacOS_version_history
-            for (version_prefix, macos_spelling, friendly_name) in [
-                ("15", "macOS", "Sequoia"),
-                ("14", "macOS", "Sonoma"),
-                ("13", "macOS", "Ventura"),
-                ("12", "macOS", "Monterey"),
-                ("11", "macOS", "Big Sur"),
-                // Big Sur identifies itself as 10.16 in some situations.
-                // https://en.wikipedia.org/wiki/MacOS_Big_Sur#Development_history
-                ("10.16", "macOS", "Big Sur"),
-                ("10.15", "macOS", "Catalina"),
-                ("10.14", "macOS", "Mojave"),
-                ("10.13", "macOS", "High Sierra"),
-                ("10.12", "macOS", "Sierra"),
-                ("10.11", "OS X", "El Capitan"),
-                ("10.10", "OS X", "Yosemite"),
-                ("10.9", "OS X", "Mavericks"),
-                ("10.8", "OS X", "Mountain Lion"),
-                ("10.7", "Mac OS X", "Lion"),
-                ("10.6", "Mac OS X", "Snow Leopard"),
-                ("10.5", "Mac OS X", "Leopard"),
-                ("10.4", "Mac OS X", "Tiger"),
-                ("10.3", "Mac OS X", "Panther"),
-                ("10.2", "Mac OS X", "Jaguar"),
-                ("10.1", "Mac OS X", "Puma"),
-                ("10.0", "Mac OS X", "Cheetah"),
-            ] {
-                if os_version.starts_with(version_prefix) {
-                    return Some(format!("{macos_spelling} {os_version} {friendly_name}"));
+            match macos_release_name(&os_version) {
+                Some((macos_spelling, friendly_name)) => {
+                    Some(format!("{macos_spelling} {os_version} {friendly_name}"))
                 }
+                None => Some(format!("macOS {os_version}")),
             }
-            Some(format!("macOS {os_version}"))
         }
 
         #[cfg(target_os = "ios")]
@@ -457,39 +554,41 @@ impl SystemInner {
         get_system_info(libc::KERN_HOSTNAME, None)
     }
 
+    pub(crate) fn domain_name() -> Option<String> {
+        crate::unix::utils::get_domain_name(Self::host_name().as_deref())
+    }
+
+    pub(crate) fn domain_kind() -> Option<DomainKind> {
+        Self::domain_name().map(|_| DomainKind::Domain)
+    }
+
+    pub(crate) fn fqdn() -> Option<String> {
+        Self::host_name().and_then(|host_name| crate::unix::utils::get_fqdn(&host_name))
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         get_system_info(libc::KERN_OSRELEASE, None)
     }
 
     pub(crate) fn os_version() -> Option<String> {
-        unsafe {
-            // get the size for the buffer first
-            let mut size = 0;
-            if get_sys_value_by_name(b"kern.osproductversion\0", &mut size, std::ptr::null_mut())
-                && size > 0
-            {
-                // now create a buffer with the size and get the real value
-                let mut buf = vec![0_u8; size as _];
+        get_sys_value_str_by_name(b"kern.osproductversion\0")
+    }
 
-                if get_sys_value_by_name(
-                    b"kern.osproductversion\0",
-                    &mut size,
-                    buf.as_mut_ptr() as *mut c_void,
-                ) {
-                    if let Some(pos) = buf.iter().position(|x| *x == 0) {
-                        // Shrink buffer to terminate the null bytes
-                        buf.resize(pos, 0);
-                    }
+    pub(crate) fn os_build() -> Option<String> {
+        get_sys_value_str_by_name(b"kern.osversion\0")
+    }
 
-                    String::from_utf8(buf).ok()
-                } else {
-                    // getting the system value failed
-                    None
-                }
-            } else {
-                // getting the system value failed, or did not return a buffer size
-                None
-            }
+    pub(crate) fn os_display_version() -> Option<String> {
+        #[cfg(target_os = "macos")]
+        {
+            Self::os_version()
+                .and_then(|os_version| macos_release_name(&os_version))
+                .map(|(_, friendly_name)| friendly_name.to_owned())
+        }
+
+        #[cfg(target_os = "ios")]
+        {
+            None
         }
     }
 
@@ -506,6 +605,11 @@ impl SystemInner {
     }
 
     pub(crate) fn cpu_arch() -> Option<String> {
+        // `hw.machine` returns the architecture of the process when it runs with Rosetta 2,
+        // which only runs on arm64.
+        if Self::is_translated() == Some(true) {
+            return Some("arm64".to_owned());
+        }
         let mut arch_str: [u8; 32] = [0; 32];
         let mut mib = [libc::CTL_HW as _, libc::HW_MACHINE as _];
 
@@ -527,6 +631,25 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn kernel_arch() -> Option<String> {
+        crate::unix::utils::get_uname_machine()
+    }
+
+    pub(crate) fn is_translated() -> Option<bool> {
+        let mut translated: c_int = 0;
+        let mut len = mem::size_of::<c_int>();
+        // This sysctl doesn't exist on the systems without Rosetta 2, like the Intel Macs
+        // before macOS 11.
+        let exists = unsafe {
+            get_sys_value_by_name(
+                b"sysctl.proc_translated\0",
+                &mut len,
+                &mut translated as *mut _ as *mut c_void,
+            )
+        };
+        Some(exists && translated == 1)
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
@@ -542,6 +665,185 @@ impl SystemInner {
             Some(10_240)
         }
     }
+
+    pub(crate) fn open_files() -> Option<(u64, u64)> {
+        let read = |name: &[u8]| {
+            let mut value: c_int = 0;
+            let mut len = mem::size_of::<c_int>();
+            unsafe {
+                get_sys_value_by_name(name, &mut len, &mut value as *mut c_int as *mut c_void)
+            }
+            .then_some(value as u64)
+        };
+        Some((read(b"kern.num_files\0")?, read(b"kern.maxfiles\0")?))
+    }
+
+    pub(crate) fn max_pid() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn threads_total() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn hardware() -> HardwareInfo {
+        #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+        {
+            crate::sys::inner::hardware::get_hardware_info()
+        }
+        #[cfg(not(all(target_os = "macos", not(feature = "apple-sandbox"))))]
+        {
+            HardwareInfo::default()
+        }
+    }
+
+    pub(crate) fn process_exists(pid: Pid) -> bool {
+        // `kill` succeeds on zombie processes, so their state is checked as well.
+        #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+        {
+            crate::unix::utils::process_exists(pid) && unsafe { !is_zombie(pid) }
+        }
+        #[cfg(not(all(target_os = "macos", not(feature = "apple-sandbox"))))]
+        {
+            crate::unix::utils::process_exists(pid)
+        }
+    }
+
+    pub(crate) fn virtualization() -> Option<Virtualization> {
+        let hardware = crate::System::hardware();
+        let hypervisor =
+            hypervisor_from_dmi([hardware.vendor.as_deref(), hardware.product_name.as_deref()])
+                .or_else(cpuid_hypervisor)
+                .or_else(|| is_vmm_present().then(|| Hypervisor::Other(String::new())));
+        Some(hypervisor.map_or(Virtualization::BareMetal, Virtualization::VirtualMachine))
+    }
+
+    pub(crate) fn machine_id() -> Option<String> {
+        #[cfg(all(target_os = "macos", not(feature = "apple-sandbox")))]
+        {
+            crate::sys::inner::hardware::get_platform_uuid()
+        }
+        #[cfg(not(all(target_os = "macos", not(feature = "apple-sandbox"))))]
+        {
+            None
+        }
+    }
+
+    pub(crate) fn boot_id() -> Option<String> {
+        let machine_id = crate::System::machine_id()?;
+        Some(derive_boot_id(&machine_id, boot_time()))
+    }
+
+    pub(crate) fn kernel_cmdline() -> Option<String> {
+        // The `boot-args` NVRAM variable, as read by the kernel when it started.
+        get_sys_value_str_by_name(b"kern.bootargs\0").map(|args| args.trim().to_owned())
+    }
+
+    pub(crate) fn secure_boot() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn tpm_version() -> Option<TpmVersion> {
+        None
+    }
+
+    pub(crate) fn kernel_parameter(_name: &str) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone()
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        // The applications started from the Finder don't have the environment variables.
+        crate::unix::utils::get_locale_from_env().or_else(|| {
+            CFLocale::current()?
+                .identifier()
+                .map(|identifier| identifier.to_string())
+        })
+    }
+
+    pub(crate) fn time_since_boot_monotonic() -> Option<Duration> {
+        // It starts at boot and keeps counting while the system is asleep.
+        crate::unix::utils::get_clock_time(libc::CLOCK_MONOTONIC)
+    }
+}
+
+// It is also set on Apple Silicon, which doesn't have CPUID.
+fn is_vmm_present() -> bool {
+    let mut present: c_int = 0;
+    let mut len = mem::size_of::<c_int>();
+    let ok = unsafe {
+        get_sys_value_by_name(
+            b"kern.hv_vmm_present\0",
+            &mut len,
+            &mut present as *mut _ as *mut c_void,
+        )
+    };
+    ok && present != 0
+}
+
+/// Returns how the release is spelled and its name, like `("macOS", "Sequoia")`.
+///
+/// See <https://en.wikipedia.org/wiki/MacOS_version_history>.
+#[cfg(target_os = "macos")]
+fn macos_release_name(os_version: &str) -> Option<(&'static str, &'static str)> {
+    [
+        ("15", "macOS", "Sequoia"),
+        ("14", "macOS", "Sonoma"),
+        ("13", "macOS", "Ventura"),
+        ("12", "macOS", "Monterey"),
+        ("11", "macOS", "Big Sur"),
+        // Big Sur identifies itself as 10.16 in some situations.
+        // https://en.wikipedia.org/wiki/MacOS_Big_Sur#Development_history
+        ("10.16", "macOS", "Big Sur"),
+        ("10.15", "macOS", "Catalina"),
+        ("10.14", "macOS", "Mojave"),
+        ("10.13", "macOS", "High Sierra"),
+        ("10.12", "macOS", "Sierra"),
+        ("10.11", "OS X", "El Capitan"),
+        ("10.10", "OS X", "Yosemite"),
+        ("10.9", "OS X", "Mavericks"),
+        ("10.8", "OS X", "Mountain Lion"),
+        ("10.7", "Mac OS X", "Lion"),
+        ("10.6", "Mac OS X", "Snow Leopard"),
+        ("10.5", "Mac OS X", "Leopard"),
+        ("10.4", "Mac OS X", "Tiger"),
+        ("10.3", "Mac OS X", "Panther"),
+        ("10.2", "Mac OS X", "Jaguar"),
+        ("10.1", "Mac OS X", "Puma"),
+        ("10.0", "Mac OS X", "Cheetah"),
+    ]
+    .into_iter()
+    .find(|(version_prefix, _, _)| os_version.starts_with(version_prefix))
+    .map(|(_, macos_spelling, friendly_name)| (macos_spelling, friendly_name))
+}
+
+fn get_sys_value_str_by_name(name: &[u8]) -> Option<String> {
+    unsafe {
+        // get the size for the buffer first
+        let mut size = 0;
+        if get_sys_value_by_name(name, &mut size, std::ptr::null_mut()) && size > 0 {
+            // now create a buffer with the size and get the real value
+            let mut buf = vec![0_u8; size as _];
+
+            if get_sys_value_by_name(name, &mut size, buf.as_mut_ptr() as *mut c_void) {
+                if let Some(pos) = buf.iter().position(|x| *x == 0) {
+                    // Shrink buffer to terminate the null bytes
+                    buf.resize(pos, 0);
+                }
+
+                String::from_utf8(buf).ok()
+            } else {
+                // getting the system value failed
+                None
+            }
+        } else {
+            // getting the system value failed, or did not return a buffer size
+            None
+        }
+    }
 }
 
 fn get_system_info(value: c_int, default: Option<&str>) -> Option<String> {
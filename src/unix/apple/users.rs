@@ -1,5 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::unix::users::c_path;
 use crate::{
     common::{Gid, Uid},
     User, UserInner,
@@ -7,6 +8,23 @@ use crate::{
 
 use libc::{c_char, endpwent, getpwent, setpwent, strlen};
 use std::collections::HashMap;
+#[cfg(target_os = "macos")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Retrieves the last login of the user from the `utmpx` database.
+#[cfg(target_os = "macos")]
+pub(crate) fn get_last_login(c_user: *const c_char) -> Option<SystemTime> {
+    let mut last_login = std::mem::MaybeUninit::<super::ffi::lastlogx>::zeroed();
+    unsafe {
+        if super::ffi::getlastlogxbyname(c_user, last_login.as_mut_ptr()).is_null() {
+            return None;
+        }
+        match last_login.assume_init().ll_tv.tv_sec {
+            secs if secs > 0 => Some(UNIX_EPOCH + Duration::from_secs(secs as u64)),
+            _ => None,
+        }
+    }
+}
 
 fn endswith(s1: *const c_char, s2: &[u8]) -> bool {
     if s1.is_null() {
@@ -55,14 +73,21 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
 
                 let uid = (*pw).pw_uid;
                 let gid = (*pw).pw_gid;
-                users_map.insert(name, (Uid(uid), Gid(gid)));
+                let full_name = crate::unix::utils::cstr_to_rust((*pw).pw_gecos)
+                    .and_then(|gecos| crate::unix::users::parse_gecos(&gecos));
+                let home_directory = c_path((*pw).pw_dir);
+                let shell = c_path((*pw).pw_shell);
+                users_map.insert(name, (Uid(uid), Gid(gid), full_name, home_directory, shell));
             }
         }
         endpwent();
     }
-    for (name, (uid, gid)) in users_map {
+    for (name, (uid, gid, full_name, home_directory, shell)) in users_map {
         users.push(User {
-            inner: UserInner::new(uid, gid, name),
+            inner: UserInner::new(uid, gid, name, full_name, home_directory, shell),
+            groups: None,
+            home_directory: None,
+            shell: None,
         });
     }
 }
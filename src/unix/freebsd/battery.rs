@@ -0,0 +1,131 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use super::utils::get_sys_value_by_name;
+use crate::{Battery, BatteryState};
+
+// Bits of `hw.acpi.battery.state`.
+const ACPI_BATT_STAT_DISCHARG: libc::c_int = 0x1;
+const ACPI_BATT_STAT_CHARGING: libc::c_int = 0x2;
+const ACPI_BATT_STAT_NOT_PRESENT: libc::c_int = 0x7;
+
+// The ACPI sysctls combine all the batteries, so they are listed as this one.
+const BATTERY_NAME: &str = "ACPI battery";
+
+unsafe fn get_sysctl(name: &[u8]) -> Option<libc::c_int> {
+    let mut value: libc::c_int = 0;
+    // Unknown values are reported as `-1`.
+    (get_sys_value_by_name(name, &mut value) && value >= 0).then_some(value)
+}
+
+pub(crate) struct BatteryInner {
+    charge_percent: Option<f32>,
+    state: BatteryState,
+    power_watts: Option<f32>,
+    pub(crate) updated: bool,
+}
+
+impl BatteryInner {
+    pub(crate) fn name(&self) -> &str {
+        BATTERY_NAME
+    }
+
+    pub(crate) fn charge_percent(&self) -> Option<f32> {
+        self.charge_percent
+    }
+
+    pub(crate) fn state(&self) -> BatteryState {
+        self.state
+    }
+
+    pub(crate) fn cycle_count(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn design_capacity(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn full_charge_capacity(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn power_watts(&self) -> Option<f32> {
+        self.power_watts
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        if let Some(battery) = get_battery() {
+            *self = battery;
+        }
+    }
+}
+
+fn get_battery() -> Option<BatteryInner> {
+    unsafe {
+        if get_sysctl(b"hw.acpi.battery.units\0")? == 0 {
+            return None;
+        }
+        let state = get_sysctl(b"hw.acpi.battery.state\0")?;
+        if state == ACPI_BATT_STAT_NOT_PRESENT {
+            return None;
+        }
+        let charge_percent = get_sysctl(b"hw.acpi.battery.life\0").map(|life| life as f32);
+        let state = if state & ACPI_BATT_STAT_CHARGING != 0 {
+            BatteryState::Charging
+        } else if state & ACPI_BATT_STAT_DISCHARG != 0 {
+            BatteryState::Discharging
+        } else if charge_percent == Some(100.) {
+            BatteryState::Full
+        } else if get_sysctl(b"hw.acpi.acline\0") == Some(1) {
+            BatteryState::NotCharging
+        } else {
+            BatteryState::Unknown
+        };
+        Some(BatteryInner {
+            charge_percent,
+            state,
+            // It is in mW.
+            power_watts: get_sysctl(b"hw.acpi.battery.rate\0").map(|rate| rate as f32 / 1_000.),
+            updated: true,
+        })
+    }
+}
+
+pub(crate) struct BatteriesInner {
+    pub(crate) batteries: Vec<Battery>,
+}
+
+impl BatteriesInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            batteries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(batteries: Vec<Battery>) -> Self {
+        Self { batteries }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Battery> {
+        self.batteries
+    }
+
+    pub(crate) fn list(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Battery] {
+        &mut self.batteries
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        let Some(inner) = get_battery() else {
+            return;
+        };
+        if let Some(battery) = self.batteries.first_mut() {
+            battery.inner = inner;
+        } else {
+            self.batteries.push(Battery { inner });
+        }
+    }
+}
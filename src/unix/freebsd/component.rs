@@ -1,7 +1,7 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use super::utils::get_sys_value_by_name;
-use crate::Component;
+use crate::{Component, Fan, PowerSensor, PowerSensorKind};
 
 pub(crate) struct ComponentInner {
     id: Vec<u8>,
@@ -20,10 +20,30 @@ impl ComponentInner {
         Some(self.max)
     }
 
+    pub(crate) fn min(&self) -> Option<f32> {
+        None
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         None
     }
 
+    pub(crate) fn critical_hysteresis(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn alarm(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn device_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn id(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
@@ -84,28 +104,137 @@ impl ComponentsInner {
     }
 
     pub(crate) fn refresh(&mut self) {
-        if self.components.len() != self.nb_cpus {
-            for core in 0..self.nb_cpus {
-                unsafe {
-                    let id = format!("dev.cpu.{core}.temperature\0").as_bytes().to_vec();
-                    if let Some(temperature) = refresh_component(&id) {
-                        self.components.push(Component {
-                            inner: ComponentInner {
-                                id,
-                                label: format!("CPU {}", core + 1),
-                                temperature: Some(temperature),
-                                max: temperature,
-                                updated: true,
-                            },
-                        });
-                    }
-                }
+        self.refresh_filtered(&mut |_, _| true);
+    }
+
+    pub(crate) fn refresh_filtered(&mut self, filter: &mut dyn FnMut(&str, &str) -> bool) {
+        for core in 0..self.nb_cpus {
+            let label = format!("CPU {}", core + 1);
+            if !filter("", &label) {
+                continue;
             }
-        } else {
-            for c in self.components.iter_mut() {
+            let id = format!("dev.cpu.{core}.temperature\0").as_bytes().to_vec();
+            if let Some(c) = self.components.iter_mut().find(|c| c.inner.id == id) {
                 c.refresh();
                 c.inner.updated = true;
+            } else if let Some(temperature) = unsafe { refresh_component(&id) } {
+                self.components.push(Component {
+                    inner: ComponentInner {
+                        id,
+                        label,
+                        temperature: Some(temperature),
+                        max: temperature,
+                        updated: true,
+                    },
+                    observed_max: None,
+                });
             }
         }
     }
 }
+
+pub(crate) struct FanInner {
+    pub(crate) updated: bool,
+}
+
+impl FanInner {
+    pub(crate) fn rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn min_rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn max_rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct FansInner {
+    pub(crate) fans: Vec<Fan>,
+}
+
+impl FansInner {
+    pub(crate) fn new() -> Self {
+        Self { fans: Vec::new() }
+    }
+
+    pub(crate) fn from_vec(fans: Vec<Fan>) -> Self {
+        Self { fans }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Fan> {
+        self.fans
+    }
+
+    pub(crate) fn list(&self) -> &[Fan] {
+        &self.fans
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Fan] {
+        &mut self.fans
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // FIXME: there is no generic way to retrieve the fans speed on FreeBSD.
+    }
+}
+
+pub(crate) struct PowerSensorInner {
+    pub(crate) updated: bool,
+}
+
+impl PowerSensorInner {
+    pub(crate) fn kind(&self) -> PowerSensorKind {
+        PowerSensorKind::Power
+    }
+
+    pub(crate) fn value(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct PowerSensorsInner {
+    pub(crate) sensors: Vec<PowerSensor>,
+}
+
+impl PowerSensorsInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            sensors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(sensors: Vec<PowerSensor>) -> Self {
+        Self { sensors }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<PowerSensor> {
+        self.sensors
+    }
+
+    pub(crate) fn list(&self) -> &[PowerSensor] {
+        &self.sensors
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [PowerSensor] {
+        &mut self.sensors
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // FIXME: there is no generic way to retrieve the power sensors on FreeBSD.
+    }
+}
@@ -58,6 +58,7 @@ impl CpusWrapper {
                 }
                 self.cpus.push(Cpu {
                     inner: CpuInner::new(format!("cpu {pos}"), vendor_id.clone(), frequency),
+                    usage_history: Vec::new(),
                 });
             }
             self.got_cpu_frequency = refresh_kind.frequency();
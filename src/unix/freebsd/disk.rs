@@ -2,6 +2,7 @@
 
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
+use std::io;
 use std::marker::PhantomData;
 use std::os::unix::ffi::OsStringExt;
 use std::path::{Path, PathBuf};
@@ -15,7 +16,8 @@ use super::ffi::{
     geom_stats_snapshot_reset, DEVSTAT_READ, DEVSTAT_WRITE,
 };
 use super::utils::{c_buf_to_utf8_str, get_sys_value_str_by_name};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::common::disk::FillRate;
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, RefreshError, RefreshSubsystem};
 
 #[derive(Debug)]
 pub(crate) struct DiskInner {
@@ -25,6 +27,7 @@ pub(crate) struct DiskInner {
     mount_point: PathBuf,
     total_space: u64,
     available_space: u64,
+    fill_rate: FillRate,
     file_system: OsString,
     is_removable: bool,
     is_read_only: bool,
@@ -61,6 +64,10 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn fill_rate_bytes_per_sec(&self) -> Option<f64> {
+        self.fill_rate.get()
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -70,7 +77,7 @@ impl DiskInner {
     }
 
     pub(crate) fn refresh_specifics(&mut self, refresh_kind: DiskRefreshKind) -> bool {
-        refresh_disk(self, refresh_kind)
+        refresh_disk(self, refresh_kind, &mut Vec::new())
     }
 
     pub(crate) fn usage(&self) -> DiskUsage {
@@ -95,7 +102,28 @@ impl crate::DisksInner {
         remove_not_listed_disks: bool,
         refresh_kind: DiskRefreshKind,
     ) {
-        unsafe { get_all_list(&mut self.disks, remove_not_listed_disks, refresh_kind) }
+        let _ = self.try_refresh_specifics(remove_not_listed_disks, refresh_kind);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        let mut errors = Vec::new();
+        unsafe {
+            get_all_list(
+                &mut self.disks,
+                remove_not_listed_disks,
+                refresh_kind,
+                &mut errors,
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
     }
 
     pub(crate) fn list(&self) -> &[Disk] {
@@ -164,13 +192,13 @@ impl GetValues for DiskInner {
 unsafe fn get_statvfs(
     c_mount_point: &[libc::c_char],
     vfs: &mut libc::statvfs,
-) -> Option<(u64, u64, bool)> {
+) -> io::Result<(u64, u64, bool)> {
     if libc::statvfs(c_mount_point.as_ptr() as *const _, vfs as *mut _) < 0 {
         sysinfo_debug!("statvfs failed");
-        None
+        Err(io::Error::last_os_error())
     } else {
         let block_size: u64 = vfs.f_frsize as _;
-        Some((
+        Ok((
             vfs.f_blocks.saturating_mul(block_size),
             vfs.f_favail.saturating_mul(block_size),
             (vfs.f_flag & libc::ST_RDONLY) != 0,
@@ -178,16 +206,26 @@ unsafe fn get_statvfs(
     }
 }
 
-fn refresh_disk(disk: &mut DiskInner, refresh_kind: DiskRefreshKind) -> bool {
+fn refresh_disk(
+    disk: &mut DiskInner,
+    refresh_kind: DiskRefreshKind,
+    errors: &mut Vec<RefreshError>,
+) -> bool {
     if refresh_kind.storage() {
         unsafe {
             let mut vfs: libc::statvfs = std::mem::zeroed();
-            if let Some((total_space, available_space, is_read_only)) =
-                get_statvfs(&disk.c_mount_point, &mut vfs)
-            {
-                disk.total_space = total_space;
-                disk.available_space = available_space;
-                disk.is_read_only = is_read_only;
+            match get_statvfs(&disk.c_mount_point, &mut vfs) {
+                Ok((total_space, available_space, is_read_only)) => {
+                    disk.total_space = total_space;
+                    disk.available_space = available_space;
+                    disk.fill_rate.update(available_space);
+                    disk.is_read_only = is_read_only;
+                }
+                Err(error) => errors.push(RefreshError::new(
+                    RefreshSubsystem::Disks,
+                    format!("statvfs({})", disk.mount_point.display()),
+                    error,
+                )),
             }
         }
     }
@@ -317,12 +355,18 @@ pub unsafe fn get_all_list(
     container: &mut Vec<Disk>,
     remove_not_listed_disks: bool,
     refresh_kind: DiskRefreshKind,
+    errors: &mut Vec<RefreshError>,
 ) {
     let mut fs_infos: *mut libc::statfs = null_mut();
 
     let count = libc::getmntinfo(&mut fs_infos, libc::MNT_WAIT);
 
     if count < 1 {
+        errors.push(RefreshError::new(
+            RefreshSubsystem::Disks,
+            "getmntinfo",
+            io::Error::last_os_error(),
+        ));
         return;
     }
     let disk_mapping = get_disks_mapping();
@@ -384,7 +428,7 @@ pub unsafe fn get_all_list(
                     .all(|(a, b)| a == b)
         }) {
             // I/O usage is updated for all disks at once at the end.
-            refresh_disk(&mut disk.inner, refresh_kind.without_io_usage());
+            refresh_disk(&mut disk.inner, refresh_kind.without_io_usage(), errors);
             disk.inner.updated = true;
         } else {
             let dev_mount_point = c_buf_to_utf8_str(&fs_info.f_mntfromname).unwrap_or("");
@@ -404,6 +448,7 @@ pub unsafe fn get_all_list(
                 dev_id: disk_mapping.get(dev_mount_point).map(ToString::to_string),
                 total_space: 0,
                 available_space: 0,
+                fill_rate: FillRate::default(),
                 file_system: OsString::from_vec(fs_type),
                 is_removable,
                 is_read_only: false,
@@ -414,7 +459,7 @@ pub unsafe fn get_all_list(
                 updated: true,
             };
             // I/O usage is updated for all disks at once at the end.
-            refresh_disk(&mut disk, refresh_kind.without_io_usage());
+            refresh_disk(&mut disk, refresh_kind.without_io_usage(), errors);
             container.push(Disk { inner: disk });
         }
     }
@@ -6,10 +6,12 @@ cfg_if! {
     if #[cfg(feature = "system")] {
         pub mod cpu;
         pub mod process;
+        pub mod process_watcher;
         pub mod system;
 
         pub(crate) use self::cpu::CpuInner;
         pub(crate) use self::process::ProcessInner;
+        pub(crate) use self::process_watcher::ProcessWatcherInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
@@ -27,18 +29,30 @@ cfg_if! {
     if #[cfg(feature = "component")] {
         pub mod component;
 
-        pub(crate) use self::component::{ComponentInner, ComponentsInner};
+        pub(crate) use self::component::{
+            ComponentInner, ComponentsInner, FanInner, FansInner, PowerSensorInner,
+            PowerSensorsInner,
+        };
+    }
+
+    if #[cfg(feature = "battery")] {
+        pub mod battery;
+
+        pub(crate) use self::battery::{BatteriesInner, BatteryInner};
     }
 
     if #[cfg(feature = "network")] {
+        pub mod connection;
         pub mod network;
 
+        pub(crate) use self::connection::get_connections;
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
     }
 
     if #[cfg(feature = "user")] {
         pub(crate) use crate::unix::groups::get_groups;
-        pub(crate) use crate::unix::users::{get_users, UserInner};
+        pub(crate) use crate::unix::sessions::get_sessions;
+        pub(crate) use crate::unix::users::{get_user_by_id, get_users, UserInner};
     }
 }
 
@@ -47,8 +61,12 @@ pub const IS_SUPPORTED_SYSTEM: bool = true;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod battery;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
+mod connection;
+#[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
@@ -59,4 +77,6 @@ mod network;
 #[cfg(any())]
 mod process;
 #[cfg(any())]
+mod process_watcher;
+#[cfg(any())]
 mod system;
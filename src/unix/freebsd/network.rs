@@ -1,11 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use std::collections::{hash_map, HashMap};
+use std::io;
 use std::mem::MaybeUninit;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
 
 use super::utils;
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::network_helper::{get_dns_servers, interface_kind_from_type};
+use crate::{
+    InterfaceKind, IpNetwork, MacAddr, NetworkData, NetworksRefreshKind, RefreshError,
+    RefreshSubsystem, RouteInfo, WifiInfo,
+};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $data:expr) => {{
@@ -29,10 +36,58 @@ impl NetworksInner {
         &self.interfaces
     }
 
-    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) {
+        let _ = self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true)
+            .map_err(|error| vec![error])
+    }
+
+    pub(crate) fn refresh_list_filtered(&mut self, filter: &mut dyn FnMut(&str) -> bool) {
+        let _ = self.refresh_filtered(true, NetworksRefreshKind::everything(), filter);
+    }
+
+    pub(crate) fn refresh_interface(&mut self, name: &str) -> bool {
         unsafe {
-            self.refresh_interfaces(true);
+            let _ = self.update_interfaces(true, &mut |interface_name| interface_name == name);
         }
+        self.interfaces
+            .get(name)
+            .is_some_and(|interface| interface.inner.updated)
+    }
+
+    pub(crate) fn refresh_interfaces(&mut self, names: &[&str]) -> usize {
+        unsafe {
+            let _ =
+                self.update_interfaces(true, &mut |interface_name| names.contains(&interface_name));
+        }
+        names
+            .iter()
+            .filter(|name| {
+                self.interfaces
+                    .get(**name)
+                    .is_some_and(|interface| interface.inner.updated)
+            })
+            .count()
+    }
+
+    fn refresh_filtered(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+        filter: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<(), RefreshError> {
+        let result = unsafe { self.update_interfaces(true, filter) };
         if remove_not_listed_interfaces {
             // Remove interfaces which are gone.
             self.interfaces.retain(|_, i| {
@@ -43,10 +98,28 @@ impl NetworksInner {
                 true
             });
         }
-        refresh_networks_addresses(&mut self.interfaces);
+        if refresh_kind.addresses() {
+            refresh_networks_addresses(&mut self.interfaces);
+        }
+        result
+    }
+
+    pub(crate) fn default_routes(&self) -> Vec<RouteInfo> {
+        // FIXME: Requires parsing the routing socket messages, which `libc` doesn't describe on
+        // FreeBSD.
+        Vec::new()
     }
 
-    unsafe fn refresh_interfaces(&mut self, refresh_all: bool) {
+    pub(crate) fn dns_servers(&self) -> Vec<IpAddr> {
+        get_dns_servers()
+    }
+
+    unsafe fn update_interfaces(
+        &mut self,
+        refresh_all: bool,
+        filter: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<(), RefreshError> {
+        let now = Instant::now();
         let mut nb_interfaces: libc::c_int = 0;
         if !utils::get_sys_value(
             &[
@@ -58,7 +131,11 @@ impl NetworksInner {
             ],
             &mut nb_interfaces,
         ) {
-            return;
+            return Err(RefreshError::new(
+                RefreshSubsystem::Networks,
+                "sysctl(IFMIB_IFCOUNT)",
+                io::Error::last_os_error(),
+            ));
         }
         if refresh_all {
             // We don't need to update this value if we're not updating all interfaces.
@@ -81,6 +158,10 @@ impl NetworksInner {
                 continue;
             }
             if let Some(name) = utils::c_buf_to_utf8_string(&data.ifmd_name) {
+                if !filter(&name) {
+                    continue;
+                }
+                let flags = data.ifmd_flags as u32;
                 let data = &data.ifmd_data;
                 let mtu = data.ifi_mtu as u64;
                 match self.interfaces.entry(name) {
@@ -94,9 +175,18 @@ impl NetworksInner {
                         old_and_new!(interface, ifi_opackets, old_ifi_opackets, data);
                         old_and_new!(interface, ifi_ierrors, old_ifi_ierrors, data);
                         old_and_new!(interface, ifi_oerrors, old_ifi_oerrors, data);
+                        old_and_new!(interface, ifi_iqdrops, old_ifi_iqdrops, data);
+                        old_and_new!(interface, ifi_oqdrops, old_ifi_oqdrops, data);
+                        old_and_new!(interface, ifi_collisions, old_ifi_collisions, data);
+                        old_and_new!(interface, ifi_imcasts, old_ifi_imcasts, data);
                         if interface.mtu != mtu {
                             interface.mtu = mtu;
                         }
+                        interface.flags = flags;
+                        interface.index = row as u32;
+                        interface.refresh_interval =
+                            Some(now.saturating_duration_since(interface.last_refresh));
+                        interface.last_refresh = now;
                         interface.updated = true;
                     }
                     hash_map::Entry::Vacant(e) => {
@@ -118,16 +208,31 @@ impl NetworksInner {
                                 old_ifi_ierrors: 0,
                                 ifi_oerrors: data.ifi_oerrors,
                                 old_ifi_oerrors: 0,
+                                ifi_iqdrops: data.ifi_iqdrops,
+                                old_ifi_iqdrops: 0,
+                                ifi_oqdrops: data.ifi_oqdrops,
+                                old_ifi_oqdrops: 0,
+                                ifi_collisions: data.ifi_collisions,
+                                old_ifi_collisions: 0,
+                                ifi_imcasts: data.ifi_imcasts,
+                                old_ifi_imcasts: 0,
+                                last_refresh: now,
+                                refresh_interval: None,
                                 updated: true,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
                                 mtu,
+                                flags,
+                                kind: interface_kind_from_type(data.ifi_type, flags),
+                                // The rows of the interfaces MIB are indexed by interface index.
+                                index: row as u32,
                             },
                         });
                     }
                 }
             }
         }
+        Ok(())
     }
 }
 
@@ -152,6 +257,22 @@ pub(crate) struct NetworkDataInner {
     /// similar to `ifi_ierrors`
     ifi_oerrors: u64,
     old_ifi_oerrors: u64,
+    /// Total number of packets dropped on input.
+    ifi_iqdrops: u64,
+    old_ifi_iqdrops: u64,
+    /// Total number of packets dropped on output.
+    ifi_oqdrops: u64,
+    old_ifi_oqdrops: u64,
+    /// Total number of collisions on CSMA interfaces.
+    ifi_collisions: u64,
+    old_ifi_collisions: u64,
+    /// Total number of multicast packets received.
+    ifi_imcasts: u64,
+    old_ifi_imcasts: u64,
+    /// When the above data was last refreshed.
+    last_refresh: Instant,
+    /// Time elapsed between the last two refreshes.
+    refresh_interval: Option<Duration>,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
     /// MAC address
@@ -160,6 +281,10 @@ pub(crate) struct NetworkDataInner {
     pub(crate) ip_networks: Vec<IpNetwork>,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Interface flags (`IFF_*`).
+    flags: u32,
+    kind: InterfaceKind,
+    index: u32,
 }
 
 impl NetworkDataInner {
@@ -211,6 +336,38 @@ impl NetworkDataInner {
         self.ifi_oerrors
     }
 
+    pub(crate) fn dropped_packets_received(&self) -> u64 {
+        self.ifi_iqdrops.saturating_sub(self.old_ifi_iqdrops)
+    }
+
+    pub(crate) fn total_dropped_packets_received(&self) -> u64 {
+        self.ifi_iqdrops
+    }
+
+    pub(crate) fn dropped_packets_transmitted(&self) -> u64 {
+        self.ifi_oqdrops.saturating_sub(self.old_ifi_oqdrops)
+    }
+
+    pub(crate) fn total_dropped_packets_transmitted(&self) -> u64 {
+        self.ifi_oqdrops
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        self.ifi_collisions.saturating_sub(self.old_ifi_collisions)
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        self.ifi_collisions
+    }
+
+    pub(crate) fn multicast_received(&self) -> u64 {
+        self.ifi_imcasts.saturating_sub(self.old_ifi_imcasts)
+    }
+
+    pub(crate) fn total_multicast_received(&self) -> u64 {
+        self.ifi_imcasts
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -222,4 +379,58 @@ impl NetworkDataInner {
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_interval
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.flags & libc::IFF_LOOPBACK as u32 != 0
+    }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.flags & libc::IFF_UP as u32 != 0
+    }
+
+    pub(crate) fn is_point_to_point(&self) -> bool {
+        self.flags & libc::IFF_POINTOPOINT as u32 != 0
+    }
+
+    pub(crate) fn supports_multicast(&self) -> bool {
+        self.flags & libc::IFF_MULTICAST as u32 != 0
+    }
+
+    pub(crate) fn supports_broadcast(&self) -> bool {
+        self.flags & libc::IFF_BROADCAST as u32 != 0
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn wifi_info(&self) -> Option<WifiInfo> {
+        None
+    }
+
+    pub(crate) fn index(&self) -> u32 {
+        self.index
+    }
+
+    // FIXME: could be retrieved with the `SIOCGIFDESCR` ioctl.
+    pub(crate) fn friendly_name(&self) -> Option<&str> {
+        None
+    }
+
+    // FIXME: could be retrieved with the `SIOCGIFVLAN` ioctl and the `IFLA_MASTER` equivalent.
+    pub(crate) fn master(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn lower_device(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn vlan_id(&self) -> Option<u16> {
+        None
+    }
 }
@@ -1,11 +1,16 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, FdUsage, Gid, IoPriority, IoPriorityError, Namespaces, Pid, Process,
+    ProcessRefreshKind, ProcessStatus, Signal, Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::utils::{get_sys_value_str, get_sysctl_raw, WrapMap};
 
@@ -54,6 +59,7 @@ pub(crate) struct ProcessInner {
     pub(crate) updated: bool,
     cpu_usage: f32,
     start_time: u64,
+    start_time_precise: Duration,
     run_time: u64,
     pub(crate) status: ProcessStatus,
     user_id: Uid,
@@ -65,7 +71,13 @@ pub(crate) struct ProcessInner {
     written_bytes: u64,
     old_written_bytes: u64,
     accumulated_cpu_time: u64,
+    user_cpu_time: Duration,
+    system_cpu_time: Duration,
+    thread_count: Option<usize>,
+    fd_usage: Option<FdUsage>,
     exists: bool,
+    // Once the process was reaped, its exit status cannot be retrieved anymore.
+    exit_status: OnceLock<ExitStatus>,
 }
 
 impl ProcessInner {
@@ -122,6 +134,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_precise(&self) -> SystemTime {
+        UNIX_EPOCH + self.start_time_precise
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -134,6 +150,18 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn user_cpu_time(&self) -> Duration {
+        self.user_cpu_time
+    }
+
+    pub(crate) fn system_cpu_time(&self) -> Duration {
+        self.system_cpu_time
+    }
+
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -143,6 +171,10 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn field_status(&self, _field: crate::ProcessField) -> crate::FieldStatus {
+        crate::FieldStatus::Unsupported
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         Some(&self.user_id)
     }
@@ -159,8 +191,28 @@ impl ProcessInner {
         Some(self.effective_group_id)
     }
 
+    pub(crate) fn umask(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn ns_pid(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn io_priority(&self) -> Option<IoPriority> {
+        None
+    }
+
+    pub(crate) fn set_io_priority(&self, _priority: IoPriority) -> Result<(), IoPriorityError> {
+        Err(IoPriorityError::Unsupported)
+    }
+
     pub(crate) fn wait(&self) -> Option<ExitStatus> {
-        crate::unix::utils::wait_process(self.pid)
+        crate::unix::utils::wait_process(self.pid, &self.exit_status)
+    }
+
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        crate::unix::utils::process_exit_code(self.pid, &self.exit_status)
     }
 
     pub(crate) fn session_id(&self) -> Option<Pid> {
@@ -228,6 +280,23 @@ impl ProcessInner {
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         crate::System::open_files_limit()
     }
+
+    pub(crate) fn fd_usage(&self) -> Option<FdUsage> {
+        self.fd_usage
+    }
+
+    pub(crate) fn namespaces(&self) -> Option<Namespaces> {
+        None
+    }
+
+    fn update_fd_usage(&mut self) {
+        self.fd_usage = FdUsage::update(
+            self.fd_usage,
+            self.open_files(),
+            self.open_files_limit(),
+            None,
+        );
+    }
 }
 
 struct AllocatedPtr<T>(*mut T);
@@ -261,6 +330,11 @@ fn get_accumulated_cpu_time(kproc: &libc::kinfo_proc) -> u64 {
     kproc.ki_runtime / 1_000
 }
 
+#[inline]
+fn timeval_to_duration(time: &libc::timeval) -> Duration {
+    Duration::new(time.tv_sec.max(0) as _, 0) + Duration::from_micros(time.tv_usec.max(0) as _)
+}
+
 pub(crate) unsafe fn get_process_data(
     kproc: &libc::kinfo_proc,
     wrap: &WrapMap,
@@ -317,6 +391,7 @@ pub(crate) unsafe fn get_process_data(
                 proc_.memory = memory;
             }
             proc_.run_time = now.saturating_sub(proc_.start_time);
+            proc_.thread_count = Some(kproc.ki_numthreads as _);
 
             if refresh_kind.disk_usage() {
                 proc_.old_read_bytes = proc_.read_bytes;
@@ -326,6 +401,11 @@ pub(crate) unsafe fn get_process_data(
             }
             if refresh_kind.cpu() {
                 proc_.accumulated_cpu_time = get_accumulated_cpu_time(kproc);
+                proc_.user_cpu_time = timeval_to_duration(&kproc.ki_rusage.ru_utime);
+                proc_.system_cpu_time = timeval_to_duration(&kproc.ki_rusage.ru_stime);
+            }
+            if refresh_kind.open_files() {
+                proc_.update_fd_usage();
             }
 
             return Ok(None);
@@ -348,44 +428,60 @@ pub(crate) unsafe fn get_process_data(
     // .map(|s| s.into())
     // .unwrap_or_else(PathBuf::new);
 
-    Ok(Some(Process {
-        inner: ProcessInner {
-            pid: Pid(kproc.ki_pid),
-            parent,
-            user_id: Uid(kproc.ki_ruid),
-            effective_user_id: Uid(kproc.ki_uid),
-            group_id: Gid(kproc.ki_rgid),
-            effective_group_id: Gid(kproc.ki_svgid),
-            start_time,
-            run_time: now.saturating_sub(start_time),
-            cpu_usage,
-            virtual_memory,
-            memory,
-            // procstat_getfiles
-            cwd: None,
-            exe: None,
-            // kvm_getargv isn't thread-safe so we get it in the main thread.
-            name: OsString::new(),
-            // kvm_getargv isn't thread-safe so we get it in the main thread.
-            cmd: Vec::new(),
-            // kvm_getargv isn't thread-safe so we get it in the main thread.
-            root: None,
-            // kvm_getenvv isn't thread-safe so we get it in the main thread.
-            environ: Vec::new(),
-            status,
-            read_bytes: kproc.ki_rusage.ru_inblock as _,
-            old_read_bytes: 0,
-            written_bytes: kproc.ki_rusage.ru_oublock as _,
-            old_written_bytes: 0,
-            accumulated_cpu_time: if refresh_kind.cpu() {
-                get_accumulated_cpu_time(kproc)
-            } else {
-                0
-            },
-            updated: true,
-            exists: true,
+    let mut inner = ProcessInner {
+        pid: Pid(kproc.ki_pid),
+        parent,
+        user_id: Uid(kproc.ki_ruid),
+        effective_user_id: Uid(kproc.ki_uid),
+        group_id: Gid(kproc.ki_rgid),
+        effective_group_id: Gid(kproc.ki_svgid),
+        start_time,
+        start_time_precise: timeval_to_duration(&kproc.ki_start),
+        run_time: now.saturating_sub(start_time),
+        cpu_usage,
+        virtual_memory,
+        memory,
+        // procstat_getfiles
+        cwd: None,
+        exe: None,
+        // kvm_getargv isn't thread-safe so we get it in the main thread.
+        name: OsString::new(),
+        // kvm_getargv isn't thread-safe so we get it in the main thread.
+        cmd: Vec::new(),
+        // kvm_getargv isn't thread-safe so we get it in the main thread.
+        root: None,
+        // kvm_getenvv isn't thread-safe so we get it in the main thread.
+        environ: Vec::new(),
+        status,
+        read_bytes: kproc.ki_rusage.ru_inblock as _,
+        old_read_bytes: 0,
+        written_bytes: kproc.ki_rusage.ru_oublock as _,
+        old_written_bytes: 0,
+        accumulated_cpu_time: if refresh_kind.cpu() {
+            get_accumulated_cpu_time(kproc)
+        } else {
+            0
         },
-    }))
+        user_cpu_time: if refresh_kind.cpu() {
+            timeval_to_duration(&kproc.ki_rusage.ru_utime)
+        } else {
+            Duration::ZERO
+        },
+        system_cpu_time: if refresh_kind.cpu() {
+            timeval_to_duration(&kproc.ki_rusage.ru_stime)
+        } else {
+            Duration::ZERO
+        },
+        thread_count: Some(kproc.ki_numthreads as _),
+        fd_usage: None,
+        updated: true,
+        exists: true,
+        exit_status: OnceLock::new(),
+    };
+    if refresh_kind.open_files() {
+        inner.update_fd_usage();
+    }
+    Ok(Some(Process { inner }))
 }
 
 pub(crate) unsafe fn get_exe(
@@ -0,0 +1,13 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{ProcessEvent, WatchProcessesError};
+
+use std::sync::mpsc::Sender;
+
+pub(crate) struct ProcessWatcherInner;
+
+impl ProcessWatcherInner {
+    pub(crate) fn new(_sender: Sender<ProcessEvent>) -> Result<Self, WatchProcessesError> {
+        Err(WatchProcessesError::Unsupported)
+    }
+}
@@ -1,13 +1,18 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::rapl::RaplPackageCounters;
+use crate::common::system::derive_boot_id;
+use crate::common::virtualization::cpuid_hypervisor;
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessInner,
-    ProcessRefreshKind, ProcessesToUpdate,
+    ContainerKind, Cpu, CpuRefreshKind, DomainKind, HardwareInfo, Hypervisor, LoadAvg,
+    MemoryRefreshKind, Pid, Pressure, Process, ProcessInner, ProcessRefreshKind, ProcessesToUpdate,
+    RefreshError, RefreshSubsystem, TpmVersion, Virtualization,
 };
 
 use std::cell::UnsafeCell;
 use std::collections::HashMap;
 use std::ffi::CStr;
+use std::io;
 use std::mem::MaybeUninit;
 use std::path::{Path, PathBuf};
 use std::ptr::NonNull;
@@ -18,7 +23,7 @@ use crate::sys::cpu::{physical_core_count, CpusWrapper};
 use crate::sys::process::get_exe;
 use crate::sys::utils::{
     self, boot_time, c_buf_to_os_string, c_buf_to_utf8_string, from_cstr_array, get_sys_value,
-    get_sys_value_by_name, init_mib,
+    get_sys_value_by_name, get_sys_value_str_by_name, init_mib,
 };
 
 use libc::c_int;
@@ -89,24 +94,47 @@ impl SystemInner {
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
+        let _ = self.try_refresh_memory_specifics(refresh_kind);
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        let mut result = Ok(());
         if refresh_kind.ram() {
             if self.mem_total == 0 {
                 self.mem_total = self.system_info.get_total_memory();
             }
-            self.mem_used = self.system_info.get_used_memory();
-            self.mem_free = self.system_info.get_free_memory();
+            match self.system_info.get_used_memory() {
+                Ok(mem_used) => self.mem_used = mem_used,
+                Err(error) => result = Err(error),
+            }
+            match self.system_info.get_free_memory() {
+                Ok(mem_free) => self.mem_free = mem_free,
+                Err(error) => result = Err(error),
+            }
         }
         if refresh_kind.swap() {
-            let (swap_used, swap_total) = self.system_info.get_swap_info();
-            self.swap_total = swap_total;
-            self.swap_used = swap_used;
+            match self.system_info.get_swap_info() {
+                Ok((swap_used, swap_total)) => {
+                    self.swap_total = swap_total;
+                    self.swap_used = swap_used;
+                }
+                Err(error) => result = Err(error),
+            }
         }
+        result
     }
 
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
 
+    pub(crate) fn cgroup_cpu_quota(&self) -> Option<f64> {
+        None
+    }
+
     pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
         self.cpus.refresh(refresh_kind)
     }
@@ -116,6 +144,8 @@ impl SystemInner {
         self.cpus.refresh(refresh_kind);
     }
 
+    pub(crate) fn set_refresh_parallelism(&mut self, _threads: usize) {}
+
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         processes_to_update: ProcessesToUpdate<'_>,
@@ -148,6 +178,10 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -185,10 +219,25 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn active_time() -> u64 {
+        // The uptime is computed from the system clock, which can be adjusted.
+        crate::unix::utils::get_clock_time(libc::CLOCK_UPTIME)
+            .map_or(0, |time| time.as_secs())
+            .min(Self::uptime())
+    }
+
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
 
+    pub(crate) fn reboot_required() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn clock_ticks_per_second() -> u64 {
+        crate::unix::utils::clock_ticks_per_second()
+    }
+
     pub(crate) fn load_average() -> LoadAvg {
         let mut loads = vec![0f64; 3];
         unsafe {
@@ -201,6 +250,22 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn memory_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn cpu_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn io_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn rapl_counters() -> Option<Vec<RaplPackageCounters>> {
+        None
+    }
+
     pub(crate) fn name() -> Option<String> {
         let mut os_type: [c_int; 2] = [0; 2];
         unsafe {
@@ -235,6 +300,18 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn domain_name() -> Option<String> {
+        crate::unix::utils::get_domain_name(Self::host_name().as_deref())
+    }
+
+    pub(crate) fn domain_kind() -> Option<DomainKind> {
+        Self::domain_name().map(|_| DomainKind::Domain)
+    }
+
+    pub(crate) fn fqdn() -> Option<String> {
+        Self::host_name().and_then(|host_name| crate::unix::utils::get_fqdn(&host_name))
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         unsafe {
             let mut kern_version: libc::c_int = 0;
@@ -246,6 +323,14 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn os_build() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn os_display_version() -> Option<String> {
+        None
+    }
+
     pub(crate) fn distribution_id() -> String {
         std::env::consts::OS.to_owned()
     }
@@ -276,6 +361,14 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn kernel_arch() -> Option<String> {
+        crate::unix::utils::get_uname_machine()
+    }
+
+    pub(crate) fn is_translated() -> Option<bool> {
+        None
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         physical_core_count()
     }
@@ -290,6 +383,132 @@ impl SystemInner {
             }
         }
     }
+
+    pub(crate) fn open_files() -> Option<(u64, u64)> {
+        let read = |name: &[u8]| {
+            let mut value: libc::c_int = 0;
+            unsafe { get_sys_value_by_name(name, &mut value) }.then_some(value as u64)
+        };
+        Some((read(b"kern.openfiles\0")?, read(b"kern.maxfiles\0")?))
+    }
+
+    pub(crate) fn max_pid() -> Option<u64> {
+        let mut value: libc::c_int = 0;
+        unsafe { get_sys_value_by_name(b"kern.pid_max\0", &mut value) }.then_some(value as u64)
+    }
+
+    pub(crate) fn threads_total() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn hardware() -> HardwareInfo {
+        HardwareInfo {
+            vendor: get_kenv(b"smbios.system.maker\0"),
+            product_name: get_kenv(b"smbios.system.product\0"),
+            board_name: get_kenv(b"smbios.planar.product\0"),
+            bios_version: get_kenv(b"smbios.bios.version\0"),
+            bios_date: get_kenv(b"smbios.bios.reldate\0"),
+            // Only known if the loader provides the numeric value and not the name of the type.
+            chassis_type: get_kenv(b"smbios.chassis.type\0")
+                .and_then(|chassis_type| chassis_type.parse().ok()),
+        }
+    }
+
+    pub(crate) fn process_exists(pid: Pid) -> bool {
+        if !crate::unix::utils::process_exists(pid) {
+            return false;
+        }
+        // `kill` succeeds on zombie processes, so their state is checked as well.
+        unsafe {
+            let mut kproc = std::mem::zeroed::<libc::kinfo_proc>();
+            !get_sys_value(
+                &[libc::CTL_KERN, libc::KERN_PROC, libc::KERN_PROC_PID, pid.0],
+                &mut kproc,
+            ) || kproc.ki_stat != libc::SZOMB
+        }
+    }
+
+    pub(crate) fn virtualization() -> Option<Virtualization> {
+        // The kernel already combines the SMBIOS information and the CPUID signature.
+        let host = match get_sys_value_str_by_name(b"kern.vm_guest\0")?.as_str() {
+            "none" => None,
+            "kvm" => Some(Hypervisor::KvmQemu),
+            "vmware" => Some(Hypervisor::VMware),
+            "hv" => Some(Hypervisor::HyperV),
+            "vbox" => Some(Hypervisor::VirtualBox),
+            "xen" => Some(Hypervisor::Xen),
+            "parallels" => Some(Hypervisor::Parallels),
+            "generic" => cpuid_hypervisor().or(Some(Hypervisor::Other(String::new()))),
+            name => Some(Hypervisor::Other(name.to_owned())),
+        };
+        let mut jailed: c_int = 0;
+        if unsafe { get_sys_value_by_name(b"security.jail.jailed\0", &mut jailed) } && jailed != 0 {
+            return Some(Virtualization::Container {
+                kind: ContainerKind::Other("jail".to_owned()),
+                host,
+            });
+        }
+        Some(host.map_or(Virtualization::BareMetal, Virtualization::VirtualMachine))
+    }
+
+    pub(crate) fn machine_id() -> Option<String> {
+        // Set from the SMBIOS UUID (or generated) by the `hostid` rc script.
+        get_sys_value_str_by_name(b"kern.hostuuid\0")
+            .filter(|id| !id.is_empty() && id.as_str() != "00000000-0000-0000-0000-000000000000")
+    }
+
+    pub(crate) fn boot_id() -> Option<String> {
+        let machine_id = crate::System::machine_id()?;
+        Some(derive_boot_id(&machine_id, boot_time()))
+    }
+
+    pub(crate) fn kernel_cmdline() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn secure_boot() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn tpm_version() -> Option<TpmVersion> {
+        None
+    }
+
+    pub(crate) fn kernel_parameter(_name: &str) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone()
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        crate::unix::utils::get_locale_from_env()
+    }
+
+    pub(crate) fn time_since_boot_monotonic() -> Option<Duration> {
+        // It starts at boot.
+        crate::unix::utils::get_clock_time(libc::CLOCK_MONOTONIC)
+    }
+}
+
+/// Reads the SMBIOS information set in the kernel environment by the loader.
+fn get_kenv(name: &[u8]) -> Option<String> {
+    let mut buf = [0 as libc::c_char; libc::KENV_MVALLEN as usize + 1];
+    unsafe {
+        if libc::kenv(
+            libc::KENV_GET,
+            name.as_ptr() as *const _,
+            buf.as_mut_ptr(),
+            buf.len() as _,
+        ) < 0
+        {
+            return None;
+        }
+    }
+    c_buf_to_utf8_string(&buf)
+        .map(|value| value.trim().to_owned())
+        .filter(|value| !value.is_empty())
 }
 
 impl SystemInner {
@@ -467,6 +686,20 @@ impl Zfs {
     }
 }
 
+/// Returns the value of the `mib` sysctl, whose name is `name`.
+unsafe fn get_memory_value(mib: &[c_int], name: &str) -> Result<u64, RefreshError> {
+    let mut value: u64 = 0;
+    if get_sys_value(mib, &mut value) {
+        Ok(value)
+    } else {
+        Err(RefreshError::new(
+            RefreshSubsystem::Memory,
+            format!("sysctl({name})"),
+            io::Error::last_os_error(),
+        ))
+    }
+}
+
 /// This struct is used to get system information more easily.
 #[derive(Debug)]
 struct SystemInfo {
@@ -551,30 +784,38 @@ impl SystemInfo {
     }
 
     /// Returns (used, total).
-    fn get_swap_info(&self) -> (u64, u64) {
+    fn get_swap_info(&self) -> Result<(u64, u64), RefreshError> {
         // Magic number used in htop. Cannot find how they got it when reading `kvm_getswapinfo`
         // source code so here we go...
         const LEN: usize = 16;
         let mut swap = MaybeUninit::<[libc::kvm_swap; LEN]>::uninit();
         unsafe {
             let nswap =
-                libc::kvm_getswapinfo(self.kd.as_ptr(), swap.as_mut_ptr() as *mut _, LEN as _, 0)
-                    as usize;
-            if nswap < 1 {
-                return (0, 0);
+                libc::kvm_getswapinfo(self.kd.as_ptr(), swap.as_mut_ptr() as *mut _, LEN as _, 0);
+            if nswap < 0 {
+                // The kvm functions don't set `errno`.
+                return Err(RefreshError::new(
+                    RefreshSubsystem::Memory,
+                    "kvm_getswapinfo",
+                    io::Error::other("cannot read the swap devices"),
+                ));
+            } else if nswap == 0 {
+                return Ok((0, 0));
             }
-            let swap =
-                std::slice::from_raw_parts(swap.as_ptr() as *mut libc::kvm_swap, nswap.min(LEN));
+            let swap = std::slice::from_raw_parts(
+                swap.as_ptr() as *mut libc::kvm_swap,
+                (nswap as usize).min(LEN),
+            );
             let (used, total) = swap.iter().fold((0, 0), |(used, total): (u64, u64), swap| {
                 (
                     used.saturating_add(swap.ksw_used as _),
                     total.saturating_add(swap.ksw_total as _),
                 )
             });
-            (
+            Ok((
                 used.saturating_mul(self.page_size as _),
                 total.saturating_mul(self.page_size as _),
-            )
+            ))
         }
     }
 
@@ -593,13 +834,11 @@ impl SystemInfo {
         }
     }
 
-    fn get_used_memory(&self) -> u64 {
-        let mut mem_active: u64 = 0;
-        let mut mem_wire: u64 = 0;
-
+    fn get_used_memory(&self) -> Result<u64, RefreshError> {
         unsafe {
-            get_sys_value(&self.virtual_active_count, &mut mem_active);
-            get_sys_value(&self.virtual_wire_count, &mut mem_wire);
+            let mem_active =
+                get_memory_value(&self.virtual_active_count, "vm.stats.vm.v_active_count")?;
+            let mem_wire = get_memory_value(&self.virtual_wire_count, "vm.stats.vm.v_wire_count")?;
 
             let mut mem_wire = mem_wire.saturating_mul(self.page_size as _);
             // We need to subtract "ZFS ARC" from the "wired memory" because it should belongs to cache
@@ -607,28 +846,25 @@ impl SystemInfo {
             if let Some(arc_size) = self.zfs.arc_size() {
                 mem_wire = mem_wire.saturating_sub(arc_size);
             }
-            mem_active
+            Ok(mem_active
                 .saturating_mul(self.page_size as _)
-                .saturating_add(mem_wire)
+                .saturating_add(mem_wire))
         }
     }
 
-    fn get_free_memory(&self) -> u64 {
-        let mut buffers_mem: u64 = 0;
-        let mut inactive_mem: u64 = 0;
-        let mut cached_mem: u64 = 0;
-        let mut free_mem: u64 = 0;
-
+    fn get_free_memory(&self) -> Result<u64, RefreshError> {
         unsafe {
-            get_sys_value(&self.buf_space, &mut buffers_mem);
-            get_sys_value(&self.virtual_inactive_count, &mut inactive_mem);
-            get_sys_value(&self.virtual_cache_count, &mut cached_mem);
-            get_sys_value(&self.virtual_free_count, &mut free_mem);
+            let buffers_mem = get_memory_value(&self.buf_space, "vfs.bufspace")?;
+            let inactive_mem =
+                get_memory_value(&self.virtual_inactive_count, "vm.stats.vm.v_inactive_count")?;
+            let cached_mem =
+                get_memory_value(&self.virtual_cache_count, "vm.stats.vm.v_cache_count")?;
+            let free_mem = get_memory_value(&self.virtual_free_count, "vm.stats.vm.v_free_count")?;
             // For whatever reason, buffers_mem is already the right value...
-            buffers_mem
+            Ok(buffers_mem
                 .saturating_add(inactive_mem.saturating_mul(self.page_size as _))
                 .saturating_add(cached_mem.saturating_mul(self.page_size as _))
-                .saturating_add(free_mem.saturating_mul(self.page_size as _))
+                .saturating_add(free_mem.saturating_mul(self.page_size as _)))
         }
     }
 
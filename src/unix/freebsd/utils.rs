@@ -143,7 +143,7 @@ pub(crate) unsafe fn get_sys_value_str(
     ))
 }
 
-#[cfg(any(feature = "system", feature = "component"))]
+#[cfg(any(feature = "system", feature = "component", feature = "battery"))]
 pub(crate) unsafe fn get_sys_value_by_name<T: Sized>(name: &[u8], value: &mut T) -> bool {
     let mut len = std::mem::size_of::<T>() as libc::size_t;
     let original = len;
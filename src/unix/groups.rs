@@ -4,8 +4,8 @@
 use crate::Group;
 
 impl crate::GroupInner {
-    pub(crate) fn new(id: crate::Gid, name: String) -> Self {
-        Self { id, name }
+    pub(crate) fn new(id: crate::Gid, name: String, members: Vec<String>) -> Self {
+        Self { id, name, members }
     }
 
     pub(crate) fn id(&self) -> &crate::Gid {
@@ -15,6 +15,10 @@ impl crate::GroupInner {
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
+
+    pub(crate) fn members(&self) -> &[String] {
+        &self.members
+    }
 }
 
 // Not used by mac.
@@ -39,10 +43,20 @@ pub(crate) fn get_groups(groups: &mut Vec<Group>) {
         let mut parts = line.split(':');
         if let Some(name) = parts.next() {
             let mut parts = parts.skip(1);
-            // Skip the user if the uid cannot be parsed correctly
+            // Skip the group if the gid cannot be parsed correctly
             if let Some(gid) = parts.next().and_then(parse_id) {
+                let members = parts
+                    .next()
+                    .map(|members| {
+                        members
+                            .split(',')
+                            .filter(|member| !member.is_empty())
+                            .map(str::to_owned)
+                            .collect()
+                    })
+                    .unwrap_or_default();
                 groups.push(Group {
-                    inner: GroupInner::new(Gid(gid), name.to_owned()),
+                    inner: GroupInner::new(Gid(gid), name.to_owned(), members),
                 });
             }
         }
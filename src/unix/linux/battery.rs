@@ -0,0 +1,271 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Information about the batteries, read from `/sys/class/power_supply`.
+//
+// The energy values are in µWh, the charge values in µAh, the voltages in µV, the currents in
+// µA and the power in µW.
+use crate::{Battery, BatteryState};
+
+use std::fs::{read_dir, read_to_string};
+use std::path::{Path, PathBuf};
+
+fn read_string(folder: &Path, file: &str) -> Option<String> {
+    read_to_string(folder.join(file))
+        .ok()
+        .map(|content| content.trim().to_owned())
+}
+
+fn read_value(folder: &Path, file: &str) -> Option<i64> {
+    read_string(folder, file)?.parse().ok()
+}
+
+/// Returns `true` if `folder` is a battery powering the machine. The batteries of the
+/// peripherals (like wireless mice) have a `Device` scope.
+fn is_system_battery(folder: &Path) -> bool {
+    read_string(folder, "type").as_deref() == Some("Battery")
+        && read_string(folder, "scope").as_deref() != Some("Device")
+}
+
+pub(crate) struct BatteryInner {
+    name: String,
+    folder: PathBuf,
+    charge_percent: Option<f32>,
+    state: BatteryState,
+    cycle_count: Option<u32>,
+    design_capacity: Option<f32>,
+    full_charge_capacity: Option<f32>,
+    power_watts: Option<f32>,
+    pub(crate) updated: bool,
+}
+
+impl BatteryInner {
+    fn new(name: String, folder: PathBuf) -> Self {
+        let mut battery = Self {
+            name,
+            folder,
+            charge_percent: None,
+            state: BatteryState::Unknown,
+            cycle_count: None,
+            design_capacity: None,
+            full_charge_capacity: None,
+            power_watts: None,
+            updated: true,
+        };
+        battery.refresh();
+        battery
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn charge_percent(&self) -> Option<f32> {
+        self.charge_percent
+    }
+
+    pub(crate) fn state(&self) -> BatteryState {
+        self.state
+    }
+
+    pub(crate) fn cycle_count(&self) -> Option<u32> {
+        self.cycle_count
+    }
+
+    pub(crate) fn design_capacity(&self) -> Option<f32> {
+        self.design_capacity
+    }
+
+    pub(crate) fn full_charge_capacity(&self) -> Option<f32> {
+        self.full_charge_capacity
+    }
+
+    pub(crate) fn power_watts(&self) -> Option<f32> {
+        self.power_watts
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        let folder = self.folder.as_path();
+        self.state = match read_string(folder, "status").as_deref() {
+            Some("Charging") => BatteryState::Charging,
+            Some("Discharging") => BatteryState::Discharging,
+            Some("Full") => BatteryState::Full,
+            Some("Not charging") => BatteryState::NotCharging,
+            _ => BatteryState::Unknown,
+        };
+        let voltage_now = read_value(folder, "voltage_now");
+        // Drivers only reporting the charge need the voltage to compute the energy.
+        let voltage = read_value(folder, "voltage_min_design").or(voltage_now);
+        let energy = |energy_file: &str, charge_file: &str| {
+            match read_value(folder, energy_file) {
+                Some(energy) => Some(energy as f64 / 1_000_000.),
+                None => Some(read_value(folder, charge_file)? as f64 * voltage? as f64 / 1e12),
+            }
+            .map(|energy| energy as f32)
+        };
+        self.design_capacity = energy("energy_full_design", "charge_full_design");
+        self.full_charge_capacity = energy("energy_full", "charge_full");
+        self.charge_percent = match read_value(folder, "capacity") {
+            Some(capacity) => Some(capacity as f32),
+            None => {
+                let now = energy("energy_now", "charge_now");
+                match (now, self.full_charge_capacity) {
+                    (Some(now), Some(full)) if full > 0. => Some((now * 100. / full).min(100.)),
+                    _ => None,
+                }
+            }
+        };
+        self.cycle_count = read_value(folder, "cycle_count").and_then(|c| u32::try_from(c).ok());
+        // Some drivers report negative values while discharging.
+        self.power_watts = match read_value(folder, "power_now") {
+            Some(power) => Some(power.unsigned_abs() as f64 / 1_000_000.),
+            None => read_value(folder, "current_now")
+                .zip(voltage_now)
+                .map(|(current, voltage)| current.unsigned_abs() as f64 * voltage as f64 / 1e12),
+        }
+        .map(|power| power as f32);
+    }
+}
+
+pub(crate) struct BatteriesInner {
+    pub(crate) batteries: Vec<Battery>,
+}
+
+impl BatteriesInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            batteries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(batteries: Vec<Battery>) -> Self {
+        Self { batteries }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Battery> {
+        self.batteries
+    }
+
+    pub(crate) fn list(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Battery] {
+        &mut self.batteries
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.refresh_from(Path::new("/sys/class/power_supply"));
+    }
+
+    fn refresh_from(&mut self, power_supply: &Path) {
+        let Ok(dir) = read_dir(power_supply) else {
+            return;
+        };
+        for entry in dir.flatten() {
+            let folder = entry.path();
+            if !is_system_battery(&folder) {
+                continue;
+            }
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            if let Some(battery) = self.batteries.iter_mut().find(|b| b.inner.name == name) {
+                battery.inner.folder = folder;
+                battery.refresh();
+                battery.inner.updated = true;
+            } else {
+                self.batteries.push(Battery {
+                    inner: BatteryInner::new(name, folder),
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BatteriesInner;
+    use crate::BatteryState;
+    use std::fs;
+
+    #[test]
+    fn check_batteries_from_power_supply() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        for (folder, files) in [
+            (
+                "BAT0",
+                &[
+                    ("type", "Battery\n"),
+                    ("status", "Discharging\n"),
+                    ("capacity", "80\n"),
+                    ("cycle_count", "42\n"),
+                    ("energy_full_design", "50000000\n"),
+                    ("energy_full", "45000000\n"),
+                    ("power_now", "7500000\n"),
+                ][..],
+            ),
+            (
+                "BAT1",
+                &[
+                    ("type", "Battery\n"),
+                    ("status", "Not charging\n"),
+                    ("voltage_min_design", "10000000\n"),
+                    ("voltage_now", "12000000\n"),
+                    ("charge_full_design", "4000000\n"),
+                    ("charge_full", "3000000\n"),
+                    ("charge_now", "1500000\n"),
+                    ("current_now", "-500000\n"),
+                ][..],
+            ),
+            ("AC", &[("type", "Mains\n"), ("online", "1\n")][..]),
+            (
+                "hidpp_battery_0",
+                &[
+                    ("type", "Battery\n"),
+                    ("scope", "Device\n"),
+                    ("capacity", "20\n"),
+                ][..],
+            ),
+        ] {
+            let folder = dir.path().join(folder);
+            fs::create_dir(&folder).expect("failed to create directory");
+            for (file, content) in files {
+                fs::write(folder.join(file), content).expect("failed to write file");
+            }
+        }
+
+        let mut batteries = BatteriesInner::new();
+        batteries.refresh_from(dir.path());
+        batteries.batteries.sort_by(|a, b| a.name().cmp(b.name()));
+        assert_eq!(batteries.batteries.len(), 2);
+
+        let battery = &batteries.batteries[0];
+        assert_eq!(battery.name(), "BAT0");
+        assert_eq!(battery.state(), BatteryState::Discharging);
+        assert_eq!(battery.charge_percent(), Some(80.));
+        assert_eq!(battery.cycle_count(), Some(42));
+        assert_eq!(battery.design_capacity(), Some(50.));
+        assert_eq!(battery.full_charge_capacity(), Some(45.));
+        assert_eq!(battery.power_watts(), Some(7.5));
+
+        let battery = &batteries.batteries[1];
+        assert_eq!(battery.name(), "BAT1");
+        assert_eq!(battery.state(), BatteryState::NotCharging);
+        assert_eq!(battery.charge_percent(), Some(50.));
+        assert_eq!(battery.cycle_count(), None);
+        assert_eq!(battery.design_capacity(), Some(40.));
+        assert_eq!(battery.full_charge_capacity(), Some(30.));
+        assert_eq!(battery.power_watts(), Some(6.));
+
+        // Existing batteries are updated instead of being added again.
+        fs::write(dir.path().join("BAT0/status"), "Charging\n").expect("failed to write file");
+        batteries.refresh_from(dir.path());
+        assert_eq!(batteries.batteries.len(), 2);
+        let battery = batteries
+            .batteries
+            .iter()
+            .find(|b| b.name() == "BAT0")
+            .expect("BAT0 not found");
+        assert_eq!(battery.state(), BatteryState::Charging);
+    }
+}
@@ -4,12 +4,15 @@
 //
 // Values in /sys/class/hwmonN are `c_long` or `c_ulong`
 // transposed to rust we only read `u32` or `i32` values.
-use crate::Component;
+use crate::sys::utils::FileCounter;
+use crate::{Component, Fan, PowerSensor, PowerSensorKind};
 
 use std::collections::HashMap;
 use std::fs::{read_dir, File};
 use std::io::Read;
+use std::os::unix::fs::FileExt;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 #[derive(Default)]
 pub(crate) struct ComponentInner {
@@ -37,15 +40,23 @@ pub(crate) struct ComponentInner {
     // /// - Read in:`temp[1-*]_max`
     // /// - Unit: read as millidegree Celsius converted to Celsius.
     // threshold_max: Option<f32>,
-    // /// Min threshold provided by the chip/kernel.
-    // /// - Read in:`temp[1-*]_min`
-    // /// - Unit: read as millidegree Celsius converted to Celsius.
-    // threshold_min: Option<f32>,
+    /// Min threshold provided by the chip/kernel.
+    /// - Read in:`temp[1-*]_min`
+    /// - Unit: read as millidegree Celsius converted to Celsius.
+    threshold_min: Option<f32>,
     /// Critical threshold provided by the chip/kernel previous user write.
     /// Read in `temp[1-*]_crit`:
     /// Typically greater than corresponding temp_max values.
     /// - Unit: read as millidegree Celsius converted to Celsius.
     threshold_critical: Option<f32>,
+    /// Hysteresis value of the critical threshold.
+    /// - Read in:`temp[1-*]_crit_hyst`
+    /// - Unit: read as millidegree Celsius converted to Celsius.
+    threshold_critical_hysteresis: Option<f32>,
+    /// Whether an alarm was raised by the chip/kernel.
+    /// - Read in: `temp[1-*]_alarm` and `temp[1-*]_crit_alarm`.
+    /// - Value: `0` or `1`.
+    alarm: Option<bool>,
     /// Sensor type, not common but can exist!
     ///
     /// Read in: `temp[1-*]_type` Sensor type selection.
@@ -88,8 +99,18 @@ pub(crate) struct ComponentInner {
     /// File to read current temperature shall be `temp[1-*]_input`
     /// It may be absent but we don't continue if absent.
     input_file: Option<PathBuf>,
+    /// `input_file` kept open so refreshing the temperature only needs one `pread` call. It is
+    /// counted in the open files limit (see `set_open_files_limit`) so, once it's reached, the
+    /// file is opened again on each refresh.
+    input: Option<FileCounter>,
     /// `temp[1-*]_highest file` to read if available highest value.
     highest_file: Option<PathBuf>,
+    /// `temp[1-*]_alarm` and `temp[1-*]_crit_alarm` files, read again on each refresh since the
+    /// alarm state changes at runtime.
+    alarm_files: Vec<PathBuf>,
+    /// NVIDIA GPU the temperature is read from, instead of `input_file`.
+    #[cfg(feature = "nvml")]
+    nvml_gpu: Option<crate::nvml::Gpu>,
     pub(crate) updated: bool,
 }
 
@@ -102,9 +123,13 @@ impl ComponentInner {
                     temperature,
                     max,
                     input_file,
+                    input,
                     highest_file,
+                    alarm,
+                    alarm_files,
                     ..
                 },
+            ..
         }: Component,
     ) {
         if let Some(temp) = temperature {
@@ -117,10 +142,15 @@ impl ComponentInner {
         }
         if input_file.is_some() && input_file != self.input_file {
             self.input_file = input_file;
+            self.input = input;
         }
         if highest_file.is_some() && highest_file != self.highest_file {
             self.highest_file = highest_file;
         }
+        if !alarm_files.is_empty() {
+            self.alarm = alarm;
+            self.alarm_files = alarm_files;
+        }
         self.updated = true;
     }
 }
@@ -137,12 +167,21 @@ fn get_file_line(file: &Path, capacity: usize) -> Option<String> {
 /// Designed at first for reading an `i32` or `u32` aka `c_long`
 /// from a `/sys/class/hwmon` sysfs file.
 fn read_number_from_file<N>(file: &Path) -> Option<N>
+where
+    N: std::str::FromStr,
+{
+    let f = File::open(file).ok()?;
+    read_number_from_opened_file(&f)
+}
+
+/// Reads a number from the start of `file`. Since sysfs files are generated again when read from
+/// their start, it can be called again on the same file to get the new value.
+fn read_number_from_opened_file<N>(file: &File) -> Option<N>
 where
     N: std::str::FromStr,
 {
     let mut reader = [0u8; 32];
-    let mut f = File::open(file).ok()?;
-    let n = f.read(&mut reader).ok()?;
+    let n = file.read_at(&mut reader, 0).ok()?;
     // parse and trim would complain about `\0`.
     let number = &reader[..n];
     let number = std::str::from_utf8(number).ok()?;
@@ -164,6 +203,16 @@ fn get_temperature_from_file(file: &Path) -> Option<f32> {
     convert_temp_celsius(temp)
 }
 
+/// Returns `true` if any of the alarm `files` is set, `None` if none of them could be read.
+fn get_alarm_from_files(files: &[PathBuf]) -> Option<bool> {
+    files
+        .iter()
+        .filter_map(|file| read_number_from_file::<u8>(file))
+        .fold(None, |alarm, value| {
+            Some(alarm.unwrap_or(false) || value != 0)
+        })
+}
+
 /// Takes a raw temperature in mili-celsius and convert it to celsius.
 #[inline]
 fn convert_temp_celsius(temp: Option<i32>) -> Option<f32> {
@@ -214,7 +263,10 @@ fn fill_component(component: &mut ComponentInner, item: &str, folder: &Path, fil
                 read_number_from_file::<u8>(&hwmon_file).map(ThermalSensorType::from)
         }
         "input" => {
-            let temperature = get_temperature_from_file(&hwmon_file);
+            let file = File::open(&hwmon_file).ok();
+            let temperature =
+                convert_temp_celsius(file.as_ref().and_then(read_number_from_opened_file));
+            component.input = file.and_then(FileCounter::new);
             component.input_file = Some(hwmon_file);
             component.temperature = temperature;
             // Maximum know try to get it from `highest` if not available
@@ -223,14 +275,20 @@ fn fill_component(component: &mut ComponentInner, item: &str, folder: &Path, fil
                 component.max = temperature;
             }
         }
-        "label" => component.label = get_file_line(&hwmon_file, 10).unwrap_or_default(),
         "highest" => {
             component.max = get_temperature_from_file(&hwmon_file).or(component.temperature);
             component.highest_file = Some(hwmon_file);
         }
         // "max" => component.threshold_max = get_temperature_from_file(&hwmon_file),
-        // "min" => component.threshold_min = get_temperature_from_file(&hwmon_file),
+        "min" => component.threshold_min = get_temperature_from_file(&hwmon_file),
         "crit" => component.threshold_critical = get_temperature_from_file(&hwmon_file),
+        "crit_hyst" => {
+            component.threshold_critical_hysteresis = get_temperature_from_file(&hwmon_file)
+        }
+        "alarm" | "crit_alarm" => {
+            component.alarm_files.push(hwmon_file);
+            component.alarm = get_alarm_from_files(&component.alarm_files);
+        }
         _ => {
             sysinfo_debug!(
                 "This hwmon-temp file is still not supported! Contributions are appreciated.;) {:?}",
@@ -258,7 +316,10 @@ impl ComponentInner {
     /// - Optional: `/device/model`
     /// - Optional: highest historic value in `tempN_highest`.
     /// - Optional: max threshold value defined in `tempN_max`
+    /// - Optional: min threshold value defined in `tempN_min`
     /// - Optional: critical threshold value defined in `tempN_crit`
+    /// - Optional: critical threshold hysteresis defined in `tempN_crit_hyst`
+    /// - Optional: alarms defined in `tempN_alarm` and `tempN_crit_alarm`
     ///
     /// Where `N` is a `u32` associated to a sensor like `temp1_max`, `temp1_input`.
     ///
@@ -267,9 +328,15 @@ impl ComponentInner {
     /// Kernel hwmon API: https://www.kernel.org/doc/html/latest/hwmon/hwmon-kernel-api.html
     /// DriveTemp kernel API: https://docs.kernel.org/gpu/amdgpu/thermal.html#hwmon-interfaces
     /// Amdgpu hwmon interface: https://www.kernel.org/doc/html/latest/hwmon/drivetemp.html
-    fn from_hwmon(components: &mut Vec<Component>, folder: &Path) -> Option<()> {
+    fn from_hwmon(
+        components: &mut Vec<Component>,
+        folder: &Path,
+        filter: &mut dyn FnMut(&str, &str) -> bool,
+    ) -> Option<()> {
         let dir = read_dir(folder).ok()?;
-        let mut matchings: HashMap<u32, Component> = HashMap::with_capacity(10);
+        // Only the file names are retrieved at first, so the files of the sensors which are
+        // filtered out are never opened.
+        let mut matchings: HashMap<u32, Vec<(String, String)>> = HashMap::with_capacity(10);
         for entry in dir.flatten() {
             if !entry.file_type().is_ok_and(|file_type| !file_type.is_dir()) {
                 continue;
@@ -284,29 +351,60 @@ impl ComponentInner {
             else {
                 continue;
             };
+            matchings
+                .entry(id)
+                .or_default()
+                .push((item.to_owned(), filename.to_owned()));
+        }
+        if matchings.is_empty() {
+            return Some(());
+        }
+        let name = get_file_line(&folder.join("name"), 16).unwrap_or_default();
+        let device_model = get_file_line(&folder.join("device/model"), 16);
+        let is_gpu = GPU_DRIVERS.contains(&name.as_str());
+        let card = if is_gpu { get_drm_card(folder) } else { None };
 
-            let component = matchings.entry(id).or_insert_with(|| Component {
-                inner: ComponentInner::default(),
-            });
-            let component = &mut component.inner;
-            let name = get_file_line(&folder.join("name"), 16);
-            component.name = name.unwrap_or_default();
-            let device_model = get_file_line(&folder.join("device/model"), 16);
-            component.device_model = device_model;
-            fill_component(component, item, folder, filename);
-        }
-        for (id, mut new_comp) in matchings
+        for (id, files) in matchings
             .into_iter()
             // Remove components without `tempN_input` file termal. `Component` doesn't support this
             // kind of sensors yet
-            .filter(|(_, c)| c.inner.input_file.is_some())
+            .filter(|(_, files)| files.iter().any(|(item, _)| item == "input"))
         {
-            if new_comp.inner.label.is_empty() {
+            let mut new_comp = Component {
+                inner: ComponentInner {
+                    name: name.clone(),
+                    device_model: device_model.clone(),
+                    label: files
+                        .iter()
+                        .find(|(item, _)| item == "label")
+                        .and_then(|(_, file)| get_file_line(&folder.join(file), 10))
+                        .unwrap_or_default(),
+                    ..Default::default()
+                },
+                observed_max: None,
+            };
+            if is_gpu {
+                new_comp.inner.label =
+                    format_gpu_label(&name, card.as_deref(), &new_comp.inner.label, id);
+            } else if new_comp.inner.label.is_empty() {
                 // sysinfo expose a generic interface with a `label`.
                 // Problem: a lot of sensors don't have a label or a device model! ¯\_(ツ)_/¯
                 // So let's pretend we have a unique label!
                 // See the table in `Component::label` documentation for the table detail.
-                new_comp.inner.label = new_comp.inner.format_label("temp", id);
+                let ComponentInner {
+                    name,
+                    label,
+                    device_model,
+                    ..
+                } = &new_comp.inner;
+                new_comp.inner.label =
+                    format_label(name, label, device_model.as_deref(), "temp", id);
+            }
+            if !filter(&new_comp.inner.name, &new_comp.inner.label) {
+                continue;
+            }
+            for (item, file) in files.iter().filter(|(item, _)| item != "label") {
+                fill_component(&mut new_comp.inner, item, folder, file);
             }
 
             if let Some(comp) = components
@@ -323,26 +421,6 @@ impl ComponentInner {
         Some(())
     }
 
-    /// Compute a label out of available information.
-    /// See the table in `Component::label`'s documentation.
-    fn format_label(&self, class: &str, id: u32) -> String {
-        let ComponentInner {
-            device_model,
-            name,
-            label,
-            ..
-        } = self;
-        let has_label = !label.is_empty();
-        match (has_label, device_model) {
-            (true, Some(device_model)) => {
-                format!("{name} {label} {device_model} {class}{id}")
-            }
-            (true, None) => format!("{name} {label}"),
-            (false, Some(device_model)) => format!("{name} {device_model}"),
-            (false, None) => format!("{name} {class}{id}"),
-        }
-    }
-
     pub(crate) fn temperature(&self) -> Option<f32> {
         self.temperature
     }
@@ -351,19 +429,63 @@ impl ComponentInner {
         self.max
     }
 
+    pub(crate) fn min(&self) -> Option<f32> {
+        self.threshold_min
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         self.threshold_critical
     }
 
+    pub(crate) fn critical_hysteresis(&self) -> Option<f32> {
+        self.threshold_critical_hysteresis
+    }
+
+    pub(crate) fn alarm(&self) -> Option<bool> {
+        self.alarm
+    }
+
+    pub(crate) fn device_name(&self) -> Option<&str> {
+        (!self.name.is_empty()).then_some(self.name.as_str())
+    }
+
+    pub(crate) fn id(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
-    pub(crate) fn refresh(&mut self) {
-        let current = self
-            .input_file
+    /// Reads the current temperature from the opened `input` file, opening it again if it fails
+    /// in case the file was replaced.
+    fn read_input(&mut self) -> Option<f32> {
+        if let Some(temperature) = self
+            .input
             .as_ref()
-            .and_then(|file| get_temperature_from_file(file.as_path()));
+            .and_then(|file| convert_temp_celsius(read_number_from_opened_file(file)))
+        {
+            return Some(temperature);
+        }
+        let file = File::open(self.input_file.as_ref()?).ok()?;
+        let temperature = convert_temp_celsius(read_number_from_opened_file(&file));
+        // Dropped first to not count the previous file against the open files limit.
+        self.input = None;
+        self.input = FileCounter::new(file);
+        temperature
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        #[cfg(feature = "nvml")]
+        if let Some(ref gpu) = self.nvml_gpu {
+            self.temperature = gpu.temperature();
+            self.max = match (self.max, self.temperature) {
+                (Some(max), Some(temperature)) => Some(max.max(temperature)),
+                (max, temperature) => max.or(temperature),
+            };
+            return;
+        }
+        let current = self.read_input();
         // tries to read out kernel highest if not compute something from temperature.
         let max = self
             .highest_file
@@ -376,6 +498,57 @@ impl ComponentInner {
             });
         self.max = max;
         self.temperature = current;
+        if !self.alarm_files.is_empty() {
+            self.alarm = get_alarm_from_files(&self.alarm_files);
+        }
+    }
+}
+
+/// Compute a label out of available information.
+/// See the table in `Component::label`'s documentation.
+fn format_label(
+    name: &str,
+    label: &str,
+    device_model: Option<&str>,
+    class: &str,
+    id: u32,
+) -> String {
+    let has_label = !label.is_empty();
+    match (has_label, device_model) {
+        (true, Some(device_model)) => {
+            format!("{name} {label} {device_model} {class}{id}")
+        }
+        (true, None) => format!("{name} {label}"),
+        (false, Some(device_model)) => format!("{name} {device_model}"),
+        (false, None) => format!("{name} {class}{id}"),
+    }
+}
+
+/// `hwmon` chip names of the GPU drivers.
+const GPU_DRIVERS: &[&str] = &["amdgpu", "nouveau", "radeon"];
+
+/// Returns the DRM card (like `card0`) of the GPU providing the `hwmon` `folder`.
+fn get_drm_card(folder: &Path) -> Option<String> {
+    read_dir(folder.join("device/drm"))
+        .ok()?
+        .flatten()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .find(|name| {
+            name.strip_prefix("card")
+                .is_some_and(|nb| !nb.is_empty() && nb.bytes().all(|c| c.is_ascii_digit()))
+        })
+}
+
+/// Compute the label of a GPU sensor, like `GPU amdgpu card0 edge`.
+fn format_gpu_label(name: &str, card: Option<&str>, label: &str, id: u32) -> String {
+    let sensor = if label.is_empty() {
+        format!("temp{id}")
+    } else {
+        label.to_owned()
+    };
+    match card {
+        Some(card) => format!("GPU {name} {card} {sensor}"),
+        None => format!("GPU {name} {sensor}"),
     }
 }
 
@@ -426,8 +599,12 @@ impl ComponentsInner {
     }
 
     pub(crate) fn refresh(&mut self) {
+        self.refresh_filtered(&mut |_, _| true);
+    }
+
+    pub(crate) fn refresh_filtered(&mut self, filter: &mut dyn FnMut(&str, &str) -> bool) {
         read_temp_dir("/sys/class/hwmon", "hwmon", |path| {
-            ComponentInner::from_hwmon(&mut self.components, &path);
+            ComponentInner::from_hwmon(&mut self.components, &path, filter);
         });
         if self.components.is_empty() {
             // Normally should only be used by raspberry pi.
@@ -437,14 +614,716 @@ impl ComponentsInner {
                     let Some(name) = get_file_line(&path.join("type"), 16) else {
                         return;
                     };
+                    if !filter(&name, "") {
+                        return;
+                    }
                     let mut component = ComponentInner {
                         name,
                         ..Default::default()
                     };
                     fill_component(&mut component, "input", &path, "temp");
-                    self.components.push(Component { inner: component });
+                    self.components.push(Component {
+                        inner: component,
+                        observed_max: None,
+                    });
                 }
             });
         }
+        #[cfg(feature = "nvml")]
+        refresh_nvml_components(&mut self.components, filter);
+    }
+}
+
+/// The NVIDIA proprietary driver doesn't provide `hwmon` sensors, so the GPUs temperature is
+/// retrieved from NVML instead.
+#[cfg(feature = "nvml")]
+fn refresh_nvml_components(
+    components: &mut Vec<Component>,
+    filter: &mut dyn FnMut(&str, &str) -> bool,
+) {
+    const NAME: &str = "nvidia";
+
+    for gpu in crate::nvml::get_gpus() {
+        let label = format!("GPU {NAME}{} {}", gpu.index(), gpu.name());
+        if !filter(NAME, &label) {
+            continue;
+        }
+        if let Some(c) = components.iter_mut().find(|c| c.inner.label == label) {
+            c.refresh();
+            c.inner.updated = true;
+            continue;
+        }
+        let temperature = gpu.temperature();
+        components.push(Component {
+            inner: ComponentInner {
+                name: NAME.to_owned(),
+                label,
+                temperature,
+                max: temperature,
+                threshold_critical: gpu.critical(),
+                nvml_gpu: Some(gpu),
+                updated: true,
+                ..Default::default()
+            },
+            observed_max: None,
+        });
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct FanInner {
+    /// Fan label, read in `fan[1-*]_label` or computed like the `Component` ones.
+    label: String,
+    /// Fan current speed
+    /// - Read in: `fan[1-*]_input`.
+    /// - Unit: RPM.
+    rpm: Option<u32>,
+    /// Minimum speed provided by the chip/kernel.
+    /// - Read in: `fan[1-*]_min`.
+    /// - Unit: RPM.
+    min_rpm: Option<u32>,
+    /// Maximum speed provided by the chip/kernel.
+    /// - Read in: `fan[1-*]_max`.
+    /// - Unit: RPM.
+    max_rpm: Option<u32>,
+    /// File to read current speed shall be `fan[1-*]_input`.
+    input_file: Option<PathBuf>,
+    pub(crate) updated: bool,
+}
+
+impl FanInner {
+    /// Read out the `fanN_*` files of the `hwmon` `folder` and store them into `fans`.
+    ///
+    /// Like for the components, fans without a `fanN_input` file are ignored.
+    fn from_hwmon(fans: &mut Vec<Fan>, folder: &Path) -> Option<()> {
+        let dir = read_dir(folder).ok()?;
+        let name = get_file_line(&folder.join("name"), 16).unwrap_or_default();
+        let device_model = get_file_line(&folder.join("device/model"), 16);
+        let mut matchings: HashMap<u32, FanInner> = HashMap::with_capacity(4);
+        for entry in dir.flatten() {
+            if !entry.file_type().is_ok_and(|file_type| !file_type.is_dir()) {
+                continue;
+            }
+
+            let entry = entry.path();
+            let filename = entry.file_name().and_then(|x| x.to_str()).unwrap_or("");
+            let Some((id, item)) = filename
+                .strip_prefix("fan")
+                .and_then(|f| f.split_once('_'))
+                .and_then(|(id, item)| Some((id.parse::<u32>().ok()?, item)))
+            else {
+                continue;
+            };
+
+            let fan = matchings.entry(id).or_default();
+            match item {
+                "input" => {
+                    fan.rpm = read_number_from_file(&entry);
+                    fan.input_file = Some(entry);
+                }
+                "label" => fan.label = get_file_line(&entry, 10).unwrap_or_default(),
+                "min" => fan.min_rpm = read_number_from_file(&entry),
+                "max" => fan.max_rpm = read_number_from_file(&entry),
+                // Alarms, targets, dividers...
+                _ => {}
+            }
+        }
+        for (id, mut new_fan) in matchings
+            .into_iter()
+            .filter(|(_, fan)| fan.input_file.is_some())
+        {
+            let label = std::mem::take(&mut new_fan.label);
+            new_fan.label = format_label(&name, &label, device_model.as_deref(), "fan", id);
+
+            if let Some(fan) = fans.iter_mut().find(|fan| fan.inner.label == new_fan.label) {
+                fan.inner.rpm = new_fan.rpm;
+                fan.inner.min_rpm = new_fan.min_rpm;
+                fan.inner.max_rpm = new_fan.max_rpm;
+                fan.inner.input_file = new_fan.input_file;
+                fan.inner.updated = true;
+            } else {
+                new_fan.updated = true;
+                fans.push(Fan { inner: new_fan });
+            }
+        }
+
+        Some(())
+    }
+
+    pub(crate) fn rpm(&self) -> Option<u32> {
+        self.rpm
+    }
+
+    pub(crate) fn min_rpm(&self) -> Option<u32> {
+        self.min_rpm
+    }
+
+    pub(crate) fn max_rpm(&self) -> Option<u32> {
+        self.max_rpm
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.rpm = self
+            .input_file
+            .as_ref()
+            .and_then(|file| read_number_from_file(file.as_path()));
+    }
+}
+
+pub(crate) struct FansInner {
+    pub(crate) fans: Vec<Fan>,
+}
+
+impl FansInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            fans: Vec::with_capacity(4),
+        }
+    }
+
+    pub(crate) fn from_vec(fans: Vec<Fan>) -> Self {
+        Self { fans }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Fan> {
+        self.fans
+    }
+
+    pub(crate) fn list(&self) -> &[Fan] {
+        &self.fans
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Fan] {
+        &mut self.fans
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        read_temp_dir("/sys/class/hwmon", "hwmon", |path| {
+            FanInner::from_hwmon(&mut self.fans, &path);
+        });
+    }
+}
+
+/// `hwmon` voltage, current and power sensors with the divisor to convert their values (in
+/// millivolts, milliamperes and microwatts) into the unit of the sensor.
+const HWMON_POWER_SENSORS: &[(&str, PowerSensorKind, f32)] = &[
+    ("in", PowerSensorKind::Voltage, 1_000.),
+    ("curr", PowerSensorKind::Current, 1_000.),
+    ("power", PowerSensorKind::Power, 1_000_000.),
+];
+
+enum PowerSensorSource {
+    /// Read in `in[0-*]_input`, `curr[1-*]_input` or `power[1-*]_input` (or
+    /// `power[1-*]_average` if there is no `input` file).
+    Hwmon { divisor: f32 },
+    /// Read in `energy_uj`, in microjoules. The counter wraps around at `max_energy_range`.
+    Rapl {
+        max_energy_range: u64,
+        last: Option<(u64, Instant)>,
+    },
+}
+
+pub(crate) struct PowerSensorInner {
+    kind: PowerSensorKind,
+    label: String,
+    value: Option<f32>,
+    input_file: PathBuf,
+    source: PowerSensorSource,
+    pub(crate) updated: bool,
+}
+
+impl PowerSensorInner {
+    /// Read out the voltage, current and power sensors of the `hwmon` `folder` and store them
+    /// into `sensors`.
+    ///
+    /// Like for the components, sensors without an input file are ignored.
+    fn from_hwmon(sensors: &mut Vec<PowerSensor>, folder: &Path) -> Option<()> {
+        let dir = read_dir(folder).ok()?;
+        let name = get_file_line(&folder.join("name"), 16).unwrap_or_default();
+        let device_model = get_file_line(&folder.join("device/model"), 16);
+        // The key is the index in `HWMON_POWER_SENSORS` and the sensor ID.
+        let mut matchings: HashMap<(usize, u32), (Option<PathBuf>, String)> = HashMap::new();
+        for entry in dir.flatten() {
+            if !entry.file_type().is_ok_and(|file_type| !file_type.is_dir()) {
+                continue;
+            }
+
+            let entry = entry.path();
+            let filename = entry.file_name().and_then(|x| x.to_str()).unwrap_or("");
+            let Some((class, id, item)) =
+                HWMON_POWER_SENSORS
+                    .iter()
+                    .enumerate()
+                    .find_map(|(class, (prefix, _, _))| {
+                        let (id, item) = filename.strip_prefix(prefix)?.split_once('_')?;
+                        Some((class, id.parse::<u32>().ok()?, item))
+                    })
+            else {
+                continue;
+            };
+
+            let (input_file, label) = matchings.entry((class, id)).or_default();
+            match item {
+                "input" => *input_file = Some(entry),
+                // Some drivers (like `amdgpu`) only provide the average power.
+                "average" if HWMON_POWER_SENSORS[class].1 == PowerSensorKind::Power => {
+                    input_file.get_or_insert(entry);
+                }
+                "label" => *label = get_file_line(&entry, 10).unwrap_or_default(),
+                _ => {}
+            }
+        }
+        for ((class, id), (input_file, label)) in matchings {
+            let Some(input_file) = input_file else {
+                continue;
+            };
+            let (prefix, kind, divisor) = HWMON_POWER_SENSORS[class];
+            let label = format_label(&name, &label, device_model.as_deref(), prefix, id);
+
+            if let Some(sensor) = sensors
+                .iter_mut()
+                .find(|sensor| sensor.inner.kind == kind && sensor.inner.label == label)
+            {
+                sensor.inner.input_file = input_file;
+                sensor.inner.refresh();
+                sensor.inner.updated = true;
+            } else {
+                let mut sensor = PowerSensorInner {
+                    kind,
+                    label,
+                    value: None,
+                    input_file,
+                    source: PowerSensorSource::Hwmon { divisor },
+                    updated: true,
+                };
+                sensor.refresh();
+                sensors.push(PowerSensor { inner: sensor });
+            }
+        }
+
+        Some(())
+    }
+
+    /// Read out the Intel RAPL zone `folder` (like `/sys/class/powercap/intel-rapl:0`) and
+    /// store it into `sensors`.
+    ///
+    /// The zone is ignored if its energy counter can't be read.
+    fn from_rapl(sensors: &mut Vec<PowerSensor>, folder: &Path) -> Option<()> {
+        let zone = folder.file_name()?.to_str()?;
+        let name = get_file_line(&folder.join("name"), 16)?;
+        let label = format!("{zone} {name}");
+
+        if let Some(sensor) = sensors.iter_mut().find(|sensor| {
+            sensor.inner.kind == PowerSensorKind::Power && sensor.inner.label == label
+        }) {
+            sensor.inner.refresh();
+            sensor.inner.updated = true;
+            return Some(());
+        }
+        let input_file = folder.join("energy_uj");
+        // `energy_uj` is only readable by root on most systems.
+        read_number_from_file::<u64>(&input_file)?;
+        let mut sensor = PowerSensorInner {
+            kind: PowerSensorKind::Power,
+            label,
+            value: None,
+            input_file,
+            source: PowerSensorSource::Rapl {
+                max_energy_range: read_number_from_file(&folder.join("max_energy_range_uj"))
+                    .unwrap_or(0),
+                last: None,
+            },
+            updated: true,
+        };
+        sensor.refresh();
+        sensors.push(PowerSensor { inner: sensor });
+        Some(())
+    }
+
+    pub(crate) fn kind(&self) -> PowerSensorKind {
+        self.kind
+    }
+
+    pub(crate) fn value(&self) -> Option<f32> {
+        self.value
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        match self.source {
+            PowerSensorSource::Hwmon { divisor } => {
+                self.value = read_number_from_file::<i64>(&self.input_file)
+                    .map(|value| value as f32 / divisor);
+            }
+            PowerSensorSource::Rapl {
+                max_energy_range,
+                ref mut last,
+            } => {
+                let energy = read_number_from_file::<u64>(&self.input_file);
+                let now = Instant::now();
+                self.value = match (*last, energy) {
+                    (Some((last_energy, last_time)), Some(energy)) => rapl_power(
+                        last_energy,
+                        energy,
+                        max_energy_range,
+                        now.duration_since(last_time),
+                    ),
+                    _ => None,
+                };
+                *last = energy.map(|energy| (energy, now));
+            }
+        }
+    }
+}
+
+/// Computes the power (in watts) from two readings of a RAPL energy counter (in microjoules)
+/// `elapsed` apart.
+fn rapl_power(
+    last_energy: u64,
+    energy: u64,
+    max_energy_range: u64,
+    elapsed: Duration,
+) -> Option<f32> {
+    let elapsed = elapsed.as_secs_f64();
+    if elapsed <= 0. {
+        return None;
+    }
+    let consumed = crate::common::rapl_energy_consumed(last_energy, energy, max_energy_range);
+    Some((consumed as f64 / 1_000_000. / elapsed) as f32)
+}
+
+pub(crate) struct PowerSensorsInner {
+    pub(crate) sensors: Vec<PowerSensor>,
+}
+
+impl PowerSensorsInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            sensors: Vec::with_capacity(4),
+        }
+    }
+
+    pub(crate) fn from_vec(sensors: Vec<PowerSensor>) -> Self {
+        Self { sensors }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<PowerSensor> {
+        self.sensors
+    }
+
+    pub(crate) fn list(&self) -> &[PowerSensor] {
+        &self.sensors
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [PowerSensor] {
+        &mut self.sensors
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        read_temp_dir("/sys/class/hwmon", "hwmon", |path| {
+            PowerSensorInner::from_hwmon(&mut self.sensors, &path);
+        });
+        read_temp_dir("/sys/class/powercap", "intel-rapl:", |path| {
+            PowerSensorInner::from_rapl(&mut self.sensors, &path);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rapl_power, ComponentInner, FanInner, PowerSensorInner};
+    use crate::PowerSensorKind;
+    use std::fs;
+    use std::time::Duration;
+
+    #[test]
+    fn check_component_thresholds_from_hwmon() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon = dir.path();
+        for (file, content) in [
+            ("name", "coretemp\n"),
+            ("temp1_input", "42000\n"),
+            ("temp1_min", "5000\n"),
+            ("temp1_crit", "100000\n"),
+            ("temp1_crit_hyst", "95000\n"),
+            ("temp1_alarm", "0\n"),
+            ("temp1_crit_alarm", "0\n"),
+            ("temp2_input", "43000\n"),
+        ] {
+            fs::write(hwmon.join(file), content).expect("failed to write file");
+        }
+
+        let mut components = Vec::new();
+        ComponentInner::from_hwmon(&mut components, hwmon, &mut |_, _| true)
+            .expect("failed to read hwmon");
+        components.sort_by(|a, b| a.inner.label.cmp(&b.inner.label));
+        assert_eq!(components.len(), 2);
+        let component = &mut components[0].inner;
+        assert_eq!(component.label, "coretemp temp1");
+        assert_eq!(component.min(), Some(5.));
+        assert_eq!(component.critical(), Some(100.));
+        assert_eq!(component.critical_hysteresis(), Some(95.));
+        assert_eq!(component.alarm(), Some(false));
+
+        // The alarm state is read again on refresh.
+        fs::write(hwmon.join("temp1_crit_alarm"), "1\n").expect("failed to write file");
+        component.refresh();
+        assert_eq!(component.alarm(), Some(true));
+
+        let component = &components[1].inner;
+        assert_eq!(component.min(), None);
+        assert_eq!(component.critical_hysteresis(), None);
+        assert_eq!(component.alarm(), None);
+    }
+
+    #[test]
+    fn check_component_filter_from_hwmon() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let coretemp = dir.path().join("hwmon0");
+        let nvme = dir.path().join("hwmon1");
+        for (folder, name) in [(&coretemp, "coretemp"), (&nvme, "nvme")] {
+            fs::create_dir(folder).expect("failed to create directory");
+            fs::write(folder.join("name"), format!("{name}\n")).expect("failed to write file");
+            fs::write(folder.join("temp1_input"), "42000\n").expect("failed to write file");
+            fs::write(folder.join("temp2_input"), "43000\n").expect("failed to write file");
+            fs::write(folder.join("temp2_label"), "Core 1\n").expect("failed to write file");
+        }
+
+        let mut components = Vec::new();
+        let mut filter = |name: &str, label: &str| name != "nvme" && label != "Core 1";
+        ComponentInner::from_hwmon(&mut components, &coretemp, &mut filter)
+            .expect("failed to read hwmon");
+        ComponentInner::from_hwmon(&mut components, &nvme, &mut filter)
+            .expect("failed to read hwmon");
+        assert_eq!(components.len(), 1);
+        let component = &components[0].inner;
+        assert_eq!(component.label(), "coretemp temp1");
+        assert_eq!(component.device_name(), Some("coretemp"));
+        assert_eq!(component.temperature(), Some(42.));
+
+        let mut labels = Vec::new();
+        ComponentInner::from_hwmon(&mut Vec::new(), &nvme, &mut |name, label| {
+            labels.push(format!("{name}: {label}"));
+            false
+        })
+        .expect("failed to read hwmon");
+        labels.sort();
+        assert_eq!(labels, ["nvme: Core 1", "nvme: nvme temp1"]);
+    }
+
+    #[test]
+    fn check_gpu_component_labels() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let amdgpu = dir.path().join("hwmon0");
+        let nouveau = dir.path().join("hwmon1");
+        fs::create_dir_all(amdgpu.join("device/drm/card1")).expect("failed to create directory");
+        fs::create_dir_all(amdgpu.join("device/drm/renderD128"))
+            .expect("failed to create directory");
+        fs::create_dir(&nouveau).expect("failed to create directory");
+        for (file, content) in [
+            ("name", "amdgpu\n"),
+            ("temp1_input", "42000\n"),
+            ("temp1_label", "edge\n"),
+            ("temp2_input", "43000\n"),
+            ("temp2_label", "junction\n"),
+        ] {
+            fs::write(amdgpu.join(file), content).expect("failed to write file");
+        }
+        fs::write(nouveau.join("name"), "nouveau\n").expect("failed to write file");
+        fs::write(nouveau.join("temp1_input"), "44000\n").expect("failed to write file");
+
+        let mut components = Vec::new();
+        for folder in [&amdgpu, &nouveau] {
+            ComponentInner::from_hwmon(&mut components, folder, &mut |_, _| true)
+                .expect("failed to read hwmon");
+        }
+        let mut labels = components
+            .iter()
+            .map(|c| c.inner.label())
+            .collect::<Vec<_>>();
+        labels.sort();
+        assert_eq!(
+            labels,
+            [
+                "GPU amdgpu card1 edge",
+                "GPU amdgpu card1 junction",
+                "GPU nouveau temp1",
+            ]
+        );
+    }
+
+    #[test]
+    fn check_component_refresh() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon = dir.path();
+        fs::write(hwmon.join("name"), "coretemp\n").expect("failed to write file");
+        fs::write(hwmon.join("temp1_input"), "42000\n").expect("failed to write file");
+
+        let mut components = Vec::new();
+        ComponentInner::from_hwmon(&mut components, hwmon, &mut |_, _| true)
+            .expect("failed to read hwmon");
+        assert_eq!(components.len(), 1);
+        let component = &mut components[0].inner;
+        assert!(component.input.is_some());
+        assert_eq!(component.temperature(), Some(42.));
+
+        // Read again from the opened file.
+        fs::write(hwmon.join("temp1_input"), "45000\n").expect("failed to write file");
+        component.refresh();
+        assert_eq!(component.temperature(), Some(45.));
+        assert_eq!(component.max(), Some(45.));
+
+        // The file is opened again if it cannot be read anymore.
+        component.input = None;
+        fs::write(hwmon.join("temp1_input"), "43000\n").expect("failed to write file");
+        component.refresh();
+        assert!(component.input.is_some());
+        assert_eq!(component.temperature(), Some(43.));
+        assert_eq!(component.max(), Some(45.));
+    }
+
+    #[test]
+    fn check_observed_max() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon = dir.path();
+        fs::write(hwmon.join("name"), "coretemp\n").expect("failed to write file");
+        fs::write(hwmon.join("temp1_input"), "42000\n").expect("failed to write file");
+        fs::write(hwmon.join("temp1_highest"), "90000\n").expect("failed to write file");
+
+        let mut components = Vec::new();
+        ComponentInner::from_hwmon(&mut components, hwmon, &mut |_, _| true)
+            .expect("failed to read hwmon");
+        let component = &mut components[0];
+        assert_eq!(component.observed_max(), None);
+        component.update_observed_max();
+        assert_eq!(component.observed_max(), Some(42.));
+
+        fs::write(hwmon.join("temp1_input"), "47000\n").expect("failed to write file");
+        component.refresh();
+        fs::write(hwmon.join("temp1_input"), "44000\n").expect("failed to write file");
+        component.refresh();
+        assert_eq!(component.temperature(), Some(44.));
+        // The highest temperature recorded by the chip can predate the refreshes.
+        assert_eq!(component.max(), Some(90.));
+        assert_eq!(component.observed_max(), Some(47.));
+    }
+
+    #[test]
+    fn check_fans_from_hwmon() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon = dir.path();
+        for (file, content) in [
+            ("name", "nct6775\n"),
+            ("fan1_input", "1200\n"),
+            ("fan2_input", "0\n"),
+            ("fan2_label", "CPU_FAN\n"),
+            ("fan2_min", "300\n"),
+            ("fan2_max", "2400\n"),
+            // No `fan3_input`, so it should be ignored.
+            ("fan3_min", "300\n"),
+            ("temp1_input", "42000\n"),
+        ] {
+            fs::write(hwmon.join(file), content).expect("failed to write file");
+        }
+
+        let mut fans = Vec::new();
+        FanInner::from_hwmon(&mut fans, hwmon).expect("failed to read hwmon");
+        fans.sort_by(|a, b| a.inner.label.cmp(&b.inner.label));
+        assert_eq!(fans.len(), 2);
+        assert_eq!(fans[0].inner.label, "nct6775 CPU_FAN");
+        assert_eq!(fans[0].inner.rpm, Some(0));
+        assert_eq!(fans[0].inner.min_rpm, Some(300));
+        assert_eq!(fans[0].inner.max_rpm, Some(2400));
+        assert_eq!(fans[1].inner.label, "nct6775 fan1");
+        assert_eq!(fans[1].inner.rpm, Some(1200));
+        assert_eq!(fans[1].inner.min_rpm, None);
+
+        fs::write(hwmon.join("fan1_input"), "1300\n").expect("failed to write file");
+        fans[1].inner.refresh();
+        assert_eq!(fans[1].inner.rpm, Some(1300));
+
+        // Fans already listed are updated instead of being added again.
+        fans.iter_mut().for_each(|fan| fan.inner.updated = false);
+        FanInner::from_hwmon(&mut fans, hwmon).expect("failed to read hwmon");
+        assert_eq!(fans.len(), 2);
+        assert!(fans.iter().all(|fan| fan.inner.updated));
+    }
+
+    #[test]
+    fn check_power_sensors_from_hwmon() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let hwmon = dir.path();
+        for (file, content) in [
+            ("name", "ina3221\n"),
+            ("in1_input", "12000\n"),
+            ("in1_label", "VBUS\n"),
+            ("curr1_input", "1500\n"),
+            ("power1_average", "5000000\n"),
+            // Not a voltage sensor.
+            ("intrusion0_alarm", "0\n"),
+        ] {
+            fs::write(hwmon.join(file), content).expect("failed to write file");
+        }
+
+        let mut sensors = Vec::new();
+        PowerSensorInner::from_hwmon(&mut sensors, hwmon).expect("failed to read hwmon");
+        sensors.sort_by(|a, b| a.inner.label.cmp(&b.inner.label));
+        let sensors = sensors
+            .iter()
+            .map(|s| (s.inner.kind, s.inner.label.as_str(), s.inner.value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sensors,
+            [
+                (PowerSensorKind::Voltage, "ina3221 VBUS", Some(12.)),
+                (PowerSensorKind::Current, "ina3221 curr1", Some(1.5)),
+                (PowerSensorKind::Power, "ina3221 power1", Some(5.)),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_rapl_power() {
+        let second = Duration::from_secs(1);
+        assert_eq!(
+            rapl_power(1_000_000, 3_000_000, 10_000_000, second * 2),
+            Some(1.)
+        );
+        // The counter wrapped around.
+        assert_eq!(
+            rapl_power(9_000_000, 1_000_000, 10_000_000, second),
+            Some(2.)
+        );
+        assert_eq!(
+            rapl_power(1_000_000, 3_000_000, 10_000_000, Duration::ZERO),
+            None
+        );
+
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let zone = dir.path().join("intel-rapl:0");
+        fs::create_dir(&zone).expect("failed to create directory");
+        fs::write(zone.join("name"), "package-0\n").expect("failed to write file");
+        fs::write(zone.join("energy_uj"), "1000\n").expect("failed to write file");
+        fs::write(zone.join("max_energy_range_uj"), "262143328850\n")
+            .expect("failed to write file");
+
+        let mut sensors = Vec::new();
+        PowerSensorInner::from_rapl(&mut sensors, &zone).expect("failed to read RAPL zone");
+        assert_eq!(sensors.len(), 1);
+        assert_eq!(sensors[0].inner.label, "intel-rapl:0 package-0");
+        // It needs two readings to compute the power.
+        assert_eq!(sensors[0].inner.value, None);
+
+        fs::write(zone.join("energy_uj"), "2000\n").expect("failed to write file");
+        PowerSensorInner::from_rapl(&mut sensors, &zone).expect("failed to read RAPL zone");
+        assert_eq!(sensors.len(), 1);
+        assert!(sensors[0].inner.value.is_some_and(|power| power > 0.));
     }
 }
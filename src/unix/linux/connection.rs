@@ -0,0 +1,268 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use std::collections::HashMap;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::Path;
+
+use crate::{Connection, ConnectionProtocol, ConnectionsFilter, Pid, TcpState};
+
+fn tcp_state(state: u8) -> TcpState {
+    // Values from `include/net/tcp_states.h`.
+    match state {
+        0x01 => TcpState::Established,
+        0x02 => TcpState::SynSent,
+        0x03 | 0x0C => TcpState::SynReceived,
+        0x04 => TcpState::FinWait1,
+        0x05 => TcpState::FinWait2,
+        0x06 => TcpState::TimeWait,
+        0x07 => TcpState::Closed,
+        0x08 => TcpState::CloseWait,
+        0x09 => TcpState::LastAck,
+        0x0A => TcpState::Listen,
+        0x0B => TcpState::Closing,
+        state => TcpState::Unknown(state as _),
+    }
+}
+
+/// Parses an `address:port` entry of the `/proc/net/{tcp,udp}{,6}` files.
+///
+/// The address is printed as 32-bit words in host byte order while the port is printed in its
+/// usual representation. The IPv4-mapped addresses of the dual-stack sockets are converted into
+/// IPv4 addresses. The scope of the link-local addresses isn't available in these files.
+fn parse_socket_address(s: &str) -> Option<SocketAddr> {
+    let (addr, port) = s.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let word = |i: usize| -> Option<[u8; 4]> {
+        let word = u32::from_str_radix(addr.get(i * 8..(i + 1) * 8)?, 16).ok()?;
+        Some(word.to_ne_bytes())
+    };
+    let addr = match addr.len() {
+        8 => IpAddr::V4(Ipv4Addr::from(word(0)?)),
+        32 => {
+            let mut bytes = [0; 16];
+            for (i, chunk) in bytes.chunks_exact_mut(4).enumerate() {
+                chunk.copy_from_slice(&word(i)?);
+            }
+            IpAddr::V6(Ipv6Addr::from(bytes)).to_canonical()
+        }
+        _ => return None,
+    };
+    Some(SocketAddr::new(addr, port))
+}
+
+fn parse_proc_net(content: &str, protocol: ConnectionProtocol) -> Vec<Connection> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let local_address = parse_socket_address(fields.get(1)?)?;
+            let remote_address = parse_socket_address(fields.get(2)?)?;
+            let state = u8::from_str_radix(fields.get(3)?, 16).ok()?;
+            let inode: u64 = fields.get(9)?.parse().ok()?;
+            Some(Connection {
+                protocol,
+                local_address,
+                remote_address: (!remote_address.ip().is_unspecified()
+                    || remote_address.port() != 0)
+                    .then_some(remote_address),
+                state: (protocol == ConnectionProtocol::Tcp).then(|| tcp_state(state)),
+                pid: None,
+                // Sockets which aren't owned by a process anymore have their inode set to 0.
+                inode: (inode != 0).then_some(inode),
+            })
+        })
+        .collect()
+}
+
+/// Returns the processes owning the sockets (identified by their inode) by going through the
+/// file descriptors of the given processes.
+fn get_socket_owners(proc_path: &Path, pids: Option<&[Pid]>) -> HashMap<u64, Pid> {
+    let pids = match pids {
+        Some(pids) => pids.to_vec(),
+        None => match fs::read_dir(proc_path) {
+            Ok(entries) => entries
+                .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse().ok())
+                .collect(),
+            Err(_) => return HashMap::new(),
+        },
+    };
+    let mut owners = HashMap::new();
+    for pid in pids {
+        let Ok(fds) = fs::read_dir(proc_path.join(pid.to_string()).join("fd")) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            let Ok(target) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            if let Some(inode) = target
+                .to_str()
+                .and_then(|target| target.strip_prefix("socket:["))
+                .and_then(|target| target.strip_suffix(']'))
+                .and_then(|inode| inode.parse().ok())
+            {
+                owners.entry(inode).or_insert(pid);
+            }
+        }
+    }
+    owners
+}
+
+pub(crate) fn get_connections(
+    connections: &mut Vec<Connection>,
+    filter: ConnectionsFilter<'_>,
+    with_pid: bool,
+) {
+    connections.clear();
+
+    for (file, protocol) in [
+        ("/proc/net/tcp", ConnectionProtocol::Tcp),
+        ("/proc/net/tcp6", ConnectionProtocol::Tcp),
+        ("/proc/net/udp", ConnectionProtocol::Udp),
+        ("/proc/net/udp6", ConnectionProtocol::Udp),
+    ] {
+        if let Ok(content) = fs::read_to_string(file) {
+            connections.extend(parse_proc_net(&content, protocol));
+        }
+    }
+    if filter == ConnectionsFilter::Listening {
+        connections.retain(|connection| connection.is_listening());
+    }
+    if with_pid {
+        let pids = match filter {
+            ConnectionsFilter::Processes(pids) => Some(pids),
+            _ => None,
+        };
+        let owners = get_socket_owners(Path::new("/proc"), pids);
+        for connection in connections.iter_mut() {
+            if let Some(inode) = connection.inode {
+                connection.pid = owners.get(&inode).copied();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_proc_net, parse_socket_address};
+    use crate::{ConnectionProtocol, TcpState};
+    use std::net::SocketAddr;
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn check_parse_socket_address() {
+        assert_eq!(
+            parse_socket_address("0100007F:0277"),
+            Some("127.0.0.1:631".parse().unwrap())
+        );
+        assert_eq!(
+            parse_socket_address("B80D0120000000000000000001000000:1F90"),
+            Some("[2001:db8::1]:8080".parse().unwrap())
+        );
+        assert_eq!(
+            parse_socket_address("0000000000000000FFFF00000100007F:0035"),
+            Some("127.0.0.1:53".parse().unwrap())
+        );
+        assert_eq!(
+            parse_socket_address("00000000000000000000000001000000:0016"),
+            Some("[::1]:22".parse().unwrap())
+        );
+        assert_eq!(
+            parse_socket_address("000080FE00000000FF005452120035FE:0222"),
+            Some("[fe80::5254:ff:fe35:12]:546".parse().unwrap())
+        );
+        assert_eq!(parse_socket_address("0100007F"), None);
+        assert_eq!(parse_socket_address("0100:0277"), None);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn check_parse_proc_net() {
+        let tcp = "\
+  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:0277 00000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 23456 1 0000000000000000 100 0 0 10 0
+   1: 0F02000A:C350 2E1A9B8E:01BB 01 00000000:00000000 02:0004F2A1 00000000  1000        0 34567 2 0000000000000000 20 4 30 10 -1
+   2: 0F02000A:C352 2E1A9B8E:01BB 06 00000000:00000000 03:00000F6E 00000000     0        0 0 3 0000000000000000
+";
+        let connections = parse_proc_net(tcp, ConnectionProtocol::Tcp);
+        assert_eq!(connections.len(), 3);
+        assert_eq!(connections[0].inode, Some(23456));
+        assert_eq!(
+            connections[0].local_address,
+            "127.0.0.1:631".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(connections[0].remote_address, None);
+        assert_eq!(connections[0].state, Some(TcpState::Listen));
+        assert_eq!(
+            connections[1].remote_address,
+            Some("142.155.26.46:443".parse().unwrap())
+        );
+        assert_eq!(connections[1].state, Some(TcpState::Established));
+        assert_eq!(connections[2].inode, None);
+        assert_eq!(connections[2].state, Some(TcpState::TimeWait));
+
+        let udp = "\
+   sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode ref pointer drops
+  128: 00000000000000000000000000000000:14E9 00000000000000000000000000000000:0000 07 00000000:00000000 00:00000000 00000000   104        0 15555 2 0000000000000000 0
+";
+        let connections = parse_proc_net(udp, ConnectionProtocol::Udp);
+        assert_eq!(connections.len(), 1);
+        assert_eq!(
+            connections[0].local_address,
+            "[::]:5353".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(connections[0].remote_address, None);
+        assert_eq!(connections[0].state, None);
+        assert!(connections[0].is_listening());
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn check_parse_proc_net_tcp6() {
+        // Captured on a dual-stack host: a socket listening on every address, a SSH connection
+        // from an IPv4 client to a dual-stack socket and a connection between link-local
+        // addresses.
+        let tcp6 = "\
+  sl  local_address                         remote_address                        st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 00000000000000000000000000000000:0016 00000000000000000000000000000000:0000 0A 00000000:00000000 00:00000000 00000000     0        0 19873 1 0000000000000000 100 0 0 10 0
+   1: 0000000000000000FFFF00000F02000A:0016 0000000000000000FFFF0000020B000A:D8E2 01 00000000:00000000 02:00085E66 00000000     0        0 41290 4 0000000000000000 20 4 27 10 20
+   2: 000080FE00000000FF005452120035FE:A4D2 000080FE00000000FF005452020035FE:1F90 01 00000000:00000000 00:00000000 00000000  1000        0 52114 1 0000000000000000 20 4 30 10 -1
+";
+        let connections = parse_proc_net(tcp6, ConnectionProtocol::Tcp);
+        assert_eq!(connections.len(), 3);
+
+        assert_eq!(
+            connections[0].local_address,
+            "[::]:22".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(connections[0].remote_address, None);
+        assert_eq!(connections[0].state, Some(TcpState::Listen));
+        assert!(connections[0].is_listening());
+
+        assert_eq!(
+            connections[1].local_address,
+            "10.0.2.15:22".parse::<SocketAddr>().unwrap()
+        );
+        assert_eq!(
+            connections[1].remote_address,
+            Some("10.0.11.2:55522".parse().unwrap())
+        );
+        assert_eq!(connections[1].state, Some(TcpState::Established));
+        assert_eq!(connections[1].inode, Some(41290));
+
+        assert_eq!(
+            connections[2].local_address,
+            "[fe80::5254:ff:fe35:12]:42194"
+                .parse::<SocketAddr>()
+                .unwrap()
+        );
+        assert_eq!(
+            connections[2].remote_address,
+            Some("[fe80::5254:ff:fe35:2]:8080".parse().unwrap())
+        );
+        assert!(connections[2].local_address.ip().is_ipv6());
+        assert_eq!(connections[2].pid, None);
+    }
+}
@@ -119,6 +119,7 @@ impl CpusWrapper {
                                     vendor_id,
                                     brand,
                                 ),
+                                usage_history: Vec::new(),
                             });
                         } else {
                             parts.next(); // we don't want the name again
@@ -1,12 +1,14 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::disk::FillRate;
 use crate::sys::utils::{get_all_utf8_data, to_cpath};
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, RefreshError, RefreshSubsystem};
 
 use libc::statvfs;
 use std::collections::HashMap;
 use std::ffi::{OsStr, OsString};
 use std::fs;
+use std::io;
 use std::mem::MaybeUninit;
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
@@ -42,6 +44,7 @@ pub(crate) struct DiskInner {
     mount_point: PathBuf,
     total_space: u64,
     available_space: u64,
+    fill_rate: FillRate,
     is_removable: bool,
     is_read_only: bool,
     old_written_bytes: u64,
@@ -76,6 +79,10 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn fill_rate_bytes_per_sec(&self) -> Option<f64> {
+        self.fill_rate.get()
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -85,7 +92,9 @@ impl DiskInner {
     }
 
     pub(crate) fn refresh_specifics(&mut self, refresh_kind: DiskRefreshKind) -> bool {
-        self.efficient_refresh(refresh_kind, &disk_stats(&refresh_kind), false)
+        let mut errors = Vec::new();
+        let procfs_disk_stats = disk_stats(&refresh_kind, &mut errors);
+        self.efficient_refresh(refresh_kind, &procfs_disk_stats, false, &mut errors)
     }
 
     fn efficient_refresh(
@@ -93,6 +102,7 @@ impl DiskInner {
         refresh_kind: DiskRefreshKind,
         procfs_disk_stats: &HashMap<String, DiskStat>,
         first: bool,
+        errors: &mut Vec<RefreshError>,
     ) -> bool {
         if refresh_kind.io_usage() {
             if self.actual_device_name.is_none() {
@@ -117,14 +127,21 @@ impl DiskInner {
         }
 
         if refresh_kind.storage() {
-            if let Some((total_space, available_space, is_read_only)) =
-                unsafe { load_statvfs_values(&self.mount_point) }
-            {
-                self.total_space = total_space;
-                self.available_space = available_space;
-                if first {
-                    self.is_read_only = is_read_only;
+            match unsafe { load_statvfs_values(&self.mount_point) } {
+                Ok(Some((total_space, available_space, is_read_only))) => {
+                    self.total_space = total_space;
+                    self.available_space = available_space;
+                    self.fill_rate.update(available_space);
+                    if first {
+                        self.is_read_only = is_read_only;
+                    }
                 }
+                Ok(None) => {}
+                Err(error) => errors.push(RefreshError::new(
+                    RefreshSubsystem::Disks,
+                    format!("statvfs({})", self.mount_point.display()),
+                    error,
+                )),
             }
         }
 
@@ -153,11 +170,24 @@ impl crate::DisksInner {
         remove_not_listed_disks: bool,
         refresh_kind: DiskRefreshKind,
     ) {
-        get_all_list(
-            &mut self.disks,
-            &get_all_utf8_data("/proc/mounts", 16_385).unwrap_or_default(),
-            refresh_kind,
-        );
+        let _ = self.try_refresh_specifics(remove_not_listed_disks, refresh_kind);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refresh_kind: DiskRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        let mut errors = Vec::new();
+        let content = get_all_utf8_data("/proc/mounts", 16_385).unwrap_or_else(|error| {
+            errors.push(RefreshError::new(
+                RefreshSubsystem::Disks,
+                "/proc/mounts",
+                error,
+            ));
+            String::new()
+        });
+        get_all_list(&mut self.disks, &content, refresh_kind, &mut errors);
 
         if remove_not_listed_disks {
             self.disks.retain_mut(|disk| {
@@ -172,6 +202,12 @@ impl crate::DisksInner {
                 c.inner.updated = false;
             }
         }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
     }
 
     pub(crate) fn list(&self) -> &[Disk] {
@@ -202,7 +238,8 @@ fn get_actual_device_name(device: &OsStr) -> String {
         .unwrap_or_default()
 }
 
-unsafe fn load_statvfs_values(mount_point: &Path) -> Option<(u64, u64, bool)> {
+/// Returns `Ok(None)` if the file system has no size (like `/dev/shm` in some containers).
+unsafe fn load_statvfs_values(mount_point: &Path) -> io::Result<Option<(u64, u64, bool)>> {
     let mount_point_cpath = to_cpath(mount_point);
     let mut stat: MaybeUninit<statvfs> = MaybeUninit::uninit();
     if retry_eintr!(statvfs(
@@ -217,14 +254,14 @@ unsafe fn load_statvfs_values(mount_point: &Path) -> Option<(u64, u64, bool)> {
         let bavail = cast!(stat.f_bavail);
         let total = bsize.saturating_mul(blocks);
         if total == 0 {
-            return None;
+            return Ok(None);
         }
         let available = bsize.saturating_mul(bavail);
         let is_read_only = (stat.f_flag & libc::ST_RDONLY) != 0;
 
-        Some((total, available, is_read_only))
+        Ok(Some((total, available, is_read_only)))
     } else {
-        None
+        Err(io::Error::last_os_error())
     }
 }
 
@@ -235,6 +272,7 @@ fn new_disk(
     removable_entries: &[PathBuf],
     procfs_disk_stats: &HashMap<String, DiskStat>,
     refresh_kind: DiskRefreshKind,
+    errors: &mut Vec<RefreshError>,
 ) -> Disk {
     let is_removable = removable_entries
         .iter()
@@ -249,6 +287,7 @@ fn new_disk(
             mount_point: mount_point.to_owned(),
             total_space: 0,
             available_space: 0,
+            fill_rate: FillRate::default(),
             is_removable,
             is_read_only: false,
             old_read_bytes: 0,
@@ -259,7 +298,7 @@ fn new_disk(
         },
     };
     disk.inner
-        .efficient_refresh(refresh_kind, procfs_disk_stats, true);
+        .efficient_refresh(refresh_kind, procfs_disk_stats, true, errors);
     disk
 }
 
@@ -334,7 +373,12 @@ fn find_type_for_device_name(device_name: &OsStr) -> DiskKind {
     }
 }
 
-fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefreshKind) {
+fn get_all_list(
+    container: &mut Vec<Disk>,
+    content: &str,
+    refresh_kind: DiskRefreshKind,
+    errors: &mut Vec<RefreshError>,
+) {
     // The goal of this array is to list all removable devices (the ones whose name starts with
     // "usb-").
     let removable_entries = match fs::read_dir("/dev/disk/by-id/") {
@@ -354,7 +398,7 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
         _ => Vec::new(),
     };
 
-    let procfs_disk_stats = disk_stats(&refresh_kind);
+    let procfs_disk_stats = disk_stats(&refresh_kind, errors);
 
     for (fs_spec, fs_file, fs_vfstype) in content
         .lines()
@@ -412,7 +456,7 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
                 && d.inner.file_system == fs_vfstype
         }) {
             disk.inner
-                .efficient_refresh(refresh_kind, &procfs_disk_stats, false);
+                .efficient_refresh(refresh_kind, &procfs_disk_stats, false, errors);
             disk.inner.updated = true;
             continue;
         }
@@ -423,6 +467,7 @@ fn get_all_list(container: &mut Vec<Disk>, content: &str, refresh_kind: DiskRefr
             &removable_entries,
             &procfs_disk_stats,
             refresh_kind,
+            errors,
         ));
     }
 }
@@ -479,13 +524,17 @@ impl DiskStat {
     }
 }
 
-fn disk_stats(refresh_kind: &DiskRefreshKind) -> HashMap<String, DiskStat> {
+fn disk_stats(
+    refresh_kind: &DiskRefreshKind,
+    errors: &mut Vec<RefreshError>,
+) -> HashMap<String, DiskStat> {
     if refresh_kind.io_usage() {
         let path = "/proc/diskstats";
         match fs::read_to_string(path) {
             Ok(content) => disk_stats_inner(&content),
-            Err(_error) => {
-                sysinfo_debug!("failed to read {path:?}: {_error:?}");
+            Err(error) => {
+                sysinfo_debug!("failed to read {path:?}: {error:?}");
+                errors.push(RefreshError::new(RefreshSubsystem::Disks, path, error));
                 HashMap::new()
             }
         }
@@ -512,8 +561,17 @@ fn disk_stats_inner(content: &str) -> HashMap<String, DiskStat> {
 
 #[cfg(test)]
 mod test {
-    use super::{disk_stats_inner, DiskStat};
+    use super::{disk_stats_inner, load_statvfs_values, DiskStat};
     use std::collections::HashMap;
+    use std::path::Path;
+
+    #[test]
+    fn test_load_statvfs_values() {
+        let error = unsafe { load_statvfs_values(Path::new("/nonexistent/mount/point")) }
+            .expect_err("statvfs should fail");
+        assert_eq!(error.raw_os_error(), Some(libc::ENOENT));
+        assert!(unsafe { load_statvfs_values(Path::new("/")) }.is_ok());
+    }
 
     #[test]
     fn test_disk_stat_parsing() {
@@ -2,11 +2,17 @@
 
 use std::collections::{hash_map, HashMap};
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::network_helper::get_dns_servers;
+use crate::{
+    InterfaceKind, IpNetwork, MacAddr, NetworkData, NetworksRefreshKind, RefreshError,
+    RefreshSubsystem, RouteInfo, WifiInfo,
+};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident) => {{
@@ -22,97 +28,183 @@ macro_rules! old_and_new {
 
 #[allow(clippy::ptr_arg)]
 fn read<P: AsRef<Path>>(parent: P, path: &str, data: &mut Vec<u8>) -> u64 {
-    if let Ok(mut f) = File::open(parent.as_ref().join(path)) {
-        if let Ok(size) = f.read(data) {
-            let mut i = 0;
-            let mut ret = 0;
+    try_read(parent.as_ref(), path, data).unwrap_or(0)
+}
+
+#[allow(clippy::ptr_arg)]
+fn try_read(parent: &Path, path: &str, data: &mut Vec<u8>) -> io::Result<u64> {
+    let size = File::open(parent.join(path))?.read(data)?;
+    let mut i = 0;
+    let mut ret = 0;
+
+    while i < size && i < data.len() && data[i] >= b'0' && data[i] <= b'9' {
+        ret *= 10;
+        ret += (data[i] - b'0') as u64;
+        i += 1;
+    }
+    Ok(ret)
+}
 
-            while i < size && i < data.len() && data[i] >= b'0' && data[i] <= b'9' {
-                ret *= 10;
-                ret += (data[i] - b'0') as u64;
-                i += 1;
+/// Reads the interface flags (`IFF_*`), which are stored as an hexadecimal value.
+fn read_flags(parent: &Path, data: &mut [u8]) -> u32 {
+    if let Ok(mut f) = File::open(parent.join("flags")) {
+        if let Ok(size) = f.read(data) {
+            if let Ok(content) = std::str::from_utf8(&data[..size]) {
+                let content = content.trim();
+                let content = content.strip_prefix("0x").unwrap_or(content);
+                return u32::from_str_radix(content, 16).unwrap_or(0);
             }
-            return ret;
         }
     }
     0
 }
 
+/// Returns the value of `DEVTYPE` in the interface `uevent` file.
+fn read_devtype(parent: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(parent.join("uevent")).ok()?;
+    content
+        .lines()
+        .find_map(|line| line.strip_prefix("DEVTYPE="))
+        .map(|devtype| devtype.to_owned())
+}
+
+/// Reads the interface alias, which is empty if none was set.
+fn read_alias(parent: &Path) -> Option<String> {
+    let content = std::fs::read_to_string(parent.join("ifalias")).ok()?;
+    let alias = content.trim_end_matches('\n');
+    (!alias.is_empty()).then(|| alias.to_owned())
+}
+
+/// Returns the name of the interface the `path` symlink points to.
+fn read_link_name(path: &Path) -> Option<String> {
+    std::fs::read_link(path)
+        .ok()?
+        .file_name()?
+        .to_str()
+        .map(|name| name.to_owned())
+}
+
+/// Returns the interface `name` is stacked on (for VLANs or macvlans for example). The
+/// interfaces enslaved to `name` (if it is a bond or a bridge) are ignored.
+fn read_lower_device(parent: &Path, name: &str) -> Option<String> {
+    let mut lower_devices = std::fs::read_dir(parent)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| {
+            let lower_device = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("lower_")?
+                .to_owned();
+            if read_link_name(&entry.path().join("master")).as_deref() == Some(name) {
+                None
+            } else {
+                Some(lower_device)
+            }
+        });
+    let lower_device = lower_devices.next()?;
+    lower_devices.next().is_none().then_some(lower_device)
+}
+
+/// Returns the VLAN ID of `name` from the content of `/proc/net/vlan/config`.
+fn parse_vlan_config(content: &str, name: &str) -> Option<u16> {
+    // The first two lines are headers.
+    content.lines().skip(2).find_map(|line| {
+        let mut fields = line.split('|').map(str::trim);
+        if fields.next()? != name {
+            return None;
+        }
+        fields.next()?.parse().ok()
+    })
+}
+
+fn read_kind(parent: &Path, data: &mut Vec<u8>) -> InterfaceKind {
+    match read_devtype(parent).as_deref() {
+        Some("wlan") => return InterfaceKind::Wifi,
+        Some("wwan") => return InterfaceKind::Cellular,
+        Some("bridge") => return InterfaceKind::Bridge,
+        Some("wireguard") => return InterfaceKind::Tunnel,
+        Some("vlan" | "bond" | "macvlan" | "ipvlan" | "vxlan") => return InterfaceKind::Virtual,
+        _ => {}
+    }
+    if parent.join("wireless").exists() || parent.join("phy80211").exists() {
+        return InterfaceKind::Wifi;
+    }
+    if parent.join("bridge").exists() {
+        return InterfaceKind::Bridge;
+    }
+    let if_type = read(parent, "type", data) as u32;
+    let Ok(arphrd) = u16::try_from(if_type) else {
+        return InterfaceKind::Other(if_type);
+    };
+    match arphrd {
+        libc::ARPHRD_LOOPBACK => InterfaceKind::Loopback,
+        libc::ARPHRD_ETHER => {
+            // Interfaces which aren't backed by a device (veth, tap, dummy...) are
+            // listed in `/sys/devices/virtual/net/`.
+            let is_virtual = std::fs::read_link(parent)
+                .map(|link| link.to_string_lossy().contains("/virtual/"))
+                .unwrap_or(false);
+            if is_virtual {
+                InterfaceKind::Virtual
+            } else {
+                InterfaceKind::Ethernet
+            }
+        }
+        libc::ARPHRD_IEEE80211 | libc::ARPHRD_IEEE80211_PRISM | libc::ARPHRD_IEEE80211_RADIOTAP => {
+            InterfaceKind::Wifi
+        }
+        libc::ARPHRD_PPP
+        | libc::ARPHRD_TUNNEL
+        | libc::ARPHRD_TUNNEL6
+        | libc::ARPHRD_SIT
+        | libc::ARPHRD_IPGRE
+        | libc::ARPHRD_NONE => InterfaceKind::Tunnel,
+        // `ARPHRD_RAWIP`, used by mobile broadband modems.
+        519 => InterfaceKind::Cellular,
+        _ => InterfaceKind::Other(if_type),
+    }
+}
+
 fn refresh_networks_list_from_sysfs(
     interfaces: &mut HashMap<String, NetworkData>,
     remove_not_listed_interfaces: bool,
     sysfs_net: &Path,
-) {
-    if let Ok(dir) = std::fs::read_dir(sysfs_net) {
-        let mut data = vec![0; 30];
+    now: Instant,
+    filter: &mut dyn FnMut(&str) -> bool,
+) -> Vec<RefreshError> {
+    let mut errors = Vec::new();
+    match std::fs::read_dir(sysfs_net) {
+        Ok(dir) => {
+            let mut data = vec![0; 30];
 
-        for stats in interfaces.values_mut() {
-            stats.inner.updated = false;
-        }
+            for stats in interfaces.values_mut() {
+                stats.inner.updated = false;
+            }
 
-        for entry in dir.flatten() {
-            let parent = &entry.path().join("statistics");
-            let entry_path = &entry.path();
-            let entry = match entry.file_name().into_string() {
-                Ok(entry) => entry,
-                Err(_) => continue,
-            };
-            let rx_bytes = read(parent, "rx_bytes", &mut data);
-            let tx_bytes = read(parent, "tx_bytes", &mut data);
-            let rx_packets = read(parent, "rx_packets", &mut data);
-            let tx_packets = read(parent, "tx_packets", &mut data);
-            let rx_errors = read(parent, "rx_errors", &mut data);
-            let tx_errors = read(parent, "tx_errors", &mut data);
-            // let rx_compressed = read(parent, "rx_compressed", &mut data);
-            // let tx_compressed = read(parent, "tx_compressed", &mut data);
-            let mtu = read(entry_path, "mtu", &mut data);
-
-            match interfaces.entry(entry) {
-                hash_map::Entry::Occupied(mut e) => {
-                    let interface = e.get_mut();
-                    let interface = &mut interface.inner;
-
-                    old_and_new!(interface, rx_bytes, old_rx_bytes);
-                    old_and_new!(interface, tx_bytes, old_tx_bytes);
-                    old_and_new!(interface, rx_packets, old_rx_packets);
-                    old_and_new!(interface, tx_packets, old_tx_packets);
-                    old_and_new!(interface, rx_errors, old_rx_errors);
-                    old_and_new!(interface, tx_errors, old_tx_errors);
-                    // old_and_new!(e, rx_compressed, old_rx_compressed);
-                    // old_and_new!(e, tx_compressed, old_tx_compressed);
-                    if interface.mtu != mtu {
-                        interface.mtu = mtu;
-                    }
-                    interface.updated = true;
-                }
-                hash_map::Entry::Vacant(e) => {
-                    e.insert(NetworkData {
-                        inner: NetworkDataInner {
-                            rx_bytes,
-                            old_rx_bytes: rx_bytes,
-                            tx_bytes,
-                            old_tx_bytes: tx_bytes,
-                            rx_packets,
-                            old_rx_packets: rx_packets,
-                            tx_packets,
-                            old_tx_packets: tx_packets,
-                            rx_errors,
-                            old_rx_errors: rx_errors,
-                            tx_errors,
-                            old_tx_errors: tx_errors,
-                            mac_addr: MacAddr::UNSPECIFIED,
-                            ip_networks: vec![],
-                            // rx_compressed,
-                            // old_rx_compressed: rx_compressed,
-                            // tx_compressed,
-                            // old_tx_compressed: tx_compressed,
-                            mtu,
-                            updated: true,
-                        },
-                    });
+            for entry in dir.flatten() {
+                let entry_path = &entry.path();
+                let entry = match entry.file_name().into_string() {
+                    Ok(entry) => entry,
+                    Err(_) => continue,
+                };
+                if filter(&entry) {
+                    refresh_interface_from_sysfs(
+                        interfaces,
+                        entry,
+                        entry_path,
+                        &mut data,
+                        now,
+                        &mut errors,
+                    );
                 }
-            };
+            }
         }
+        Err(error) => errors.push(RefreshError::new(
+            RefreshSubsystem::Networks,
+            sysfs_net.display().to_string(),
+            error,
+        )),
     }
     // We do this here because `refresh_networks_list_remove_interface` test is checking that
     // this is working as expected.
@@ -126,6 +218,387 @@ fn refresh_networks_list_from_sysfs(
             true
         });
     }
+    errors
+}
+
+/// Refreshes the `name` interface without going through the other ones. Returns `false` if it
+/// doesn't exist.
+fn refresh_interface_from_sysfs_dir(
+    interfaces: &mut HashMap<String, NetworkData>,
+    name: &str,
+    sysfs_net: &Path,
+    now: Instant,
+) -> bool {
+    // Prevents going outside of `sysfs_net`.
+    if name.is_empty() || name.contains('/') || name == "." || name == ".." {
+        return false;
+    }
+    let entry_path = sysfs_net.join(name);
+    if !entry_path.is_dir() {
+        return false;
+    }
+    let mut data = vec![0; 30];
+    refresh_interface_from_sysfs(
+        interfaces,
+        name.to_owned(),
+        &entry_path,
+        &mut data,
+        now,
+        &mut Vec::new(),
+    );
+    true
+}
+
+fn refresh_interface_from_sysfs(
+    interfaces: &mut HashMap<String, NetworkData>,
+    name: String,
+    entry_path: &Path,
+    data: &mut Vec<u8>,
+    now: Instant,
+    errors: &mut Vec<RefreshError>,
+) {
+    // The `statistics` files are generated from `rtnl_link_stats64`, so the counters are
+    // always 64-bit wide, even on 32-bit kernels.
+    let parent = &entry_path.join("statistics");
+    // If the first counter can't be read, the other ones can't either so only one error is
+    // reported for the interface.
+    let rx_bytes = try_read(parent, "rx_bytes", data).unwrap_or_else(|error| {
+        errors.push(RefreshError::new(
+            RefreshSubsystem::Networks,
+            parent.join("rx_bytes").display().to_string(),
+            error,
+        ));
+        0
+    });
+    let tx_bytes = read(parent, "tx_bytes", data);
+    let rx_packets = read(parent, "rx_packets", data);
+    let tx_packets = read(parent, "tx_packets", data);
+    let rx_errors = read(parent, "rx_errors", data);
+    let tx_errors = read(parent, "tx_errors", data);
+    let rx_dropped = read(parent, "rx_dropped", data);
+    let tx_dropped = read(parent, "tx_dropped", data);
+    let collisions = read(parent, "collisions", data);
+    let multicast = read(parent, "multicast", data);
+    // let rx_compressed = read(parent, "rx_compressed", data);
+    // let tx_compressed = read(parent, "tx_compressed", data);
+    let mtu = read(entry_path, "mtu", data);
+
+    match interfaces.entry(name) {
+        hash_map::Entry::Occupied(mut e) => {
+            let interface = e.get_mut();
+            let interface = &mut interface.inner;
+
+            old_and_new!(interface, rx_bytes, old_rx_bytes);
+            old_and_new!(interface, tx_bytes, old_tx_bytes);
+            old_and_new!(interface, rx_packets, old_rx_packets);
+            old_and_new!(interface, tx_packets, old_tx_packets);
+            old_and_new!(interface, rx_errors, old_rx_errors);
+            old_and_new!(interface, tx_errors, old_tx_errors);
+            old_and_new!(interface, rx_dropped, old_rx_dropped);
+            old_and_new!(interface, tx_dropped, old_tx_dropped);
+            old_and_new!(interface, collisions, old_collisions);
+            old_and_new!(interface, multicast, old_multicast);
+            // old_and_new!(e, rx_compressed, old_rx_compressed);
+            // old_and_new!(e, tx_compressed, old_tx_compressed);
+            if interface.mtu != mtu {
+                interface.mtu = mtu;
+            }
+            // The interface can be brought up or down at any time.
+            interface.flags = read_flags(entry_path, data);
+            // An interface can be added to (or removed from) a bond or a bridge at any time.
+            interface.master = read_link_name(&entry_path.join("master"));
+            interface.refresh_interval =
+                Some(now.saturating_duration_since(interface.last_refresh));
+            interface.last_refresh = now;
+            interface.updated = true;
+        }
+        hash_map::Entry::Vacant(e) => {
+            let flags = read_flags(entry_path, data);
+            let kind = read_kind(entry_path, data);
+            let index = read(entry_path, "ifindex", data) as u32;
+            let friendly_name = read_alias(entry_path);
+            let master = read_link_name(&entry_path.join("master"));
+            let lower_device = read_lower_device(entry_path, e.key());
+            // `/proc/net/vlan/config` is only readable by root.
+            let vlan_id = if read_devtype(entry_path).as_deref() == Some("vlan") {
+                std::fs::read_to_string("/proc/net/vlan/config")
+                    .ok()
+                    .and_then(|content| parse_vlan_config(&content, e.key()))
+            } else {
+                None
+            };
+            e.insert(NetworkData {
+                inner: NetworkDataInner {
+                    rx_bytes,
+                    old_rx_bytes: rx_bytes,
+                    tx_bytes,
+                    old_tx_bytes: tx_bytes,
+                    rx_packets,
+                    old_rx_packets: rx_packets,
+                    tx_packets,
+                    old_tx_packets: tx_packets,
+                    rx_errors,
+                    old_rx_errors: rx_errors,
+                    tx_errors,
+                    old_tx_errors: tx_errors,
+                    rx_dropped,
+                    old_rx_dropped: rx_dropped,
+                    tx_dropped,
+                    old_tx_dropped: tx_dropped,
+                    collisions,
+                    old_collisions: collisions,
+                    multicast,
+                    old_multicast: multicast,
+                    mac_addr: MacAddr::UNSPECIFIED,
+                    ip_networks: vec![],
+                    // rx_compressed,
+                    // old_rx_compressed: rx_compressed,
+                    // tx_compressed,
+                    // old_tx_compressed: tx_compressed,
+                    mtu,
+                    flags,
+                    kind,
+                    wifi_info: None,
+                    index,
+                    friendly_name,
+                    master,
+                    lower_device,
+                    vlan_id,
+                    last_refresh: now,
+                    refresh_interval: None,
+                    updated: true,
+                },
+            });
+        }
+    };
+}
+
+// Wireless extensions types and requests from `<linux/wireless.h>`.
+const SIOCGIWFREQ: u32 = 0x8B05;
+const SIOCGIWAP: u32 = 0x8B15;
+const SIOCGIWESSID: u32 = 0x8B1B;
+const IW_ESSID_MAX_SIZE: usize = 32;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct iw_point {
+    pointer: *mut libc::c_void,
+    length: u16,
+    flags: u16,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct iw_freq {
+    m: i32,
+    e: i16,
+    i: u8,
+    flags: u8,
+}
+
+#[repr(C)]
+union iwreq_data {
+    essid: iw_point,
+    ap_addr: libc::sockaddr,
+    freq: iw_freq,
+}
+
+#[repr(C)]
+struct iwreq {
+    ifr_name: [libc::c_char; libc::IFNAMSIZ],
+    u: iwreq_data,
+}
+
+impl iwreq {
+    fn new(name: &str) -> Option<Self> {
+        if name.len() >= libc::IFNAMSIZ {
+            return None;
+        }
+        // SAFETY: all fields can be zero-initialized.
+        let mut req: Self = unsafe { std::mem::zeroed() };
+        for (dest, src) in req.ifr_name.iter_mut().zip(name.as_bytes()) {
+            *dest = *src as _;
+        }
+        Some(req)
+    }
+}
+
+/// Converts a Wi-Fi frequency (in MHz) into its channel number.
+fn frequency_to_channel(frequency: u32) -> Option<u32> {
+    match frequency {
+        2484 => Some(14),
+        2412..=2472 => Some((frequency - 2407) / 5),
+        5955..=7115 => Some((frequency - 5950) / 5),
+        5160..=5885 => Some((frequency - 5000) / 5),
+        _ => None,
+    }
+}
+
+/// Parses `/proc/net/wireless` content and returns the signal level (in dBm) of each interface.
+fn parse_proc_net_wireless(content: &str) -> HashMap<&str, i32> {
+    content
+        .lines()
+        // The first two lines are headers.
+        .skip(2)
+        .filter_map(|line| {
+            let (name, values) = line.split_once(':')?;
+            // Values are: status, link quality, signal level, noise level...
+            let level = values.split_whitespace().nth(2)?;
+            let level = level.trim_end_matches('.').parse::<f32>().ok()?;
+            Some((name.trim(), level as i32))
+        })
+        .collect()
+}
+
+const RTF_UP: u32 = 0x0001;
+const RTF_GATEWAY: u32 = 0x0002;
+
+/// Parses the default routes from the content of `/proc/net/route`.
+fn parse_ipv4_routes(content: &str) -> Vec<RouteInfo> {
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            let interface = fields.next()?;
+            let destination = u32::from_str_radix(fields.next()?, 16).ok()?;
+            // The gateway is printed as a 32-bit word in host byte order.
+            let gateway = u32::from_str_radix(fields.next()?, 16).ok()?;
+            let flags = u32::from_str_radix(fields.next()?, 16).ok()?;
+            let metric = fields.nth(2)?.parse().ok()?;
+            let mask = u32::from_str_radix(fields.next()?, 16).ok()?;
+            if destination != 0
+                || mask != 0
+                || flags & (RTF_UP | RTF_GATEWAY) != RTF_UP | RTF_GATEWAY
+            {
+                return None;
+            }
+            Some(RouteInfo {
+                gateway: IpAddr::V4(Ipv4Addr::from(gateway.to_ne_bytes())),
+                interface: interface.to_owned(),
+                metric,
+            })
+        })
+        .collect()
+}
+
+/// Parses the default routes from the content of `/proc/net/ipv6_route`.
+fn parse_ipv6_routes(content: &str) -> Vec<RouteInfo> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let &[destination, prefix, _, _, next_hop, metric, _, _, flags, interface] =
+                fields.as_slice()
+            else {
+                return None;
+            };
+            let flags = u32::from_str_radix(flags, 16).ok()?;
+            if u128::from_str_radix(destination, 16).ok()? != 0
+                || prefix != "00"
+                || flags & RTF_UP == 0
+            {
+                return None;
+            }
+            let gateway = Ipv6Addr::from(u128::from_str_radix(next_hop, 16).ok()?);
+            if gateway.is_unspecified() {
+                return None;
+            }
+            Some(RouteInfo {
+                gateway: IpAddr::V6(gateway),
+                interface: interface.to_owned(),
+                metric: u32::from_str_radix(metric, 16).ok()?,
+            })
+        })
+        .collect()
+}
+
+unsafe fn get_wifi_info(socket: libc::c_int, name: &str, signal: Option<i32>) -> WifiInfo {
+    let mut info = WifiInfo {
+        signal,
+        ..Default::default()
+    };
+
+    if let Some(mut req) = iwreq::new(name) {
+        let mut essid = [0u8; IW_ESSID_MAX_SIZE + 1];
+        req.u.essid = iw_point {
+            pointer: essid.as_mut_ptr() as _,
+            length: essid.len() as _,
+            flags: 0,
+        };
+        if libc::ioctl(socket, SIOCGIWESSID as _, &mut req) == 0 {
+            let len = (req.u.essid.length as usize).min(IW_ESSID_MAX_SIZE);
+            if len > 0 {
+                info.ssid = Some(String::from_utf8_lossy(&essid[..len]).into_owned());
+            }
+        }
+    }
+    if let Some(mut req) = iwreq::new(name) {
+        if libc::ioctl(socket, SIOCGIWAP as _, &mut req) == 0 {
+            let data = req.u.ap_addr.sa_data;
+            let addr = MacAddr([
+                data[0] as _,
+                data[1] as _,
+                data[2] as _,
+                data[3] as _,
+                data[4] as _,
+                data[5] as _,
+            ]);
+            if !addr.is_unspecified() {
+                info.bssid = Some(addr);
+            }
+        }
+    }
+    if let Some(mut req) = iwreq::new(name) {
+        if libc::ioctl(socket, SIOCGIWFREQ as _, &mut req) == 0 {
+            let freq = req.u.freq;
+            if freq.e == 0 && freq.m > 0 && freq.m < 1000 {
+                // It's a channel number.
+                info.channel = Some(freq.m as _);
+            } else if freq.m > 0 {
+                // The frequency is `m * 10^e` Hz.
+                let frequency = freq.m as f64 * 10f64.powi(freq.e as _) / 1_000_000.;
+                info.frequency = Some(frequency as _);
+                info.channel = frequency_to_channel(frequency as _);
+            }
+        }
+    }
+    info
+}
+
+fn refresh_wifi_info(interfaces: &mut HashMap<String, NetworkData>) {
+    if interfaces
+        .values()
+        .all(|interface| interface.inner.kind != InterfaceKind::Wifi)
+    {
+        return;
+    }
+    let wireless = std::fs::read_to_string("/proc/net/wireless").unwrap_or_default();
+    let signals = parse_proc_net_wireless(&wireless);
+
+    unsafe {
+        let socket = libc::socket(libc::AF_INET, libc::SOCK_DGRAM, 0);
+        if socket < 0 {
+            sysinfo_debug!("Failed to create socket to get Wi-Fi information");
+        }
+        for (name, interface) in interfaces.iter_mut() {
+            if interface.inner.kind != InterfaceKind::Wifi {
+                continue;
+            }
+            let signal = signals.get(name.as_str()).copied();
+            interface.inner.wifi_info = if socket < 0 {
+                Some(WifiInfo {
+                    signal,
+                    ..Default::default()
+                })
+            } else {
+                Some(get_wifi_info(socket, name, signal))
+            };
+        }
+        if socket >= 0 {
+            libc::close(socket);
+        }
+    }
 }
 
 pub(crate) struct NetworksInner {
@@ -143,13 +616,90 @@ impl NetworksInner {
         &self.interfaces
     }
 
-    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
-        refresh_networks_list_from_sysfs(
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) {
+        self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        let errors =
+            self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    pub(crate) fn refresh_list_filtered(&mut self, filter: &mut dyn FnMut(&str) -> bool) {
+        self.refresh_filtered(true, NetworksRefreshKind::everything(), filter);
+    }
+
+    fn refresh_filtered(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+        filter: &mut dyn FnMut(&str) -> bool,
+    ) -> Vec<RefreshError> {
+        let errors = refresh_networks_list_from_sysfs(
             &mut self.interfaces,
             remove_not_listed_interfaces,
             Path::new("/sys/class/net/"),
+            Instant::now(),
+            filter,
         );
-        refresh_networks_addresses(&mut self.interfaces);
+        if refresh_kind.addresses() {
+            refresh_networks_addresses(&mut self.interfaces);
+        }
+        if refresh_kind.wifi_info() {
+            refresh_wifi_info(&mut self.interfaces);
+        }
+        errors
+    }
+
+    pub(crate) fn refresh_interface(&mut self, name: &str) -> bool {
+        refresh_interface_from_sysfs_dir(
+            &mut self.interfaces,
+            name,
+            Path::new("/sys/class/net/"),
+            Instant::now(),
+        )
+    }
+
+    pub(crate) fn refresh_interfaces(&mut self, names: &[&str]) -> usize {
+        let now = Instant::now();
+        names
+            .iter()
+            .filter(|name| {
+                refresh_interface_from_sysfs_dir(
+                    &mut self.interfaces,
+                    name,
+                    Path::new("/sys/class/net/"),
+                    now,
+                )
+            })
+            .count()
+    }
+
+    pub(crate) fn default_routes(&self) -> Vec<RouteInfo> {
+        let mut routes = std::fs::read_to_string("/proc/net/route")
+            .map(|content| parse_ipv4_routes(&content))
+            .unwrap_or_default();
+        if let Ok(content) = std::fs::read_to_string("/proc/net/ipv6_route") {
+            routes.extend(parse_ipv6_routes(&content));
+        }
+        routes
+    }
+
+    pub(crate) fn dns_servers(&self) -> Vec<IpAddr> {
+        get_dns_servers()
     }
 }
 
@@ -174,11 +724,33 @@ pub(crate) struct NetworkDataInner {
     /// similar to `rx_errors`
     tx_errors: u64,
     old_tx_errors: u64,
+    /// Total number of packets received but dropped (because of a lack of buffer space for
+    /// example).
+    rx_dropped: u64,
+    old_rx_dropped: u64,
+    /// similar to `rx_dropped`
+    tx_dropped: u64,
+    old_tx_dropped: u64,
+    /// Total number of collisions during packets transmission.
+    collisions: u64,
+    old_collisions: u64,
+    /// Total number of multicast packets received.
+    multicast: u64,
+    old_multicast: u64,
     /// MAC address
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Interface flags (`IFF_*`).
+    flags: u32,
+    kind: InterfaceKind,
+    pub(crate) wifi_info: Option<WifiInfo>,
+    index: u32,
+    friendly_name: Option<String>,
+    master: Option<String>,
+    lower_device: Option<String>,
+    vlan_id: Option<u16>,
     // /// Indicates the number of compressed packets received by this
     // /// network device. This value might only be relevant for interfaces
     // /// that support packet compression (e.g: PPP).
@@ -189,6 +761,10 @@ pub(crate) struct NetworkDataInner {
     // /// compression (e.g: PPP).
     // tx_compressed: usize,
     // old_tx_compressed: usize,
+    /// When the above data was last refreshed.
+    last_refresh: Instant,
+    /// Time elapsed between the last two refreshes.
+    refresh_interval: Option<Duration>,
     /// Whether or not the above data has been updated during refresh
     updated: bool,
 }
@@ -242,6 +818,38 @@ impl NetworkDataInner {
         self.tx_errors
     }
 
+    pub(crate) fn dropped_packets_received(&self) -> u64 {
+        self.rx_dropped.saturating_sub(self.old_rx_dropped)
+    }
+
+    pub(crate) fn total_dropped_packets_received(&self) -> u64 {
+        self.rx_dropped
+    }
+
+    pub(crate) fn dropped_packets_transmitted(&self) -> u64 {
+        self.tx_dropped.saturating_sub(self.old_tx_dropped)
+    }
+
+    pub(crate) fn total_dropped_packets_transmitted(&self) -> u64 {
+        self.tx_dropped
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        self.collisions.saturating_sub(self.old_collisions)
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        self.collisions
+    }
+
+    pub(crate) fn multicast_received(&self) -> u64 {
+        self.multicast.saturating_sub(self.old_multicast)
+    }
+
+    pub(crate) fn total_multicast_received(&self) -> u64 {
+        self.multicast
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -253,13 +861,71 @@ impl NetworkDataInner {
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_interval
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.flags & libc::IFF_LOOPBACK as u32 != 0
+    }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.flags & libc::IFF_UP as u32 != 0
+    }
+
+    pub(crate) fn is_point_to_point(&self) -> bool {
+        self.flags & libc::IFF_POINTOPOINT as u32 != 0
+    }
+
+    pub(crate) fn supports_multicast(&self) -> bool {
+        self.flags & libc::IFF_MULTICAST as u32 != 0
+    }
+
+    pub(crate) fn supports_broadcast(&self) -> bool {
+        self.flags & libc::IFF_BROADCAST as u32 != 0
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn wifi_info(&self) -> Option<WifiInfo> {
+        self.wifi_info.clone()
+    }
+
+    pub(crate) fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub(crate) fn friendly_name(&self) -> Option<&str> {
+        self.friendly_name.as_deref()
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn lower_device(&self) -> Option<&str> {
+        self.lower_device.as_deref()
+    }
+
+    pub(crate) fn vlan_id(&self) -> Option<u16> {
+        self.vlan_id
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::refresh_networks_list_from_sysfs;
+    use super::{
+        frequency_to_channel, parse_ipv4_routes, parse_ipv6_routes, parse_proc_net_wireless,
+        parse_vlan_config, refresh_interface_from_sysfs_dir, refresh_networks_list_from_sysfs,
+    };
+    use crate::{RefreshSubsystem, RouteInfo};
     use std::collections::HashMap;
     use std::fs;
+    use std::net::{IpAddr, Ipv4Addr};
+    use std::time::{Duration, Instant};
 
     #[test]
     fn refresh_networks_list_add_interface() {
@@ -269,17 +935,72 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf1"]);
 
         fs::create_dir(sys_net_dir.path().join("itf2")).expect("failed to create subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
     }
 
+    #[test]
+    fn refresh_networks_list_errors() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = sys_net_dir.path();
+
+        fs::create_dir_all(path.join("eth0/statistics")).expect("failed to create subdirectory");
+        fs::write(path.join("eth0/statistics/rx_bytes"), "42\n").expect("failed to write file");
+        // `eth1` has no statistics.
+        fs::create_dir(path.join("eth1")).expect("failed to create subdirectory");
+
+        let mut interfaces = HashMap::new();
+
+        let errors = refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            path,
+            Instant::now(),
+            &mut |_| true,
+        );
+        // Both interfaces are still listed.
+        assert_eq!(interfaces.len(), 2);
+        assert_eq!(interfaces["eth0"].total_received(), 42);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].subsystem(), RefreshSubsystem::Networks);
+        assert_eq!(
+            errors[0].origin(),
+            path.join("eth1/statistics/rx_bytes").display().to_string()
+        );
+        assert_eq!(errors[0].io_error().kind(), std::io::ErrorKind::NotFound);
+
+        let errors = refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            &path.join("missing"),
+            Instant::now(),
+            &mut |_| true,
+        );
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].raw_os_error(), Some(libc::ENOENT));
+        // The interfaces are kept.
+        assert_eq!(interfaces.len(), 2);
+    }
+
     #[test]
     fn refresh_networks_list_remove_interface() {
         let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
@@ -291,14 +1012,429 @@ mod test {
 
         let mut interfaces = HashMap::new();
 
-        refresh_networks_list_from_sysfs(&mut interfaces, false, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
         let mut itf_names: Vec<String> = interfaces.keys().map(|n| n.to_owned()).collect();
         itf_names.sort();
         assert_eq!(itf_names, ["itf1", "itf2"]);
 
         fs::remove_dir(&itf1_dir).expect("failed to remove subdirectory");
 
-        refresh_networks_list_from_sysfs(&mut interfaces, true, sys_net_dir.path());
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            true,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
         assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["itf2"]);
     }
+
+    #[test]
+    fn refresh_networks_list_filtered() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        for name in ["eth0", "veth1", "veth2"] {
+            fs::create_dir(sys_net_dir.path().join(name)).expect("failed to create subdirectory");
+        }
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
+        assert_eq!(interfaces.len(), 3);
+
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            true,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |name| !name.starts_with("veth"),
+        );
+        assert_eq!(interfaces.keys().collect::<Vec<_>>(), ["eth0"]);
+    }
+
+    #[test]
+    fn refresh_single_interface() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let eth_stats = sys_net_dir.path().join("eth0/statistics");
+        let veth_stats = sys_net_dir.path().join("veth0/statistics");
+        fs::create_dir_all(&eth_stats).expect("failed to create subdirectory");
+        fs::create_dir_all(&veth_stats).expect("failed to create subdirectory");
+        fs::write(eth_stats.join("rx_bytes"), "100\n").expect("failed to write rx_bytes");
+        fs::write(veth_stats.join("rx_bytes"), "10\n").expect("failed to write rx_bytes");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
+
+        fs::write(eth_stats.join("rx_bytes"), "150\n").expect("failed to write rx_bytes");
+        fs::write(veth_stats.join("rx_bytes"), "30\n").expect("failed to write rx_bytes");
+
+        assert!(refresh_interface_from_sysfs_dir(
+            &mut interfaces,
+            "eth0",
+            sys_net_dir.path(),
+            Instant::now(),
+        ));
+        assert_eq!(interfaces["eth0"].total_received(), 150);
+        assert_eq!(interfaces["eth0"].received(), 50);
+        // The other interfaces must not be updated.
+        assert_eq!(interfaces["veth0"].total_received(), 10);
+
+        assert!(!refresh_interface_from_sysfs_dir(
+            &mut interfaces,
+            "eth1",
+            sys_net_dir.path(),
+            Instant::now(),
+        ));
+        assert!(!refresh_interface_from_sysfs_dir(
+            &mut interfaces,
+            "../eth0",
+            sys_net_dir.path(),
+            Instant::now(),
+        ));
+        assert_eq!(interfaces.len(), 2);
+    }
+
+    #[test]
+    fn refresh_networks_list_relationships() {
+        use std::os::unix::fs::symlink;
+
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = sys_net_dir.path();
+
+        for name in ["eth0", "eth1", "bond0", "eth1.10"] {
+            fs::create_dir(path.join(name)).expect("failed to create subdirectory");
+        }
+        symlink("../bond0", path.join("eth0/master")).expect("failed to create symlink");
+        symlink("../eth0", path.join("bond0/lower_eth0")).expect("failed to create symlink");
+        symlink("../eth1", path.join("eth1.10/lower_eth1")).expect("failed to create symlink");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, path, Instant::now(), &mut |_| {
+            true
+        });
+        assert_eq!(interfaces["eth0"].master(), Some("bond0"));
+        assert_eq!(interfaces["eth0"].lower_device(), None);
+        // The enslaved interfaces aren't lower devices.
+        assert_eq!(interfaces["bond0"].master(), None);
+        assert_eq!(interfaces["bond0"].lower_device(), None);
+        assert_eq!(interfaces["eth1"].master(), None);
+        assert_eq!(interfaces["eth1.10"].lower_device(), Some("eth1"));
+
+        symlink("../bond0", path.join("eth1/master")).expect("failed to create symlink");
+        fs::remove_file(path.join("eth0/master")).expect("failed to remove symlink");
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, path, Instant::now(), &mut |_| {
+            true
+        });
+        assert_eq!(interfaces["eth0"].master(), None);
+        assert_eq!(interfaces["eth1"].master(), Some("bond0"));
+    }
+
+    #[test]
+    fn refresh_networks_list_index_and_alias() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = sys_net_dir.path();
+
+        for name in ["eth0", "eth1"] {
+            fs::create_dir(path.join(name)).expect("failed to create subdirectory");
+        }
+        fs::write(path.join("eth0/ifindex"), "2\n").expect("failed to write ifindex");
+        fs::write(path.join("eth0/ifalias"), "uplink\n").expect("failed to write ifalias");
+        fs::write(path.join("eth1/ifindex"), "3\n").expect("failed to write ifindex");
+        // No alias set.
+        fs::write(path.join("eth1/ifalias"), "\n").expect("failed to write ifalias");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(&mut interfaces, false, path, Instant::now(), &mut |_| {
+            true
+        });
+        assert_eq!(interfaces["eth0"].index(), 2);
+        assert_eq!(interfaces["eth0"].friendly_name(), Some("uplink"));
+        assert_eq!(interfaces["eth1"].index(), 3);
+        assert_eq!(interfaces["eth1"].friendly_name(), None);
+    }
+
+    #[test]
+    fn check_parse_vlan_config() {
+        let content = "\
+VLAN Dev name    | VLAN ID
+Name-Type: VLAN_NAME_TYPE_RAW_PLUS_VID_NO_PAD
+eth0.100       | 100  | eth0
+vlan42         | 42  | eth1
+";
+        assert_eq!(parse_vlan_config(content, "eth0.100"), Some(100));
+        assert_eq!(parse_vlan_config(content, "vlan42"), Some(42));
+        assert_eq!(parse_vlan_config(content, "eth0"), None);
+        assert_eq!(parse_vlan_config("", "eth0.100"), None);
+    }
+
+    #[test]
+    fn refresh_networks_list_flags() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let lo_dir = sys_net_dir.path().join("lo");
+        let eth_dir = sys_net_dir.path().join("eth0");
+        fs::create_dir(&lo_dir).expect("failed to create subdirectory");
+        fs::create_dir(&eth_dir).expect("failed to create subdirectory");
+        fs::write(lo_dir.join("flags"), "0x9\n").expect("failed to write flags");
+        fs::write(eth_dir.join("flags"), "0x1003\n").expect("failed to write flags");
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
+        let lo = &interfaces["lo"].inner;
+        assert!(lo.is_loopback());
+        assert!(!lo.is_point_to_point());
+        assert!(!lo.supports_broadcast());
+        assert!(!lo.supports_multicast());
+        let eth = &interfaces["eth0"].inner;
+        assert!(!eth.is_loopback());
+        assert!(!eth.is_point_to_point());
+        assert!(eth.supports_broadcast());
+        assert!(eth.supports_multicast());
+    }
+
+    #[test]
+    fn refresh_networks_list_extended_counters() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let stats_dir = sys_net_dir.path().join("eth0").join("statistics");
+        fs::create_dir_all(&stats_dir).expect("failed to create subdirectory");
+        let write_stats = |rx_dropped, tx_dropped, collisions, multicast| {
+            for (name, value) in [
+                ("rx_dropped", rx_dropped),
+                ("tx_dropped", tx_dropped),
+                ("collisions", collisions),
+                ("multicast", multicast),
+            ] {
+                fs::write(stats_dir.join(name), format!("{value}\n"))
+                    .expect("failed to write statistics");
+            }
+        };
+
+        let mut interfaces = HashMap::new();
+
+        write_stats(1, 2, 3, 4);
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
+        let eth = &interfaces["eth0"].inner;
+        assert_eq!(eth.total_dropped_packets_received(), 1);
+        assert_eq!(eth.total_dropped_packets_transmitted(), 2);
+        assert_eq!(eth.total_collisions(), 3);
+        assert_eq!(eth.total_multicast_received(), 4);
+        assert_eq!(eth.dropped_packets_received(), 0);
+
+        write_stats(11, 12, 13, 14);
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
+        let eth = &interfaces["eth0"].inner;
+        assert_eq!(eth.dropped_packets_received(), 10);
+        assert_eq!(eth.dropped_packets_transmitted(), 10);
+        assert_eq!(eth.collisions(), 10);
+        assert_eq!(eth.multicast_received(), 10);
+        assert_eq!(eth.total_multicast_received(), 14);
+    }
+
+    #[test]
+    fn refresh_networks_list_refresh_interval() {
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let stats_dir = sys_net_dir.path().join("eth0").join("statistics");
+        fs::create_dir_all(&stats_dir).expect("failed to create subdirectory");
+        fs::write(stats_dir.join("rx_bytes"), "1000\n").expect("failed to write statistics");
+
+        let mut interfaces = HashMap::new();
+        let now = Instant::now();
+
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            now,
+            &mut |_| true,
+        );
+        assert_eq!(interfaces["eth0"].inner.refresh_interval(), None);
+        assert_eq!(interfaces["eth0"].received_bytes_per_sec(), None);
+
+        fs::write(stats_dir.join("rx_bytes"), "3000\n").expect("failed to write statistics");
+        let now = now + Duration::from_millis(500);
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            now,
+            &mut |_| true,
+        );
+        assert_eq!(
+            interfaces["eth0"].inner.refresh_interval(),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(interfaces["eth0"].received_bytes_per_sec(), Some(4000.));
+        assert_eq!(interfaces["eth0"].transmitted_bytes_per_sec(), Some(0.));
+
+        // The counter was reset (interface re-created for example).
+        fs::write(stats_dir.join("rx_bytes"), "10\n").expect("failed to write statistics");
+        let now = now + Duration::from_secs(1);
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            now,
+            &mut |_| true,
+        );
+        assert_eq!(interfaces["eth0"].received_bytes_per_sec(), Some(0.));
+    }
+
+    #[test]
+    fn refresh_networks_list_kind() {
+        use crate::InterfaceKind;
+
+        let sys_net_dir = tempfile::tempdir().expect("failed to create temporary directory");
+
+        let create_itf = |name: &str, if_type: &str, devtype: Option<&str>| {
+            let dir = sys_net_dir.path().join(name);
+            fs::create_dir(&dir).expect("failed to create subdirectory");
+            fs::write(dir.join("type"), if_type).expect("failed to write type");
+            if let Some(devtype) = devtype {
+                fs::write(
+                    dir.join("uevent"),
+                    format!("DEVTYPE={devtype}\nINTERFACE={name}\nIFINDEX=2\n"),
+                )
+                .expect("failed to write uevent");
+            }
+            dir
+        };
+        create_itf("lo", "772\n", None);
+        create_itf("eth0", "1\n", None);
+        create_itf("wlan0", "1\n", Some("wlan"));
+        let wlan1 = create_itf("wlan1", "1\n", None);
+        fs::create_dir(wlan1.join("phy80211")).expect("failed to create subdirectory");
+        create_itf("br0", "1\n", Some("bridge"));
+        create_itf("tun0", "65534\n", None);
+        create_itf("can0", "280\n", None);
+
+        let mut interfaces = HashMap::new();
+
+        refresh_networks_list_from_sysfs(
+            &mut interfaces,
+            false,
+            sys_net_dir.path(),
+            Instant::now(),
+            &mut |_| true,
+        );
+        assert_eq!(interfaces["lo"].inner.kind(), InterfaceKind::Loopback);
+        assert_eq!(interfaces["eth0"].inner.kind(), InterfaceKind::Ethernet);
+        assert_eq!(interfaces["wlan0"].inner.kind(), InterfaceKind::Wifi);
+        assert_eq!(interfaces["wlan1"].inner.kind(), InterfaceKind::Wifi);
+        assert_eq!(interfaces["br0"].inner.kind(), InterfaceKind::Bridge);
+        assert_eq!(interfaces["tun0"].inner.kind(), InterfaceKind::Tunnel);
+        assert_eq!(interfaces["can0"].inner.kind(), InterfaceKind::Other(280));
+    }
+
+    #[test]
+    fn check_parse_proc_net_wireless() {
+        let content = "\
+Inter-| sta-|   Quality        |   Discarded packets               | Missed | WE
+ face | tus | link level noise |  nwid  crypt   frag  retry   misc | beacon | 22
+wlp2s0: 0000   54.  -56.  -256        0      0      0      0     12        0
+ wlan1: 0000   70.  -40.  -256        0      0      0      0      0        0
+";
+        let signals = parse_proc_net_wireless(content);
+        assert_eq!(signals.len(), 2);
+        assert_eq!(signals.get("wlp2s0"), Some(&-56));
+        assert_eq!(signals.get("wlan1"), Some(&-40));
+    }
+
+    #[test]
+    fn check_frequency_to_channel() {
+        assert_eq!(frequency_to_channel(2412), Some(1));
+        assert_eq!(frequency_to_channel(2484), Some(14));
+        assert_eq!(frequency_to_channel(5180), Some(36));
+        assert_eq!(frequency_to_channel(5955), Some(1));
+        assert_eq!(frequency_to_channel(900), None);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn check_parse_ipv4_routes() {
+        let content = "\
+Iface\tDestination\tGateway \tFlags\tRefCnt\tUse\tMetric\tMask\t\tMTU\tWindow\tIRTT
+eth0\t00000000\t0101A8C0\t0003\t0\t0\t100\t00000000\t0\t0\t0
+eth0\t0001A8C0\t00000000\t0001\t0\t0\t100\t00FFFFFF\t0\t0\t0
+wlan0\t00000000\t01000A0A\t0003\t0\t0\t600\t00000000\t0\t0\t0
+tun0\t00000000\t00000000\t0001\t0\t0\t0\t00000000\t0\t0\t0
+";
+        let routes = parse_ipv4_routes(content);
+        assert_eq!(
+            routes,
+            [
+                RouteInfo {
+                    gateway: IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)),
+                    interface: "eth0".to_owned(),
+                    metric: 100,
+                },
+                RouteInfo {
+                    gateway: IpAddr::V4(Ipv4Addr::new(10, 10, 0, 1)),
+                    interface: "wlan0".to_owned(),
+                    metric: 600,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn check_parse_ipv6_routes() {
+        let content = "\
+fe800000000000000000000000000000 40 00000000000000000000000000000000 00 00000000000000000000000000000000 00000100 00000001 00000000 00000001     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 fe800000000000000000000000000001 00000400 00000001 00000000 00450003     eth0
+00000000000000000000000000000000 00 00000000000000000000000000000000 00 00000000000000000000000000000000 ffffffff 00000001 00000000 00200200       lo
+";
+        let routes = parse_ipv6_routes(content);
+        assert_eq!(
+            routes,
+            [RouteInfo {
+                gateway: "fe80::1".parse().unwrap(),
+                interface: "eth0".to_owned(),
+                metric: 1024,
+            }]
+        );
+    }
 }
@@ -5,26 +5,28 @@ use std::collections::{HashMap, HashSet};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fs::{self, DirEntry, File};
-use std::io::Read;
+use std::io::{self, Read};
 use std::os::unix::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::process::ExitStatus;
 use std::str::{self, FromStr};
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use libc::{c_ulong, gid_t, uid_t};
 
-use crate::sys::system::SystemInfo;
+use crate::common::field_status::FieldStatuses;
+use crate::sys::system::{SystemInfo, SystemInner};
 use crate::sys::utils::{
-    get_all_data_from_file, get_all_utf8_data, realpath, PathHandler, PathPush,
+    get_all_data_from_file, get_all_utf8_data, realpath, FileCounter, PathHandler, PathPush,
 };
 use crate::{
-    DiskUsage, Gid, Pid, Process, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal,
+    DiskUsage, FdUsage, FieldStatus, Gid, IoPriority, IoPriorityClass, IoPriorityError, Namespaces,
+    Pid, Process, ProcessField, ProcessRefreshKind, ProcessStatus, ProcessesToUpdate, Signal,
     ThreadKind, Uid,
 };
 
-use crate::sys::system::remaining_files;
-
 #[doc(hidden)]
 impl From<char> for ProcessStatus {
     fn from(status: char) -> ProcessStatus {
@@ -95,11 +97,13 @@ enum ProcIndex {
 
 pub(crate) struct ProcessInner {
     pub(crate) name: OsString,
+    comm: String,
     pub(crate) cmd: Vec<OsString>,
     pub(crate) exe: Option<PathBuf>,
     pub(crate) pid: Pid,
     parent: Option<Pid>,
     pub(crate) environ: Vec<OsString>,
+    environ_read: bool,
     pub(crate) cwd: Option<PathBuf>,
     pub(crate) root: Option<PathBuf>,
     pub(crate) memory: u64,
@@ -111,6 +115,7 @@ pub(crate) struct ProcessInner {
     start_time_without_boot_time: u64,
     start_time: u64,
     start_time_raw: u64,
+    start_time_precise: Duration,
     run_time: u64,
     pub(crate) updated: bool,
     cpu_usage: f32,
@@ -118,6 +123,8 @@ pub(crate) struct ProcessInner {
     effective_user_id: Option<Uid>,
     group_id: Option<Gid>,
     effective_group_id: Option<Gid>,
+    umask: Option<u32>,
+    ns_pid: Option<Pid>,
     pub(crate) status: ProcessStatus,
     pub(crate) tasks: Option<HashSet<Pid>>,
     stat_file: Option<FileCounter>,
@@ -128,17 +135,27 @@ pub(crate) struct ProcessInner {
     thread_kind: Option<ThreadKind>,
     proc_path: PathBuf,
     accumulated_cpu_time: u64,
+    user_cpu_time: Duration,
+    system_cpu_time: Duration,
+    thread_count: Option<usize>,
+    fd_usage: Option<FdUsage>,
+    namespaces: Option<Namespaces>,
+    field_statuses: FieldStatuses,
     exists: bool,
+    // Once the process was reaped, its exit status cannot be retrieved anymore.
+    exit_status: OnceLock<ExitStatus>,
 }
 
 impl ProcessInner {
     pub(crate) fn new(pid: Pid, proc_path: PathBuf) -> Self {
         Self {
             name: OsString::new(),
+            comm: String::new(),
             pid,
             parent: None,
             cmd: Vec::new(),
             environ: Vec::new(),
+            environ_read: false,
             exe: None,
             cwd: None,
             root: None,
@@ -153,11 +170,14 @@ impl ProcessInner {
             start_time_without_boot_time: 0,
             start_time: 0,
             start_time_raw: 0,
+            start_time_precise: Duration::ZERO,
             run_time: 0,
             user_id: None,
             effective_user_id: None,
             group_id: None,
             effective_group_id: None,
+            umask: None,
+            ns_pid: None,
             status: ProcessStatus::Unknown(0),
             tasks: None,
             stat_file: None,
@@ -168,7 +188,14 @@ impl ProcessInner {
             thread_kind: None,
             proc_path,
             accumulated_cpu_time: 0,
+            user_cpu_time: Duration::ZERO,
+            system_cpu_time: Duration::ZERO,
+            thread_count: None,
+            fd_usage: None,
+            namespaces: None,
+            field_statuses: FieldStatuses::default(),
             exists: true,
+            exit_status: OnceLock::new(),
         }
     }
 
@@ -181,10 +208,18 @@ impl ProcessInner {
         &self.name
     }
 
+    pub(crate) fn comm(&self) -> &str {
+        &self.comm
+    }
+
     pub(crate) fn cmd(&self) -> &[OsString] {
         &self.cmd
     }
 
+    pub(crate) fn field_status(&self, field: ProcessField) -> FieldStatus {
+        self.field_statuses.get(field)
+    }
+
     pub(crate) fn exe(&self) -> Option<&Path> {
         self.exe.as_deref()
     }
@@ -225,6 +260,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_precise(&self) -> SystemTime {
+        UNIX_EPOCH + self.start_time_precise
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -237,6 +276,18 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn user_cpu_time(&self) -> Duration {
+        self.user_cpu_time
+    }
+
+    pub(crate) fn system_cpu_time(&self) -> Duration {
+        self.system_cpu_time
+    }
+
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -262,17 +313,81 @@ impl ProcessInner {
         self.effective_group_id
     }
 
-    pub(crate) fn wait(&self) -> Option<ExitStatus> {
-        // If anything fails when trying to retrieve the start time, better to return `None`.
-        let (data, _) = _get_stat_data_and_file(&self.proc_path).ok()?;
-        let parts = parse_stat_file(&data)?;
+    pub(crate) fn umask(&self) -> Option<u32> {
+        self.umask
+    }
+
+    pub(crate) fn ns_pid(&self) -> Option<Pid> {
+        self.ns_pid
+    }
+
+    pub(crate) fn io_priority(&self) -> Option<IoPriority> {
+        let ret = unsafe { libc::syscall(libc::SYS_ioprio_get, IOPRIO_WHO_PROCESS, self.pid.0) };
+        if ret < 0 {
+            return None;
+        }
+        io_priority_from_raw(ret as u32)
+    }
+
+    pub(crate) fn set_io_priority(&self, priority: IoPriority) -> Result<(), IoPriorityError> {
+        if priority.level > IOPRIO_LEVEL_MASK as u8 {
+            return Err(IoPriorityError::InvalidPriority);
+        }
+        let ret = unsafe {
+            libc::syscall(
+                libc::SYS_ioprio_set,
+                IOPRIO_WHO_PROCESS,
+                self.pid.0,
+                io_priority_to_raw(priority),
+            )
+        };
+        if ret == 0 {
+            return Ok(());
+        }
+        match std::io::Error::last_os_error().raw_os_error() {
+            Some(libc::EPERM) => Err(IoPriorityError::PermissionDenied),
+            Some(libc::EINVAL) => Err(IoPriorityError::InvalidPriority),
+            _ => Err(IoPriorityError::FailedToSetPriority),
+        }
+    }
+
+    /// Checks that the start time of the PID is still the one of this process. If not, it means
+    /// the process died and a new one got its PID.
+    fn is_same_process(&self) -> bool {
+        // If anything fails when trying to retrieve the start time, better to return `false`.
+        let Some((data, _)) = _get_stat_data_and_file(&self.proc_path).ok() else {
+            return false;
+        };
+        let Some(parts) = parse_stat_file(&data) else {
+            return false;
+        };
 
         if start_time_raw(&parts) != self.start_time_raw {
             sysinfo_debug!("Seems to not be the same process anymore");
+            return false;
+        }
+        true
+    }
+
+    pub(crate) fn wait(&self) -> Option<ExitStatus> {
+        // Once reaped, the process isn't in `/proc` anymore.
+        if let Some(exit_status) = self.exit_status.get() {
+            return Some(*exit_status);
+        }
+        if !self.is_same_process() {
             return None;
         }
+        crate::unix::utils::wait_process(self.pid, &self.exit_status)
+    }
 
-        crate::unix::utils::wait_process(self.pid)
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        if let Some(exit_status) = self.exit_status.get() {
+            return exit_status.code();
+        }
+        if !self.is_same_process() {
+            return None;
+        }
+        crate::unix::utils::process_exit_code(self.pid, &self.exit_status)
     }
 
     pub(crate) fn session_id(&self) -> Option<Pid> {
@@ -317,27 +432,103 @@ impl ProcessInner {
     }
 
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
+        self.open_files_limits().0
+    }
+
+    /// Returns the soft and hard limits of open files. `None` means unlimited or unknown.
+    fn open_files_limits(&self) -> (Option<usize>, Option<usize>) {
         let limits_files = self.proc_path.as_path().join("limits");
         match fs::read_to_string(&limits_files) {
-            Ok(content) => {
-                for line in content.lines() {
-                    if let Some(line) = line.strip_prefix("Max open files ") {
-                        if let Some(nb) = line.split_whitespace().find(|p| !p.is_empty()) {
-                            return usize::from_str(nb).ok();
-                        }
-                    }
-                }
-                None
-            }
+            Ok(content) => parse_open_files_limits(&content),
             Err(_error) => {
                 sysinfo_debug!(
                     "Failed to get limits in `{}`: {_error:?}",
                     limits_files.display()
                 );
-                None
+                (None, None)
             }
         }
     }
+
+    pub(crate) fn fd_usage(&self) -> Option<FdUsage> {
+        self.fd_usage
+    }
+
+    pub(crate) fn namespaces(&self) -> Option<Namespaces> {
+        self.namespaces
+    }
+}
+
+fn parse_open_files_limits(limits: &str) -> (Option<usize>, Option<usize>) {
+    for line in limits.lines() {
+        if let Some(line) = line.strip_prefix("Max open files ") {
+            let mut values = line.split_whitespace().map(|nb| usize::from_str(nb).ok());
+            return (values.next().flatten(), values.next().flatten());
+        }
+    }
+    (None, None)
+}
+
+fn read_namespaces(proc_path: &Path) -> Option<Namespaces> {
+    let ns_path = proc_path.join("ns");
+    let read = |kind: &str| {
+        // Reading the links of another user's process requires `CAP_SYS_PTRACE`.
+        let link = fs::read_link(Path::join(&ns_path, kind)).ok()?;
+        parse_namespace_link(link.to_str()?, kind)
+    };
+    let namespaces = Namespaces {
+        pid: read("pid"),
+        net: read("net"),
+        mnt: read("mnt"),
+        uts: read("uts"),
+        ipc: read("ipc"),
+        user: read("user"),
+        cgroup: read("cgroup"),
+    };
+    if namespaces == Namespaces::default() {
+        None
+    } else {
+        Some(namespaces)
+    }
+}
+
+/// Parses the target of a `/proc/[pid]/ns/*` link, which looks like `net:[4026531840]`.
+fn parse_namespace_link(link: &str, kind: &str) -> Option<u64> {
+    link.strip_prefix(kind)?
+        .strip_prefix(":[")?
+        .strip_suffix(']')?
+        .parse()
+        .ok()
+}
+
+// From `include/uapi/linux/ioprio.h`.
+const IOPRIO_WHO_PROCESS: libc::c_int = 1;
+const IOPRIO_CLASS_SHIFT: u32 = 13;
+// The other bits of the priority data are used for hints since Linux 6.5.
+const IOPRIO_LEVEL_MASK: u32 = 0x7;
+
+fn io_priority_from_raw(value: u32) -> Option<IoPriority> {
+    let class = match value >> IOPRIO_CLASS_SHIFT {
+        0 => IoPriorityClass::None,
+        1 => IoPriorityClass::Realtime,
+        2 => IoPriorityClass::BestEffort,
+        3 => IoPriorityClass::Idle,
+        _ => return None,
+    };
+    Some(IoPriority {
+        class,
+        level: (value & IOPRIO_LEVEL_MASK) as u8,
+    })
+}
+
+fn io_priority_to_raw(priority: IoPriority) -> u32 {
+    let class = match priority.class {
+        IoPriorityClass::None => 0,
+        IoPriorityClass::Realtime => 1,
+        IoPriorityClass::BestEffort => 2,
+        IoPriorityClass::Idle => 3,
+    };
+    class << IOPRIO_CLASS_SHIFT | u32::from(priority.level)
 }
 
 pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, total_time: f32, max_value: f32) {
@@ -366,7 +557,10 @@ pub(crate) fn set_time(p: &mut ProcessInner, utime: u64, stime: u64) {
 }
 
 pub(crate) fn update_process_disk_activity(p: &mut ProcessInner, path: &mut PathHandler) {
-    let data = match get_all_utf8_data(path.join("io"), 16_384) {
+    let data = get_all_utf8_data(path.join("io"), 16_384);
+    p.field_statuses
+        .set(ProcessField::DiskUsage, FieldStatus::from_result(&data));
+    let data = match data {
         Ok(d) => d,
         Err(_) => return,
     };
@@ -453,13 +647,19 @@ fn refresh_user_group_ids(
         return;
     }
 
-    if let Some(((user_id, effective_user_id), (group_id, effective_group_id))) =
-        get_uid_and_gid(path.join("status"))
+    if let Some(StatusData {
+        user_ids: (user_id, effective_user_id),
+        group_ids: (group_id, effective_group_id),
+        umask,
+        ns_pid,
+    }) = get_status_data(path.join("status"))
     {
         p.user_id = Some(Uid(user_id));
         p.effective_user_id = Some(Uid(effective_user_id));
         p.group_id = Some(Gid(group_id));
         p.effective_group_id = Some(Gid(effective_group_id));
+        p.umask = umask;
+        p.ns_pid = ns_pid;
     }
 }
 
@@ -476,38 +676,91 @@ fn update_proc_info(
     update_parent_pid(p, parent_pid, str_parts);
 
     get_status(p, str_parts[ProcIndex::State as usize]);
+    p.thread_count = usize::from_str(str_parts[ProcIndex::NumberOfThreads as usize]).ok();
     refresh_user_group_ids(p, proc_path, refresh_kind);
 
     if refresh_kind.exe().needs_update(|| p.exe.is_none()) {
         // Do not use cmd[0] because it is not the same thing.
         // See https://github.com/GuillaumeGomez/sysinfo/issues/697.
-        p.exe = realpath(proc_path.join("exe"));
+        let exe = realpath(proc_path.join("exe"));
+        p.field_statuses
+            .set(ProcessField::Exe, FieldStatus::from_result(&exe));
+        p.exe = exe.ok();
     }
 
     if refresh_kind.cmd().needs_update(|| p.cmd.is_empty()) {
-        p.cmd = copy_from_file(proc_path.join("cmdline"));
+        let cmd = copy_from_file(proc_path.join("cmdline"));
+        p.field_statuses
+            .set(ProcessField::Cmd, FieldStatus::from_result(&cmd));
+        p.cmd = cmd.unwrap_or_default();
+    }
+    // As long as the full name wasn't found.
+    if p.name.as_bytes() == p.comm.as_bytes() {
+        if let Some(name) = get_full_name(p.comm.as_bytes(), &p.cmd, p.exe.as_deref()) {
+            p.name = name;
+        }
     }
-    if refresh_kind.environ().needs_update(|| p.environ.is_empty()) {
-        p.environ = copy_from_file(proc_path.join("environ"));
+    // The environment of a process is very unlikely to change, so unless asked otherwise, it is
+    // only read the first time the process is seen, even if it was empty or unreadable.
+    if refresh_kind.environ().needs_update(|| !p.environ_read) {
+        let environ = copy_from_file(proc_path.join("environ"));
+        p.field_statuses
+            .set(ProcessField::Environ, FieldStatus::from_result(&environ));
+        p.environ = environ.unwrap_or_default();
+        p.environ_read = true;
     }
     if refresh_kind.cwd().needs_update(|| p.cwd.is_none()) {
-        p.cwd = realpath(proc_path.join("cwd"));
+        let cwd = realpath(proc_path.join("cwd"));
+        p.field_statuses
+            .set(ProcessField::Cwd, FieldStatus::from_result(&cwd));
+        p.cwd = cwd.ok();
     }
     if refresh_kind.root().needs_update(|| p.root.is_none()) {
-        p.root = realpath(proc_path.join("root"));
+        p.root = realpath(proc_path.join("root")).ok();
     }
 
     update_time_and_memory(proc_path, p, str_parts, uptime, info, refresh_kind);
     if refresh_kind.disk_usage() {
         update_process_disk_activity(p, proc_path);
     }
+    if refresh_kind.open_files() {
+        let (soft_limit, hard_limit) = p.open_files_limits();
+        p.fd_usage = FdUsage::update(p.fd_usage, p.open_files(), soft_limit, hard_limit);
+    }
+    if refresh_kind.namespaces() {
+        p.namespaces = read_namespaces(&p.proc_path);
+    }
     // Needs to be after `update_time_and_memory`.
     if refresh_kind.cpu() {
-        // The external values for CPU times are in "ticks", which are
-        // scaled by "HZ", which is pegged externally at 100 ticks/second.
+        // The external values for CPU times are in "ticks", which are scaled by `USER_HZ`. It is
+        // usually 100 ticks/second, but not always, so it is retrieved with `_SC_CLK_TCK`.
         p.accumulated_cpu_time =
             p.utime.saturating_add(p.stime).saturating_mul(1_000) / info.clock_cycle;
+        p.user_cpu_time = ticks_to_duration(p.utime, info.clock_cycle);
+        p.system_cpu_time = ticks_to_duration(p.stime, info.clock_cycle);
+    }
+}
+
+fn ticks_to_duration(ticks: u64, clock_cycle: u64) -> Duration {
+    // The seconds are computed separately to not overflow when converting to nanoseconds.
+    Duration::from_secs(ticks / clock_cycle)
+        + Duration::from_nanos((ticks % clock_cycle) * 1_000_000_000 / clock_cycle)
+}
+
+/// The kernel truncates `comm` to `TASK_COMM_LEN - 1` bytes, so the full name is retrieved from
+/// the command line or the executable, as long as it starts with `comm`.
+fn get_full_name(comm: &[u8], cmd: &[OsString], exe: Option<&Path>) -> Option<OsString> {
+    const TASK_COMM_LEN: usize = 16;
+
+    if comm.len() < TASK_COMM_LEN - 1 {
+        return None;
     }
+    cmd.first()
+        .and_then(|arg| Path::new(arg).file_name())
+        .into_iter()
+        .chain(exe.and_then(Path::file_name))
+        .find(|name| name.len() > comm.len() && name.as_bytes().starts_with(comm))
+        .map(OsStr::to_os_string)
 }
 
 fn update_parent_pid(p: &mut ProcessInner, parent_pid: Option<Pid>, str_parts: &[&str]) {
@@ -540,8 +793,12 @@ fn retrieve_all_new_process_info(
     p.start_time = p
         .start_time_without_boot_time
         .saturating_add(info.boot_time);
+    p.start_time_precise = info.precise_boot_time.saturating_add(Duration::from_nanos(
+        (start_time_raw as u128 * 1_000_000_000 / info.clock_cycle.max(1) as u128) as u64,
+    ));
 
     p.name = OsStr::from_bytes(name).to_os_string();
+    p.comm = String::from_utf8_lossy(name).into_owned();
     if c_ulong::from_str(parts.str_parts[ProcIndex::Flags as usize])
         .map(|flags| flags & libc::PF_KTHREAD as c_ulong != 0)
         .unwrap_or(false)
@@ -589,7 +846,6 @@ fn update_existing_process(
     } else {
         _get_stat_data(&entry.proc_path, &mut entry.stat_file)?
     };
-    entry.tasks = tasks;
 
     let parts = parse_stat_file(&data).ok_or(())?;
     let start_time_raw = start_time_raw(&parts);
@@ -598,6 +854,13 @@ fn update_existing_process(
     // If the start time differs, then it means it's not the same process anymore and that we
     // need to get all its information, hence why we check it here.
     if start_time_raw == entry.start_time_raw {
+        entry.tasks = tasks;
+        // It can be changed by the process itself.
+        if entry.comm.as_bytes() != parts.short_exe {
+            entry.comm = String::from_utf8_lossy(parts.short_exe).into_owned();
+            // The full name is retrieved again from the new `comm` in `update_proc_info`.
+            entry.name = OsStr::from_bytes(parts.short_exe).to_os_string();
+        }
         let mut proc_path = PathHandler::new(&entry.proc_path);
 
         update_proc_info(
@@ -613,8 +876,9 @@ fn update_existing_process(
         refresh_user_group_ids(entry, &mut proc_path, refresh_kind);
         return Ok(None);
     }
-    // If we're here, it means that the PID still exists but it's a different process.
-    let p = retrieve_all_new_process_info(
+    // If we're here, it means that the PID still exists but it's a different process. Nothing
+    // (CPU and disk usage deltas, run time, ...) is kept from the previous one.
+    let mut p = retrieve_all_new_process_info(
         entry.pid,
         parent_pid,
         &parts,
@@ -623,6 +887,8 @@ fn update_existing_process(
         refresh_kind,
         uptime,
     );
+    p.inner.stat_file = entry.stat_file.take();
+    p.inner.tasks = tasks;
     *proc = p;
     // Since this PID is already in the HashMap, no need to add it again.
     Ok(None)
@@ -653,6 +919,45 @@ pub(crate) fn _get_process_data(
     Ok(Some(new_process))
 }
 
+pub(crate) fn get_process_from_dir(path: &Path) -> Option<Process> {
+    _get_process_from_dir(
+        path,
+        SystemInner::uptime(),
+        &SystemInfo::new(),
+        ProcessRefreshKind::everything(),
+    )
+}
+
+/// Contrary to [`_get_process_data`], `path` doesn't need to be in `/proc` and the PID is read
+/// from its `stat` file.
+fn _get_process_from_dir(
+    path: &Path,
+    uptime: u64,
+    info: &SystemInfo,
+    refresh_kind: ProcessRefreshKind,
+) -> Option<Process> {
+    let (data, _) = _get_stat_data_and_file(path).ok()?;
+    let parts = parse_stat_file(&data)?;
+    let pid = Pid::from_str(parts.str_parts[ProcIndex::Pid as usize]).ok()?;
+    let tasks = if refresh_kind.tasks() {
+        fs::read_dir(path.join("task")).ok().map(|entries| {
+            entries
+                .filter_map(|entry| {
+                    let task = entry.ok()?.file_name().to_str()?.parse::<usize>().ok()?;
+                    Some(Pid::from(task)).filter(|task| *task != pid)
+                })
+                .collect()
+        })
+    } else {
+        None
+    };
+
+    let mut process =
+        retrieve_all_new_process_info(pid, None, &parts, path, info, refresh_kind, uptime);
+    process.inner.tasks = tasks;
+    Some(process)
+}
+
 fn old_get_memory(entry: &mut ProcessInner, str_parts: &[&str], info: &SystemInfo) {
     // rss
     entry.memory = u64::from_str(str_parts[ProcIndex::ResidentSetSize as usize])
@@ -751,8 +1056,16 @@ fn get_all_pid_entries(
     if !file_type.is_dir() {
         return None;
     }
+    get_pid_entries(parent, parent_pid, entry.path(), data, enable_task_stats)
+}
 
-    let entry = entry.path();
+fn get_pid_entries(
+    parent: Option<&OsStr>,
+    parent_pid: Option<Pid>,
+    entry: PathBuf,
+    data: &mut Vec<ProcAndTasks>,
+    enable_task_stats: bool,
+) -> Option<Pid> {
     let name = entry.file_name();
 
     if name == parent {
@@ -788,6 +1101,44 @@ fn get_all_pid_entries(
     Some(pid)
 }
 
+/// Only reads the folders of the given `pids` instead of the whole `/proc` folder. Since the
+/// tasks are not listed in `/proc`, their thread group ID is used to find their folder in the
+/// `task` subfolder of their process.
+fn get_pid_list_entries(path: &Path, pids: &[Pid], enable_task_stats: bool) -> Vec<ProcAndTasks> {
+    let mut data = Vec::with_capacity(pids.len());
+    for &pid in pids {
+        let entry = path.join(pid.to_string());
+        let Some(tgid) = get_all_utf8_data(Path::join(&entry, "status"), 16_385)
+            .ok()
+            .and_then(|status| parse_thread_group_id(&status))
+        else {
+            // The process doesn't exist anymore.
+            continue;
+        };
+        if tgid == pid {
+            get_pid_entries(None, None, entry, &mut data, enable_task_stats);
+        } else if enable_task_stats {
+            let entry = path
+                .join(tgid.to_string())
+                .join("task")
+                .join(pid.to_string());
+            get_pid_entries(None, Some(tgid), entry, &mut data, enable_task_stats);
+        }
+    }
+    // A task can be listed both directly and as a task of a listed process.
+    let mut seen = HashSet::with_capacity(data.len());
+    data.retain(|entry| seen.insert(entry.pid));
+    data
+}
+
+fn parse_thread_group_id(status: &str) -> Option<Pid> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("Tgid:"))
+        .and_then(|tgid| usize::from_str(tgid.trim()).ok())
+        .map(Pid::from)
+}
+
 #[cfg(feature = "multithread")]
 #[inline]
 pub(crate) fn iter<T>(val: T) -> rayon::iter::IterBridge<T>
@@ -806,8 +1157,8 @@ where
     val
 }
 
-/// We're forced to read the whole `/proc` folder because if a process died and another took its
-/// place, we need to get the task parent (if it's a task).
+/// When all processes are refreshed, the whole `/proc` folder is read. Otherwise, only the
+/// folders of the given processes are read (see `get_pid_list_entries`).
 pub(crate) fn refresh_procs(
     proc_list: &mut HashMap<Pid, Process>,
     path: &Path,
@@ -819,69 +1170,60 @@ pub(crate) fn refresh_procs(
     #[cfg(feature = "multithread")]
     use rayon::iter::ParallelIterator;
 
-    #[inline(always)]
-    fn real_filter(e: &ProcAndTasks, filter: &[Pid]) -> bool {
-        filter.contains(&e.pid)
-    }
-
-    #[inline(always)]
-    fn empty_filter(_e: &ProcAndTasks, _filter: &[Pid]) -> bool {
-        true
-    }
-
-    #[allow(clippy::type_complexity)]
-    let (filter, filter_callback): (
-        &[Pid],
-        &(dyn Fn(&ProcAndTasks, &[Pid]) -> bool + Sync + Send),
-    ) = match processes_to_update {
-        ProcessesToUpdate::All => (&[], &empty_filter),
-        ProcessesToUpdate::Some(pids) => {
-            if pids.is_empty() {
-                return 0;
-            }
-            (pids, &real_filter)
-        }
-    };
-
     let nb_updated = AtomicUsize::new(0);
 
-    // FIXME: To prevent retrieving a task more than once (it can be listed in `/proc/[PID]/task`
-    // subfolder and directly in `/proc` at the same time), might be interesting to use a `HashSet`.
     let procs = {
-        let d = match fs::read_dir(path) {
-            Ok(d) => d,
-            Err(_err) => {
-                sysinfo_debug!("Failed to read folder {path:?}: {_err:?}");
-                return 0;
-            }
-        };
         let proc_list = Wrap(UnsafeCell::new(proc_list));
+        let get_process = |e: ProcAndTasks| {
+            let proc_list = proc_list.get();
+            let new_process = _get_process_data(
+                e.path.as_path(),
+                proc_list,
+                e.pid,
+                e.parent_pid,
+                uptime,
+                info,
+                refresh_kind,
+                e.tasks,
+            )
+            .ok()?;
+            nb_updated.fetch_add(1, Ordering::Relaxed);
+            new_process
+        };
 
-        iter(d)
-            .flat_map(|entry| {
-                let Ok(entry) = entry else { return Vec::new() };
-                let mut entries = Vec::new();
-                get_all_pid_entries(None, None, entry, &mut entries, refresh_kind.tasks());
-                entries
-            })
-            .filter(|e| filter_callback(e, filter))
-            .filter_map(|e| {
-                let proc_list = proc_list.get();
-                let new_process = _get_process_data(
-                    e.path.as_path(),
-                    proc_list,
-                    e.pid,
-                    e.parent_pid,
-                    uptime,
-                    info,
-                    refresh_kind,
-                    e.tasks,
-                )
-                .ok()?;
-                nb_updated.fetch_add(1, Ordering::Relaxed);
-                new_process
-            })
-            .collect::<Vec<_>>()
+        match processes_to_update {
+            ProcessesToUpdate::All => {
+                let d = match fs::read_dir(path) {
+                    Ok(d) => d,
+                    Err(_err) => {
+                        sysinfo_debug!("Failed to read folder {path:?}: {_err:?}");
+                        return 0;
+                    }
+                };
+                // FIXME: To prevent retrieving a task more than once (it can be listed in
+                // `/proc/[PID]/task` subfolder and directly in `/proc` at the same time), might
+                // be interesting to use a `HashSet`.
+                iter(d)
+                    .flat_map(|entry| {
+                        let Ok(entry) = entry else { return Vec::new() };
+                        let mut entries = Vec::new();
+                        get_all_pid_entries(None, None, entry, &mut entries, refresh_kind.tasks());
+                        entries
+                    })
+                    .filter_map(get_process)
+                    .collect::<Vec<_>>()
+            }
+            ProcessesToUpdate::Some(pids) => {
+                if pids.is_empty() {
+                    return 0;
+                }
+                iter(get_pid_list_entries(path, pids, refresh_kind.tasks()).into_iter())
+                    // The tasks of the listed processes are only refreshed if they are listed too.
+                    .filter(|e| pids.contains(&e.pid))
+                    .filter_map(get_process)
+                    .collect::<Vec<_>>()
+            }
+        }
     };
     for proc_ in procs {
         proc_list.insert(proc_.pid(), proc_);
@@ -889,69 +1231,57 @@ pub(crate) fn refresh_procs(
     nb_updated.into_inner()
 }
 
-// FIXME: To be removed once MSRV for this crate is 1.80 and use the `trim_ascii()` method instead.
-fn trim_ascii(mut bytes: &[u8]) -> &[u8] {
-    // Code from Rust code library.
-    while let [rest @ .., last] = bytes {
-        if last.is_ascii_whitespace() {
-            bytes = rest;
-        } else {
-            break;
-        }
-    }
-    while let [first, rest @ ..] = bytes {
-        if first.is_ascii_whitespace() {
-            bytes = rest;
-        } else {
-            break;
-        }
-    }
-    bytes
-}
-
+// Each argument (or environment variable) is terminated by a NUL byte. The arguments are kept
+// as is, even when they are empty or only contain whitespace.
 fn split_content(mut data: &[u8]) -> Vec<OsString> {
     let mut out = Vec::with_capacity(10);
     while let Some(pos) = data.iter().position(|c| *c == 0) {
-        let s = trim_ascii(&data[..pos]);
-        if !s.is_empty() {
-            out.push(OsStr::from_bytes(s).to_os_string());
-        }
+        out.push(OsStr::from_bytes(&data[..pos]).to_os_string());
         data = &data[pos + 1..];
     }
+    // The processes can overwrite their arguments without the last NUL byte.
     if !data.is_empty() {
-        let s = trim_ascii(data);
-        if !s.is_empty() {
-            out.push(OsStr::from_bytes(s).to_os_string());
-        }
+        out.push(OsStr::from_bytes(data).to_os_string());
     }
     out
 }
 
-fn copy_from_file(entry: &Path) -> Vec<OsString> {
+fn copy_from_file(entry: &Path) -> io::Result<Vec<OsString>> {
     match File::open(entry) {
         Ok(mut f) => {
             let mut data = Vec::with_capacity(16_384);
 
-            if let Err(_e) = f.read_to_end(&mut data) {
-                sysinfo_debug!("Failed to read file in `copy_from_file`: {:?}", _e);
-                Vec::new()
+            if let Err(e) = f.read_to_end(&mut data) {
+                sysinfo_debug!("Failed to read file in `copy_from_file`: {:?}", e);
+                Err(e)
             } else {
-                split_content(&data)
+                Ok(split_content(&data))
             }
         }
-        Err(_e) => {
-            sysinfo_debug!("Failed to open file in `copy_from_file`: {:?}", _e);
-            Vec::new()
+        Err(e) => {
+            sysinfo_debug!("Failed to open file in `copy_from_file`: {:?}", e);
+            Err(e)
         }
     }
 }
 
-// Fetch tuples of real and effective UID and GID.
-fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))> {
+struct StatusData {
+    user_ids: (uid_t, uid_t),
+    group_ids: (gid_t, gid_t),
+    umask: Option<u32>,
+    ns_pid: Option<Pid>,
+}
+
+// Fetch tuples of real and effective UID and GID, and the umask and the PID in the process' PID
+// namespace if available.
+fn get_status_data(file_path: &Path) -> Option<StatusData> {
     let status_data = get_all_utf8_data(file_path, 16_385).ok()?;
+    parse_status_data(&status_data)
+}
 
-    // We're only interested in the lines starting with Uid: and Gid:
-    // here. From these lines, we're looking at the first and second entries to get
+fn parse_status_data(status_data: &str) -> Option<StatusData> {
+    // We're only interested in the lines starting with Umask:, Uid:, Gid: and NSpid:
+    // here. From Uid: and Gid:, we're looking at the first and second entries to get
     // the real u/gid.
 
     let f = |h: &str, n: &str| -> (Option<uid_t>, Option<uid_t>) {
@@ -969,8 +1299,23 @@ fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))>
     let mut effective_uid = None;
     let mut gid = None;
     let mut effective_gid = None;
+    let mut umask = None;
+    let mut ns_pid = None;
     for line in status_data.lines() {
-        if let (Some(real), Some(effective)) = f(line, "Uid:") {
+        // The `Umask` line comes before the `Uid` and `Gid` ones.
+        if let Some(value) = line.strip_prefix("Umask:") {
+            umask = u32::from_str_radix(value.trim(), 8).ok();
+        } else if let Some(value) = line.strip_prefix("NSpid:") {
+            // It contains the PID in each nested PID namespace, the last one being the
+            // namespace of the process. It was added in Linux 4.1.
+            ns_pid = value
+                .split_whitespace()
+                .next_back()
+                .and_then(|pid| usize::from_str(pid).ok())
+                .map(Pid::from);
+            // The `NSpid` line comes after the `Uid` and `Gid` ones.
+            break;
+        } else if let (Some(real), Some(effective)) = f(line, "Uid:") {
             debug_assert!(uid.is_none() && effective_uid.is_none());
             uid = Some(real);
             effective_uid = Some(effective);
@@ -978,17 +1323,15 @@ fn get_uid_and_gid(file_path: &Path) -> Option<((uid_t, uid_t), (gid_t, gid_t))>
             debug_assert!(gid.is_none() && effective_gid.is_none());
             gid = Some(real);
             effective_gid = Some(effective);
-        } else {
-            continue;
-        }
-        if uid.is_some() && gid.is_some() {
-            break;
         }
     }
     match (uid, effective_uid, gid, effective_gid) {
-        (Some(uid), Some(effective_uid), Some(gid), Some(effective_gid)) => {
-            Some(((uid, effective_uid), (gid, effective_gid)))
-        }
+        (Some(uid), Some(effective_uid), Some(gid), Some(effective_gid)) => Some(StatusData {
+            user_ids: (uid, effective_uid),
+            group_ids: (gid, effective_gid),
+            umask,
+            ns_pid,
+        }),
         _ => None,
     }
 }
@@ -1021,48 +1364,57 @@ fn parse_stat_file(data: &[u8]) -> Option<Parts<'_>> {
     })
 }
 
-/// Type used to correctly handle the `REMAINING_FILES` global.
-struct FileCounter(File);
-
-impl FileCounter {
-    fn new(f: File) -> Option<Self> {
-        let any_remaining =
-            remaining_files().fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| {
-                if remaining > 0 {
-                    Some(remaining - 1)
-                } else {
-                    // All file descriptors we were allowed are being used.
-                    None
-                }
-            });
-
-        any_remaining.ok().map(|_| Self(f))
-    }
-}
-
-impl std::ops::Deref for FileCounter {
-    type Target = File;
+#[cfg(test)]
+mod tests {
+    use super::{
+        _get_process_data, _get_process_from_dir, get_pid_list_entries, io_priority_from_raw,
+        io_priority_to_raw, parse_namespace_link, parse_open_files_limits, parse_status_data,
+        refresh_procs, split_content, SystemInfo,
+    };
+    use crate::utils::RefreshPool;
+    use crate::{
+        FieldStatus, IoPriority, IoPriorityClass, Pid, ProcessField, ProcessRefreshKind,
+        ProcessesToUpdate, Uid, UpdateKind,
+    };
+    use std::collections::HashMap;
+    use std::ffi::OsString;
+    use std::fs;
+    use std::os::unix::ffi::OsStringExt;
+    use std::path::Path;
+    use std::time::{Duration, UNIX_EPOCH};
 
-    fn deref(&self) -> &Self::Target {
-        &self.0
-    }
-}
-impl std::ops::DerefMut for FileCounter {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+    #[test]
+    fn test_parse_open_files_limits() {
+        let limits = "\
+Limit                     Soft Limit           Hard Limit           Units
+Max cpu time              unlimited            unlimited            seconds
+Max open files            1024                 524288               files
+Max locked memory         8388608              8388608              bytes
+";
+        assert_eq!(parse_open_files_limits(limits), (Some(1024), Some(524288)));
+        assert_eq!(
+            parse_open_files_limits(
+                "Max open files            unlimited            unlimited            files"
+            ),
+            (None, None)
+        );
+        assert_eq!(parse_open_files_limits(""), (None, None));
     }
-}
 
-impl Drop for FileCounter {
-    fn drop(&mut self) {
-        remaining_files().fetch_add(1, Ordering::Relaxed);
+    #[test]
+    fn test_parse_namespace_link() {
+        assert_eq!(
+            parse_namespace_link("net:[4026531840]", "net"),
+            Some(4026531840)
+        );
+        assert_eq!(
+            parse_namespace_link("cgroup:[4026531835]", "cgroup"),
+            Some(4026531835)
+        );
+        assert_eq!(parse_namespace_link("pid:[4026531836]", "net"), None);
+        assert_eq!(parse_namespace_link("net:4026531840", "net"), None);
+        assert_eq!(parse_namespace_link("net:[]", "net"), None);
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::split_content;
-    use std::ffi::OsString;
 
     // This test ensures that all the parts of the data are split.
     #[test]
@@ -1075,7 +1427,477 @@ mod tests {
         );
         assert_eq!(
             split_content(b"hello\0\0\0\0b"),
-            vec![OsString::from("hello"), "b".into()]
+            vec![
+                OsString::from("hello"),
+                "".into(),
+                "".into(),
+                "".into(),
+                "b".into()
+            ]
+        );
+        // The empty and whitespace-only arguments are kept as is.
+        assert_eq!(
+            split_content(b"echo\0\0 a \0"),
+            vec![OsString::from("echo"), "".into(), " a ".into()]
+        );
+        // The bytes which aren't valid UTF-8 are kept.
+        assert_eq!(
+            split_content(b"caf\xe9\0--n\xffme=\xc3\0"),
+            vec![
+                OsString::from_vec(b"caf\xe9".to_vec()),
+                OsString::from_vec(b"--n\xffme=\xc3".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_status_data() {
+        let status = "\
+Name:\tbash
+Umask:\t0022
+State:\tS (sleeping)
+Uid:\t1000\t0\t1000\t1000
+Gid:\t100\t101\t100\t100
+NStgid:\t4242\t1
+NSpid:\t4242\t1
+";
+        let data = parse_status_data(status).expect("failed to parse status");
+        assert_eq!(data.user_ids, (1000, 0));
+        assert_eq!(data.group_ids, (100, 101));
+        assert_eq!(data.umask, Some(0o022));
+        assert_eq!(data.ns_pid, Some(Pid(1)));
+
+        // The `NSpid` field was added in Linux 4.1.
+        let data = parse_status_data(&status.replace("NSpid:\t4242\t1\n", ""))
+            .expect("failed to parse status");
+        assert_eq!(data.ns_pid, None);
+
+        // The `Umask` field was added in Linux 4.7.
+        let data = parse_status_data(&status.replace("Umask:\t0022\n", ""))
+            .expect("failed to parse status");
+        assert_eq!(data.umask, None);
+    }
+
+    #[test]
+    fn test_pid_list_entries() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = proc_dir.path();
+        // The tasks are accessible both with `/proc/[TID]` and `/proc/[PID]/task/[TID]`.
+        for (dir, tgid) in [
+            ("100", 100),
+            ("101", 100),
+            ("100/task/100", 100),
+            ("100/task/101", 100),
+        ] {
+            fs::create_dir_all(path.join(dir)).expect("failed to create process folder");
+            fs::write(
+                path.join(dir).join("status"),
+                format!("Name:\tbash\nTgid:\t{tgid}\n"),
+            )
+            .expect("failed to write status file");
+        }
+
+        let pids = [Pid(100), Pid(101), Pid(999)];
+        let entries = get_pid_list_entries(path, &pids, true);
+        let entries = entries
+            .iter()
+            .map(|e| (e.pid, e.parent_pid, e.path.strip_prefix(path).unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            entries,
+            [
+                (Pid(101), Some(Pid(100)), Path::new("100/task/101")),
+                (Pid(100), None, Path::new("100")),
+            ]
+        );
+
+        // The tasks are skipped when they are not refreshed.
+        let entries = get_pid_list_entries(path, &pids, false);
+        assert_eq!(
+            entries
+                .iter()
+                .map(|e| (e.pid, e.tasks.is_none()))
+                .collect::<Vec<_>>(),
+            [(Pid(100), true)]
+        );
+    }
+
+    fn write_stat(dir: &std::path::Path, utime: u64, start_time: u64) {
+        fs::write(
+            dir.join("stat"),
+            format!(
+                "4242 (reused) S 1 4242 4242 0 -1 4194560 0 0 0 0 {utime} 0 0 0 20 0 1 0 \
+                 {start_time} 1000 10\n"
+            ),
+        )
+        .expect("failed to write stat file");
+    }
+
+    /// Creates a `/proc` like folder with `count` processes.
+    fn create_synthetic_proc(path: &Path, count: usize) {
+        for pid in 1..=count {
+            let dir = path.join(pid.to_string());
+            fs::create_dir(&dir).expect("failed to create process folder");
+            fs::write(
+                dir.join("stat"),
+                format!(
+                    "{pid} (proc{pid}) S 1 {pid} {pid} 0 -1 4194560 0 0 0 0 {pid} 0 0 0 20 0 1 0 \
+                     {pid} 1000 10\n"
+                ),
+            )
+            .expect("failed to write stat file");
+            fs::write(
+                dir.join("status"),
+                format!("Name:\tproc{pid}\nTgid:\t{pid}\nUid:\t1000\t1000\t1000\t1000\n"),
+            )
+            .expect("failed to write status file");
+            fs::write(dir.join("cmdline"), format!("proc{pid}\0--id\0{pid}\0"))
+                .expect("failed to write cmdline file");
+        }
+    }
+
+    fn refresh_synthetic_proc(path: &Path, threads: usize) -> HashMap<Pid, crate::Process> {
+        let info = SystemInfo {
+            page_size_b: 4096,
+            clock_cycle: 100,
+            boot_time: 1_000,
+            precise_boot_time: Duration::from_secs(1_000),
+        };
+        let mut pool = RefreshPool::new();
+        pool.set_threads(threads);
+        let mut processes = HashMap::new();
+        let nb_updated = pool.install(|| {
+            refresh_procs(
+                &mut processes,
+                path,
+                100_000,
+                &info,
+                ProcessesToUpdate::All,
+                ProcessRefreshKind::everything(),
+            )
+        });
+        assert_eq!(nb_updated, processes.len());
+        processes
+    }
+
+    #[test]
+    fn test_parallel_refresh_is_deterministic() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        create_synthetic_proc(proc_dir.path(), 200);
+
+        let sequential = refresh_synthetic_proc(proc_dir.path(), 1);
+        let parallel = refresh_synthetic_proc(proc_dir.path(), 4);
+        assert_eq!(sequential.len(), 200);
+        assert_eq!(parallel.len(), 200);
+        for (pid, process) in &sequential {
+            let other = &parallel[pid];
+            assert_eq!(process.name(), other.name());
+            assert_eq!(process.cmd(), other.cmd());
+            assert_eq!(process.start_time(), other.start_time());
+            assert_eq!(process.accumulated_cpu_time(), other.accumulated_cpu_time());
+        }
+        assert_eq!(sequential[&Pid(42)].cmd(), ["proc42", "--id", "42"]);
+    }
+
+    // This test ensures that a PID reused by another process replaces the previous entry
+    // instead of updating it.
+    #[test]
+    fn test_pid_reuse() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = proc_dir.path();
+        let info = SystemInfo {
+            page_size_b: 4096,
+            clock_cycle: 100,
+            boot_time: 1_000,
+            precise_boot_time: Duration::from_secs(1_000),
+        };
+        let refresh_kind = ProcessRefreshKind::nothing().with_cpu();
+        let pid = Pid(4242);
+        let mut processes = HashMap::new();
+        let refresh = |processes: &mut HashMap<_, _>, uptime| {
+            _get_process_data(
+                path,
+                processes,
+                pid,
+                None,
+                uptime,
+                &info,
+                refresh_kind,
+                None,
+            )
+            .expect("failed to get process data")
+        };
+
+        write_stat(path, 100, 1_000);
+        let p = refresh(&mut processes, 50).expect("expected a new process");
+        assert_eq!(p.inner.start_time, 1_010);
+        assert_eq!(p.inner.run_time, 40);
+        processes.insert(pid, p);
+
+        // Same process: it is updated.
+        write_stat(path, 200, 1_000);
+        assert!(refresh(&mut processes, 60).is_none());
+        let p = &processes[&pid].inner;
+        assert_eq!(p.start_time, 1_010);
+        assert_eq!(p.run_time, 50);
+        assert_eq!((p.old_utime, p.utime), (100, 200));
+
+        // The PID was reused: the previous process information is dropped.
+        write_stat(path, 5, 5_000);
+        assert!(refresh(&mut processes, 70).is_none());
+        let p = &processes[&pid].inner;
+        assert_eq!(p.start_time, 1_050);
+        assert_eq!(p.run_time, 20);
+        assert_eq!((p.old_utime, p.utime), (0, 5));
+        assert_eq!(p.accumulated_cpu_time, 50);
+    }
+
+    #[test]
+    fn test_process_from_dir() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = proc_dir.path();
+        let info = SystemInfo {
+            page_size_b: 4096,
+            clock_cycle: 100,
+            boot_time: 1_000,
+            precise_boot_time: Duration::from_secs(1_000),
+        };
+        let refresh_kind = ProcessRefreshKind::nothing()
+            .with_user(UpdateKind::Always)
+            .without_tasks();
+
+        // The `stat` file is required.
+        assert!(_get_process_from_dir(path, 50, &info, refresh_kind).is_none());
+
+        write_stat(path, 100, 1_000);
+        fs::write(
+            path.join("status"),
+            "Uid:\t1000\t0\t1000\t1000\nGid:\t100\t100\t100\t100\n",
+        )
+        .expect("failed to write status file");
+        fs::write(path.join("cmdline"), b"reused\0--flag\0").expect("failed to write cmdline file");
+        for task in ["4242", "4243"] {
+            fs::create_dir_all(path.join("task").join(task)).expect("failed to create task folder");
+        }
+
+        let p = _get_process_from_dir(path, 50, &info, refresh_kind).expect("expected a process");
+        assert_eq!(p.pid(), Pid(4242));
+        assert_eq!(p.name(), "reused");
+        assert_eq!(p.parent(), Some(Pid(1)));
+        assert_eq!(p.start_time(), 1_010);
+        assert_eq!(p.run_time(), 40);
+        assert_eq!(p.user_id(), Some(&Uid(1000)));
+        assert_eq!(p.thread_count(), Some(1));
+        // Not requested by `refresh_kind`.
+        assert!(p.cmd().is_empty());
+        assert!(p.tasks().is_none());
+
+        let p = _get_process_from_dir(
+            path,
+            50,
+            &info,
+            refresh_kind.with_cmd(UpdateKind::Always).with_tasks(),
+        )
+        .expect("expected a process");
+        assert_eq!(p.cmd(), [OsString::from("reused"), "--flag".into()]);
+        assert_eq!(p.tasks().map(|tasks| tasks.len()), Some(1));
+        assert!(p.tasks().is_some_and(|tasks| tasks.contains(&Pid(4243))));
+    }
+
+    // `USER_HZ` isn't always 100.
+    #[test]
+    fn test_cpu_time_clock_ticks() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = proc_dir.path();
+        let info = SystemInfo {
+            page_size_b: 4096,
+            clock_cycle: 250,
+            boot_time: 1_000,
+            precise_boot_time: Duration::from_secs(1_000),
+        };
+        let mut processes = HashMap::new();
+
+        write_stat(path, 625, 1_000);
+        let p = _get_process_data(
+            path,
+            &mut processes,
+            Pid(4242),
+            None,
+            50,
+            &info,
+            ProcessRefreshKind::nothing().with_cpu(),
+            None,
+        )
+        .expect("failed to get process data")
+        .expect("expected a new process");
+        assert_eq!(p.inner.accumulated_cpu_time, 2_500);
+        assert_eq!(p.inner.user_cpu_time, Duration::from_millis(2_500));
+        assert_eq!(p.inner.system_cpu_time, Duration::ZERO);
+    }
+
+    // The precise start time keeps the sub-second parts of both the boot time and the number of
+    // clock ticks since boot.
+    #[test]
+    fn test_start_time_precise() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = proc_dir.path();
+        let info = SystemInfo {
+            page_size_b: 4096,
+            clock_cycle: 100,
+            boot_time: 1_000,
+            precise_boot_time: Duration::from_millis(1_000_250),
+        };
+        let mut processes = HashMap::new();
+
+        write_stat(path, 100, 1_005);
+        let p = _get_process_data(
+            path,
+            &mut processes,
+            Pid(4242),
+            None,
+            50,
+            &info,
+            ProcessRefreshKind::nothing(),
+            None,
+        )
+        .expect("failed to get process data")
+        .expect("expected a new process");
+        assert_eq!(p.inner.start_time, 1_010);
+        assert_eq!(
+            p.inner.start_time_precise(),
+            UNIX_EPOCH + Duration::from_millis(1_010_300)
+        );
+    }
+
+    // The environment is only read the first time the process is seen unless
+    // `UpdateKind::Always` is used.
+    #[test]
+    fn test_environ_read_once() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = proc_dir.path();
+        let info = SystemInfo {
+            page_size_b: 4096,
+            clock_cycle: 100,
+            boot_time: 1_000,
+            precise_boot_time: Duration::from_secs(1_000),
+        };
+        let pid = Pid(4242);
+        let mut processes = HashMap::new();
+        let refresh = |processes: &mut HashMap<_, _>, environ| {
+            _get_process_data(
+                path,
+                processes,
+                pid,
+                None,
+                50,
+                &info,
+                ProcessRefreshKind::nothing().with_environ(environ),
+                None,
+            )
+            .expect("failed to get process data")
+        };
+
+        write_stat(path, 100, 1_000);
+        let p = refresh(&mut processes, UpdateKind::OnlyIfNotSet).expect("expected a new process");
+        assert!(p.environ().is_empty());
+        processes.insert(pid, p);
+
+        fs::write(path.join("environ"), b"A=1\0").expect("failed to write environ file");
+        assert!(refresh(&mut processes, UpdateKind::OnlyIfNotSet).is_none());
+        assert!(processes[&pid].environ().is_empty());
+
+        assert!(refresh(&mut processes, UpdateKind::Always).is_none());
+        assert_eq!(processes[&pid].environ(), [OsString::from("A=1")]);
+    }
+
+    #[test]
+    fn test_field_status() {
+        let proc_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let path = proc_dir.path();
+        let info = SystemInfo {
+            page_size_b: 4096,
+            clock_cycle: 100,
+            boot_time: 1_000,
+            precise_boot_time: Duration::from_secs(1_000),
+        };
+        let pid = Pid(4242);
+        let mut processes = HashMap::new();
+
+        write_stat(path, 100, 1_000);
+        let p = _get_process_data(
+            path,
+            &mut processes,
+            pid,
+            None,
+            50,
+            &info,
+            ProcessRefreshKind::nothing(),
+            None,
+        )
+        .expect("failed to get process data")
+        .expect("expected a new process");
+        assert_eq!(p.field_status(ProcessField::Exe), FieldStatus::NotRetrieved);
+        processes.insert(pid, p);
+
+        // `cwd`, `environ` and `io` don't exist.
+        std::os::unix::fs::symlink("/usr/bin/true", path.join("exe"))
+            .expect("failed to create exe symlink");
+        fs::write(path.join("cmdline"), b"true\0").expect("failed to write cmdline file");
+        let refresh_kind = ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::Always)
+            .with_cmd(UpdateKind::Always)
+            .with_cwd(UpdateKind::Always)
+            .with_environ(UpdateKind::Always)
+            .with_disk_usage();
+        assert!(_get_process_data(
+            path,
+            &mut processes,
+            pid,
+            None,
+            50,
+            &info,
+            refresh_kind,
+            None,
+        )
+        .expect("failed to get process data")
+        .is_none());
+        let p = &processes[&pid];
+        assert_eq!(p.exe(), Some(Path::new("/usr/bin/true")));
+        assert_eq!(p.field_status(ProcessField::Exe), FieldStatus::Ok);
+        assert_eq!(p.field_status(ProcessField::Cmd), FieldStatus::Ok);
+        assert_eq!(p.cwd(), None);
+        assert_eq!(p.field_status(ProcessField::Cwd), FieldStatus::NotFound);
+        assert_eq!(p.field_status(ProcessField::Environ), FieldStatus::NotFound);
+        assert_eq!(
+            p.field_status(ProcessField::DiskUsage),
+            FieldStatus::NotFound
+        );
+    }
+
+    #[test]
+    fn test_io_priority_raw_conversion() {
+        // `ionice -c 2 -n 7`
+        let priority = IoPriority {
+            class: IoPriorityClass::BestEffort,
+            level: 7,
+        };
+        assert_eq!(io_priority_to_raw(priority), 0x4007);
+        assert_eq!(io_priority_from_raw(0x4007), Some(priority));
+        assert_eq!(
+            io_priority_from_raw(0x6000),
+            Some(IoPriority {
+                class: IoPriorityClass::Idle,
+                level: 0,
+            })
+        );
+        // Hints in the upper bits of the priority data are ignored.
+        assert_eq!(
+            io_priority_from_raw(0x2000 | 1 << 3 | 2),
+            Some(IoPriority {
+                class: IoPriorityClass::Realtime,
+                level: 2,
+            })
         );
+        assert_eq!(io_priority_from_raw(0xE000), None);
     }
 }
@@ -0,0 +1,437 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Pid, ProcessEvent, ProcessEventKind, WatchProcessesError};
+
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::io;
+use std::mem::{size_of, zeroed};
+use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+use std::sync::mpsc::Sender;
+use std::thread::JoinHandle;
+
+// From `linux/connector.h` and `linux/cn_proc.h`.
+const CN_IDX_PROC: u32 = 1;
+const CN_VAL_PROC: u32 = 1;
+const PROC_CN_MCAST_LISTEN: u32 = 1;
+const PROC_EVENT_FORK: u32 = 0x0000_0001;
+const PROC_EVENT_EXEC: u32 = 0x0000_0002;
+const PROC_EVENT_EXIT: u32 = 0x8000_0000;
+
+const NLMSG_HEADER_LEN: usize = size_of::<libc::nlmsghdr>();
+// `struct cn_msg`: the `cb_id` (`idx` and `val`), `seq`, `ack`, `len` and `flags`.
+const CN_MSG_LEN: usize = 20;
+// `struct proc_event` before `event_data`: `what`, `cpu` and `timestamp_ns`.
+const PROC_EVENT_HEADER_LEN: usize = 16;
+
+/// Event of the proc connector, the PIDs are the ones of the kernel: the thread group ID is the
+/// PID of the process and the PID is the one of the thread.
+#[derive(Debug, PartialEq, Eq)]
+enum ProcConnectorEvent {
+    Fork {
+        parent_tgid: i32,
+        child_pid: i32,
+        child_tgid: i32,
+    },
+    Exec {
+        process_pid: i32,
+        process_tgid: i32,
+    },
+    Exit {
+        process_pid: i32,
+        process_tgid: i32,
+        parent_tgid: i32,
+    },
+}
+
+// Netlink socket subscribed to the proc connector, which sends an event whenever a process
+// forks, executes a program or exits.
+//
+// The proc connector only sends events in the initial network namespace, and subscribing to
+// it requires the `CAP_NET_ADMIN` capability.
+pub(crate) struct ProcessWatcherInner {
+    // Writing into it wakes up the thread so it stops.
+    stop: OwnedFd,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ProcessWatcherInner {
+    pub(crate) fn new(sender: Sender<ProcessEvent>) -> Result<Self, WatchProcessesError> {
+        let socket = subscribe().map_err(|error| {
+            sysinfo_debug!("failed to subscribe to the proc connector: {error:?}");
+            start_error(&error)
+        })?;
+        let mut pipe = [0; 2];
+        if unsafe { libc::pipe2(pipe.as_mut_ptr(), libc::O_CLOEXEC) } != 0 {
+            sysinfo_debug!("pipe2 failed: {:?}", io::Error::last_os_error());
+            return Err(WatchProcessesError::FailedToStart);
+        }
+        let (stopped, stop) =
+            unsafe { (OwnedFd::from_raw_fd(pipe[0]), OwnedFd::from_raw_fd(pipe[1])) };
+        let thread = std::thread::spawn(move || receive_events(&socket, &stopped, &sender));
+        Ok(Self {
+            stop,
+            thread: Some(thread),
+        })
+    }
+}
+
+impl Drop for ProcessWatcherInner {
+    fn drop(&mut self) {
+        unsafe {
+            let _ = libc::write(self.stop.as_raw_fd(), [0u8].as_ptr() as *const _, 1);
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn start_error(error: &io::Error) -> WatchProcessesError {
+    match error.raw_os_error() {
+        Some(libc::EPERM | libc::EACCES) => WatchProcessesError::PermissionDenied,
+        // The kernel was built without the proc connector.
+        Some(libc::EPROTONOSUPPORT) => WatchProcessesError::Unsupported,
+        _ => WatchProcessesError::FailedToStart,
+    }
+}
+
+fn subscribe() -> io::Result<OwnedFd> {
+    unsafe {
+        let fd = libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+            libc::NETLINK_CONNECTOR,
+        );
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let socket = OwnedFd::from_raw_fd(fd);
+
+        let mut address: libc::sockaddr_nl = zeroed();
+        address.nl_family = libc::AF_NETLINK as _;
+        address.nl_groups = CN_IDX_PROC;
+        if libc::bind(
+            fd,
+            &address as *const libc::sockaddr_nl as *const libc::sockaddr,
+            size_of::<libc::sockaddr_nl>() as _,
+        ) != 0
+        {
+            return Err(io::Error::last_os_error());
+        }
+
+        let message = listen_message();
+        if libc::send(fd, message.as_ptr() as *const _, message.len(), 0) < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(socket)
+    }
+}
+
+/// Returns the message asking the proc connector to send the events.
+fn listen_message() -> Vec<u8> {
+    let len = NLMSG_HEADER_LEN + CN_MSG_LEN + size_of::<u32>();
+    let mut message = Vec::with_capacity(len);
+    // `struct nlmsghdr`.
+    message.extend_from_slice(&(len as u32).to_ne_bytes());
+    message.extend_from_slice(&(libc::NLMSG_DONE as u16).to_ne_bytes());
+    message.extend_from_slice(&0u16.to_ne_bytes());
+    message.extend_from_slice(&0u32.to_ne_bytes());
+    message.extend_from_slice(&std::process::id().to_ne_bytes());
+    // `struct cn_msg`.
+    message.extend_from_slice(&CN_IDX_PROC.to_ne_bytes());
+    message.extend_from_slice(&CN_VAL_PROC.to_ne_bytes());
+    message.extend_from_slice(&0u32.to_ne_bytes());
+    message.extend_from_slice(&0u32.to_ne_bytes());
+    message.extend_from_slice(&(size_of::<u32>() as u16).to_ne_bytes());
+    message.extend_from_slice(&0u16.to_ne_bytes());
+    // `enum proc_cn_mcast_op`.
+    message.extend_from_slice(&PROC_CN_MCAST_LISTEN.to_ne_bytes());
+    message
+}
+
+fn receive_events(socket: &OwnedFd, stopped: &OwnedFd, sender: &Sender<ProcessEvent>) {
+    // The name and the parent of the processes, since the exit events come after the process
+    // was reaped by its parent.
+    let mut processes: HashMap<Pid, (Option<Pid>, OsString)> = HashMap::new();
+    let mut buffer = vec![0u8; 8192];
+    loop {
+        let mut fds = [
+            libc::pollfd {
+                fd: socket.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            },
+            libc::pollfd {
+                fd: stopped.as_raw_fd(),
+                events: libc::POLLIN,
+                revents: 0,
+            },
+        ];
+        if unsafe { libc::poll(fds.as_mut_ptr(), fds.len() as _, -1) } < 0 {
+            if io::Error::last_os_error().kind() == io::ErrorKind::Interrupted {
+                continue;
+            }
+            sysinfo_debug!("poll failed: {:?}", io::Error::last_os_error());
+            return;
+        }
+        if fds[1].revents != 0 {
+            return;
+        }
+        if fds[0].revents & libc::POLLIN == 0 {
+            sysinfo_debug!("the proc connector socket was closed");
+            return;
+        }
+        let len = unsafe {
+            libc::recv(
+                socket.as_raw_fd(),
+                buffer.as_mut_ptr() as *mut _,
+                buffer.len(),
+                0,
+            )
+        };
+        if len < 0 {
+            match io::Error::last_os_error().raw_os_error() {
+                // Some events were lost because they were not read fast enough.
+                Some(libc::ENOBUFS | libc::EINTR) => continue,
+                _error => {
+                    sysinfo_debug!("recv failed: {_error:?}");
+                    return;
+                }
+            }
+        }
+        for event in parse_messages(&buffer[..len as usize]) {
+            if let Some(event) = handle_event(event, &mut processes) {
+                if sender.send(event).is_err() {
+                    // The receiver was dropped.
+                    return;
+                }
+            }
+        }
+    }
+}
+
+fn handle_event(
+    event: ProcConnectorEvent,
+    processes: &mut HashMap<Pid, (Option<Pid>, OsString)>,
+) -> Option<ProcessEvent> {
+    match event {
+        // The threads are created with `clone` too, they are skipped.
+        ProcConnectorEvent::Fork {
+            parent_tgid,
+            child_pid,
+            child_tgid,
+        } if child_pid == child_tgid => {
+            let pid = Pid(child_tgid as _);
+            let parent_pid = Pid(parent_tgid as _);
+            // The child didn't execute its program yet, so it has the name of its parent.
+            let name = read_comm(pid)
+                .or_else(|| processes.get(&parent_pid).map(|(_, name)| name.clone()))
+                .unwrap_or_default();
+            processes.insert(pid, (Some(parent_pid), name.clone()));
+            Some(ProcessEvent {
+                kind: ProcessEventKind::Started,
+                pid,
+                parent_pid: Some(parent_pid),
+                name,
+            })
+        }
+        ProcConnectorEvent::Exec {
+            process_pid,
+            process_tgid,
+        } if process_pid == process_tgid => {
+            let pid = Pid(process_tgid as _);
+            if let Some(name) = read_comm(pid) {
+                processes.entry(pid).or_insert((None, OsString::new())).1 = name;
+            }
+            None
+        }
+        ProcConnectorEvent::Exit {
+            process_pid,
+            process_tgid,
+            parent_tgid,
+        } if process_pid == process_tgid => {
+            let pid = Pid(process_tgid as _);
+            let (parent_pid, name) = match processes.remove(&pid) {
+                Some((parent_pid, name)) => (parent_pid, name),
+                None => (None, read_comm(pid).unwrap_or_default()),
+            };
+            // Kernels older than 4.18 don't send the parent.
+            let parent_pid = parent_pid.or((parent_tgid > 0).then_some(Pid(parent_tgid as _)));
+            Some(ProcessEvent {
+                kind: ProcessEventKind::Stopped,
+                pid,
+                parent_pid,
+                name,
+            })
+        }
+        _ => None,
+    }
+}
+
+fn read_comm(pid: Pid) -> Option<OsString> {
+    use std::os::unix::ffi::OsStringExt;
+
+    let mut comm = std::fs::read(format!("/proc/{pid}/comm")).ok()?;
+    if comm.last() == Some(&b'\n') {
+        comm.pop();
+    }
+    Some(OsString::from_vec(comm))
+}
+
+fn read_u32(buffer: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_ne_bytes(
+        buffer.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_i32(buffer: &[u8], offset: usize) -> Option<i32> {
+    read_u32(buffer, offset).map(|value| value as i32)
+}
+
+/// Parses the netlink messages received from the proc connector.
+fn parse_messages(mut buffer: &[u8]) -> Vec<ProcConnectorEvent> {
+    let mut events = Vec::new();
+    while let Some(len) = read_u32(buffer, 0) {
+        let len = len as usize;
+        if len < NLMSG_HEADER_LEN || len > buffer.len() {
+            break;
+        }
+        if let Some(event) = parse_proc_event(&buffer[NLMSG_HEADER_LEN..len]) {
+            events.push(event);
+        }
+        // The messages are aligned on 4 bytes.
+        buffer = buffer.get(len.next_multiple_of(4)..).unwrap_or_default();
+    }
+    events
+}
+
+fn parse_proc_event(message: &[u8]) -> Option<ProcConnectorEvent> {
+    if read_u32(message, 0)? != CN_IDX_PROC || read_u32(message, 4)? != CN_VAL_PROC {
+        return None;
+    }
+    let event = message.get(CN_MSG_LEN..)?;
+    let data = event.get(PROC_EVENT_HEADER_LEN..)?;
+    match read_u32(event, 0)? {
+        PROC_EVENT_FORK => Some(ProcConnectorEvent::Fork {
+            parent_tgid: read_i32(data, 4)?,
+            child_pid: read_i32(data, 8)?,
+            child_tgid: read_i32(data, 12)?,
+        }),
+        PROC_EVENT_EXEC => Some(ProcConnectorEvent::Exec {
+            process_pid: read_i32(data, 0)?,
+            process_tgid: read_i32(data, 4)?,
+        }),
+        PROC_EVENT_EXIT => Some(ProcConnectorEvent::Exit {
+            process_pid: read_i32(data, 0)?,
+            process_tgid: read_i32(data, 4)?,
+            // Only sent since Linux 4.18.
+            parent_tgid: read_i32(data, 20).unwrap_or(0),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(what: u32, data: &[i32]) -> Vec<u8> {
+        let data: Vec<u8> = data.iter().flat_map(|value| value.to_ne_bytes()).collect();
+        let len = NLMSG_HEADER_LEN + CN_MSG_LEN + PROC_EVENT_HEADER_LEN + data.len();
+        let mut message = vec![0; NLMSG_HEADER_LEN];
+        message[..4].copy_from_slice(&(len as u32).to_ne_bytes());
+        message.extend_from_slice(&CN_IDX_PROC.to_ne_bytes());
+        message.extend_from_slice(&CN_VAL_PROC.to_ne_bytes());
+        message.extend_from_slice(&[0; CN_MSG_LEN - 8]);
+        message.extend_from_slice(&what.to_ne_bytes());
+        message.extend_from_slice(&[0; PROC_EVENT_HEADER_LEN - 4]);
+        message.extend_from_slice(&data);
+        message
+    }
+
+    #[test]
+    fn check_listen_message() {
+        let message = listen_message();
+        assert_eq!(message.len(), 40);
+        assert_eq!(read_u32(&message, 0), Some(40));
+        assert_eq!(read_u32(&message, NLMSG_HEADER_LEN), Some(CN_IDX_PROC));
+        assert_eq!(read_u32(&message, 36), Some(PROC_CN_MCAST_LISTEN));
+    }
+
+    #[test]
+    fn check_parse_messages() {
+        let mut buffer = message(PROC_EVENT_FORK, &[10, 10, 11, 11]);
+        buffer.extend(message(PROC_EVENT_EXEC, &[11, 11]));
+        buffer.extend(message(PROC_EVENT_EXIT, &[11, 11, 0, 0, 10, 10]));
+        // Other events, like `PROC_EVENT_UID`, are skipped.
+        buffer.extend(message(0x4, &[11, 11, 0, 0]));
+        assert_eq!(
+            parse_messages(&buffer),
+            [
+                ProcConnectorEvent::Fork {
+                    parent_tgid: 10,
+                    child_pid: 11,
+                    child_tgid: 11,
+                },
+                ProcConnectorEvent::Exec {
+                    process_pid: 11,
+                    process_tgid: 11,
+                },
+                ProcConnectorEvent::Exit {
+                    process_pid: 11,
+                    process_tgid: 11,
+                    parent_tgid: 10,
+                },
+            ]
+        );
+        // Truncated messages are ignored.
+        assert!(parse_messages(&buffer[..NLMSG_HEADER_LEN + 4]).is_empty());
+        // Before Linux 4.18, the exit events don't contain the parent.
+        assert_eq!(
+            parse_messages(&message(PROC_EVENT_EXIT, &[11, 11, 0, 0])),
+            [ProcConnectorEvent::Exit {
+                process_pid: 11,
+                process_tgid: 11,
+                parent_tgid: 0,
+            }]
+        );
+    }
+
+    #[test]
+    fn check_handle_event() {
+        let mut processes = HashMap::new();
+        processes.insert(Pid((i32::MAX - 1) as _), (None, OsString::from("parent")));
+
+        // A new thread.
+        let thread = ProcConnectorEvent::Fork {
+            parent_tgid: i32::MAX - 1,
+            child_pid: 12,
+            child_tgid: 11,
+        };
+        assert_eq!(handle_event(thread, &mut processes), None);
+
+        // The process doesn't exist, so its name is the one of its parent.
+        let fork = ProcConnectorEvent::Fork {
+            parent_tgid: i32::MAX - 1,
+            child_pid: i32::MAX,
+            child_tgid: i32::MAX,
+        };
+        let started = handle_event(fork, &mut processes).unwrap();
+        assert_eq!(started.kind, ProcessEventKind::Started);
+        assert_eq!(started.pid, Pid(i32::MAX as _));
+        assert_eq!(started.parent_pid, Some(Pid((i32::MAX - 1) as _)));
+        assert_eq!(started.name, "parent");
+
+        let exit = ProcConnectorEvent::Exit {
+            process_pid: i32::MAX,
+            process_tgid: i32::MAX,
+            parent_tgid: 0,
+        };
+        let stopped = handle_event(exit, &mut processes).unwrap();
+        assert_eq!(stopped.kind, ProcessEventKind::Stopped);
+        assert_eq!(stopped.parent_pid, Some(Pid((i32::MAX - 1) as _)));
+        assert_eq!(stopped.name, "parent");
+        assert!(!processes.contains_key(&Pid(i32::MAX as _)));
+    }
+}
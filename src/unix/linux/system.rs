@@ -1,77 +1,28 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::sys::cpu::{get_physical_core_count, CpusWrapper};
+use crate::common::rapl::{RaplCounter, RaplPackageCounters};
+use crate::common::virtualization::{cpuid_hypervisor, hypervisor_from_dmi};
+use crate::sys::cpu::{get_physical_core_count, get_vendor_id_and_brand, CpusWrapper};
 use crate::sys::process::{compute_cpu_usage, refresh_procs};
-use crate::sys::utils::{get_all_utf8_data, to_u64};
+use crate::sys::utils::{get_all_utf8_data, getrlimit, to_u64};
+use crate::unix::utils::get_clock_time;
+use crate::utils::RefreshPool;
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    ContainerKind, Cpu, CpuRefreshKind, DomainKind, HardwareInfo, Hypervisor, LoadAvg,
+    MemoryRefreshKind, Pid, Pressure, PressureStall, Process, ProcessRefreshKind,
+    ProcessesToUpdate, RefreshError, RefreshSubsystem, TpmVersion, Virtualization,
 };
 
-use libc::{self, c_char, sysconf, _SC_CLK_TCK, _SC_HOST_NAME_MAX, _SC_PAGESIZE};
+use libc::{self, c_char, sysconf, _SC_HOST_NAME_MAX, _SC_PAGESIZE};
 
 use std::cmp::min;
 use std::collections::HashMap;
-use std::ffi::CStr;
 use std::fs::File;
-use std::io::Read;
-use std::path::Path;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use std::sync::{atomic::AtomicIsize, OnceLock};
 use std::time::Duration;
 
-unsafe fn getrlimit() -> Option<libc::rlimit> {
-    let mut limits = libc::rlimit {
-        rlim_cur: 0,
-        rlim_max: 0,
-    };
-
-    if libc::getrlimit(libc::RLIMIT_NOFILE, &mut limits) != 0 {
-        None
-    } else {
-        Some(limits)
-    }
-}
-
-pub(crate) fn get_max_nb_fds() -> usize {
-    unsafe {
-        let mut limits = libc::rlimit {
-            rlim_cur: 0,
-            rlim_max: 0,
-        };
-        if libc::getrlimit(libc::RLIMIT_NOFILE, &mut limits) != 0 {
-            // Most Linux system now defaults to 1024.
-            1024 / 2
-        } else {
-            limits.rlim_max as usize / 2
-        }
-    }
-}
-
-// This whole thing is to prevent having too many files open at once. It could be problematic
-// for processes using a lot of files and using sysinfo at the same time.
-pub(crate) fn remaining_files() -> &'static AtomicIsize {
-    static REMAINING_FILES: OnceLock<AtomicIsize> = OnceLock::new();
-    REMAINING_FILES.get_or_init(|| unsafe {
-        let Some(mut limits) = getrlimit() else {
-            // Most Linux system now defaults to 1024.
-            return AtomicIsize::new(1024 / 2);
-        };
-        // We save the value in case the update fails.
-        let current = limits.rlim_cur;
-
-        // The set the soft limit to the hard one.
-        limits.rlim_cur = limits.rlim_max;
-        // In this part, we leave minimum 50% of the available file descriptors to the process
-        // using sysinfo.
-        AtomicIsize::new(if libc::setrlimit(libc::RLIMIT_NOFILE, &limits) == 0 {
-            limits.rlim_cur / 2
-        } else {
-            current / 2
-        } as _)
-    })
-}
-
 declare_signals! {
     libc::c_int,
     Signal::Hangup => libc::SIGHUP,
@@ -146,20 +97,31 @@ pub(crate) struct SystemInfo {
     pub(crate) page_size_b: u64,
     pub(crate) clock_cycle: u64,
     pub(crate) boot_time: u64,
+    /// Unlike `boot_time`, it isn't rounded to the second.
+    pub(crate) precise_boot_time: Duration,
 }
 
 impl SystemInfo {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
+        let boot_time = boot_time();
         unsafe {
             Self {
                 page_size_b: sysconf(_SC_PAGESIZE) as _,
-                clock_cycle: sysconf(_SC_CLK_TCK) as _,
-                boot_time: boot_time(),
+                clock_cycle: crate::unix::utils::clock_ticks_per_second(),
+                boot_time,
+                precise_boot_time: precise_boot_time()
+                    .unwrap_or_else(|| Duration::from_secs(boot_time)),
             }
         }
     }
 }
 
+/// The start time of the processes is counted from the boot with the same clock as
+/// `CLOCK_BOOTTIME`, so the boot time is the difference between both clocks.
+fn precise_boot_time() -> Option<Duration> {
+    get_clock_time(libc::CLOCK_REALTIME)?.checked_sub(get_clock_time(libc::CLOCK_BOOTTIME)?)
+}
+
 pub(crate) struct SystemInner {
     process_list: HashMap<Pid, Process>,
     mem_total: u64,
@@ -173,6 +135,7 @@ pub(crate) struct SystemInner {
     swap_free: u64,
     info: SystemInfo,
     cpus: CpusWrapper,
+    refresh_pool: RefreshPool,
 }
 
 impl SystemInner {
@@ -225,13 +188,21 @@ impl SystemInner {
             swap_total: 0,
             swap_free: 0,
             cpus: CpusWrapper::new(),
+            refresh_pool: RefreshPool::new(),
             info: SystemInfo::new(),
         }
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
+        let _ = self.try_refresh_memory_specifics(refresh_kind);
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
         if !refresh_kind.ram() && !refresh_kind.swap() {
-            return;
+            return Ok(());
         }
         let mut mem_available_found = false;
         read_table("/proc/meminfo", ':', |key, value_kib| {
@@ -252,7 +223,8 @@ impl SystemInner {
             };
             // /proc/meminfo reports KiB, though it says "kB". Convert it.
             *field = value_kib.saturating_mul(1_024);
-        });
+        })
+        .map_err(|error| RefreshError::new(RefreshSubsystem::Memory, "/proc/meminfo", error))?;
 
         // Linux < 3.14 may not have MemAvailable in /proc/meminfo
         // So it should fallback to the old way of estimating available memory
@@ -265,12 +237,24 @@ impl SystemInner {
                 .saturating_add(self.mem_slab_reclaimable)
                 .saturating_sub(self.mem_shmem);
         }
+        Ok(())
     }
 
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         crate::CGroupLimits::new(self)
     }
 
+    pub(crate) fn cgroup_cpu_quota(&self) -> Option<f64> {
+        if let Ok(content) = std::fs::read_to_string("/sys/fs/cgroup/cpu.max") {
+            // cgroups v2
+            return parse_cpu_max(&content);
+        }
+        // cgroups v1, the quota is `-1` when no limit is set.
+        let quota = read_u64("/sys/fs/cgroup/cpu/cpu.cfs_quota_us")?;
+        let period = read_u64("/sys/fs/cgroup/cpu/cpu.cfs_period_us")?;
+        (period != 0).then(|| quota as f64 / period as f64)
+    }
+
     pub(crate) fn refresh_cpu_specifics(&mut self, refresh_kind: CpuRefreshKind) {
         self.refresh_cpus(false, refresh_kind);
     }
@@ -281,18 +265,26 @@ impl SystemInner {
         refresh_kind: ProcessRefreshKind,
     ) -> usize {
         let uptime = Self::uptime();
-        let nb_updated = refresh_procs(
-            &mut self.process_list,
-            Path::new("/proc"),
-            uptime,
-            &self.info,
-            processes_to_update,
-            refresh_kind,
-        );
+        let process_list = &mut self.process_list;
+        let info = &self.info;
+        let nb_updated = self.refresh_pool.install(|| {
+            refresh_procs(
+                process_list,
+                Path::new("/proc"),
+                uptime,
+                info,
+                processes_to_update,
+                refresh_kind,
+            )
+        });
         self.update_procs_cpu(refresh_kind);
         nb_updated
     }
 
+    pub(crate) fn set_refresh_parallelism(&mut self, threads: usize) {
+        self.refresh_pool.set_threads(threads);
+    }
+
     // COMMON PART
     //
     // Need to be moved into a "common" file to avoid duplication.
@@ -317,6 +309,10 @@ impl SystemInner {
         &self.cpus.cpus
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus.cpus
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -355,10 +351,23 @@ impl SystemInner {
             .unwrap_or_default()
     }
 
+    pub(crate) fn active_time() -> u64 {
+        // Unlike `CLOCK_BOOTTIME`, it doesn't count while the system is suspended.
+        get_clock_time(libc::CLOCK_MONOTONIC).map_or(0, |time| time.as_secs())
+    }
+
     pub(crate) fn boot_time() -> u64 {
         boot_time()
     }
 
+    pub(crate) fn reboot_required() -> Option<bool> {
+        reboot_required(Path::new("/"), Self::kernel_version().as_deref())
+    }
+
+    pub(crate) fn clock_ticks_per_second() -> u64 {
+        crate::unix::utils::clock_ticks_per_second()
+    }
+
     pub(crate) fn load_average() -> LoadAvg {
         let mut s = String::new();
         if File::open("/proc/loadavg")
@@ -380,6 +389,31 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn memory_pressure() -> Option<Pressure> {
+        read_pressure("/proc/pressure/memory")
+    }
+
+    pub(crate) fn cpu_pressure() -> Option<Pressure> {
+        read_pressure("/proc/pressure/cpu")
+    }
+
+    pub(crate) fn io_pressure() -> Option<Pressure> {
+        read_pressure("/proc/pressure/io")
+    }
+
+    pub(crate) fn rapl_counters() -> Option<Vec<RaplPackageCounters>> {
+        // The AMD CPUs are exposed through the same `intel-rapl` interface but their counters
+        // don't have the same meaning, so they are ignored.
+        if !cfg!(any(target_arch = "x86", target_arch = "x86_64"))
+            || !get_vendor_id_and_brand()
+                .values()
+                .any(|(vendor_id, _)| vendor_id == "GenuineIntel")
+        {
+            return None;
+        }
+        read_rapl_counters(Path::new("/sys/class/powercap"))
+    }
+
     #[cfg(not(target_os = "android"))]
     pub(crate) fn name() -> Option<String> {
         get_system_info_linux(
@@ -454,6 +488,18 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn domain_name() -> Option<String> {
+        crate::unix::utils::get_domain_name(Self::host_name().as_deref())
+    }
+
+    pub(crate) fn domain_kind() -> Option<DomainKind> {
+        Self::domain_name().map(|_| DomainKind::Domain)
+    }
+
+    pub(crate) fn fqdn() -> Option<String> {
+        Self::host_name().and_then(|host_name| crate::unix::utils::get_fqdn(&host_name))
+    }
+
     pub(crate) fn kernel_version() -> Option<String> {
         let mut raw = std::mem::MaybeUninit::<libc::utsname>::zeroed();
 
@@ -489,6 +535,36 @@ impl SystemInner {
         get_system_info_android(InfoType::OsVersion)
     }
 
+    #[cfg(not(target_os = "android"))]
+    pub(crate) fn os_build() -> Option<String> {
+        get_system_info_linux(
+            InfoType::BuildId,
+            Path::new("/etc/os-release"),
+            Path::new(""),
+        )
+    }
+
+    #[cfg(target_os = "android")]
+    pub(crate) fn os_build() -> Option<String> {
+        get_system_info_android(InfoType::BuildId)
+    }
+
+    #[cfg(not(target_os = "android"))]
+    pub(crate) fn os_display_version() -> Option<String> {
+        get_system_info_linux(
+            InfoType::Codename,
+            Path::new("/etc/os-release"),
+            Path::new("/etc/lsb-release"),
+        )
+    }
+
+    #[cfg(target_os = "android")]
+    pub(crate) fn os_display_version() -> Option<String> {
+        // Currently get_system_info_android doesn't support InfoType::Codename and always
+        // returns None.
+        get_system_info_android(InfoType::Codename)
+    }
+
     #[cfg(not(target_os = "android"))]
     pub(crate) fn distribution_id() -> String {
         get_system_info_linux(
@@ -536,24 +612,15 @@ impl SystemInner {
     }
 
     pub(crate) fn cpu_arch() -> Option<String> {
-        let mut raw = std::mem::MaybeUninit::<libc::utsname>::uninit();
+        Self::kernel_arch()
+    }
 
-        unsafe {
-            if libc::uname(raw.as_mut_ptr()) != 0 {
-                return None;
-            }
-            let info = raw.assume_init();
-            // Converting `&[i8]` to `&[u8]`.
-            let machine: &[u8] =
-                std::slice::from_raw_parts(info.machine.as_ptr() as *const _, info.machine.len());
+    pub(crate) fn kernel_arch() -> Option<String> {
+        crate::unix::utils::get_uname_machine()
+    }
 
-            CStr::from_bytes_until_nul(machine)
-                .ok()
-                .and_then(|res| match res.to_str() {
-                    Ok(arch) => Some(arch.to_string()),
-                    Err(_) => None,
-                })
-        }
+    pub(crate) fn is_translated() -> Option<bool> {
+        None
     }
 
     pub(crate) fn physical_core_count() -> Option<usize> {
@@ -576,6 +643,346 @@ impl SystemInner {
             }
         }
     }
+
+    pub(crate) fn open_files() -> Option<(u64, u64)> {
+        parse_file_nr(&get_all_utf8_data("/proc/sys/fs/file-nr", 100).ok()?)
+    }
+
+    pub(crate) fn max_pid() -> Option<u64> {
+        get_all_utf8_data("/proc/sys/kernel/pid_max", 30)
+            .ok()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
+    pub(crate) fn threads_total() -> Option<u64> {
+        parse_loadavg_threads(&get_all_utf8_data("/proc/loadavg", 100).ok()?)
+    }
+
+    pub(crate) fn hardware() -> HardwareInfo {
+        get_hardware_info(Path::new("/sys/class/dmi/id"))
+    }
+
+    pub(crate) fn process_exists(pid: Pid) -> bool {
+        if !crate::unix::utils::process_exists(pid) {
+            return false;
+        }
+        // `kill` succeeds on zombie processes, so their state is checked as well.
+        match std::fs::read(format!("/proc/{pid}/stat")) {
+            Ok(stat) => !matches!(
+                stat.iter()
+                    .rposition(|&c| c == b')')
+                    .and_then(|pos| stat.get(pos + 2)),
+                Some(b'Z' | b'X')
+            ),
+            // It can fail if `/proc` is mounted with `hidepid`.
+            Err(err) => err.kind() != std::io::ErrorKind::NotFound,
+        }
+    }
+
+    pub(crate) fn virtualization() -> Option<Virtualization> {
+        Some(get_virtualization(Path::new("/"), cpuid_hypervisor()))
+    }
+
+    pub(crate) fn machine_id() -> Option<String> {
+        // The D-Bus one is used by the distributions which don't use systemd.
+        read_id("/etc/machine-id").or_else(|| read_id("/var/lib/dbus/machine-id"))
+    }
+
+    pub(crate) fn boot_id() -> Option<String> {
+        read_id("/proc/sys/kernel/random/boot_id")
+    }
+
+    pub(crate) fn kernel_cmdline() -> Option<String> {
+        let cmdline = std::fs::read_to_string("/proc/cmdline").ok()?;
+        Some(cmdline.trim().to_owned())
+    }
+
+    pub(crate) fn secure_boot() -> Option<bool> {
+        read_secure_boot(Path::new("/sys/firmware/efi/efivars"))
+    }
+
+    pub(crate) fn tpm_version() -> Option<TpmVersion> {
+        read_tpm_version(Path::new("/sys/class/tpm/tpm0"))
+    }
+
+    pub(crate) fn kernel_parameter(name: &str) -> Option<String> {
+        let path = kernel_parameter_path(name)?;
+        // It fails for the write-only parameters and the ones restricted to root.
+        let value = std::fs::read_to_string(path).ok()?;
+        Some(value.trim_end_matches('\n').to_owned())
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        crate::unix::utils::get_timezone()
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        crate::unix::utils::get_locale_from_env()
+    }
+
+    pub(crate) fn time_since_boot_monotonic() -> Option<Duration> {
+        // Unlike `CLOCK_MONOTONIC`, it keeps counting while the system is suspended.
+        get_clock_time(libc::CLOCK_BOOTTIME)
+    }
+}
+
+/// Debian and Ubuntu create `/var/run/reboot-required` when an update needs a reboot. Otherwise,
+/// the running kernel is compared to the newest installed kernel with the same flavour (like
+/// `generic` in `6.8.0-45-generic`) in `/boot`, which is only a heuristic.
+fn reboot_required(root: &Path, running_kernel: Option<&str>) -> Option<bool> {
+    if root.join("var/run/reboot-required").exists() {
+        return Some(true);
+    }
+    let running_kernel = running_kernel?;
+    let flavour = kernel_flavour(running_kernel);
+    let newest_kernel = std::fs::read_dir(root.join("boot"))
+        .ok()?
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let version = name
+                .strip_prefix("vmlinuz-")
+                .or_else(|| name.strip_prefix("vmlinux-"))?;
+            (version.starts_with(|c: char| c.is_ascii_digit())
+                && kernel_flavour(version) == flavour)
+                .then(|| version.to_owned())
+        })
+        .max_by(|a, b| compare_kernel_versions(a, b))?;
+    Some(compare_kernel_versions(&newest_kernel, running_kernel) == std::cmp::Ordering::Greater)
+}
+
+fn kernel_version_parts(version: &str) -> impl Iterator<Item = &str> {
+    version
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+}
+
+fn kernel_flavour(version: &str) -> Vec<&str> {
+    kernel_version_parts(version)
+        .filter(|part| part.parse::<u64>().is_err())
+        .collect()
+}
+
+/// Compares the numbers of the versions numerically, so `6.10` is newer than `6.9`.
+fn compare_kernel_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    let key = |version| {
+        kernel_version_parts(version)
+            .map(|part| (part.parse::<u64>().ok(), part))
+            .collect::<Vec<_>>()
+    };
+    key(a).cmp(&key(b))
+}
+
+/// Parses `/proc/sys/fs/file-nr`, which contains the number of allocated file handles, the
+/// number of unused ones (always `0` since Linux 2.6) and the maximum.
+fn parse_file_nr(content: &str) -> Option<(u64, u64)> {
+    let mut values = content
+        .split_whitespace()
+        .map(|value| value.parse::<u64>().ok());
+    let allocated = values.next()??;
+    let unused = values.next()??;
+    let max = values.next()??;
+    Some((allocated.saturating_sub(unused), max))
+}
+
+/// The fourth field of `/proc/loadavg` is the number of runnable threads and the total number
+/// of threads, like `2/1047`.
+fn parse_loadavg_threads(content: &str) -> Option<u64> {
+    content
+        .split_whitespace()
+        .nth(3)?
+        .split_once('/')?
+        .1
+        .parse()
+        .ok()
+}
+
+/// Like `sysctl`, accepts both the `vm.swappiness` and the `vm/swappiness` forms. The latter is
+/// needed when a component contains a dot, like a network interface name.
+fn kernel_parameter_path(name: &str) -> Option<PathBuf> {
+    let separator = if name.contains('/') { '/' } else { '.' };
+    let mut path = PathBuf::from("/proc/sys");
+    for component in name.split(separator) {
+        // Prevents escaping from `/proc/sys`.
+        if matches!(component, "" | "." | "..") || component.contains('\0') {
+            return None;
+        }
+        path.push(component);
+    }
+    Some(path)
+}
+
+/// The `SecureBoot` EFI variable contains 4 bytes of attributes followed by the value, which is
+/// `1` if Secure Boot is enabled. `efivars_dir` only exists if the system was booted with UEFI.
+fn read_secure_boot(efivars_dir: &Path) -> Option<bool> {
+    const SECURE_BOOT_VARIABLE: &str = "SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c";
+
+    let content = std::fs::read(efivars_dir.join(SECURE_BOOT_VARIABLE)).ok()?;
+    content.get(4).map(|value| *value == 1)
+}
+
+fn read_tpm_version(tpm_dir: &Path) -> Option<TpmVersion> {
+    let version = std::fs::read_to_string(tpm_dir.join("tpm_version_major")).ok()?;
+    match version.trim() {
+        "1" => Some(TpmVersion::V1),
+        "2" => Some(TpmVersion::V2),
+        _ => None,
+    }
+}
+
+fn read_id(path: &str) -> Option<String> {
+    let content = std::fs::read_to_string(path).ok()?;
+    let id = content.trim();
+    // systemd writes `uninitialized` until the first boot is complete.
+    (!id.is_empty() && id != "uninitialized").then(|| id.to_owned())
+}
+
+/// Reads the counters of the `intel-rapl:N` zones of `powercap_dir`, if any. The `core` and `dram`
+/// counters are in the `intel-rapl:N:M` subzones.
+fn read_rapl_counters(powercap_dir: &Path) -> Option<Vec<RaplPackageCounters>> {
+    fn read_zone(zone_dir: &Path) -> Option<(String, RaplCounter)> {
+        let read = |file_name| std::fs::read_to_string(zone_dir.join(file_name)).ok();
+        let counter = RaplCounter {
+            energy: read("energy_uj")?.trim().parse().ok()?,
+            max_energy_range: read("max_energy_range_uj")?.trim().parse().ok()?,
+        };
+        Some((read("name")?.trim().to_owned(), counter))
+    }
+
+    let mut packages = Vec::new();
+    for entry in std::fs::read_dir(powercap_dir).ok()?.flatten() {
+        let file_name = entry.file_name();
+        let Some(zone) = file_name
+            .to_str()
+            .and_then(|f| f.strip_prefix("intel-rapl:"))
+        else {
+            continue;
+        };
+        if zone.contains(':') {
+            continue;
+        }
+        let zone_dir = entry.path();
+        // The other zones, like `psys`, aren't attached to a package.
+        let Some((package_id, package)) = read_zone(&zone_dir).and_then(|(name, counter)| {
+            Some((name.strip_prefix("package-")?.parse().ok()?, counter))
+        }) else {
+            continue;
+        };
+        let mut counters = RaplPackageCounters {
+            package_id,
+            package,
+            core: None,
+            dram: None,
+        };
+        let prefix = format!("intel-rapl:{zone}:");
+        for subzone in std::fs::read_dir(&zone_dir).into_iter().flatten().flatten() {
+            if !subzone.file_name().to_string_lossy().starts_with(&prefix) {
+                continue;
+            }
+            match read_zone(&subzone.path()) {
+                Some((name, counter)) if name == "core" => counters.core = Some(counter),
+                Some((name, counter)) if name == "dram" => counters.dram = Some(counter),
+                _ => {}
+            }
+        }
+        packages.push(counters);
+    }
+    if packages.is_empty() {
+        return None;
+    }
+    packages.sort_unstable_by_key(|package| package.package_id);
+    Some(packages)
+}
+
+/// Only the serial numbers and a few other fields of the DMI information are restricted to root,
+/// so all the ones we need are readable. They don't exist on most ARM boards.
+fn get_hardware_info(dmi_dir: &Path) -> HardwareInfo {
+    let read = |file_name| {
+        let content = std::fs::read_to_string(dmi_dir.join(file_name)).ok()?;
+        Some(content.trim().to_owned()).filter(|content| !content.is_empty())
+    };
+    HardwareInfo {
+        vendor: read("sys_vendor"),
+        product_name: read("product_name"),
+        board_name: read("board_name"),
+        bios_version: read("bios_version"),
+        bios_date: read("bios_date"),
+        chassis_type: read("chassis_type").and_then(|chassis_type| chassis_type.parse().ok()),
+    }
+}
+
+/// Follows the same heuristics as `systemd-detect-virt`. `root` is only used by the tests.
+fn get_virtualization(root: &Path, cpuid: Option<Hypervisor>) -> Virtualization {
+    let host = get_hypervisor(root, cpuid);
+    match get_container_kind(root) {
+        Some(kind) => Virtualization::Container { kind, host },
+        None => host.map_or(Virtualization::BareMetal, Virtualization::VirtualMachine),
+    }
+}
+
+fn get_container_kind(root: &Path) -> Option<ContainerKind> {
+    // Written by systemd and most of the container managers.
+    if let Ok(name) = std::fs::read_to_string(root.join("run/systemd/container")) {
+        return match name.trim() {
+            "docker" => Some(ContainerKind::Docker),
+            "podman" => Some(ContainerKind::Podman),
+            "lxc" | "lxc-libvirt" => Some(ContainerKind::Lxc),
+            // systemd considers WSL as a container but it runs in a virtual machine.
+            "" | "wsl" => None,
+            name => Some(ContainerKind::Other(name.to_owned())),
+        };
+    }
+    if root.join("run/.containerenv").exists() {
+        return Some(ContainerKind::Podman);
+    }
+    if root.join(".dockerenv").exists() {
+        return Some(ContainerKind::Docker);
+    }
+    // Only useful with cgroups v1, the paths are hidden by the cgroup namespaces otherwise.
+    let cgroup = std::fs::read_to_string(root.join("proc/1/cgroup")).ok()?;
+    if cgroup.contains("libpod") {
+        Some(ContainerKind::Podman)
+    } else if cgroup.contains("/docker") {
+        Some(ContainerKind::Docker)
+    } else if cgroup.contains("/lxc") {
+        Some(ContainerKind::Lxc)
+    } else {
+        None
+    }
+}
+
+fn get_hypervisor(root: &Path, cpuid: Option<Hypervisor>) -> Option<Hypervisor> {
+    let read = |path: &str| std::fs::read_to_string(root.join(path)).unwrap_or_default();
+
+    if read("proc/sys/kernel/osrelease")
+        .to_lowercase()
+        .contains("microsoft")
+    {
+        return Some(Hypervisor::Wsl);
+    }
+    // The privileged Xen domain runs on the hardware but has the Xen CPUID signature.
+    if read("proc/xen/capabilities").contains("control_d") {
+        return None;
+    }
+    let hardware = get_hardware_info(&root.join("sys/class/dmi/id"));
+    if let Some(hypervisor) =
+        hypervisor_from_dmi([hardware.vendor.as_deref(), hardware.product_name.as_deref()])
+    {
+        return Some(hypervisor);
+    }
+    if cpuid.is_some() {
+        return cpuid;
+    }
+    // The paravirtualized Xen guests don't have the CPUID signature.
+    if root.join("proc/xen").exists() {
+        return Some(Hypervisor::Xen);
+    }
+    read("proc/cpuinfo")
+        .lines()
+        .filter(|line| line.starts_with("flags"))
+        .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"))
+        .then(|| Hypervisor::Other(String::new()))
 }
 
 fn read_u64(filename: &str) -> Option<u64> {
@@ -590,23 +997,58 @@ fn read_u64(filename: &str) -> Option<u64> {
     result
 }
 
-fn read_table<F>(filename: &str, colsep: char, mut f: F)
+fn read_pressure(path: &str) -> Option<Pressure> {
+    parse_pressure(&std::fs::read_to_string(path).ok()?)
+}
+
+/// Parses a PSI file, which looks like this (`full` is missing for the CPU before Linux 5.13):
+///
+/// ```text
+/// some avg10=0.12 avg60=0.05 avg300=0.01 total=123456
+/// full avg10=0.00 avg60=0.00 avg300=0.00 total=7890
+/// ```
+fn parse_pressure(content: &str) -> Option<Pressure> {
+    let mut pressure = Pressure::default();
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next();
+        let mut stall = PressureStall::default();
+        for field in fields {
+            let (key, value) = field.split_once('=')?;
+            match key {
+                "avg10" => stall.avg10 = value.parse().ok()?,
+                "avg60" => stall.avg60 = value.parse().ok()?,
+                "avg300" => stall.avg300 = value.parse().ok()?,
+                "total" => stall.total = value.parse().ok()?,
+                _ => {}
+            }
+        }
+        match kind {
+            Some("some") => pressure.some = Some(stall),
+            Some("full") => pressure.full = Some(stall),
+            _ => {}
+        }
+    }
+    (pressure.some.is_some() || pressure.full.is_some()).then_some(pressure)
+}
+
+fn read_table<F>(filename: &str, colsep: char, mut f: F) -> io::Result<()>
 where
     F: FnMut(&str, u64),
 {
-    if let Ok(content) = get_all_utf8_data(filename, 16_635) {
-        content
-            .split('\n')
-            .flat_map(|line| {
-                let mut split = line.split(colsep);
-                let key = split.next()?;
-                let value = split.next()?;
-                let value0 = value.trim_start().split(' ').next()?;
-                let value0_u64 = u64::from_str(value0).ok()?;
-                Some((key, value0_u64))
-            })
-            .for_each(|(k, v)| f(k, v));
-    }
+    let content = get_all_utf8_data(filename, 16_635)?;
+    content
+        .split('\n')
+        .flat_map(|line| {
+            let mut split = line.split(colsep);
+            let key = split.next()?;
+            let value = split.next()?;
+            let value0 = value.trim_start().split(' ').next()?;
+            let value0_u64 = u64::from_str(value0).ok()?;
+            Some((key, value0_u64))
+        })
+        .for_each(|(k, v)| f(k, v));
+    Ok(())
 }
 
 fn read_table_key(filename: &str, target_key: &str, colsep: char) -> Option<u64> {
@@ -627,6 +1069,15 @@ fn read_table_key(filename: &str, target_key: &str, colsep: char) -> Option<u64>
     None
 }
 
+/// `cpu.max` contains the quota and the period, in microseconds. The quota is `max` when no
+/// limit is set.
+fn parse_cpu_max(content: &str) -> Option<f64> {
+    let mut values = content.split_whitespace();
+    let quota = values.next()?.parse::<u64>().ok()?;
+    let period = values.next()?.parse::<u64>().ok()?;
+    (period != 0).then(|| quota as f64 / period as f64)
+}
+
 impl crate::CGroupLimits {
     fn new(sys: &SystemInner) -> Option<Self> {
         assert!(
@@ -691,6 +1142,12 @@ enum InfoType {
     /// Machine-parseable ID_LIKE of related distributions, see
     /// <https://www.freedesktop.org/software/systemd/man/latest/os-release.html#ID_LIKE=>
     DistributionIDLike,
+    /// Build of the distribution image, see
+    /// <https://www.freedesktop.org/software/systemd/man/latest/os-release.html#BUILD_ID=>
+    BuildId,
+    /// Code name of the distribution release, see
+    /// <https://www.freedesktop.org/software/systemd/man/latest/os-release.html#VERSION_CODENAME=>
+    Codename,
 }
 
 #[cfg(not(target_os = "android"))]
@@ -705,6 +1162,8 @@ fn get_system_info_linux(info: InfoType, path: &Path, fallback_path: &Path) -> O
             InfoType::OsVersion => "VERSION_ID=",
             InfoType::DistributionID => "ID=",
             InfoType::DistributionIDLike => "ID_LIKE=",
+            InfoType::BuildId => "BUILD_ID=",
+            InfoType::Codename => "VERSION_CODENAME=",
         };
 
         for line in buf.lines() {
@@ -737,6 +1196,11 @@ fn get_system_info_linux(info: InfoType, path: &Path, fallback_path: &Path) -> O
             // lsb-release doesn't support ID_LIKE.
             return None;
         }
+        InfoType::Codename => "DISTRIB_CODENAME=",
+        InfoType::BuildId => {
+            // lsb-release doesn't support BUILD_ID.
+            return None;
+        }
     };
     for line in buf.lines() {
         if let Some(stripped) = line.strip_prefix(info_str) {
@@ -762,7 +1226,8 @@ fn get_system_info_android(info: InfoType) -> Option<String> {
     let name: &'static [u8] = match info {
         InfoType::Name => b"ro.product.model\0",
         InfoType::OsVersion => b"ro.build.version.release\0",
-        InfoType::DistributionID => {
+        InfoType::BuildId => b"ro.build.id\0",
+        InfoType::DistributionID | InfoType::Codename => {
             // Not supported.
             return None;
         }
@@ -792,14 +1257,27 @@ fn get_system_info_android(info: InfoType) -> Option<String> {
 
 #[cfg(test)]
 mod test {
+    use super::compare_kernel_versions;
+    use super::get_hardware_info;
     #[cfg(target_os = "android")]
     use super::get_system_info_android;
     #[cfg(not(target_os = "android"))]
     use super::get_system_info_linux;
+    use super::get_virtualization;
+    use super::kernel_parameter_path;
+    use super::parse_cpu_max;
+    use super::parse_file_nr;
+    use super::parse_loadavg_threads;
+    use super::parse_pressure;
+    use super::read_rapl_counters;
+    use super::read_secure_boot;
     use super::read_table;
     use super::read_table_key;
+    use super::read_tpm_version;
+    use super::reboot_required;
     use super::system_info_as_list;
     use super::InfoType;
+    use crate::{ContainerKind, Hypervisor, TpmVersion, Virtualization};
     use std::collections::HashMap;
     use std::io::Write;
     use tempfile::NamedTempFile;
@@ -819,7 +1297,8 @@ mod test {
         let mut result = HashMap::new();
         read_table(file_path, ':', |key, value| {
             result.insert(key.to_string(), value);
-        });
+        })
+        .unwrap();
 
         assert_eq!(result.get("KEY1"), Some(&100));
         assert_eq!(result.get("KEY2"), Some(&200));
@@ -837,7 +1316,8 @@ mod test {
         let mut result = HashMap::new();
         read_table(file_path, ' ', |key, value| {
             result.insert(key.to_string(), value);
-        });
+        })
+        .unwrap();
 
         assert_eq!(result.get("KEY1"), Some(&400));
         assert_eq!(result.get("KEY2"), Some(&500));
@@ -850,16 +1330,19 @@ mod test {
         let mut result = HashMap::new();
         read_table(file_path, ':', |key, value| {
             result.insert(key.to_string(), value);
-        });
+        })
+        .unwrap();
 
         assert!(result.is_empty());
 
         // Test with non-existent file
         let mut result = HashMap::new();
-        read_table("/nonexistent/file", ':', |key, value| {
+        let error = read_table("/nonexistent/file", ':', |key, value| {
             result.insert(key.to_string(), value);
-        });
+        })
+        .unwrap_err();
 
+        assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
         assert!(result.is_empty());
     }
 
@@ -902,6 +1385,8 @@ mod test {
         assert!(get_system_info_android(InfoType::Name).is_some());
         assert!(get_system_info_android(InfoType::DistributionID).is_none());
         assert!(get_system_info_android(InfoType::DistributionIDLike).is_none());
+        assert!(get_system_info_android(InfoType::BuildId).is_some());
+        assert!(get_system_info_android(InfoType::Codename).is_none());
     }
 
     #[test]
@@ -956,6 +1441,14 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             get_system_info_linux(InfoType::DistributionIDLike, &tmp1, Path::new("")),
             Some("debian".to_owned())
         );
+        assert_eq!(
+            get_system_info_linux(InfoType::Codename, &tmp1, Path::new("")),
+            Some("groovy".to_owned())
+        );
+        assert_eq!(
+            get_system_info_linux(InfoType::BuildId, &tmp1, Path::new("")),
+            None
+        );
 
         // Check for the "fallback" path: "/etc/lsb-release"
         assert_eq!(
@@ -974,6 +1467,14 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             get_system_info_linux(InfoType::DistributionIDLike, Path::new(""), &tmp2),
             None
         );
+        assert_eq!(
+            get_system_info_linux(InfoType::Codename, Path::new(""), &tmp2),
+            Some("groovy".to_owned())
+        );
+        assert_eq!(
+            get_system_info_linux(InfoType::BuildId, Path::new(""), &tmp2),
+            None
+        );
     }
 
     #[test]
@@ -1006,4 +1507,376 @@ DISTRIB_DESCRIPTION="Ubuntu 20.10"
             vec!["rhel".to_string(), "fedora".to_string()],
         );
     }
+
+    #[test]
+    fn test_parse_pressure() {
+        use crate::{Pressure, PressureStall};
+
+        assert_eq!(
+            parse_pressure(
+                "some avg10=1.50 avg60=0.25 avg300=0.00 total=123456\n\
+                 full avg10=0.50 avg60=0.10 avg300=0.00 total=7890\n",
+            ),
+            Some(Pressure {
+                level: None,
+                some: Some(PressureStall {
+                    avg10: 1.5,
+                    avg60: 0.25,
+                    avg300: 0.,
+                    total: 123456,
+                }),
+                full: Some(PressureStall {
+                    avg10: 0.5,
+                    avg60: 0.1,
+                    avg300: 0.,
+                    total: 7890,
+                }),
+            }),
+        );
+        // No `full` line for the CPU before Linux 5.13.
+        assert_eq!(
+            parse_pressure("some avg10=0.00 avg60=0.00 avg300=0.00 total=42\n")
+                .map(|pressure| (pressure.some.map(|some| some.total), pressure.full)),
+            Some((Some(42), None)),
+        );
+        assert_eq!(parse_pressure(""), None);
+        assert_eq!(parse_pressure("some avg10=invalid\n"), None);
+    }
+
+    #[test]
+    fn test_get_hardware_info() {
+        let dmi_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let dmi_dir = dmi_dir.path();
+        for (file_name, content) in [
+            ("sys_vendor", "LENOVO\n"),
+            ("product_name", "20XW0055GE\n"),
+            ("board_name", "\n"),
+            ("bios_version", "N32ET75W (1.51 )\n"),
+            ("bios_date", "08/03/2021\n"),
+            ("chassis_type", "10\n"),
+        ] {
+            std::fs::write(dmi_dir.join(file_name), content).expect("failed to write DMI file");
+        }
+
+        let hardware = get_hardware_info(dmi_dir);
+        assert_eq!(hardware.vendor.as_deref(), Some("LENOVO"));
+        assert_eq!(hardware.product_name.as_deref(), Some("20XW0055GE"));
+        assert_eq!(hardware.board_name, None);
+        assert_eq!(hardware.bios_version.as_deref(), Some("N32ET75W (1.51 )"));
+        assert_eq!(hardware.bios_date.as_deref(), Some("08/03/2021"));
+        assert_eq!(hardware.chassis_type, Some(10));
+
+        assert_eq!(
+            get_hardware_info(&dmi_dir.join("missing")),
+            Default::default()
+        );
+    }
+
+    #[test]
+    fn test_read_rapl_counters() {
+        let powercap_dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let powercap_dir = powercap_dir.path();
+        for (zone, name, energy) in [
+            ("intel-rapl:0", "package-0", "1234\n"),
+            ("intel-rapl:0/intel-rapl:0:0", "core", "567\n"),
+            ("intel-rapl:0/intel-rapl:0:1", "uncore", "8\n"),
+            ("intel-rapl:0/intel-rapl:0:2", "dram", "90\n"),
+            ("intel-rapl:1", "psys", "10\n"),
+        ] {
+            let zone_dir = powercap_dir.join(zone);
+            std::fs::create_dir_all(&zone_dir).expect("failed to create zone directory");
+            for (file_name, content) in [
+                ("name", format!("{name}\n")),
+                ("energy_uj", energy.to_owned()),
+                ("max_energy_range_uj", "262143328850\n".to_owned()),
+            ] {
+                std::fs::write(zone_dir.join(file_name), content).expect("failed to write file");
+            }
+        }
+
+        let packages = read_rapl_counters(powercap_dir).expect("no RAPL package found");
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages[0].package_id, 0);
+        assert_eq!(packages[0].package.energy, 1234);
+        assert_eq!(packages[0].package.max_energy_range, 262_143_328_850);
+        assert_eq!(packages[0].core.map(|core| core.energy), Some(567));
+        assert_eq!(packages[0].dram.map(|dram| dram.energy), Some(90));
+
+        assert_eq!(read_rapl_counters(&powercap_dir.join("missing")), None);
+    }
+
+    #[test]
+    fn test_read_secure_boot_and_tpm_version() {
+        let dir = tempfile::tempdir().expect("failed to create temporary directory");
+        let dir = dir.path();
+        let variable = dir.join("SecureBoot-8be4df61-93ca-11d2-aa0d-00e098032b8c");
+
+        // Not booted with UEFI.
+        assert_eq!(read_secure_boot(&dir.join("missing")), None);
+        std::fs::write(&variable, [6, 0, 0, 0, 1]).expect("failed to write EFI variable");
+        assert_eq!(read_secure_boot(dir), Some(true));
+        std::fs::write(&variable, [6, 0, 0, 0, 0]).expect("failed to write EFI variable");
+        assert_eq!(read_secure_boot(dir), Some(false));
+        std::fs::write(&variable, [6, 0, 0, 0]).expect("failed to write EFI variable");
+        assert_eq!(read_secure_boot(dir), None);
+
+        assert_eq!(read_tpm_version(dir), None);
+        std::fs::write(dir.join("tpm_version_major"), "2\n").expect("failed to write file");
+        assert_eq!(read_tpm_version(dir), Some(TpmVersion::V2));
+        std::fs::write(dir.join("tpm_version_major"), "1\n").expect("failed to write file");
+        assert_eq!(read_tpm_version(dir), Some(TpmVersion::V1));
+    }
+
+    #[test]
+    fn test_parse_cpu_max() {
+        assert_eq!(parse_cpu_max("250000 100000\n"), Some(2.5));
+        assert_eq!(parse_cpu_max("50000 100000"), Some(0.5));
+        assert_eq!(parse_cpu_max("max 100000\n"), None);
+        assert_eq!(parse_cpu_max("100000 0"), None);
+        assert_eq!(parse_cpu_max(""), None);
+    }
+
+    #[test]
+    fn test_kernel_parameter_path() {
+        use std::path::Path;
+
+        assert_eq!(
+            kernel_parameter_path("vm.swappiness").as_deref(),
+            Some(Path::new("/proc/sys/vm/swappiness"))
+        );
+        assert_eq!(
+            kernel_parameter_path("net/ipv4/conf/eth0.100/forwarding").as_deref(),
+            Some(Path::new("/proc/sys/net/ipv4/conf/eth0.100/forwarding"))
+        );
+        for name in [
+            "",
+            "vm..swappiness",
+            ".vm",
+            "../../etc/passwd",
+            "vm/../..",
+            "/etc",
+        ] {
+            assert_eq!(kernel_parameter_path(name), None, "{name:?}");
+        }
+    }
+
+    #[test]
+    fn test_reboot_required() {
+        let root = tempfile::tempdir().expect("failed to create temporary directory");
+        let boot = root.path().join("boot");
+        std::fs::create_dir(&boot).unwrap();
+        assert_eq!(reboot_required(root.path(), Some("6.8.0-45-generic")), None);
+
+        for name in [
+            "vmlinuz-6.8.0-45-generic",
+            "vmlinuz-6.8.0-50-generic",
+            "vmlinuz-6.9.0-60-lowlatency",
+            "vmlinuz-linux",
+            "config-6.8.0-51-generic",
+        ] {
+            std::fs::write(boot.join(name), "").unwrap();
+        }
+        assert_eq!(
+            reboot_required(root.path(), Some("6.8.0-45-generic")),
+            Some(true)
+        );
+        assert_eq!(
+            reboot_required(root.path(), Some("6.8.0-50-generic")),
+            Some(false)
+        );
+        assert_eq!(
+            reboot_required(root.path(), Some("6.9.0-60-lowlatency")),
+            Some(false)
+        );
+        // The running kernel isn't installed in `/boot`, like in a container.
+        assert_eq!(
+            reboot_required(root.path(), Some("6.6.87.2-microsoft-standard-WSL2")),
+            None
+        );
+        assert_eq!(reboot_required(root.path(), None), None);
+
+        std::fs::create_dir_all(root.path().join("var/run")).unwrap();
+        std::fs::write(root.path().join("var/run/reboot-required"), "").unwrap();
+        assert_eq!(
+            reboot_required(root.path(), Some("6.8.0-50-generic")),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_compare_kernel_versions() {
+        use std::cmp::Ordering;
+
+        assert_eq!(
+            compare_kernel_versions("6.10.0-1-generic", "6.9.0-1-generic"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_kernel_versions("5.14.0-427.el9.x86_64", "5.14.0-503.el9.x86_64"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_kernel_versions("6.1.0-28-amd64", "6.1.0-28-amd64"),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_parse_file_nr() {
+        assert_eq!(
+            parse_file_nr("9344\t0\t9223372036854775807\n"),
+            Some((9344, 9223372036854775807))
+        );
+        assert_eq!(parse_file_nr("1152 96 203413\n"), Some((1056, 203413)));
+        assert_eq!(parse_file_nr("1152 0\n"), None);
+        assert_eq!(parse_file_nr(""), None);
+    }
+
+    #[test]
+    fn test_parse_loadavg_threads() {
+        assert_eq!(
+            parse_loadavg_threads("0.52 0.58 0.59 2/1047 31267\n"),
+            Some(1047)
+        );
+        assert_eq!(parse_loadavg_threads("0.52 0.58 0.59"), None);
+        assert_eq!(parse_loadavg_threads("0.52 0.58 0.59 1047 31267"), None);
+    }
+
+    #[test]
+    fn test_get_virtualization() {
+        fn virtualization(files: &[(&str, &str)], cpuid: Option<Hypervisor>) -> Virtualization {
+            let root = tempfile::tempdir().expect("failed to create temporary directory");
+            for (path, content) in files {
+                let path = root.path().join(path);
+                std::fs::create_dir_all(path.parent().unwrap()).expect("failed to create dir");
+                std::fs::write(path, content).expect("failed to write fixture file");
+            }
+            get_virtualization(root.path(), cpuid)
+        }
+
+        const CPUINFO: &str = "processor\t: 0\nflags\t\t: fpu vme de pse tsc msr pae";
+        const CPUINFO_VM: &str = "processor\t: 0\nflags\t\t: fpu vme de pse hypervisor lahf_lm";
+
+        assert_eq!(
+            virtualization(
+                &[
+                    ("sys/class/dmi/id/sys_vendor", "Dell Inc.\n"),
+                    ("proc/cpuinfo", CPUINFO),
+                    ("proc/1/cgroup", "0::/init.scope\n"),
+                ],
+                None,
+            ),
+            Virtualization::BareMetal
+        );
+        // The DMI information takes precedence over CPUID.
+        assert_eq!(
+            virtualization(
+                &[
+                    ("sys/class/dmi/id/sys_vendor", "QEMU\n"),
+                    (
+                        "sys/class/dmi/id/product_name",
+                        "Standard PC (i440FX + PIIX, 1996)\n"
+                    ),
+                ],
+                Some(Hypervisor::HyperV),
+            ),
+            Virtualization::VirtualMachine(Hypervisor::KvmQemu)
+        );
+        assert_eq!(
+            virtualization(
+                &[("sys/class/dmi/id/sys_vendor", "Amazon EC2\n")],
+                Some(Hypervisor::KvmQemu),
+            ),
+            Virtualization::VirtualMachine(Hypervisor::KvmQemu)
+        );
+        assert_eq!(
+            virtualization(&[("proc/cpuinfo", CPUINFO_VM)], None),
+            Virtualization::VirtualMachine(Hypervisor::Other(String::new()))
+        );
+        assert_eq!(
+            virtualization(&[("proc/xen/capabilities", "")], None),
+            Virtualization::VirtualMachine(Hypervisor::Xen)
+        );
+        assert_eq!(
+            virtualization(
+                &[
+                    ("proc/xen/capabilities", "control_d\n"),
+                    ("proc/cpuinfo", CPUINFO_VM)
+                ],
+                Some(Hypervisor::Xen),
+            ),
+            Virtualization::BareMetal
+        );
+        assert_eq!(
+            virtualization(
+                &[
+                    (
+                        "proc/sys/kernel/osrelease",
+                        "5.15.153.1-microsoft-standard-WSL2\n",
+                    ),
+                    ("run/systemd/container", "wsl\n"),
+                ],
+                Some(Hypervisor::HyperV),
+            ),
+            Virtualization::VirtualMachine(Hypervisor::Wsl)
+        );
+
+        assert_eq!(
+            virtualization(
+                &[
+                    (".dockerenv", ""),
+                    ("sys/class/dmi/id/sys_vendor", "VMware, Inc.\n"),
+                ],
+                None,
+            ),
+            Virtualization::Container {
+                kind: ContainerKind::Docker,
+                host: Some(Hypervisor::VMware),
+            }
+        );
+        assert_eq!(
+            virtualization(
+                &[("run/.containerenv", ""), ("proc/cpuinfo", CPUINFO)],
+                None
+            ),
+            Virtualization::Container {
+                kind: ContainerKind::Podman,
+                host: None,
+            }
+        );
+        assert_eq!(
+            virtualization(&[("run/systemd/container", "lxc\n")], None),
+            Virtualization::Container {
+                kind: ContainerKind::Lxc,
+                host: None,
+            }
+        );
+        assert_eq!(
+            virtualization(&[("run/systemd/container", "systemd-nspawn\n")], None),
+            Virtualization::Container {
+                kind: ContainerKind::Other("systemd-nspawn".to_owned()),
+                host: None,
+            }
+        );
+        assert_eq!(
+            virtualization(
+                &[(
+                    "proc/1/cgroup",
+                    "12:pids:/docker/3f4b1c2a9e8d\n11:memory:/docker/3f4b1c2a9e8d\n",
+                )],
+                Some(Hypervisor::KvmQemu),
+            ),
+            Virtualization::Container {
+                kind: ContainerKind::Docker,
+                host: Some(Hypervisor::KvmQemu),
+            }
+        );
+        assert_eq!(
+            virtualization(&[("proc/1/cgroup", "1:name=systemd:/lxc/web01\n")], None),
+            Virtualization::Container {
+                kind: ContainerKind::Lxc,
+                host: None,
+            }
+        );
+    }
 }
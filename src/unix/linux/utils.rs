@@ -1,11 +1,113 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-#[cfg(any(feature = "disk", feature = "system"))]
+#[cfg(any(feature = "disk", feature = "system", feature = "component"))]
 use std::fs::File;
 #[cfg(any(feature = "disk", feature = "system"))]
 use std::io::{self, Read, Seek};
 #[cfg(any(feature = "disk", feature = "system"))]
 use std::path::Path;
+#[cfg(any(feature = "system", feature = "component"))]
+use std::sync::atomic::{AtomicIsize, Ordering};
+#[cfg(any(feature = "system", feature = "component"))]
+use std::sync::OnceLock;
+
+#[cfg(any(feature = "system", feature = "component"))]
+pub(crate) unsafe fn getrlimit() -> Option<libc::rlimit> {
+    let mut limits = libc::rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+
+    if libc::getrlimit(libc::RLIMIT_NOFILE, &mut limits) != 0 {
+        None
+    } else {
+        Some(limits)
+    }
+}
+
+#[cfg(any(feature = "system", feature = "component"))]
+pub(crate) fn get_max_nb_fds() -> usize {
+    unsafe {
+        let mut limits = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        if libc::getrlimit(libc::RLIMIT_NOFILE, &mut limits) != 0 {
+            // Most Linux system now defaults to 1024.
+            1024 / 2
+        } else {
+            limits.rlim_max as usize / 2
+        }
+    }
+}
+
+#[cfg(any(feature = "system", feature = "component"))]
+// This whole thing is to prevent having too many files open at once. It could be problematic
+// for processes using a lot of files and using sysinfo at the same time.
+pub(crate) fn remaining_files() -> &'static AtomicIsize {
+    static REMAINING_FILES: OnceLock<AtomicIsize> = OnceLock::new();
+    REMAINING_FILES.get_or_init(|| unsafe {
+        let Some(mut limits) = getrlimit() else {
+            // Most Linux system now defaults to 1024.
+            return AtomicIsize::new(1024 / 2);
+        };
+        // We save the value in case the update fails.
+        let current = limits.rlim_cur;
+
+        // The set the soft limit to the hard one.
+        limits.rlim_cur = limits.rlim_max;
+        // In this part, we leave minimum 50% of the available file descriptors to the process
+        // using sysinfo.
+        AtomicIsize::new(if libc::setrlimit(libc::RLIMIT_NOFILE, &limits) == 0 {
+            limits.rlim_cur / 2
+        } else {
+            current / 2
+        } as _)
+    })
+}
+
+/// Type used to correctly handle the `REMAINING_FILES` global.
+#[cfg(any(feature = "system", feature = "component"))]
+pub(crate) struct FileCounter(File);
+
+#[cfg(any(feature = "system", feature = "component"))]
+impl FileCounter {
+    pub(crate) fn new(f: File) -> Option<Self> {
+        let any_remaining =
+            remaining_files().fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| {
+                if remaining > 0 {
+                    Some(remaining - 1)
+                } else {
+                    // All file descriptors we were allowed are being used.
+                    None
+                }
+            });
+
+        any_remaining.ok().map(|_| Self(f))
+    }
+}
+
+#[cfg(any(feature = "system", feature = "component"))]
+impl std::ops::Deref for FileCounter {
+    type Target = File;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+#[cfg(any(feature = "system", feature = "component"))]
+impl std::ops::DerefMut for FileCounter {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[cfg(any(feature = "system", feature = "component"))]
+impl Drop for FileCounter {
+    fn drop(&mut self) {
+        remaining_files().fetch_add(1, Ordering::Relaxed);
+    }
+}
 
 #[cfg(feature = "system")]
 pub(crate) fn get_all_data_from_file(file: &mut File, size: usize) -> io::Result<Vec<u8>> {
@@ -31,14 +133,11 @@ pub(crate) fn get_all_utf8_data<P: AsRef<Path>>(file_path: P, size: usize) -> io
 
 #[cfg(feature = "system")]
 #[allow(clippy::useless_conversion)]
-pub(crate) fn realpath(path: &Path) -> Option<std::path::PathBuf> {
-    match std::fs::read_link(path) {
-        Ok(path) => Some(path),
-        Err(_e) => {
-            sysinfo_debug!("failed to get real path for {:?}: {:?}", path, _e);
-            None
-        }
-    }
+pub(crate) fn realpath(path: &Path) -> io::Result<std::path::PathBuf> {
+    std::fs::read_link(path).map_err(|e| {
+        sysinfo_debug!("failed to get real path for {:?}: {:?}", path, e);
+        e
+    })
 }
 
 /// This type is used in `retrieve_all_new_process_info` because we have a "parent" path and
@@ -50,6 +50,7 @@ cfg_if! {
     if #[cfg(feature = "user")] {
         pub(crate) mod users;
         pub(crate) mod groups;
+        pub(crate) mod sessions;
     }
 }
 
@@ -67,4 +68,6 @@ mod linux;
 #[cfg(any())]
 mod network_helper;
 #[cfg(any())]
+mod sessions;
+#[cfg(any())]
 mod users;
@@ -11,6 +11,8 @@ use std::ptr::null_mut;
 use std::str::from_utf8_unchecked;
 use std::{io, mem};
 
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+use crate::InterfaceKind;
 use crate::{IpNetwork, MacAddr};
 
 /// This iterator yields an interface name and address.
@@ -81,6 +83,51 @@ impl From<&libc::sockaddr_dl> for MacAddr {
     }
 }
 
+// Interface types from `<net/if_types.h>`.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+mod if_types {
+    pub(crate) const IFT_OTHER: u8 = 0x1;
+    pub(crate) const IFT_ETHER: u8 = 0x6;
+    pub(crate) const IFT_PPP: u8 = 0x17;
+    pub(crate) const IFT_LOOP: u8 = 0x18;
+    pub(crate) const IFT_PROPVIRTUAL: u8 = 0x35;
+    pub(crate) const IFT_GIF: u8 = 0x37;
+    pub(crate) const IFT_STF: u8 = 0x39;
+    pub(crate) const IFT_IEEE80211: u8 = 0x47;
+    pub(crate) const IFT_TUNNEL: u8 = 0x83;
+    pub(crate) const IFT_L2VLAN: u8 = 0x87;
+    pub(crate) const IFT_IEEE8023ADLAG: u8 = 0x88;
+    pub(crate) const IFT_BRIDGE: u8 = 0xd1;
+    #[cfg(target_os = "freebsd")]
+    pub(crate) const IFT_WIREGUARD: u8 = 0xf8;
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    pub(crate) const IFT_CELLULAR: u8 = 0xff;
+}
+
+/// Converts the `ifi_type` of an interface into an [`InterfaceKind`].
+///
+/// `flags` are the interface `IFF_*` flags: since tunnels like `utun` don't have a specific
+/// type, a point-to-point interface of an "other" type is considered to be a tunnel.
+#[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
+pub(crate) fn interface_kind_from_type(ifi_type: u8, flags: u32) -> InterfaceKind {
+    use if_types::*;
+
+    match ifi_type {
+        IFT_ETHER => InterfaceKind::Ethernet,
+        IFT_IEEE80211 => InterfaceKind::Wifi,
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        IFT_CELLULAR => InterfaceKind::Cellular,
+        IFT_LOOP => InterfaceKind::Loopback,
+        IFT_BRIDGE => InterfaceKind::Bridge,
+        IFT_PPP | IFT_GIF | IFT_STF | IFT_TUNNEL => InterfaceKind::Tunnel,
+        #[cfg(target_os = "freebsd")]
+        IFT_WIREGUARD => InterfaceKind::Tunnel,
+        IFT_OTHER if flags & libc::IFF_POINTOPOINT as u32 != 0 => InterfaceKind::Tunnel,
+        IFT_PROPVIRTUAL | IFT_L2VLAN | IFT_IEEE8023ADLAG => InterfaceKind::Virtual,
+        _ => InterfaceKind::Other(ifi_type as _),
+    }
+}
+
 #[cfg(any(target_os = "macos", target_os = "freebsd", target_os = "ios"))]
 unsafe fn parse_interface_address(ifap: *const libc::ifaddrs) -> Option<MacAddr> {
     let sock_addr = (*ifap).ifa_addr;
@@ -125,8 +172,137 @@ pub(crate) unsafe fn get_interface_address() -> Result<InterfaceAddressIterator,
     }
 }
 
+// Flags from `<linux/if_addr.h>`.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const IFA_F_TEMPORARY: u32 = 0x01;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const IFA_F_DEPRECATED: u32 = 0x20;
+
+/// Parses the content of `/proc/net/if_inet6` to get the `IFA_F_*` flags of each IPv6 address.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn parse_if_inet6(content: &str) -> HashMap<(String, Ipv6Addr), u32> {
+    content
+        .lines()
+        .filter_map(|line| {
+            // Each line is: address, interface index, prefix length, scope, flags, name.
+            let mut parts = line.split_whitespace();
+            let addr = u128::from_str_radix(parts.next()?, 16).ok()?;
+            let flags = u32::from_str_radix(parts.nth(3)?, 16).ok()?;
+            let name = parts.next()?;
+            Some(((name.to_owned(), Ipv6Addr::from(addr)), flags))
+        })
+        .collect()
+}
+
+/// Retrieves the temporary and deprecated flags of the IPv6 addresses.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+struct Ipv6FlagsReader(HashMap<(String, Ipv6Addr), u32>);
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl Ipv6FlagsReader {
+    fn new() -> Self {
+        Self(
+            std::fs::read_to_string("/proc/net/if_inet6")
+                .map(|content| parse_if_inet6(&content))
+                .unwrap_or_default(),
+        )
+    }
+
+    fn get(&mut self, _ifa: &libc::ifaddrs, name: &str, addr: Ipv6Addr) -> (bool, bool) {
+        match self.0.get(&(name.to_owned(), addr)) {
+            Some(flags) => (flags & IFA_F_TEMPORARY != 0, flags & IFA_F_DEPRECATED != 0),
+            None => (false, false),
+        }
+    }
+}
+
+// Flags and request from `<netinet6/in6_var.h>`.
+#[cfg(target_os = "macos")]
+const IN6_IFF_DEPRECATED: libc::c_int = 0x0010;
+#[cfg(target_os = "macos")]
+const IN6_IFF_TEMPORARY: libc::c_int = 0x0080;
+#[cfg(target_os = "macos")]
+const SIOCGIFAFLAG_IN6: libc::c_ulong = 0xc1206949;
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+union in6_ifreq_ifru {
+    ifru_addr: libc::sockaddr_in6,
+    ifru_flags6: libc::c_int,
+    // The biggest member of this union is `struct icmp6_ifstat`.
+    _size: [u64; 34],
+}
+
+#[cfg(target_os = "macos")]
+#[repr(C)]
+struct in6_ifreq {
+    ifr_name: [c_char; libc::IFNAMSIZ],
+    ifr_ifru: in6_ifreq_ifru,
+}
+
+/// Retrieves the temporary and deprecated flags of the IPv6 addresses.
+#[cfg(target_os = "macos")]
+struct Ipv6FlagsReader(Option<std::os::fd::OwnedFd>);
+
+#[cfg(target_os = "macos")]
+impl Ipv6FlagsReader {
+    fn new() -> Self {
+        use std::os::fd::FromRawFd;
+
+        let fd = unsafe { libc::socket(libc::AF_INET6, libc::SOCK_DGRAM, 0) };
+        if fd < 0 {
+            sysinfo_debug!("failed to create socket: {:?}", io::Error::last_os_error());
+            return Self(None);
+        }
+        Self(Some(unsafe { std::os::fd::OwnedFd::from_raw_fd(fd) }))
+    }
+
+    fn get(&mut self, ifa: &libc::ifaddrs, _name: &str, _addr: Ipv6Addr) -> (bool, bool) {
+        use std::os::fd::AsRawFd;
+
+        let Some(ref socket) = self.0 else {
+            return (false, false);
+        };
+        unsafe {
+            let mut req: in6_ifreq = mem::zeroed();
+            let name = CStr::from_ptr(ifa.ifa_name).to_bytes();
+            for (dst, src) in req
+                .ifr_name
+                .iter_mut()
+                .zip(name.iter().take(libc::IFNAMSIZ - 1))
+            {
+                *dst = *src as c_char;
+            }
+            req.ifr_ifru.ifru_addr = (ifa.ifa_addr as *const libc::sockaddr_in6).read_unaligned();
+            if libc::ioctl(socket.as_raw_fd(), SIOCGIFAFLAG_IN6, &mut req) < 0 {
+                return (false, false);
+            }
+            let flags = req.ifr_ifru.ifru_flags6;
+            (
+                flags & IN6_IFF_TEMPORARY != 0,
+                flags & IN6_IFF_DEPRECATED != 0,
+            )
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+struct Ipv6FlagsReader;
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos")))]
+impl Ipv6FlagsReader {
+    fn new() -> Self {
+        Self
+    }
+
+    fn get(&mut self, _ifa: &libc::ifaddrs, _name: &str, _addr: Ipv6Addr) -> (bool, bool) {
+        (false, false)
+    }
+}
+
 pub(crate) unsafe fn get_interface_ip_networks() -> HashMap<String, HashSet<IpNetwork>> {
     let mut ifaces: HashMap<String, HashSet<IpNetwork>> = HashMap::new();
+    let mut ipv6_flags = Ipv6FlagsReader::new();
     let mut addrs: MaybeUninit<*mut libc::ifaddrs> = MaybeUninit::uninit();
 
     // Safety: addrs.as_mut_ptr() is valid, it points to addrs.
@@ -169,12 +345,16 @@ pub(crate) unsafe fn get_interface_ip_networks() -> HashMap<String, HashSet<IpNe
             .and_then(|netmask| ip_mask_to_prefix(netmask).ok())
             .unwrap_or(0);
         if let Some(ip) = ip {
-            ifaces
-                .entry(name)
-                .and_modify(|values| {
-                    values.insert(IpNetwork { addr: ip, prefix });
-                })
-                .or_insert(HashSet::from([IpNetwork { addr: ip, prefix }]));
+            let (is_temporary, is_deprecated) = match ip {
+                IpAddr::V6(ip) => ipv6_flags.get(addr_ref, &name, ip),
+                IpAddr::V4(_) => (false, false),
+            };
+            ifaces.entry(name).or_default().insert(IpNetwork {
+                addr: ip,
+                prefix,
+                is_temporary,
+                is_deprecated,
+            });
         }
         addr = addr_ref.ifa_next;
     }
@@ -277,6 +457,40 @@ fn sockaddr_to_network_addr(sa: *const libc::sockaddr) -> Option<IpAddr> {
     }
 }
 
+/// Parses the `nameserver` entries of a `resolv.conf` file.
+pub(crate) fn parse_resolv_conf(content: &str) -> Vec<IpAddr> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            if parts.next()? != "nameserver" {
+                return None;
+            }
+            // IPv6 link-local addresses can be followed by `%<interface>`.
+            parts.next()?.split('%').next()?.parse().ok()
+        })
+        .collect()
+}
+
+pub(crate) fn get_dns_servers() -> Vec<IpAddr> {
+    let read = |path| {
+        std::fs::read_to_string(path)
+            .map(|content| parse_resolv_conf(&content))
+            .unwrap_or_default()
+    };
+    let servers = read("/etc/resolv.conf");
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if servers == [IpAddr::V4(Ipv4Addr::new(127, 0, 0, 53))] {
+        // This is the `systemd-resolved` stub resolver, the servers it forwards the queries to
+        // are listed in this file.
+        let upstream = read("/run/systemd/resolve/resolv.conf");
+        if !upstream.is_empty() {
+            return upstream;
+        }
+    }
+    servers
+}
+
 pub(crate) fn ip_mask_to_prefix(mask: IpAddr) -> Result<u8, &'static str> {
     match mask {
         IpAddr::V4(mask) => ipv4_mask_to_prefix(mask),
@@ -332,6 +546,64 @@ pub(crate) fn ipv6_mask_to_prefix(mask: Ipv6Addr) -> Result<u8, &'static str> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn resolv_conf() {
+        let servers = parse_resolv_conf(
+            "\
+# Generated by NetworkManager
+search example.com
+nameserver 192.168.1.1
+nameserver   fe80::1%eth0
+;nameserver 10.0.0.1
+nameserver 2001:4860:4860::8888
+nameserver invalid
+options edns0
+",
+        );
+        assert_eq!(
+            servers,
+            [
+                IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1)),
+                "fe80::1".parse::<IpAddr>().unwrap(),
+                "2001:4860:4860::8888".parse::<IpAddr>().unwrap(),
+            ]
+        );
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[test]
+    fn if_inet6_flags() {
+        let flags = parse_if_inet6(
+            "\
+fe800000000000000000000000000001 02 40 20 80     eth0
+20010db8000000001c2b3d4e5f607182 02 40 00 01     eth0
+20010db8000000000000000000000001 02 40 00 20     eth0
+00000000000000000000000000000001 01 80 10 80       lo
+invalid line
+",
+        );
+        assert_eq!(flags.len(), 4);
+        assert_eq!(
+            flags.get(&("eth0".to_owned(), "fe80::1".parse().unwrap())),
+            Some(&0x80)
+        );
+        assert_eq!(
+            flags.get(&(
+                "eth0".to_owned(),
+                "2001:db8::1c2b:3d4e:5f60:7182".parse().unwrap()
+            )),
+            Some(&IFA_F_TEMPORARY)
+        );
+        assert_eq!(
+            flags.get(&("eth0".to_owned(), "2001:db8::1".parse().unwrap())),
+            Some(&IFA_F_DEPRECATED)
+        );
+        assert_eq!(
+            flags.get(&("lo".to_owned(), "::1".parse().unwrap())),
+            Some(&0x80)
+        );
+    }
+
     #[test]
     fn ipv4_mask() {
         let mask = Ipv4Addr::new(255, 255, 255, 0);
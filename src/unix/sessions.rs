@@ -0,0 +1,178 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Session;
+#[cfg(not(target_os = "android"))]
+use crate::{SessionKind, Uid};
+
+#[cfg(not(target_os = "android"))]
+use libc::c_char;
+#[cfg(not(target_os = "android"))]
+use std::collections::HashMap;
+
+/// Converts a fixed-size field of a `utmpx` entry, which isn't always NUL-terminated.
+#[cfg(not(target_os = "android"))]
+fn field_to_string(field: &[c_char]) -> Option<String> {
+    let bytes = field
+        .iter()
+        .take_while(|c| **c != 0)
+        .map(|c| *c as u8)
+        .collect::<Vec<_>>();
+    if bytes.is_empty() {
+        None
+    } else {
+        Some(String::from_utf8_lossy(&bytes).into_owned())
+    }
+}
+
+/// The X display of the local graphical sessions (like `:0`) is stored as the host.
+///
+/// Virtual consoles and serial lines are named `tty` followed by a number, `S` (the Linux serial
+/// lines) or `v` (the FreeBSD virtual consoles), unlike the pseudo-terminals of macOS (`ttys000`).
+#[cfg(not(target_os = "android"))]
+fn get_kind_and_remote_host(
+    host: Option<String>,
+    terminal: Option<&str>,
+) -> (SessionKind, Option<String>) {
+    match host {
+        Some(host) if !host.starts_with(':') => (SessionKind::Remote, Some(host)),
+        None if terminal
+            .and_then(|terminal| terminal.strip_prefix("tty"))
+            .is_some_and(|rest| {
+                rest.starts_with(|c: char| c.is_ascii_digit() || c == 'S' || c == 'v')
+            }) =>
+        {
+            (SessionKind::Console, None)
+        }
+        _ => (SessionKind::Local, None),
+    }
+}
+
+#[cfg(not(target_os = "android"))]
+fn is_process_alive(pid: libc::pid_t) -> bool {
+    // `kill(0)` would check the whole process group.
+    pid <= 0
+        || unsafe { libc::kill(pid, 0) } == 0
+        || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+#[cfg(not(target_os = "android"))]
+unsafe fn get_user_id(user_name: &str, buffer: &mut Vec<c_char>) -> Option<Uid> {
+    let user_name = std::ffi::CString::new(user_name).ok()?;
+    let mut passwd = std::mem::MaybeUninit::<libc::passwd>::uninit();
+    let mut result = std::ptr::null_mut();
+    loop {
+        let ret = libc::getpwnam_r(
+            user_name.as_ptr(),
+            passwd.as_mut_ptr(),
+            buffer.as_mut_ptr(),
+            buffer.capacity() as _,
+            &mut result,
+        );
+        if ret == libc::ERANGE {
+            // Needs to be updated for `Vec::reserve` to actually add additional capacity.
+            // In here it's "fine" since we never read from `buffer`.
+            buffer.set_len(buffer.capacity());
+            buffer.reserve(2048);
+            continue;
+        }
+        if ret != 0 || result.is_null() {
+            return None;
+        }
+        return Some(Uid(passwd.assume_init().pw_uid));
+    }
+}
+
+#[cfg(not(target_os = "android"))]
+pub(crate) fn get_sessions(sessions: &mut Vec<Session>) {
+    sessions.clear();
+
+    let mut buffer = Vec::with_capacity(2048);
+    let mut user_ids = HashMap::new();
+
+    unsafe {
+        libc::setutxent();
+        loop {
+            let entry = libc::getutxent();
+            if entry.is_null() {
+                break;
+            }
+            let entry = &*entry;
+            // The entries of the sessions which were not properly closed are not removed.
+            if entry.ut_type != libc::USER_PROCESS || !is_process_alive(entry.ut_pid) {
+                continue;
+            }
+            let Some(user_name) = field_to_string(&entry.ut_user) else {
+                continue;
+            };
+            let user_id = user_ids
+                .entry(user_name.clone())
+                .or_insert_with(|| get_user_id(&user_name, &mut buffer))
+                .clone();
+            let session_id = match libc::getsid(entry.ut_pid) {
+                -1 => None,
+                id => Some(id as u32),
+            };
+            let terminal = field_to_string(&entry.ut_line);
+            let (kind, remote_host) =
+                get_kind_and_remote_host(field_to_string(&entry.ut_host), terminal.as_deref());
+            sessions.push(Session {
+                user_name,
+                user_id,
+                session_id,
+                login_time: entry.ut_tv.tv_sec.max(0) as u64,
+                kind,
+                terminal,
+                remote_host,
+            });
+        }
+        libc::endutxent();
+    }
+}
+
+// Android doesn't have a `utmpx` database.
+#[cfg(target_os = "android")]
+pub(crate) fn get_sessions(sessions: &mut Vec<Session>) {
+    sessions.clear();
+}
+
+#[cfg(all(test, not(target_os = "android")))]
+mod tests {
+    use super::{field_to_string, get_kind_and_remote_host};
+    use crate::SessionKind;
+
+    #[test]
+    fn check_session_fields() {
+        let field = [b'p' as _, b't' as _, b's' as _, b'/' as _, b'0' as _];
+        assert_eq!(field_to_string(&field).as_deref(), Some("pts/0"));
+        assert_eq!(
+            field_to_string(&[b'a' as _, 0, b'b' as _]).as_deref(),
+            Some("a")
+        );
+        assert_eq!(field_to_string(&[0; 4]), None);
+
+        assert_eq!(
+            get_kind_and_remote_host(None, None),
+            (SessionKind::Local, None)
+        );
+        assert_eq!(
+            get_kind_and_remote_host(Some(":0".to_owned()), Some("tty7")),
+            (SessionKind::Local, None)
+        );
+        assert_eq!(
+            get_kind_and_remote_host(Some("192.0.2.1".to_owned()), Some("pts/0")),
+            (SessionKind::Remote, Some("192.0.2.1".to_owned()))
+        );
+        for terminal in ["tty1", "ttyS0", "ttyv0"] {
+            assert_eq!(
+                get_kind_and_remote_host(None, Some(terminal)),
+                (SessionKind::Console, None)
+            );
+        }
+        for terminal in ["pts/0", "ttys000", "console"] {
+            assert_eq!(
+                get_kind_and_remote_host(None, Some(terminal)),
+                (SessionKind::Local, None)
+            );
+        }
+    }
+}
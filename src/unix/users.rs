@@ -2,23 +2,36 @@
 
 use crate::{
     common::{Gid, Uid},
-    Group,
+    Group, User,
 };
 
-#[cfg(not(any(target_os = "macos", target_os = "ios")))]
-use crate::User;
-
 use libc::{getgrgid_r, getgrouplist};
+use std::ffi::{CStr, OsStr};
+use std::os::unix::ffi::OsStrExt;
+use std::path::PathBuf;
+use std::time::SystemTime;
 
 pub(crate) struct UserInner {
     pub(crate) uid: Uid,
     pub(crate) gid: Gid,
     pub(crate) name: String,
     c_user: Vec<u8>,
+    full_name: Option<String>,
+    // Both come from the passwd entry, so they are retrieved with the other information.
+    home_directory: Option<PathBuf>,
+    shell: Option<PathBuf>,
+    pub(crate) is_disabled: Option<bool>,
 }
 
 impl UserInner {
-    pub(crate) fn new(uid: Uid, gid: Gid, name: String) -> Self {
+    pub(crate) fn new(
+        uid: Uid,
+        gid: Gid,
+        name: String,
+        full_name: Option<String>,
+        home_directory: Option<PathBuf>,
+        shell: Option<PathBuf>,
+    ) -> Self {
         let mut c_user = name.as_bytes().to_vec();
         c_user.push(0);
         Self {
@@ -26,6 +39,10 @@ impl UserInner {
             gid,
             name,
             c_user,
+            full_name,
+            home_directory,
+            shell,
+            is_disabled: None,
         }
     }
 
@@ -41,9 +58,54 @@ impl UserInner {
         &self.name
     }
 
+    pub(crate) fn full_name(&self) -> Option<&str> {
+        self.full_name.as_deref()
+    }
+
+    pub(crate) fn is_disabled(&self) -> Option<bool> {
+        self.is_disabled
+    }
+
+    pub(crate) fn is_locked_out(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn password_expired(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn last_login(&self) -> Option<SystemTime> {
+        get_last_login(self)
+    }
+
     pub(crate) fn groups(&self) -> Vec<Group> {
         unsafe { get_user_groups(self.c_user.as_ptr() as *const _, self.gid.0 as _) }
     }
+
+    pub(crate) fn home_directory_and_shell(&self) -> (Option<PathBuf>, Option<PathBuf>) {
+        (self.home_directory.clone(), self.shell.clone())
+    }
+}
+
+/// The GECOS field contains comma-separated information about the user, starting with their full
+/// name.
+pub(crate) fn parse_gecos(gecos: &str) -> Option<String> {
+    gecos
+        .split(',')
+        .next()
+        .map(str::trim)
+        .filter(|full_name| !full_name.is_empty())
+        .map(str::to_owned)
+}
+
+pub(crate) unsafe fn c_path(c: *const libc::c_char) -> Option<PathBuf> {
+    if c.is_null() {
+        return None;
+    }
+    match CStr::from_ptr(c).to_bytes() {
+        [] => None,
+        bytes => Some(PathBuf::from(OsStr::from_bytes(bytes))),
+    }
 }
 
 pub(crate) unsafe fn get_group_name(
@@ -106,38 +168,272 @@ pub(crate) unsafe fn get_user_groups(
             .filter_map(|group_id| {
                 let name = get_group_name(*group_id as _, &mut buffer)?;
                 Some(Group {
-                    inner: crate::GroupInner::new(Gid(*group_id as _), name),
+                    inner: crate::GroupInner::new(Gid(*group_id as _), name, Vec::new()),
                 })
             })
             .collect();
     }
 }
 
+pub(crate) fn get_user_by_id(uid: &Uid) -> Option<User> {
+    let mut passwd = std::mem::MaybeUninit::<libc::passwd>::uninit();
+    let mut buffer = Vec::<libc::c_char>::with_capacity(2048);
+    let mut result = std::ptr::null_mut();
+    unsafe {
+        loop {
+            // Contrary to most functions, the error is returned and not set in `errno`.
+            match libc::getpwuid_r(
+                uid.0,
+                passwd.as_mut_ptr(),
+                buffer.as_mut_ptr(),
+                buffer.capacity() as _,
+                &mut result,
+            ) {
+                0 => break,
+                libc::EINTR => continue,
+                libc::ERANGE => {
+                    // Needs to be updated for `Vec::reserve` to actually add additional capacity.
+                    // In here it's "fine" since we never read from `buffer`.
+                    buffer.set_len(buffer.capacity());
+                    buffer.reserve(2048);
+                }
+                _ => return None,
+            }
+        }
+        if result.is_null() {
+            return None;
+        }
+        let passwd = passwd.assume_init();
+        let name = super::utils::cstr_to_rust(passwd.pw_name)?;
+        // Android doesn't have a GECOS field.
+        #[cfg(not(target_os = "android"))]
+        let full_name =
+            super::utils::cstr_to_rust(passwd.pw_gecos).and_then(|gecos| parse_gecos(&gecos));
+        #[cfg(target_os = "android")]
+        let full_name = None;
+        #[cfg_attr(any(target_os = "macos", target_os = "ios"), allow(unused_mut))]
+        let mut user = User {
+            inner: UserInner::new(
+                Uid(passwd.pw_uid),
+                Gid(passwd.pw_gid),
+                name,
+                full_name,
+                c_path(passwd.pw_dir),
+                c_path(passwd.pw_shell),
+            ),
+            groups: None,
+            home_directory: None,
+            shell: None,
+        };
+        #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+        set_disabled_status(std::slice::from_mut(&mut user));
+        Some(user)
+    }
+}
+
+#[cfg(target_os = "linux")]
+const LASTLOG_FILE: &str = "/var/log/lastlog";
+#[cfg(target_os = "linux")]
+const WTMP_FILE: &str = "/var/log/wtmp";
+
+// Like `ut_tv`, `ll_time` is only 64 bits on the 64-bit targets without a 32-bit compatibility
+// layer.
+#[cfg(all(
+    target_os = "linux",
+    any(
+        target_arch = "aarch64",
+        target_arch = "s390x",
+        target_arch = "loongarch64",
+        all(target_pointer_width = "64", not(target_env = "gnu"))
+    )
+))]
+type LastlogTime = i64;
+#[cfg(all(
+    target_os = "linux",
+    not(any(
+        target_arch = "aarch64",
+        target_arch = "s390x",
+        target_arch = "loongarch64",
+        all(target_pointer_width = "64", not(target_env = "gnu"))
+    ))
+))]
+type LastlogTime = i32;
+
+/// Size of a `lastlog` record: the time followed by the terminal and the host fields.
+#[cfg(target_os = "linux")]
+const LASTLOG_RECORD_SIZE: u64 = std::mem::size_of::<LastlogTime>() as u64 + 32 + 256;
+
+/// Retrieves the last login of the user from `/var/log/lastlog`, or from `/var/log/wtmp` on the
+/// systems which don't have the former.
+#[cfg(target_os = "linux")]
+fn get_last_login(user: &UserInner) -> Option<SystemTime> {
+    use std::fs::File;
+
+    let secs = match File::open(LASTLOG_FILE) {
+        Ok(mut file) if file.metadata().is_ok_and(|metadata| metadata.len() > 0) => {
+            read_lastlog_time(&mut file, user.uid.0)
+        }
+        _ => {
+            let file = File::open(WTMP_FILE).ok()?;
+            scan_wtmp(std::io::BufReader::new(file), user.name.as_bytes())
+        }
+    }?;
+    Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+}
+
+/// The `lastlog` file is indexed by user ID. It is a sparse file in which the records of the
+/// users who never logged in are holes that are read as zeros, so only the record of the user is
+/// read.
+#[cfg(target_os = "linux")]
+fn read_lastlog_time<R: std::io::Read + std::io::Seek>(file: &mut R, uid: u32) -> Option<u64> {
+    let mut time = [0; std::mem::size_of::<LastlogTime>()];
+    file.seek(std::io::SeekFrom::Start(uid as u64 * LASTLOG_RECORD_SIZE))
+        .ok()?;
+    // Past the end of the file if no user with a higher ID ever logged in.
+    file.read_exact(&mut time).ok()?;
+    match LastlogTime::from_ne_bytes(time) {
+        secs if secs > 0 => Some(secs as u64),
+        _ => None,
+    }
+}
+
+/// Returns the time of the most recent login of the user in the `wtmp` records.
+#[cfg(target_os = "linux")]
+fn scan_wtmp<R: std::io::Read>(mut reader: R, name: &[u8]) -> Option<u64> {
+    let mut record = [0; std::mem::size_of::<libc::utmpx>()];
+    let mut last_login = None;
+    while reader.read_exact(&mut record).is_ok() {
+        let entry = unsafe { std::ptr::read_unaligned(record.as_ptr() as *const libc::utmpx) };
+        if entry.ut_type != libc::USER_PROCESS
+            || !entry
+                .ut_user
+                .iter()
+                .take_while(|c| **c != 0)
+                .map(|c| *c as u8)
+                .eq(name.iter().copied())
+        {
+            continue;
+        }
+        let secs = entry.ut_tv.tv_sec.max(0) as u64;
+        if secs > 0 && last_login.map_or(true, |last_login| secs > last_login) {
+            last_login = Some(secs);
+        }
+    }
+    last_login
+}
+
+/// Retrieves the last login of the user from the `utmpx` last login database.
+#[cfg(target_os = "freebsd")]
+fn get_last_login(user: &UserInner) -> Option<SystemTime> {
+    unsafe {
+        if libc::setutxdb(libc::UTXDB_LASTLOGIN, std::ptr::null()) != 0 {
+            return None;
+        }
+        let entry = libc::getutxuser(user.c_user.as_ptr() as *const _);
+        let secs = if entry.is_null() {
+            0
+        } else {
+            (*entry).ut_tv.tv_sec.max(0) as u64
+        };
+        libc::endutxent();
+        (secs > 0).then(|| std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn get_last_login(user: &UserInner) -> Option<SystemTime> {
+    crate::unix::apple::users::get_last_login(user.c_user.as_ptr() as *const _)
+}
+
+// There is no login records database on Android and iOS.
+#[cfg(any(target_os = "android", target_os = "ios"))]
+fn get_last_login(_user: &UserInner) -> Option<SystemTime> {
+    None
+}
+
+// Unlike `/etc/passwd`, it is only readable by root.
+#[cfg(target_os = "freebsd")]
+const SHADOW_FILE: &str = "/etc/master.passwd";
+#[cfg(not(any(target_os = "freebsd", target_os = "macos", target_os = "ios")))]
+const SHADOW_FILE: &str = "/etc/shadow";
+
+/// Sets whether the accounts are disabled, if the shadow file can be read.
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+fn set_disabled_status(users: &mut [User]) {
+    let Ok(content) = std::fs::read_to_string(SHADOW_FILE) else {
+        return;
+    };
+    let locked_accounts = parse_locked_accounts(&content);
+    for user in users {
+        user.inner.is_disabled = locked_accounts.get(user.inner.name.as_str()).copied();
+    }
+}
+
+/// Parses the `name:password:...` lines of the shadow file. The password hash of the locked
+/// accounts starts with `!` or `*` (like `*LOCKED*` on FreeBSD).
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+fn parse_locked_accounts(content: &str) -> std::collections::HashMap<&str, bool> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split(':');
+            let name = parts.next().filter(|name| !name.is_empty())?;
+            let password = parts.next()?;
+            Some((name, password.starts_with(['!', '*'])))
+        })
+        .collect()
+}
+
 // Not used by mac.
 #[cfg(not(any(target_os = "macos", target_os = "ios")))]
 pub(crate) fn get_users(users: &mut Vec<User>) {
     use std::fs::File;
     use std::io::Read;
 
+    users.clear();
+
+    let mut s = String::new();
+
+    let _ = File::open("/etc/passwd").and_then(|mut f| f.read_to_string(&mut s));
+    parse_passwd(&s, users);
+    set_disabled_status(users);
+}
+
+/// Parses the `name:password:uid:gid:gecos:home:shell` lines of `/etc/passwd`.
+#[cfg(not(any(target_os = "macos", target_os = "ios")))]
+fn parse_passwd(content: &str, users: &mut Vec<User>) {
     #[inline]
     fn parse_id(id: &str) -> Option<u32> {
         id.parse::<u32>().ok()
     }
 
-    users.clear();
-
-    let mut s = String::new();
+    #[inline]
+    fn parse_path(path: Option<&str>) -> Option<PathBuf> {
+        path.filter(|path| !path.is_empty()).map(PathBuf::from)
+    }
 
-    let _ = File::open("/etc/passwd").and_then(|mut f| f.read_to_string(&mut s));
-    for line in s.lines() {
+    for line in content.lines() {
         let mut parts = line.split(':');
         if let Some(username) = parts.next() {
             let mut parts = parts.skip(1);
             // Skip the user if the uid cannot be parsed correctly
             if let Some(uid) = parts.next().and_then(parse_id) {
                 if let Some(group_id) = parts.next().and_then(parse_id) {
+                    let full_name = parts.next().and_then(parse_gecos);
+                    let home_directory = parse_path(parts.next());
+                    let shell = parse_path(parts.next());
                     users.push(User {
-                        inner: UserInner::new(Uid(uid), Gid(group_id), username.to_owned()),
+                        inner: UserInner::new(
+                            Uid(uid),
+                            Gid(group_id),
+                            username.to_owned(),
+                            full_name,
+                            home_directory,
+                            shell,
+                        ),
+                        groups: None,
+                        home_directory: None,
+                        shell: None,
                     });
                 }
             }
@@ -147,3 +443,153 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
 
 #[cfg(any(target_os = "macos", target_os = "ios"))]
 pub(crate) use crate::unix::apple::users::get_users;
+
+#[cfg(all(test, not(any(target_os = "macos", target_os = "ios"))))]
+mod tests {
+    use super::{parse_locked_accounts, parse_passwd};
+    #[cfg(target_os = "linux")]
+    use super::{read_lastlog_time, scan_wtmp, LastlogTime, LASTLOG_RECORD_SIZE};
+    use std::path::Path;
+
+    #[test]
+    fn check_parse_passwd() {
+        let mut users = Vec::new();
+        parse_passwd(
+            "root:x:0:0:root:/root:/bin/bash\n\
+             daemon:x:1:1::/usr/sbin:/usr/sbin/nologin\n\
+             invalid:x:a:1::/:/bin/sh\n\
+             nohome:x:1000:1000:No Home::\n\
+             alice:x:1001:1001:Alice Liddell,Room 1,555-0100,,:/home/alice:/bin/sh\n\
+             nameless:x:1002:1002:,Room 2,,:/home/nameless:/bin/sh\n",
+            &mut users,
+        );
+        assert_eq!(
+            users
+                .iter()
+                .map(|user| user.inner.full_name())
+                .collect::<Vec<_>>(),
+            [
+                Some("root"),
+                None,
+                Some("No Home"),
+                Some("Alice Liddell"),
+                None
+            ]
+        );
+        let users = users
+            .iter()
+            .map(|user| {
+                let (home_directory, shell) = user.inner.home_directory_and_shell();
+                (user.inner.name.as_str(), home_directory, shell)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            users,
+            [
+                (
+                    "root",
+                    Some(Path::new("/root").to_owned()),
+                    Some(Path::new("/bin/bash").to_owned())
+                ),
+                (
+                    "daemon",
+                    Some(Path::new("/usr/sbin").to_owned()),
+                    Some(Path::new("/usr/sbin/nologin").to_owned())
+                ),
+                ("nohome", None, None),
+                (
+                    "alice",
+                    Some(Path::new("/home/alice").to_owned()),
+                    Some(Path::new("/bin/sh").to_owned())
+                ),
+                (
+                    "nameless",
+                    Some(Path::new("/home/nameless").to_owned()),
+                    Some(Path::new("/bin/sh").to_owned())
+                ),
+            ]
+        );
+    }
+    #[test]
+    fn check_parse_locked_accounts() {
+        let locked_accounts = parse_locked_accounts(
+            "root:$6$abc:19000:0:99999:7:::\n\
+             daemon:*:19000:0:99999:7:::\n\
+             alice:!$6$def:19000:0:99999:7:::\n\
+             toor:*LOCKED*$6$ghi:0:0::0:0:Bourne-again Superuser:/root:\n\
+             nopassword::19000:0:99999:7:::\n\
+             invalid\n",
+        );
+        let mut locked_accounts = locked_accounts.into_iter().collect::<Vec<_>>();
+        locked_accounts.sort();
+        assert_eq!(
+            locked_accounts,
+            [
+                ("alice", true),
+                ("daemon", true),
+                ("nopassword", false),
+                ("root", false),
+                ("toor", true),
+            ]
+        );
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn check_read_lastlog_time() {
+        use std::io::Cursor;
+
+        // Only the user 2 logged in, so the record of the user 1 is a hole.
+        let mut content = vec![0; 3 * LASTLOG_RECORD_SIZE as usize];
+        let offset = 2 * LASTLOG_RECORD_SIZE as usize;
+        let time = (1_700_000_000 as LastlogTime).to_ne_bytes();
+        content[offset..offset + time.len()].copy_from_slice(&time);
+        content[offset + time.len()..offset + time.len() + 5].copy_from_slice(b"pts/0");
+        let mut file = Cursor::new(content);
+
+        assert_eq!(read_lastlog_time(&mut file, 2), Some(1_700_000_000));
+        assert_eq!(read_lastlog_time(&mut file, 0), None);
+        assert_eq!(read_lastlog_time(&mut file, 1), None);
+        // Past the end of the file.
+        assert_eq!(read_lastlog_time(&mut file, 3), None);
+        assert_eq!(read_lastlog_time(&mut file, u32::MAX), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn check_scan_wtmp() {
+        fn record(ut_type: libc::c_short, user: &[u8], secs: u32) -> Vec<u8> {
+            let mut entry: libc::utmpx = unsafe { std::mem::zeroed() };
+            entry.ut_type = ut_type;
+            for (dst, src) in entry.ut_user.iter_mut().zip(user) {
+                *dst = *src as _;
+            }
+            entry.ut_tv.tv_sec = secs as _;
+            unsafe {
+                std::slice::from_raw_parts(
+                    &entry as *const libc::utmpx as *const u8,
+                    std::mem::size_of::<libc::utmpx>(),
+                )
+            }
+            .to_vec()
+        }
+
+        let content = [
+            record(libc::USER_PROCESS, b"alice", 1_000),
+            record(libc::USER_PROCESS, b"alice", 3_000),
+            record(libc::DEAD_PROCESS, b"alice", 4_000),
+            record(libc::USER_PROCESS, b"alice2", 5_000),
+            record(libc::USER_PROCESS, b"bob", 2_000),
+            record(libc::USER_PROCESS, b"alice", 2_000),
+        ]
+        .concat();
+        assert_eq!(scan_wtmp(content.as_slice(), b"alice"), Some(3_000));
+        assert_eq!(scan_wtmp(content.as_slice(), b"bob"), Some(2_000));
+        assert_eq!(scan_wtmp(content.as_slice(), b"carol"), None);
+        // A truncated record is ignored.
+        assert_eq!(
+            scan_wtmp(&content[..content.len() - 1], b"alice"),
+            Some(3_000)
+        );
+    }
+}
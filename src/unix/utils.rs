@@ -35,6 +35,15 @@ pub(crate) fn cstr_to_rust_with_size(
     }
 }
 
+/// `kill` with the signal `0` only checks that the process exists and can receive signals.
+#[cfg(feature = "system")]
+pub(crate) fn process_exists(pid: crate::Pid) -> bool {
+    // `0` and negative values would target process groups.
+    pid.0 > 0
+        && (unsafe { libc::kill(pid.0, 0) } == 0
+            || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM))
+}
+
 #[cfg(all(
     feature = "system",
     not(any(
@@ -42,9 +51,17 @@ pub(crate) fn cstr_to_rust_with_size(
         all(target_os = "macos", feature = "apple-sandbox",)
     ))
 ))]
-pub(crate) fn wait_process(pid: crate::Pid) -> Option<std::process::ExitStatus> {
+pub(crate) fn wait_process(
+    pid: crate::Pid,
+    exit_status: &std::sync::OnceLock<std::process::ExitStatus>,
+) -> Option<std::process::ExitStatus> {
     use std::os::unix::process::ExitStatusExt;
 
+    // The process was already reaped (by `process_exit_code` for example) so it cannot be waited
+    // anymore.
+    if let Some(exit_status) = exit_status.get() {
+        return Some(*exit_status);
+    }
     let mut status = 0;
     // attempt waiting
     unsafe {
@@ -54,7 +71,232 @@ pub(crate) fn wait_process(pid: crate::Pid) -> Option<std::process::ExitStatus>
             while libc::kill(pid.0, 0) == 0 {
                 std::thread::sleep(duration);
             }
+            Some(std::process::ExitStatus::from_raw(status))
+        } else {
+            let status = std::process::ExitStatus::from_raw(status);
+            // Kept for `process_exit_code` since the process was reaped.
+            let _ = exit_status.set(status);
+            Some(status)
         }
-        Some(std::process::ExitStatus::from_raw(status))
+    }
+}
+
+/// Returns the exit code of `pid` if it is a child process which exited, without blocking.
+#[cfg(all(
+    feature = "system",
+    not(any(
+        target_os = "ios",
+        all(target_os = "macos", feature = "apple-sandbox",)
+    ))
+))]
+pub(crate) fn process_exit_code(
+    pid: crate::Pid,
+    exit_status: &std::sync::OnceLock<std::process::ExitStatus>,
+) -> Option<i32> {
+    use std::os::unix::process::ExitStatusExt;
+
+    if let Some(exit_status) = exit_status.get() {
+        return exit_status.code();
+    }
+    let mut status = 0;
+    // It returns 0 if the process is still running and fails if it's not a child process.
+    if unsafe { retry_eintr!(libc::waitpid(pid.0, &mut status, libc::WNOHANG)) } <= 0 {
+        return None;
+    }
+    exit_status
+        .get_or_init(|| std::process::ExitStatus::from_raw(status))
+        .code()
+}
+
+/// `/etc/localtime` is a symbolic link to the time zone file on most systems, like
+/// `/usr/share/zoneinfo/Europe/Paris` or `/var/db/timezone/zoneinfo/Europe/Paris` on macOS.
+#[cfg(feature = "system")]
+pub(crate) fn get_timezone() -> Option<String> {
+    if let Some(timezone) = std::fs::read_link("/etc/localtime")
+        .ok()
+        .and_then(|path| timezone_from_zoneinfo_path(&path))
+    {
+        return Some(timezone);
+    }
+    // Used by Debian and the distributions based on it, and by FreeBSD when the file was copied.
+    ["/etc/timezone", "/var/db/zoneinfo"]
+        .into_iter()
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .map(|content| content.trim().to_owned())
+        .find(|timezone| !timezone.is_empty())
+}
+
+#[cfg(feature = "system")]
+fn timezone_from_zoneinfo_path(path: &std::path::Path) -> Option<String> {
+    let path = path.to_str()?;
+    let (_, timezone) = path.rsplit_once("zoneinfo/")?;
+    // These directories contain the same time zones with different leap seconds handling.
+    let timezone = timezone
+        .strip_prefix("posix/")
+        .or_else(|| timezone.strip_prefix("right/"))
+        .unwrap_or(timezone);
+    (!timezone.is_empty()).then(|| timezone.to_owned())
+}
+
+/// Returns the machine field of `uname`, which is the architecture the kernel reports to the
+/// current process.
+#[cfg(feature = "system")]
+pub(crate) fn get_uname_machine() -> Option<String> {
+    let mut raw = std::mem::MaybeUninit::<libc::utsname>::uninit();
+
+    unsafe {
+        if libc::uname(raw.as_mut_ptr()) != 0 {
+            return None;
+        }
+        let info = raw.assume_init();
+        cstr_to_rust_with_size(info.machine.as_ptr(), Some(info.machine.len()))
+    }
+}
+
+/// Like `setlocale`, `LC_ALL` takes precedence over `LANG`.
+#[cfg(feature = "system")]
+pub(crate) fn get_locale_from_env() -> Option<String> {
+    ["LC_ALL", "LANG"]
+        .into_iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|locale| !locale.is_empty())
+}
+
+/// Returns `sysconf(_SC_CLK_TCK)`, or `100` (the most common value) if it fails.
+#[cfg(all(
+    feature = "system",
+    not(any(
+        target_os = "ios",
+        all(target_os = "macos", feature = "apple-sandbox",)
+    ))
+))]
+pub(crate) fn clock_ticks_per_second() -> u64 {
+    match unsafe { libc::sysconf(libc::_SC_CLK_TCK) } {
+        ticks if ticks > 0 => ticks as u64,
+        _ => {
+            sysinfo_debug!("sysconf(_SC_CLK_TCK) failed");
+            100
+        }
+    }
+}
+
+#[cfg(feature = "system")]
+pub(crate) fn get_clock_time(clock: libc::clockid_t) -> Option<std::time::Duration> {
+    use std::time::Duration;
+
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    if unsafe { libc::clock_gettime(clock, &mut time) } != 0 {
+        sysinfo_debug!("clock_gettime failed");
+        return None;
+    }
+    Some(Duration::new(time.tv_sec as _, time.tv_nsec as _))
+}
+
+/// Resolves the canonical name of the host with `getaddrinfo`, which is its fully qualified
+/// domain name when the resolver (the DNS or `/etc/hosts`) knows it.
+#[cfg(feature = "system")]
+pub(crate) fn get_fqdn(host_name: &str) -> Option<String> {
+    if host_name.contains('.') {
+        return Some(host_name.to_owned());
+    }
+    let c_host_name = std::ffi::CString::new(host_name).ok()?;
+    unsafe {
+        let mut hints: libc::addrinfo = std::mem::zeroed();
+        hints.ai_flags = libc::AI_CANONNAME;
+        hints.ai_family = libc::AF_UNSPEC;
+        // Otherwise, the same address is returned once per socket type.
+        hints.ai_socktype = libc::SOCK_STREAM;
+        let mut info = std::ptr::null_mut();
+        if libc::getaddrinfo(c_host_name.as_ptr(), std::ptr::null(), &hints, &mut info) != 0 {
+            sysinfo_debug!("getaddrinfo failed: the FQDN cannot be retrieved...");
+            return None;
+        }
+        let name = cstr_to_rust_with_size((*info).ai_canonname, None);
+        libc::freeaddrinfo(info);
+        name.map(|name| name.trim_end_matches('.').to_owned())
+            .filter(|name| name.contains('.'))
+    }
+}
+
+/// Returns the DNS domain of the host, or its NIS domain if the DNS one isn't known.
+#[cfg(feature = "system")]
+pub(crate) fn get_domain_name(host_name: Option<&str>) -> Option<String> {
+    host_name
+        .and_then(get_fqdn)
+        .and_then(|fqdn| domain_from_fqdn(&fqdn).map(str::to_owned))
+        .or_else(get_nis_domain_name)
+}
+
+#[cfg(feature = "system")]
+fn domain_from_fqdn(fqdn: &str) -> Option<&str> {
+    let (_, domain) = fqdn.trim_end_matches('.').split_once('.')?;
+    (!domain.is_empty()).then_some(domain)
+}
+
+#[cfg(feature = "system")]
+fn get_nis_domain_name() -> Option<String> {
+    let mut buffer = [0 as libc::c_char; 256];
+    if unsafe { libc::getdomainname(buffer.as_mut_ptr(), buffer.len() as _) } != 0 {
+        sysinfo_debug!("getdomainname failed: the domain name cannot be retrieved...");
+        return None;
+    }
+    cstr_to_rust_with_size(buffer.as_ptr(), Some(buffer.len()))
+        .and_then(|name| nis_domain_name(&name).map(str::to_owned))
+}
+
+/// Linux returns `(none)` when the NIS domain isn't set.
+#[cfg(feature = "system")]
+fn nis_domain_name(name: &str) -> Option<&str> {
+    let name = name.trim();
+    (!name.is_empty() && name != "(none)").then_some(name)
+}
+
+#[cfg(all(test, feature = "system"))]
+mod tests {
+    use super::{domain_from_fqdn, nis_domain_name, timezone_from_zoneinfo_path};
+    use std::path::Path;
+
+    #[test]
+    fn check_timezone_from_zoneinfo_path() {
+        for (path, timezone) in [
+            ("/usr/share/zoneinfo/Europe/Paris", Some("Europe/Paris")),
+            (
+                "../usr/share/zoneinfo/America/New_York",
+                Some("America/New_York"),
+            ),
+            (
+                "/var/db/timezone/zoneinfo/America/Argentina/Buenos_Aires",
+                Some("America/Argentina/Buenos_Aires"),
+            ),
+            ("/usr/share/zoneinfo/posix/Asia/Tokyo", Some("Asia/Tokyo")),
+            ("/usr/share/zoneinfo/UTC", Some("UTC")),
+            ("/usr/share/zoneinfo/", None),
+            ("/etc/custom-time", None),
+        ] {
+            assert_eq!(
+                timezone_from_zoneinfo_path(Path::new(path)).as_deref(),
+                timezone,
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn check_domain_from_fqdn() {
+        assert_eq!(domain_from_fqdn("host.example.com"), Some("example.com"));
+        assert_eq!(domain_from_fqdn("host.example.com."), Some("example.com"));
+        assert_eq!(domain_from_fqdn("host.local"), Some("local"));
+        assert_eq!(domain_from_fqdn("host"), None);
+        assert_eq!(domain_from_fqdn("host."), None);
+    }
+
+    #[test]
+    fn check_nis_domain_name() {
+        assert_eq!(nis_domain_name("nis.example"), Some("nis.example"));
+        assert_eq!(nis_domain_name("(none)"), None);
+        assert_eq!(nis_domain_name(""), None);
     }
 }
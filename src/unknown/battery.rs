@@ -0,0 +1,71 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Battery, BatteryState};
+
+pub(crate) struct BatteryInner {
+    pub(crate) updated: bool,
+}
+
+impl BatteryInner {
+    pub(crate) fn name(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn charge_percent(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn state(&self) -> BatteryState {
+        BatteryState::Unknown
+    }
+
+    pub(crate) fn cycle_count(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn design_capacity(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn full_charge_capacity(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn power_watts(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct BatteriesInner {
+    pub(crate) batteries: Vec<Battery>,
+}
+
+impl BatteriesInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            batteries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(batteries: Vec<Battery>) -> Self {
+        Self { batteries }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Battery> {
+        self.batteries
+    }
+
+    pub(crate) fn list(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Battery] {
+        &mut self.batteries
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // Doesn't do anything.
+    }
+}
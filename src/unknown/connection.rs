@@ -0,0 +1,11 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Connection, ConnectionsFilter};
+
+pub(crate) fn get_connections(
+    connections: &mut Vec<Connection>,
+    _filter: ConnectionsFilter<'_>,
+    _with_pid: bool,
+) {
+    connections.clear();
+}
@@ -31,6 +31,10 @@ impl DiskInner {
         0
     }
 
+    pub(crate) fn fill_rate_bytes_per_sec(&self) -> Option<f64> {
+        None
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         false
     }
@@ -73,6 +77,14 @@ impl DisksInner {
         // Does nothing.
     }
 
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        _remove_not_listed_disks: bool,
+        _refreshes: DiskRefreshKind,
+    ) -> Result<(), Vec<crate::RefreshError>> {
+        Ok(())
+    }
+
     pub(crate) fn list(&self) -> &[Disk] {
         &self.disks
     }
@@ -10,6 +10,10 @@ impl GroupInner {
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
+
+    pub(crate) fn members(&self) -> &[String] {
+        &self.members
+    }
 }
 
 pub(crate) fn get_groups(_: &mut Vec<Group>) {}
@@ -4,10 +4,12 @@ cfg_if! {
     if #[cfg(feature = "system")] {
         pub mod cpu;
         pub mod process;
+        pub mod process_watcher;
         pub mod system;
 
         pub(crate) use self::cpu::CpuInner;
         pub(crate) use self::process::ProcessInner;
+        pub(crate) use self::process_watcher::ProcessWatcherInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
@@ -21,21 +23,34 @@ cfg_if! {
     if #[cfg(feature = "component")] {
         pub mod component;
 
-        pub(crate) use self::component::{ComponentInner, ComponentsInner};
+        pub(crate) use self::component::{
+            ComponentInner, ComponentsInner, FanInner, FansInner, PowerSensorInner,
+            PowerSensorsInner,
+        };
+    }
+
+    if #[cfg(feature = "battery")] {
+        pub mod battery;
+
+        pub(crate) use self::battery::{BatteriesInner, BatteryInner};
     }
 
     if #[cfg(feature = "network")] {
+        pub mod connection;
         pub mod network;
 
+        pub(crate) use self::connection::get_connections;
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
     }
 
     if #[cfg(feature = "user")] {
         pub mod groups;
+        pub mod sessions;
         pub mod users;
 
         pub(crate) use self::groups::get_groups;
-        pub(crate) use self::users::{get_users, UserInner};
+        pub(crate) use self::sessions::get_sessions;
+        pub(crate) use self::users::{get_user_by_id, get_users, UserInner};
     }
 }
 
@@ -44,8 +59,12 @@ pub const IS_SUPPORTED_SYSTEM: bool = false;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod battery;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
+mod connection;
+#[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
@@ -56,6 +75,10 @@ mod network;
 #[cfg(any())]
 mod process;
 #[cfg(any())]
+mod process_watcher;
+#[cfg(any())]
+mod sessions;
+#[cfg(any())]
 mod system;
 #[cfg(any())]
 mod users;
@@ -1,8 +1,12 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::{
+    InterfaceKind, IpNetwork, MacAddr, NetworkData, NetworksRefreshKind, RouteInfo, WifiInfo,
+};
 
 use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::Duration;
 
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
@@ -19,7 +23,38 @@ impl NetworksInner {
         &self.interfaces
     }
 
-    pub(crate) fn refresh(&mut self, _remove_not_listed_interfaces: bool) {}
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        _remove_not_listed_interfaces: bool,
+        _refresh_kind: NetworksRefreshKind,
+    ) {
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        _remove_not_listed_interfaces: bool,
+        _refresh_kind: NetworksRefreshKind,
+    ) -> Result<(), Vec<crate::RefreshError>> {
+        Ok(())
+    }
+
+    pub(crate) fn refresh_list_filtered(&mut self, _filter: &mut dyn FnMut(&str) -> bool) {}
+
+    pub(crate) fn refresh_interface(&mut self, _name: &str) -> bool {
+        false
+    }
+
+    pub(crate) fn refresh_interfaces(&mut self, _names: &[&str]) -> usize {
+        0
+    }
+
+    pub(crate) fn default_routes(&self) -> Vec<RouteInfo> {
+        Vec::new()
+    }
+
+    pub(crate) fn dns_servers(&self) -> Vec<IpAddr> {
+        Vec::new()
+    }
 }
 
 pub(crate) struct NetworkDataInner;
@@ -73,6 +108,38 @@ impl NetworkDataInner {
         0
     }
 
+    pub(crate) fn dropped_packets_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_dropped_packets_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn dropped_packets_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_dropped_packets_transmitted(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn multicast_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_multicast_received(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         MacAddr::UNSPECIFIED
     }
@@ -84,4 +151,56 @@ impl NetworkDataInner {
     pub(crate) fn mtu(&self) -> u64 {
         0
     }
+
+    pub(crate) fn refresh_interval(&self) -> Option<Duration> {
+        None
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_up(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_point_to_point(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn supports_multicast(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn supports_broadcast(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        InterfaceKind::Other(0)
+    }
+
+    pub(crate) fn wifi_info(&self) -> Option<WifiInfo> {
+        None
+    }
+
+    pub(crate) fn index(&self) -> u32 {
+        0
+    }
+
+    pub(crate) fn friendly_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn lower_device(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn vlan_id(&self) -> Option<u16> {
+        None
+    }
 }
@@ -1,11 +1,15 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::{DiskUsage, Gid, Pid, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, FdUsage, Gid, IoPriority, IoPriorityError, Namespaces, Pid, ProcessStatus, Signal,
+    Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::path::Path;
 use std::process::ExitStatus;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 impl fmt::Display for ProcessStatus {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -71,6 +75,10 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn start_time_precise(&self) -> SystemTime {
+        UNIX_EPOCH
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         0
     }
@@ -83,10 +91,26 @@ impl ProcessInner {
         0
     }
 
+    pub(crate) fn user_cpu_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    pub(crate) fn system_cpu_time(&self) -> Duration {
+        Duration::ZERO
+    }
+
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        None
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage::default()
     }
 
+    pub(crate) fn field_status(&self, _field: crate::ProcessField) -> crate::FieldStatus {
+        crate::FieldStatus::Unsupported
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         None
     }
@@ -103,10 +127,30 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn umask(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn ns_pid(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn io_priority(&self) -> Option<IoPriority> {
+        None
+    }
+
+    pub(crate) fn set_io_priority(&self, _priority: IoPriority) -> Result<(), IoPriorityError> {
+        Err(IoPriorityError::Unsupported)
+    }
+
     pub(crate) fn wait(&self) -> Option<ExitStatus> {
         None
     }
 
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        None
+    }
+
     pub(crate) fn session_id(&self) -> Option<Pid> {
         None
     }
@@ -128,4 +172,12 @@ impl ProcessInner {
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         None
     }
+
+    pub(crate) fn fd_usage(&self) -> Option<FdUsage> {
+        None
+    }
+
+    pub(crate) fn namespaces(&self) -> Option<Namespaces> {
+        None
+    }
 }
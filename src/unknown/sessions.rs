@@ -0,0 +1,5 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::Session;
+
+pub(crate) fn get_sessions(_: &mut Vec<Session>) {}
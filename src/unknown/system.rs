@@ -1,8 +1,9 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::rapl::RaplPackageCounters;
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, Process, ProcessRefreshKind,
-    ProcessesToUpdate,
+    Cpu, CpuRefreshKind, DomainKind, HardwareInfo, LoadAvg, MemoryRefreshKind, Pid, Pressure,
+    Process, ProcessRefreshKind, ProcessesToUpdate, TpmVersion, Virtualization,
 };
 
 use std::collections::HashMap;
@@ -31,14 +32,27 @@ impl SystemInner {
 
     pub(crate) fn refresh_memory_specifics(&mut self, _refresh_kind: MemoryRefreshKind) {}
 
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        _refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), crate::RefreshError> {
+        Ok(())
+    }
+
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
 
+    pub(crate) fn cgroup_cpu_quota(&self) -> Option<f64> {
+        None
+    }
+
     pub(crate) fn refresh_cpu_specifics(&mut self, _refresh_kind: CpuRefreshKind) {}
 
     pub(crate) fn refresh_cpu_list(&mut self, _refresh_kind: CpuRefreshKind) {}
 
+    pub(crate) fn set_refresh_parallelism(&mut self, _threads: usize) {}
+
     pub(crate) fn refresh_processes_specifics(
         &mut self,
         _processes_to_update: ProcessesToUpdate<'_>,
@@ -71,6 +85,10 @@ impl SystemInner {
         &[]
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut []
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         0
     }
@@ -103,10 +121,22 @@ impl SystemInner {
         0
     }
 
+    pub(crate) fn active_time() -> u64 {
+        0
+    }
+
     pub(crate) fn boot_time() -> u64 {
         0
     }
 
+    pub(crate) fn reboot_required() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn clock_ticks_per_second() -> u64 {
+        100
+    }
+
     pub(crate) fn load_average() -> LoadAvg {
         LoadAvg {
             one: 0.,
@@ -115,6 +145,22 @@ impl SystemInner {
         }
     }
 
+    pub(crate) fn memory_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn cpu_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn io_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn rapl_counters() -> Option<Vec<RaplPackageCounters>> {
+        None
+    }
+
     pub(crate) fn name() -> Option<String> {
         None
     }
@@ -131,6 +177,14 @@ impl SystemInner {
         None
     }
 
+    pub(crate) fn os_build() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn os_display_version() -> Option<String> {
+        None
+    }
+
     pub(crate) fn distribution_id() -> String {
         std::env::consts::OS.to_owned()
     }
@@ -150,6 +204,14 @@ impl SystemInner {
         None
     }
 
+    pub(crate) fn kernel_arch() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn is_translated() -> Option<bool> {
+        None
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         None
     }
@@ -157,4 +219,76 @@ impl SystemInner {
     pub(crate) fn open_files_limit() -> Option<usize> {
         None
     }
+
+    pub(crate) fn open_files() -> Option<(u64, u64)> {
+        None
+    }
+
+    pub(crate) fn max_pid() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn threads_total() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn hardware() -> HardwareInfo {
+        HardwareInfo::default()
+    }
+
+    pub(crate) fn process_exists(_pid: Pid) -> bool {
+        false
+    }
+
+    pub(crate) fn virtualization() -> Option<Virtualization> {
+        None
+    }
+
+    pub(crate) fn machine_id() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn boot_id() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn kernel_cmdline() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn secure_boot() -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn tpm_version() -> Option<TpmVersion> {
+        None
+    }
+
+    pub(crate) fn kernel_parameter(_name: &str) -> Option<String> {
+        None
+    }
+
+    pub(crate) fn domain_name() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn domain_kind() -> Option<DomainKind> {
+        None
+    }
+
+    pub(crate) fn fqdn() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn time_since_boot_monotonic() -> Option<Duration> {
+        None
+    }
 }
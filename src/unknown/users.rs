@@ -2,6 +2,9 @@
 
 use crate::{Gid, Group, Uid, User};
 
+use std::path::PathBuf;
+use std::time::SystemTime;
+
 pub(crate) struct UserInner;
 
 impl UserInner {
@@ -17,9 +20,37 @@ impl UserInner {
         ""
     }
 
+    pub(crate) fn full_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn is_disabled(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn is_locked_out(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn password_expired(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn last_login(&self) -> Option<SystemTime> {
+        None
+    }
+
     pub(crate) fn groups(&self) -> Vec<Group> {
         Vec::new()
     }
+
+    pub(crate) fn home_directory_and_shell(&self) -> (Option<PathBuf>, Option<PathBuf>) {
+        (None, None)
+    }
+}
+
+pub(crate) fn get_user_by_id(_: &Uid) -> Option<User> {
+    None
 }
 
 pub(crate) fn get_users(_: &mut Vec<User>) {}
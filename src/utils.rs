@@ -21,6 +21,48 @@ cfg_if! {
             val.into_par_iter()
         }
 
+        /// Thread pool used to refresh the processes, configured with
+        /// `System::set_refresh_parallelism`. The global `rayon` thread pool is used by default.
+        #[cfg(feature = "system")]
+        #[allow(dead_code)]
+        pub(crate) struct RefreshPool(Option<rayon::ThreadPool>);
+
+        #[cfg(feature = "system")]
+        #[allow(dead_code)]
+        impl RefreshPool {
+            pub(crate) fn new() -> Self {
+                Self(None)
+            }
+
+            /// Uses a pool of `threads` threads, or the global `rayon` thread pool if `threads`
+            /// is `0`.
+            pub(crate) fn set_threads(&mut self, threads: usize) {
+                if threads == 0 {
+                    self.0 = None;
+                    return;
+                }
+                match rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .thread_name(|index| format!("sysinfo-refresh-{index}"))
+                    .build()
+                {
+                    Ok(pool) => self.0 = Some(pool),
+                    Err(_err) => {
+                        sysinfo_debug!("failed to create the refresh thread pool: {_err}");
+                        self.0 = None;
+                    }
+                }
+            }
+
+            /// Runs `op` so the parallel iterators it uses are run on this thread pool.
+            pub(crate) fn install<R: Send, F: FnOnce() -> R + Send>(&self, op: F) -> R {
+                match self.0 {
+                    Some(ref pool) => pool.install(op),
+                    None => op(),
+                }
+            }
+        }
+
         // /// Converts the value into a parallel mutable iterator if the `multithread` feature is
         // /// enabled. Uses the `rayon::iter::IntoParallelRefMutIterator` trait.
         // #[cfg(feature = "component")]
@@ -43,6 +85,25 @@ cfg_if! {
             val.into_iter()
         }
 
+        /// Without the `multithread` feature, the processes are always refreshed sequentially.
+        #[cfg(feature = "system")]
+        #[allow(dead_code)]
+        pub(crate) struct RefreshPool;
+
+        #[cfg(feature = "system")]
+        #[allow(dead_code)]
+        impl RefreshPool {
+            pub(crate) fn new() -> Self {
+                Self
+            }
+
+            pub(crate) fn set_threads(&mut self, _threads: usize) {}
+
+            pub(crate) fn install<R, F: FnOnce() -> R>(&self, op: F) -> R {
+                op()
+            }
+        }
+
         // In the multithreaded version of `into_iter_mut` above, the `&mut` on the argument is
         // indicating the parallel iterator is an exclusive reference. In the non-multithreaded
         // case, the `&mut` is already part of `T` and specifying it will result in the argument
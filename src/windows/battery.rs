@@ -0,0 +1,303 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::HandleWrapper;
+use crate::{Battery, BatteryState};
+
+use std::ffi::c_void;
+use std::mem::size_of;
+
+use windows::core::PCWSTR;
+use windows::Win32::Devices::DeviceAndDriverInstallation::{
+    SetupDiDestroyDeviceInfoList, SetupDiEnumDeviceInterfaces, SetupDiGetClassDevsW,
+    SetupDiGetDeviceInterfaceDetailW, DIGCF_DEVICEINTERFACE, DIGCF_PRESENT, HDEVINFO,
+    SP_DEVICE_INTERFACE_DATA, SP_DEVICE_INTERFACE_DETAIL_DATA_W,
+};
+use windows::Win32::Storage::FileSystem::{FILE_GENERIC_READ, FILE_GENERIC_WRITE};
+use windows::Win32::System::Power::{
+    BatteryDeviceName, BatteryInformation, GetSystemPowerStatus, BATTERY_CAPACITY_RELATIVE,
+    BATTERY_CHARGING, BATTERY_DISCHARGING, BATTERY_INFORMATION, BATTERY_POWER_ON_LINE,
+    BATTERY_QUERY_INFORMATION, BATTERY_QUERY_INFORMATION_LEVEL, BATTERY_STATUS,
+    BATTERY_TAG_INVALID, BATTERY_UNKNOWN_CAPACITY, BATTERY_UNKNOWN_RATE, BATTERY_WAIT_STATUS,
+    GUID_DEVICE_BATTERY, IOCTL_BATTERY_QUERY_INFORMATION, IOCTL_BATTERY_QUERY_STATUS,
+    IOCTL_BATTERY_QUERY_TAG, SYSTEM_POWER_STATUS,
+};
+use windows::Win32::System::IO::DeviceIoControl;
+
+// `SYSTEM_POWER_STATUS::BatteryFlag` value when the machine has no battery.
+const NO_SYSTEM_BATTERY: u8 = 128;
+
+fn has_system_battery() -> bool {
+    let mut status = SYSTEM_POWER_STATUS::default();
+    unsafe { GetSystemPowerStatus(&mut status) }.is_ok() && status.BatteryFlag != NO_SYSTEM_BATTERY
+}
+
+struct DeviceInfoList(HDEVINFO);
+
+impl Drop for DeviceInfoList {
+    fn drop(&mut self) {
+        let _err = unsafe { SetupDiDestroyDeviceInfoList(self.0) };
+    }
+}
+
+/// Returns the NUL-terminated device paths of the batteries.
+unsafe fn get_battery_paths() -> Vec<Vec<u16>> {
+    let Ok(devices) = SetupDiGetClassDevsW(
+        Some(&GUID_DEVICE_BATTERY),
+        PCWSTR::null(),
+        None,
+        DIGCF_PRESENT | DIGCF_DEVICEINTERFACE,
+    ) else {
+        sysinfo_debug!("SetupDiGetClassDevsW failed");
+        return Vec::new();
+    };
+    let devices = DeviceInfoList(devices);
+    let mut paths = Vec::new();
+
+    for index in 0.. {
+        let mut interface = SP_DEVICE_INTERFACE_DATA {
+            cbSize: size_of::<SP_DEVICE_INTERFACE_DATA>() as _,
+            ..Default::default()
+        };
+        if SetupDiEnumDeviceInterfaces(devices.0, None, &GUID_DEVICE_BATTERY, index, &mut interface)
+            .is_err()
+        {
+            break;
+        }
+        // The first call only retrieves the size of the interface details.
+        let mut size = 0;
+        let _ =
+            SetupDiGetDeviceInterfaceDetailW(devices.0, &interface, None, 0, Some(&mut size), None);
+        if (size as usize) <= size_of::<u32>() {
+            continue;
+        }
+        // `u32` to ensure the buffer is correctly aligned for `SP_DEVICE_INTERFACE_DETAIL_DATA_W`.
+        let mut buffer = vec![0u32; (size as usize).div_ceil(size_of::<u32>())];
+        let detail = buffer.as_mut_ptr() as *mut SP_DEVICE_INTERFACE_DETAIL_DATA_W;
+        (*detail).cbSize = size_of::<SP_DEVICE_INTERFACE_DETAIL_DATA_W>() as _;
+        if SetupDiGetDeviceInterfaceDetailW(devices.0, &interface, Some(detail), size, None, None)
+            .is_err()
+        {
+            continue;
+        }
+        // The path follows `cbSize` and fills the rest of the buffer.
+        let path = std::slice::from_raw_parts(
+            std::ptr::addr_of!((*detail).DevicePath) as *const u16,
+            (size as usize - size_of::<u32>()) / size_of::<u16>(),
+        );
+        let len = path.iter().position(|&c| c == 0).unwrap_or(path.len());
+        paths.push(path[..len].iter().copied().chain(Some(0)).collect());
+    }
+    paths
+}
+
+unsafe fn device_io_control<I, O>(
+    handle: &HandleWrapper,
+    code: u32,
+    input: &I,
+    output: &mut O,
+) -> Option<u32> {
+    let mut size = 0;
+    DeviceIoControl(
+        handle.0,
+        code,
+        Some(input as *const I as *const c_void),
+        size_of::<I>() as _,
+        Some(output as *mut O as *mut c_void),
+        size_of::<O>() as _,
+        Some(&mut size),
+        None,
+    )
+    .ok()?;
+    Some(size)
+}
+
+unsafe fn query_information<O>(
+    handle: &HandleWrapper,
+    tag: u32,
+    level: BATTERY_QUERY_INFORMATION_LEVEL,
+    output: &mut O,
+) -> Option<u32> {
+    let query = BATTERY_QUERY_INFORMATION {
+        BatteryTag: tag,
+        InformationLevel: level,
+        AtRate: 0,
+    };
+    device_io_control(handle, IOCTL_BATTERY_QUERY_INFORMATION, &query, output)
+}
+
+pub(crate) struct BatteryInner {
+    name: String,
+    path: Vec<u16>,
+    charge_percent: Option<f32>,
+    state: BatteryState,
+    cycle_count: Option<u32>,
+    design_capacity: Option<f32>,
+    full_charge_capacity: Option<f32>,
+    power_watts: Option<f32>,
+    pub(crate) updated: bool,
+}
+
+impl BatteryInner {
+    fn new(index: usize, path: Vec<u16>) -> Option<Self> {
+        let mut battery = Self {
+            name: String::new(),
+            path,
+            charge_percent: None,
+            state: BatteryState::Unknown,
+            cycle_count: None,
+            design_capacity: None,
+            full_charge_capacity: None,
+            power_watts: None,
+            updated: true,
+        };
+        let handle = battery.update()?;
+        let mut name = [0u16; 128];
+        battery.name = unsafe { get_tag(&handle) }
+            .and_then(|tag| unsafe {
+                query_information(&handle, tag, BatteryDeviceName, &mut name)
+            })
+            .map(|size| {
+                let name = &name[..(size as usize / size_of::<u16>()).min(name.len())];
+                let len = name.iter().position(|&c| c == 0).unwrap_or(name.len());
+                String::from_utf16_lossy(&name[..len])
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| format!("Battery {index}"));
+        Some(battery)
+    }
+
+    /// Returns the handle of the battery so it can be queried for more information.
+    fn update(&mut self) -> Option<HandleWrapper> {
+        unsafe {
+            let handle =
+                HandleWrapper::new_from_file(&self.path, FILE_GENERIC_READ | FILE_GENERIC_WRITE)?;
+            let tag = get_tag(&handle)?;
+
+            let mut info = BATTERY_INFORMATION::default();
+            query_information(&handle, tag, BatteryInformation, &mut info)?;
+            let wait = BATTERY_WAIT_STATUS {
+                BatteryTag: tag,
+                ..Default::default()
+            };
+            let mut status = BATTERY_STATUS::default();
+            device_io_control(&handle, IOCTL_BATTERY_QUERY_STATUS, &wait, &mut status)?;
+
+            // Relative capacities don't have a unit but can still be used for the charge.
+            let relative = info.Capabilities & BATTERY_CAPACITY_RELATIVE != 0;
+            let to_watt_hours = |capacity: u32| {
+                (!relative && capacity != BATTERY_UNKNOWN_CAPACITY)
+                    .then_some(capacity as f32 / 1_000.)
+            };
+            self.design_capacity = to_watt_hours(info.DesignedCapacity);
+            self.full_charge_capacity = to_watt_hours(info.FullChargedCapacity);
+            // Batteries which don't support it report 0 cycles.
+            self.cycle_count = (info.CycleCount != 0).then_some(info.CycleCount);
+            let capacity = status.Capacity;
+            self.charge_percent = (capacity != BATTERY_UNKNOWN_CAPACITY
+                && info.FullChargedCapacity != 0)
+                .then(|| (capacity as f32 * 100. / info.FullChargedCapacity as f32).min(100.));
+            self.state = if status.PowerState & BATTERY_CHARGING != 0 {
+                BatteryState::Charging
+            } else if status.PowerState & BATTERY_DISCHARGING != 0 {
+                BatteryState::Discharging
+            } else if status.PowerState & BATTERY_POWER_ON_LINE != 0 {
+                if capacity != BATTERY_UNKNOWN_CAPACITY && capacity >= info.FullChargedCapacity {
+                    BatteryState::Full
+                } else {
+                    BatteryState::NotCharging
+                }
+            } else {
+                BatteryState::Unknown
+            };
+            // It is in mW, and negative when discharging.
+            self.power_watts = (!relative && status.Rate != BATTERY_UNKNOWN_RATE as i32)
+                .then_some(status.Rate.unsigned_abs() as f32 / 1_000.);
+            Some(handle)
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(crate) fn charge_percent(&self) -> Option<f32> {
+        self.charge_percent
+    }
+
+    pub(crate) fn state(&self) -> BatteryState {
+        self.state
+    }
+
+    pub(crate) fn cycle_count(&self) -> Option<u32> {
+        self.cycle_count
+    }
+
+    pub(crate) fn design_capacity(&self) -> Option<f32> {
+        self.design_capacity
+    }
+
+    pub(crate) fn full_charge_capacity(&self) -> Option<f32> {
+        self.full_charge_capacity
+    }
+
+    pub(crate) fn power_watts(&self) -> Option<f32> {
+        self.power_watts
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        self.update();
+    }
+}
+
+/// The tag identifies the battery currently in the slot, it changes when it is replaced.
+unsafe fn get_tag(handle: &HandleWrapper) -> Option<u32> {
+    // Don't wait if there is no battery in the slot.
+    let timeout: u32 = 0;
+    let mut tag = BATTERY_TAG_INVALID;
+    device_io_control(handle, IOCTL_BATTERY_QUERY_TAG, &timeout, &mut tag)?;
+    (tag != BATTERY_TAG_INVALID).then_some(tag)
+}
+
+pub(crate) struct BatteriesInner {
+    pub(crate) batteries: Vec<Battery>,
+}
+
+impl BatteriesInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            batteries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(batteries: Vec<Battery>) -> Self {
+        Self { batteries }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Battery> {
+        self.batteries
+    }
+
+    pub(crate) fn list(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Battery] {
+        &mut self.batteries
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // Avoids enumerating the devices on desktops.
+        if !has_system_battery() {
+            return;
+        }
+        for (index, path) in unsafe { get_battery_paths() }.into_iter().enumerate() {
+            if let Some(battery) = self.batteries.iter_mut().find(|b| b.inner.path == path) {
+                if battery.inner.update().is_some() {
+                    battery.inner.updated = true;
+                }
+            } else if let Some(inner) = BatteryInner::new(index, path) {
+                self.batteries.push(Battery { inner });
+            }
+        }
+    }
+}
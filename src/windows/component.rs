@@ -1,21 +1,39 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-use crate::Component;
-
-use windows::core::w;
-use windows::Win32::Foundation::{SysAllocString, SysFreeString};
+use crate::sys::utils::HandleWrapper;
+use crate::{Component, Fan, PowerSensor, PowerSensorKind};
+
+use std::mem::size_of;
+
+use windows::core::{w, PCWSTR};
+use windows::Wdk::Graphics::Direct3D::{
+    D3DKMTCloseAdapter, D3DKMTEnumAdapters2, D3DKMTOpenAdapterFromLuid, D3DKMTQueryAdapterInfo,
+    D3DKMT_ADAPTERINFO, D3DKMT_ADAPTERREGISTRYINFO, D3DKMT_ADAPTER_PERFDATA,
+    D3DKMT_ADAPTER_PERFDATACAPS, D3DKMT_CLOSEADAPTER, D3DKMT_ENUMADAPTERS2,
+    D3DKMT_OPENADAPTERFROMLUID, D3DKMT_QUERYADAPTERINFO, KMTQAITYPE_ADAPTERPERFDATA,
+    KMTQAITYPE_ADAPTERPERFDATA_CAPS, KMTQAITYPE_ADAPTERREGISTRYINFO, KMTQUERYADAPTERINFOTYPE,
+};
+#[cfg(feature = "nvml")]
+use windows::Wdk::Graphics::Direct3D::{D3DKMT_ADAPTERADDRESS, KMTQAITYPE_ADAPTERADDRESS};
+use windows::Win32::Foundation::{SysAllocString, SysFreeString, LUID};
 use windows::Win32::Security::PSECURITY_DESCRIPTOR;
 use windows::Win32::System::Com::{
     CoCreateInstance, CoInitializeEx, CoInitializeSecurity, CoSetProxyBlanket,
     CLSCTX_INPROC_SERVER, EOAC_NONE, RPC_C_AUTHN_LEVEL_CALL, RPC_C_AUTHN_LEVEL_DEFAULT,
     RPC_C_IMP_LEVEL_IMPERSONATE,
 };
+use windows::Win32::System::Ioctl::{
+    PropertyStandardQuery, StorageDeviceProperty, StorageDeviceTemperatureProperty,
+    IOCTL_STORAGE_QUERY_PROPERTY, STORAGE_DEVICE_DESCRIPTOR, STORAGE_PROPERTY_ID,
+    STORAGE_PROPERTY_QUERY, STORAGE_TEMPERATURE_DATA_DESCRIPTOR,
+};
 use windows::Win32::System::Rpc::{RPC_C_AUTHN_WINNT, RPC_C_AUTHZ_NONE};
-use windows::Win32::System::Variant::{VariantClear, VARIANT};
+use windows::Win32::System::Variant::{VariantClear, VariantToString, VariantToUInt64, VARIANT};
 use windows::Win32::System::Wmi::{
-    IEnumWbemClassObject, IWbemLocator, IWbemServices, WbemLocator, WBEM_FLAG_FORWARD_ONLY,
-    WBEM_FLAG_NONSYSTEM_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
+    IEnumWbemClassObject, IWbemClassObject, IWbemLocator, IWbemServices, WbemLocator,
+    WBEM_FLAG_FORWARD_ONLY, WBEM_FLAG_NONSYSTEM_ONLY, WBEM_FLAG_RETURN_IMMEDIATELY, WBEM_INFINITE,
 };
+use windows::Win32::System::IO::DeviceIoControl;
 
 use std::cell::OnceCell;
 use std::sync::OnceLock;
@@ -25,18 +43,34 @@ pub(crate) struct ComponentInner {
     max: f32,
     critical: Option<f32>,
     label: String,
-    connection: Option<Connection>,
+    source: ComponentSource,
     pub(crate) updated: bool,
 }
 
+/// Where the temperature of a component comes from.
+enum ComponentSource {
+    /// `MSAcpi_ThermalZoneTemperature` WMI class.
+    AcpiThermalZone(Option<Connection>),
+    /// `Win32_PerfFormattedData_Counters_ThermalZoneInformation` WMI class, with the name of the
+    /// zone.
+    ThermalZoneInformation(String),
+    /// NUL-terminated path of the physical drive.
+    Storage(Vec<u16>),
+    /// LUID of the GPU adapter.
+    Gpu(LUID),
+    /// NVIDIA GPU, from the NVML library.
+    #[cfg(feature = "nvml")]
+    Nvml(crate::nvml::Gpu),
+}
+
 impl ComponentInner {
     /// Creates a new `ComponentInner` with the given information.
     fn new() -> Option<Self> {
         let mut c = Connection::new()
             .and_then(|x| x.create_instance())
-            .and_then(|x| x.connect_server())
+            .and_then(|x| x.connect_server(w!("root\\WMI")))
             .and_then(|x| x.set_proxy_blanket())
-            .and_then(|x| x.exec_query())?;
+            .and_then(|x| x.exec_query(THERMAL_ZONE_QUERY))?;
 
         c.temperature(true)
             .map(|(temperature, critical)| ComponentInner {
@@ -44,11 +78,27 @@ impl ComponentInner {
                 label: "Computer".to_owned(),
                 max: temperature,
                 critical,
-                connection: Some(c),
+                source: ComponentSource::AcpiThermalZone(Some(c)),
                 updated: true,
             })
     }
 
+    fn from_source(
+        label: String,
+        temperature: f32,
+        critical: Option<f32>,
+        source: ComponentSource,
+    ) -> Self {
+        Self {
+            temperature,
+            max: temperature,
+            critical,
+            label,
+            source,
+            updated: true,
+        }
+    }
+
     pub(crate) fn temperature(&self) -> Option<f32> {
         Some(self.temperature)
     }
@@ -57,37 +107,360 @@ impl ComponentInner {
         Some(self.max)
     }
 
+    pub(crate) fn min(&self) -> Option<f32> {
+        None
+    }
+
     pub(crate) fn critical(&self) -> Option<f32> {
         self.critical
     }
 
+    pub(crate) fn critical_hysteresis(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn alarm(&self) -> Option<bool> {
+        None
+    }
+
+    pub(crate) fn device_name(&self) -> Option<&str> {
+        None
+    }
+
+    pub(crate) fn id(&self) -> Option<&str> {
+        None
+    }
+
     pub(crate) fn label(&self) -> &str {
         &self.label
     }
 
     pub(crate) fn refresh(&mut self) {
-        if self.connection.is_none() {
-            self.connection = Connection::new()
-                .and_then(|x| x.create_instance())
-                .and_then(|x| x.connect_server())
-                .and_then(|x| x.set_proxy_blanket());
-        }
-        self.connection = if let Some(x) = self.connection.take() {
-            x.exec_query()
-        } else {
-            None
+        let temperature = match self.source {
+            ComponentSource::AcpiThermalZone(ref mut connection) => {
+                get_acpi_temperature(connection)
+            }
+            ComponentSource::ThermalZoneInformation(ref name) => get_thermal_zones()
+                .into_iter()
+                .find(|(zone, _)| zone == name)
+                .map(|(_, temperature)| temperature),
+            ComponentSource::Storage(ref path) => unsafe {
+                HandleWrapper::new_from_file(path, Default::default())
+                    .and_then(|handle| get_storage_temperature(&handle))
+                    .map(|(temperature, _)| temperature)
+            },
+            ComponentSource::Gpu(luid) => unsafe { get_gpu_temperature_from_luid(luid) },
+            #[cfg(feature = "nvml")]
+            ComponentSource::Nvml(ref gpu) => gpu.temperature(),
         };
-        if let Some(ref mut connection) = self.connection {
-            if let Some((temperature, _)) = connection.temperature(false) {
-                self.temperature = temperature;
-                if self.temperature > self.max {
-                    self.max = self.temperature;
-                }
+        if let Some(temperature) = temperature {
+            self.temperature = temperature;
+            if self.temperature > self.max {
+                self.max = self.temperature;
             }
         }
     }
 }
 
+const THERMAL_ZONE_QUERY: PCWSTR = w!("SELECT * FROM MSAcpi_ThermalZoneTemperature");
+
+fn get_acpi_temperature(connection: &mut Option<Connection>) -> Option<f32> {
+    if connection.is_none() {
+        *connection = Connection::new()
+            .and_then(|x| x.create_instance())
+            .and_then(|x| x.connect_server(w!("root\\WMI")))
+            .and_then(|x| x.set_proxy_blanket());
+    }
+    *connection = connection
+        .take()
+        .and_then(|x| x.exec_query(THERMAL_ZONE_QUERY));
+    connection
+        .as_mut()?
+        .temperature(false)
+        .map(|(temperature, _)| temperature)
+}
+
+/// Returns the name and temperature (in °C) of the zones listed by the
+/// `Win32_PerfFormattedData_Counters_ThermalZoneInformation` WMI class.
+fn get_thermal_zones() -> Vec<(String, f32)> {
+    let Some(mut connection) = query(
+        w!("root\\CIMV2"),
+        w!("SELECT Name, Temperature, HighPrecisionTemperature \
+            FROM Win32_PerfFormattedData_Counters_ThermalZoneInformation"),
+    ) else {
+        return Vec::new();
+    };
+    let mut zones = Vec::new();
+    connection.for_each_object(|class_obj| unsafe {
+        let Some(name) = get_string_property(class_obj, w!("Name")) else {
+            return;
+        };
+        // `HighPrecisionTemperature` is given in tenth of degrees Kelvin and `Temperature` in
+        // degrees Kelvin, 0 meaning that the value isn't available.
+        let temperature = match get_u64_property(class_obj, w!("HighPrecisionTemperature")) {
+            Some(temperature) if temperature != 0 => temperature as f32 / 10.,
+            _ => match get_u64_property(class_obj, w!("Temperature")) {
+                Some(temperature) if temperature != 0 => temperature as f32,
+                _ => return,
+            },
+        };
+        zones.push((name, temperature - 273.15));
+    });
+    zones
+}
+
+fn get_thermal_zone_components() -> Vec<ComponentInner> {
+    get_thermal_zones()
+        .into_iter()
+        .map(|(name, temperature)| {
+            ComponentInner::from_source(
+                format!("Thermal Zone {name}"),
+                temperature,
+                None,
+                ComponentSource::ThermalZoneInformation(name),
+            )
+        })
+        .collect()
+}
+
+/// Number of `\\.\PhysicalDriveN` devices checked for a temperature sensor.
+const MAX_PHYSICAL_DRIVES: u32 = 32;
+
+fn get_storage_components() -> Vec<ComponentInner> {
+    (0..MAX_PHYSICAL_DRIVES)
+        .filter_map(|i| {
+            let path = format!("\\\\.\\PhysicalDrive{i}")
+                .encode_utf16()
+                .chain(Some(0))
+                .collect::<Vec<_>>();
+            // No access rights are needed to query the storage properties, so it only fails if
+            // the drive doesn't exist.
+            let handle = unsafe { HandleWrapper::new_from_file(&path, Default::default()) }?;
+            let (temperature, critical) = unsafe { get_storage_temperature(&handle) }?;
+            let label = unsafe { get_storage_product_id(&handle) }
+                .unwrap_or_else(|| format!("PhysicalDrive{i}"));
+            Some(ComponentInner::from_source(
+                label,
+                temperature,
+                critical,
+                ComponentSource::Storage(path),
+            ))
+        })
+        .collect()
+}
+
+unsafe fn query_storage_property(
+    handle: &HandleWrapper,
+    property: STORAGE_PROPERTY_ID,
+    buffer: *mut std::ffi::c_void,
+    buffer_size: usize,
+) -> Option<u32> {
+    let query = STORAGE_PROPERTY_QUERY {
+        PropertyId: property,
+        QueryType: PropertyStandardQuery,
+        AdditionalParameters: [0],
+    };
+    let mut size = 0;
+    DeviceIoControl(
+        handle.0,
+        IOCTL_STORAGE_QUERY_PROPERTY,
+        Some(&query as *const STORAGE_PROPERTY_QUERY as *const _),
+        size_of::<STORAGE_PROPERTY_QUERY>() as _,
+        Some(buffer),
+        buffer_size as _,
+        Some(&mut size),
+        None,
+    )
+    .ok()?;
+    Some(size)
+}
+
+/// Returns the temperature and the critical temperature (in °C) of the drive.
+unsafe fn get_storage_temperature(handle: &HandleWrapper) -> Option<(f32, Option<f32>)> {
+    let mut data = STORAGE_TEMPERATURE_DATA_DESCRIPTOR::default();
+    query_storage_property(
+        handle,
+        StorageDeviceTemperatureProperty,
+        &mut data as *mut STORAGE_TEMPERATURE_DATA_DESCRIPTOR as *mut _,
+        size_of::<STORAGE_TEMPERATURE_DATA_DESCRIPTOR>(),
+    )?;
+    if data.InfoCount == 0 {
+        return None;
+    }
+    let critical = (data.CriticalTemperature > 0).then_some(data.CriticalTemperature as f32);
+    Some((data.TemperatureInfo[0].Temperature as f32, critical))
+}
+
+unsafe fn get_storage_product_id(handle: &HandleWrapper) -> Option<String> {
+    // The descriptor is followed by the strings it contains the offsets of. `u64` to ensure the
+    // buffer is correctly aligned for `STORAGE_DEVICE_DESCRIPTOR`.
+    let mut buffer = [0u64; 128];
+    let size = query_storage_property(
+        handle,
+        StorageDeviceProperty,
+        buffer.as_mut_ptr() as *mut _,
+        std::mem::size_of_val(&buffer),
+    )? as usize;
+    if size < size_of::<STORAGE_DEVICE_DESCRIPTOR>() {
+        return None;
+    }
+    let descriptor = &*(buffer.as_ptr() as *const STORAGE_DEVICE_DESCRIPTOR);
+    let bytes = std::slice::from_raw_parts(
+        buffer.as_ptr() as *const u8,
+        size.min(std::mem::size_of_val(&buffer)),
+    );
+    // An offset of 0 means that there is no product ID.
+    let product_id = match descriptor.ProductIdOffset as usize {
+        0 => return None,
+        offset => bytes.get(offset..)?,
+    };
+    let len = product_id
+        .iter()
+        .position(|&c| c == 0)
+        .unwrap_or(product_id.len());
+    let product_id = String::from_utf8_lossy(&product_id[..len])
+        .trim()
+        .to_owned();
+    (!product_id.is_empty()).then_some(product_id)
+}
+
+fn get_gpu_components() -> Vec<ComponentInner> {
+    unsafe {
+        let mut adapters = D3DKMT_ENUMADAPTERS2::default();
+        // The first call only retrieves the number of adapters.
+        if D3DKMTEnumAdapters2(&mut adapters).is_err() || adapters.NumAdapters == 0 {
+            return Vec::new();
+        }
+        let mut infos = vec![D3DKMT_ADAPTERINFO::default(); adapters.NumAdapters as usize];
+        adapters.pAdapters = infos.as_mut_ptr();
+        if D3DKMTEnumAdapters2(&mut adapters).is_err() {
+            return Vec::new();
+        }
+        infos.truncate(adapters.NumAdapters as usize);
+
+        infos
+            .into_iter()
+            .filter_map(|info| {
+                let component = get_gpu_temperature(info.hAdapter).map(|temperature| {
+                    let critical = query_adapter_info::<D3DKMT_ADAPTER_PERFDATACAPS>(
+                        info.hAdapter,
+                        KMTQAITYPE_ADAPTERPERFDATA_CAPS,
+                    )
+                    .and_then(|caps| deci_celsius(caps.TemperatureMax));
+                    let label = query_adapter_info::<D3DKMT_ADAPTERREGISTRYINFO>(
+                        info.hAdapter,
+                        KMTQAITYPE_ADAPTERREGISTRYINFO,
+                    )
+                    .map(|registry| {
+                        let name = &registry.AdapterString;
+                        let len = name.iter().position(|&c| c == 0).unwrap_or(name.len());
+                        String::from_utf16_lossy(&name[..len])
+                    })
+                    .filter(|label| !label.is_empty())
+                    .unwrap_or_else(|| "GPU".to_owned());
+                    ComponentInner::from_source(
+                        label,
+                        temperature,
+                        critical,
+                        ComponentSource::Gpu(info.AdapterLuid),
+                    )
+                });
+                let _ = D3DKMTCloseAdapter(&D3DKMT_CLOSEADAPTER {
+                    hAdapter: info.hAdapter,
+                });
+                component
+            })
+            .collect()
+    }
+}
+
+/// Returns the NVIDIA GPUs found through NVML which don't already provide their temperature
+/// to Windows, which would be part of `gpu_components`. They're matched with their PCI location.
+#[cfg(feature = "nvml")]
+fn get_nvml_components(gpu_components: &[ComponentInner]) -> Vec<ComponentInner> {
+    let known_locations = gpu_components
+        .iter()
+        .filter_map(|c| match c.source {
+            ComponentSource::Gpu(luid) => unsafe { get_gpu_pci_location_from_luid(luid) },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    crate::nvml::get_gpus()
+        .into_iter()
+        .filter(|gpu| {
+            gpu.pci_location()
+                .map_or(true, |location| !known_locations.contains(&location))
+        })
+        .filter_map(|gpu| {
+            let temperature = gpu.temperature()?;
+            // Same label as on Linux.
+            let label = format!("GPU nvidia{} {}", gpu.index(), gpu.name());
+            let critical = gpu.critical();
+            Some(ComponentInner::from_source(
+                label,
+                temperature,
+                critical,
+                ComponentSource::Nvml(gpu),
+            ))
+        })
+        .collect()
+}
+
+unsafe fn query_adapter_info<T: Default>(adapter: u32, kind: KMTQUERYADAPTERINFOTYPE) -> Option<T> {
+    let mut value = T::default();
+    let mut query = D3DKMT_QUERYADAPTERINFO {
+        hAdapter: adapter,
+        Type: kind,
+        pPrivateDriverData: &mut value as *mut T as *mut _,
+        PrivateDriverDataSize: size_of::<T>() as _,
+    };
+    D3DKMTQueryAdapterInfo(&mut query).is_ok().then_some(value)
+}
+
+/// The GPU temperatures are given in tenth of degrees Celsius, 0 meaning that the driver doesn't
+/// expose them.
+fn deci_celsius(temperature: u32) -> Option<f32> {
+    (temperature != 0).then(|| temperature as f32 / 10.)
+}
+
+unsafe fn get_gpu_temperature(adapter: u32) -> Option<f32> {
+    query_adapter_info::<D3DKMT_ADAPTER_PERFDATA>(adapter, KMTQAITYPE_ADAPTERPERFDATA)
+        .and_then(|data| deci_celsius(data.Temperature))
+}
+
+unsafe fn get_gpu_temperature_from_luid(luid: LUID) -> Option<f32> {
+    let mut adapter = D3DKMT_OPENADAPTERFROMLUID {
+        AdapterLuid: luid,
+        hAdapter: 0,
+    };
+    if D3DKMTOpenAdapterFromLuid(&mut adapter).is_err() {
+        return None;
+    }
+    let temperature = get_gpu_temperature(adapter.hAdapter);
+    let _ = D3DKMTCloseAdapter(&D3DKMT_CLOSEADAPTER {
+        hAdapter: adapter.hAdapter,
+    });
+    temperature
+}
+
+/// Returns the PCI bus and device numbers of the GPU adapter.
+#[cfg(feature = "nvml")]
+unsafe fn get_gpu_pci_location_from_luid(luid: LUID) -> Option<(u32, u32)> {
+    let mut adapter = D3DKMT_OPENADAPTERFROMLUID {
+        AdapterLuid: luid,
+        hAdapter: 0,
+    };
+    if D3DKMTOpenAdapterFromLuid(&mut adapter).is_err() {
+        return None;
+    }
+    let address =
+        query_adapter_info::<D3DKMT_ADAPTERADDRESS>(adapter.hAdapter, KMTQAITYPE_ADAPTERADDRESS);
+    let _ = D3DKMTCloseAdapter(&D3DKMT_CLOSEADAPTER {
+        hAdapter: adapter.hAdapter,
+    });
+    address.map(|address| (address.BusNumber, address.DeviceNumber))
+}
+
 pub(crate) struct ComponentsInner {
     pub(crate) components: Vec<Component>,
 }
@@ -116,16 +489,38 @@ impl ComponentsInner {
     }
 
     pub(crate) fn refresh(&mut self) {
+        self.refresh_filtered(&mut |_, _| true);
+    }
+
+    pub(crate) fn refresh_filtered(&mut self, filter: &mut dyn FnMut(&str, &str) -> bool) {
         if self.components.is_empty() {
-            self.components = match ComponentInner::new() {
-                Some(c) => vec![Component { inner: c }],
-                None => Vec::new(),
-            };
+            let mut components = ComponentInner::new().into_iter().collect::<Vec<_>>();
+            // The ACPI thermal zones are often not available, in which case the performance
+            // counters might still have them.
+            if components.is_empty() {
+                components = get_thermal_zone_components();
+            }
+            components.extend(get_storage_components());
+            components.extend(get_gpu_components());
+            #[cfg(feature = "nvml")]
+            {
+                let nvml_components = get_nvml_components(&components);
+                components.extend(nvml_components);
+            }
+            self.components = components
+                .into_iter()
+                .filter(|inner| filter("", inner.label()))
+                .map(|inner| Component {
+                    inner,
+                    observed_max: None,
+                })
+                .collect();
         } else {
-            // There should always be only one here but just in case...
             for c in self.components.iter_mut() {
-                c.refresh();
-                c.inner.updated = true;
+                if filter("", c.inner.label()) {
+                    c.refresh();
+                    c.inner.updated = true;
+                }
             }
         }
     }
@@ -208,10 +603,10 @@ impl Connection {
         Some(self)
     }
 
-    fn connect_server(mut self) -> Option<Connection> {
+    fn connect_server(mut self, namespace: PCWSTR) -> Option<Connection> {
         let instance = self.instance.as_ref()?;
         let svc = unsafe {
-            let s = bstr!("root\\WMI");
+            let s = SysAllocString(namespace);
             let res = instance.ConnectServer(
                 &s,
                 &Default::default(),
@@ -248,12 +643,12 @@ impl Connection {
         Some(self)
     }
 
-    fn exec_query(mut self) -> Option<Connection> {
+    fn exec_query(mut self, query: PCWSTR) -> Option<Connection> {
         let server_connection = self.server_connection.as_ref()?;
 
         let enumerator = unsafe {
             let s = bstr!("WQL"); // query kind
-            let query = bstr!("SELECT * FROM MSAcpi_ThermalZoneTemperature");
+            let query = SysAllocString(query);
             let hres = server_connection.ExecQuery(
                 &s,
                 &query,
@@ -328,6 +723,160 @@ impl Connection {
             Some((temp, critical))
         }
     }
+
+    /// Calls `f` on each object returned by the last query.
+    fn for_each_object(&mut self, mut f: impl FnMut(&IWbemClassObject)) {
+        let Some(enumerator) = self.enumerator.take() else {
+            return;
+        };
+
+        loop {
+            let mut nb_returned = 0;
+            let mut obj = [None; 1];
+
+            unsafe {
+                let _r = enumerator.Next(WBEM_INFINITE, obj.as_mut_slice(), &mut nb_returned);
+            }
+            match &obj {
+                [Some(class_obj)] if nb_returned != 0 => f(class_obj),
+                _ => break,
+            }
+        }
+    }
+}
+
+/// Runs `query` in the WMI `namespace`.
+fn query(namespace: PCWSTR, query: PCWSTR) -> Option<Connection> {
+    Connection::new()
+        .and_then(|x| x.create_instance())
+        .and_then(|x| x.connect_server(namespace))
+        .and_then(|x| x.set_proxy_blanket())
+        .and_then(|x| x.exec_query(query))
+}
+
+unsafe fn get_property<T>(
+    class_obj: &IWbemClassObject,
+    name: PCWSTR,
+    f: impl FnOnce(&VARIANT) -> Option<T>,
+) -> Option<T> {
+    let mut variant = std::mem::MaybeUninit::<VARIANT>::uninit();
+    // `Get` only initializes the variant if it succeeds, early returning is not a problem.
+    class_obj
+        .Get(name, 0, variant.as_mut_ptr(), None, None)
+        .ok()?;
+    let mut variant = variant.assume_init();
+    let value = f(&variant);
+    let _r = VariantClear(&mut variant);
+    value
+}
+
+unsafe fn get_string_property(class_obj: &IWbemClassObject, name: PCWSTR) -> Option<String> {
+    get_property(class_obj, name, |variant| {
+        let mut buffer = [0u16; 256];
+        VariantToString(variant, &mut buffer).ok()?;
+        let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+        Some(String::from_utf16_lossy(&buffer[..len]))
+    })
+}
+
+unsafe fn get_u64_property(class_obj: &IWbemClassObject, name: PCWSTR) -> Option<u64> {
+    // `uint64` properties are given as strings, `VariantToUInt64` takes care of it.
+    get_property(class_obj, name, |variant| VariantToUInt64(variant).ok())
+}
+
+/// Returns the DeviceID and speed of the fans listed by the `Win32_Fan` WMI class.
+fn get_fans() -> Vec<(String, Option<u32>)> {
+    let Some(mut connection) = query(
+        w!("root\\CIMV2"),
+        w!("SELECT DeviceID, DesiredSpeed FROM Win32_Fan"),
+    ) else {
+        return Vec::new();
+    };
+    let mut fans = Vec::new();
+    connection.for_each_object(|class_obj| unsafe {
+        let Some(label) = get_string_property(class_obj, w!("DeviceID")) else {
+            return;
+        };
+        let rpm = get_u64_property(class_obj, w!("DesiredSpeed"));
+        fans.push((label, rpm.map(|rpm| rpm.min(u32::MAX as _) as u32)));
+    });
+    fans
+}
+
+pub(crate) struct FanInner {
+    rpm: Option<u32>,
+    label: String,
+    pub(crate) updated: bool,
+}
+
+impl FanInner {
+    pub(crate) fn rpm(&self) -> Option<u32> {
+        self.rpm
+    }
+
+    pub(crate) fn min_rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn max_rpm(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        if let Some((_, rpm)) = get_fans()
+            .into_iter()
+            .find(|(label, _)| *label == self.label)
+        {
+            self.rpm = rpm;
+        }
+    }
+}
+
+pub(crate) struct FansInner {
+    pub(crate) fans: Vec<Fan>,
+}
+
+impl FansInner {
+    pub(crate) fn new() -> Self {
+        Self { fans: Vec::new() }
+    }
+
+    pub(crate) fn from_vec(fans: Vec<Fan>) -> Self {
+        Self { fans }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<Fan> {
+        self.fans
+    }
+
+    pub(crate) fn list(&self) -> &[Fan] {
+        &self.fans
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [Fan] {
+        &mut self.fans
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        for (label, rpm) in get_fans() {
+            if let Some(f) = self.fans.iter_mut().find(|f| f.inner.label == label) {
+                f.inner.rpm = rpm;
+                f.inner.updated = true;
+            } else {
+                self.fans.push(Fan {
+                    inner: FanInner {
+                        rpm,
+                        label,
+                        updated: true,
+                    },
+                });
+            }
+        }
+    }
 }
 
 impl Drop for Connection {
@@ -338,3 +887,55 @@ impl Drop for Connection {
         self.instance.take();
     }
 }
+
+pub(crate) struct PowerSensorInner {
+    pub(crate) updated: bool,
+}
+
+impl PowerSensorInner {
+    pub(crate) fn kind(&self) -> PowerSensorKind {
+        PowerSensorKind::Power
+    }
+
+    pub(crate) fn value(&self) -> Option<f32> {
+        None
+    }
+
+    pub(crate) fn label(&self) -> &str {
+        ""
+    }
+
+    pub(crate) fn refresh(&mut self) {}
+}
+
+pub(crate) struct PowerSensorsInner {
+    pub(crate) sensors: Vec<PowerSensor>,
+}
+
+impl PowerSensorsInner {
+    pub(crate) fn new() -> Self {
+        Self {
+            sensors: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_vec(sensors: Vec<PowerSensor>) -> Self {
+        Self { sensors }
+    }
+
+    pub(crate) fn into_vec(self) -> Vec<PowerSensor> {
+        self.sensors
+    }
+
+    pub(crate) fn list(&self) -> &[PowerSensor] {
+        &self.sensors
+    }
+
+    pub(crate) fn list_mut(&mut self) -> &mut [PowerSensor] {
+        &mut self.sensors
+    }
+
+    pub(crate) fn refresh(&mut self) {
+        // FIXME: not supported yet.
+    }
+}
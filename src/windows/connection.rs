@@ -0,0 +1,182 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Connection, ConnectionProtocol, ConnectionsFilter, Pid, TcpState};
+
+use std::ffi::c_void;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use windows::Win32::Foundation::{ERROR_INSUFFICIENT_BUFFER, NO_ERROR};
+use windows::Win32::NetworkManagement::IpHelper::{
+    GetExtendedTcpTable, GetExtendedUdpTable, MIB_TCP6ROW_OWNER_PID, MIB_TCP6TABLE_OWNER_PID,
+    MIB_TCPROW_OWNER_PID, MIB_TCPTABLE_OWNER_PID, MIB_TCP_STATE_CLOSED, MIB_TCP_STATE_CLOSE_WAIT,
+    MIB_TCP_STATE_CLOSING, MIB_TCP_STATE_ESTAB, MIB_TCP_STATE_FIN_WAIT1, MIB_TCP_STATE_FIN_WAIT2,
+    MIB_TCP_STATE_LAST_ACK, MIB_TCP_STATE_LISTEN, MIB_TCP_STATE_SYN_RCVD, MIB_TCP_STATE_SYN_SENT,
+    MIB_TCP_STATE_TIME_WAIT, MIB_UDP6ROW_OWNER_PID, MIB_UDP6TABLE_OWNER_PID, MIB_UDPROW_OWNER_PID,
+    MIB_UDPTABLE_OWNER_PID, TCP_TABLE_CLASS, TCP_TABLE_OWNER_PID_ALL, TCP_TABLE_OWNER_PID_LISTENER,
+    UDP_TABLE_OWNER_PID,
+};
+use windows::Win32::Networking::WinSock::{AF_INET, AF_INET6};
+
+fn tcp_state(state: u32) -> TcpState {
+    match state as i32 {
+        x if x == MIB_TCP_STATE_CLOSED.0 => TcpState::Closed,
+        x if x == MIB_TCP_STATE_LISTEN.0 => TcpState::Listen,
+        x if x == MIB_TCP_STATE_SYN_SENT.0 => TcpState::SynSent,
+        x if x == MIB_TCP_STATE_SYN_RCVD.0 => TcpState::SynReceived,
+        x if x == MIB_TCP_STATE_ESTAB.0 => TcpState::Established,
+        x if x == MIB_TCP_STATE_FIN_WAIT1.0 => TcpState::FinWait1,
+        x if x == MIB_TCP_STATE_FIN_WAIT2.0 => TcpState::FinWait2,
+        x if x == MIB_TCP_STATE_CLOSE_WAIT.0 => TcpState::CloseWait,
+        x if x == MIB_TCP_STATE_CLOSING.0 => TcpState::Closing,
+        x if x == MIB_TCP_STATE_LAST_ACK.0 => TcpState::LastAck,
+        x if x == MIB_TCP_STATE_TIME_WAIT.0 => TcpState::TimeWait,
+        _ => TcpState::Unknown(state),
+    }
+}
+
+/// The ports are stored in network byte order in the lower 16 bits.
+fn port(port: u32) -> u16 {
+    u16::from_be(port as u16)
+}
+
+fn remote_address(addr: IpAddr, port_value: u32) -> Option<SocketAddr> {
+    let port = port(port_value);
+    (!addr.is_unspecified() || port != 0).then_some(SocketAddr::new(addr, port))
+}
+
+/// Calls `get_table` with a large enough buffer and returns the rows of the returned table.
+///
+/// `Table` is only used to check that it matches [`TableLayout`].
+unsafe fn get_rows<Table, Row: Copy>(
+    get_table: impl Fn(Option<*mut c_void>, &mut u32) -> u32,
+) -> Vec<Row> {
+    let mut size = 0;
+    // `u64` to ensure the buffer is correctly aligned for the table.
+    let mut buffer = Vec::<u64>::new();
+    loop {
+        match get_table(Some(buffer.as_mut_ptr() as _), &mut size) {
+            ret if ret == NO_ERROR.0 => break,
+            ret if ret == ERROR_INSUFFICIENT_BUFFER.0 => {
+                buffer.resize((size as usize).div_ceil(std::mem::size_of::<u64>()), 0);
+            }
+            _ret => {
+                sysinfo_debug!("failed to retrieve the sockets table: {_ret}");
+                return Vec::new();
+            }
+        }
+    }
+    if buffer.is_empty() {
+        return Vec::new();
+    }
+    let table = buffer.as_ptr() as *const TableLayout<Row>;
+    debug_assert_eq!(
+        std::mem::size_of::<Table>(),
+        std::mem::size_of::<TableLayout<Row>>()
+    );
+    std::slice::from_raw_parts(
+        std::ptr::addr_of!((*table).rows) as *const Row,
+        (*table).nb_rows as usize,
+    )
+    .to_vec()
+}
+
+/// Generic representation of the `MIB_*TABLE_OWNER_PID` types.
+#[repr(C)]
+struct TableLayout<Row> {
+    nb_rows: u32,
+    rows: [Row; 1],
+}
+
+unsafe fn get_tcp_rows<Table, Row: Copy>(family: u16, table_class: TCP_TABLE_CLASS) -> Vec<Row> {
+    get_rows::<Table, Row>(|buffer, size| {
+        GetExtendedTcpTable(buffer, size, false, family as _, table_class, 0)
+    })
+}
+
+unsafe fn get_udp_rows<Table, Row: Copy>(family: u16) -> Vec<Row> {
+    get_rows::<Table, Row>(|buffer, size| {
+        GetExtendedUdpTable(buffer, size, false, family as _, UDP_TABLE_OWNER_PID, 0)
+    })
+}
+
+pub(crate) fn get_connections(
+    connections: &mut Vec<Connection>,
+    filter: ConnectionsFilter<'_>,
+    with_pid: bool,
+) {
+    connections.clear();
+
+    let pid = |pid: u32| with_pid.then_some(Pid::from_u32(pid));
+    let tcp_table_class = if filter == ConnectionsFilter::Listening {
+        TCP_TABLE_OWNER_PID_LISTENER
+    } else {
+        TCP_TABLE_OWNER_PID_ALL
+    };
+
+    unsafe {
+        for row in
+            get_tcp_rows::<MIB_TCPTABLE_OWNER_PID, MIB_TCPROW_OWNER_PID>(AF_INET.0, tcp_table_class)
+        {
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Tcp,
+                local_address: SocketAddr::new(
+                    IpAddr::V4(Ipv4Addr::from(row.dwLocalAddr.to_ne_bytes())),
+                    port(row.dwLocalPort),
+                ),
+                remote_address: remote_address(
+                    IpAddr::V4(Ipv4Addr::from(row.dwRemoteAddr.to_ne_bytes())),
+                    row.dwRemotePort,
+                ),
+                state: Some(tcp_state(row.dwState)),
+                pid: pid(row.dwOwningPid),
+                inode: None,
+            });
+        }
+        for row in get_tcp_rows::<MIB_TCP6TABLE_OWNER_PID, MIB_TCP6ROW_OWNER_PID>(
+            AF_INET6.0,
+            tcp_table_class,
+        ) {
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Tcp,
+                local_address: SocketAddr::new(
+                    IpAddr::V6(Ipv6Addr::from(row.ucLocalAddr)).to_canonical(),
+                    port(row.dwLocalPort),
+                ),
+                remote_address: remote_address(
+                    IpAddr::V6(Ipv6Addr::from(row.ucRemoteAddr)).to_canonical(),
+                    row.dwRemotePort,
+                ),
+                state: Some(tcp_state(row.dwState)),
+                pid: pid(row.dwOwningPid),
+                inode: None,
+            });
+        }
+        // UDP sockets are never connected as far as this API is concerned.
+        for row in get_udp_rows::<MIB_UDPTABLE_OWNER_PID, MIB_UDPROW_OWNER_PID>(AF_INET.0) {
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Udp,
+                local_address: SocketAddr::new(
+                    IpAddr::V4(Ipv4Addr::from(row.dwLocalAddr.to_ne_bytes())),
+                    port(row.dwLocalPort),
+                ),
+                remote_address: None,
+                state: None,
+                pid: pid(row.dwOwningPid),
+                inode: None,
+            });
+        }
+        for row in get_udp_rows::<MIB_UDP6TABLE_OWNER_PID, MIB_UDP6ROW_OWNER_PID>(AF_INET6.0) {
+            connections.push(Connection {
+                protocol: ConnectionProtocol::Udp,
+                local_address: SocketAddr::new(
+                    IpAddr::V6(Ipv6Addr::from(row.ucLocalAddr)).to_canonical(),
+                    port(row.dwLocalPort),
+                ),
+                remote_address: None,
+                state: None,
+                pid: pid(row.dwOwningPid),
+                inode: None,
+            });
+        }
+    }
+}
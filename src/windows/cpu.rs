@@ -281,6 +281,10 @@ impl CpusWrapper {
         &self.cpus
     }
 
+    pub fn cpus_mut(&mut self) -> &mut [Cpu] {
+        &mut self.cpus
+    }
+
     fn init_if_needed(&mut self, refresh_kind: CpuRefreshKind) {
         if self.cpus.is_empty() {
             self.cpus = init_cpus(refresh_kind);
@@ -606,6 +610,7 @@ fn init_cpus(refresh_kind: CpuRefreshKind) -> Vec<Cpu> {
                     brand.clone(),
                     *frequency,
                 ),
+                usage_history: Vec::new(),
             });
         }
         ret
@@ -1,9 +1,11 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::disk::FillRate;
 use crate::sys::utils::HandleWrapper;
-use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage};
+use crate::{Disk, DiskKind, DiskRefreshKind, DiskUsage, RefreshError, RefreshSubsystem};
 
 use std::ffi::{OsStr, OsString};
+use std::io;
 use std::mem::size_of;
 use std::os::windows::ffi::OsStringExt;
 use std::path::Path;
@@ -41,15 +43,18 @@ const ERROR_MORE_DATA: HRESULT = windows::Win32::Foundation::ERROR_MORE_DATA.to_
 /// Volume GUID paths have the form `\\?\{xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx}\`.
 ///
 /// Rather confusingly, the Win32 API _also_ calls these "volume names".
-pub(crate) fn get_volume_guid_paths() -> Vec<Vec<u16>> {
+pub(crate) fn get_volume_guid_paths(errors: &mut Vec<RefreshError>) -> Vec<Vec<u16>> {
     let mut volume_names = Vec::new();
     unsafe {
         let mut buf = Box::new([0u16; VOLUME_NAME_SIZE]);
         let Ok(handle) = FindFirstVolumeW(&mut buf[..]) else {
-            sysinfo_debug!(
-                "Error: FindFirstVolumeW() = {:?}",
-                Error::from_win32().code()
-            );
+            let error = io::Error::last_os_error();
+            sysinfo_debug!("Error: FindFirstVolumeW() = {:?}", error);
+            errors.push(RefreshError::new(
+                RefreshSubsystem::Disks,
+                "FindFirstVolumeW",
+                error,
+            ));
             return Vec::new();
         };
         volume_names.push(from_zero_terminated(&buf[..]));
@@ -125,6 +130,7 @@ pub(crate) struct DiskInner {
     s_mount_point: OsString,
     total_space: u64,
     available_space: u64,
+    fill_rate: FillRate,
     is_removable: bool,
     is_read_only: bool,
     device_path: Vec<u16>,
@@ -160,6 +166,10 @@ impl DiskInner {
         self.available_space
     }
 
+    pub(crate) fn fill_rate_bytes_per_sec(&self) -> Option<f64> {
+        self.fill_rate.get()
+    }
+
     pub(crate) fn is_removable(&self) -> bool {
         self.is_removable
     }
@@ -169,6 +179,14 @@ impl DiskInner {
     }
 
     pub(crate) fn refresh_specifics(&mut self, refreshes: DiskRefreshKind) -> bool {
+        self.refresh_with_errors(refreshes, &mut Vec::new())
+    }
+
+    fn refresh_with_errors(
+        &mut self,
+        refreshes: DiskRefreshKind,
+        errors: &mut Vec<RefreshError>,
+    ) -> bool {
         if refreshes.kind() || refreshes.io_usage() {
             unsafe {
                 if let Some(handle) =
@@ -193,11 +211,20 @@ impl DiskInner {
         }
 
         if refreshes.storage() {
-            if let Some((total_space, available_space)) =
-                unsafe { get_drive_size(&self.mount_point) }
-            {
-                self.total_space = total_space;
-                self.available_space = available_space;
+            match unsafe { get_drive_size(&self.mount_point) } {
+                Ok((total_space, available_space)) => {
+                    self.total_space = total_space;
+                    self.available_space = available_space;
+                    self.fill_rate.update(available_space);
+                }
+                Err(error) => errors.push(RefreshError::new(
+                    RefreshSubsystem::Disks,
+                    format!(
+                        "GetDiskFreeSpaceExW({})",
+                        Path::new(&self.s_mount_point).display()
+                    ),
+                    error,
+                )),
             }
         }
         true
@@ -237,8 +264,27 @@ impl DisksInner {
         remove_not_listed_disks: bool,
         refreshes: DiskRefreshKind,
     ) {
+        let _ = self.try_refresh_specifics(remove_not_listed_disks, refreshes);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_disks: bool,
+        refreshes: DiskRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        let mut errors = Vec::new();
         unsafe {
-            get_list(&mut self.disks, remove_not_listed_disks, refreshes);
+            get_list(
+                &mut self.disks,
+                remove_not_listed_disks,
+                refreshes,
+                &mut errors,
+            );
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
         }
     }
 
@@ -251,7 +297,7 @@ impl DisksInner {
     }
 }
 
-unsafe fn get_drive_size(mount_point: &[u16]) -> Option<(u64, u64)> {
+unsafe fn get_drive_size(mount_point: &[u16]) -> io::Result<(u64, u64)> {
     let mut total_size = 0;
     let mut available_space = 0;
     let lpdirectoryname = PCWSTR::from_raw(mount_point.as_ptr());
@@ -263,9 +309,9 @@ unsafe fn get_drive_size(mount_point: &[u16]) -> Option<(u64, u64)> {
     )
     .is_ok()
     {
-        Some((total_size, available_space))
+        Ok((total_size, available_space))
     } else {
-        None
+        Err(io::Error::last_os_error())
     }
 }
 
@@ -273,8 +319,9 @@ pub(crate) unsafe fn get_list(
     disks: &mut Vec<Disk>,
     remove_not_listed_disks: bool,
     refreshes: DiskRefreshKind,
+    errors: &mut Vec<RefreshError>,
 ) {
-    for volume_name in get_volume_guid_paths() {
+    for volume_name in get_volume_guid_paths(errors) {
         let mount_paths = get_volume_path_names_for_volume_name(&volume_name[..]);
         if mount_paths.is_empty() {
             continue;
@@ -324,7 +371,7 @@ pub(crate) unsafe fn get_list(
                 .iter_mut()
                 .find(|d| d.inner.mount_point == mount_path && d.inner.file_system == file_system)
             {
-                disk.refresh_specifics(refreshes);
+                disk.inner.refresh_with_errors(refreshes, errors);
                 disk.inner.updated = true;
                 continue;
             }
@@ -336,6 +383,7 @@ pub(crate) unsafe fn get_list(
                 mount_point: mount_path,
                 total_space: 0,
                 available_space: 0,
+                fill_rate: FillRate::default(),
                 is_removable,
                 is_read_only,
                 device_path: device_path.clone(),
@@ -345,7 +393,7 @@ pub(crate) unsafe fn get_list(
                 written_bytes: 0,
                 updated: true,
             };
-            disk.refresh_specifics(refreshes);
+            disk.refresh_with_errors(refreshes, errors);
             disks.push(Disk { inner: disk });
         }
     }
@@ -1,17 +1,25 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::sys::utils::to_utf8_str;
-use crate::{Gid, Group, GroupInner};
+use crate::windows::sid::Sid;
+use crate::{Gid, Group, GroupInner, Uid};
 
 use std::ptr::null_mut;
+use windows::core::PCWSTR;
 use windows::Win32::Foundation::{ERROR_MORE_DATA, ERROR_SUCCESS};
 use windows::Win32::NetworkManagement::NetManagement::{
-    NetApiBufferFree, NetLocalGroupEnum, LOCALGROUP_INFO_0, MAX_PREFERRED_LENGTH,
+    NetApiBufferFree, NetLocalGroupEnum, NetLocalGroupGetMembers, LOCALGROUP_INFO_0,
+    LOCALGROUP_MEMBERS_INFO_1, MAX_PREFERRED_LENGTH,
 };
 
 impl GroupInner {
-    pub(crate) fn new(id: Gid, name: String) -> Self {
-        Self { id, name }
+    pub(crate) fn new(id: Gid, name: String, members: Vec<String>) -> Self {
+        Self {
+            id,
+            name,
+            members,
+            member_ids: Vec::new(),
+        }
     }
 
     pub(crate) fn id(&self) -> &Gid {
@@ -21,11 +29,15 @@ impl GroupInner {
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
+
+    pub(crate) fn members(&self) -> &[String] {
+        &self.members
+    }
 }
 
-struct NetApiBuffer(*mut LOCALGROUP_INFO_0);
+struct NetApiBuffer<T>(*mut T);
 
-impl Drop for NetApiBuffer {
+impl<T> Drop for NetApiBuffer<T> {
     fn drop(&mut self) {
         if !self.0.is_null() {
             unsafe { NetApiBufferFree(Some(self.0.cast())) };
@@ -33,18 +45,55 @@ impl Drop for NetApiBuffer {
     }
 }
 
-impl Default for NetApiBuffer {
+impl<T> Default for NetApiBuffer<T> {
     fn default() -> Self {
         Self(null_mut())
     }
 }
 
-impl NetApiBuffer {
-    pub fn inner_mut(&mut self) -> *mut *mut LOCALGROUP_INFO_0 {
+impl<T> NetApiBuffer<T> {
+    pub fn inner_mut(&mut self) -> *mut *mut T {
         &mut self.0 as *mut _
     }
 }
 
+/// Returns the names and the SIDs of the members of the group.
+unsafe fn get_group_members(group_name: PCWSTR) -> (Vec<String>, Vec<Uid>) {
+    let mut members = Vec::new();
+    let mut member_ids = Vec::new();
+    let mut nb_entries = 0;
+    let mut total_entries_hint = 0;
+    let mut handle = 0;
+    loop {
+        let mut buff = NetApiBuffer::<LOCALGROUP_MEMBERS_INFO_1>::default();
+        let res = NetLocalGroupGetMembers(
+            None,
+            group_name,
+            1, // Level. Here, get the SID, account type and name of the members.
+            buff.inner_mut() as *mut _,
+            MAX_PREFERRED_LENGTH,
+            &mut nb_entries,
+            &mut total_entries_hint,
+            Some(&mut handle),
+        );
+        if res != ERROR_SUCCESS.0 && res != ERROR_MORE_DATA.0 {
+            sysinfo_debug!("NetLocalGroupGetMembers failed: {res:?}");
+            break;
+        }
+        if !buff.0.is_null() {
+            let entries = std::slice::from_raw_parts(buff.0, nb_entries as usize);
+            for entry in entries {
+                members.push(to_utf8_str(entry.lgrmi1_name));
+                member_ids.extend(Sid::from_psid(entry.lgrmi1_sid).map(Uid));
+            }
+        }
+        if res != ERROR_MORE_DATA.0 {
+            break;
+        }
+    }
+    (members, member_ids)
+}
+
 pub(crate) fn get_groups(groups: &mut Vec<Group>) {
     groups.clear();
 
@@ -53,7 +102,7 @@ pub(crate) fn get_groups(groups: &mut Vec<Group>) {
         let mut total_entries_hint = 0;
         let mut handle = 0;
         loop {
-            let mut buff = NetApiBuffer::default();
+            let mut buff = NetApiBuffer::<LOCALGROUP_INFO_0>::default();
             let res = NetLocalGroupEnum(
                 None,
                 0, // Level. Here, just get the group names.
@@ -70,8 +119,12 @@ pub(crate) fn get_groups(groups: &mut Vec<Group>) {
             let entries = std::slice::from_raw_parts(buff.0, nb_entries as usize);
             for entry in entries {
                 let name = to_utf8_str(entry.lgrpi0_name);
+                let (members, member_ids) = get_group_members(PCWSTR(entry.lgrpi0_name.0));
                 groups.push(Group {
-                    inner: GroupInner::new(Gid(0), name),
+                    inner: GroupInner {
+                        member_ids,
+                        ..GroupInner::new(Gid(0), name, members)
+                    },
                 });
             }
             if res != ERROR_MORE_DATA.0 {
@@ -5,11 +5,15 @@ mod utils;
 cfg_if! {
     if #[cfg(feature = "system")] {
         mod process;
+        mod process_watcher;
         mod cpu;
+        mod reboot;
         mod system;
+        mod time_zones;
 
         pub(crate) use self::cpu::CpuInner;
         pub(crate) use self::process::ProcessInner;
+        pub(crate) use self::process_watcher::ProcessWatcherInner;
         pub(crate) use self::system::SystemInner;
         pub use self::system::{MINIMUM_CPU_UPDATE_INTERVAL, SUPPORTED_SIGNALS};
     }
@@ -22,22 +26,35 @@ cfg_if! {
     if #[cfg(feature = "component")] {
         pub mod component;
 
-        pub(crate) use self::component::{ComponentInner, ComponentsInner};
+        pub(crate) use self::component::{
+            ComponentInner, ComponentsInner, FanInner, FansInner, PowerSensorInner,
+            PowerSensorsInner,
+        };
+    }
+
+    if #[cfg(feature = "battery")] {
+        pub mod battery;
+
+        pub(crate) use self::battery::{BatteriesInner, BatteryInner};
     }
 
     if #[cfg(feature = "network")] {
+        mod connection;
         mod network;
         pub(crate) mod network_helper;
 
+        pub(crate) use self::connection::get_connections;
         pub(crate) use self::network::{NetworkDataInner, NetworksInner};
     }
 
     if #[cfg(feature = "user")] {
         mod groups;
+        mod sessions;
         mod users;
 
         pub(crate) use self::groups::get_groups;
-        pub(crate) use self::users::get_users;
+        pub(crate) use self::sessions::get_sessions;
+        pub(crate) use self::users::{get_user_by_id, get_users};
         pub(crate) use self::users::UserInner;
     }
 
@@ -53,8 +70,12 @@ pub const IS_SUPPORTED_SYSTEM: bool = true;
 
 // Make formattable by rustfmt.
 #[cfg(any())]
+mod battery;
+#[cfg(any())]
 mod component;
 #[cfg(any())]
+mod connection;
+#[cfg(any())]
 mod cpu;
 #[cfg(any())]
 mod disk;
@@ -67,6 +88,10 @@ mod network_helper;
 #[cfg(any())]
 mod process;
 #[cfg(any())]
+mod process_watcher;
+#[cfg(any())]
+mod sessions;
+#[cfg(any())]
 mod sid;
 #[cfg(any())]
 mod system;
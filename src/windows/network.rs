@@ -1,12 +1,38 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::network::refresh_networks_addresses;
-use crate::{IpNetwork, MacAddr, NetworkData};
+use crate::network_helper::{get_dns_servers, get_ip_address_from_socket_address};
+use crate::{
+    InterfaceKind, IpNetwork, MacAddr, NetworkData, NetworksRefreshKind, RefreshError,
+    RefreshSubsystem, RouteInfo, WifiInfo,
+};
 
-use std::collections::{hash_map, HashMap};
+use std::collections::{hash_map, HashMap, HashSet};
+use std::io;
+use std::net::IpAddr;
+use std::ptr::NonNull;
+use std::time::{Duration, Instant};
 
-use windows::Win32::NetworkManagement::IpHelper::{FreeMibTable, GetIfTable2, MIB_IF_TABLE2};
-use windows::Win32::NetworkManagement::Ndis::MediaConnectStateDisconnected;
+use windows::core::GUID;
+use windows::Win32::Foundation::{ERROR_SUCCESS, HANDLE};
+use windows::Win32::NetworkManagement::IpHelper::{
+    ConvertInterfaceLuidToAlias, FreeMibTable, GetIfStackTable, GetIfTable2, GetIpForwardTable2,
+    IF_TYPE_ETHERNET_CSMACD, IF_TYPE_IEEE80211, IF_TYPE_PPP, IF_TYPE_PROP_VIRTUAL,
+    IF_TYPE_SOFTWARE_LOOPBACK, IF_TYPE_TUNNEL, IF_TYPE_WWANPP, IF_TYPE_WWANPP2, MIB_IFSTACK_TABLE,
+    MIB_IF_ROW2, MIB_IF_TABLE2, MIB_IPFORWARD_TABLE2,
+};
+use windows::Win32::NetworkManagement::Ndis::{
+    IfOperStatusUp, MediaConnectStateDisconnected, NdisPhysicalMediumNative802_11,
+    NdisPhysicalMediumWirelessLan, NdisPhysicalMediumWirelessWan, IF_OPER_STATUS,
+    NET_IF_ACCESS_BROADCAST, NET_IF_ACCESS_LOOPBACK, NET_IF_ACCESS_POINT_TO_MULTI_POINT,
+    NET_IF_ACCESS_POINT_TO_POINT, NET_IF_ACCESS_TYPE,
+};
+use windows::Win32::NetworkManagement::WiFi::{
+    wlan_interface_state_connected, wlan_intf_opcode_channel_number,
+    wlan_intf_opcode_current_connection, WlanCloseHandle, WlanEnumInterfaces, WlanFreeMemory,
+    WlanOpenHandle, WlanQueryInterface, WLAN_CONNECTION_ATTRIBUTES, WLAN_INTERFACE_INFO_LIST,
+};
+use windows::Win32::Networking::WinSock::{AF_UNSPEC, SOCKADDR};
 
 macro_rules! old_and_new {
     ($ty_:expr, $name:ident, $old:ident, $new_val:expr) => {{
@@ -15,6 +41,207 @@ macro_rules! old_and_new {
     }};
 }
 
+fn interface_kind(row: &MIB_IF_ROW2) -> InterfaceKind {
+    let medium = row.PhysicalMediumType;
+    if medium == NdisPhysicalMediumNative802_11 || medium == NdisPhysicalMediumWirelessLan {
+        return InterfaceKind::Wifi;
+    } else if medium == NdisPhysicalMediumWirelessWan {
+        return InterfaceKind::Cellular;
+    }
+    match row.Type {
+        IF_TYPE_ETHERNET_CSMACD => {
+            // The first bit is `HardwareInterface`.
+            if row.InterfaceAndOperStatusFlags._bitfield & 1 != 0 {
+                InterfaceKind::Ethernet
+            } else {
+                InterfaceKind::Virtual
+            }
+        }
+        IF_TYPE_IEEE80211 => InterfaceKind::Wifi,
+        IF_TYPE_WWANPP | IF_TYPE_WWANPP2 => InterfaceKind::Cellular,
+        IF_TYPE_SOFTWARE_LOOPBACK => InterfaceKind::Loopback,
+        IF_TYPE_TUNNEL | IF_TYPE_PPP => InterfaceKind::Tunnel,
+        IF_TYPE_PROP_VIRTUAL => InterfaceKind::Virtual,
+        if_type => InterfaceKind::Other(if_type),
+    }
+}
+
+/// Converts a Wi-Fi channel number into its frequency (in MHz). 6 GHz channels cannot be
+/// distinguished from 2.4 GHz and 5 GHz ones from their number only, so they're not handled.
+fn channel_to_frequency(channel: u32) -> Option<u32> {
+    match channel {
+        14 => Some(2484),
+        1..=13 => Some(2407 + channel * 5),
+        32..=177 => Some(5000 + channel * 5),
+        _ => None,
+    }
+}
+
+unsafe fn get_wifi_info(handle: HANDLE, guid: &GUID) -> Option<WifiInfo> {
+    let mut size = 0;
+    let mut data: *mut WLAN_CONNECTION_ATTRIBUTES = std::ptr::null_mut();
+    if WlanQueryInterface(
+        handle,
+        guid,
+        wlan_intf_opcode_current_connection,
+        None,
+        &mut size,
+        &mut data as *mut _ as *mut _,
+        None,
+    ) != ERROR_SUCCESS.0
+        || data.is_null()
+    {
+        return None;
+    }
+    let attributes = &(*data).wlanAssociationAttributes;
+    let ssid = &attributes.dot11Ssid;
+    let ssid_len = (ssid.uSSIDLength as usize).min(ssid.ucSSID.len());
+    let bssid = MacAddr(attributes.dot11Bssid);
+    let mut info = WifiInfo {
+        ssid: if ssid_len > 0 {
+            Some(String::from_utf8_lossy(&ssid.ucSSID[..ssid_len]).into_owned())
+        } else {
+            None
+        },
+        bssid: if bssid.is_unspecified() {
+            None
+        } else {
+            Some(bssid)
+        },
+        // The signal quality goes from 0 (-100 dBm) to 100 (-50 dBm).
+        signal: Some(attributes.wlanSignalQuality.min(100) as i32 / 2 - 100),
+        frequency: None,
+        channel: None,
+    };
+    WlanFreeMemory(data as _);
+
+    let mut channel: *mut u32 = std::ptr::null_mut();
+    if WlanQueryInterface(
+        handle,
+        guid,
+        wlan_intf_opcode_channel_number,
+        None,
+        &mut size,
+        &mut channel as *mut _ as *mut _,
+        None,
+    ) == ERROR_SUCCESS.0
+        && !channel.is_null()
+    {
+        info.channel = Some(*channel);
+        info.frequency = channel_to_frequency(*channel);
+        WlanFreeMemory(channel as _);
+    }
+    Some(info)
+}
+
+fn refresh_wifi_info(interfaces: &mut HashMap<String, NetworkData>) {
+    if interfaces
+        .values()
+        .all(|interface| interface.inner.kind != InterfaceKind::Wifi)
+    {
+        return;
+    }
+    unsafe {
+        let mut handle = HANDLE::default();
+        let mut version = 0;
+        if WlanOpenHandle(2, None, &mut version, &mut handle) != ERROR_SUCCESS.0 {
+            sysinfo_debug!("WlanOpenHandle failed");
+            return;
+        }
+        let mut list: *mut WLAN_INTERFACE_INFO_LIST = std::ptr::null_mut();
+        if WlanEnumInterfaces(handle, None, &mut list) == ERROR_SUCCESS.0 && !list.is_null() {
+            let infos = std::slice::from_raw_parts(
+                (*list).InterfaceInfo.as_ptr(),
+                (*list).dwNumberOfItems as _,
+            );
+            for interface in interfaces.values_mut() {
+                let interface = &mut interface.inner;
+                if interface.kind != InterfaceKind::Wifi {
+                    continue;
+                }
+                interface.wifi_info = infos
+                    .iter()
+                    .find(|info| info.InterfaceGuid == interface.guid)
+                    .filter(|info| info.isState == wlan_interface_state_connected)
+                    .and_then(|info| get_wifi_info(handle, &info.InterfaceGuid));
+            }
+            WlanFreeMemory(list as _);
+        }
+        WlanCloseHandle(handle, None);
+    }
+}
+
+/// Converts a NUL-terminated UTF-16 buffer into a `String`.
+/// Retrieves on which interfaces each interface is stacked with `GetIfStackTable`. An interface
+/// stacked on several others aggregates them (like a NIC team) so it's their master, otherwise
+/// it's stacked on its lower device (like a VLAN).
+unsafe fn refresh_interfaces_stack(interfaces: &mut HashMap<String, NetworkData>) {
+    let mut table: *mut MIB_IFSTACK_TABLE = std::ptr::null_mut();
+    if GetIfStackTable(&mut table) != ERROR_SUCCESS {
+        sysinfo_debug!("GetIfStackTable failed");
+        return;
+    }
+    let mut lower_layers: HashMap<u32, Vec<u32>> = HashMap::new();
+    let rows = std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as _);
+    for row in rows {
+        // `0` marks the top and the bottom of a stack.
+        if row.HigherLayerInterfaceIndex != 0 && row.LowerLayerInterfaceIndex != 0 {
+            lower_layers
+                .entry(row.HigherLayerInterfaceIndex)
+                .or_default()
+                .push(row.LowerLayerInterfaceIndex);
+        }
+    }
+    FreeMibTable(table as _);
+
+    let names: HashMap<u32, String> = interfaces
+        .iter()
+        .map(|(name, interface)| (interface.inner.index, name.clone()))
+        .collect();
+    for interface in interfaces.values_mut() {
+        interface.inner.master = None;
+        interface.inner.lower_device = None;
+    }
+    for (&index, name) in &names {
+        // The filter drivers are stacked between the interfaces but aren't listed, so we go
+        // through them.
+        let mut lower_devices = Vec::new();
+        let mut visited = HashSet::new();
+        let mut to_visit = vec![index];
+        while let Some(index) = to_visit.pop() {
+            for &lower in lower_layers.get(&index).into_iter().flatten() {
+                if !visited.insert(lower) {
+                    continue;
+                }
+                match names.get(&lower) {
+                    Some(lower) => lower_devices.push(lower),
+                    None => to_visit.push(lower),
+                }
+            }
+        }
+        match lower_devices.as_slice() {
+            [] => {}
+            [lower_device] => {
+                if let Some(interface) = interfaces.get_mut(name) {
+                    interface.inner.lower_device = Some((*lower_device).clone());
+                }
+            }
+            members => {
+                for member in members {
+                    if let Some(interface) = interfaces.get_mut(*member) {
+                        interface.inner.master = Some(name.clone());
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn utf16_to_string(buffer: &[u16]) -> Option<String> {
+    let len = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+    String::from_utf16(&buffer[..len]).ok()
+}
+
 pub(crate) struct NetworksInner {
     pub(crate) interfaces: HashMap<String, NetworkData>,
 }
@@ -30,12 +257,133 @@ impl NetworksInner {
         &self.interfaces
     }
 
-    pub(crate) fn refresh(&mut self, remove_not_listed_interfaces: bool) {
+    pub(crate) fn default_routes(&self) -> Vec<RouteInfo> {
+        let mut table: *mut MIB_IPFORWARD_TABLE2 = std::ptr::null_mut();
+        let mut routes = Vec::new();
+
+        unsafe {
+            if GetIpForwardTable2(AF_UNSPEC, &mut table).is_err() {
+                return routes;
+            }
+            let rows =
+                std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as _);
+            for row in rows {
+                if row.DestinationPrefix.PrefixLength != 0 {
+                    continue;
+                }
+                let next_hop = &row.NextHop as *const _ as *mut SOCKADDR;
+                let Some(gateway) =
+                    NonNull::new(next_hop).and_then(get_ip_address_from_socket_address)
+                else {
+                    continue;
+                };
+                if gateway.is_unspecified() {
+                    continue;
+                }
+                let mut alias = [0u16; 257];
+                if ConvertInterfaceLuidToAlias(&row.InterfaceLuid, &mut alias).is_err() {
+                    continue;
+                }
+                let Some(interface) = utf16_to_string(&alias) else {
+                    continue;
+                };
+                routes.push(RouteInfo {
+                    gateway,
+                    interface,
+                    metric: row.Metric,
+                });
+            }
+            FreeMibTable(table as _);
+        }
+        routes
+    }
+
+    pub(crate) fn dns_servers(&self) -> Vec<IpAddr> {
+        unsafe { get_dns_servers() }
+    }
+
+    pub(crate) fn refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) {
+        let _ = self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true);
+    }
+
+    pub(crate) fn try_refresh_specifics(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+    ) -> Result<(), Vec<RefreshError>> {
+        self.refresh_filtered(remove_not_listed_interfaces, refresh_kind, &mut |_| true)
+            .map_err(|error| vec![error])
+    }
+
+    pub(crate) fn refresh_list_filtered(&mut self, filter: &mut dyn FnMut(&str) -> bool) {
+        let _ = self.refresh_filtered(true, NetworksRefreshKind::everything(), filter);
+    }
+
+    pub(crate) fn refresh_interface(&mut self, name: &str) -> bool {
+        let _ = self.update_interfaces(&mut |interface_name| interface_name == name);
+        self.interfaces
+            .get(name)
+            .is_some_and(|interface| interface.inner.updated)
+    }
+
+    pub(crate) fn refresh_interfaces(&mut self, names: &[&str]) -> usize {
+        let _ = self.update_interfaces(&mut |interface_name| names.contains(&interface_name));
+        names
+            .iter()
+            .filter(|name| {
+                self.interfaces
+                    .get(**name)
+                    .is_some_and(|interface| interface.inner.updated)
+            })
+            .count()
+    }
+
+    fn refresh_filtered(
+        &mut self,
+        remove_not_listed_interfaces: bool,
+        refresh_kind: NetworksRefreshKind,
+        filter: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<(), RefreshError> {
+        let result = self.update_interfaces(filter);
+        if remove_not_listed_interfaces {
+            // Remove interfaces which are gone.
+            self.interfaces.retain(|_, i| {
+                if !i.inner.updated {
+                    return false;
+                }
+                i.inner.updated = false;
+                true
+            });
+        }
+        if refresh_kind.addresses() {
+            refresh_networks_addresses(&mut self.interfaces);
+        }
+        if refresh_kind.wifi_info() {
+            refresh_wifi_info(&mut self.interfaces);
+        }
+        result
+    }
+
+    fn update_interfaces(
+        &mut self,
+        filter: &mut dyn FnMut(&str) -> bool,
+    ) -> Result<(), RefreshError> {
         let mut table: *mut MIB_IF_TABLE2 = std::ptr::null_mut();
+        let now = Instant::now();
 
         unsafe {
-            if GetIfTable2(&mut table).is_err() {
-                return;
+            // Contrary to `GetIfTable`, `GetIfTable2` returns 64-bit counters so they don't wrap.
+            let error = GetIfTable2(&mut table);
+            if error.is_err() {
+                return Err(RefreshError::new(
+                    RefreshSubsystem::Networks,
+                    "GetIfTable2",
+                    io::Error::from_raw_os_error(error.0 as _),
+                ));
             }
 
             for (_, data) in self.interfaces.iter_mut() {
@@ -81,17 +429,12 @@ impl NetworksInner {
                 if *groups.get(&id).unwrap_or(&0) > 1 {
                     continue;
                 }
-                let mut pos = 0;
-                for x in ptr.Alias.iter() {
-                    if *x == 0 {
-                        break;
-                    }
-                    pos += 1;
-                }
-                let interface_name = match String::from_utf16(&ptr.Alias[..pos]) {
-                    Ok(s) => s,
-                    _ => continue,
+                let Some(interface_name) = utf16_to_string(&ptr.Alias) else {
+                    continue;
                 };
+                if !filter(&interface_name) {
+                    continue;
+                }
 
                 let mtu = ptr.Mtu as u64;
                 match self.interfaces.entry(interface_name) {
@@ -114,9 +457,16 @@ impl NetworksInner {
                         );
                         old_and_new!(interface, errors_in, old_errors_in, ptr.InErrors);
                         old_and_new!(interface, errors_out, old_errors_out, ptr.OutErrors);
+                        old_and_new!(interface, discards_in, old_discards_in, ptr.InDiscards);
+                        old_and_new!(interface, discards_out, old_discards_out, ptr.OutDiscards);
                         if interface.mtu != mtu {
                             interface.mtu = mtu;
                         }
+                        interface.index = ptr.InterfaceIndex;
+                        interface.oper_status = ptr.OperStatus;
+                        interface.refresh_interval =
+                            Some(now.saturating_duration_since(interface.last_refresh));
+                        interface.last_refresh = now;
                         interface.updated = true;
                     }
                     hash_map::Entry::Vacant(e) => {
@@ -137,9 +487,26 @@ impl NetworksInner {
                                 old_errors_in: ptr.InErrors,
                                 errors_out: ptr.OutErrors,
                                 old_errors_out: ptr.OutErrors,
+                                discards_in: ptr.InDiscards,
+                                old_discards_in: ptr.InDiscards,
+                                discards_out: ptr.OutDiscards,
+                                old_discards_out: ptr.OutDiscards,
                                 mac_addr: MacAddr::UNSPECIFIED,
                                 ip_networks: vec![],
                                 mtu,
+                                if_type: ptr.Type,
+                                access_type: ptr.AccessType,
+                                oper_status: ptr.OperStatus,
+                                kind: interface_kind(ptr),
+                                guid: ptr.InterfaceGuid,
+                                wifi_info: None,
+                                index: ptr.InterfaceIndex,
+                                friendly_name: utf16_to_string(&ptr.Description)
+                                    .filter(|description| !description.is_empty()),
+                                master: None,
+                                lower_device: None,
+                                last_refresh: now,
+                                refresh_interval: None,
                                 updated: true,
                             },
                         });
@@ -147,19 +514,9 @@ impl NetworksInner {
                 }
             }
             FreeMibTable(table as _);
+            refresh_interfaces_stack(&mut self.interfaces);
         }
-        if remove_not_listed_interfaces {
-            // Remove interfaces which are gone.
-            self.interfaces.retain(|_, i| {
-                if !i.inner.updated {
-                    return false;
-                }
-                i.inner.updated = false;
-                true
-            });
-        }
-        // Refresh all interfaces' addresses.
-        refresh_networks_addresses(&mut self.interfaces);
+        Ok(())
     }
 }
 
@@ -176,11 +533,30 @@ pub(crate) struct NetworkDataInner {
     old_errors_in: u64,
     errors_out: u64,
     old_errors_out: u64,
+    discards_in: u64,
+    old_discards_in: u64,
+    discards_out: u64,
+    old_discards_out: u64,
+    /// When the above data was last refreshed.
+    last_refresh: Instant,
+    /// Time elapsed between the last two refreshes.
+    refresh_interval: Option<Duration>,
     updated: bool,
     pub(crate) mac_addr: MacAddr,
     pub(crate) ip_networks: Vec<IpNetwork>,
     /// Interface Maximum Transfer Unit (MTU)
     mtu: u64,
+    /// Interface type (`IF_TYPE_*`).
+    if_type: u32,
+    access_type: NET_IF_ACCESS_TYPE,
+    oper_status: IF_OPER_STATUS,
+    kind: InterfaceKind,
+    guid: GUID,
+    wifi_info: Option<WifiInfo>,
+    index: u32,
+    friendly_name: Option<String>,
+    master: Option<String>,
+    lower_device: Option<String>,
 }
 
 impl NetworkDataInner {
@@ -232,6 +608,38 @@ impl NetworkDataInner {
         self.errors_out
     }
 
+    pub(crate) fn dropped_packets_received(&self) -> u64 {
+        self.discards_in.saturating_sub(self.old_discards_in)
+    }
+
+    pub(crate) fn total_dropped_packets_received(&self) -> u64 {
+        self.discards_in
+    }
+
+    pub(crate) fn dropped_packets_transmitted(&self) -> u64 {
+        self.discards_out.saturating_sub(self.old_discards_out)
+    }
+
+    pub(crate) fn total_dropped_packets_transmitted(&self) -> u64 {
+        self.discards_out
+    }
+
+    pub(crate) fn collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_collisions(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn multicast_received(&self) -> u64 {
+        0
+    }
+
+    pub(crate) fn total_multicast_received(&self) -> u64 {
+        0
+    }
+
     pub(crate) fn mac_address(&self) -> MacAddr {
         self.mac_addr
     }
@@ -243,4 +651,57 @@ impl NetworkDataInner {
     pub(crate) fn mtu(&self) -> u64 {
         self.mtu
     }
+
+    pub(crate) fn refresh_interval(&self) -> Option<Duration> {
+        self.refresh_interval
+    }
+
+    pub(crate) fn is_loopback(&self) -> bool {
+        self.if_type == IF_TYPE_SOFTWARE_LOOPBACK || self.access_type == NET_IF_ACCESS_LOOPBACK
+    }
+
+    pub(crate) fn is_up(&self) -> bool {
+        self.oper_status == IfOperStatusUp
+    }
+
+    pub(crate) fn is_point_to_point(&self) -> bool {
+        self.access_type == NET_IF_ACCESS_POINT_TO_POINT
+    }
+
+    pub(crate) fn supports_multicast(&self) -> bool {
+        self.access_type == NET_IF_ACCESS_BROADCAST
+            || self.access_type == NET_IF_ACCESS_POINT_TO_MULTI_POINT
+    }
+
+    pub(crate) fn supports_broadcast(&self) -> bool {
+        self.access_type == NET_IF_ACCESS_BROADCAST
+    }
+
+    pub(crate) fn kind(&self) -> InterfaceKind {
+        self.kind
+    }
+
+    pub(crate) fn wifi_info(&self) -> Option<WifiInfo> {
+        self.wifi_info.clone()
+    }
+
+    pub(crate) fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub(crate) fn friendly_name(&self) -> Option<&str> {
+        self.friendly_name.as_deref()
+    }
+
+    pub(crate) fn master(&self) -> Option<&str> {
+        self.master.as_deref()
+    }
+
+    pub(crate) fn lower_device(&self) -> Option<&str> {
+        self.lower_device.as_deref()
+    }
+
+    pub(crate) fn vlan_id(&self) -> Option<u16> {
+        None
+    }
 }
@@ -7,10 +7,13 @@ use std::ptr::{null_mut, NonNull};
 use windows::Win32::Foundation::{ERROR_BUFFER_OVERFLOW, ERROR_SUCCESS};
 use windows::Win32::NetworkManagement::IpHelper::{
     GetAdaptersAddresses, GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST,
-    IP_ADAPTER_ADDRESSES_LH, IP_ADAPTER_UNICAST_ADDRESS_LH,
+    GAA_FLAG_SKIP_UNICAST, GET_ADAPTERS_ADDRESSES_FLAGS, IP_ADAPTER_ADDRESSES_LH,
+    IP_ADAPTER_UNICAST_ADDRESS_LH,
 };
+use windows::Win32::NetworkManagement::Ndis::IfOperStatusUp;
 use windows::Win32::Networking::WinSock::{
-    AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR, SOCKADDR_IN, SOCKADDR_IN6,
+    IpDadStateDeprecated, IpSuffixOriginRandom, AF_INET, AF_INET6, AF_UNSPEC, SOCKADDR,
+    SOCKADDR_IN, SOCKADDR_IN6,
 };
 
 use crate::{IpNetwork, MacAddr};
@@ -102,6 +105,42 @@ impl Drop for InterfaceAddressIterator {
 }
 
 pub(crate) unsafe fn get_interface_address() -> Result<InterfaceAddressIterator, String> {
+    get_adapters_addresses(
+        GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_DNS_SERVER,
+    )
+}
+
+/// Returns the DNS servers of the interfaces which are up, without duplicates.
+pub(crate) unsafe fn get_dns_servers() -> Vec<IpAddr> {
+    let Ok(iterator) = get_adapters_addresses(
+        GAA_FLAG_SKIP_UNICAST | GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_ANYCAST,
+    ) else {
+        return Vec::new();
+    };
+    let mut servers = Vec::new();
+    let mut adapter = iterator.buf;
+    while !adapter.is_null() {
+        if (*adapter).OperStatus == IfOperStatusUp {
+            let mut server = (*adapter).FirstDnsServerAddress;
+            while !server.is_null() {
+                if let Some(addr) = NonNull::new((*server).Address.lpSockaddr)
+                    .and_then(get_ip_address_from_socket_address)
+                {
+                    if !servers.contains(&addr) {
+                        servers.push(addr);
+                    }
+                }
+                server = (*server).Next;
+            }
+        }
+        adapter = (*adapter).Next;
+    }
+    servers
+}
+
+unsafe fn get_adapters_addresses(
+    flags: GET_ADAPTERS_ADDRESSES_FLAGS,
+) -> Result<InterfaceAddressIterator, String> {
     // https://learn.microsoft.com/en-us/windows/win32/api/iphlpapi/nf-iphlpapi-getadaptersaddresses#remarks
     // A 15k buffer is recommended
     let mut size: u32 = 15 * 1024;
@@ -114,7 +153,7 @@ pub(crate) unsafe fn get_interface_address() -> Result<InterfaceAddressIterator,
         iterator = iterator.realloc(size as _)?;
         ret = GetAdaptersAddresses(
             AF_UNSPEC.0.into(),
-            GAA_FLAG_SKIP_MULTICAST | GAA_FLAG_SKIP_ANYCAST | GAA_FLAG_SKIP_DNS_SERVER,
+            flags,
             None,
             Some(iterator.buf),
             &mut size,
@@ -142,6 +181,9 @@ fn get_ip_networks(mut prefixes_ptr: *mut IP_ADAPTER_UNICAST_ADDRESS_LH) -> Hash
                 ip_networks.insert(IpNetwork {
                     addr: ipaddr,
                     prefix: prefix.OnLinkPrefixLength,
+                    // Temporary addresses have a randomly generated interface identifier.
+                    is_temporary: prefix.SuffixOrigin == IpSuffixOriginRandom,
+                    is_deprecated: prefix.DadState == IpDadStateDeprecated,
                 });
             }
         }
@@ -151,7 +193,9 @@ fn get_ip_networks(mut prefixes_ptr: *mut IP_ADAPTER_UNICAST_ADDRESS_LH) -> Hash
 }
 
 /// Converts a Windows socket address to an ip address.
-fn get_ip_address_from_socket_address(socket_address: NonNull<SOCKADDR>) -> Option<IpAddr> {
+pub(crate) fn get_ip_address_from_socket_address(
+    socket_address: NonNull<SOCKADDR>,
+) -> Option<IpAddr> {
     let socket_address_family = unsafe { socket_address.as_ref().sa_family };
     match socket_address_family {
         AF_INET => {
@@ -1,9 +1,13 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
+use crate::common::field_status::FieldStatuses;
 use crate::sys::system::is_proc_running;
 use crate::sys::utils::HandleWrapper;
 use crate::windows::Sid;
-use crate::{DiskUsage, Gid, Pid, ProcessRefreshKind, ProcessStatus, Signal, Uid};
+use crate::{
+    DiskUsage, FdUsage, FieldStatus, Gid, IoPriority, IoPriorityError, Namespaces, Pid,
+    ProcessField, ProcessRefreshKind, ProcessStatus, Signal, Uid,
+};
 
 use std::ffi::{OsStr, OsString};
 use std::fmt;
@@ -17,20 +21,21 @@ use std::process::{self, ExitStatus};
 use std::ptr::null_mut;
 use std::str::{self, FromStr};
 use std::sync::{Arc, OnceLock};
-use std::time::Instant;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use libc::c_void;
 use ntapi::ntrtl::RTL_USER_PROCESS_PARAMETERS;
 use ntapi::ntwow64::{PEB32, RTL_USER_PROCESS_PARAMETERS32};
-use windows::core::PCWSTR;
+use windows::core::{PCWSTR, PWSTR};
 use windows::Wdk::System::SystemServices::RtlGetVersion;
 use windows::Wdk::System::Threading::{
     NtQueryInformationProcess, ProcessBasicInformation, ProcessCommandLineInformation,
     ProcessWow64Information, PROCESSINFOCLASS,
 };
 use windows::Win32::Foundation::{
-    LocalFree, ERROR_INSUFFICIENT_BUFFER, FILETIME, HANDLE, HLOCAL, HMODULE, MAX_PATH,
-    STATUS_BUFFER_OVERFLOW, STATUS_BUFFER_TOO_SMALL, STATUS_INFO_LENGTH_MISMATCH, UNICODE_STRING,
+    LocalFree, ERROR_ACCESS_DENIED, ERROR_INSUFFICIENT_BUFFER, ERROR_INVALID_PARAMETER, FILETIME,
+    HANDLE, HLOCAL, HMODULE, MAX_PATH, STATUS_BUFFER_OVERFLOW, STATUS_BUFFER_TOO_SMALL,
+    STATUS_INFO_LENGTH_MISMATCH, UNICODE_STRING,
 };
 use windows::Win32::Security::{GetTokenInformation, TokenUser, TOKEN_QUERY, TOKEN_USER};
 use windows::Win32::System::Diagnostics::Debug::ReadProcessMemory;
@@ -45,9 +50,9 @@ use windows::Win32::System::RemoteDesktop::ProcessIdToSessionId;
 use windows::Win32::System::SystemInformation::OSVERSIONINFOEXW;
 use windows::Win32::System::Threading::{
     GetExitCodeProcess, GetProcessHandleCount, GetProcessIoCounters, GetProcessTimes,
-    GetSystemTimes, OpenProcess, OpenProcessToken, CREATE_NO_WINDOW, IO_COUNTERS, PEB,
-    PROCESS_BASIC_INFORMATION, PROCESS_QUERY_INFORMATION, PROCESS_QUERY_LIMITED_INFORMATION,
-    PROCESS_VM_READ,
+    GetSystemTimes, OpenProcess, OpenProcessToken, QueryFullProcessImageNameW, CREATE_NO_WINDOW,
+    IO_COUNTERS, PEB, PROCESS_BASIC_INFORMATION, PROCESS_NAME_WIN32, PROCESS_QUERY_INFORMATION,
+    PROCESS_QUERY_LIMITED_INFORMATION, PROCESS_VM_READ,
 };
 use windows::Win32::UI::Shell::CommandLineToArgvW;
 
@@ -64,30 +69,43 @@ impl fmt::Display for ProcessStatus {
     }
 }
 
-fn get_process_handler(pid: Pid) -> Option<HandleWrapper> {
+fn field_status_from_error(error: &windows::core::Error) -> FieldStatus {
+    if error.code() == ERROR_ACCESS_DENIED.to_hresult() {
+        FieldStatus::PermissionDenied
+    } else if error.code() == ERROR_INVALID_PARAMETER.to_hresult() {
+        // It's what `OpenProcess` returns when the process doesn't exist.
+        FieldStatus::NotFound
+    } else {
+        FieldStatus::Other
+    }
+}
+
+/// Returns the handle of the process and whether it can be used to read the memory of the
+/// process, or why it couldn't be opened.
+fn get_process_handler(pid: Pid) -> Result<(HandleWrapper, bool), FieldStatus> {
     if pid.0 == 0 {
-        return None;
+        // The System Idle Process cannot be opened.
+        return Err(FieldStatus::NotFound);
     }
     let options = PROCESS_QUERY_INFORMATION | PROCESS_VM_READ;
 
-    HandleWrapper::new(unsafe { OpenProcess(options, false, pid.0 as u32).unwrap_or_default() })
-        .or_else(|| {
-            sysinfo_debug!(
-                "OpenProcess failed, error: {:?}",
-                io::Error::last_os_error()
-            );
-            HandleWrapper::new(unsafe {
-                OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid.0 as u32)
-                    .unwrap_or_default()
-            })
-        })
-        .or_else(|| {
-            sysinfo_debug!(
-                "OpenProcess limited failed, error: {:?}",
-                io::Error::last_os_error()
-            );
-            None
-        })
+    match unsafe { OpenProcess(options, false, pid.0 as u32) } {
+        Ok(handle) => {
+            if let Some(handle) = HandleWrapper::new(handle) {
+                return Ok((handle, true));
+            }
+        }
+        Err(_error) => sysinfo_debug!("OpenProcess failed, error: {:?}", _error),
+    }
+    match unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid.0 as u32) } {
+        Ok(handle) => HandleWrapper::new(handle)
+            .map(|handle| (handle, false))
+            .ok_or(FieldStatus::Other),
+        Err(error) => {
+            sysinfo_debug!("OpenProcess limited failed, error: {:?}", error);
+            Err(field_status_from_error(&error))
+        }
+    }
 }
 
 unsafe fn get_process_user_id(process: &mut ProcessInner, refresh_kind: ProcessRefreshKind) {
@@ -178,6 +196,7 @@ pub(crate) struct ProcessInner {
     name: OsString,
     cmd: Vec<OsString>,
     exe: Option<PathBuf>,
+    image_path: Option<PathBuf>,
     pid: Pid,
     user_id: Option<Uid>,
     environ: Vec<OsString>,
@@ -188,8 +207,15 @@ pub(crate) struct ProcessInner {
     pub(crate) parent: Option<Pid>,
     status: ProcessStatus,
     handle: Option<Arc<HandleWrapper>>,
+    // Why `handle` couldn't be opened, and whether it can be used to read the memory of the
+    // process, which is needed for the executable, the command line, the environment and the
+    // current working directory.
+    handle_status: FieldStatus,
+    can_read_memory: bool,
+    field_statuses: FieldStatuses,
     cpu_calc_values: CPUsageCalculationValues,
     start_time: u64,
+    start_time_precise: Duration,
     pub(crate) run_time: u64,
     cpu_usage: f32,
     pub(crate) updated: bool,
@@ -198,6 +224,10 @@ pub(crate) struct ProcessInner {
     read_bytes: u64,
     written_bytes: u64,
     accumulated_cpu_time: u64,
+    user_cpu_time: Duration,
+    system_cpu_time: Duration,
+    pub(crate) thread_count: Option<usize>,
+    fd_usage: Option<FdUsage>,
     exists: bool,
 }
 
@@ -237,27 +267,67 @@ fn windows_8_1_or_newer() -> &'static bool {
     })
 }
 
+/// Unlike `GetModuleFileNameExW`, it only needs the `PROCESS_QUERY_LIMITED_INFORMATION` access
+/// right and the path can be longer than `MAX_PATH`.
+unsafe fn get_image_path(process_handler: &HandleWrapper) -> Option<PathBuf> {
+    let mut buffer = vec![0u16; MAX_PATH as usize + 1];
+    loop {
+        let mut size = buffer.len() as u32;
+        match QueryFullProcessImageNameW(
+            **process_handler,
+            PROCESS_NAME_WIN32,
+            PWSTR::from_raw(buffer.as_mut_ptr()),
+            &mut size,
+        ) {
+            Ok(()) => return Some(PathBuf::from(OsString::from_wide(&buffer[..size as usize]))),
+            // The paths cannot be longer than 32_767 characters.
+            Err(err)
+                if err.code() == ERROR_INSUFFICIENT_BUFFER.to_hresult()
+                    && buffer.len() < 32_768 =>
+            {
+                buffer.resize(buffer.len() * 2, 0);
+            }
+            Err(_err) => {
+                sysinfo_debug!("QueryFullProcessImageNameW failed: {_err:?}");
+                return None;
+            }
+        }
+    }
+}
+
 unsafe fn get_exe(process_handler: &HandleWrapper) -> Option<PathBuf> {
     let mut exe_buf = [0u16; MAX_PATH as usize + 1];
-    GetModuleFileNameExW(
+    if GetModuleFileNameExW(
         Some(**process_handler),
         Some(HMODULE::default()),
         exe_buf.as_mut_slice(),
-    );
+    ) == 0
+    {
+        sysinfo_debug!(
+            "GetModuleFileNameExW failed: {:?}",
+            io::Error::last_os_error()
+        );
+        return None;
+    }
 
     Some(PathBuf::from(null_terminated_wchar_to_string(&exe_buf)))
 }
 
 impl ProcessInner {
     pub(crate) fn new(pid: Pid, parent: Option<Pid>, now: u64, name: OsString) -> Self {
-        let (handle, start_time, run_time) = if let Some(handle) = get_process_handler(pid) {
-            let (start_time, run_time) = get_start_and_run_time(*handle, now);
-            (Some(Arc::new(handle)), start_time, run_time)
-        } else {
-            (None, 0, 0)
+        let (handle, handle_status, can_read_memory) = match get_process_handler(pid) {
+            Ok((handle, can_read_memory)) => (Some(handle), FieldStatus::Ok, can_read_memory),
+            Err(status) => (None, status, false),
+        };
+        let (start_time, start_time_precise, run_time) = match handle {
+            Some(ref handle) => get_start_and_run_time(**handle, now),
+            None => (0, Duration::ZERO, 0),
         };
         Self {
-            handle,
+            handle: handle.map(Arc::new),
+            handle_status,
+            can_read_memory,
+            field_statuses: FieldStatuses::default(),
             name,
             pid,
             parent,
@@ -265,6 +335,7 @@ impl ProcessInner {
             cmd: Vec::new(),
             environ: Vec::new(),
             exe: None,
+            image_path: None,
             cwd: None,
             root: None,
             status: ProcessStatus::Run,
@@ -273,6 +344,7 @@ impl ProcessInner {
             cpu_usage: 0.,
             cpu_calc_values: CPUsageCalculationValues::new(),
             start_time,
+            start_time_precise,
             run_time,
             updated: true,
             old_read_bytes: 0,
@@ -280,6 +352,10 @@ impl ProcessInner {
             read_bytes: 0,
             written_bytes: 0,
             accumulated_cpu_time: 0,
+            user_cpu_time: Duration::ZERO,
+            system_cpu_time: Duration::ZERO,
+            thread_count: None,
+            fd_usage: None,
             exists: true,
         }
     }
@@ -297,6 +373,14 @@ impl ProcessInner {
         if refresh_kind.disk_usage() {
             update_disk_usage(self);
         }
+        if refresh_kind.open_files() {
+            self.fd_usage = FdUsage::update(
+                self.fd_usage,
+                self.open_files(),
+                self.open_files_limit(),
+                None,
+            );
+        }
         if refresh_kind.memory() {
             let mut mem_info = PROCESS_MEMORY_COUNTERS_EX::default();
             if let Some(handle) = self.get_handle() {
@@ -314,9 +398,32 @@ impl ProcessInner {
                 }
             }
         }
+        let cmd_needs_update = refresh_kind.cmd().needs_update(|| self.cmd.is_empty());
+        let environ_needs_update = refresh_kind
+            .environ()
+            .needs_update(|| self.environ.is_empty());
+        let cwd_needs_update = refresh_kind.cwd().needs_update(|| self.cwd.is_none());
         unsafe {
             get_process_user_id(self, refresh_kind);
-            get_process_params(self, refresh_kind, refresh_parent);
+            let params_read = get_process_params(self, refresh_kind, refresh_parent);
+            let params_status = match self.memory_status() {
+                FieldStatus::Ok if !params_read => FieldStatus::Other,
+                status => status,
+            };
+            if cmd_needs_update {
+                self.field_statuses.set(ProcessField::Cmd, params_status);
+            }
+            if environ_needs_update {
+                self.field_statuses
+                    .set(ProcessField::Environ, params_status);
+            }
+            if cwd_needs_update {
+                let status = match params_status {
+                    FieldStatus::Ok if self.cwd.is_none() => FieldStatus::Other,
+                    status => status,
+                };
+                self.field_statuses.set(ProcessField::Cwd, status);
+            }
         }
         if refresh_kind.exe().needs_update(|| self.exe.is_none()) {
             unsafe {
@@ -325,6 +432,20 @@ impl ProcessInner {
                     None => get_executable_path(self.pid),
                 };
             }
+            let status = match self.memory_status() {
+                FieldStatus::Ok if self.exe.is_none() => FieldStatus::Other,
+                status => status,
+            };
+            self.field_statuses.set(ProcessField::Exe, status);
+        }
+        if refresh_kind
+            .exe()
+            .needs_update(|| self.image_path.is_none())
+        {
+            self.image_path = self
+                .handle
+                .as_ref()
+                .and_then(|handle| unsafe { get_image_path(handle) });
         }
         self.run_time = now.saturating_sub(self.start_time());
         self.updated = true;
@@ -347,13 +468,34 @@ impl ProcessInner {
             }
         };
 
-        Self::new(pid, ppid, now, name)
+        let mut p = Self::new(pid, ppid, now, name);
+        p.thread_count = Some(entry.cntThreads as _);
+        p
     }
 
     pub(crate) fn get_handle(&self) -> Option<HANDLE> {
         self.handle.as_ref().map(|h| ***h)
     }
 
+    /// Returns whether the memory of the process can be read, and why otherwise.
+    fn memory_status(&self) -> FieldStatus {
+        match self.handle_status {
+            FieldStatus::Ok if !self.can_read_memory => FieldStatus::PermissionDenied,
+            status => status,
+        }
+    }
+
+    /// Returns `false` if the PID is now owned by another process.
+    pub(crate) fn is_same_process(&self) -> bool {
+        // As long as we have a handle on the process, its PID cannot be reused.
+        if self.handle.is_some() {
+            return true;
+        }
+        get_process_handler(self.pid)
+            .map(|(handle, _)| get_start_time(*handle) == self.start_time)
+            .unwrap_or(true)
+    }
+
     pub(crate) fn kill_with(&self, signal: Signal) -> Option<bool> {
         crate::sys::system::convert_signal(signal)?;
         let mut kill = process::Command::new("taskkill.exe");
@@ -377,6 +519,10 @@ impl ProcessInner {
         self.exe.as_deref()
     }
 
+    pub(crate) fn image_path(&self) -> Option<&Path> {
+        self.image_path.as_deref()
+    }
+
     pub(crate) fn pid(&self) -> Pid {
         self.pid
     }
@@ -413,6 +559,10 @@ impl ProcessInner {
         self.start_time
     }
 
+    pub(crate) fn start_time_precise(&self) -> SystemTime {
+        UNIX_EPOCH + self.start_time_precise
+    }
+
     pub(crate) fn run_time(&self) -> u64 {
         self.run_time
     }
@@ -425,6 +575,18 @@ impl ProcessInner {
         self.accumulated_cpu_time
     }
 
+    pub(crate) fn user_cpu_time(&self) -> Duration {
+        self.user_cpu_time
+    }
+
+    pub(crate) fn system_cpu_time(&self) -> Duration {
+        self.system_cpu_time
+    }
+
+    pub(crate) fn thread_count(&self) -> Option<usize> {
+        self.thread_count
+    }
+
     pub(crate) fn disk_usage(&self) -> DiskUsage {
         DiskUsage {
             written_bytes: self.written_bytes.saturating_sub(self.old_written_bytes),
@@ -434,6 +596,10 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn field_status(&self, field: ProcessField) -> FieldStatus {
+        self.field_statuses.get(field)
+    }
+
     pub(crate) fn user_id(&self) -> Option<&Uid> {
         self.user_id.as_ref()
     }
@@ -450,6 +616,22 @@ impl ProcessInner {
         None
     }
 
+    pub(crate) fn umask(&self) -> Option<u32> {
+        None
+    }
+
+    pub(crate) fn ns_pid(&self) -> Option<Pid> {
+        None
+    }
+
+    pub(crate) fn io_priority(&self) -> Option<IoPriority> {
+        None
+    }
+
+    pub(crate) fn set_io_priority(&self, _priority: IoPriority) -> Result<(), IoPriorityError> {
+        Err(IoPriorityError::Unsupported)
+    }
+
     pub(crate) fn wait(&self) -> Option<ExitStatus> {
         if let Some(handle) = self.get_handle() {
             while is_proc_running(handle) {
@@ -477,6 +659,16 @@ impl ProcessInner {
         }
     }
 
+    pub(crate) fn exit_code(&self) -> Option<i32> {
+        let handle = self.get_handle()?;
+        if is_proc_running(handle) {
+            return None;
+        }
+        let mut exit_code = 0;
+        unsafe { GetExitCodeProcess(handle, &mut exit_code) }.ok()?;
+        Some(exit_code as _)
+    }
+
     pub(crate) fn session_id(&self) -> Option<Pid> {
         unsafe {
             let mut out = 0;
@@ -522,6 +714,14 @@ impl ProcessInner {
     pub(crate) fn open_files_limit(&self) -> Option<usize> {
         crate::System::open_files_limit()
     }
+
+    pub(crate) fn fd_usage(&self) -> Option<FdUsage> {
+        self.fd_usage
+    }
+
+    pub(crate) fn namespaces(&self) -> Option<Namespaces> {
+        None
+    }
 }
 
 #[inline]
@@ -564,12 +764,23 @@ fn compute_start(process_times: u64) -> u64 {
     process_times / 10_000_000 - 11_644_473_600
 }
 
-fn get_start_and_run_time(handle: HANDLE, now: u64) -> (u64, u64) {
+#[inline]
+fn compute_start_precise(process_times: u64) -> Duration {
+    // 116_444_736_000_000_000 is the number of 100-nanosecond intervals between the Windows
+    // epoch (1601-01-01) and the Linux epoch (1970-01-01).
+    Duration::from_nanos(
+        process_times
+            .saturating_sub(116_444_736_000_000_000)
+            .saturating_mul(100),
+    )
+}
+
+fn get_start_and_run_time(handle: HANDLE, now: u64) -> (u64, Duration, u64) {
     unsafe {
         let process_times = get_process_times(handle);
         let start = compute_start(process_times);
         let run_time = check_sub(now, start);
-        (start, run_time)
+        (start, compute_start_precise(process_times), run_time)
     }
 }
 
@@ -738,15 +949,15 @@ unsafe fn get_process_params(
     process: &mut ProcessInner,
     refresh_kind: ProcessRefreshKind,
     refresh_parent: bool,
-) {
+) -> bool {
     let has_anything_to_update = has_anything_to_update(process, refresh_kind);
     if !refresh_parent && !has_anything_to_update {
-        return;
+        return true;
     }
 
     let handle = match process.handle.as_ref().map(|handle| handle.0) {
         Some(h) => h,
-        None => return,
+        None => return false,
     };
 
     // First check if target process is running in wow64 compatibility emulator
@@ -761,7 +972,7 @@ unsafe fn get_process_params(
     .is_err()
     {
         sysinfo_debug!("Unable to check WOW64 information about the process");
-        return;
+        return false;
     }
     let pwow32info = pwow32info.assume_init();
 
@@ -779,7 +990,7 @@ unsafe fn get_process_params(
         .is_err()
         {
             sysinfo_debug!("Unable to get basic process information");
-            return;
+            return false;
         }
         let pinfo = pbasicinfo.assume_init();
 
@@ -792,7 +1003,7 @@ unsafe fn get_process_params(
         process.parent = parent;
 
         if !has_anything_to_update {
-            return;
+            return true;
         }
 
         Some(pinfo)
@@ -803,7 +1014,7 @@ unsafe fn get_process_params(
     if pwow32info.is_null() {
         // target is a 64 bit process
 
-        let Some(pinfo) = pinfo else { return };
+        let Some(pinfo) = pinfo else { return false };
 
         let mut peb = MaybeUninit::<PEB>::uninit();
         if ReadProcessMemory(
@@ -816,7 +1027,7 @@ unsafe fn get_process_params(
         .is_err()
         {
             sysinfo_debug!("Unable to read process PEB");
-            return;
+            return false;
         }
 
         let peb = peb.assume_init();
@@ -832,7 +1043,7 @@ unsafe fn get_process_params(
         .is_err()
         {
             sysinfo_debug!("Unable to read process parameters");
-            return;
+            return false;
         }
 
         let proc_params = proc_params.assume_init();
@@ -845,11 +1056,12 @@ unsafe fn get_process_params(
             &mut process.cwd,
             &mut process.root,
         );
+        return true;
     }
     // target is a 32 bit process in wow64 mode
 
     if !has_anything_to_update {
-        return;
+        return true;
     }
 
     let mut peb32 = MaybeUninit::<PEB32>::uninit();
@@ -863,7 +1075,7 @@ unsafe fn get_process_params(
     .is_err()
     {
         sysinfo_debug!("Unable to read PEB32");
-        return;
+        return false;
     }
     let peb32 = peb32.assume_init();
 
@@ -878,7 +1090,7 @@ unsafe fn get_process_params(
     .is_err()
     {
         sysinfo_debug!("Unable to read 32 bit process parameters");
-        return;
+        return false;
     }
     let proc_params = proc_params.assume_init();
     get_cmd_line(&proc_params, handle, refresh_kind, &mut process.cmd);
@@ -890,6 +1102,7 @@ unsafe fn get_process_params(
         &mut process.cwd,
         &mut process.root,
     );
+    true
 }
 
 fn get_cwd_and_root<T: RtlUserProcessParameters>(
@@ -1055,6 +1268,8 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
         let global_user_time = filetime_to_u64(fglobal_user_time);
 
         p.accumulated_cpu_time = user.saturating_add(sys) / FILETIMES_PER_MILLISECONDS;
+        p.user_cpu_time = Duration::from_nanos(user.saturating_mul(100));
+        p.system_cpu_time = Duration::from_nanos(sys.saturating_mul(100));
         if !need_update {
             return;
         }
@@ -1086,17 +1301,24 @@ pub(crate) fn compute_cpu_usage(p: &mut ProcessInner, nb_cpus: u64) {
 pub(crate) fn update_disk_usage(p: &mut ProcessInner) {
     let mut counters = MaybeUninit::<IO_COUNTERS>::uninit();
 
-    if let Some(handle) = p.get_handle() {
-        unsafe {
-            if GetProcessIoCounters(handle, counters.as_mut_ptr()).is_err() {
-                sysinfo_debug!("GetProcessIoCounters call failed on process {}", p.pid());
-            } else {
-                let counters = counters.assume_init();
-                p.old_read_bytes = p.read_bytes;
-                p.old_written_bytes = p.written_bytes;
-                p.read_bytes = counters.ReadTransferCount;
-                p.written_bytes = counters.WriteTransferCount;
-            }
+    let Some(handle) = p.get_handle() else {
+        p.field_statuses
+            .set(ProcessField::DiskUsage, p.handle_status);
+        return;
+    };
+    unsafe {
+        if let Err(error) = GetProcessIoCounters(handle, counters.as_mut_ptr()) {
+            sysinfo_debug!("GetProcessIoCounters call failed on process {}", p.pid());
+            p.field_statuses
+                .set(ProcessField::DiskUsage, field_status_from_error(&error));
+        } else {
+            let counters = counters.assume_init();
+            p.old_read_bytes = p.read_bytes;
+            p.old_written_bytes = p.written_bytes;
+            p.read_bytes = counters.ReadTransferCount;
+            p.written_bytes = counters.WriteTransferCount;
+            p.field_statuses
+                .set(ProcessField::DiskUsage, FieldStatus::Ok);
         }
     }
 }
@@ -0,0 +1,266 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::{Pid, ProcessEvent, ProcessEventKind, WatchProcessesError};
+
+use std::collections::HashMap;
+use std::ffi::{c_void, OsString};
+use std::mem::size_of;
+use std::os::windows::ffi::OsStringExt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::thread::JoinHandle;
+
+use windows::core::{w, GUID, PCWSTR, PWSTR};
+use windows::Win32::Foundation::{ERROR_ACCESS_DENIED, ERROR_SUCCESS, WIN32_ERROR};
+use windows::Win32::System::Diagnostics::Etw::{
+    CloseTrace, ControlTraceW, EnableTraceEx2, OpenTraceW, ProcessTrace, StartTraceW,
+    TdhGetProperty, TdhGetPropertySize, CONTROLTRACE_HANDLE, EVENT_CONTROL_CODE_ENABLE_PROVIDER,
+    EVENT_RECORD, EVENT_TRACE_CONTROL_STOP, EVENT_TRACE_LOGFILEW, EVENT_TRACE_PROPERTIES,
+    EVENT_TRACE_REAL_TIME_MODE, PROCESSTRACE_HANDLE, PROCESS_TRACE_MODE_EVENT_RECORD,
+    PROCESS_TRACE_MODE_REAL_TIME, PROPERTY_DATA_DESCRIPTOR, TRACE_LEVEL_INFORMATION,
+    WNODE_FLAG_TRACED_GUID,
+};
+
+// `Microsoft-Windows-Kernel-Process` provider.
+const KERNEL_PROCESS_PROVIDER: GUID = GUID::from_u128(0x22fb2cd6_0e7b_422b_a0c7_2fad1fd0e716);
+const WINEVENT_KEYWORD_PROCESS: u64 = 0x10;
+const PROCESS_START_EVENT_ID: u16 = 1;
+const PROCESS_STOP_EVENT_ID: u16 = 2;
+const INVALID_PROCESSTRACE_HANDLE: u64 = u64::MAX;
+
+struct CallbackContext {
+    sender: Sender<ProcessEvent>,
+    // The stop events don't contain the parent PID, so we keep the ones from the start events.
+    parents: HashMap<Pid, Pid>,
+}
+
+// Real-time ETW session receiving the `Microsoft-Windows-Kernel-Process` events.
+//
+// Please note that ETW sessions outlive the process which created them, so if it is killed
+// before the watcher is dropped, the session keeps running until it is stopped with
+// `logman stop <session name> -ets`.
+pub(crate) struct ProcessWatcherInner {
+    // NUL-terminated session name.
+    name: Vec<u16>,
+    session: CONTROLTRACE_HANDLE,
+    trace: Option<PROCESSTRACE_HANDLE>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl ProcessWatcherInner {
+    pub(crate) fn new(sender: Sender<ProcessEvent>) -> Result<Self, WatchProcessesError> {
+        static NB_SESSIONS: AtomicUsize = AtomicUsize::new(0);
+
+        let name = format!(
+            "sysinfo-process-watcher-{}-{}",
+            std::process::id(),
+            NB_SESSIONS.fetch_add(1, Ordering::Relaxed),
+        );
+        let name: Vec<u16> = name.encode_utf16().chain(Some(0)).collect();
+        let mut session = CONTROLTRACE_HANDLE::default();
+        unsafe {
+            let mut properties = new_properties(&name);
+            let ret = StartTraceW(
+                &mut session,
+                PCWSTR(name.as_ptr()),
+                properties.as_mut_ptr() as *mut EVENT_TRACE_PROPERTIES,
+            );
+            if ret != ERROR_SUCCESS {
+                sysinfo_debug!("StartTraceW failed: {ret:?}");
+                return Err(start_error(ret));
+            }
+        }
+        // From now on, the session is stopped when `watcher` is dropped.
+        let mut watcher = Self {
+            name,
+            session,
+            trace: None,
+            thread: None,
+        };
+
+        unsafe {
+            let ret = EnableTraceEx2(
+                session,
+                &KERNEL_PROCESS_PROVIDER,
+                EVENT_CONTROL_CODE_ENABLE_PROVIDER.0,
+                TRACE_LEVEL_INFORMATION as _,
+                WINEVENT_KEYWORD_PROCESS,
+                0,
+                0,
+                None,
+            );
+            if ret != ERROR_SUCCESS {
+                sysinfo_debug!("EnableTraceEx2 failed: {ret:?}");
+                return Err(start_error(ret));
+            }
+
+            let context = Box::into_raw(Box::new(CallbackContext {
+                sender,
+                parents: HashMap::new(),
+            }));
+            let mut logfile = EVENT_TRACE_LOGFILEW {
+                LoggerName: PWSTR(watcher.name.as_mut_ptr()),
+                Context: context as *mut c_void,
+                ..Default::default()
+            };
+            logfile.Anonymous1.ProcessTraceMode =
+                PROCESS_TRACE_MODE_REAL_TIME | PROCESS_TRACE_MODE_EVENT_RECORD;
+            logfile.Anonymous2.EventRecordCallback = Some(event_record_callback);
+            let trace = OpenTraceW(&mut logfile);
+            if trace.Value == INVALID_PROCESSTRACE_HANDLE {
+                sysinfo_debug!("OpenTraceW failed");
+                drop(Box::from_raw(context));
+                return Err(WatchProcessesError::FailedToStart);
+            }
+            watcher.trace = Some(trace);
+
+            // Raw pointers aren't `Send`.
+            let context = context as usize;
+            watcher.thread = Some(std::thread::spawn(move || {
+                // It only returns once the session is stopped or the trace is closed.
+                let _ret = ProcessTrace(&[trace], None, None);
+                if _ret != ERROR_SUCCESS {
+                    sysinfo_debug!("ProcessTrace failed: {_ret:?}");
+                }
+                drop(Box::from_raw(context as *mut CallbackContext));
+            }));
+        }
+        Ok(watcher)
+    }
+}
+
+impl Drop for ProcessWatcherInner {
+    fn drop(&mut self) {
+        unsafe {
+            let mut properties = new_properties(&self.name);
+            let _ = ControlTraceW(
+                self.session,
+                PCWSTR::null(),
+                properties.as_mut_ptr() as *mut EVENT_TRACE_PROPERTIES,
+                EVENT_TRACE_CONTROL_STOP,
+            );
+            if let Some(trace) = self.trace {
+                let _ = CloseTrace(trace);
+            }
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn start_error(error: WIN32_ERROR) -> WatchProcessesError {
+    if error == ERROR_ACCESS_DENIED {
+        WatchProcessesError::PermissionDenied
+    } else {
+        WatchProcessesError::FailedToStart
+    }
+}
+
+/// Returns a buffer containing an `EVENT_TRACE_PROPERTIES` followed by room for the session
+/// name, as expected by `StartTraceW` and `ControlTraceW`.
+fn new_properties(name: &[u16]) -> Vec<u64> {
+    let size = size_of::<EVENT_TRACE_PROPERTIES>() + std::mem::size_of_val(name);
+    // `u64` to ensure the buffer is correctly aligned for `EVENT_TRACE_PROPERTIES`.
+    let mut buffer = vec![0u64; size.div_ceil(size_of::<u64>())];
+    let properties = buffer.as_mut_ptr() as *mut EVENT_TRACE_PROPERTIES;
+    unsafe {
+        (*properties).Wnode.BufferSize = (buffer.len() * size_of::<u64>()) as _;
+        (*properties).Wnode.Flags = WNODE_FLAG_TRACED_GUID;
+        // Timestamps use `QueryPerformanceCounter`.
+        (*properties).Wnode.ClientContext = 1;
+        (*properties).LogFileMode = EVENT_TRACE_REAL_TIME_MODE;
+        (*properties).LoggerNameOffset = size_of::<EVENT_TRACE_PROPERTIES>() as _;
+    }
+    buffer
+}
+
+unsafe fn get_property(record: &EVENT_RECORD, name: PCWSTR) -> Option<Vec<u8>> {
+    let descriptor = [PROPERTY_DATA_DESCRIPTOR {
+        PropertyName: name.as_ptr() as usize as u64,
+        ArrayIndex: u32::MAX,
+        Reserved: 0,
+    }];
+    let mut size = 0;
+    if TdhGetPropertySize(record, None, &descriptor, &mut size) != ERROR_SUCCESS.0 {
+        return None;
+    }
+    let mut buffer = vec![0; size as usize];
+    if TdhGetProperty(record, None, &descriptor, &mut buffer) != ERROR_SUCCESS.0 {
+        return None;
+    }
+    Some(buffer)
+}
+
+unsafe fn get_pid_property(record: &EVENT_RECORD, name: PCWSTR) -> Option<Pid> {
+    let buffer = get_property(record, name)?;
+    let pid = u32::from_ne_bytes(buffer.get(..4)?.try_into().ok()?);
+    Some(Pid::from_u32(pid))
+}
+
+/// Only keeps the file name of `path`.
+fn file_name(path: &[u16]) -> &[u16] {
+    let start = path
+        .iter()
+        .rposition(|&c| c == u16::from(b'\\'))
+        .map_or(0, |pos| pos + 1);
+    &path[start..]
+}
+
+// The start events contain the image path as an UTF-16 string.
+fn image_name_from_utf16(buffer: &[u8]) -> OsString {
+    let path: Vec<u16> = buffer
+        .chunks_exact(2)
+        .map(|c| u16::from_ne_bytes([c[0], c[1]]))
+        .take_while(|&c| c != 0)
+        .collect();
+    OsString::from_wide(file_name(&path))
+}
+
+// The stop events contain the image name as an ANSI string.
+fn image_name_from_ansi(buffer: &[u8]) -> OsString {
+    let end = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    let path: Vec<u16> = buffer[..end].iter().map(|&c| u16::from(c)).collect();
+    OsString::from_wide(file_name(&path))
+}
+
+unsafe extern "system" fn event_record_callback(record: *mut EVENT_RECORD) {
+    let Some(record) = record.as_ref() else {
+        return;
+    };
+    let Some(context) = (record.UserContext as *mut CallbackContext).as_mut() else {
+        return;
+    };
+    let kind = match record.EventHeader.EventDescriptor.Id {
+        PROCESS_START_EVENT_ID => ProcessEventKind::Started,
+        PROCESS_STOP_EVENT_ID => ProcessEventKind::Stopped,
+        _ => return,
+    };
+    let Some(pid) = get_pid_property(record, w!("ProcessID")) else {
+        return;
+    };
+    let image_name = get_property(record, w!("ImageName"));
+    let (parent_pid, name) = match kind {
+        ProcessEventKind::Started => {
+            let parent_pid = get_pid_property(record, w!("ParentProcessID"));
+            if let Some(parent_pid) = parent_pid {
+                context.parents.insert(pid, parent_pid);
+            }
+            (
+                parent_pid,
+                image_name.map(|name| image_name_from_utf16(&name)),
+            )
+        }
+        ProcessEventKind::Stopped => (
+            context.parents.remove(&pid),
+            image_name.map(|name| image_name_from_ansi(&name)),
+        ),
+    };
+    // The receiver might have been dropped already, nothing to be done in this case.
+    let _ = context.sender.send(ProcessEvent {
+        kind,
+        pid,
+        parent_pid,
+        name: name.unwrap_or_default(),
+    });
+}
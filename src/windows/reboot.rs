@@ -0,0 +1,104 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::system::{reg_key_exists, reg_value_exists};
+
+use windows::Win32::System::Registry::HKEY_LOCAL_MACHINE;
+
+/// A registry entry which exists as long as a reboot is pending.
+enum PendingRebootLocation {
+    Key(&'static str),
+    Value(&'static str, &'static str),
+}
+
+/// The locations checked to know if a reboot is pending, all of them in `HKEY_LOCAL_MACHINE`.
+const PENDING_REBOOT_LOCATIONS: &[PendingRebootLocation] = &[
+    // Set by the servicing stack when installing or removing components and updates.
+    PendingRebootLocation::Key(
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\Component Based Servicing\RebootPending",
+    ),
+    // Set by Windows Update once updates were installed.
+    PendingRebootLocation::Key(
+        r"SOFTWARE\Microsoft\Windows\CurrentVersion\WindowsUpdate\Auto Update\RebootRequired",
+    ),
+    // The files which are in use are replaced or deleted at the next boot.
+    PendingRebootLocation::Value(
+        r"SYSTEM\CurrentControlSet\Control\Session Manager",
+        "PendingFileRenameOperations",
+    ),
+];
+
+/// Gives access to the registry, so the detection can be tested without a real registry.
+trait Registry {
+    fn key_exists(&self, path: &str) -> bool;
+    fn value_exists(&self, path: &str, name: &str) -> bool;
+}
+
+struct LocalMachine;
+
+impl Registry for LocalMachine {
+    fn key_exists(&self, path: &str) -> bool {
+        reg_key_exists(HKEY_LOCAL_MACHINE, path)
+    }
+
+    fn value_exists(&self, path: &str, name: &str) -> bool {
+        reg_value_exists(HKEY_LOCAL_MACHINE, path, name)
+    }
+}
+
+fn is_reboot_pending(registry: &impl Registry) -> bool {
+    PENDING_REBOOT_LOCATIONS
+        .iter()
+        .any(|location| match location {
+            PendingRebootLocation::Key(path) => registry.key_exists(path),
+            PendingRebootLocation::Value(path, name) => registry.value_exists(path, name),
+        })
+}
+
+pub(crate) fn reboot_required() -> bool {
+    is_reboot_pending(&LocalMachine)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_reboot_pending, PendingRebootLocation, Registry, PENDING_REBOOT_LOCATIONS};
+
+    #[derive(Default)]
+    struct MockRegistry {
+        keys: Vec<&'static str>,
+        values: Vec<(&'static str, &'static str)>,
+    }
+
+    impl Registry for MockRegistry {
+        fn key_exists(&self, path: &str) -> bool {
+            self.keys.contains(&path)
+        }
+
+        fn value_exists(&self, path: &str, name: &str) -> bool {
+            self.values.contains(&(path, name))
+        }
+    }
+
+    #[test]
+    fn check_no_pending_reboot() {
+        let registry = MockRegistry {
+            keys: vec![r"SOFTWARE\Microsoft\Windows\CurrentVersion\WindowsUpdate\Auto Update"],
+            values: vec![(
+                r"SYSTEM\CurrentControlSet\Control\Session Manager",
+                "BootExecute",
+            )],
+        };
+        assert!(!is_reboot_pending(&registry));
+    }
+
+    #[test]
+    fn check_each_pending_reboot_location() {
+        for location in PENDING_REBOOT_LOCATIONS {
+            let mut registry = MockRegistry::default();
+            match location {
+                PendingRebootLocation::Key(path) => registry.keys.push(path),
+                PendingRebootLocation::Value(path, name) => registry.values.push((path, name)),
+            }
+            assert!(is_reboot_pending(&registry));
+        }
+    }
+}
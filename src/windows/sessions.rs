@@ -0,0 +1,144 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+use crate::sys::utils::to_utf8_str;
+use crate::sys::Sid;
+use crate::{Session, SessionKind, Uid};
+
+use std::ffi::c_void;
+use std::mem::size_of;
+
+use windows::core::PWSTR;
+use windows::Win32::System::RemoteDesktop::{
+    WTSActive, WTSClientAddress, WTSClientName, WTSClientProtocolType, WTSDisconnected,
+    WTSEnumerateSessionsW, WTSFreeMemory, WTSQuerySessionInformationW, WTSSessionInfo, WTSINFOW,
+    WTS_CLIENT_ADDRESS, WTS_INFO_CLASS, WTS_SESSION_INFOW,
+};
+
+// `WTS_CLIENT_ADDRESS::AddressFamily` value of IPv4 addresses.
+const AF_INET: u32 = 2;
+// `WTSClientProtocolType` value of the console session.
+const CONSOLE_PROTOCOL: u16 = 0;
+
+/// Memory allocated by the Remote Desktop Services API.
+struct WtsMemory<T>(*mut T);
+
+impl<T> Drop for WtsMemory<T> {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { WTSFreeMemory(self.0 as *mut c_void) };
+        }
+    }
+}
+
+unsafe fn query_session_information(
+    session_id: u32,
+    class: WTS_INFO_CLASS,
+) -> Option<(WtsMemory<u16>, usize)> {
+    let mut buffer = PWSTR::null();
+    let mut size = 0;
+    if let Err(_err) = WTSQuerySessionInformationW(None, session_id, class, &mut buffer, &mut size)
+    {
+        sysinfo_debug!("WTSQuerySessionInformationW failed: {:?}", _err);
+        return None;
+    }
+    let buffer = WtsMemory(buffer.0);
+    (!buffer.0.is_null()).then_some((buffer, size as usize))
+}
+
+unsafe fn query_session_value<T: Copy>(session_id: u32, class: WTS_INFO_CLASS) -> Option<T> {
+    let (buffer, size) = query_session_information(session_id, class)?;
+    (size >= size_of::<T>()).then(|| std::ptr::read_unaligned(buffer.0 as *const T))
+}
+
+unsafe fn query_session_string(session_id: u32, class: WTS_INFO_CLASS) -> Option<String> {
+    let (buffer, _) = query_session_information(session_id, class)?;
+    Some(to_utf8_str(PWSTR::from_raw(buffer.0))).filter(|s| !s.is_empty())
+}
+
+fn utf16_field_to_string(field: &[u16]) -> String {
+    let len = field.iter().position(|&c| c == 0).unwrap_or(field.len());
+    String::from_utf16_lossy(&field[..len])
+}
+
+unsafe fn get_remote_host(session_id: u32) -> Option<String> {
+    if let Some(address) = query_session_value::<WTS_CLIENT_ADDRESS>(session_id, WTSClientAddress) {
+        // The IPv4 address starts after the port.
+        if address.AddressFamily == AF_INET && address.Address[2..6] != [0; 4] {
+            let [a, b, c, d] = [
+                address.Address[2],
+                address.Address[3],
+                address.Address[4],
+                address.Address[5],
+            ];
+            return Some(format!("{a}.{b}.{c}.{d}"));
+        }
+    }
+    query_session_string(session_id, WTSClientName)
+}
+
+unsafe fn get_session(session_id: u32) -> Option<Session> {
+    let info = query_session_value::<WTSINFOW>(session_id, WTSSessionInfo)?;
+    let user_name = utf16_field_to_string(&info.UserName);
+    if user_name.is_empty() {
+        return None;
+    }
+    let domain = utf16_field_to_string(&info.Domain);
+    let account_name = if domain.is_empty() {
+        user_name.clone()
+    } else {
+        format!("{domain}\\{user_name}")
+    };
+    let terminal = Some(utf16_field_to_string(&info.WinStationName)).filter(|s| !s.is_empty());
+    // 11_644_473_600 is the number of seconds between the Windows epoch (1601-01-01) and
+    // the Linux epoch (1970-01-01).
+    let login_time = match info.LogonTime {
+        time if time > 0 => (time as u64 / 10_000_000).saturating_sub(11_644_473_600),
+        _ => 0,
+    };
+    // The services run in the session 0 since Windows Vista.
+    let protocol = match info.SessionId {
+        0 => None,
+        _ => query_session_value::<u16>(session_id, WTSClientProtocolType),
+    };
+    let (kind, remote_host) = match protocol {
+        _ if info.SessionId == 0 => (SessionKind::Service, None),
+        Some(CONSOLE_PROTOCOL) => (SessionKind::Local, None),
+        Some(_) => (SessionKind::Remote, get_remote_host(session_id)),
+        None => (SessionKind::Unknown, None),
+    };
+    Some(Session {
+        user_name,
+        user_id: Sid::from_account_name(&account_name).map(Uid),
+        session_id: Some(info.SessionId),
+        login_time,
+        kind,
+        terminal,
+        remote_host,
+    })
+}
+
+pub(crate) fn get_sessions(sessions: &mut Vec<Session>) {
+    sessions.clear();
+
+    unsafe {
+        let mut list: *mut WTS_SESSION_INFOW = std::ptr::null_mut();
+        let mut count = 0;
+        if let Err(_err) = WTSEnumerateSessionsW(None, 0, 1, &mut list, &mut count) {
+            sysinfo_debug!("WTSEnumerateSessionsW failed: {:?}", _err);
+            return;
+        }
+        let list = WtsMemory(list);
+        if list.0.is_null() {
+            return;
+        }
+        for entry in std::slice::from_raw_parts(list.0, count as usize) {
+            // The other states are the listening sessions and the ones without a user.
+            if entry.State != WTSActive && entry.State != WTSDisconnected {
+                continue;
+            }
+            if let Some(session) = get_session(entry.SessionId) {
+                sessions.push(session);
+            }
+        }
+    }
+}
@@ -9,7 +9,7 @@ use windows::Win32::Foundation::{LocalFree, HLOCAL};
 use windows::Win32::Security::Authorization::{ConvertSidToStringSidW, ConvertStringSidToSidW};
 use windows::Win32::Security::{CopySid, GetLengthSid, IsValidSid, PSID};
 #[cfg(feature = "user")]
-use windows::Win32::Security::{LookupAccountSidW, SidTypeUnknown};
+use windows::Win32::Security::{LookupAccountNameW, LookupAccountSidW, SidTypeUnknown};
 
 use crate::sys::utils::to_utf8_str;
 
@@ -57,11 +57,69 @@ impl Sid {
         Some(Self { sid })
     }
 
-    /// Retrieves the account name of this SID.
+    /// Returns the last sub-authority of the SID, which is the relative identifier of the
+    /// accounts SIDs.
+    #[cfg(any(feature = "system", feature = "user"))]
+    pub(crate) fn rid(&self) -> Option<u32> {
+        // The sub-authorities follow the revision, the number of sub-authorities and the 6 bytes
+        // of the identifier authority.
+        let count = *self.sid.get(1)? as usize;
+        let start = 8 + count.checked_sub(1)? * 4;
+        let rid = self.sid.get(start..start + 4)?;
+        Some(u32::from_le_bytes(rid.try_into().ok()?))
+    }
+
+    /// Retrieves the SID of the given account name, which can be prefixed with its domain
+    /// (like `DOMAIN\\user`).
     #[cfg(feature = "user")]
-    pub(crate) fn account_name(&self) -> Option<String> {
-        let (name, _domain) = self.account_name_and_domain()?;
-        Some(name)
+    pub(crate) fn from_account_name(account_name: &str) -> Option<Self> {
+        let account_name = account_name
+            .encode_utf16()
+            .chain(Some(0))
+            .collect::<Vec<_>>();
+        unsafe {
+            let mut sid_len = 0;
+            let mut domain_len = 0;
+            let mut name_use = SidTypeUnknown;
+
+            if let Err(err) = LookupAccountNameW(
+                PCWSTR::null(),
+                PCWSTR::from_raw(account_name.as_ptr()),
+                None,
+                &mut sid_len,
+                None,
+                &mut domain_len,
+                &mut name_use,
+            ) {
+                if err.code() != ERROR_INSUFFICIENT_BUFFER.to_hresult() {
+                    sysinfo_debug!("LookupAccountNameW failed: {:?}", err);
+                    return None;
+                }
+            }
+
+            let mut sid = vec![0u8; sid_len as usize];
+            let mut domain = vec![0u16; domain_len as usize];
+
+            if LookupAccountNameW(
+                PCWSTR::null(),
+                PCWSTR::from_raw(account_name.as_ptr()),
+                Some(PSID(sid.as_mut_ptr().cast())),
+                &mut sid_len,
+                Some(PWSTR::from_raw(domain.as_mut_ptr())),
+                &mut domain_len,
+                &mut name_use,
+            )
+            .is_err()
+            {
+                sysinfo_debug!(
+                    "LookupAccountNameW failed: {:?}",
+                    std::io::Error::last_os_error()
+                );
+                return None;
+            }
+
+            Self::from_psid(PSID(sid.as_mut_ptr().cast()))
+        }
     }
 
     /// Retrieves both the account name and domain of this SID.
@@ -110,7 +168,8 @@ impl Sid {
             }
 
             let username = to_utf8_str(PWSTR::from_raw(name.as_mut_ptr()));
-            let domain_name = if domain_len > 1 {
+            // On success, `domain_len` doesn't include the NUL terminator anymore.
+            let domain_name = if domain_len > 0 {
                 let domain_str = to_utf8_str(PWSTR::from_raw(domain.as_mut_ptr()));
                 if domain_str.is_empty() {
                     None
@@ -1,10 +1,17 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
 use crate::{
-    Cpu, CpuRefreshKind, LoadAvg, MemoryRefreshKind, Pid, ProcessRefreshKind, ProcessesToUpdate,
+    Cpu, CpuRefreshKind, DomainKind, HardwareInfo, LoadAvg, MemoryRefreshKind, Pid, Pressure,
+    ProcessRefreshKind, ProcessesToUpdate, RefreshError, RefreshSubsystem, TpmVersion,
+    Virtualization,
 };
 
+use crate::common::rapl::RaplPackageCounters;
+use crate::common::system::derive_boot_id;
+use crate::common::virtualization::{cpuid_hypervisor, hypervisor_from_dmi};
 use crate::sys::cpu::*;
+use crate::sys::time_zones::windows_to_iana;
+use crate::sys::utils::to_utf8_str;
 use crate::{Process, ProcessInner};
 
 use std::collections::HashMap;
@@ -13,8 +20,17 @@ use std::mem::{size_of, zeroed};
 use std::os::windows::ffi::OsStrExt;
 use std::time::{Duration, SystemTime};
 
+use ntapi::ntexapi::SYSTEM_TIMEOFDAY_INFORMATION;
 use windows::core::{Owned, PCWSTR, PWSTR};
+use windows::Wdk::System::SystemInformation::{
+    NtQuerySystemInformation, SystemTimeOfDayInformation,
+};
 use windows::Win32::Foundation::{self, HANDLE, STILL_ACTIVE};
+use windows::Win32::Globalization::GetUserDefaultLocaleName;
+use windows::Win32::NetworkManagement::NetManagement::{
+    NERR_Success, NetApiBufferFree, NetGetJoinInformation, NetSetupDomainName,
+    NetSetupWorkgroupName, NETSETUP_JOIN_STATUS,
+};
 use windows::Win32::System::Diagnostics::ToolHelp::{
     CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W, TH32CS_SNAPPROCESS,
 };
@@ -22,12 +38,24 @@ use windows::Win32::System::ProcessStatus::{K32GetPerformanceInfo, PERFORMANCE_I
 use windows::Win32::System::Registry::{
     RegCloseKey, RegOpenKeyExW, RegQueryValueExW, HKEY, HKEY_LOCAL_MACHINE, KEY_READ, REG_NONE,
 };
-use windows::Win32::System::SystemInformation::{self, GetSystemInfo};
+use windows::Win32::System::SystemInformation::{self, GetNativeSystemInfo};
 use windows::Win32::System::SystemInformation::{
-    ComputerNamePhysicalDnsHostname, GetComputerNameExW, GetTickCount64, GlobalMemoryStatusEx,
-    MEMORYSTATUSEX, SYSTEM_INFO,
+    ComputerNameDnsFullyQualified, ComputerNamePhysicalDnsHostname, FirmwareTypeUefi,
+    GetComputerNameExW, GetFirmwareType, GetSystemFirmwareTable, GetSystemTimeAdjustment,
+    GetTickCount64, GlobalMemoryStatusEx, COMPUTER_NAME_FORMAT, FIRMWARE_TYPE, IMAGE_FILE_MACHINE,
+    MEMORYSTATUSEX, PROCESSOR_ARCHITECTURE, RSMB, SYSTEM_INFO,
+};
+use windows::Win32::System::Threading::{
+    GetCurrentProcess, GetExitCodeProcess, IsWow64Process2, OpenProcess,
+    PROCESS_QUERY_LIMITED_INFORMATION,
+};
+use windows::Win32::System::Time::{
+    GetDynamicTimeZoneInformation, DYNAMIC_TIME_ZONE_INFORMATION, TIME_ZONE_ID_INVALID,
 };
-use windows::Win32::System::Threading::GetExitCodeProcess;
+use windows::Win32::System::TpmBaseServices::{
+    Tbsi_GetDeviceInfo, TPM_DEVICE_INFO, TPM_VERSION_12, TPM_VERSION_20,
+};
+use windows::Win32::System::WindowsProgramming::QueryUnbiasedInterruptTime;
 
 declare_signals! {
     (),
@@ -44,11 +72,23 @@ const WINDOWS_ELEVEN_BUILD_NUMBER: u32 = 22000;
 
 impl SystemInner {
     fn is_windows_eleven() -> bool {
-        WINDOWS_ELEVEN_BUILD_NUMBER
-            <= Self::kernel_version()
-                .unwrap_or_default()
-                .parse()
-                .unwrap_or(0)
+        is_windows_eleven_build(&Self::kernel_version().unwrap_or_default())
+    }
+}
+
+/// Windows 11 kept the major version and the product name of Windows 10, so only the build
+/// number tells them apart.
+fn is_windows_eleven_build(build_number: &str) -> bool {
+    build_number
+        .parse::<u32>()
+        .is_ok_and(|build_number| build_number >= WINDOWS_ELEVEN_BUILD_NUMBER)
+}
+
+/// Formats the build like `winver` does, for example "22631.4123".
+fn format_os_build(build_number: &str, ubr: Option<u32>) -> String {
+    match ubr {
+        Some(ubr) => format!("{build_number}.{ubr}"),
+        None => build_number.to_owned(),
     }
 }
 
@@ -161,19 +201,30 @@ impl SystemInner {
     }
 
     pub(crate) fn refresh_memory_specifics(&mut self, refresh_kind: MemoryRefreshKind) {
+        let _ = self.try_refresh_memory_specifics(refresh_kind);
+    }
+
+    pub(crate) fn try_refresh_memory_specifics(
+        &mut self,
+        refresh_kind: MemoryRefreshKind,
+    ) -> Result<(), RefreshError> {
+        let mut result = Ok(());
         unsafe {
             if refresh_kind.ram() {
                 let mut mem_info: MEMORYSTATUSEX = zeroed();
                 mem_info.dwLength = size_of::<MEMORYSTATUSEX>() as _;
-                let _err = GlobalMemoryStatusEx(&mut mem_info);
+                if let Err(error) = GlobalMemoryStatusEx(&mut mem_info) {
+                    result = Err(RefreshError::new(
+                        RefreshSubsystem::Memory,
+                        "GlobalMemoryStatusEx",
+                        error.into(),
+                    ));
+                }
                 self.mem_total = mem_info.ullTotalPhys as _;
                 self.mem_available = mem_info.ullAvailPhys as _;
             }
             if refresh_kind.swap() {
-                let mut perf_info: PERFORMANCE_INFORMATION = zeroed();
-                if K32GetPerformanceInfo(&mut perf_info, size_of::<PERFORMANCE_INFORMATION>() as _)
-                    .as_bool()
-                {
+                if let Some(perf_info) = get_performance_info() {
                     let page_size = perf_info.PageSize as u64;
                     let physical_total = perf_info.PhysicalTotal as u64;
                     let commit_limit = perf_info.CommitLimit as u64;
@@ -185,12 +236,19 @@ impl SystemInner {
                 }
             }
         }
+        result
     }
 
     pub(crate) fn cgroup_limits(&self) -> Option<crate::CGroupLimits> {
         None
     }
 
+    pub(crate) fn cgroup_cpu_quota(&self) -> Option<f64> {
+        None
+    }
+
+    pub(crate) fn set_refresh_parallelism(&mut self, _threads: usize) {}
+
     #[allow(clippy::cast_ptr_alignment)]
     pub(crate) fn refresh_processes_specifics(
         &mut self,
@@ -271,7 +329,11 @@ impl SystemInner {
 
             if filter_callback(proc_id, filter_array) {
                 // exists already
-                if let Some(p) = process_list.get_mut(&proc_id) {
+                if let Some(p) = process_list
+                    .get_mut(&proc_id)
+                    // If the PID was reused, we replace the old entry with the new process.
+                    .filter(|p| p.inner.is_same_process())
+                {
                     // Update with the most recent information
                     let p = &mut p.inner;
                     p.update(refresh_kind, nb_cpus, now, false);
@@ -284,6 +346,7 @@ impl SystemInner {
                     };
 
                     p.parent = parent;
+                    p.thread_count = Some(process_entry.cntThreads as _);
                 } else {
                     // Make a new 'ProcessInner' using the Windows PROCESSENTRY32W struct.
                     let mut p = ProcessInner::from_process_entry(&process_entry, now);
@@ -323,6 +386,10 @@ impl SystemInner {
         self.cpus.cpus()
     }
 
+    pub(crate) fn cpus_mut(&mut self) -> &mut [Cpu] {
+        self.cpus.cpus_mut()
+    }
+
     pub(crate) fn total_memory(&self) -> u64 {
         self.mem_total
     }
@@ -353,17 +420,63 @@ impl SystemInner {
     }
 
     pub(crate) fn uptime() -> u64 {
+        // It keeps counting while the system is asleep or hibernated.
         unsafe { GetTickCount64() / 1_000 }
     }
 
+    pub(crate) fn active_time() -> u64 {
+        // In units of 100 nanoseconds.
+        let mut time = 0;
+        if unsafe { QueryUnbiasedInterruptTime(&mut time) }.as_bool() {
+            time / 10_000_000
+        } else {
+            sysinfo_debug!("QueryUnbiasedInterruptTime failed");
+            0
+        }
+    }
+
+    pub(crate) fn clock_ticks_per_second() -> u64 {
+        let (mut adjustment, mut increment, mut disabled) = (0, 0, Default::default());
+        // The increment is the clock interrupt period, in units of 100 nanoseconds.
+        if unsafe { GetSystemTimeAdjustment(&mut adjustment, &mut increment, &mut disabled) }
+            .is_ok()
+            && increment != 0
+        {
+            10_000_000 / increment as u64
+        } else {
+            sysinfo_debug!("GetSystemTimeAdjustment failed");
+            64
+        }
+    }
+
     pub(crate) fn boot_time() -> u64 {
         unsafe { boot_time() }
     }
 
+    pub(crate) fn reboot_required() -> Option<bool> {
+        Some(crate::sys::reboot::reboot_required())
+    }
+
     pub(crate) fn load_average() -> LoadAvg {
         get_load_average()
     }
 
+    pub(crate) fn memory_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn cpu_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn io_pressure() -> Option<Pressure> {
+        None
+    }
+
+    pub(crate) fn rapl_counters() -> Option<Vec<RaplPackageCounters>> {
+        None
+    }
+
     pub(crate) fn name() -> Option<String> {
         Some("Windows".to_owned())
     }
@@ -385,7 +498,20 @@ impl SystemInner {
     }
 
     pub(crate) fn host_name() -> Option<String> {
-        get_dns_hostname()
+        get_computer_name(ComputerNamePhysicalDnsHostname)
+    }
+
+    pub(crate) fn domain_name() -> Option<String> {
+        get_join_information().map(|(name, _)| name)
+    }
+
+    pub(crate) fn domain_kind() -> Option<DomainKind> {
+        get_join_information().map(|(_, kind)| kind)
+    }
+
+    pub(crate) fn fqdn() -> Option<String> {
+        // Without a DNS domain, it only contains the host name.
+        get_computer_name(ComputerNameDnsFullyQualified).filter(|name| name.contains('.'))
     }
 
     pub(crate) fn kernel_version() -> Option<String> {
@@ -418,6 +544,33 @@ impl SystemInner {
         Some(format!("{major} ({build_number})"))
     }
 
+    pub(crate) fn os_build() -> Option<String> {
+        let build_number = Self::kernel_version()?;
+        let ubr = get_reg_value_u32(
+            HKEY_LOCAL_MACHINE,
+            r"SOFTWARE\Microsoft\Windows NT\CurrentVersion",
+            "UBR",
+        )
+        .map(u32::from_le_bytes);
+        Some(format_os_build(&build_number, ubr))
+    }
+
+    pub(crate) fn os_display_version() -> Option<String> {
+        get_reg_string_value(
+            HKEY_LOCAL_MACHINE,
+            r"SOFTWARE\Microsoft\Windows NT\CurrentVersion",
+            "DisplayVersion",
+        )
+        // `DisplayVersion` replaced `ReleaseId` with Windows 10 20H2.
+        .or_else(|| {
+            get_reg_string_value(
+                HKEY_LOCAL_MACHINE,
+                r"SOFTWARE\Microsoft\Windows NT\CurrentVersion",
+                "ReleaseId",
+            )
+        })
+    }
+
     pub(crate) fn distribution_id() -> String {
         std::env::consts::OS.to_owned()
     }
@@ -431,30 +584,32 @@ impl SystemInner {
     }
 
     pub(crate) fn cpu_arch() -> Option<String> {
+        // Unlike `GetNativeSystemInfo`, it isn't affected by the x64 emulation on ARM64.
+        get_native_machine()
+            .and_then(machine_name)
+            .map(str::to_owned)
+            .or_else(Self::kernel_arch)
+    }
+
+    pub(crate) fn kernel_arch() -> Option<String> {
+        // https://docs.microsoft.com/fr-fr/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info
+        let mut info = SYSTEM_INFO::default();
         unsafe {
-            // https://docs.microsoft.com/fr-fr/windows/win32/api/sysinfoapi/ns-sysinfoapi-system_info
-            let mut info = SYSTEM_INFO::default();
-            GetSystemInfo(&mut info);
-            match info.Anonymous.Anonymous.wProcessorArchitecture {
-                SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA => Some("alpha".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA64 => Some("alpha64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_AMD64 => Some("x86_64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ARM => Some("arm".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ARM32_ON_WIN64 => Some("arm".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_ARM64 => Some("arm64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_ARM64
-                | SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_WIN64 => {
-                    Some("ia32".to_string())
-                }
-                SystemInformation::PROCESSOR_ARCHITECTURE_IA64 => Some("ia64".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_INTEL => Some("x86".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_MIPS => Some("mips".to_string()),
-                SystemInformation::PROCESSOR_ARCHITECTURE_PPC => Some("powerpc".to_string()),
-                _ => None,
-            }
+            GetNativeSystemInfo(&mut info);
+            processor_architecture_name(info.Anonymous.Anonymous.wProcessorArchitecture)
+                .map(str::to_owned)
         }
     }
 
+    pub(crate) fn is_translated() -> Option<bool> {
+        // A x86 process on x64 runs natively with WOW64, only ARM64 emulates them.
+        let native_machine = get_native_machine()?;
+        Some(
+            native_machine == SystemInformation::IMAGE_FILE_MACHINE_ARM64
+                && cfg!(any(target_arch = "x86", target_arch = "x86_64")),
+        )
+    }
+
     pub(crate) fn physical_core_count() -> Option<usize> {
         get_physical_core_count()
     }
@@ -473,6 +628,182 @@ impl SystemInner {
         // So 128 * (1 << 6) = 8192
         Some(8192)
     }
+
+    pub(crate) fn open_files() -> Option<(u64, u64)> {
+        // There is no limit of handles on the whole system.
+        get_performance_info().map(|info| (info.HandleCount as u64, u64::MAX))
+    }
+
+    pub(crate) fn max_pid() -> Option<u64> {
+        None
+    }
+
+    pub(crate) fn threads_total() -> Option<u64> {
+        get_performance_info().map(|info| info.ThreadCount as u64)
+    }
+
+    pub(crate) fn hardware() -> HardwareInfo {
+        let get_value = |name| {
+            get_reg_string_value(
+                HKEY_LOCAL_MACHINE,
+                "HARDWARE\\DESCRIPTION\\System\\BIOS",
+                name,
+            )
+            .map(|value| value.trim().to_owned())
+            .filter(|value| !value.is_empty())
+        };
+        HardwareInfo {
+            vendor: get_value("SystemManufacturer"),
+            product_name: get_value("SystemProductName"),
+            board_name: get_value("BaseBoardProduct"),
+            bios_version: get_value("BIOSVersion"),
+            bios_date: get_value("BIOSReleaseDate"),
+            chassis_type: get_chassis_type(),
+        }
+    }
+
+    pub(crate) fn process_exists(pid: Pid) -> bool {
+        // The process object is kept as long as a handle to it is open, so its exit code needs
+        // to be checked.
+        match unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid.0 as u32) } {
+            Ok(handle) => is_proc_running(*unsafe { Owned::new(handle) }),
+            // It fails for protected processes like the ones of the antivirus.
+            Err(err) => err.code() == Foundation::ERROR_ACCESS_DENIED.to_hresult(),
+        }
+    }
+
+    pub(crate) fn virtualization() -> Option<Virtualization> {
+        // The registry values come from the SMBIOS information.
+        let hardware = crate::System::hardware();
+        let hypervisor =
+            hypervisor_from_dmi([hardware.vendor.as_deref(), hardware.product_name.as_deref()])
+                .or_else(cpuid_hypervisor);
+        Some(hypervisor.map_or(Virtualization::BareMetal, Virtualization::VirtualMachine))
+    }
+
+    pub(crate) fn machine_id() -> Option<String> {
+        // Generated when Windows is installed.
+        get_reg_string_value(
+            HKEY_LOCAL_MACHINE,
+            "SOFTWARE\\Microsoft\\Cryptography",
+            "MachineGuid",
+        )
+        .filter(|id| !id.is_empty())
+    }
+
+    pub(crate) fn boot_id() -> Option<String> {
+        let machine_id = crate::System::machine_id()?;
+        Some(derive_boot_id(&machine_id, get_kernel_boot_time()?))
+    }
+
+    pub(crate) fn kernel_cmdline() -> Option<String> {
+        None
+    }
+
+    pub(crate) fn secure_boot() -> Option<bool> {
+        let mut firmware_type = FIRMWARE_TYPE::default();
+        if let Err(_err) = unsafe { GetFirmwareType(&mut firmware_type) } {
+            sysinfo_debug!("GetFirmwareType failed: {:?}", _err);
+            return None;
+        }
+        // The key can be left over from a previous UEFI boot, so it's only read if Windows was
+        // booted with UEFI.
+        if firmware_type != FirmwareTypeUefi {
+            return None;
+        }
+        get_reg_value_u32(
+            HKEY_LOCAL_MACHINE,
+            r"SYSTEM\CurrentControlSet\Control\SecureBoot\State",
+            "UEFISecureBootEnabled",
+        )
+        .map(|enabled| u32::from_le_bytes(enabled) != 0)
+    }
+
+    pub(crate) fn tpm_version() -> Option<TpmVersion> {
+        let mut info = TPM_DEVICE_INFO::default();
+        let result = unsafe {
+            Tbsi_GetDeviceInfo(
+                size_of::<TPM_DEVICE_INFO>() as _,
+                &mut info as *mut TPM_DEVICE_INFO as *mut _,
+            )
+        };
+        // It returns `TBS_SUCCESS` (`0`) on success, and an error if there is no TPM.
+        if result != 0 {
+            return None;
+        }
+        match info.tpmVersion {
+            TPM_VERSION_12 => Some(TpmVersion::V1),
+            TPM_VERSION_20 => Some(TpmVersion::V2),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn timezone() -> Option<String> {
+        let mut info = DYNAMIC_TIME_ZONE_INFORMATION::default();
+        if unsafe { GetDynamicTimeZoneInformation(&mut info) } == TIME_ZONE_ID_INVALID {
+            sysinfo_debug!("GetDynamicTimeZoneInformation failed");
+            return None;
+        }
+        let key_name = utf16_to_string(&info.TimeZoneKeyName);
+        windows_to_iana(&key_name).map(str::to_owned)
+    }
+
+    pub(crate) fn locale() -> Option<String> {
+        // `LOCALE_NAME_MAX_LENGTH`
+        let mut buffer = [0; 85];
+        if unsafe { GetUserDefaultLocaleName(&mut buffer) } == 0 {
+            sysinfo_debug!("GetUserDefaultLocaleName failed");
+            return None;
+        }
+        Some(utf16_to_string(&buffer)).filter(|locale| !locale.is_empty())
+    }
+
+    pub(crate) fn time_since_boot_monotonic() -> Option<Duration> {
+        // It keeps counting while the system is asleep.
+        Some(Duration::from_millis(unsafe { GetTickCount64() }))
+    }
+
+    pub(crate) fn kernel_parameter(_name: &str) -> Option<String> {
+        None
+    }
+}
+
+/// Unlike [`boot_time`], which is computed from the uptime and can be off by one second between
+/// two calls, it returns the value stored by the kernel, in 100 nanoseconds intervals.
+fn get_kernel_boot_time() -> Option<u64> {
+    unsafe {
+        let mut info = zeroed::<SYSTEM_TIMEOFDAY_INFORMATION>();
+        let mut len = 0;
+        if NtQuerySystemInformation(
+            SystemTimeOfDayInformation,
+            &mut info as *mut _ as *mut _,
+            size_of::<SYSTEM_TIMEOFDAY_INFORMATION>() as _,
+            &mut len,
+        )
+        .is_err()
+        {
+            sysinfo_debug!("NtQuerySystemInformation(SystemTimeOfDayInformation) failed");
+            return None;
+        }
+        Some(*info.BootTime.QuadPart() as u64)
+    }
+}
+
+fn get_performance_info() -> Option<PERFORMANCE_INFORMATION> {
+    unsafe {
+        let mut info: PERFORMANCE_INFORMATION = zeroed();
+        if K32GetPerformanceInfo(&mut info, size_of::<PERFORMANCE_INFORMATION>() as _).as_bool() {
+            Some(info)
+        } else {
+            sysinfo_debug!("K32GetPerformanceInfo failed");
+            None
+        }
+    }
+}
+
+fn utf16_to_string(buffer: &[u16]) -> String {
+    let len = buffer.iter().position(|&c| c == 0).unwrap_or(buffer.len());
+    String::from_utf16_lossy(&buffer[..len])
 }
 
 pub(crate) fn is_proc_running(handle: HANDLE) -> bool {
@@ -481,20 +812,51 @@ pub(crate) fn is_proc_running(handle: HANDLE) -> bool {
         && exit_code == STILL_ACTIVE.0 as u32
 }
 
-fn get_dns_hostname() -> Option<String> {
+// The chassis type isn't in the registry so it is read from the "System Enclosure" (type 3)
+// SMBIOS structure.
+fn get_chassis_type() -> Option<u8> {
+    unsafe {
+        let size = GetSystemFirmwareTable(RSMB, 0, None);
+        if size == 0 {
+            return None;
+        }
+        let mut buffer = vec![0_u8; size as usize];
+        if GetSystemFirmwareTable(RSMB, 0, Some(&mut buffer)) != size {
+            return None;
+        }
+        // Skipping the `RawSMBIOSData` header.
+        let mut table = buffer.get(8..)?;
+        // Each structure is made of a formatted area of `length` bytes followed by its strings,
+        // ended by two NUL bytes.
+        while let [kind, length, ..] = *table {
+            let length = length as usize;
+            match kind {
+                3 => return table.get(5).filter(|_| length > 5).map(|kind| kind & 0x7F),
+                127 => return None,
+                _ => {}
+            }
+            let strings = table.get(length..)?;
+            let end = strings.windows(2).position(|bytes| bytes == [0, 0])?;
+            table = &strings[end + 2..];
+        }
+        None
+    }
+}
+
+fn get_computer_name(format: COMPUTER_NAME_FORMAT) -> Option<String> {
     let mut buffer_size = 0;
     // Running this first to get the buffer size since the DNS name can be longer than MAX_COMPUTERNAME_LENGTH
     // setting the `lpBuffer` to null will return the buffer size
     // https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getcomputernameexw
     unsafe {
-        let _err = GetComputerNameExW(ComputerNamePhysicalDnsHostname, None, &mut buffer_size);
+        let _err = GetComputerNameExW(format, None, &mut buffer_size);
 
         // Setting the buffer with the new length
         let mut buffer = vec![0_u16; buffer_size as usize];
 
         // https://docs.microsoft.com/en-us/windows/win32/api/sysinfoapi/ne-sysinfoapi-computer_name_format
         if GetComputerNameExW(
-            ComputerNamePhysicalDnsHostname,
+            format,
             Some(PWSTR::from_raw(buffer.as_mut_ptr())),
             &mut buffer_size,
         )
@@ -512,6 +874,79 @@ fn get_dns_hostname() -> Option<String> {
     None
 }
 
+/// Returns the architecture of the machine, even if the current process runs with WOW64 or
+/// is emulated.
+fn get_native_machine() -> Option<IMAGE_FILE_MACHINE> {
+    let mut process_machine = IMAGE_FILE_MACHINE::default();
+    let mut native_machine = IMAGE_FILE_MACHINE::default();
+    unsafe {
+        if let Err(_err) = IsWow64Process2(
+            GetCurrentProcess(),
+            &mut process_machine,
+            Some(&mut native_machine),
+        ) {
+            sysinfo_debug!("IsWow64Process2 failed: {:?}", _err);
+            return None;
+        }
+    }
+    Some(native_machine)
+}
+
+fn machine_name(machine: IMAGE_FILE_MACHINE) -> Option<&'static str> {
+    match machine {
+        SystemInformation::IMAGE_FILE_MACHINE_AMD64 => Some("x86_64"),
+        SystemInformation::IMAGE_FILE_MACHINE_ARM64 => Some("arm64"),
+        SystemInformation::IMAGE_FILE_MACHINE_ARMNT => Some("arm"),
+        SystemInformation::IMAGE_FILE_MACHINE_I386 => Some("x86"),
+        SystemInformation::IMAGE_FILE_MACHINE_IA64 => Some("ia64"),
+        _ => None,
+    }
+}
+
+fn processor_architecture_name(architecture: PROCESSOR_ARCHITECTURE) -> Option<&'static str> {
+    match architecture {
+        SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA => Some("alpha"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ALPHA64 => Some("alpha64"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_AMD64 => Some("x86_64"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ARM => Some("arm"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ARM32_ON_WIN64 => Some("arm"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_ARM64 => Some("arm64"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_ARM64
+        | SystemInformation::PROCESSOR_ARCHITECTURE_IA32_ON_WIN64 => Some("ia32"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_IA64 => Some("ia64"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_INTEL => Some("x86"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_MIPS => Some("mips"),
+        SystemInformation::PROCESSOR_ARCHITECTURE_PPC => Some("powerpc"),
+        _ => None,
+    }
+}
+
+/// Returns the name of the domain or of the workgroup the computer is a member of.
+fn get_join_information() -> Option<(String, DomainKind)> {
+    let mut buffer = PWSTR::null();
+    let mut status = NETSETUP_JOIN_STATUS::default();
+    unsafe {
+        let ret = NetGetJoinInformation(PCWSTR::null(), &mut buffer, &mut status);
+        if ret != NERR_Success {
+            sysinfo_debug!("NetGetJoinInformation failed: {}", ret);
+            return None;
+        }
+        let name = to_utf8_str(buffer);
+        if !buffer.is_null() {
+            NetApiBufferFree(Some(buffer.0.cast()));
+        }
+        let kind = if status == NetSetupDomainName {
+            DomainKind::Domain
+        } else if status == NetSetupWorkgroupName {
+            DomainKind::Workgroup
+        } else {
+            // The computer isn't joined or its status is unknown.
+            return None;
+        };
+        (!name.is_empty()).then_some((name, kind))
+    }
+}
+
 fn add_english_counter(
     s: String,
     query: &mut super::cpu::Query,
@@ -576,6 +1011,18 @@ impl RegKey {
         )
         .ok()
     }
+
+    unsafe fn has_value(&self, field_name: &[u16]) -> bool {
+        RegQueryValueExW(
+            self.0,
+            PCWSTR::from_raw(field_name.as_ptr()),
+            None,
+            None,
+            None,
+            None,
+        )
+        .is_ok()
+    }
 }
 
 impl Drop for RegKey {
@@ -631,3 +1078,38 @@ pub(crate) fn get_reg_value_u32(hkey: HKEY, path: &str, field_name: &str) -> Opt
             .ok()
     }
 }
+
+pub(crate) fn reg_key_exists(hkey: HKEY, path: &str) -> bool {
+    unsafe { RegKey::open(hkey, &utf16_str(path)).is_some() }
+}
+
+pub(crate) fn reg_value_exists(hkey: HKEY, path: &str, field_name: &str) -> bool {
+    unsafe {
+        RegKey::open(hkey, &utf16_str(path))
+            .is_some_and(|key| key.has_value(&utf16_str(field_name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_os_build, is_windows_eleven_build};
+
+    #[test]
+    fn check_is_windows_eleven_build() {
+        // Windows 10 22H2.
+        assert!(!is_windows_eleven_build("19045"));
+        // Windows Server 2022.
+        assert!(!is_windows_eleven_build("20348"));
+        // Windows 11 21H2 and 23H2.
+        assert!(is_windows_eleven_build("22000"));
+        assert!(is_windows_eleven_build("22631"));
+        assert!(!is_windows_eleven_build(""));
+        assert!(!is_windows_eleven_build("unknown"));
+    }
+
+    #[test]
+    fn check_format_os_build() {
+        assert_eq!(format_os_build("22631", Some(4123)), "22631.4123");
+        assert_eq!(format_os_build("19045", None), "19045");
+    }
+}
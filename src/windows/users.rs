@@ -3,18 +3,24 @@
 use crate::sys::utils::to_utf8_str;
 use crate::{windows::sid::Sid, Gid, Group, GroupInner, Uid, User};
 
+use std::ffi::OsString;
+use std::os::windows::ffi::OsStringExt;
+use std::path::PathBuf;
 use std::ptr::null_mut;
-use windows::core::PCWSTR;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use windows::core::{w, PCWSTR, PWSTR};
 use windows::Win32::Foundation::{ERROR_MORE_DATA, LUID};
 use windows::Win32::NetworkManagement::NetManagement::{
     NERR_Success, NetApiBufferFree, NetUserEnum, NetUserGetInfo, NetUserGetLocalGroups,
     FILTER_NORMAL_ACCOUNT, LG_INCLUDE_INDIRECT, LOCALGROUP_USERS_INFO_0, MAX_PREFERRED_LENGTH,
-    USER_INFO_0, USER_INFO_23,
+    UF_ACCOUNTDISABLE, UF_LOCKOUT, USER_ACCOUNT_FLAGS, USER_INFO_0, USER_INFO_2, USER_INFO_23,
+    USER_INFO_4,
 };
 use windows::Win32::Security::Authentication::Identity::{
-    LsaEnumerateLogonSessions, LsaFreeReturnBuffer, LsaGetLogonSessionData,
+    LsaEnumerateLogonSessions, LsaFreeReturnBuffer, LsaGetLogonSessionData, LSA_UNICODE_STRING,
     SECURITY_LOGON_SESSION_DATA, SECURITY_LOGON_TYPE,
 };
+use windows::Win32::System::Registry::{RegGetValueW, HKEY_LOCAL_MACHINE, RRF_RT_REG_SZ};
 
 pub(crate) struct UserInner {
     pub(crate) uid: Uid,
@@ -23,16 +29,43 @@ pub(crate) struct UserInner {
     c_user_name: Option<Vec<u16>>,
     is_local: bool,
     pub(crate) domain: Option<String>,
+    full_name: Option<String>,
+    status: AccountStatus,
+}
+
+/// Status of a local account. It is unknown for the other ones.
+#[derive(Default)]
+struct AccountStatus {
+    is_disabled: Option<bool>,
+    is_locked_out: Option<bool>,
+    password_expired: Option<bool>,
+}
+
+impl AccountStatus {
+    fn from_flags(flags: USER_ACCOUNT_FLAGS, password_expired: Option<bool>) -> Self {
+        Self {
+            is_disabled: Some(flags.0 & UF_ACCOUNTDISABLE.0 != 0),
+            is_locked_out: Some(flags.0 & UF_LOCKOUT.0 != 0),
+            password_expired,
+        }
+    }
 }
 
 impl UserInner {
-    fn new(uid: Uid, name: String, c_name: PCWSTR, is_local: bool) -> Self {
+    fn new(
+        uid: Uid,
+        name: String,
+        domain: Option<String>,
+        full_name: Option<String>,
+        status: AccountStatus,
+        c_name: PCWSTR,
+        is_local: bool,
+    ) -> Self {
         let c_user_name = if c_name.is_null() {
             None
         } else {
             Some(unsafe { c_name.as_wide() }.into())
         };
-        let domain = uid.0.account_name_and_domain().and_then(|(_, domain)| domain);
         Self {
             uid,
             gid: Gid(0),
@@ -40,6 +73,8 @@ impl UserInner {
             c_user_name,
             is_local,
             domain,
+            full_name,
+            status,
         }
     }
 
@@ -55,17 +90,19 @@ impl UserInner {
         &self.name
     }
 
+    /// Returns the null-terminated name of the user if it is a local account.
+    fn local_user_name(&self) -> Option<Vec<u16>> {
+        let c_user_name = self.c_user_name.as_ref().filter(|_| self.is_local)?;
+        let mut null_terminated = c_user_name.to_vec();
+        if null_terminated.last().is_some_and(|v| *v != 0) {
+            null_terminated.push(0);
+        }
+        Some(null_terminated)
+    }
+
     pub(crate) fn groups(&self) -> Vec<Group> {
-        if let (Some(c_user_name), true) = (&self.c_user_name, self.is_local) {
-            // Convert the wide string to a PCWSTR, and ensure it has a null terminator.
-            // Since the Vec is created here, we can ensure it will not be dropped prematurely.
-            let username = {
-                let mut null_terminated = c_user_name.to_vec();
-                if null_terminated.last().is_some_and(|v| *v != 0) {
-                    null_terminated.push(0);
-                }
-                null_terminated
-            };
+        // Since the Vec is created here, we can ensure it will not be dropped prematurely.
+        if let Some(username) = self.local_user_name() {
             unsafe { get_groups_for_user(PCWSTR::from_raw(username.as_ptr())) }
         } else {
             Vec::new()
@@ -75,6 +112,112 @@ impl UserInner {
     pub(crate) fn domain(&self) -> Option<&str> {
         self.domain.as_deref()
     }
+
+    pub(crate) fn full_name(&self) -> Option<&str> {
+        self.full_name.as_deref()
+    }
+
+    pub(crate) fn is_disabled(&self) -> Option<bool> {
+        self.status.is_disabled
+    }
+
+    pub(crate) fn is_locked_out(&self) -> Option<bool> {
+        self.status.is_locked_out
+    }
+
+    pub(crate) fn password_expired(&self) -> Option<bool> {
+        self.status.password_expired
+    }
+
+    pub(crate) fn last_login(&self) -> Option<SystemTime> {
+        let username = self.local_user_name()?;
+        unsafe { get_last_logon(PCWSTR::from_raw(username.as_ptr())) }
+    }
+
+    pub(crate) fn home_directory_and_shell(&self) -> (Option<PathBuf>, Option<PathBuf>) {
+        // The home folder is only set on the accounts configured to use one (usually on a network
+        // share), the other ones use their profile directory.
+        let home_directory = self
+            .local_user_name()
+            .and_then(|username| unsafe { get_home_directory(PCWSTR::from_raw(username.as_ptr())) })
+            .or_else(|| unsafe { get_profile_directory(&self.uid.0) });
+        (home_directory, None)
+    }
+}
+
+/// Retrieves the home folder set on the account of a local user.
+///
+/// # Safety
+/// The caller must ensure that the `username` is a valid wide Unicode string with a null terminator.
+unsafe fn get_home_directory(username: PCWSTR) -> Option<PathBuf> {
+    let mut user: NetApiBuffer<USER_INFO_4> = Default::default();
+    let status = NetUserGetInfo(PCWSTR::null(), username, 4, user.inner_mut_as_bytes());
+    if status != NERR_Success || user.0.is_null() {
+        sysinfo_debug!("NetUserGetInfo failed with ret code {}", status);
+        return None;
+    }
+    let home_directory = (*user.0).usri4_home_dir;
+    if home_directory.is_null() {
+        return None;
+    }
+    match home_directory.as_wide() {
+        [] => None,
+        path => Some(PathBuf::from(OsString::from_wide(path))),
+    }
+}
+
+/// Retrieves when a local user last logged on. It is only tracked by this machine, the logons on
+/// the other machines of the domain are not taken into account.
+///
+/// # Safety
+/// The caller must ensure that the `username` is a valid wide Unicode string with a null terminator.
+unsafe fn get_last_logon(username: PCWSTR) -> Option<SystemTime> {
+    let mut user: NetApiBuffer<USER_INFO_2> = Default::default();
+    let status = NetUserGetInfo(PCWSTR::null(), username, 2, user.inner_mut_as_bytes());
+    if status != NERR_Success || user.0.is_null() {
+        sysinfo_debug!("NetUserGetInfo failed with ret code {}", status);
+        return None;
+    }
+    // It is the number of seconds since UNIX epoch, or 0 if the user never logged on.
+    match (*user.0).usri2_last_logon {
+        0 => None,
+        secs => Some(UNIX_EPOCH + Duration::from_secs(secs as u64)),
+    }
+}
+
+/// Retrieves the profile directory of the user from the registry. If the user never logged in,
+/// it has no profile directory.
+unsafe fn get_profile_directory(sid: &Sid) -> Option<PathBuf> {
+    let key = format!("SOFTWARE\\Microsoft\\Windows NT\\CurrentVersion\\ProfileList\\{sid}")
+        .encode_utf16()
+        .chain(Some(0))
+        .collect::<Vec<_>>();
+    let mut buffer = vec![0u16; 260];
+    loop {
+        let mut size = (buffer.len() * std::mem::size_of::<u16>()) as u32;
+        // `REG_EXPAND_SZ` values are expanded and returned as `REG_SZ`.
+        let ret = RegGetValueW(
+            HKEY_LOCAL_MACHINE,
+            PCWSTR::from_raw(key.as_ptr()),
+            w!("ProfileImagePath"),
+            RRF_RT_REG_SZ,
+            None,
+            Some(buffer.as_mut_ptr() as _),
+            Some(&mut size),
+        );
+        if ret == ERROR_MORE_DATA {
+            buffer.resize(size as usize / std::mem::size_of::<u16>() + 1, 0);
+            continue;
+        }
+        if ret.is_err() {
+            return None;
+        }
+        let len = buffer.iter().position(|c| *c == 0).unwrap_or(buffer.len());
+        if len == 0 {
+            return None;
+        }
+        return Some(PathBuf::from(OsString::from_wide(&buffer[..len])));
+    }
 }
 
 struct NetApiBuffer<T>(*mut T);
@@ -129,6 +272,56 @@ impl<T> LsaBuffer<T> {
     }
 }
 
+/// Information about a local account.
+struct LocalAccount {
+    sid: Sid,
+    full_name: Option<String>,
+    status: AccountStatus,
+}
+
+/// Retrieves the information of a local account. The level 4 information is only available to
+/// administrators, so it falls back to the level 23 which doesn't say if the password expired.
+///
+/// # Safety
+/// The caller must ensure that the `username` is a valid wide Unicode string with a null terminator.
+unsafe fn get_local_account(username: PCWSTR) -> Option<LocalAccount> {
+    // The comment is used when the full name isn't set.
+    let full_name = |full_name: PWSTR, comment: PWSTR| {
+        [full_name, comment]
+            .into_iter()
+            .map(|s| to_utf8_str(s))
+            .find(|s| !s.is_empty())
+    };
+
+    let mut user: NetApiBuffer<USER_INFO_4> = Default::default();
+    if NetUserGetInfo(PCWSTR::null(), username, 4, user.inner_mut_as_bytes()) == NERR_Success
+        && !user.0.is_null()
+    {
+        let user = &*user.0;
+        return Some(LocalAccount {
+            sid: Sid::from_psid(user.usri4_user_sid)?,
+            full_name: full_name(user.usri4_full_name, user.usri4_comment),
+            status: AccountStatus::from_flags(
+                user.usri4_flags,
+                Some(user.usri4_password_expired != 0),
+            ),
+        });
+    }
+
+    let mut user: NetApiBuffer<USER_INFO_23> = Default::default();
+    if NetUserGetInfo(PCWSTR::null(), username, 23, user.inner_mut_as_bytes()) == NERR_Success
+        && !user.0.is_null()
+    {
+        let user = &*user.0;
+        return Some(LocalAccount {
+            sid: Sid::from_psid(user.usri23_user_sid)?,
+            full_name: full_name(user.usri23_full_name, user.usri23_comment),
+            status: AccountStatus::from_flags(user.usri23_flags, None),
+        });
+    }
+    None
+}
+
 /// Get the groups for a user.
 ///
 /// # Safety
@@ -155,7 +348,7 @@ unsafe fn get_groups_for_user(username: PCWSTR) -> Vec<Group> {
         if !buf.0.is_null() {
             let entries = std::slice::from_raw_parts(buf.0, nb_entries as _);
             groups.extend(entries.iter().map(|entry| Group {
-                inner: GroupInner::new(Gid(0), to_utf8_str(entry.lgrui0_name)),
+                inner: GroupInner::new(Gid(0), to_utf8_str(entry.lgrui0_name), Vec::new()),
             }));
         }
     } else {
@@ -166,6 +359,52 @@ unsafe fn get_groups_for_user(username: PCWSTR) -> Vec<Group> {
     groups
 }
 
+unsafe fn lsa_string_to_string(s: &LSA_UNICODE_STRING) -> String {
+    if s.Buffer.is_null() {
+        return String::new();
+    }
+    String::from_utf16(std::slice::from_raw_parts(
+        s.Buffer.as_ptr(),
+        s.Length as usize / std::mem::size_of::<u16>(),
+    ))
+    .unwrap_or_else(|_err| {
+        sysinfo_debug!("Failed to convert from UTF-16 string: {}", _err);
+        String::new()
+    })
+}
+
+pub(crate) fn get_user_by_id(uid: &Uid) -> Option<User> {
+    let (name, domain) = uid.0.account_name_and_domain()?;
+    let c_name = name.encode_utf16().chain(Some(0)).collect::<Vec<_>>();
+    unsafe {
+        // If it is a local account, the same information as `get_users` is retrieved.
+        let account = get_local_account(PCWSTR::from_raw(c_name.as_ptr()))
+            .filter(|account| account.sid == uid.0);
+        let is_local = account.is_some();
+        let (full_name, status) = account
+            .map(|account| (account.full_name, account.status))
+            .unwrap_or_default();
+        Some(User {
+            inner: UserInner::new(
+                uid.clone(),
+                name,
+                domain,
+                full_name,
+                status,
+                if is_local {
+                    PCWSTR::from_raw(c_name.as_ptr())
+                } else {
+                    PCWSTR::null()
+                },
+                is_local,
+            ),
+            groups: None,
+            home_directory: None,
+            shell: None,
+        })
+    }
+}
+
 pub(crate) fn get_users(users: &mut Vec<User>) {
     users.clear();
 
@@ -192,30 +431,30 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
                         continue;
                     }
 
-                    let mut user: NetApiBuffer<USER_INFO_23> = Default::default();
-                    if NetUserGetInfo(
-                        PCWSTR::null(),
-                        PCWSTR::from_raw(entry.usri0_name.as_ptr()),
-                        23,
-                        user.inner_mut_as_bytes(),
-                    ) == NERR_Success
+                    if let Some(account) =
+                        get_local_account(PCWSTR::from_raw(entry.usri0_name.as_ptr()))
                     {
-                        if let Some(sid) = Sid::from_psid((*user.0).usri23_user_sid) {
-                            // Get the account name from the SID (because it's usually
-                            // a better name), but fall back to the name we were given
-                            // if this fails.
-                            let name = sid
-                                .account_name()
-                                .unwrap_or_else(|| to_utf8_str(entry.usri0_name));
-                            users.push(User {
-                                inner: UserInner::new(
-                                    Uid(sid),
-                                    name,
-                                    PCWSTR(entry.usri0_name.0 as *const _),
-                                    true,
-                                ),
-                            });
-                        }
+                        // Get the account name from the SID (because it's usually
+                        // a better name), but fall back to the name we were given
+                        // if this fails.
+                        let (name, domain) = account
+                            .sid
+                            .account_name_and_domain()
+                            .unwrap_or_else(|| (to_utf8_str(entry.usri0_name), None));
+                        users.push(User {
+                            inner: UserInner::new(
+                                Uid(account.sid),
+                                name,
+                                domain,
+                                account.full_name,
+                                account.status,
+                                PCWSTR(entry.usri0_name.0 as *const _),
+                                true,
+                            ),
+                            groups: None,
+                            home_directory: None,
+                            shell: None,
+                        });
                     }
                 }
             } else {
@@ -259,22 +498,30 @@ pub(crate) fn get_users(users: &mut Vec<User>) {
                         continue;
                     }
 
-                    // Get the account name from the SID (because it's usually
-                    // a better name), but fall back to the name we were given
-                    // if this fails.
-                    let name = sid.account_name().unwrap_or_else(|| {
-                        String::from_utf16(std::slice::from_raw_parts(
-                            data.UserName.Buffer.as_ptr(),
-                            data.UserName.Length as usize / std::mem::size_of::<u16>(),
-                        ))
-                        .unwrap_or_else(|_err| {
-                            sysinfo_debug!("Failed to convert from UTF-16 string: {}", _err);
-                            String::new()
-                        })
+                    // Get the account name and domain from the SID (because they're
+                    // usually better names), but fall back to the ones of the logon
+                    // session if this fails.
+                    let (name, domain) = sid.account_name_and_domain().unwrap_or_else(|| {
+                        let domain = lsa_string_to_string(&data.LogonDomain);
+                        (
+                            lsa_string_to_string(&data.UserName),
+                            (!domain.is_empty()).then_some(domain),
+                        )
                     });
 
                     users.push(User {
-                        inner: UserInner::new(Uid(sid), name, PCWSTR::null(), false),
+                        inner: UserInner::new(
+                            Uid(sid),
+                            name,
+                            domain,
+                            None,
+                            AccountStatus::default(),
+                            PCWSTR::null(),
+                            false,
+                        ),
+                        groups: None,
+                        home_directory: None,
+                        shell: None,
                     });
                 }
             }
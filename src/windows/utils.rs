@@ -1,6 +1,6 @@
 // Take a look at the license at the top of the repository in the LICENSE file.
 
-#[cfg(feature = "disk")]
+#[cfg(any(feature = "battery", feature = "component", feature = "disk"))]
 use windows::Win32::Storage::FileSystem::{
     CreateFileW, FILE_ACCESS_RIGHTS, FILE_SHARE_READ, FILE_SHARE_WRITE, OPEN_EXISTING,
 };
@@ -18,7 +18,7 @@ pub(crate) unsafe fn to_utf8_str(p: windows::core::PWSTR) -> String {
 }
 
 cfg_if! {
-    if #[cfg(any(feature = "disk", feature = "system"))] {
+    if #[cfg(any(feature = "battery", feature = "component", feature = "disk", feature = "system"))] {
         use windows::Win32::Foundation::{CloseHandle, HANDLE};
         use std::ops::Deref;
 
@@ -34,7 +34,7 @@ cfg_if! {
                 }
             }
 
-            #[cfg(feature = "disk")]
+            #[cfg(any(feature = "battery", feature = "component", feature = "disk"))]
             pub(crate) unsafe fn new_from_file(
                 drive_name: &[u16],
                 open_rights: FILE_ACCESS_RIGHTS,
@@ -0,0 +1,18 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+#[cfg(feature = "battery")]
+#[test]
+fn test_batteries() {
+    let mut batteries = sysinfo::Batteries::new();
+    assert!(batteries.is_empty());
+
+    // The CI machines (and desktops) don't have any battery, so we can only check the ones
+    // which are listed.
+    batteries.refresh(true);
+    for battery in &batteries {
+        assert!(!battery.name().is_empty());
+        if let Some(charge) = battery.charge_percent() {
+            assert!((0. ..=100.).contains(&charge));
+        }
+    }
+}
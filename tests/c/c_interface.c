@@ -0,0 +1,145 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Checks the C interface through `sysinfo.h`. It's compiled and run by `tests/c_interface.rs`,
+// which passes `EXPECTED_ARG` as first argument.
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <unistd.h>
+#include "sysinfo.h"
+
+#define EXPECTED_ARG "sysinfo-c-interface-arg"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                          \
+    do {                                                                     \
+        if (!(cond)) {                                                       \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+                    #cond);                                                  \
+            failures += 1;                                                   \
+        }                                                                    \
+    } while (0)
+
+static bool count_process(PID pid, CProcess process, void *data) {
+    CHECK(sysinfo_process_pid(process) == pid);
+    int status = sysinfo_process_status(process);
+    CHECK(status >= SYSINFO_PROCESS_STATUS_IDLE && status <= SYSINFO_PROCESS_STATUS_UNKNOWN);
+    *(size_t *)data += 1;
+    return true;
+}
+
+static void check_processes(CSystem system) {
+    size_t count = 0;
+    size_t len = sysinfo_processes(system, count_process, &count);
+    CHECK(len > 0);
+    CHECK(count == len);
+
+    CProcess process = sysinfo_process_by_pid(system, getpid());
+    CHECK(process != NULL);
+    if (process == NULL) {
+        return;
+    }
+    CHECK(sysinfo_process_pid(process) == getpid());
+    CHECK(sysinfo_process_parent_pid(process) == getppid());
+    CHECK(sysinfo_process_memory(process) > 0);
+    CHECK(sysinfo_process_cpu_usage(process) >= 0.);
+
+    // Only the length is asked first.
+    char buffer[4096];
+    size_t name_len = sysinfo_process_name(process, NULL, 0);
+    CHECK(name_len > 0 && name_len <= sizeof(buffer));
+    CHECK(sysinfo_process_name(process, buffer, sizeof(buffer)) == name_len);
+
+    size_t exe_len = sysinfo_process_exe(process, buffer, sizeof(buffer));
+    CHECK(exe_len > 0 && exe_len <= sizeof(buffer));
+    // The name is the last component of the executable path (the program is given a name short
+    // enough to not be truncated on Linux).
+    char name[4096];
+    sysinfo_process_name(process, name, sizeof(name));
+    CHECK(exe_len >= name_len && memcmp(buffer + exe_len - name_len, name, name_len) == 0);
+
+    // The truncated strings aren't nul-terminated and the whole length is returned.
+    memset(buffer, 'x', sizeof(buffer));
+    CHECK(sysinfo_process_exe(process, buffer, 1) == exe_len);
+    CHECK(buffer[0] == '/' && buffer[1] == 'x');
+
+    size_t cmd_len = sysinfo_process_cmd(process, buffer, sizeof(buffer));
+    CHECK(cmd_len > 0 && cmd_len <= sizeof(buffer));
+    // The arguments are separated by nul bytes: the first one is the program, then `EXPECTED_ARG`.
+    size_t first_len = strnlen(buffer, cmd_len);
+    CHECK(first_len < cmd_len);
+    if (first_len < cmd_len) {
+        const char *arg = buffer + first_len + 1;
+        size_t arg_len = cmd_len - first_len - 1;
+        CHECK(arg_len == sizeof(EXPECTED_ARG) && memcmp(arg, EXPECTED_ARG, arg_len) == 0);
+    }
+
+#ifdef __linux__
+    // The current process is running while it reads its own status.
+    CHECK(sysinfo_process_status(process) == SYSINFO_PROCESS_STATUS_RUN);
+#endif
+}
+
+static bool check_disk(CDisk disk, void *data) {
+    char buffer[4096];
+    size_t len = sysinfo_disk_mount_point(disk, buffer, sizeof(buffer));
+    CHECK(len > 0 && len <= sizeof(buffer));
+    CHECK(sysinfo_disk_file_system(disk, NULL, 0) > 0);
+    sysinfo_disk_name(disk, buffer, sizeof(buffer));
+    CHECK(sysinfo_disk_available_space(disk) <= sysinfo_disk_total_space(disk));
+    int kind = sysinfo_disk_kind(disk);
+    CHECK(kind >= SYSINFO_DISK_KIND_HDD && kind <= SYSINFO_DISK_KIND_UNKNOWN);
+    *(size_t *)data += 1;
+    return true;
+}
+
+static bool stop_at_first_disk(CDisk disk, void *data) {
+    (void)disk;
+    *(size_t *)data += 1;
+    return false;
+}
+
+static void check_disks(void) {
+    CDisks disks = sysinfo_disks_init();
+    size_t count = 0;
+    CHECK(sysinfo_disks(disks, stop_at_first_disk, &count) == 0 && count == 0);
+    sysinfo_disks_refresh(disks);
+
+    size_t len = sysinfo_disks(disks, check_disk, &count);
+    CHECK(count == len);
+    count = 0;
+    sysinfo_disks(disks, stop_at_first_disk, &count);
+    CHECK(count == (len > 0 ? 1 : 0));
+    sysinfo_disks_destroy(disks);
+}
+
+static void check_cpus(CSystem system) {
+    unsigned int len = 0;
+    float *cpus = NULL;
+    sysinfo_refresh_cpu(system);
+    sysinfo_cpus_usage(system, &len, &cpus);
+    CHECK(cpus != NULL);
+    sysinfo_cpus_usage_free(cpus);
+}
+
+int main(int argc, char **argv) {
+    if (argc != 2 || strcmp(argv[1], EXPECTED_ARG) != 0) {
+        fprintf(stderr, "usage: %s " EXPECTED_ARG "\n", argv[0]);
+        return 2;
+    }
+    CSystem system = sysinfo_init();
+    sysinfo_refresh_all(system);
+
+    check_processes(system);
+    check_disks();
+    check_cpus(system);
+
+    sysinfo_destroy(system);
+    if (failures != 0) {
+        fprintf(stderr, "%d check(s) failed\n", failures);
+        return 1;
+    }
+    return 0;
+}
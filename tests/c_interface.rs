@@ -0,0 +1,59 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// Builds the library with the `c-interface` feature, compiles `tests/c/c_interface.c` against it
+// with the system C compiler and runs it, like the `make` of the C example does.
+//
+// It only runs when the `c-interface` feature is enabled, which the `c_interface` CI job does with
+// `cargo test --features c-interface --test c_interface`.
+
+#![cfg(all(feature = "c-interface", unix))]
+
+use std::path::Path;
+use std::process::Command;
+
+#[test]
+fn check_c_interface() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // A separate target directory is used, otherwise the build would wait for the lock of the
+    // `cargo test` running this test.
+    let tmp_dir = Path::new(env!("CARGO_TARGET_TMPDIR"));
+    let target_dir = tmp_dir.join("c-interface");
+
+    let status = Command::new(env!("CARGO"))
+        .arg("rustc")
+        .arg("--manifest-path")
+        .arg(manifest_dir.join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .args([
+            "--lib",
+            "--features",
+            "c-interface",
+            "--crate-type",
+            "cdylib",
+        ])
+        .status()
+        .expect("failed to run cargo");
+    assert!(status.success(), "failed to build the C interface");
+
+    let lib_dir = target_dir.join("debug");
+    let program = tmp_dir.join("c_interface");
+    let status = Command::new(std::env::var_os("CC").unwrap_or_else(|| "cc".into()))
+        .arg(manifest_dir.join("tests/c/c_interface.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("src"))
+        .arg("-L")
+        .arg(&lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .args(["-Wall", "-Werror", "-lsysinfo", "-lpthread", "-o"])
+        .arg(&program)
+        .status()
+        .expect("failed to run the C compiler");
+    assert!(status.success(), "failed to compile the C program");
+
+    let status = Command::new(&program)
+        .arg("sysinfo-c-interface-arg")
+        .status()
+        .expect("failed to run the C program");
+    assert!(status.success(), "the C checks failed");
+}
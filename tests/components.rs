@@ -16,3 +16,30 @@ fn test_components() {
     c.refresh(false);
     assert!(!c.is_empty());
 }
+
+#[cfg(feature = "component")]
+#[test]
+fn test_fans() {
+    let mut fans = sysinfo::Fans::new();
+    assert!(fans.is_empty());
+
+    // Most machines (and all the CI ones) don't expose any fan, so we can only check the ones
+    // which are listed.
+    fans.refresh(true);
+    for fan in &fans {
+        assert!(!fan.label().is_empty());
+    }
+}
+
+#[cfg(feature = "component")]
+#[test]
+fn test_power_sensors() {
+    let mut sensors = sysinfo::PowerSensors::new();
+    assert!(sensors.is_empty());
+
+    sensors.refresh(true);
+    for sensor in &sensors {
+        assert!(!sensor.label().is_empty());
+        assert_eq!(sensor.unit(), sensor.kind().unit());
+    }
+}
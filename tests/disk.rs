@@ -184,3 +184,50 @@ fn test_disks_usage() {
     // just verify the number is non-zero.
     assert!(written_bytes > 0);
 }
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_fill_rate() {
+    use sysinfo::{DiskRefreshKind, Disks};
+
+    if should_skip() {
+        return;
+    }
+
+    let mut disks = Disks::new_with_refreshed_list();
+    assert!(disks
+        .list()
+        .iter()
+        .all(|disk| disk.fill_rate_bytes_per_sec().is_none()));
+
+    // The storage information needs to be refreshed to compute a rate.
+    disks.refresh_specifics(false, DiskRefreshKind::nothing());
+    assert!(disks
+        .list()
+        .iter()
+        .all(|disk| disk.fill_rate_bytes_per_sec().is_none()));
+
+    std::thread::sleep(std::time::Duration::from_millis(10));
+    disks.refresh(false);
+    assert!(disks
+        .list()
+        .iter()
+        .filter(|disk| disk.total_space() != 0)
+        .all(|disk| disk.fill_rate_bytes_per_sec().is_some_and(f64::is_finite)));
+}
+
+#[test]
+#[cfg(all(feature = "system", feature = "disk"))]
+fn test_disks_new_all_refreshed() {
+    if should_skip() {
+        return;
+    }
+
+    let disks = sysinfo::Disks::new_all_refreshed();
+    assert!(!disks.list().is_empty());
+    for disk in disks.list() {
+        let usage = disk.usage();
+        assert!(usage.read_bytes <= usage.total_read_bytes);
+        assert!(usage.written_bytes <= usage.total_written_bytes);
+    }
+}
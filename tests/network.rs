@@ -14,3 +14,100 @@ fn test_networks() {
         assert!(n.iter().count() > 0);
     }
 }
+
+#[cfg(feature = "network")]
+#[test]
+fn test_refresh_interface() {
+    use sysinfo::Networks;
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let mut n = Networks::new_with_refreshed_list();
+        let Some(name) = n.keys().next().cloned() else {
+            return;
+        };
+
+        let mut filtered = Networks::new();
+        filtered.refresh_list_filtered(|interface_name| interface_name == name);
+        assert_eq!(filtered.keys().collect::<Vec<_>>(), [&name]);
+
+        assert!(n.refresh_interface(&name));
+        assert!(!n.refresh_interface("sysinfo-does-not-exist"));
+        assert!(!n.contains_key("sysinfo-does-not-exist"));
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_refresh_interfaces() {
+    use sysinfo::{Networks, NetworksRefreshKind};
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let all = Networks::new_with_refreshed_list_specifics(NetworksRefreshKind::nothing());
+        let Some(name) = all.keys().next() else {
+            return;
+        };
+
+        let mut n = Networks::new();
+        assert_eq!(
+            n.refresh_interfaces(&[name.as_str(), "sysinfo-does-not-exist"]),
+            1
+        );
+        assert_eq!(n.keys().collect::<Vec<_>>(), [name]);
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_get_by_index() {
+    use sysinfo::Networks;
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let n = Networks::new_with_refreshed_list();
+        for (name, network) in &n {
+            if network.index() == 0 {
+                continue;
+            }
+            assert_eq!(
+                n.get_by_index(network.index()).map(|(name, _)| name),
+                Some(name.as_str())
+            );
+        }
+        assert!(n.get_by_index(0).is_none());
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_network_totals() {
+    use sysinfo::{NetworkTotalsFilter, Networks};
+
+    let n = Networks::new_with_refreshed_list();
+    let everything = NetworkTotalsFilter::new().with_loopback(true);
+    let expected: u64 = n.values().map(|network| network.total_received()).sum();
+    assert_eq!(n.total_received(everything), expected);
+    let expected: u64 = n.values().map(|network| network.transmitted()).sum();
+    assert_eq!(n.transmitted(everything), expected);
+
+    let expected: u64 = n
+        .values()
+        .filter(|network| !network.is_loopback() && network.is_up())
+        .map(|network| network.total_packets_received())
+        .sum();
+    let filter = NetworkTotalsFilter::new().with_down_interfaces(false);
+    assert_eq!(n.total_packets_received(filter), expected);
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn test_networks_new_all_refreshed() {
+    use sysinfo::Networks;
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let n = Networks::new_all_refreshed();
+        assert!(n.iter().count() > 0);
+        for network in n.values() {
+            assert!(network.received() <= network.total_received());
+            assert!(network.transmitted() <= network.total_transmitted());
+        }
+    }
+}
@@ -53,6 +53,31 @@ fn test_cwd() {
     }
 }
 
+#[cfg(feature = "exe-hash")]
+#[test]
+fn test_exe_hash() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current PID");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::Always),
+    );
+    let process = s.process(pid).expect("current process not found");
+    let hash = process
+        .exe_hash(sysinfo::HashAlgorithm::Sha256)
+        .expect("failed to hash the current executable");
+    assert_eq!(hash.len(), 64);
+    assert!(hash
+        .bytes()
+        .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+    // The second call uses the cached value.
+    assert_eq!(process.exe_hash(sysinfo::HashAlgorithm::Sha256), Some(hash));
+}
+
 #[test]
 fn test_cmd() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -192,6 +217,58 @@ fn test_process_refresh() {
         .any(|(_, p)| !p.name().is_empty() && p.memory() != 0));
 }
 
+#[test]
+fn test_processes_by_exe() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::Always),
+    );
+    let exe = s
+        .process(pid)
+        .and_then(|p| p.exe())
+        .expect("failed to get exe")
+        .to_path_buf();
+    assert!(s.processes_by_exe(&exe, false).any(|p| p.pid() == pid));
+    assert!(s.processes_by_exe(&exe, true).any(|p| p.pid() == pid));
+    assert!(s
+        .processes_by_exe(std::path::Path::new("/not/a/real/exe"), true)
+        .next()
+        .is_none());
+}
+
+#[test]
+fn test_processes_by_user_and_group() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        false,
+        ProcessRefreshKind::nothing().with_user(UpdateKind::Always),
+    );
+    let process = s.process(pid).expect("failed to get current process");
+    let uid = process.user_id().expect("failed to get uid").clone();
+    assert!(s.processes_by_user(uid.clone()).any(|p| p.pid() == pid));
+    assert!(s
+        .processes_by_user(uid.clone())
+        .all(|p| p.user_id() == Some(&uid)));
+
+    // The group ID is never retrieved on Windows.
+    if !cfg!(windows) {
+        let gid = process.group_id().expect("failed to get gid");
+        assert!(s.processes_by_group(gid).any(|p| p.pid() == pid));
+        assert!(s.processes_by_group(gid).all(|p| p.group_id() == Some(gid)));
+    }
+}
+
 #[test]
 fn test_process_disk_usage() {
     use std::fs;
@@ -313,6 +390,13 @@ fn test_process_times() {
                     .as_secs(),
         );
         assert!(p.start_time() >= boot_time);
+        // The seconds can differ by one since `start_time` uses the rounded boot time.
+        let precise = p
+            .start_time_precise()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        assert!(precise.abs_diff(p.start_time()) <= 1);
     } else {
         panic!("Process not found!");
     }
@@ -329,6 +413,26 @@ fn test_process_session_id() {
     assert!(s.processes().values().any(|p| p.session_id().is_some()));
 }
 
+// Checks that `is_kernel_thread` is working.
+#[test]
+fn test_process_is_kernel_thread() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    assert!(!s.process(current_pid).unwrap().is_kernel_thread());
+    if cfg!(target_os = "linux") {
+        // `kthreadd` might not be visible if we're running in a container.
+        if let Some(kthreadd) = s.processes_by_exact_name("kthreadd".as_ref()).next() {
+            assert!(kthreadd.is_kernel_thread());
+        }
+    } else {
+        assert!(s.processes().values().all(|p| !p.is_kernel_thread()));
+    }
+}
+
 // Checks that `refresh_processes` is removing dead processes.
 #[test]
 fn test_refresh_processes() {
@@ -590,6 +694,38 @@ fn test_refresh_process() {
     assert!(s.process(pid).is_none());
 }
 
+// Checks that `System::refresh_pids` only refreshes the given processes and keeps the dead ones.
+#[test]
+fn test_refresh_pids_keeps_dead_processes() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut p = start_proc!("300", "RefreshPidsDead");
+
+    let pid = Pid::from_u32(p.id() as _);
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    let pids = [pid, current_pid];
+    let refresh_kind = ProcessRefreshKind::nothing().with_cpu();
+    let mut s = System::new();
+    assert_eq!(s.refresh_pids(&pids, refresh_kind), 2);
+    assert!(s.process(pid).is_some_and(|p| p.exists()));
+    assert!(s.process(current_pid).is_some_and(|p| p.exists()));
+    assert_eq!(s.processes().len(), 2);
+
+    p.kill().expect("Unable to kill process.");
+    // We need this, otherwise the process will still be around as a zombie on linux.
+    let _ = p.wait();
+    // Let's give some time to the system to clean up...
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    assert_eq!(s.refresh_pids(&pids, refresh_kind), 1);
+    // The dead process is still listed but marked as not existing anymore.
+    assert!(s.process(pid).is_some_and(|p| !p.exists()));
+    assert!(s.process(current_pid).is_some_and(|p| p.exists()));
+}
+
 #[test]
 fn test_wait_child() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -617,6 +753,41 @@ fn test_wait_child() {
     assert!(before.elapsed() < std::time::Duration::from_millis(1000));
 }
 
+#[test]
+fn test_exit_code_child() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") || cfg!(windows) {
+        return;
+    }
+    let mut p = std::process::Command::new("sh")
+        .arg("-c")
+        .arg("sleep 1; exit 3")
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_u32(p.id());
+
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[pid]), false);
+    let process = s.process(pid).expect("Process not found!");
+
+    // The process is still running.
+    assert_eq!(process.exit_code(), None);
+    let mut exit_code = None;
+    for _ in 0..500 {
+        exit_code = process.exit_code();
+        if exit_code.is_some() {
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(exit_code, Some(3));
+    // The process was reaped but its exit code is kept.
+    assert_eq!(process.exit_code(), Some(3));
+    // And so is its exit status.
+    assert_eq!(process.wait().and_then(|status| status.code()), Some(3));
+    // It was already reaped so it's expected to fail.
+    let _ = p.wait();
+}
+
 #[test]
 fn test_wait_non_child() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -882,6 +1053,68 @@ fn test_refresh_pids() {
     }
 }
 
+#[test]
+fn test_process_ns_pid() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[current_pid]),
+        false,
+        ProcessRefreshKind::nothing().with_user(UpdateKind::Always),
+    );
+    let process = s.process(current_pid).expect("no process found");
+    if cfg!(any(target_os = "linux", target_os = "android")) {
+        // The tests run in the same PID namespace as `sysinfo`.
+        assert_eq!(process.ns_pid(), Some(current_pid));
+    } else {
+        assert_eq!(process.ns_pid(), None);
+    }
+    assert!(!process.is_container_init());
+}
+
+#[test]
+fn test_process_namespaces() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut p = start_proc!("300", "ProcessNamespaces");
+    let pid = Pid::from_u32(p.id() as _);
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let pids = [current_pid, pid];
+
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&pids),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    assert_eq!(s.process(current_pid).unwrap().namespaces(), None);
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&pids),
+        false,
+        ProcessRefreshKind::nothing().with_namespaces(),
+    );
+    let namespaces = s.process(current_pid).unwrap().namespaces();
+    let child_namespaces = s.process(pid).map(|child| child.namespaces());
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+
+    if cfg!(any(target_os = "linux", target_os = "android")) {
+        let namespaces = namespaces.expect("no namespaces");
+        assert!(namespaces.pid.is_some());
+        assert!(namespaces.net.is_some());
+        assert!(namespaces.mnt.is_some());
+        // A child process is created in the namespaces of its parent.
+        assert_eq!(child_namespaces, Some(Some(namespaces)));
+    } else {
+        assert_eq!(namespaces, None);
+    }
+}
+
 #[test]
 fn test_process_run_time() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -1055,6 +1288,73 @@ fn accumulated_cpu_time() {
     );
 }
 
+#[test]
+fn test_thread_count() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    // Ensures there are at least two threads.
+    let (sender, receiver) = std::sync::mpsc::channel::<()>();
+    let thread = std::thread::spawn(move || receiver.recv());
+
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[current_pid]),
+        false,
+        ProcessRefreshKind::nothing().with_memory().without_tasks(),
+    );
+    let thread_count = s
+        .process(current_pid)
+        .expect("no process found")
+        .thread_count();
+    drop(sender);
+    let _ = thread.join();
+    assert!(
+        thread_count.is_some_and(|count| count >= 2),
+        "{thread_count:?}"
+    );
+}
+
+#[cfg(all(target_os = "linux", not(feature = "unknown-ci")))]
+#[test]
+fn test_process_from_proc_dir() {
+    use sysinfo::Process;
+
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let process = Process::from_proc_dir("/proc/self").expect("failed to read /proc/self");
+    assert_eq!(process.pid(), current_pid);
+    assert!(!process.cmd().is_empty());
+    assert!(Process::from_proc_dir("/proc/sysinfo-does-not-exist").is_none());
+}
+
+#[test]
+fn test_cpu_time() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+
+    let mut s = System::new();
+    let current_pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    // Spends some CPU time in this process.
+    let start = std::time::Instant::now();
+    while start.elapsed() < std::time::Duration::from_millis(100) {
+        std::hint::black_box(System::new());
+    }
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[current_pid]),
+        false,
+        ProcessRefreshKind::nothing().with_cpu(),
+    );
+    let process = s.process(current_pid).expect("no process found");
+    assert_eq!(
+        process.cpu_time(),
+        process.user_cpu_time() + process.system_cpu_time()
+    );
+    assert!(!process.cpu_time().is_zero());
+}
+
 #[test]
 fn test_exists() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -1084,6 +1384,164 @@ fn test_exists() {
     assert!(!s.process(pid).unwrap().exists());
 }
 
+#[test]
+fn test_process_exists() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+
+    let file_name = "target/test_binary5";
+    build_test_binary(file_name);
+    let mut p = std::process::Command::new(format!("./{file_name}"))
+        .arg("1")
+        .spawn()
+        .unwrap();
+    let pid = Pid::from_u32(p.id() as _);
+    assert!(System::process_exists(pid));
+    assert!(sysinfo::process_exists(pid));
+
+    p.kill().expect("Unable to kill process.");
+    if cfg!(any(target_os = "linux", target_os = "android")) {
+        // The process isn't reaped yet so it is a zombie.
+        std::thread::sleep(std::time::Duration::from_millis(500));
+        assert!(!System::process_exists(pid));
+    }
+    let _ = p.wait();
+    assert!(!System::process_exists(pid));
+}
+
+#[test]
+fn test_process_tree() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut p = start_proc!("3", "ProcessTree");
+    let child_pid = Pid::from_u32(p.id() as _);
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+
+    let tree = s.process_tree(None);
+    assert!(!tree.roots().is_empty());
+    let node = tree.find(pid).expect("current process not in the tree");
+    assert_eq!(node.process().pid(), pid);
+    assert!(node
+        .children()
+        .iter()
+        .any(|child| child.process().pid() == child_pid));
+    assert!(tree.to_string().contains(&format!("{child_pid} ")));
+    assert_eq!(s.child_count(pid), node.children().len());
+    assert_eq!(s.child_count(child_pid), 0);
+
+    // Only the subtree of the given process is kept.
+    let tree = s.process_tree(Some(pid));
+    assert_eq!(tree.roots().len(), 1);
+    assert_eq!(tree.roots()[0].process().pid(), pid);
+    assert!(tree.find(child_pid).is_some());
+    assert_eq!(
+        tree.to_string().lines().next(),
+        Some(format!("{pid} {}", node.process().name().to_string_lossy()).as_str())
+    );
+
+    // The processes whose parent is not in the list are at the top of the tree.
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::Some(&[child_pid]), false);
+    let tree = s.process_tree(None);
+    assert_eq!(tree.roots().len(), 1);
+    assert_eq!(tree.roots()[0].process().pid(), child_pid);
+
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+}
+
+#[test]
+fn test_processes_sorted_by() {
+    use sysinfo::ProcessSortKey;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, false);
+
+    let processes = s.processes_sorted_by(ProcessSortKey::Memory, true);
+    assert_eq!(processes.len(), s.processes().len());
+    assert!(processes.windows(2).all(|w| w[0].memory() >= w[1].memory()));
+
+    let processes = s.processes_sorted_by(ProcessSortKey::Pid, false);
+    assert!(processes.windows(2).all(|w| w[0].pid() < w[1].pid()));
+
+    let processes = s.processes_sorted_by(ProcessSortKey::Name, false);
+    assert!(processes.windows(2).all(|w| w[0].name() <= w[1].name()));
+}
+
+#[test]
+fn test_process_name_index() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut p = start_proc!("3", "ProcessNameIndex");
+    let pid = Pid::from_u32(p.id() as _);
+    let mut s = System::new().with_process_name_index(true);
+    s.refresh_processes(ProcessesToUpdate::All, true);
+
+    let name = s.process(pid).expect("child not found").name().to_owned();
+    let indexed: Vec<Pid> = s
+        .processes_by_exact_name(&name)
+        .map(|process| process.pid())
+        .collect();
+    assert!(indexed.contains(&pid));
+    assert!(indexed.iter().all(|pid| s
+        .process(*pid)
+        .is_some_and(|process| process.name() == name)));
+
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+    s.refresh_processes(ProcessesToUpdate::All, true);
+    assert!(s
+        .processes_by_exact_name(&name)
+        .all(|process| process.pid() != pid));
+}
+
+#[test]
+fn test_snapshot_diff() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut s = System::new();
+    s.refresh_processes(ProcessesToUpdate::All, true);
+    let before = s.snapshot();
+    assert_eq!(before.processes().len(), s.processes().len());
+
+    let mut p = start_proc!("3", "SnapshotDiff");
+    let pid = Pid::from_u32(p.id() as _);
+    s.refresh_processes(ProcessesToUpdate::All, true);
+    let after = s.snapshot();
+    let diff = before.diff(&after);
+    assert!(diff
+        .added_processes()
+        .iter()
+        .any(|process| process.pid() == pid));
+    assert!(diff
+        .removed_processes()
+        .iter()
+        .all(|process| process.pid() != pid));
+    assert!(before.diff(&before).changed_processes().is_empty());
+
+    p.kill().expect("Unable to kill process.");
+    let _ = p.wait();
+    s.refresh_processes(ProcessesToUpdate::All, true);
+    let diff = after.diff(&s.snapshot());
+    assert!(diff
+        .removed_processes()
+        .iter()
+        .any(|process| process.pid() == pid));
+    assert!(diff
+        .added_processes()
+        .iter()
+        .all(|process| process.pid() != pid));
+}
+
 #[cfg(target_os = "linux")]
 #[test]
 fn test_tasks() {
@@ -1149,6 +1607,45 @@ fn open_files() {
         .is_some_and(|open_files| open_files > 0));
 }
 
+#[test]
+fn test_fd_usage() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    assert_eq!(s.process(pid).unwrap().fd_usage(), None);
+
+    let file = std::fs::File::create(std::env::temp_dir().join("sysinfo-fd-usage.test")).unwrap();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_open_files(),
+    );
+    let usage = s.process(pid).unwrap().fd_usage().expect("no fd usage");
+    assert!(usage.open > 0);
+    assert!(usage.peak >= usage.open);
+    assert!(usage.soft_limit.is_some_and(|limit| limit > 0));
+    if let (Some(soft_limit), Some(hard_limit)) = (usage.soft_limit, usage.hard_limit) {
+        assert!(hard_limit >= soft_limit);
+    }
+
+    // The peak is kept when files are closed.
+    drop(file);
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_open_files(),
+    );
+    let new_usage = s.process(pid).unwrap().fd_usage().expect("no fd usage");
+    assert!(new_usage.peak >= usage.peak);
+}
+
 #[test]
 fn test_wait() {
     if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
@@ -1166,13 +1663,163 @@ fn test_wait() {
     let mut s2 = System::new();
     s2.refresh_processes_specifics(ProcessesToUpdate::All, false, ProcessRefreshKind::nothing());
     assert!(s2.process(pid).is_none());
-    // And we check that waiting for it will return `None`.
+    // And we check that waiting for it again returns the same exit status: on Windows because
+    // we have a handle, and on the other platforms because it was kept when it was reaped.
+    assert_eq!(s.process(pid).unwrap().wait(), exit_status);
+}
+
+#[test]
+fn test_io_priority() {
+    use sysinfo::{IoPriority, IoPriorityClass, IoPriorityError};
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
+    }
+    let mut p = start_proc!("3", "TestIoPriority");
+    let pid = Pid::from_u32(p.id() as _);
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    let process = s.process(pid).unwrap();
+    let priority = IoPriority {
+        class: IoPriorityClass::Idle,
+        level: 0,
+    };
     if cfg!(target_os = "linux") {
-        assert_eq!(s.process(pid).unwrap().wait(), None);
+        assert!(process.io_priority().is_some());
+        // Lowering the I/O priority of our own child doesn't require any privilege.
+        assert_eq!(process.set_io_priority(priority), Ok(()));
+        assert_eq!(process.io_priority(), Some(priority));
+        assert_eq!(
+            process.set_io_priority(IoPriority {
+                class: IoPriorityClass::BestEffort,
+                level: 8,
+            }),
+            Err(IoPriorityError::InvalidPriority)
+        );
     } else {
-        // On windows we can get the exit status as long as we have a handle.
-        // On mac and freebsd, no clue why.
-        let exit_status = s.process(pid).unwrap().wait();
-        assert!(exit_status.is_some());
+        assert_eq!(process.io_priority(), None);
+        assert_eq!(
+            process.set_io_priority(priority),
+            Err(IoPriorityError::Unsupported)
+        );
+    }
+    let _ = p.kill();
+    let _ = p.wait();
+}
+
+#[cfg(all(target_os = "linux", not(feature = "unknown-ci")))]
+#[test]
+#[allow(clippy::zombie_processes)]
+fn test_process_comm() {
+    // The name is longer than the 15 bytes of `comm`.
+    let file_name = "target/test_binary_with_a_long_name";
+    build_test_binary(file_name);
+    let mut p = std::process::Command::new(format!("./{file_name}"))
+        .spawn()
+        .unwrap();
+    std::thread::sleep(std::time::Duration::from_secs(1));
+
+    let pid = Pid::from_u32(p.id() as _);
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    let process = s.process(pid).expect("process not found");
+    assert_eq!(process.comm(), "test_binary_wit");
+    // Without the command line and the executable, the full name cannot be known.
+    assert_eq!(process.name(), "test_binary_wit");
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_cmd(UpdateKind::OnlyIfNotSet),
+    );
+    p.kill().expect("Unable to kill process.");
+    let process = s.process(pid).expect("process not found");
+    assert_eq!(process.comm(), "test_binary_wit");
+    assert_eq!(process.name(), "test_binary_with_a_long_name");
+}
+
+#[cfg(all(windows, not(feature = "unknown-ci")))]
+#[test]
+fn test_process_image_path() {
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    // Only retrieved with the executable path.
+    assert!(s
+        .process(pid)
+        .expect("process not found")
+        .image_path()
+        .is_none());
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_exe(UpdateKind::OnlyIfNotSet),
+    );
+    let image_path = s
+        .process(pid)
+        .expect("process not found")
+        .image_path()
+        .expect("no image path");
+    assert!(image_path.is_absolute());
+    assert_eq!(
+        image_path.file_name(),
+        std::env::current_exe().unwrap().file_name()
+    );
+}
+
+#[test]
+fn test_field_status_of_current_process() {
+    use sysinfo::{FieldStatus, ProcessField};
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM || cfg!(feature = "apple-sandbox") {
+        return;
     }
+    let pid = sysinfo::get_current_pid().expect("failed to get current pid");
+    let mut s = System::new();
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing(),
+    );
+    let process = s.process(pid).expect("current process not found");
+    let expected = if cfg!(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        windows
+    )) {
+        FieldStatus::NotRetrieved
+    } else {
+        FieldStatus::Unsupported
+    };
+    assert_eq!(process.field_status(ProcessField::Exe), expected);
+
+    s.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::Always)
+            .with_cmd(UpdateKind::Always),
+    );
+    let process = s.process(pid).expect("current process not found");
+    let expected = if expected == FieldStatus::NotRetrieved {
+        FieldStatus::Ok
+    } else {
+        expected
+    };
+    assert_eq!(process.field_status(ProcessField::Exe), expected);
+    assert_eq!(process.field_status(ProcessField::Cmd), expected);
 }
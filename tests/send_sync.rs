@@ -9,4 +9,7 @@ fn test_send_sync() {
 
     is_send::<sysinfo::System>();
     is_sync::<sysinfo::System>();
+    is_send::<sysinfo::SharedSystem>();
+    is_sync::<sysinfo::SharedSystem>();
+    is_send::<sysinfo::ProcessWatcher>();
 }
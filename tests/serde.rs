@@ -0,0 +1,313 @@
+// Take a look at the license at the top of the repository in the LICENSE file.
+
+// These tests define the serialized format of the snapshot data: each collection is serialized to
+// JSON and deserialized into a plain owned struct which rejects the unknown fields. If a field is
+// added, renamed or removed, the corresponding struct needs to be updated.
+
+#![cfg(feature = "serde")]
+// Most of the fields are only there to define the format, they are not read.
+#![allow(dead_code)]
+
+use serde::Deserialize;
+use serde_json::Value;
+
+#[cfg(feature = "system")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SystemSnapshot {
+    global_cpu_usage: f32,
+    cpus: Vec<CpuSnapshot>,
+    physical_core_count: Option<usize>,
+    total_memory: u64,
+    free_memory: u64,
+    available_memory: u64,
+    used_memory: u64,
+    total_swap: u64,
+    free_swap: u64,
+    used_swap: u64,
+    uptime: u64,
+    boot_time: u64,
+    load_average: Value,
+    name: Option<String>,
+    kernel_version: Option<String>,
+    os_version: Option<String>,
+    long_os_version: Option<String>,
+    distribution_id: String,
+    host_name: Option<String>,
+}
+
+#[cfg(feature = "system")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CpuSnapshot {
+    cpu_usage: f32,
+    name: String,
+    vendor_id: String,
+    brand: String,
+    frequency: u64,
+}
+
+#[cfg(feature = "system")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProcessSnapshot {
+    // The `OsStr` and `OsString` values are serialized with their platform representation.
+    name: Value,
+    cmd: Vec<Value>,
+    exe: Option<String>,
+    pid: u32,
+    ns_pid: Option<String>,
+    environ: Vec<Value>,
+    cwd: Option<String>,
+    root: Option<String>,
+    memory: u64,
+    virtual_memory: u64,
+    parent: Option<String>,
+    status: Value,
+    start_time: u64,
+    run_time: u64,
+    cpu_usage: f32,
+    accumulated_cpu_time: u64,
+    user_cpu_time: Value,
+    system_cpu_time: Value,
+    thread_count: Option<usize>,
+    disk_usage: Value,
+    fd_usage: Option<Value>,
+    namespaces: Option<Value>,
+    user_id: Option<String>,
+    effective_user_id: Option<String>,
+    group_id: Option<String>,
+    effective_group_id: Option<String>,
+    session_id: Option<String>,
+    thread_kind: Option<Value>,
+}
+
+#[cfg(feature = "disk")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DiskSnapshot {
+    #[serde(rename = "DiskKind")]
+    kind: Value,
+    name: String,
+    file_system: Value,
+    mount_point: String,
+    total_space: u64,
+    available_space: u64,
+    is_removable: bool,
+    is_read_only: bool,
+    usage: DiskUsageSnapshot,
+}
+
+#[cfg(feature = "disk")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DiskUsageSnapshot {
+    total_written_bytes: u64,
+    written_bytes: u64,
+    total_read_bytes: u64,
+    read_bytes: u64,
+}
+
+#[cfg(feature = "network")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct NetworkDataSnapshot {
+    received: u64,
+    total_received: u64,
+    transmitted: u64,
+    total_transmitted: u64,
+    packets_received: u64,
+    total_packets_received: u64,
+    packets_transmitted: u64,
+    total_packets_transmitted: u64,
+    errors_on_received: u64,
+    total_errors_on_received: u64,
+    errors_on_transmitted: u64,
+    total_errors_on_transmitted: u64,
+    dropped_packets_received: u64,
+    total_dropped_packets_received: u64,
+    dropped_packets_transmitted: u64,
+    total_dropped_packets_transmitted: u64,
+    collisions: u64,
+    total_collisions: u64,
+    multicast_received: u64,
+    total_multicast_received: u64,
+    received_bytes_per_sec: Option<f64>,
+    transmitted_bytes_per_sec: Option<f64>,
+    packets_received_per_sec: Option<f64>,
+    packets_transmitted_per_sec: Option<f64>,
+    mac_address: [u8; 6],
+    ip_networks: Vec<Value>,
+    mtu: u64,
+    is_loopback: bool,
+    is_up: bool,
+    is_point_to_point: bool,
+    supports_multicast: bool,
+    supports_broadcast: bool,
+    kind: Value,
+    wifi_info: Option<Value>,
+    index: u32,
+    friendly_name: Option<String>,
+    master: Option<String>,
+    lower_device: Option<String>,
+    vlan_id: Option<u16>,
+}
+
+#[cfg(feature = "component")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ComponentSnapshot {
+    temperature: Option<f32>,
+    max: Option<f32>,
+    observed_max: Option<f32>,
+    min: Option<f32>,
+    critical: Option<f32>,
+    critical_hysteresis: Option<f32>,
+    alarm: Option<bool>,
+    label: String,
+    id: Option<String>,
+    device_name: Option<String>,
+}
+
+#[cfg(feature = "user")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UserSnapshot {
+    id: String,
+    group_id: String,
+    name: String,
+    full_name: Option<String>,
+    is_disabled: Option<bool>,
+    is_locked_out: Option<bool>,
+    password_expired: Option<bool>,
+    groups: Vec<GroupSnapshot>,
+    home_directory: Option<String>,
+    shell: Option<String>,
+    domain: Option<String>,
+    last_login: Option<Value>,
+}
+
+#[cfg(feature = "user")]
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GroupSnapshot {
+    id: String,
+    name: String,
+    members: Vec<String>,
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn check_system_round_trip() {
+    let mut s = sysinfo::System::new();
+    s.refresh_memory();
+    s.refresh_cpu_all();
+
+    let json = serde_json::to_string(&s).expect("failed to serialize System");
+    let snapshot: SystemSnapshot = serde_json::from_str(&json).expect("unexpected System fields");
+    assert_eq!(snapshot.total_memory, s.total_memory());
+    assert_eq!(snapshot.total_swap, s.total_swap());
+    assert_eq!(snapshot.cpus.len(), s.cpus().len());
+    for (cpu, cpu_snapshot) in s.cpus().iter().zip(&snapshot.cpus) {
+        assert_eq!(cpu_snapshot.name, cpu.name());
+        assert_eq!(cpu_snapshot.brand, cpu.brand());
+    }
+}
+
+#[cfg(feature = "system")]
+#[test]
+fn check_processes_round_trip() {
+    use std::collections::HashMap;
+
+    let mut s = sysinfo::System::new();
+    s.refresh_processes(sysinfo::ProcessesToUpdate::All, false);
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(!s.processes().is_empty());
+    }
+
+    let json = serde_json::to_string(s.processes()).expect("failed to serialize the processes");
+    let snapshot: HashMap<String, ProcessSnapshot> =
+        serde_json::from_str(&json).expect("unexpected Process fields");
+    assert_eq!(snapshot.len(), s.processes().len());
+    for (pid, process) in s.processes() {
+        let process_snapshot = &snapshot[&pid.to_string()];
+        assert_eq!(process_snapshot.pid, pid.as_u32());
+        assert_eq!(process_snapshot.memory, process.memory());
+    }
+}
+
+#[cfg(feature = "disk")]
+#[test]
+fn check_disks_round_trip() {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+
+    let json = serde_json::to_string(&disks).expect("failed to serialize the disks");
+    let snapshot: Vec<DiskSnapshot> = serde_json::from_str(&json).expect("unexpected Disk fields");
+    assert_eq!(snapshot.len(), disks.list().len());
+    for (disk, disk_snapshot) in disks.iter().zip(&snapshot) {
+        assert_eq!(disk_snapshot.total_space, disk.total_space());
+        assert_eq!(
+            disk_snapshot.mount_point,
+            disk.mount_point().to_string_lossy()
+        );
+    }
+}
+
+#[cfg(feature = "network")]
+#[test]
+fn check_networks_round_trip() {
+    let networks = sysinfo::Networks::new_with_refreshed_list();
+
+    let json = serde_json::to_string(&networks).expect("failed to serialize the networks");
+    let snapshot: Vec<(String, NetworkDataSnapshot)> =
+        serde_json::from_str(&json).expect("unexpected NetworkData fields");
+    assert_eq!(snapshot.len(), networks.iter().count());
+    for (name, network_snapshot) in &snapshot {
+        let network = &networks[name];
+        assert_eq!(network_snapshot.total_received, network.total_received());
+        assert_eq!(network_snapshot.mtu, network.mtu());
+    }
+}
+
+#[cfg(feature = "component")]
+#[test]
+fn check_components_round_trip() {
+    let components = sysinfo::Components::new_with_refreshed_list();
+
+    let json = serde_json::to_string(&components).expect("failed to serialize the components");
+    let snapshot: Vec<ComponentSnapshot> =
+        serde_json::from_str(&json).expect("unexpected Component fields");
+    assert_eq!(snapshot.len(), components.list().len());
+    for (component, component_snapshot) in components.iter().zip(&snapshot) {
+        assert_eq!(component_snapshot.label, component.label());
+    }
+}
+
+#[cfg(feature = "user")]
+#[test]
+fn check_users_round_trip() {
+    let users = sysinfo::Users::new_with_refreshed_list();
+
+    let json = serde_json::to_string(&users).expect("failed to serialize the users");
+    let snapshot: Vec<UserSnapshot> = serde_json::from_str(&json).expect("unexpected User fields");
+    assert_eq!(snapshot.len(), users.list().len());
+    for (user, user_snapshot) in users.iter().zip(&snapshot) {
+        assert_eq!(user_snapshot.name, user.name());
+        assert_eq!(user_snapshot.id, user.id().to_string());
+    }
+}
+
+#[cfg(feature = "user")]
+#[test]
+fn check_groups_round_trip() {
+    let groups = sysinfo::Groups::new_with_refreshed_list();
+
+    let json = serde_json::to_string(&groups).expect("failed to serialize the groups");
+    let snapshot: Vec<GroupSnapshot> =
+        serde_json::from_str(&json).expect("unexpected Group fields");
+    assert_eq!(snapshot.len(), groups.list().len());
+    for (group, group_snapshot) in groups.iter().zip(&snapshot) {
+        assert_eq!(group_snapshot.name, group.name());
+        assert_eq!(group_snapshot.id, group.id().to_string());
+    }
+}
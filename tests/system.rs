@@ -3,7 +3,7 @@
 #![cfg(feature = "system")]
 #![allow(clippy::assertions_on_constants)]
 
-use sysinfo::{ProcessesToUpdate, System};
+use sysinfo::{CpuRefreshKind, ProcessesToUpdate, RefreshKind, System};
 
 #[test]
 fn test_refresh_system() {
@@ -107,6 +107,32 @@ fn check_hostname_has_no_nuls() {
     }
 }
 
+#[test]
+fn test_new_all_refreshed() {
+    let s = System::new_all_refreshed();
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(!s.cpus().is_empty());
+        assert!(s.total_memory() > 0);
+        assert!(s.process(sysinfo::get_current_pid().unwrap()).is_some());
+    }
+}
+
+#[test]
+fn test_refresh_kind_without() {
+    let s = System::new_with_specifics(RefreshKind::everything().without_processes());
+    assert!(s.processes().is_empty());
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(!s.cpus().is_empty());
+        assert!(s.total_memory() > 0);
+    }
+
+    let s = System::new_with_specifics(RefreshKind::everything().without_cpu_frequency());
+    assert!(s.cpus().iter().all(|cpu| cpu.frequency() == 0));
+    if sysinfo::IS_SUPPORTED_SYSTEM && !cfg!(feature = "apple-sandbox") {
+        assert!(s.process(sysinfo::get_current_pid().unwrap()).is_some());
+    }
+}
+
 #[test]
 fn check_uptime() {
     let uptime = System::uptime();
@@ -117,6 +143,69 @@ fn check_uptime() {
     }
 }
 
+#[test]
+fn check_active_time() {
+    let active_time = System::active_time();
+    let uptime = System::uptime();
+    assert!(active_time <= uptime);
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_ne!(active_time, 0);
+    } else {
+        assert_eq!(active_time, 0);
+    }
+}
+
+#[test]
+fn test_open_files_max_pid_and_threads_total() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(System::open_files(), None);
+        assert_eq!(System::max_pid(), None);
+        assert_eq!(System::threads_total(), None);
+        return;
+    }
+    if let Some((allocated, max)) = System::open_files() {
+        assert!(allocated > 0);
+        assert!(allocated <= max);
+    }
+    let current_pid = sysinfo::get_current_pid().unwrap();
+    if let Some(max_pid) = System::max_pid() {
+        assert!(current_pid.as_u32() as u64 <= max_pid);
+    }
+    if let Some(threads) = System::threads_total() {
+        assert!(threads > 0);
+    }
+    if cfg!(any(target_os = "linux", target_os = "android")) {
+        assert!(System::open_files().is_some());
+        assert!(System::max_pid().is_some());
+        assert!(System::threads_total().is_some());
+    }
+}
+
+#[test]
+fn check_reboot_required() {
+    let reboot_required = System::reboot_required();
+    if !sysinfo::IS_SUPPORTED_SYSTEM
+        || !cfg!(any(target_os = "linux", target_os = "android", windows))
+    {
+        assert_eq!(reboot_required, None);
+    } else if cfg!(windows) {
+        assert!(reboot_required.is_some());
+    }
+}
+
+#[test]
+fn check_cpu_arch_detail() {
+    let detail = System::cpu_arch_detail();
+    assert!(detail.bits == 32 || detail.bits == 64);
+    // A 64-bit process can only run on a 64-bit CPU.
+    if usize::BITS == 64 {
+        assert_eq!(detail.bits, 64);
+    }
+    if cfg!(target_arch = "x86_64") && System::is_translated() != Some(true) {
+        assert!(matches!(detail.arch, sysinfo::Arch::X86(Some(_))));
+    }
+}
+
 #[test]
 fn check_boot_time() {
     if sysinfo::IS_SUPPORTED_SYSTEM {
@@ -246,3 +335,248 @@ fn test_refresh_memory() {
     assert_ne!(s.total_memory(), 0);
     assert_ne!(s.free_memory(), 0);
 }
+
+#[test]
+fn test_try_refresh_memory() {
+    let mut s = System::new();
+    if let Err(error) = s.try_refresh_memory() {
+        panic!("failed to refresh the memory: {error}");
+    }
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_ne!(s.total_memory(), 0);
+    }
+}
+
+#[test]
+fn test_used_memory_like_free() {
+    let mut s = System::new();
+    assert_eq!(s.used_memory_like_free(), 0);
+    s.refresh_memory();
+    assert_eq!(
+        s.used_memory_like_free() + s.available_memory(),
+        s.total_memory()
+    );
+    if cfg!(any(target_os = "linux", target_os = "android", windows)) {
+        assert_eq!(s.used_memory_like_free(), s.used_memory());
+    }
+}
+
+#[test]
+fn test_pressure() {
+    for pressure in [
+        System::memory_pressure(),
+        System::cpu_pressure(),
+        System::io_pressure(),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        for stall in [&pressure.some, &pressure.full].into_iter().flatten() {
+            assert!((0. ..=100.).contains(&stall.avg10));
+        }
+        if cfg!(any(target_os = "linux", target_os = "android")) {
+            assert!(pressure.some.is_some());
+            assert_eq!(pressure.level, None);
+        }
+    }
+    if !cfg!(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+    )) {
+        assert_eq!(System::memory_pressure(), None);
+    }
+}
+
+#[test]
+fn test_hardware() {
+    let hardware = System::hardware();
+    // The information is only retrieved once.
+    assert!(std::ptr::eq(hardware, System::hardware()));
+    for value in [
+        &hardware.vendor,
+        &hardware.product_name,
+        &hardware.board_name,
+        &hardware.bios_version,
+        &hardware.bios_date,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        assert!(!value.is_empty());
+        assert_eq!(value.trim(), value);
+    }
+}
+
+#[test]
+fn test_machine_and_boot_ids() {
+    let machine_id = System::machine_id();
+    let boot_id = System::boot_id();
+    assert_eq!(machine_id, System::machine_id());
+    assert_eq!(boot_id, System::boot_id());
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(machine_id, None);
+        assert_eq!(boot_id, None);
+        return;
+    }
+    if let Some(machine_id) = machine_id {
+        assert!(!machine_id.is_empty());
+        assert_eq!(machine_id.trim(), machine_id);
+        // The boot identifier is derived from the machine one on the other platforms.
+        if !cfg!(any(target_os = "linux", target_os = "android")) {
+            assert!(boot_id.is_some());
+        }
+    }
+    if let Some(boot_id) = boot_id {
+        assert_eq!(boot_id.len(), 36);
+    }
+    if cfg!(target_os = "linux") && std::path::Path::new("/proc/sys/kernel/random/boot_id").exists()
+    {
+        assert!(System::boot_id().is_some());
+    }
+}
+
+#[test]
+fn test_kernel_cmdline_and_parameters() {
+    if cfg!(target_os = "linux") && sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(System::kernel_cmdline().is_some());
+        let pid_max = System::kernel_parameter("kernel.pid_max").expect("no pid_max");
+        assert!(pid_max.parse::<u64>().is_ok_and(|pid_max| pid_max > 0));
+        assert_eq!(
+            System::kernel_parameter("kernel/pid_max").as_deref(),
+            Some(pid_max.as_str())
+        );
+    }
+    assert_eq!(System::kernel_parameter("../../etc/passwd"), None);
+    assert_eq!(System::kernel_parameter("kernel.does_not_exist"), None);
+}
+
+#[test]
+fn test_effective_cpu_count() {
+    let s = System::new();
+    assert!(s.effective_cpu_count() > 0.);
+
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        let s =
+            System::new_with_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing()));
+        let count = s.effective_cpu_count();
+        assert!(count > 0.);
+        assert!(count <= s.cpus().len() as f64);
+    }
+}
+
+#[test]
+fn test_timezone_locale_and_monotonic_uptime() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(System::timezone(), None);
+        assert_eq!(System::time_since_boot_monotonic(), None);
+        return;
+    }
+    if let Some(timezone) = System::timezone() {
+        assert!(!timezone.is_empty());
+        assert!(!timezone.starts_with('/'), "{timezone}");
+    }
+    if let Some(locale) = System::locale() {
+        assert!(!locale.is_empty());
+    }
+
+    let since_boot = System::time_since_boot_monotonic().expect("no monotonic uptime");
+    assert!(since_boot > std::time::Duration::ZERO);
+    assert!(System::time_since_boot_monotonic().unwrap() >= since_boot);
+    // Unless the system clock was changed since the boot, both values should be close.
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let boot_time = now.saturating_sub(since_boot.as_secs());
+    assert!(boot_time.abs_diff(System::boot_time()) < 24 * 60 * 60);
+}
+
+#[test]
+fn test_domain_name_and_fqdn() {
+    let domain_name = System::domain_name();
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(domain_name, None);
+        assert_eq!(System::domain_kind(), None);
+        assert_eq!(System::fqdn(), None);
+        return;
+    }
+    if let Some(domain_name) = &domain_name {
+        assert!(!domain_name.is_empty());
+        assert_ne!(domain_name, "(none)");
+    }
+    if let Some(fqdn) = System::fqdn() {
+        assert!(fqdn.contains('.'), "{fqdn}");
+        assert!(!fqdn.ends_with('.'), "{fqdn}");
+    }
+    #[cfg(not(windows))]
+    assert_eq!(
+        System::domain_kind(),
+        domain_name.map(|_| sysinfo::DomainKind::Domain)
+    );
+}
+
+#[test]
+fn test_architectures() {
+    assert_eq!(System::process_arch(), std::env::consts::ARCH);
+    assert!(!System::cpu_arch().is_empty());
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(System::kernel_arch(), None);
+        assert_eq!(System::is_translated(), None);
+        return;
+    }
+    let kernel_arch = System::kernel_arch().expect("no kernel architecture");
+    assert!(!kernel_arch.is_empty());
+    match System::is_translated() {
+        Some(translated) => {
+            assert!(cfg!(any(target_os = "macos", target_os = "ios", windows)));
+            if !translated && cfg!(target_os = "macos") {
+                assert_eq!(System::cpu_arch(), kernel_arch);
+            }
+        }
+        // Only macOS and Windows run translated processes.
+        None => {
+            assert!(!cfg!(any(target_os = "macos", target_os = "ios", windows)));
+            assert_eq!(System::cpu_arch(), kernel_arch);
+        }
+    }
+}
+
+#[test]
+fn test_virtualization() {
+    let virtualization = System::virtualization();
+    if sysinfo::IS_SUPPORTED_SYSTEM {
+        assert!(virtualization.is_some());
+    } else {
+        assert_eq!(virtualization, None);
+    }
+}
+
+#[test]
+fn check_os_build_and_display_version() {
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(System::os_build(), None);
+        assert_eq!(System::os_display_version(), None);
+        return;
+    }
+    if cfg!(any(target_os = "macos", windows)) {
+        let os_build = System::os_build().expect("the OS build should be known");
+        assert!(!os_build.is_empty());
+    }
+    if cfg!(windows) {
+        // The build always starts with the build number.
+        assert!(System::os_build()
+            .unwrap()
+            .starts_with(&System::kernel_version().unwrap()));
+    }
+}
+
+#[test]
+fn check_clock_ticks_per_second() {
+    let ticks = System::clock_ticks_per_second();
+    assert!(ticks > 0);
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        assert_eq!(ticks, 100);
+    }
+}
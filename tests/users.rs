@@ -27,6 +27,31 @@ fn test_users() {
     }
 }
 
+#[cfg(feature = "user")]
+#[test]
+fn test_users_home_directory_and_shell() {
+    use sysinfo::{Users, UsersRefreshKind};
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let users = Users::new_with_refreshed_list();
+    assert!(users
+        .iter()
+        .all(|user| user.home_directory().is_none() && user.shell().is_none()));
+
+    let users = Users::new_with_refreshed_list_specifics(
+        UsersRefreshKind::nothing()
+            .with_home_directory()
+            .with_shell(),
+    );
+    // Users who never logged in have no profile directory on Windows.
+    if !cfg!(windows) {
+        assert!(users.iter().any(|user| user.home_directory().is_some()));
+        assert!(users.iter().any(|user| user.shell().is_some()));
+    }
+}
+
 // This test ensures that there are actually groups listed, in particular for Windows.
 #[cfg(feature = "user")]
 #[test]
@@ -40,3 +65,132 @@ fn test_groups() {
     groups.refresh();
     assert!(groups.list().len() > 1);
 }
+
+#[cfg(all(feature = "user", windows))]
+#[test]
+fn test_group_member_ids() {
+    use sysinfo::Groups;
+
+    let groups = Groups::new_with_refreshed_list();
+    assert!(groups
+        .list()
+        .iter()
+        .all(|group| group.member_ids().len() <= group.members().len()));
+    // The local administrators group always has at least one member.
+    assert!(groups
+        .list()
+        .iter()
+        .any(|group| !group.member_ids().is_empty()));
+}
+
+#[cfg(feature = "user")]
+#[test]
+fn test_sessions() {
+    use sysinfo::{SessionKind, Sessions, Users};
+
+    let mut sessions = Sessions::new();
+    assert!(sessions.list().is_empty());
+    // There can be no logged in user, like in a CI.
+    sessions.refresh();
+    let users = Users::new_with_refreshed_list();
+    for session in &sessions {
+        assert!(!session.user_name().is_empty());
+        if session.kind() == SessionKind::Local {
+            assert!(session.remote_host().is_none());
+        }
+        if let Some(user) = session.user_id().and_then(|id| users.get_user_by_id(id)) {
+            assert_eq!(user.name(), session.user_name());
+        }
+    }
+}
+
+#[cfg(feature = "user")]
+#[test]
+fn test_get_user_by_id_or_fetch() {
+    use sysinfo::Users;
+
+    if !sysinfo::IS_SUPPORTED_SYSTEM {
+        return;
+    }
+    let all_users = Users::new_with_refreshed_list();
+    let mut users = Users::new();
+    for user in all_users.list() {
+        let fetched = users
+            .get_user_by_id_or_fetch(user.id())
+            .expect("failed to fetch user");
+        assert_eq!(fetched.id(), user.id());
+        // Some accounts share the same ID, like `root` and `toor` on FreeBSD.
+        if all_users.iter().filter(|u| u.id() == user.id()).count() == 1 {
+            assert_eq!(fetched.name(), user.name());
+            assert_eq!(fetched.group_id(), user.group_id());
+            assert_eq!(fetched.full_name(), user.full_name());
+        }
+    }
+    // The users are only fetched once.
+    let count = users.list().len();
+    if let Some(user) = all_users.list().first() {
+        assert!(users.get_user_by_id_or_fetch(user.id()).is_some());
+    }
+    assert_eq!(users.list().len(), count);
+}
+
+#[cfg(feature = "user")]
+#[test]
+fn test_users_account_status() {
+    use sysinfo::Users;
+
+    let users = Users::new_with_refreshed_list();
+    for user in users.list() {
+        // All the flags are retrieved at once.
+        if user.is_locked_out().is_some() || user.password_expired().is_some() {
+            assert!(user.is_disabled().is_some());
+        }
+        if !cfg!(windows) {
+            assert!(user.is_locked_out().is_none());
+            assert!(user.password_expired().is_none());
+        }
+    }
+}
+
+#[cfg(feature = "user")]
+#[test]
+fn test_users_last_login() {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    use sysinfo::Users;
+
+    let users = Users::new_with_refreshed_list();
+    let now = SystemTime::now();
+    for user in users.list() {
+        if let Some(last_login) = user.last_login() {
+            assert!(last_login > UNIX_EPOCH);
+            assert!(last_login <= now);
+        }
+    }
+}
+
+#[cfg(feature = "user")]
+#[test]
+fn test_uid_gid_conversions() {
+    use sysinfo::{Gid, Uid};
+
+    assert_eq!(*Gid::from(42), 42);
+    assert_eq!(Some(Gid::from(42)), "42".parse::<Gid>().ok());
+    if cfg!(windows) {
+        let uid = "S-1-5-21-1004336348-1177238915-682003330-500"
+            .parse::<Uid>()
+            .expect("failed to parse SID");
+        assert_eq!(uid.as_u32(), Some(500));
+        let uid = "S-1-5-18".parse::<Uid>().expect("failed to parse SID");
+        assert_eq!(uid.as_u32(), Some(18));
+    } else {
+        let uid = "1000".parse::<Uid>().expect("failed to parse uid");
+        assert_eq!(uid.as_u32(), Some(1000));
+        assert_eq!(uid.to_string(), "1000");
+        assert!("-1".parse::<Uid>().is_err());
+    }
+
+    #[cfg(not(windows))]
+    for user in sysinfo::Users::new_with_refreshed_list().list() {
+        assert_eq!(user.id().as_u32(), Some(**user.id()));
+    }
+}